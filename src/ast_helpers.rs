@@ -1,20 +1,59 @@
+use std::borrow::Cow;
+
 use squalid::OptionExt;
-use tree_sitter_lint::{tree_sitter::Node, tree_sitter_grep::SupportedLanguage, NodeExt};
+use tree_sitter_lint::{
+    tree_sitter::{Node, Range},
+    tree_sitter_grep::SupportedLanguage,
+    NodeExt, QueryMatchContext,
+};
+#[cfg(any(feature = "stylistic", feature = "type-aware"))]
+use tree_sitter_lint_plugin_eslint_builtin::kind::{is_literal_kind, TemplateString};
+#[cfg(feature = "type-aware")]
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    BinaryExpression, ConditionalExpression, MemberExpression, SubscriptExpression,
+    TemplateSubstitution,
+};
 use tree_sitter_lint_plugin_eslint_builtin::{
     assert_kind,
-    ast_helpers::skip_nodes_of_type,
-    kind::{Class, ClassDeclaration, ClassHeritage, MethodDefinition},
+    ast_helpers::{get_method_definition_kind, skip_nodes_of_type, MethodDefinitionKind},
+    kind::{
+        Arguments, ArrayPattern, ArrowFunction, AssignmentPattern, CallExpression, Class,
+        ClassBody, ClassDeclaration, ClassHeritage, Comment, ComputedPropertyName, Decorator,
+        Function, FunctionDeclaration, GeneratorFunction, GeneratorFunctionDeclaration, Identifier,
+        ImportClause, JsxExpression, MethodDefinition, NamedImports, NamespaceImport,
+        NewExpression, Object, ObjectAssignmentPattern, ObjectPattern, Pair, PairPattern,
+        ParenthesizedExpression, PropertyIdentifier, RestPattern, ReturnStatement,
+        ShorthandPropertyIdentifier, ShorthandPropertyIdentifierPattern, SpreadElement,
+        String as StringKind, This, UnaryExpression, VariableDeclarator,
+    },
+    utils::ast_utils::get_static_string_value,
+};
+#[cfg(feature = "stylistic")]
+use tree_sitter_lint_plugin_eslint_builtin::{
+    ast_helpers::{is_simple_template_literal, is_tagged_template_expression},
+    kind::Number,
 };
 
-use crate::kind::{
-    AbstractMethodSignature, AccessibilityModifier, AmbientDeclaration, ImplementsClause,
-    IndexSignature, InterfaceDeclaration, MappedTypeClause, MethodSignature, NestedTypeIdentifier,
-    ObjectType, OverrideModifier, ParenthesizedType, PropertySignature, PublicFieldDefinition,
-    TypeIdentifier, TypeParameter,
+#[cfg(any(feature = "stylistic", feature = "type-aware"))]
+use crate::util::skip_parenthesized_expressions;
+use crate::{
+    kind::{
+        AbstractClassDeclaration, AbstractMethodSignature, AccessibilityModifier,
+        AmbientDeclaration, AsExpression, EnumAssignment, EnumDeclaration, FunctionType,
+        ImplementsClause, IndexSignature, InterfaceDeclaration, InternalModule, MappedTypeClause,
+        MethodSignature, Module, NestedTypeIdentifier, NonNullExpression, ObjectType,
+        OptionalParameter, OverrideModifier, ParenthesizedType, PropertySignature,
+        PublicFieldDefinition, RequiredParameter, SatisfiesExpression, TypeAssertion,
+        TypeIdentifier, TypeParameter,
+    },
+    util::{is_in_type_position, nearest_enclosing_function, resolve_function},
 };
 
 pub fn is_member_static(node: Node) -> bool {
-    assert_kind!(node, MethodDefinition | MethodSignature);
+    assert_kind!(
+        node,
+        MethodDefinition | MethodSignature | AbstractMethodSignature | PublicFieldDefinition
+    );
     node.non_comment_children_and_field_names(SupportedLanguage::Javascript)
         .take_while(|(_, field_name)| *field_name != Some("name"))
         .any(|(child, _)| child.kind() == "static")
@@ -73,11 +112,33 @@ pub fn is_type_reference(node: Node) -> bool {
     {
         return false;
     }
-    true
+    is_in_type_position(node)
+}
+
+pub fn is_async_function(node: Node) -> bool {
+    assert_kind!(
+        node,
+        ArrowFunction
+            | Function
+            | FunctionDeclaration
+            | GeneratorFunction
+            | GeneratorFunctionDeclaration
+            | MethodDefinition
+    );
+
+    node.has_child_of_kind("async")
+}
+
+/// Class expressions and (abstract or not) class declarations.
+pub fn is_class(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        Class | ClassDeclaration | AbstractClassDeclaration
+    )
 }
 
 pub fn get_class_heritage(node: Node) -> Option<Node> {
-    assert_kind!(node, Class | ClassDeclaration);
+    assert_kind!(node, Class | ClassDeclaration | AbstractClassDeclaration);
 
     node.non_comment_named_children_and_field_names(SupportedLanguage::Javascript)
         .take_while(|(_, field_name)| *field_name != Some("body"))
@@ -85,7 +146,7 @@ pub fn get_class_heritage(node: Node) -> Option<Node> {
 }
 
 pub fn class_has_implements_clause(node: Node) -> bool {
-    assert_kind!(node, Class | ClassDeclaration);
+    assert_kind!(node, Class | ClassDeclaration | AbstractClassDeclaration);
 
     get_class_heritage(node)
         .matches(|class_heritage| class_heritage.has_child_of_kind(ImplementsClause))
@@ -110,6 +171,8 @@ pub fn get_accessibility_modifier(node: Node) -> Option<Node> {
             | AbstractMethodSignature
             | MethodDefinition
             | PropertySignature
+            | RequiredParameter
+            | OptionalParameter
     );
 
     node.non_comment_named_children_and_field_names(SupportedLanguage::Javascript)
@@ -117,13 +180,11 @@ pub fn get_accessibility_modifier(node: Node) -> Option<Node> {
         .find_map(|(node, _)| (node.kind() == AccessibilityModifier).then_some(node))
 }
 
-#[allow(dead_code)]
+/// An actual index signature (eg `[key: string]: number`), as opposed to
+/// the `index_signature` that tree-sitter-typescript parses the body of a
+/// mapped type as.
 pub fn is_index_signature(node: Node) -> bool {
-    if node.kind() != IndexSignature {
-        return false;
-    }
-
-    !is_mapped_type(node)
+    node.kind() == IndexSignature && !node.has_child_of_kind(MappedTypeClause)
 }
 
 pub fn is_global_ambient_declaration(node: Node) -> bool {
@@ -135,3 +196,654 @@ pub fn is_global_ambient_declaration(node: Node) -> bool {
             .kind()
             == "global"
 }
+
+/// A `namespace` or `module` declaration. (`declare global {}` isn't one,
+/// since tree-sitter-typescript parses it as just an `ambient_declaration`.)
+pub fn is_module_declaration(node: Node) -> bool {
+    matches!(node.kind(), InternalModule | Module)
+}
+
+/// `declare module 'foo' {}`, which declares (or augments) an external
+/// module rather than a namespace.
+pub fn is_external_module_declaration(node: Node) -> bool {
+    is_module_declaration(node) && node.field("name").kind() == StringKind
+}
+
+/// The `module` or `namespace` keyword of a module declaration.
+#[cfg(feature = "stylistic")]
+pub fn get_module_declaration_keyword(node: Node) -> Node {
+    assert_kind!(node, InternalModule | Module);
+    node.first_non_comment_child(SupportedLanguage::Javascript)
+}
+
+/// Everything making up the signature of a function-like node (type
+/// parameters, parameters, return type), ie all of its children other than
+/// its body.
+#[cfg(feature = "type-aware")]
+pub fn get_signature_children(node: Node) -> impl Iterator<Item = Node> {
+    node.non_comment_named_children_and_field_names(SupportedLanguage::Javascript)
+        .filter(|(_, field_name)| *field_name != Some("body"))
+        .map(|(child, _)| child)
+}
+
+/// A TS `this` parameter (eg `this: Foo` in `function foo(this: Foo) {}`),
+/// which declares the type of `this` rather than an actual parameter.
+pub fn is_this_parameter(node: Node) -> bool {
+    matches!(node.kind(), RequiredParameter | OptionalParameter)
+        && node.field("pattern").kind() == This
+}
+
+/// The `this` parameter of a function-like node, if any (TS only allows it
+/// as the first parameter).
+pub fn get_this_parameter(node: Node) -> Option<Node> {
+    node.child_by_field_name("parameters")?
+        .non_comment_named_children(SupportedLanguage::Javascript)
+        .next()
+        .filter(|&parameter| is_this_parameter(parameter))
+}
+
+/// A constructor parameter with an accessibility, `override` or `readonly`
+/// modifier, which also declares a class property.
+pub fn is_parameter_property(node: Node) -> bool {
+    node.non_comment_children_and_field_names(SupportedLanguage::Javascript)
+        .take_while(|(_, field_name)| *field_name != Some("pattern"))
+        .any(|(child, _)| {
+            matches!(
+                child.kind(),
+                AccessibilityModifier | OverrideModifier | "readonly"
+            )
+        })
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CallArgument<'a> {
+    Expression(Node<'a>),
+    /// A `spread_element`, along with the expression being spread.
+    Spread {
+        node: Node<'a>,
+        argument: Node<'a>,
+    },
+}
+
+impl CallArgument<'_> {
+    pub fn is_spread(&self) -> bool {
+        matches!(self, Self::Spread { .. })
+    }
+}
+
+/// The arguments of a call or `new` expression (none for tagged templates
+/// and `new Foo`).
+pub fn get_call_arguments(node: Node) -> impl Iterator<Item = CallArgument> {
+    assert_kind!(node, CallExpression | NewExpression);
+    node.child_by_field_name("arguments")
+        .filter(|arguments| arguments.kind() == Arguments)
+        .into_iter()
+        .flat_map(|arguments| arguments.non_comment_named_children(SupportedLanguage::Javascript))
+        .map(|argument| match argument.kind() {
+            SpreadElement => CallArgument::Spread {
+                node: argument,
+                argument: argument.first_non_comment_named_child(SupportedLanguage::Javascript),
+            },
+            _ => CallArgument::Expression(argument),
+        })
+}
+
+/// The decorators applied directly to a class, class member or parameter.
+pub fn get_decorators(node: Node) -> impl Iterator<Item = Node> {
+    node.non_comment_named_children(SupportedLanguage::Javascript)
+        .filter(|child| child.kind() == Decorator)
+}
+
+/// The decorators applied to a class member. tree-sitter-typescript puts a
+/// method's decorators in the class body just ahead of it rather than in the
+/// `method_definition` itself, so those count as well.
+pub fn get_member_decorators(node: Node) -> Vec<Node> {
+    let mut decorators = vec![];
+    if node.parent().unwrap().kind() == ClassBody {
+        let mut sibling = node.prev_named_sibling();
+        while let Some(current) = sibling {
+            match current.kind() {
+                Decorator => decorators.push(current),
+                Comment => (),
+                _ => break,
+            }
+            sibling = current.prev_named_sibling();
+        }
+        decorators.reverse();
+    }
+    decorators.extend(get_decorators(node));
+    decorators
+}
+
+/// `range` (eg a reported function head), moved to start after `node`'s
+/// decorators if it would otherwise start with them.
+pub fn skip_leading_decorators(range: Range, node: Node) -> Range {
+    if range.start_byte != node.start_byte() {
+        return range;
+    }
+    let Some(first_child) = node
+        .non_comment_children(SupportedLanguage::Javascript)
+        .find(|child| child.kind() != Decorator)
+    else {
+        return range;
+    };
+    Range {
+        start_byte: first_child.start_byte(),
+        start_point: first_child.start_position(),
+        ..range
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ImportBindingKind {
+    Default,
+    Namespace,
+    Named,
+}
+
+/// The local names bound by an `import` clause, along with how each of them
+/// is imported (`import { default as Foo }` counts as a default import).
+pub fn get_import_bindings<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Vec<(Node<'a>, ImportBindingKind)> {
+    assert_kind!(node, ImportClause);
+
+    let mut bindings = vec![];
+    for child in node.non_comment_named_children(SupportedLanguage::Javascript) {
+        match child.kind() {
+            Identifier => bindings.push((child, ImportBindingKind::Default)),
+            NamespaceImport => bindings.push((
+                child.first_non_comment_named_child(SupportedLanguage::Javascript),
+                ImportBindingKind::Namespace,
+            )),
+            NamedImports => {
+                for specifier in child.non_comment_named_children(SupportedLanguage::Javascript) {
+                    let imported = specifier.field("name");
+                    bindings.push((
+                        specifier.child_by_field_name("alias").unwrap_or(imported),
+                        if imported.text(context) == "default" {
+                            ImportBindingKind::Default
+                        } else {
+                            ImportBindingKind::Named
+                        },
+                    ));
+                }
+            }
+            _ => (),
+        }
+    }
+    bindings
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PropertyKind {
+    ObjectLiteralProperty,
+    ObjectLiteralMethod,
+    ObjectLiteralAccessor,
+    ClassProperty,
+    ClassMethod,
+    ClassicAccessor,
+    AutoAccessor,
+    TypeProperty,
+    TypeMethod,
+}
+
+fn is_function_expression(node: Node) -> bool {
+    matches!(node.kind(), ArrowFunction | Function | GeneratorFunction)
+}
+
+/// Whether any of the (anonymous) modifier keywords appear before the
+/// member's name, eg `static` or `readonly`.
+pub fn has_keyword_before_name(node: Node, keywords: &[&str]) -> bool {
+    node.non_comment_children_and_field_names(SupportedLanguage::Javascript)
+        .take_while(|(_, field_name)| *field_name != Some("name"))
+        .any(|(child, _)| keywords.contains(&child.kind()))
+}
+
+/// Classifies an object literal, class or type member by the kind of property
+/// it declares. Constructors aren't properties, so get `None`.
+pub fn get_property_kind<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<PropertyKind> {
+    match node.kind() {
+        Pair | ShorthandPropertyIdentifier => Some(
+            if node
+                .child_by_field_name("value")
+                .matches(is_function_expression)
+            {
+                PropertyKind::ObjectLiteralMethod
+            } else {
+                PropertyKind::ObjectLiteralProperty
+            },
+        ),
+        MethodDefinition => {
+            let is_object_literal = node.parent().unwrap().kind() == Object;
+            match get_method_definition_kind(node, context) {
+                MethodDefinitionKind::Constructor => None,
+                MethodDefinitionKind::Get | MethodDefinitionKind::Set => {
+                    Some(if is_object_literal {
+                        PropertyKind::ObjectLiteralAccessor
+                    } else {
+                        PropertyKind::ClassicAccessor
+                    })
+                }
+                _ => Some(if is_object_literal {
+                    PropertyKind::ObjectLiteralMethod
+                } else {
+                    PropertyKind::ClassMethod
+                }),
+            }
+        }
+        PublicFieldDefinition => Some(if has_keyword_before_name(node, &["accessor"]) {
+            PropertyKind::AutoAccessor
+        } else if node
+            .child_by_field_name("value")
+            .matches(is_function_expression)
+        {
+            PropertyKind::ClassMethod
+        } else {
+            PropertyKind::ClassProperty
+        }),
+        MethodSignature | AbstractMethodSignature => {
+            if node.parent().unwrap().kind() != ClassBody {
+                return Some(PropertyKind::TypeMethod);
+            }
+            // overload signatures
+            if node.field("name").text(context) == "constructor" {
+                return None;
+            }
+            Some(if has_keyword_before_name(node, &["get", "set"]) {
+                PropertyKind::ClassicAccessor
+            } else {
+                PropertyKind::ClassMethod
+            })
+        }
+        PropertySignature => Some(
+            if node.child_by_field_name("type").matches(|type_annotation| {
+                type_annotation
+                    .first_non_comment_named_child(SupportedLanguage::Javascript)
+                    .skip_parenthesized_types()
+                    .kind()
+                    == FunctionType
+            }) {
+                PropertyKind::TypeMethod
+            } else {
+                PropertyKind::TypeProperty
+            },
+        ),
+        _ => None,
+    }
+}
+
+fn collect_pattern_identifiers<'a>(node: Node<'a>, identifiers: &mut Vec<Node<'a>>) {
+    match node.kind() {
+        Identifier | ShorthandPropertyIdentifierPattern => identifiers.push(node),
+        ObjectPattern | ArrayPattern | RestPattern => {
+            for child in node.non_comment_named_children(SupportedLanguage::Javascript) {
+                collect_pattern_identifiers(child, identifiers);
+            }
+        }
+        PairPattern => collect_pattern_identifiers(node.field("value"), identifiers),
+        ObjectAssignmentPattern | AssignmentPattern => {
+            collect_pattern_identifiers(node.field("left"), identifiers)
+        }
+        _ => (),
+    }
+}
+
+/// The identifiers bound by a (possibly destructuring) binding pattern.
+pub fn get_pattern_identifiers(node: Node) -> Vec<Node> {
+    let mut identifiers = vec![];
+    collect_pattern_identifiers(node, &mut identifiers);
+    identifiers
+}
+
+pub fn get_range_from(start: Node, end: Node) -> Range {
+    Range {
+        start_byte: start.start_byte(),
+        end_byte: end.end_byte(),
+        start_point: start.start_position(),
+        end_point: end.end_position(),
+    }
+}
+
+/// Whether the two expressions are written the same way, ignoring
+/// whitespace, comments and redundant parentheses (so eg `foo.bar[0]` and
+/// `(foo) . bar[ /* first */ 0 ]` are the same). This says nothing about
+/// whether evaluating them gives the same value, eg for `foo()`.
+#[cfg(any(feature = "stylistic", feature = "type-aware"))]
+pub fn is_same_expression<'a>(
+    a: Node<'a>,
+    b: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    let a = skip_parenthesized_expressions(a);
+    let b = skip_parenthesized_expressions(b);
+    if a.kind() != b.kind() {
+        return false;
+    }
+    let a_children = a
+        .non_comment_children(SupportedLanguage::Javascript)
+        .collect::<Vec<_>>();
+    let b_children = b
+        .non_comment_children(SupportedLanguage::Javascript)
+        .collect::<Vec<_>>();
+    if a_children.is_empty() && b_children.is_empty() {
+        return a.text(context) == b.text(context);
+    }
+    a_children.len() == b_children.len()
+        && a_children
+            .into_iter()
+            .zip(b_children)
+            .all(|(a, b)| is_same_expression(a, b, context))
+}
+
+/// `void foo`.
+pub fn is_void_operator_expression(node: Node) -> bool {
+    node.kind() == UnaryExpression && node.field("operator").kind() == "void"
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AssertionKind {
+    /// `x as T`
+    As,
+    /// `<T>x`
+    AngleBracket,
+    /// `x satisfies T`
+    Satisfies,
+    /// `x!`
+    NonNull,
+}
+
+impl AssertionKind {
+    /// Whether this is an actual type assertion, ie one that can change the
+    /// type of the expression to something other than a narrowing of it.
+    pub fn is_type_assertion(self) -> bool {
+        matches!(self, Self::As | Self::AngleBracket)
+    }
+}
+
+/// The pieces of an assertion-like wrapper around an expression.
+#[derive(Copy, Clone, Debug)]
+pub struct AssertionParts<'a> {
+    pub kind: AssertionKind,
+    pub expression: Node<'a>,
+    /// `None` for `as const`/`<const>` and for `x!`.
+    pub type_: Option<Node<'a>>,
+}
+
+pub fn get_assertion_kind(node: Node) -> Option<AssertionKind> {
+    match node.kind() {
+        AsExpression => Some(AssertionKind::As),
+        TypeAssertion => Some(AssertionKind::AngleBracket),
+        SatisfiesExpression => Some(AssertionKind::Satisfies),
+        NonNullExpression => Some(AssertionKind::NonNull),
+        _ => None,
+    }
+}
+
+/// The expression wrapped by `x as T`, `<T>x`, `x satisfies T` or `x!`.
+pub fn get_asserted_expression(node: Node) -> Option<Node> {
+    Some(match get_assertion_kind(node)? {
+        AssertionKind::AngleBracket => node
+            .non_comment_named_children(SupportedLanguage::Javascript)
+            .last()
+            .unwrap(),
+        _ => node.first_non_comment_named_child(SupportedLanguage::Javascript),
+    })
+}
+
+/// Takes apart `x as T`, `<T>x`, `x satisfies T` or `x!`.
+pub fn unwrap_assertion<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<AssertionParts<'a>> {
+    let kind = get_assertion_kind(node)?;
+    let expression = get_asserted_expression(node).unwrap();
+    let type_ = match kind {
+        AssertionKind::As | AssertionKind::Satisfies => {
+            let type_ = node
+                .non_comment_named_children(SupportedLanguage::Javascript)
+                .last()
+                .unwrap();
+            (type_ != expression).then_some(type_)
+        }
+        AssertionKind::AngleBracket => {
+            let type_arguments = node.first_non_comment_named_child(SupportedLanguage::Javascript);
+            (type_arguments.text(context) != "<const>").then(|| {
+                type_arguments.first_non_comment_named_child(SupportedLanguage::Javascript)
+            })
+        }
+        AssertionKind::NonNull => None,
+    };
+    Some(AssertionParts {
+        kind,
+        expression,
+        type_,
+    })
+}
+
+/// Takes apart `x as T` or `<T>x`.
+pub fn unwrap_type_assertion<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<AssertionParts<'a>> {
+    unwrap_assertion(node, context).filter(|assertion| assertion.kind.is_type_assertion())
+}
+
+/// Looks through parentheses and any assertion-like wrappers, none of which
+/// change the runtime value.
+pub fn skip_parentheses_and_assertions(mut node: Node) -> Node {
+    loop {
+        node = match node.kind() {
+            ParenthesizedExpression => {
+                node.first_non_comment_named_child(SupportedLanguage::Javascript)
+            }
+            _ => match get_asserted_expression(node) {
+                Some(expression) => expression,
+                None => return node,
+            },
+        };
+    }
+}
+
+/// Whether evaluating the expression can't do anything besides produce a
+/// value (ignoring getters and coercions): identifiers, literals, member
+/// chains and operators combining those.
+#[cfg(feature = "type-aware")]
+pub fn is_side_effect_free(node: Node) -> bool {
+    match node.kind() {
+        Identifier | This => true,
+        TemplateString => !node
+            .non_comment_named_children(SupportedLanguage::Javascript)
+            .any(|child| child.kind() == TemplateSubstitution),
+        kind if is_literal_kind(kind) => true,
+        ParenthesizedExpression => is_side_effect_free(skip_parenthesized_expressions(node)),
+        MemberExpression => is_side_effect_free(node.field("object")),
+        SubscriptExpression => {
+            is_side_effect_free(node.field("object")) && is_side_effect_free(node.field("index"))
+        }
+        UnaryExpression => {
+            node.field("operator").kind() != "delete" && is_side_effect_free(node.field("argument"))
+        }
+        BinaryExpression => {
+            is_side_effect_free(node.field("left")) && is_side_effect_free(node.field("right"))
+        }
+        ConditionalExpression => {
+            is_side_effect_free(node.field("condition"))
+                && is_side_effect_free(node.field("consequence"))
+                && is_side_effect_free(node.field("alternative"))
+        }
+        _ => false,
+    }
+}
+
+/// Literals whose value is fixed, ie that could be exposed as either a
+/// `readonly` field or a getter (or narrowed with `as const`): plain
+/// literals (including bigints), negated numeric literals, simple template
+/// literals and `Symbol()` calls.
+#[cfg(feature = "stylistic")]
+pub fn is_supported_literal(node: Node, context: &QueryMatchContext) -> bool {
+    match node.kind() {
+        kind if is_literal_kind(kind) => true,
+        CallExpression if is_tagged_template_expression(node) => {
+            is_simple_template_literal(node.field("arguments"))
+        }
+        CallExpression => {
+            let function = node.field("function");
+            function.kind() == Identifier
+                && function.text(context) == "Symbol"
+                && node.field("arguments").kind() == Arguments
+                && node
+                    .field("arguments")
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                    .all(|argument| argument.kind() == StringKind)
+        }
+        TemplateString => is_simple_template_literal(node),
+        UnaryExpression => {
+            node.field("operator").kind() == "-" && node.field("argument").kind() == Number
+        }
+        _ => false,
+    }
+}
+
+fn get_enum_member_name<'a>(
+    member: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Cow<'a, str>> {
+    let name = match member.kind() {
+        EnumAssignment => member.field("name"),
+        _ => member,
+    };
+    let name = match name.kind() {
+        ComputedPropertyName => name.first_non_comment_named_child(SupportedLanguage::Javascript),
+        _ => name,
+    };
+    match name.kind() {
+        PropertyIdentifier | Identifier => Some(name.text(context)),
+        _ => get_static_string_value(name, context),
+    }
+}
+
+/// The member of the enum `declaration` named `name` (either a bare name or
+/// an `enum_assignment`).
+pub fn find_enum_member<'a>(
+    declaration: Node<'a>,
+    name: &str,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    assert_kind!(declaration, EnumDeclaration);
+
+    declaration
+        .field("body")
+        .non_comment_named_children(SupportedLanguage::Javascript)
+        .find(|&member| get_enum_member_name(member, context).as_deref() == Some(name))
+}
+
+/// Whether the argument at `index` of a call gets a type from the callee's
+/// signature. Callees that aren't functions declared in this file are
+/// assumed to be typed.
+fn is_argument_for_annotated_parameter<'a>(
+    call: Node<'a>,
+    index: usize,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    let callee = call.field(if call.kind() == NewExpression {
+        "constructor"
+    } else {
+        "function"
+    });
+    if callee.kind() != Identifier {
+        return true;
+    }
+    let Some(function) = resolve_function(callee, context) else {
+        return true;
+    };
+    // eg `const foo: Foo = (callback) => {}`
+    if function.parent().unwrap().kind() == VariableDeclarator
+        && function
+            .parent()
+            .unwrap()
+            .child_by_field_name("type")
+            .is_some()
+    {
+        return true;
+    }
+    let Some(parameters) = function.child_by_field_name("parameters") else {
+        // a lone arrow function parameter (which can't be annotated)
+        return false;
+    };
+    let parameters = parameters
+        .non_comment_named_children(SupportedLanguage::Javascript)
+        .filter(|&parameter| {
+            matches!(parameter.kind(), RequiredParameter | OptionalParameter)
+                && !is_this_parameter(parameter)
+        })
+        .collect::<Vec<_>>();
+    let parameter = parameters.get(index).copied().or_else(|| {
+        // a rest parameter covers all of the remaining arguments
+        parameters
+            .last()
+            .copied()
+            .filter(|parameter| parameter.field("pattern").kind() == RestPattern)
+    });
+    parameter.is_some_and(|parameter| parameter.child_by_field_name("type").is_some())
+}
+
+fn is_contextually_typed_argument<'a>(
+    argument: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    let call = argument.parent().unwrap().parent().unwrap();
+    let mut index = 0;
+    for call_argument in get_call_arguments(call) {
+        match call_argument {
+            CallArgument::Expression(expression) if expression == argument => {
+                return is_argument_for_annotated_parameter(call, index, context);
+            }
+            // (which parameter later arguments correspond to isn't known)
+            CallArgument::Spread { .. } => return true,
+            CallArgument::Expression(_) => index += 1,
+        }
+    }
+    unreachable!()
+}
+
+/// Whether the function expression (or object literal method) gets its type
+/// from where it appears, so that its parameters (and return type) can be
+/// left unannotated, eg when it's assigned to an annotated variable, passed
+/// for an annotated parameter, returned from a function with a return type
+/// or is a property of an object literal in such a position.
+pub fn is_contextually_typed_position<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    let parent = node.parent().unwrap();
+    match parent.kind() {
+        AsExpression | SatisfiesExpression | TypeAssertion | JsxExpression => true,
+        ParenthesizedExpression => is_contextually_typed_position(parent, context),
+        VariableDeclarator | PublicFieldDefinition | RequiredParameter | OptionalParameter => {
+            parent.child_by_field_name("value") == Some(node)
+                && parent.child_by_field_name("type").is_some()
+        }
+        Arguments => is_contextually_typed_argument(node, context),
+        Pair => {
+            parent.field("value") == node
+                && is_contextually_typed_position(parent.parent().unwrap(), context)
+        }
+        // an object literal method
+        Object => is_contextually_typed_position(parent, context),
+        ReturnStatement => nearest_enclosing_function(parent).is_some_and(|function| {
+            function.child_by_field_name("return_type").is_some()
+                || is_contextually_typed_position(function, context)
+        }),
+        // the body of an arrow function without braces
+        ArrowFunction => {
+            parent.child_by_field_name("return_type").is_some()
+                || is_contextually_typed_position(parent, context)
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,19 @@
+//! Writes markdown documentation for every rule, eg
+//! `cargo run --features docs --bin generate-docs -- docs/rules`.
+
+use std::{env, path::PathBuf, process};
+
+use tree_sitter_lint_plugin_typescript_eslint::generate_docs;
+
+fn main() {
+    let out_dir = env::args()
+        .nth(1)
+        .map_or_else(|| PathBuf::from("docs/rules"), PathBuf::from);
+    match generate_docs(&out_dir) {
+        Ok(paths) => println!("wrote {} files to {}", paths.len(), out_dir.display()),
+        Err(error) => {
+            eprintln!("failed to generate docs: {error}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer};
+
+#[cfg(feature = "type-aware")]
+use crate::rules::NoMisusedPromisesOptions;
+use crate::{
+    rule_names::normalize_rule_name,
+    rules::{BanTsCommentOptions, BanTypesOptions, MemberOrderingOptions},
+};
+
+/// A rule option value that's accepted but probably doesn't do what was
+/// intended (eg it makes some other option have no effect).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfigWarning {
+    pub rule_name: &'static str,
+    /// The option the warning is about.
+    pub option: &'static str,
+    pub message: String,
+}
+
+impl ConfigWarning {
+    pub(crate) fn new(
+        rule_name: &'static str,
+        option: &'static str,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            rule_name,
+            option,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.rule_name, self.message)
+    }
+}
+
+/// Implemented by the `Options` of rules where some combinations of values
+/// are valid but ineffective or contradictory.
+pub(crate) trait ValidateOptions {
+    fn validate(&self) -> Vec<ConfigWarning>;
+}
+
+fn validate<'de, TOptions: Deserialize<'de> + ValidateOptions, D: Deserializer<'de>>(
+    options: D,
+) -> Result<Vec<ConfigWarning>, D::Error> {
+    Ok(TOptions::deserialize(options)?.validate())
+}
+
+/// Checks a rule's (not yet instantiated) options for misconfigurations, so
+/// that they can be surfaced when the config is loaded rather than just
+/// making the rule behave oddly. Options that don't deserialize are an
+/// error; rules with nothing to check (or that aren't rules of this plugin)
+/// never have warnings.
+pub fn validate_rule_options<'de, D: Deserializer<'de>>(
+    rule_name: &str,
+    options: D,
+) -> Result<Vec<ConfigWarning>, D::Error> {
+    match normalize_rule_name(rule_name) {
+        "ban-ts-comment" => validate::<BanTsCommentOptions, _>(options),
+        "ban-types" => validate::<BanTypesOptions, _>(options),
+        "member-ordering" => validate::<MemberOrderingOptions, _>(options),
+        #[cfg(feature = "type-aware")]
+        "no-misused-promises" => validate::<NoMisusedPromisesOptions, _>(options),
+        _ => Ok(Default::default()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_ban_ts_comment_minimum_description_length() {
+        assert_eq!(
+            validate_rule_options("ban-ts-comment", json!({})).unwrap(),
+            vec![]
+        );
+        assert_eq!(
+            validate_rule_options("ban-ts-comment", json!({ "minimum_description_length": 5 }))
+                .unwrap(),
+            vec![]
+        );
+        let warnings = validate_rule_options(
+            "@typescript-eslint/ban-ts-comment",
+            json!({ "minimum_description_length": 0 }),
+        )
+        .unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].rule_name, "ban-ts-comment");
+        assert_eq!(warnings[0].option, "minimum_description_length");
+
+        let warnings = validate_rule_options(
+            "ban-ts-comment",
+            json!({ "ts-expect-error": true, "minimum_description_length": 10 }),
+        )
+        .unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].option, "minimum_description_length");
+    }
+
+    #[test]
+    fn test_ban_types_nothing_banned() {
+        let warnings =
+            validate_rule_options("ban-types", json!({ "extend_defaults": false })).unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].option, "extend_defaults");
+        assert_eq!(
+            validate_rule_options(
+                "ban-types",
+                json!({ "extend_defaults": false, "types": { "Foo": false } })
+            )
+            .unwrap()
+            .len(),
+            1
+        );
+        assert_eq!(
+            validate_rule_options(
+                "ban-types",
+                json!({ "extend_defaults": false, "types": { "Foo": "Use Bar instead" } })
+            )
+            .unwrap(),
+            vec![]
+        );
+        assert_eq!(
+            validate_rule_options(
+                "ban-types",
+                json!({ "extend_defaults": false, "type_patterns": { "NS.*": null } })
+            )
+            .unwrap(),
+            vec![]
+        );
+        assert_eq!(
+            validate_rule_options("ban-types", json!({})).unwrap(),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_member_ordering_repeated_member_type() {
+        assert_eq!(
+            validate_rule_options("member-ordering", json!({})).unwrap(),
+            vec![]
+        );
+        assert_eq!(
+            validate_rule_options(
+                "member-ordering",
+                json!({ "default": ["field", ["constructor", "method"]] })
+            )
+            .unwrap(),
+            vec![]
+        );
+        let warnings = validate_rule_options(
+            "member-ordering",
+            json!({ "default": "never", "classes": ["method", ["field", "method"]] }),
+        )
+        .unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].rule_name, "member-ordering");
+        assert_eq!(warnings[0].option, "classes");
+        let warnings = validate_rule_options(
+            "member-ordering",
+            json!({
+                "interfaces": {
+                    "member_types": ["signature", "field", "signature"],
+                    "order": "alphabetically"
+                }
+            }),
+        )
+        .unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].option, "interfaces");
+    }
+
+    #[cfg(feature = "type-aware")]
+    #[test]
+    fn test_no_misused_promises_attributes() {
+        assert_eq!(
+            validate_rule_options(
+                "no-misused-promises",
+                json!({ "checks_void_return": { "attributes": false } })
+            )
+            .unwrap(),
+            vec![]
+        );
+        let warnings = validate_rule_options(
+            "no-misused-promises",
+            json!({ "checks_void_return": { "attributes": true, "returns": false } }),
+        )
+        .unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].option, "checks_void_return");
+    }
+
+    #[test]
+    fn test_invalid_options() {
+        assert!(validate_rule_options("ban-types", json!({ "extend_defaults": "nope" })).is_err());
+    }
+
+    #[test]
+    fn test_no_warnings_for_other_rules() {
+        assert_eq!(
+            validate_rule_options("array-type", json!({ "default": "generic" })).unwrap(),
+            vec![]
+        );
+        assert_eq!(
+            validate_rule_options("nonexistent", json!(null)).unwrap(),
+            vec![]
+        );
+    }
+}
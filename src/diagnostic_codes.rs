@@ -0,0 +1,510 @@
+use crate::rule_names::normalize_rule_name;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DiagnosticCategory {
+    Correctness,
+    Style,
+    Suspicious,
+}
+
+impl DiagnosticCategory {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Correctness => "correctness",
+            Self::Style => "style",
+            Self::Suspicious => "suspicious",
+        }
+    }
+}
+
+/// Every rule's category, along with its message ids in the order they were
+/// assigned codes. Codes have to stay stable (they get baselined by CI
+/// tooling), so new message ids only ever get appended.
+static RULE_DIAGNOSTICS: &[(&str, DiagnosticCategory, &[&str])] = &[
+    (
+        "adjacent-overload-signatures",
+        DiagnosticCategory::Style,
+        &["adjacent_signature"],
+    ),
+    (
+        "array-type",
+        DiagnosticCategory::Style,
+        &[
+            "error_string_generic",
+            "error_string_array",
+            "error_string_array_simple",
+            "error_string_generic_simple",
+        ],
+    ),
+    (
+        "await-thenable",
+        DiagnosticCategory::Correctness,
+        &["non_thenable_await"],
+    ),
+    (
+        "ban-ts-comment",
+        DiagnosticCategory::Suspicious,
+        &[
+            "ts_directive_comment",
+            "ts_ignore_instead_of_expect_error",
+            "ts_directive_comment_requires_description",
+            "ts_directive_comment_description_not_match_pattern",
+            "replace_ts_ignore_with_ts_expect_error",
+        ],
+    ),
+    (
+        "ban-tslint-comment",
+        DiagnosticCategory::Style,
+        &["comment_detected"],
+    ),
+    (
+        "ban-types",
+        DiagnosticCategory::Suspicious,
+        &["banned_type_message", "banned_type_replacement"],
+    ),
+    (
+        "class-literal-property-style",
+        DiagnosticCategory::Style,
+        &[
+            "prefer_field_style",
+            "prefer_field_style_suggestion",
+            "prefer_getter_style",
+            "prefer_getter_style_suggestion",
+        ],
+    ),
+    (
+        "class-methods-use-this",
+        DiagnosticCategory::Suspicious,
+        &["missing_this"],
+    ),
+    (
+        "consistent-generic-constructors",
+        DiagnosticCategory::Style,
+        &["prefer_type_annotation", "prefer_constructor"],
+    ),
+    (
+        "consistent-indexed-object-style",
+        DiagnosticCategory::Style,
+        &["prefer_record", "prefer_index_signature"],
+    ),
+    (
+        "consistent-type-definitions",
+        DiagnosticCategory::Style,
+        &["interface_over_type", "type_over_interface"],
+    ),
+    (
+        "default-param-last",
+        DiagnosticCategory::Suspicious,
+        &["should_be_last"],
+    ),
+    (
+        "explicit-function-return-type",
+        DiagnosticCategory::Style,
+        &["missing_return_type"],
+    ),
+    (
+        "explicit-module-boundary-types",
+        DiagnosticCategory::Style,
+        &[
+            "missing_return_type",
+            "missing_arg_type",
+            "missing_arg_type_unnamed",
+            "any_typed_arg",
+            "any_typed_arg_unnamed",
+        ],
+    ),
+    (
+        "member-ordering",
+        DiagnosticCategory::Style,
+        &[
+            "incorrect_group_order",
+            "incorrect_order",
+            "incorrect_required_members_order",
+        ],
+    ),
+    (
+        "method-signature-style",
+        DiagnosticCategory::Style,
+        &["error_method", "error_property"],
+    ),
+    (
+        "naming-convention",
+        DiagnosticCategory::Style,
+        &[
+            "unexpected_underscore",
+            "missing_underscore",
+            "missing_affix",
+            "satisfy_custom",
+            "does_not_match_format",
+            "does_not_match_format_trimmed",
+        ],
+    ),
+    (
+        "no-array-constructor",
+        DiagnosticCategory::Style,
+        &["use_literal"],
+    ),
+    (
+        "no-array-delete",
+        DiagnosticCategory::Correctness,
+        &["no_array_delete", "use_splice"],
+    ),
+    (
+        "no-array-method-misuse",
+        DiagnosticCategory::Style,
+        &[
+            "unnecessary_flat_depth",
+            "empty_concat",
+            "mutated_slice_copy",
+        ],
+    ),
+    (
+        "no-async-promise-executor",
+        DiagnosticCategory::Correctness,
+        &["async_"],
+    ),
+    (
+        "no-confusing-void-expression",
+        DiagnosticCategory::Suspicious,
+        &[
+            "invalid_void_expr",
+            "invalid_void_expr_wrap_void",
+            "invalid_void_expr_arrow",
+            "invalid_void_expr_arrow_wrap_void",
+            "invalid_void_expr_return",
+            "invalid_void_expr_return_last",
+            "invalid_void_expr_return_wrap_void",
+        ],
+    ),
+    (
+        "no-constant-condition",
+        DiagnosticCategory::Correctness,
+        &["unexpected"],
+    ),
+    (
+        "no-dynamic-delete",
+        DiagnosticCategory::Suspicious,
+        &["dynamic_delete"],
+    ),
+    (
+        "no-empty-function",
+        DiagnosticCategory::Style,
+        &["unexpected"],
+    ),
+    (
+        "no-empty-interface",
+        DiagnosticCategory::Style,
+        &["no_empty", "no_empty_with_super"],
+    ),
+    ("no-extra-semi", DiagnosticCategory::Style, &["unexpected"]),
+    (
+        "no-extraneous-class",
+        DiagnosticCategory::Suspicious,
+        &["empty", "only_constructor", "only_static"],
+    ),
+    (
+        "no-inferrable-types",
+        DiagnosticCategory::Style,
+        &["no_inferrable_type"],
+    ),
+    (
+        "no-invalid-this",
+        DiagnosticCategory::Correctness,
+        &["unexpected_this"],
+    ),
+    (
+        "no-loss-of-precision",
+        DiagnosticCategory::Correctness,
+        &["no_loss_of_precision"],
+    ),
+    (
+        "no-magic-numbers",
+        DiagnosticCategory::Style,
+        &["use_const", "no_magic"],
+    ),
+    (
+        "no-meaningless-void-operator",
+        DiagnosticCategory::Suspicious,
+        &["meaningless_void_operator", "remove_void"],
+    ),
+    (
+        "no-misused-new",
+        DiagnosticCategory::Correctness,
+        &["error_message_interface", "error_message_class"],
+    ),
+    (
+        "no-misused-promises",
+        DiagnosticCategory::Correctness,
+        &[
+            "void_return_argument",
+            "void_return_variable",
+            "void_return_property",
+            "void_return_return_value",
+            "void_return_attribute",
+        ],
+    ),
+    (
+        "no-namespace",
+        DiagnosticCategory::Style,
+        &["module_syntax_is_preferred"],
+    ),
+    (
+        "no-non-null-asserted-nullish-coalescing",
+        DiagnosticCategory::Suspicious,
+        &[
+            "no_non_null_asserted_nullish_coalescing",
+            "suggest_removing_non_null",
+        ],
+    ),
+    (
+        "no-non-null-asserted-optional-chain",
+        DiagnosticCategory::Suspicious,
+        &["no_non_null_optional_chain", "suggest_removing_non_null"],
+    ),
+    (
+        "no-non-null-assertion",
+        DiagnosticCategory::Suspicious,
+        &["no_non_null", "suggest_optional_chain"],
+    ),
+    (
+        "no-require-imports",
+        DiagnosticCategory::Style,
+        &["no_require_imports"],
+    ),
+    (
+        "no-restricted-ts-syntax",
+        DiagnosticCategory::Style,
+        &["restricted_syntax", "restricted_syntax_custom"],
+    ),
+    (
+        "no-shadow",
+        DiagnosticCategory::Suspicious,
+        &["no_shadow", "no_shadow_global"],
+    ),
+    (
+        "no-this-alias",
+        DiagnosticCategory::Style,
+        &["this_assignment", "this_destructure"],
+    ),
+    (
+        "no-type-alias",
+        DiagnosticCategory::Style,
+        &["no_composition_alias", "no_type_alias"],
+    ),
+    (
+        "no-unnecessary-boolean-literal-compare",
+        DiagnosticCategory::Style,
+        &[
+            "comparing_nullable_to_false",
+            "comparing_nullable_to_true_direct",
+            "comparing_nullable_to_true_negated",
+            "direct",
+            "negated",
+        ],
+    ),
+    (
+        "no-unnecessary-type-assertion",
+        DiagnosticCategory::Style,
+        &["contextually_unnecessary", "unnecessary_assertion"],
+    ),
+    (
+        "no-unnecessary-type-parameters",
+        DiagnosticCategory::Suspicious,
+        &["sole"],
+    ),
+    (
+        "no-unsafe-argument",
+        DiagnosticCategory::Suspicious,
+        &["unsafe_argument", "unsafe_array_spread", "unsafe_spread"],
+    ),
+    (
+        "no-unsafe-member-access",
+        DiagnosticCategory::Suspicious,
+        &["unsafe_member_expression", "unsafe_computed_member_access"],
+    ),
+    (
+        "no-unused-private-class-members",
+        DiagnosticCategory::Suspicious,
+        &["unused_private_class_member"],
+    ),
+    (
+        "no-useless-constructor",
+        DiagnosticCategory::Style,
+        &["no_useless_constructor"],
+    ),
+    (
+        "no-var-requires",
+        DiagnosticCategory::Style,
+        &["no_var_reqs"],
+    ),
+    (
+        "parameter-properties",
+        DiagnosticCategory::Style,
+        &["prefer_class_property", "prefer_parameter_property"],
+    ),
+    (
+        "prefer-array-index-at",
+        DiagnosticCategory::Style,
+        &["prefer_at"],
+    ),
+    ("prefer-const", DiagnosticCategory::Style, &["use_const"]),
+    (
+        "prefer-function-type",
+        DiagnosticCategory::Style,
+        &[
+            "function_type_over_callable_type",
+            "unexpected_this_on_function_only_interface",
+        ],
+    ),
+    (
+        "prefer-literal-enum-member",
+        DiagnosticCategory::Suspicious,
+        &["define_initializer"],
+    ),
+    (
+        "prefer-namespace-keyword",
+        DiagnosticCategory::Style,
+        &["use_namespace"],
+    ),
+    (
+        "prefer-nullish-coalescing",
+        DiagnosticCategory::Style,
+        &[
+            "prefer_nullish_over_or",
+            "prefer_nullish_over_ternary",
+            "suggest_nullish",
+        ],
+    ),
+    (
+        "prefer-string-starts-ends-with",
+        DiagnosticCategory::Style,
+        &["prefer_starts_with", "prefer_ends_with"],
+    ),
+    (
+        "restrict-plus-operands",
+        DiagnosticCategory::Correctness,
+        &["bigint_and_number", "invalid", "mismatched"],
+    ),
+    (
+        "restrict-template-expressions",
+        DiagnosticCategory::Correctness,
+        &["invalid_type"],
+    ),
+    (
+        "return-await",
+        DiagnosticCategory::Suspicious,
+        &[
+            "non_promise_await",
+            "disallowed_promise_await",
+            "required_promise_await",
+        ],
+    ),
+    (
+        "typedef",
+        DiagnosticCategory::Style,
+        &["expected_typedef", "expected_typedef_named"],
+    ),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiagnosticCode {
+    /// eg `TSL-ARRAY-TYPE-001`
+    pub code: String,
+    pub category: DiagnosticCategory,
+}
+
+fn get_rule_diagnostics(
+    rule_name: &str,
+) -> Option<&'static (&'static str, DiagnosticCategory, &'static [&'static str])> {
+    let rule_name = normalize_rule_name(rule_name);
+    RULE_DIAGNOSTICS
+        .iter()
+        .find(|(name, _, _)| *name == rule_name)
+}
+
+fn format_code(rule_name: &str, index: usize) -> String {
+    format!("TSL-{}-{:03}", rule_name.to_uppercase(), index + 1)
+}
+
+pub fn get_rule_category(rule_name: &str) -> Option<DiagnosticCategory> {
+    get_rule_diagnostics(rule_name).map(|&(_, category, _)| category)
+}
+
+pub fn get_diagnostic_code(rule_name: &str, message_id: &str) -> Option<DiagnosticCode> {
+    let &(rule_name, category, message_ids) = get_rule_diagnostics(rule_name)?;
+    let index = message_ids.iter().position(|&id| id == message_id)?;
+    Some(DiagnosticCode {
+        code: format_code(rule_name, index),
+        category,
+    })
+}
+
+/// `(message_id, code)` for each of the rule's messages.
+pub fn get_diagnostic_codes(rule_name: &str) -> Option<Vec<(&'static str, String)>> {
+    let &(rule_name, _, message_ids) = get_rule_diagnostics(rule_name)?;
+    Some(
+        message_ids
+            .iter()
+            .enumerate()
+            .map(|(index, &message_id)| (message_id, format_code(rule_name, index)))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::{instantiate, rule_groups::is_rule_available};
+
+    #[test]
+    fn test_get_diagnostic_code() {
+        assert_eq!(
+            get_diagnostic_code("array-type", "error_string_array"),
+            Some(DiagnosticCode {
+                code: "TSL-ARRAY-TYPE-002".to_owned(),
+                category: DiagnosticCategory::Style,
+            })
+        );
+        assert_eq!(
+            get_diagnostic_code("@typescript-eslint/ban-types", "banned_type_message"),
+            Some(DiagnosticCode {
+                code: "TSL-BAN-TYPES-001".to_owned(),
+                category: DiagnosticCategory::Suspicious,
+            })
+        );
+        assert_eq!(get_diagnostic_code("array-type", "nonexistent"), None);
+        assert_eq!(
+            get_diagnostic_code("nonexistent", "error_string_array"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_every_message_has_a_code() {
+        let rules = instantiate().rules;
+        assert_eq!(
+            rules.len(),
+            RULE_DIAGNOSTICS
+                .iter()
+                .filter(|(name, _, _)| is_rule_available(name))
+                .count()
+        );
+        for rule in rules {
+            let meta = rule.meta();
+            let message_ids = get_diagnostic_codes(&meta.name)
+                .unwrap_or_else(|| panic!("no diagnostic codes for {}", meta.name))
+                .into_iter()
+                .map(|(message_id, _)| message_id.to_owned())
+                .collect::<HashSet<_>>();
+            let expected = meta
+                .messages
+                .iter()
+                .flatten()
+                .map(|(message_id, _)| message_id.clone())
+                .collect::<HashSet<_>>();
+            assert_eq!(message_ids, expected, "{}", meta.name);
+        }
+    }
+}
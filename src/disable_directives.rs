@@ -0,0 +1,362 @@
+use std::collections::HashSet;
+
+use squalid::regex;
+use tree_sitter_lint::{
+    tree_sitter::{Node, Point},
+    FileRunContext, FromFileRunContext, QueryMatchContext,
+};
+use tree_sitter_lint_plugin_eslint_builtin::{ast_helpers::get_comment_contents, kind::Comment};
+
+use crate::rule_names::{get_rule_name_for_upstream_name, normalize_rule_name};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DisableDirectiveKind {
+    Disable,
+    Enable,
+    DisableLine,
+    DisableNextLine,
+}
+
+/// An `eslint-disable`-style comment, with any upstream rule names (eg
+/// `@typescript-eslint/array-type`) mapped onto this plugin's rule names.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisableDirective {
+    pub kind: DisableDirectiveKind,
+    /// `None` if the directive applies to all rules.
+    pub rule_names: Option<Vec<String>>,
+}
+
+/// Rule names that aren't the upstream name of a rule this plugin provides (eg
+/// most core ESLint rules) get dropped, and a
+/// directive that listed only such rules doesn't apply to this plugin at all.
+pub fn parse_disable_directive(comment_contents: &str) -> Option<DisableDirective> {
+    let captures = regex!(
+        r#"^\s*eslint-(disable-next-line|disable-line|disable|enable)(?:\s+([^\n]*?))?\s*$"#
+    )
+    .captures(comment_contents)?;
+    let kind = match &captures[1] {
+        "disable" => DisableDirectiveKind::Disable,
+        "enable" => DisableDirectiveKind::Enable,
+        "disable-line" => DisableDirectiveKind::DisableLine,
+        "disable-next-line" => DisableDirectiveKind::DisableNextLine,
+        _ => unreachable!(),
+    };
+    // anything after a standalone `--` is a description
+    let rule_list = captures.get(2).map_or("", |rule_list| {
+        regex!(r#"(?:^|\s)--(?:\s|$)"#)
+            .split(rule_list.as_str())
+            .next()
+            .unwrap()
+    });
+    let rule_names = rule_list
+        .split(',')
+        .map(str::trim)
+        .filter(|rule_name| !rule_name.is_empty())
+        .collect::<Vec<_>>();
+    if rule_names.is_empty() {
+        return Some(DisableDirective {
+            kind,
+            rule_names: None,
+        });
+    }
+
+    let rule_names = rule_names
+        .into_iter()
+        .filter_map(get_rule_name_for_upstream_name)
+        .map(ToOwned::to_owned)
+        .collect::<Vec<_>>();
+    if rule_names.is_empty() {
+        return None;
+    }
+    Some(DisableDirective {
+        kind,
+        rule_names: Some(rule_names),
+    })
+}
+
+pub fn get_disable_directive<'a>(
+    comment: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<DisableDirective> {
+    parse_disable_directive(&get_comment_contents(comment, context))
+}
+
+/// The disable directives found in a file, in source order, for answering
+/// whether a given rule's violation at a given position is suppressed.
+///
+/// This is one of the [`ProvidedTypes`](crate::ProvidedTypes), so a runner
+/// that doesn't handle `eslint-disable` comments itself can retrieve it for
+/// each file and drop the violations that [`is_disabled`](Self::is_disabled)
+/// says are suppressed.
+#[derive(Debug, Default)]
+pub struct DisableDirectives {
+    directives: Vec<(Point, Point, DisableDirective)>,
+}
+
+impl DisableDirectives {
+    pub fn from_parsed(
+        directives: impl IntoIterator<Item = (Point, Point, DisableDirective)>,
+    ) -> Self {
+        let mut directives = directives.into_iter().collect::<Vec<_>>();
+        directives.sort_by_key(|(start, _, _)| *start);
+        Self { directives }
+    }
+
+    pub fn is_disabled(&self, rule_name: &str, position: Point) -> bool {
+        let rule_name = normalize_rule_name(rule_name);
+        let applies_to_rule = |directive: &DisableDirective| {
+            directive.rule_names.as_ref().map_or(true, |rule_names| {
+                rule_names.iter().any(|name| name == rule_name)
+            })
+        };
+
+        let mut is_disabled = false;
+        for (start, end, directive) in &self.directives {
+            if !applies_to_rule(directive) {
+                continue;
+            }
+            match directive.kind {
+                DisableDirectiveKind::DisableLine if start.row == position.row => return true,
+                DisableDirectiveKind::DisableNextLine if end.row + 1 == position.row => {
+                    return true
+                }
+                DisableDirectiveKind::Disable if *start <= position => is_disabled = true,
+                DisableDirectiveKind::Enable if *start <= position => is_disabled = false,
+                _ => (),
+            }
+        }
+        is_disabled
+    }
+
+    pub fn disabled_rule_names_at(&self, position: Point) -> HashSet<String> {
+        self.directives
+            .iter()
+            .filter_map(|(_, _, directive)| directive.rule_names.as_ref())
+            .flatten()
+            .filter(|rule_name| self.is_disabled(rule_name, position))
+            .cloned()
+            .collect()
+    }
+}
+
+impl<'a> FromFileRunContext<'a> for DisableDirectives {
+    fn from_file_run_context(file_run_context: FileRunContext<'a, '_>) -> Self {
+        let mut directives: Vec<(Point, Point, DisableDirective)> = Default::default();
+
+        let mut cursor = file_run_context.tree.root_node().walk();
+        'outer: loop {
+            let node = cursor.node();
+            if node.kind() == Comment {
+                if let Some(directive) =
+                    parse_disable_directive(&get_comment_contents(node, &file_run_context))
+                {
+                    directives.push((node.start_position(), node.end_position(), directive));
+                }
+            }
+            if cursor.goto_first_child() {
+                continue;
+            }
+            loop {
+                if cursor.goto_next_sibling() {
+                    continue 'outer;
+                }
+                if !cursor.goto_parent() {
+                    break 'outer;
+                }
+            }
+        }
+
+        Self::from_parsed(directives)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use tree_sitter_lint::{rule, rule_tests, violation, Rule, RuleTester};
+
+    use super::*;
+    use crate::get_instance_provider_factory;
+
+    fn directive(kind: DisableDirectiveKind, rule_names: Option<&[&str]>) -> DisableDirective {
+        DisableDirective {
+            kind,
+            rule_names: rule_names
+                .map(|rule_names| rule_names.iter().map(|&name| name.to_owned()).collect()),
+        }
+    }
+
+    #[test]
+    fn test_parse_disable_directive() {
+        for (comment_contents, expected) in [
+            (
+                " eslint-disable ",
+                Some(directive(DisableDirectiveKind::Disable, None)),
+            ),
+            (
+                " eslint-enable",
+                Some(directive(DisableDirectiveKind::Enable, None)),
+            ),
+            (
+                " eslint-disable-next-line @typescript-eslint/array-type",
+                Some(directive(
+                    DisableDirectiveKind::DisableNextLine,
+                    Some(&["array-type"]),
+                )),
+            ),
+            (
+                " eslint-disable-line @typescript-eslint/array-type, no-console, @typescript-eslint/ban-types -- legacy code",
+                Some(directive(
+                    DisableDirectiveKind::DisableLine,
+                    Some(&["array-type", "ban-types"]),
+                )),
+            ),
+            (" eslint-disable-next-line no-console", None),
+            (" eslint-disable-next-line @typescript-eslint/nonexistent", None),
+            (" eslint-disable-next-line array-type", None),
+            // core ESLint rules that this plugin provides go by their
+            // unprefixed names
+            (
+                " eslint-disable-next-line prefer-const",
+                Some(directive(
+                    DisableDirectiveKind::DisableNextLine,
+                    Some(&["prefer-const"]),
+                )),
+            ),
+            (" eslint-disable-next-line @typescript-eslint/prefer-const", None),
+            (" eslint-disabled", None),
+            (" tslint:disable", None),
+        ] {
+            assert_eq!(parse_disable_directive(comment_contents), expected);
+        }
+    }
+
+    #[test]
+    fn test_is_disabled() {
+        let point = |row, column| Point { row, column };
+        let directives = DisableDirectives::from_parsed([
+            (
+                point(0, 0),
+                point(0, 40),
+                directive(DisableDirectiveKind::DisableNextLine, Some(&["array-type"])),
+            ),
+            (
+                point(3, 20),
+                point(3, 50),
+                directive(DisableDirectiveKind::DisableLine, None),
+            ),
+            (
+                point(5, 0),
+                point(5, 40),
+                directive(DisableDirectiveKind::Disable, Some(&["ban-types"])),
+            ),
+            (
+                point(8, 0),
+                point(8, 40),
+                directive(DisableDirectiveKind::Enable, Some(&["ban-types"])),
+            ),
+        ]);
+
+        assert!(directives.is_disabled("array-type", point(1, 7)));
+        assert!(directives.is_disabled("@typescript-eslint/array-type", point(1, 7)));
+        assert!(!directives.is_disabled("ban-types", point(1, 7)));
+        assert!(!directives.is_disabled("array-type", point(2, 7)));
+        assert!(directives.is_disabled("array-type", point(3, 7)));
+        assert!(directives.is_disabled("ban-types", point(3, 7)));
+        assert!(directives.is_disabled("ban-types", point(6, 7)));
+        assert!(!directives.is_disabled("array-type", point(6, 7)));
+        assert!(!directives.is_disabled("ban-types", point(9, 7)));
+        assert_eq!(
+            directives.disabled_rule_names_at(point(6, 7)),
+            HashSet::from(["ban-types".to_owned()])
+        );
+    }
+
+    // stands in for the plugin's no-namespace, but checks the file's disable
+    // directives the way a runner would before reporting
+    fn no_namespace_honoring_disable_directives_rule() -> Arc<dyn Rule> {
+        rule! {
+            name => "no-namespace",
+            languages => [Typescript],
+            messages => [
+                module_syntax_is_prohibited => "ES2015 module syntax is preferred over namespaces.",
+            ],
+            listeners => [
+                r#"
+                  (internal_module) @c
+                "# => |node, context| {
+                    if context
+                        .retrieve::<DisableDirectives>()
+                        .is_disabled("no-namespace", node.start_position())
+                    {
+                        return;
+                    }
+                    context.report(violation! {
+                        node => node,
+                        message_id => "module_syntax_is_prohibited",
+                    });
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_disable_directives_from_file() {
+        RuleTester::run_with_from_file_run_context_instance_provider(
+            no_namespace_honoring_disable_directives_rule(),
+            rule_tests! {
+                valid => [
+                    "
+// eslint-disable-next-line @typescript-eslint/no-namespace
+namespace Foo {}
+                    ",
+                    "namespace Foo {} // eslint-disable-line @typescript-eslint/no-namespace -- legacy",
+                    "
+/* eslint-disable @typescript-eslint/no-namespace */
+namespace Foo {}
+namespace Bar {}
+                    ",
+                    "
+// eslint-disable-next-line
+namespace Foo {}
+                    ",
+                ],
+                invalid => [
+                    {
+                      code => "
+// eslint-disable-next-line @typescript-eslint/array-type
+namespace Foo {}
+                      ",
+                      errors => [{ message_id => "module_syntax_is_prohibited", line => 3 }],
+                    },
+                    {
+                      code => "
+// eslint-disable-next-line no-namespace
+namespace Foo {}
+                      ",
+                      errors => [{ message_id => "module_syntax_is_prohibited", line => 3 }],
+                    },
+                    {
+                      code => "
+// eslint-disable-next-line @typescript-eslint/no-namespace
+
+namespace Foo {}
+                      ",
+                      errors => [{ message_id => "module_syntax_is_prohibited", line => 4 }],
+                    },
+                    {
+                      code => "
+/* eslint-disable @typescript-eslint/no-namespace */
+namespace Foo {}
+/* eslint-enable @typescript-eslint/no-namespace */
+namespace Bar {}
+                      ",
+                      errors => [{ message_id => "module_syntax_is_prohibited", line => 5 }],
+                    },
+                ],
+            },
+            get_instance_provider_factory(),
+        )
+    }
+}
@@ -0,0 +1,251 @@
+//! Generates per-rule markdown documentation from the rules' metadata and
+//! test cases, so that the documented examples are the ones the rule is
+//! actually tested against.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use tree_sitter_lint::serde_json::Value;
+
+use crate::{
+    rule_metadata::{rules_metadata, RuleMetadata},
+    rules::get_rule_tests,
+};
+
+const MAX_EXAMPLES: usize = 5;
+
+const MAX_OPTION_VALUES: usize = 5;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RuleExample {
+    pub code: String,
+    pub options: Option<Value>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RuleExamples {
+    pub valid: Vec<RuleExample>,
+    pub invalid: Vec<RuleExample>,
+}
+
+fn get_example(code: &str, options: Option<&Value>) -> RuleExample {
+    RuleExample {
+        code: code.trim().to_owned(),
+        options: options.cloned(),
+    }
+}
+
+/// The rule's test cases (including generated ones), in order.
+pub fn get_rule_examples(rule_name: &str) -> RuleExamples {
+    let Some(rule_tests) = get_rule_tests(rule_name) else {
+        return Default::default();
+    };
+    RuleExamples {
+        valid: rule_tests
+            .valid
+            .iter()
+            .map(|case| get_example(&case.code, case.options.as_ref()))
+            .collect(),
+        invalid: rule_tests
+            .invalid
+            .iter()
+            .map(|case| get_example(&case.code, case.options.as_ref()))
+            .collect(),
+    }
+}
+
+/// Each option that the examples give, with the distinct values they give
+/// it (as JSON), in order of first appearance. Options that aren't an object
+/// (eg consistent-type-definitions' single string) are listed under an empty
+/// name.
+pub fn get_rule_options(examples: &RuleExamples) -> Vec<(String, Vec<String>)> {
+    let mut options: BTreeMap<String, Vec<String>> = Default::default();
+    let mut add_value = |name: &str, value: &Value| {
+        let value = value.to_string();
+        let values = options.entry(name.to_owned()).or_default();
+        if !values.contains(&value) {
+            values.push(value);
+        }
+    };
+    for example_options in examples
+        .valid
+        .iter()
+        .chain(&examples.invalid)
+        .filter_map(|example| example.options.as_ref())
+    {
+        match example_options {
+            Value::Object(example_options) => {
+                for (name, value) in example_options {
+                    add_value(name, value);
+                }
+            }
+            example_options => add_value("", example_options),
+        }
+    }
+    options.into_iter().collect()
+}
+
+fn write_examples(markdown: &mut String, heading: &str, examples: &[RuleExample]) {
+    if examples.is_empty() {
+        return;
+    }
+    writeln!(markdown, "### {heading}\n").unwrap();
+    for example in examples.iter().take(MAX_EXAMPLES) {
+        if let Some(options) = example.options.as_ref() {
+            writeln!(markdown, "With options `{options}`:\n").unwrap();
+        }
+        writeln!(markdown, "```ts\n{}\n```\n", example.code).unwrap();
+    }
+}
+
+pub fn render_rule_doc(rule_metadata: &RuleMetadata) -> String {
+    let mut markdown = String::new();
+    writeln!(markdown, "# `{}`\n", rule_metadata.name).unwrap();
+    match rule_metadata.upstream_name.as_ref() {
+        Some(upstream_name) => writeln!(markdown, "- Upstream rule: `{upstream_name}`").unwrap(),
+        None => writeln!(markdown, "- Upstream rule: none (specific to this plugin)").unwrap(),
+    }
+    writeln!(markdown, "- Category: {}", rule_metadata.category.as_str()).unwrap();
+    if let Some(feature_name) = rule_metadata.group.feature_name() {
+        writeln!(markdown, "- Cargo feature: `{feature_name}`").unwrap();
+    }
+    writeln!(
+        markdown,
+        "- Fixable: {}\n",
+        if rule_metadata.fixable { "yes" } else { "no" }
+    )
+    .unwrap();
+
+    writeln!(markdown, "## Messages\n").unwrap();
+    writeln!(markdown, "| Code | Message id | Message |").unwrap();
+    writeln!(markdown, "| --- | --- | --- |").unwrap();
+    for message in &rule_metadata.messages {
+        writeln!(
+            markdown,
+            "| `{}` | `{}` | {} |",
+            message.code,
+            message.message_id,
+            message.message.replace('|', "\\|")
+        )
+        .unwrap();
+    }
+    writeln!(markdown).unwrap();
+
+    let examples = get_rule_examples(&rule_metadata.name);
+    let options = get_rule_options(&examples);
+    if !options.is_empty() {
+        writeln!(markdown, "## Options\n").unwrap();
+        writeln!(markdown, "| Option | Example values |").unwrap();
+        writeln!(markdown, "| --- | --- |").unwrap();
+        for (name, values) in options {
+            let name = if name.is_empty() {
+                "(the options themselves)".to_owned()
+            } else {
+                format!("`{name}`")
+            };
+            let mut rendered_values = values
+                .iter()
+                .take(MAX_OPTION_VALUES)
+                .map(|value| format!("`{}`", value.replace('|', "\\|")))
+                .collect::<Vec<_>>();
+            if values.len() > MAX_OPTION_VALUES {
+                rendered_values.push("…".to_owned());
+            }
+            writeln!(markdown, "| {name} | {} |", rendered_values.join(", ")).unwrap();
+        }
+        writeln!(markdown).unwrap();
+    }
+
+    if !examples.valid.is_empty() || !examples.invalid.is_empty() {
+        writeln!(markdown, "## Examples\n").unwrap();
+        write_examples(&mut markdown, "Incorrect", &examples.invalid);
+        write_examples(&mut markdown, "Correct", &examples.valid);
+    }
+
+    markdown.truncate(markdown.trim_end().len());
+    markdown.push('\n');
+    markdown
+}
+
+/// Writes `<rule-name>.md` for every compiled-in rule into `out_dir`,
+/// returning the written paths.
+pub fn generate_docs(out_dir: &Path) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(out_dir)?;
+    rules_metadata()
+        .into_iter()
+        .map(|rule_metadata| {
+            let path = out_dir.join(format!("{}.md", rule_metadata.name));
+            fs::write(&path, render_rule_doc(&rule_metadata))?;
+            Ok(path)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_get_rule_examples() {
+        let examples = get_rule_examples("no-non-null-assertion");
+        assert!(!examples.valid.is_empty());
+        assert!(!examples.invalid.is_empty());
+        assert!(examples
+            .valid
+            .iter()
+            .chain(&examples.invalid)
+            .all(|example| example.code == example.code.trim()));
+        assert_eq!(get_rule_examples("not-a-rule"), Default::default());
+    }
+
+    #[test]
+    fn test_get_rule_options() {
+        let example = |options: Option<Value>| RuleExample {
+            code: "let x: number[];".to_owned(),
+            options,
+        };
+        let examples = RuleExamples {
+            valid: vec![
+                example(None),
+                example(Some(json!({ "default": "array" }))),
+                example(Some(json!({ "default": "generic", "readonly": "array" }))),
+            ],
+            invalid: vec![
+                example(Some(json!({ "default": "array" }))),
+                example(Some(json!("type"))),
+            ],
+        };
+        assert_eq!(
+            get_rule_options(&examples),
+            [
+                ("".to_owned(), vec!["\"type\"".to_owned()]),
+                (
+                    "default".to_owned(),
+                    vec!["\"array\"".to_owned(), "\"generic\"".to_owned()]
+                ),
+                ("readonly".to_owned(), vec!["\"array\"".to_owned()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_rule_doc() {
+        let rule_metadata = rules_metadata()
+            .into_iter()
+            .find(|rule_metadata| rule_metadata.name == "ban-types")
+            .unwrap();
+        let markdown = render_rule_doc(&rule_metadata);
+        assert!(markdown.starts_with("# `ban-types`\n"));
+        assert!(markdown.contains("| `TSL-BAN-TYPES-001` | `banned_type_message` |"));
+        assert!(markdown.contains("## Options"));
+        assert!(markdown.contains("| `extend_defaults` | `false` |"));
+        assert!(markdown.contains("### Incorrect"));
+        assert!(markdown.contains("### Correct"));
+    }
+}
@@ -0,0 +1,74 @@
+//! Running rule tests under both the TypeScript and TSX grammars, since
+//! `.tsx` files get linted with the latter and it differs in places (eg it
+//! has no `type_assertion`).
+
+use std::sync::Arc;
+
+use tree_sitter_lint::{
+    rule_tests, tree_sitter::Parser, Rule, RuleTestInvalid, RuleTestValid, RuleTester, RuleTests,
+};
+
+use crate::{get_instance_provider_factory, util::get_tsx_language};
+
+/// The file name the TSX runs' cases get linted as (which is what picks the
+/// grammar).
+const TSX_FILENAME: &str = "file.tsx";
+
+/// Whether the code parses cleanly under the TSX grammar, ie it isn't eg an
+/// angle-bracket type assertion or a generic arrow function without a
+/// trailing comma, which TSX reads as JSX.
+fn parses_as_tsx(code: &str) -> bool {
+    let mut parser = Parser::new();
+    parser.set_language(get_tsx_language()).unwrap();
+    !parser.parse(code, None).unwrap().root_node().has_error()
+}
+
+/// The cases that also parse as TSX, to be linted as a `.tsx` file.
+fn get_tsx_rule_tests(rule_tests: &RuleTests) -> RuleTests {
+    let valid = rule_tests
+        .valid
+        .iter()
+        .filter(|case| parses_as_tsx(&case.code))
+        .map(|case| RuleTestValid {
+            filename: Some(TSX_FILENAME.to_owned()),
+            ..case.clone()
+        })
+        .collect::<Vec<_>>();
+    let invalid = rule_tests
+        .invalid
+        .iter()
+        .filter(|case| parses_as_tsx(&case.code))
+        .map(|case| RuleTestInvalid {
+            filename: Some(TSX_FILENAME.to_owned()),
+            ..case.clone()
+        })
+        .collect::<Vec<_>>();
+    rule_tests! {
+        valid => [...valid],
+        invalid => [...invalid],
+    }
+}
+
+/// `RuleTester::run()`, once as usual (ie as a `.ts` file) and once more as
+/// a `.tsx` file for the cases that parse as TSX.
+pub fn run_for_typescript_and_tsx(rule: Arc<dyn Rule>, rule_tests: RuleTests) {
+    let tsx_rule_tests = get_tsx_rule_tests(&rule_tests);
+    RuleTester::run(rule.clone(), rule_tests);
+    RuleTester::run(rule, tsx_rule_tests);
+}
+
+/// `run_for_typescript_and_tsx()` with this plugin's provided types (eg
+/// `TypeDeclarations`) available.
+pub fn run_for_typescript_and_tsx_with_provided_types(rule: Arc<dyn Rule>, rule_tests: RuleTests) {
+    let tsx_rule_tests = get_tsx_rule_tests(&rule_tests);
+    RuleTester::run_with_from_file_run_context_instance_provider(
+        rule.clone(),
+        rule_tests,
+        get_instance_provider_factory(),
+    );
+    RuleTester::run_with_from_file_run_context_instance_provider(
+        rule,
+        tsx_rule_tests,
+        get_instance_provider_factory(),
+    );
+}
@@ -0,0 +1,148 @@
+//! Checks (by scanning the rule sources) that the expected output of every
+//! fixed test case still parses, so that a fixer producing broken code can't
+//! get enshrined in its tests.
+
+use std::fs;
+
+use tree_sitter_lint::{
+    tree_sitter::{Node, Parser},
+    tree_sitter_grep::SupportedLanguage,
+};
+
+use crate::{
+    rule_metadata::rules_metadata,
+    rule_sources::{get_list_items, get_rule_source_path, parse_string_literal},
+};
+
+/// The top-level `name => value` fields of a test case written out as
+/// `{ ... }` (rather than as just a string of code).
+fn get_case_fields(item: &str) -> Vec<(&str, &str)> {
+    let Some(body) = item.strip_prefix('{') else {
+        return Default::default();
+    };
+    get_list_items(body)
+        .into_iter()
+        .filter_map(|field| {
+            let (name, value) = field.split_once("=>")?;
+            Some((name.trim(), value.trim()))
+        })
+        .collect()
+}
+
+/// `(code, output)` of each case (in any `rule_tests!`) with an expected
+/// output.
+fn get_fixed_cases(tests_source: &str) -> Vec<(String, String)> {
+    tests_source
+        .match_indices("invalid => [")
+        .flat_map(|(index, list_start)| get_list_items(&tests_source[index + list_start.len()..]))
+        .filter_map(|item| {
+            let fields = get_case_fields(item);
+            let get_field = |name: &str| {
+                fields
+                    .iter()
+                    .find(|(field_name, _)| *field_name == name)
+                    .and_then(|(_, value)| parse_string_literal(value))
+            };
+            Some((get_field("code")?, get_field("output")?))
+        })
+        .collect()
+}
+
+fn find_error(node: Node) -> Option<Node> {
+    if node.is_error() || node.is_missing() {
+        return Some(node);
+    }
+    if !node.has_error() {
+        return None;
+    }
+    let mut cursor = node.walk();
+    let children = node.children(&mut cursor).collect::<Vec<_>>();
+    children.into_iter().find_map(find_error)
+}
+
+/// Describes the first `ERROR` or `MISSING` node of the parsed `source`, if
+/// any.
+fn get_parse_error(parser: &mut Parser, source: &str) -> Option<String> {
+    let tree = parser.parse(source, None).unwrap();
+    let error = find_error(tree.root_node())?;
+    Some(format!(
+        "{} at {}:{}",
+        if error.is_missing() {
+            format!("MISSING {}", error.kind())
+        } else {
+            "ERROR".to_owned()
+        },
+        error.start_position().row + 1,
+        error.start_position().column + 1,
+    ))
+}
+
+#[test]
+fn test_get_fixed_cases() {
+    let source = r####"
+                invalid => [
+                  {
+                    code => "let x: Array<number>;",
+                    output => "let x: number[];",
+                    errors => [
+                      {
+                        message_id => "error_string_array",
+                        // suggestions: [{ output => "foo" }],
+                      },
+                    ],
+                  },
+                  {
+                    code => r#"let y = "[";"#,
+                    errors => [{ message_id => "foo" }],
+                  },
+                  {
+                    code => "interface Foo {}",
+                    output => None,
+                    errors => [{ message_id => "foo" }],
+                  },
+                ],
+    "####;
+    assert_eq!(
+        get_fixed_cases(source),
+        [(
+            "let x: Array<number>;".to_owned(),
+            "let x: number[];".to_owned()
+        )]
+    );
+}
+
+#[test]
+fn test_fix_outputs_parse() {
+    let mut parser = Parser::new();
+    parser
+        .set_language(SupportedLanguage::Typescript.language(None))
+        .unwrap();
+    let mut failures: Vec<String> = Default::default();
+    for rule_metadata in rules_metadata() {
+        if !rule_metadata.fixable {
+            continue;
+        }
+        let source = fs::read_to_string(get_rule_source_path(&rule_metadata.name)).unwrap();
+        let Some(tests_source) = source.split("#[cfg(test)]").nth(1) else {
+            continue;
+        };
+        for (code, output) in get_fixed_cases(tests_source) {
+            // (a fixer can't be blamed for a syntax error that was already
+            // there)
+            if get_parse_error(&mut parser, &code).is_some() {
+                continue;
+            }
+            if let Some(error) = get_parse_error(&mut parser, &output) {
+                failures.push(format!(
+                    "{}: {error} in output:\n{output}",
+                    rule_metadata.name
+                ));
+            }
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "fix outputs that don't parse:\n{}",
+        failures.join("\n\n")
+    );
+}
@@ -0,0 +1,294 @@
+//! A shared model of `import` statements, so that fixers in this plugin (eg
+//! consistent-type-imports) and import-sorting rules in other plugins rewrite
+//! imports the same way instead of undoing each other's fixes.
+
+use std::borrow::Cow;
+
+use itertools::Itertools;
+use tree_sitter_lint::{
+    tree_sitter::Node, tree_sitter_grep::SupportedLanguage, NodeExt, QueryMatchContext,
+};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    assert_kind,
+    kind::{
+        Identifier, ImportClause, ImportSpecifier, ImportStatement, NamedImports, NamespaceImport,
+    },
+    utils::ast_utils::get_static_string_value,
+};
+
+use crate::kind::ImportRequireClause;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportSpecifierModel<'a> {
+    pub imported: Cow<'a, str>,
+    pub local: Cow<'a, str>,
+    /// `import { type Foo }`
+    pub is_type_only: bool,
+}
+
+impl<'a> ImportSpecifierModel<'a> {
+    fn render(&self, in_type_only_import: bool) -> String {
+        format!(
+            "{}{}",
+            if self.is_type_only && !in_type_only_import {
+                "type "
+            } else {
+                ""
+            },
+            if self.imported == self.local {
+                self.local.clone().into_owned()
+            } else {
+                format!("{} as {}", self.imported, self.local)
+            }
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportStatementModel<'a> {
+    /// The source exactly as written (including its quotes), so that
+    /// re-rendering doesn't change quote style.
+    pub source_text: Cow<'a, str>,
+    pub source: Cow<'a, str>,
+    /// `import type ...`
+    pub is_type_only: bool,
+    pub default: Option<Cow<'a, str>>,
+    pub namespace: Option<Cow<'a, str>>,
+    pub named: Vec<ImportSpecifierModel<'a>>,
+}
+
+impl<'a> ImportStatementModel<'a> {
+    /// `None` for `import foo = require(...)`, which doesn't fit the model.
+    pub fn from_node(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Option<Self> {
+        assert_kind!(node, ImportStatement);
+
+        if node.has_child_of_kind(ImportRequireClause) {
+            return None;
+        }
+        let source_node = node.field("source");
+        let mut model = Self {
+            source_text: source_node.text(context),
+            source: get_static_string_value(source_node, context).unwrap(),
+            is_type_only: node.has_child_of_kind("type"),
+            default: Default::default(),
+            namespace: Default::default(),
+            named: Default::default(),
+        };
+        let Some(import_clause) = node.maybe_first_child_of_kind(ImportClause) else {
+            return Some(model);
+        };
+        for child in import_clause.non_comment_named_children(SupportedLanguage::Javascript) {
+            match child.kind() {
+                Identifier => model.default = Some(child.text(context)),
+                NamespaceImport => {
+                    model.namespace = Some(
+                        child
+                            .first_non_comment_named_child(SupportedLanguage::Javascript)
+                            .text(context),
+                    )
+                }
+                NamedImports => {
+                    model.named = child
+                        .non_comment_named_children(SupportedLanguage::Javascript)
+                        .filter(|specifier| specifier.kind() == ImportSpecifier)
+                        .map(|specifier| {
+                            let imported = specifier.field("name").text(context);
+                            ImportSpecifierModel {
+                                local: specifier
+                                    .child_by_field_name("alias")
+                                    .map_or_else(|| imported.clone(), |alias| alias.text(context)),
+                                imported,
+                                is_type_only: specifier.has_child_of_kind("type"),
+                            }
+                        })
+                        .collect();
+                }
+                _ => (),
+            }
+        }
+        Some(model)
+    }
+
+    pub fn is_side_effect_only(&self) -> bool {
+        self.default.is_none() && self.namespace.is_none() && self.named.is_empty()
+    }
+
+    /// Whether everything this statement imports is only usable as a type.
+    pub fn imports_only_types(&self) -> bool {
+        self.is_type_only
+            || self.default.is_none()
+                && self.namespace.is_none()
+                && !self.named.is_empty()
+                && self.named.iter().all(|specifier| specifier.is_type_only)
+    }
+
+    /// The canonical text for the statement. Fixers that rewrite imports
+    /// should go through this so that their output is stable regardless of
+    /// which plugin's fix gets applied first.
+    pub fn render(&self) -> String {
+        let mut clauses: Vec<String> = Default::default();
+        if let Some(default) = self.default.as_ref() {
+            clauses.push(default.clone().into_owned());
+        }
+        if let Some(namespace) = self.namespace.as_ref() {
+            clauses.push(format!("* as {namespace}"));
+        }
+        if !self.named.is_empty() {
+            clauses.push(format!(
+                "{{ {} }}",
+                self.named
+                    .iter()
+                    .map(|specifier| specifier.render(self.is_type_only))
+                    .join(", ")
+            ));
+        }
+        if clauses.is_empty() {
+            return format!("import {};", self.source_text);
+        }
+        format!(
+            "import {}{} from {};",
+            if self.is_type_only { "type " } else { "" },
+            clauses.join(", "),
+            self.source_text
+        )
+    }
+
+    /// Splits the statement so that the named imports whose local names are
+    /// in `locals` are imported with `import type`. The type-only statement
+    /// (if any) always comes first, which is also where import sorters that
+    /// group type imports expect it.
+    pub fn split_type_only(&self, locals: &[&str]) -> Vec<Self> {
+        if self.is_type_only {
+            return vec![self.clone()];
+        }
+        let (type_only, value): (Vec<_>, Vec<_>) = self
+            .named
+            .iter()
+            .cloned()
+            .partition(|specifier| locals.contains(&&*specifier.local));
+        if type_only.is_empty() {
+            return vec![self.clone()];
+        }
+
+        let type_only_statement = Self {
+            is_type_only: true,
+            default: Default::default(),
+            namespace: Default::default(),
+            named: type_only
+                .into_iter()
+                .map(|specifier| ImportSpecifierModel {
+                    is_type_only: false,
+                    ..specifier
+                })
+                .collect(),
+            ..self.clone()
+        };
+        let value_statement = Self {
+            named: value,
+            ..self.clone()
+        };
+        if value_statement.is_side_effect_only() {
+            return vec![type_only_statement];
+        }
+        vec![type_only_statement, value_statement]
+    }
+
+    /// The key import-sorting rules should order statements by, so that
+    /// statements split apart by [`Self::split_type_only`] stay adjacent.
+    pub fn sort_key(&self) -> (&str, bool) {
+        (&*self.source, !self.is_type_only)
+    }
+}
+
+pub fn get_import_statements<'a>(
+    program: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Vec<(Node<'a>, ImportStatementModel<'a>)> {
+    program
+        .non_comment_named_children(SupportedLanguage::Javascript)
+        .filter(|child| child.kind() == ImportStatement)
+        .filter_map(|import_statement| {
+            ImportStatementModel::from_node(import_statement, context)
+                .map(|model| (import_statement, model))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn specifier(
+        imported: &'static str,
+        local: &'static str,
+        is_type_only: bool,
+    ) -> ImportSpecifierModel<'static> {
+        ImportSpecifierModel {
+            imported: imported.into(),
+            local: local.into(),
+            is_type_only,
+        }
+    }
+
+    fn statement(named: Vec<ImportSpecifierModel<'static>>) -> ImportStatementModel<'static> {
+        ImportStatementModel {
+            source_text: "'./foo'".into(),
+            source: "./foo".into(),
+            is_type_only: false,
+            default: Some("Foo".into()),
+            namespace: None,
+            named,
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            statement(vec![specifier("a", "a", false), specifier("b", "c", true)]).render(),
+            "import Foo, { a, type b as c } from './foo';"
+        );
+        assert_eq!(
+            ImportStatementModel {
+                default: None,
+                ..statement(vec![])
+            }
+            .render(),
+            "import './foo';"
+        );
+    }
+
+    #[test]
+    fn test_split_type_only() {
+        let split = statement(vec![
+            specifier("a", "a", false),
+            specifier("B", "B", false),
+            specifier("C", "D", true),
+        ])
+        .split_type_only(&["B", "D"]);
+        assert_eq!(
+            split
+                .iter()
+                .map(ImportStatementModel::render)
+                .collect::<Vec<_>>(),
+            [
+                "import type { B, C as D } from './foo';",
+                "import Foo, { a } from './foo';",
+            ]
+        );
+        assert!(split[0].sort_key() < split[1].sort_key());
+
+        let split = ImportStatementModel {
+            default: None,
+            ..statement(vec![specifier("B", "B", false)])
+        }
+        .split_type_only(&["B"]);
+        assert_eq!(
+            split
+                .iter()
+                .map(ImportStatementModel::render)
+                .collect::<Vec<_>>(),
+            ["import type { B } from './foo';"]
+        );
+        assert!(split[0].imports_only_types());
+    }
+}
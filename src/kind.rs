@@ -32,6 +32,7 @@ pub const IndexSignature: &str = "index_signature";
 pub const IndexTypeQuery: &str = "index_type_query";
 pub const InferType: &str = "infer_type";
 pub const InstantiationExpression: &str = "instantiation_expression";
+pub const InterfaceBody: &str = "interface_body";
 pub const InterfaceDeclaration: &str = "interface_declaration";
 pub const InternalModule: &str = "internal_module";
 pub const IntersectionType: &str = "intersection_type";
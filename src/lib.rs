@@ -1,45 +1,239 @@
 #![allow(non_upper_case_globals, clippy::into_iter_on_ref)]
 
+use std::sync::Arc;
+
 use tree_sitter_lint::{
-    instance_provider_factory, FromFileRunContextInstanceProviderFactory, Plugin,
+    instance_provider_factory, FromFileRunContextInstanceProviderFactory, Plugin, Rule,
 };
 use tree_sitter_lint_plugin_eslint_builtin::AllComments;
 
 mod ast_helpers;
+mod config_warnings;
+mod diagnostic_codes;
+mod disable_directives;
+#[cfg(feature = "docs")]
+mod docs;
+#[cfg(test)]
+mod dual_grammar;
+#[cfg(test)]
+mod fix_outputs;
+mod import_model;
 mod kind;
+mod lib_globals;
+mod module_kind;
+mod restricted_syntax;
+mod rule_groups;
+mod rule_metadata;
+mod rule_names;
+#[cfg(test)]
+mod rule_sources;
 mod rules;
+#[cfg(any(test, feature = "docs"))]
+mod test_helpers;
+#[cfg(feature = "type-aware")]
+mod try_contexts;
+mod type_declarations;
 mod type_utils;
 mod util;
+mod violation_data;
 
+use rule_names::get_rule_names;
+#[cfg(feature = "formatting")]
+use rules::no_extra_semi_rule;
+#[cfg(feature = "stylistic")]
+use rules::{
+    adjacent_overload_signatures_rule, array_type_rule, ban_tslint_comment_rule,
+    class_literal_property_style_rule, consistent_generic_constructors_rule,
+    consistent_indexed_object_style_rule, consistent_type_definitions_rule, no_empty_function_rule,
+    no_empty_interface_rule, no_inferrable_types_rule, prefer_array_index_at_rule,
+    prefer_function_type_rule, prefer_namespace_keyword_rule,
+};
+#[cfg(feature = "type-aware")]
 use rules::{
-    adjacent_overload_signatures_rule, array_type_rule, ban_ts_comment_rule,
-    ban_tslint_comment_rule, ban_types_rule, class_literal_property_style_rule,
-    class_methods_use_this_rule, consistent_generic_constructors_rule,
-    consistent_type_definitions_rule, default_param_last_rule,
+    await_thenable_rule, no_array_delete_rule, no_confusing_void_expression_rule,
+    no_meaningless_void_operator_rule, no_misused_promises_rule,
+    no_unnecessary_boolean_literal_compare_rule, no_unnecessary_type_assertion_rule,
+    no_unnecessary_type_parameters_rule, no_unsafe_argument_rule, no_unsafe_member_access_rule,
+    prefer_nullish_coalescing_rule, prefer_string_starts_ends_with_rule,
+    restrict_plus_operands_rule, restrict_template_expressions_rule, return_await_rule,
+};
+use rules::{
+    ban_ts_comment_rule, ban_types_rule, class_methods_use_this_rule, default_param_last_rule,
+    explicit_function_return_type_rule, explicit_module_boundary_types_rule, member_ordering_rule,
+    method_signature_style_rule, naming_convention_rule, no_array_constructor_rule,
+    no_array_method_misuse_rule, no_async_promise_executor_rule, no_constant_condition_rule,
+    no_dynamic_delete_rule, no_extraneous_class_rule, no_invalid_this_rule,
+    no_loss_of_precision_rule, no_magic_numbers_rule, no_misused_new_rule, no_namespace_rule,
+    no_non_null_asserted_nullish_coalescing_rule, no_non_null_asserted_optional_chain_rule,
+    no_non_null_assertion_rule, no_require_imports_rule, no_restricted_ts_syntax_rule,
+    no_shadow_rule, no_this_alias_rule, no_type_alias_rule, no_unused_private_class_members_rule,
+    no_useless_constructor_rule, no_var_requires_rule, parameter_properties_rule,
+    prefer_const_rule, prefer_literal_enum_member_rule, typedef_rule,
 };
 
-pub type ProvidedTypes<'a> = ();
+pub use config_warnings::{validate_rule_options, ConfigWarning};
+pub use diagnostic_codes::{
+    get_diagnostic_code, get_diagnostic_codes, get_rule_category, DiagnosticCategory,
+    DiagnosticCode,
+};
+pub use disable_directives::{
+    get_disable_directive, parse_disable_directive, DisableDirective, DisableDirectiveKind,
+    DisableDirectives,
+};
+#[cfg(feature = "docs")]
+pub use docs::{
+    generate_docs, get_rule_examples, get_rule_options, render_rule_doc, RuleExample, RuleExamples,
+};
+pub use import_model::{get_import_statements, ImportSpecifierModel, ImportStatementModel};
+pub use module_kind::{FileModuleKind, ModuleKind};
+pub use restricted_syntax::{
+    get_restricted_syntax_preset, restricted_syntax_presets, RestrictedSyntaxPreset,
+};
+pub use rule_groups::{get_rule_group, is_known_rule, is_rule_available, RuleGroup};
+pub use rule_metadata::{rules_metadata, RuleMessage, RuleMetadata};
+pub use rule_names::{
+    get_rule_by_name, get_rule_name_for_upstream_name, get_upstream_rule_name,
+    get_upstream_rule_names, normalize_rule_name,
+};
+#[cfg(feature = "type-aware")]
+pub use try_contexts::{TryContext, TryContexts};
+pub use type_declarations::{TypeDeclarationKind, TypeDeclarations};
+pub use violation_data::{BANNED_NAME, OPTION, REPLACEMENT, VIOLATION_DATA_KEYS};
+
+#[cfg(feature = "type-aware")]
+pub type ProvidedTypes<'a> = (
+    TypeDeclarations<'a>,
+    TryContexts,
+    FileModuleKind,
+    DisableDirectives,
+);
+#[cfg(not(feature = "type-aware"))]
+pub type ProvidedTypes<'a> = (TypeDeclarations<'a>, FileModuleKind, DisableDirectives);
 
 pub fn instantiate() -> Plugin {
     Plugin {
         name: "typescript-eslint".to_owned(),
-        rules: vec![
-            adjacent_overload_signatures_rule(),
-            array_type_rule(),
-            ban_ts_comment_rule(),
-            ban_tslint_comment_rule(),
-            ban_types_rule(),
-            class_literal_property_style_rule(),
-            class_methods_use_this_rule(),
-            consistent_generic_constructors_rule(),
-            consistent_type_definitions_rule(),
-            default_param_last_rule(),
-        ],
+        rules: get_rule_names().filter_map(instantiate_rule).collect(),
     }
 }
 
+/// `None` for names that aren't rules of this plugin or whose rules aren't
+/// compiled in.
+fn instantiate_rule(rule_name: &str) -> Option<Arc<dyn Rule>> {
+    Some(match rule_name {
+        "ban-ts-comment" => ban_ts_comment_rule(),
+        "ban-types" => ban_types_rule(),
+        "class-methods-use-this" => class_methods_use_this_rule(),
+        "default-param-last" => default_param_last_rule(),
+        "explicit-function-return-type" => explicit_function_return_type_rule(),
+        "explicit-module-boundary-types" => explicit_module_boundary_types_rule(),
+        "member-ordering" => member_ordering_rule(),
+        "method-signature-style" => method_signature_style_rule(),
+        "naming-convention" => naming_convention_rule(),
+        "no-array-constructor" => no_array_constructor_rule(),
+        "no-array-method-misuse" => no_array_method_misuse_rule(),
+        "no-async-promise-executor" => no_async_promise_executor_rule(),
+        "no-constant-condition" => no_constant_condition_rule(),
+        "no-dynamic-delete" => no_dynamic_delete_rule(),
+        "no-extraneous-class" => no_extraneous_class_rule(),
+        "no-invalid-this" => no_invalid_this_rule(),
+        "no-loss-of-precision" => no_loss_of_precision_rule(),
+        "no-magic-numbers" => no_magic_numbers_rule(),
+        "no-misused-new" => no_misused_new_rule(),
+        "no-namespace" => no_namespace_rule(),
+        "no-non-null-asserted-nullish-coalescing" => no_non_null_asserted_nullish_coalescing_rule(),
+        "no-non-null-asserted-optional-chain" => no_non_null_asserted_optional_chain_rule(),
+        "no-non-null-assertion" => no_non_null_assertion_rule(),
+        "no-require-imports" => no_require_imports_rule(),
+        "no-restricted-ts-syntax" => no_restricted_ts_syntax_rule(),
+        "no-shadow" => no_shadow_rule(),
+        "no-this-alias" => no_this_alias_rule(),
+        "no-type-alias" => no_type_alias_rule(),
+        "no-unused-private-class-members" => no_unused_private_class_members_rule(),
+        "no-useless-constructor" => no_useless_constructor_rule(),
+        "no-var-requires" => no_var_requires_rule(),
+        "parameter-properties" => parameter_properties_rule(),
+        "prefer-const" => prefer_const_rule(),
+        "prefer-literal-enum-member" => prefer_literal_enum_member_rule(),
+        "typedef" => typedef_rule(),
+        #[cfg(feature = "stylistic")]
+        "adjacent-overload-signatures" => adjacent_overload_signatures_rule(),
+        #[cfg(feature = "stylistic")]
+        "array-type" => array_type_rule(),
+        #[cfg(feature = "stylistic")]
+        "ban-tslint-comment" => ban_tslint_comment_rule(),
+        #[cfg(feature = "stylistic")]
+        "class-literal-property-style" => class_literal_property_style_rule(),
+        #[cfg(feature = "stylistic")]
+        "consistent-generic-constructors" => consistent_generic_constructors_rule(),
+        #[cfg(feature = "stylistic")]
+        "consistent-indexed-object-style" => consistent_indexed_object_style_rule(),
+        #[cfg(feature = "stylistic")]
+        "consistent-type-definitions" => consistent_type_definitions_rule(),
+        #[cfg(feature = "stylistic")]
+        "no-empty-function" => no_empty_function_rule(),
+        #[cfg(feature = "stylistic")]
+        "no-empty-interface" => no_empty_interface_rule(),
+        #[cfg(feature = "stylistic")]
+        "no-inferrable-types" => no_inferrable_types_rule(),
+        #[cfg(feature = "stylistic")]
+        "prefer-array-index-at" => prefer_array_index_at_rule(),
+        #[cfg(feature = "stylistic")]
+        "prefer-function-type" => prefer_function_type_rule(),
+        #[cfg(feature = "stylistic")]
+        "prefer-namespace-keyword" => prefer_namespace_keyword_rule(),
+        #[cfg(feature = "type-aware")]
+        "await-thenable" => await_thenable_rule(),
+        #[cfg(feature = "type-aware")]
+        "no-array-delete" => no_array_delete_rule(),
+        #[cfg(feature = "type-aware")]
+        "no-confusing-void-expression" => no_confusing_void_expression_rule(),
+        #[cfg(feature = "type-aware")]
+        "no-meaningless-void-operator" => no_meaningless_void_operator_rule(),
+        #[cfg(feature = "type-aware")]
+        "no-misused-promises" => no_misused_promises_rule(),
+        #[cfg(feature = "type-aware")]
+        "no-unnecessary-boolean-literal-compare" => no_unnecessary_boolean_literal_compare_rule(),
+        #[cfg(feature = "type-aware")]
+        "no-unnecessary-type-assertion" => no_unnecessary_type_assertion_rule(),
+        #[cfg(feature = "type-aware")]
+        "no-unnecessary-type-parameters" => no_unnecessary_type_parameters_rule(),
+        #[cfg(feature = "type-aware")]
+        "no-unsafe-argument" => no_unsafe_argument_rule(),
+        #[cfg(feature = "type-aware")]
+        "no-unsafe-member-access" => no_unsafe_member_access_rule(),
+        #[cfg(feature = "type-aware")]
+        "prefer-nullish-coalescing" => prefer_nullish_coalescing_rule(),
+        #[cfg(feature = "type-aware")]
+        "prefer-string-starts-ends-with" => prefer_string_starts_ends_with_rule(),
+        #[cfg(feature = "type-aware")]
+        "restrict-plus-operands" => restrict_plus_operands_rule(),
+        #[cfg(feature = "type-aware")]
+        "restrict-template-expressions" => restrict_template_expressions_rule(),
+        #[cfg(feature = "type-aware")]
+        "return-await" => return_await_rule(),
+        #[cfg(feature = "formatting")]
+        "no-extra-semi" => no_extra_semi_rule(),
+        _ => return None,
+    })
+}
+
 pub fn get_instance_provider_factory() -> Box<dyn FromFileRunContextInstanceProviderFactory> {
-    type ProvidedTypesForRuleTests<'a> = (AllComments<'a>,);
+    #[cfg(feature = "type-aware")]
+    type ProvidedTypesForRuleTests<'a> = (
+        AllComments<'a>,
+        TypeDeclarations<'a>,
+        TryContexts,
+        FileModuleKind,
+        DisableDirectives,
+    );
+    #[cfg(not(feature = "type-aware"))]
+    type ProvidedTypesForRuleTests<'a> = (
+        AllComments<'a>,
+        TypeDeclarations<'a>,
+        FileModuleKind,
+        DisableDirectives,
+    );
 
     Box::new(instance_provider_factory!(ProvidedTypesForRuleTests))
 }
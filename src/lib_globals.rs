@@ -0,0 +1,226 @@
+//! The globals declared by TypeScript's standard library (the `lib.es*.d.ts`
+//! files up to ES2023, but not eg `lib.dom.d.ts`), and whether each of them
+//! is a type, a value or both.
+
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LibGlobalKind {
+    Type,
+    Value,
+    TypeAndValue,
+}
+
+impl LibGlobalKind {
+    pub fn is_value(self) -> bool {
+        self != Self::Type
+    }
+}
+
+/// An interface merged with a `declare var` (or a namespace), eg `Array`.
+const TYPES_AND_VALUES: &[&str] = &[
+    "AggregateError",
+    "Array",
+    "ArrayBuffer",
+    "Atomics",
+    "BigInt",
+    "BigInt64Array",
+    "BigUint64Array",
+    "Boolean",
+    "DataView",
+    "Date",
+    "Error",
+    "EvalError",
+    "FinalizationRegistry",
+    "Float32Array",
+    "Float64Array",
+    "Function",
+    "Int16Array",
+    "Int32Array",
+    "Int8Array",
+    "Intl",
+    "JSON",
+    "Map",
+    "Math",
+    "Number",
+    "Object",
+    "Promise",
+    "RangeError",
+    "ReferenceError",
+    "Reflect",
+    "RegExp",
+    "Set",
+    "SharedArrayBuffer",
+    "String",
+    "Symbol",
+    "SyntaxError",
+    "TypeError",
+    "URIError",
+    "Uint16Array",
+    "Uint32Array",
+    "Uint8Array",
+    "Uint8ClampedArray",
+    "WeakMap",
+    "WeakRef",
+    "WeakSet",
+];
+
+const VALUES: &[&str] = &[
+    "Infinity",
+    "NaN",
+    "Proxy",
+    "decodeURI",
+    "decodeURIComponent",
+    "encodeURI",
+    "encodeURIComponent",
+    "escape",
+    "eval",
+    "globalThis",
+    "isFinite",
+    "isNaN",
+    "parseFloat",
+    "parseInt",
+    "undefined",
+    "unescape",
+];
+
+const TYPES: &[&str] = &[
+    "AggregateErrorConstructor",
+    "ArrayBufferConstructor",
+    "ArrayBufferLike",
+    "ArrayBufferTypes",
+    "ArrayBufferView",
+    "ArrayConstructor",
+    "ArrayLike",
+    "AsyncGenerator",
+    "AsyncGeneratorFunction",
+    "AsyncGeneratorFunctionConstructor",
+    "AsyncIterable",
+    "AsyncIterableIterator",
+    "AsyncIterator",
+    "Awaited",
+    "BigInt64ArrayConstructor",
+    "BigIntConstructor",
+    "BigUint64ArrayConstructor",
+    "BooleanConstructor",
+    "CallableFunction",
+    "Capitalize",
+    "ClassAccessorDecoratorContext",
+    "ClassAccessorDecoratorResult",
+    "ClassAccessorDecoratorTarget",
+    "ClassDecorator",
+    "ClassDecoratorContext",
+    "ClassFieldDecoratorContext",
+    "ClassGetterDecoratorContext",
+    "ClassMemberDecoratorContext",
+    "ClassMethodDecoratorContext",
+    "ClassSetterDecoratorContext",
+    "ConcatArray",
+    "ConstructorParameters",
+    "DataViewConstructor",
+    "DateConstructor",
+    "DecoratorContext",
+    "DecoratorMetadata",
+    "DecoratorMetadataObject",
+    "ErrorConstructor",
+    "EvalErrorConstructor",
+    "Exclude",
+    "Extract",
+    "FinalizationRegistryConstructor",
+    "Float32ArrayConstructor",
+    "Float64ArrayConstructor",
+    "FunctionConstructor",
+    "Generator",
+    "GeneratorFunction",
+    "GeneratorFunctionConstructor",
+    "IArguments",
+    "ImportAssertions",
+    "ImportAttributes",
+    "ImportCallOptions",
+    "ImportMeta",
+    "InstanceType",
+    "Int16ArrayConstructor",
+    "Int32ArrayConstructor",
+    "Int8ArrayConstructor",
+    "Iterable",
+    "IterableIterator",
+    "Iterator",
+    "IteratorResult",
+    "IteratorReturnResult",
+    "IteratorYieldResult",
+    "Lowercase",
+    "MapConstructor",
+    "MethodDecorator",
+    "NewableFunction",
+    "NoInfer",
+    "NonNullable",
+    "NumberConstructor",
+    "ObjectConstructor",
+    "Omit",
+    "OmitThisParameter",
+    "ParameterDecorator",
+    "Parameters",
+    "Partial",
+    "Pick",
+    "PromiseConstructor",
+    "PromiseConstructorLike",
+    "PromiseFulfilledResult",
+    "PromiseLike",
+    "PromiseRejectedResult",
+    "PromiseSettledResult",
+    "PropertyDecorator",
+    "PropertyDescriptor",
+    "PropertyDescriptorMap",
+    "PropertyKey",
+    "ProxyConstructor",
+    "ProxyHandler",
+    "RangeErrorConstructor",
+    "Readonly",
+    "ReadonlyArray",
+    "ReadonlyMap",
+    "ReadonlySet",
+    "Record",
+    "ReferenceErrorConstructor",
+    "RegExpConstructor",
+    "RegExpExecArray",
+    "RegExpMatchArray",
+    "Required",
+    "ReturnType",
+    "SetConstructor",
+    "SharedArrayBufferConstructor",
+    "StringConstructor",
+    "SymbolConstructor",
+    "SyntaxErrorConstructor",
+    "TemplateStringsArray",
+    "ThisParameterType",
+    "ThisType",
+    "TypeErrorConstructor",
+    "TypedPropertyDescriptor",
+    "URIErrorConstructor",
+    "Uint16ArrayConstructor",
+    "Uint32ArrayConstructor",
+    "Uint8ArrayConstructor",
+    "Uint8ClampedArrayConstructor",
+    "Uncapitalize",
+    "Uppercase",
+    "WeakKey",
+    "WeakKeyTypes",
+    "WeakMapConstructor",
+    "WeakRefConstructor",
+    "WeakSetConstructor",
+];
+
+static LIB_GLOBALS: Lazy<HashMap<&'static str, LibGlobalKind>> = Lazy::new(|| {
+    TYPES_AND_VALUES
+        .iter()
+        .map(|&name| (name, LibGlobalKind::TypeAndValue))
+        .chain(VALUES.iter().map(|&name| (name, LibGlobalKind::Value)))
+        .chain(TYPES.iter().map(|&name| (name, LibGlobalKind::Type)))
+        .collect()
+});
+
+pub fn get_lib_global(name: &str) -> Option<LibGlobalKind> {
+    LIB_GLOBALS.get(name).copied()
+}
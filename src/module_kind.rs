@@ -0,0 +1,96 @@
+use tree_sitter_lint::{
+    tree_sitter::Node, tree_sitter_grep::SupportedLanguage, FileRunContext, FromFileRunContext,
+    NodeExt, QueryMatchContext,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{ExportStatement, ImportStatement};
+
+use crate::kind::{AmbientDeclaration, InterfaceDeclaration, TypeAliasDeclaration};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ModuleKind {
+    /// Has top-level `import`/`export` statements.
+    Module,
+    /// Has no `import`/`export` statements, so (presumably) a CommonJS or
+    /// global script.
+    Script,
+    /// Only declares types, eg the contents of a `.d.ts` file.
+    AmbientDeclaration,
+}
+
+/// Whether the file being linted is an ES module, a script or an ambient
+/// declaration file.
+///
+/// Rules don't get to see the file name, so this is purely syntactic: a file
+/// counts as ambient if every top-level statement (looking through `export`)
+/// is a `declare`, an interface, a type alias or an `import`/re-export.
+#[derive(Copy, Clone, Debug)]
+pub struct FileModuleKind {
+    kind: ModuleKind,
+}
+
+impl FileModuleKind {
+    pub fn get(&self) -> ModuleKind {
+        self.kind
+    }
+
+    pub fn is_module(&self) -> bool {
+        self.kind == ModuleKind::Module
+    }
+
+    pub fn is_script(&self) -> bool {
+        self.kind == ModuleKind::Script
+    }
+
+    pub fn is_ambient_declaration(&self) -> bool {
+        self.kind == ModuleKind::AmbientDeclaration
+    }
+}
+
+fn is_ambient_statement(statement: Node) -> bool {
+    match statement.kind() {
+        AmbientDeclaration | InterfaceDeclaration | TypeAliasDeclaration | ImportStatement => true,
+        ExportStatement => match statement.child_by_field_name("declaration") {
+            Some(declaration) => is_ambient_statement(declaration),
+            // `export {}`, `export { foo } from 'foo'`, but not eg
+            // `export default foo`
+            None => statement.child_by_field_name("value").is_none(),
+        },
+        _ => false,
+    }
+}
+
+impl<'a> FromFileRunContext<'a> for FileModuleKind {
+    fn from_file_run_context(file_run_context: FileRunContext<'a, '_>) -> Self {
+        let statements = file_run_context
+            .tree
+            .root_node()
+            .non_comment_named_children(SupportedLanguage::Javascript)
+            .collect::<Vec<_>>();
+
+        let kind = if !statements.is_empty() && statements.iter().copied().all(is_ambient_statement)
+        {
+            ModuleKind::AmbientDeclaration
+        } else if statements
+            .iter()
+            .any(|statement| matches!(statement.kind(), ImportStatement | ExportStatement))
+        {
+            ModuleKind::Module
+        } else {
+            ModuleKind::Script
+        };
+
+        Self { kind }
+    }
+}
+
+/// Whether `node` only declares things rather than implementing them, ie is
+/// inside a `declare` (eg `declare class`, `declare namespace`) or anywhere in
+/// an ambient declaration file.
+pub fn is_in_ambient_context<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    context
+        .retrieve::<FileModuleKind>()
+        .is_ambient_declaration()
+        || node
+            .ancestors()
+            .any(|ancestor| ancestor.kind() == AmbientDeclaration)
+}
@@ -0,0 +1,110 @@
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RestrictedSyntaxPreset {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// A tree-sitter query for the TypeScript grammar. Every capture not
+    /// starting with `_` gets reported.
+    pub query: &'static str,
+}
+
+static RESTRICTED_SYNTAX_PRESETS: &[RestrictedSyntaxPreset] = &[
+    RestrictedSyntaxPreset {
+        name: "abstract-class",
+        description: "`abstract class` declarations",
+        query: "(abstract_class_declaration) @restricted",
+    },
+    RestrictedSyntaxPreset {
+        name: "any-type",
+        description: "the `any` type",
+        query: r#"((predefined_type) @restricted (#eq? @restricted "any"))"#,
+    },
+    RestrictedSyntaxPreset {
+        name: "as-expression",
+        description: "`as` type assertions (other than `as const`)",
+        // (`as const` has no named type child)
+        query: "(as_expression (_) (_)) @restricted",
+    },
+    RestrictedSyntaxPreset {
+        name: "const-enum",
+        description: "`const enum` declarations",
+        query: r#"(enum_declaration "const") @restricted"#,
+    },
+    RestrictedSyntaxPreset {
+        name: "decorator",
+        description: "decorators",
+        query: "(decorator) @restricted",
+    },
+    RestrictedSyntaxPreset {
+        name: "enum-declaration",
+        description: "`enum` declarations (including `const enum`)",
+        query: "(enum_declaration) @restricted",
+    },
+    RestrictedSyntaxPreset {
+        name: "namespace-with-body",
+        description: "`namespace`/`module` declarations with a body",
+        query: r#"
+          (internal_module body: (statement_block)) @restricted
+          (module body: (statement_block)) @restricted
+        "#,
+    },
+    RestrictedSyntaxPreset {
+        name: "non-null-assertion",
+        description: "non-null assertions (`foo!`)",
+        query: "(non_null_expression) @restricted",
+    },
+    RestrictedSyntaxPreset {
+        name: "parameter-property",
+        description: "constructor parameter properties (`constructor(private foo)`)",
+        query: r#"
+          (required_parameter (accessibility_modifier)) @restricted
+          (optional_parameter (accessibility_modifier)) @restricted
+          (required_parameter "readonly") @restricted
+          (optional_parameter "readonly") @restricted
+        "#,
+    },
+    RestrictedSyntaxPreset {
+        name: "type-assertion",
+        description: "angle-bracket type assertions (`<Foo>bar`)",
+        query: "(type_assertion) @restricted",
+    },
+];
+
+/// Named query snippets for commonly restricted TS constructs, which can be
+/// used in place of a raw query when configuring `no-restricted-ts-syntax`.
+pub fn restricted_syntax_presets() -> &'static [RestrictedSyntaxPreset] {
+    RESTRICTED_SYNTAX_PRESETS
+}
+
+pub fn get_restricted_syntax_preset(name: &str) -> Option<&'static RestrictedSyntaxPreset> {
+    RESTRICTED_SYNTAX_PRESETS
+        .iter()
+        .find(|preset| preset.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_restricted_syntax_preset() {
+        assert_eq!(
+            get_restricted_syntax_preset("non-null-assertion")
+                .unwrap()
+                .query,
+            "(non_null_expression) @restricted"
+        );
+        assert!(get_restricted_syntax_preset("(non_null_expression)").is_none());
+    }
+
+    #[test]
+    fn test_restricted_syntax_presets_are_sorted() {
+        let names = restricted_syntax_presets()
+            .iter()
+            .map(|preset| preset.name)
+            .collect::<Vec<_>>();
+        let mut sorted = names.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(names, sorted);
+    }
+}
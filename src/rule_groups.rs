@@ -0,0 +1,126 @@
+use crate::{diagnostic_codes::get_rule_category, rule_names::normalize_rule_name};
+
+/// Which cargo feature (if any) a rule is compiled in behind, so that
+/// embedders can leave out groups of rules they don't use.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RuleGroup {
+    Core,
+    Stylistic,
+    TypeAware,
+    Formatting,
+}
+
+impl RuleGroup {
+    pub fn feature_name(self) -> Option<&'static str> {
+        match self {
+            Self::Core => None,
+            Self::Stylistic => Some("stylistic"),
+            Self::TypeAware => Some("type-aware"),
+            Self::Formatting => Some("formatting"),
+        }
+    }
+
+    pub fn is_enabled(self) -> bool {
+        match self {
+            Self::Core => true,
+            Self::Stylistic => cfg!(feature = "stylistic"),
+            Self::TypeAware => cfg!(feature = "type-aware"),
+            Self::Formatting => cfg!(feature = "formatting"),
+        }
+    }
+}
+
+/// The rules that aren't always compiled in. These have to agree with the
+/// `#[cfg(feature = ...)]`s in `rules/mod.rs` and `instantiate_rule()`.
+static RULE_GROUPS: &[(&str, RuleGroup)] = &[
+    ("adjacent-overload-signatures", RuleGroup::Stylistic),
+    ("array-type", RuleGroup::Stylistic),
+    ("ban-tslint-comment", RuleGroup::Stylistic),
+    ("class-literal-property-style", RuleGroup::Stylistic),
+    ("consistent-generic-constructors", RuleGroup::Stylistic),
+    ("consistent-indexed-object-style", RuleGroup::Stylistic),
+    ("consistent-type-definitions", RuleGroup::Stylistic),
+    ("no-empty-function", RuleGroup::Stylistic),
+    ("no-empty-interface", RuleGroup::Stylistic),
+    ("no-inferrable-types", RuleGroup::Stylistic),
+    ("prefer-array-index-at", RuleGroup::Stylistic),
+    ("prefer-function-type", RuleGroup::Stylistic),
+    ("prefer-namespace-keyword", RuleGroup::Stylistic),
+    ("await-thenable", RuleGroup::TypeAware),
+    ("no-array-delete", RuleGroup::TypeAware),
+    ("no-confusing-void-expression", RuleGroup::TypeAware),
+    ("no-meaningless-void-operator", RuleGroup::TypeAware),
+    ("no-misused-promises", RuleGroup::TypeAware),
+    (
+        "no-unnecessary-boolean-literal-compare",
+        RuleGroup::TypeAware,
+    ),
+    ("no-unnecessary-type-assertion", RuleGroup::TypeAware),
+    ("no-unnecessary-type-parameters", RuleGroup::TypeAware),
+    ("no-unsafe-argument", RuleGroup::TypeAware),
+    ("no-unsafe-member-access", RuleGroup::TypeAware),
+    ("prefer-nullish-coalescing", RuleGroup::TypeAware),
+    ("prefer-string-starts-ends-with", RuleGroup::TypeAware),
+    ("restrict-plus-operands", RuleGroup::TypeAware),
+    ("restrict-template-expressions", RuleGroup::TypeAware),
+    ("return-await", RuleGroup::TypeAware),
+    ("no-extra-semi", RuleGroup::Formatting),
+];
+
+/// `None` for names that aren't rules of this plugin (whether or not they're
+/// compiled in).
+pub fn get_rule_group(rule_name: &str) -> Option<RuleGroup> {
+    let rule_name = normalize_rule_name(rule_name);
+    get_rule_category(rule_name)?;
+    Some(
+        RULE_GROUPS
+            .iter()
+            .find(|(name, _)| *name == rule_name)
+            .map_or(RuleGroup::Core, |&(_, group)| group),
+    )
+}
+
+/// Whether this is a rule of this plugin, even if it isn't compiled in (eg
+/// so that disable directives naming it are still recognized).
+pub fn is_known_rule(rule_name: &str) -> bool {
+    get_rule_group(rule_name).is_some()
+}
+
+/// Whether the rule is compiled in, ie is returned by `instantiate()`.
+pub fn is_rule_available(rule_name: &str) -> bool {
+    get_rule_group(rule_name).is_some_and(RuleGroup::is_enabled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{instantiate, rule_names::get_rule_by_name};
+
+    #[test]
+    fn test_get_rule_group() {
+        assert_eq!(get_rule_group("array-type"), Some(RuleGroup::Stylistic));
+        assert_eq!(
+            get_rule_group("@typescript-eslint/return-await"),
+            Some(RuleGroup::TypeAware)
+        );
+        assert_eq!(get_rule_group("ban-types"), Some(RuleGroup::Core));
+        assert_eq!(get_rule_group("nonexistent"), None);
+    }
+
+    #[test]
+    fn test_rule_availability_matches_instantiate() {
+        for &(rule_name, _) in RULE_GROUPS {
+            assert!(is_known_rule(rule_name), "{rule_name}");
+        }
+        for rule in instantiate().rules {
+            assert!(is_rule_available(&rule.meta().name), "{}", rule.meta().name);
+        }
+        for &(rule_name, group) in RULE_GROUPS {
+            assert_eq!(
+                get_rule_by_name(rule_name).is_some(),
+                group.is_enabled(),
+                "{rule_name}"
+            );
+        }
+    }
+}
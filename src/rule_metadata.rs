@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use crate::{
+    diagnostic_codes::{get_diagnostic_codes, get_rule_category, DiagnosticCategory},
+    instantiate,
+    rule_groups::{get_rule_group, RuleGroup},
+    rule_names::get_upstream_rule_name,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RuleMessage {
+    pub message_id: String,
+    pub message: String,
+    pub code: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct RuleMetadata {
+    pub name: String,
+    /// `None` for rules that only this plugin has.
+    pub upstream_name: Option<String>,
+    pub category: DiagnosticCategory,
+    pub group: RuleGroup,
+    pub fixable: bool,
+    /// In diagnostic code order.
+    pub messages: Vec<RuleMessage>,
+}
+
+/// Everything known about each compiled-in rule (see [`instantiate`]),
+/// sorted by name.
+pub fn rules_metadata() -> Vec<RuleMetadata> {
+    let mut rules_metadata = instantiate()
+        .rules
+        .into_iter()
+        .map(|rule| {
+            let meta = rule.meta();
+            let messages = meta
+                .messages
+                .iter()
+                .flatten()
+                .map(|(message_id, message)| (message_id.to_string(), message.to_string()))
+                .collect::<HashMap<_, _>>();
+            RuleMetadata {
+                name: meta.name.clone(),
+                upstream_name: get_upstream_rule_name(&meta.name).map(ToOwned::to_owned),
+                category: get_rule_category(&meta.name)
+                    .unwrap_or_else(|| panic!("no diagnostic codes for {}", meta.name)),
+                group: get_rule_group(&meta.name).unwrap(),
+                fixable: meta.fixable,
+                messages: get_diagnostic_codes(&meta.name)
+                    .unwrap()
+                    .into_iter()
+                    .map(|(message_id, code)| RuleMessage {
+                        message_id: message_id.to_owned(),
+                        message: messages[message_id].clone(),
+                        code,
+                    })
+                    .collect(),
+            }
+        })
+        .collect::<Vec<_>>();
+    rules_metadata.sort_by(|a, b| a.name.cmp(&b.name));
+    rules_metadata
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rules_metadata() {
+        let rules_metadata = rules_metadata();
+        assert_eq!(rules_metadata.len(), instantiate().rules.len());
+        let ban_types = rules_metadata
+            .iter()
+            .find(|rule_metadata| rule_metadata.name == "ban-types")
+            .unwrap();
+        assert_eq!(
+            ban_types.upstream_name.as_deref(),
+            Some("@typescript-eslint/ban-types")
+        );
+        assert_eq!(ban_types.category, DiagnosticCategory::Suspicious);
+        assert_eq!(ban_types.group, RuleGroup::Core);
+        assert!(ban_types.fixable);
+        assert_eq!(ban_types.messages[0].code, "TSL-BAN-TYPES-001");
+        assert_eq!(ban_types.messages[0].message_id, "banned_type_message");
+    }
+}
@@ -0,0 +1,364 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::Rule;
+
+use crate::{instantiate_rule, rule_groups::is_rule_available};
+
+/// Every rule of this plugin (whether or not it's compiled in), with the name
+/// it goes by upstream: in typescript-eslint, or in ESLint itself for core
+/// rules that typescript-eslint doesn't extend. `None` for rules that only
+/// this plugin has.
+static UPSTREAM_RULE_NAMES: &[(&str, Option<&str>)] = &[
+    (
+        "adjacent-overload-signatures",
+        Some("@typescript-eslint/adjacent-overload-signatures"),
+    ),
+    ("array-type", Some("@typescript-eslint/array-type")),
+    ("await-thenable", Some("@typescript-eslint/await-thenable")),
+    ("ban-ts-comment", Some("@typescript-eslint/ban-ts-comment")),
+    (
+        "ban-tslint-comment",
+        Some("@typescript-eslint/ban-tslint-comment"),
+    ),
+    ("ban-types", Some("@typescript-eslint/ban-types")),
+    (
+        "class-literal-property-style",
+        Some("@typescript-eslint/class-literal-property-style"),
+    ),
+    (
+        "class-methods-use-this",
+        Some("@typescript-eslint/class-methods-use-this"),
+    ),
+    (
+        "consistent-generic-constructors",
+        Some("@typescript-eslint/consistent-generic-constructors"),
+    ),
+    (
+        "consistent-indexed-object-style",
+        Some("@typescript-eslint/consistent-indexed-object-style"),
+    ),
+    (
+        "consistent-type-definitions",
+        Some("@typescript-eslint/consistent-type-definitions"),
+    ),
+    (
+        "default-param-last",
+        Some("@typescript-eslint/default-param-last"),
+    ),
+    (
+        "explicit-function-return-type",
+        Some("@typescript-eslint/explicit-function-return-type"),
+    ),
+    (
+        "explicit-module-boundary-types",
+        Some("@typescript-eslint/explicit-module-boundary-types"),
+    ),
+    (
+        "member-ordering",
+        Some("@typescript-eslint/member-ordering"),
+    ),
+    (
+        "method-signature-style",
+        Some("@typescript-eslint/method-signature-style"),
+    ),
+    (
+        "naming-convention",
+        Some("@typescript-eslint/naming-convention"),
+    ),
+    (
+        "no-array-constructor",
+        Some("@typescript-eslint/no-array-constructor"),
+    ),
+    (
+        "no-array-delete",
+        Some("@typescript-eslint/no-array-delete"),
+    ),
+    ("no-array-method-misuse", None),
+    (
+        "no-async-promise-executor",
+        Some("no-async-promise-executor"),
+    ),
+    (
+        "no-confusing-void-expression",
+        Some("@typescript-eslint/no-confusing-void-expression"),
+    ),
+    ("no-constant-condition", Some("no-constant-condition")),
+    (
+        "no-dynamic-delete",
+        Some("@typescript-eslint/no-dynamic-delete"),
+    ),
+    (
+        "no-empty-function",
+        Some("@typescript-eslint/no-empty-function"),
+    ),
+    (
+        "no-empty-interface",
+        Some("@typescript-eslint/no-empty-interface"),
+    ),
+    ("no-extra-semi", Some("@typescript-eslint/no-extra-semi")),
+    (
+        "no-extraneous-class",
+        Some("@typescript-eslint/no-extraneous-class"),
+    ),
+    (
+        "no-inferrable-types",
+        Some("@typescript-eslint/no-inferrable-types"),
+    ),
+    (
+        "no-invalid-this",
+        Some("@typescript-eslint/no-invalid-this"),
+    ),
+    (
+        "no-loss-of-precision",
+        Some("@typescript-eslint/no-loss-of-precision"),
+    ),
+    (
+        "no-magic-numbers",
+        Some("@typescript-eslint/no-magic-numbers"),
+    ),
+    (
+        "no-meaningless-void-operator",
+        Some("@typescript-eslint/no-meaningless-void-operator"),
+    ),
+    ("no-misused-new", Some("@typescript-eslint/no-misused-new")),
+    (
+        "no-misused-promises",
+        Some("@typescript-eslint/no-misused-promises"),
+    ),
+    ("no-namespace", Some("@typescript-eslint/no-namespace")),
+    (
+        "no-non-null-asserted-nullish-coalescing",
+        Some("@typescript-eslint/no-non-null-asserted-nullish-coalescing"),
+    ),
+    (
+        "no-non-null-asserted-optional-chain",
+        Some("@typescript-eslint/no-non-null-asserted-optional-chain"),
+    ),
+    (
+        "no-non-null-assertion",
+        Some("@typescript-eslint/no-non-null-assertion"),
+    ),
+    (
+        "no-require-imports",
+        Some("@typescript-eslint/no-require-imports"),
+    ),
+    ("no-restricted-ts-syntax", None),
+    ("no-shadow", Some("@typescript-eslint/no-shadow")),
+    ("no-this-alias", Some("@typescript-eslint/no-this-alias")),
+    ("no-type-alias", Some("@typescript-eslint/no-type-alias")),
+    (
+        "no-unnecessary-boolean-literal-compare",
+        Some("@typescript-eslint/no-unnecessary-boolean-literal-compare"),
+    ),
+    (
+        "no-unnecessary-type-assertion",
+        Some("@typescript-eslint/no-unnecessary-type-assertion"),
+    ),
+    (
+        "no-unnecessary-type-parameters",
+        Some("@typescript-eslint/no-unnecessary-type-parameters"),
+    ),
+    (
+        "no-unsafe-argument",
+        Some("@typescript-eslint/no-unsafe-argument"),
+    ),
+    (
+        "no-unsafe-member-access",
+        Some("@typescript-eslint/no-unsafe-member-access"),
+    ),
+    (
+        "no-unused-private-class-members",
+        Some("@typescript-eslint/no-unused-private-class-members"),
+    ),
+    (
+        "no-useless-constructor",
+        Some("@typescript-eslint/no-useless-constructor"),
+    ),
+    (
+        "no-var-requires",
+        Some("@typescript-eslint/no-var-requires"),
+    ),
+    (
+        "parameter-properties",
+        Some("@typescript-eslint/parameter-properties"),
+    ),
+    ("prefer-array-index-at", None),
+    ("prefer-const", Some("prefer-const")),
+    (
+        "prefer-function-type",
+        Some("@typescript-eslint/prefer-function-type"),
+    ),
+    (
+        "prefer-literal-enum-member",
+        Some("@typescript-eslint/prefer-literal-enum-member"),
+    ),
+    (
+        "prefer-namespace-keyword",
+        Some("@typescript-eslint/prefer-namespace-keyword"),
+    ),
+    (
+        "prefer-nullish-coalescing",
+        Some("@typescript-eslint/prefer-nullish-coalescing"),
+    ),
+    (
+        "prefer-string-starts-ends-with",
+        Some("@typescript-eslint/prefer-string-starts-ends-with"),
+    ),
+    (
+        "restrict-plus-operands",
+        Some("@typescript-eslint/restrict-plus-operands"),
+    ),
+    (
+        "restrict-template-expressions",
+        Some("@typescript-eslint/restrict-template-expressions"),
+    ),
+    ("return-await", Some("@typescript-eslint/return-await")),
+    ("typedef", Some("@typescript-eslint/typedef")),
+];
+
+pub(crate) fn get_rule_names() -> impl Iterator<Item = &'static str> {
+    UPSTREAM_RULE_NAMES.iter().map(|&(rule_name, _)| rule_name)
+}
+
+/// The rule of this plugin (whether or not it's compiled in) that goes by
+/// the given name upstream.
+pub fn get_rule_name_for_upstream_name(upstream_name: &str) -> Option<&'static str> {
+    UPSTREAM_RULE_NAMES
+        .iter()
+        .find(|&&(_, name)| name == Some(upstream_name))
+        .map(|&(rule_name, _)| rule_name)
+}
+
+/// Maps an upstream rule name (eg `@typescript-eslint/array-type`) onto the
+/// name this plugin registers the rule under (eg `array-type`). Other names
+/// are returned as-is.
+pub fn normalize_rule_name(rule_name: &str) -> &str {
+    get_rule_name_for_upstream_name(rule_name).unwrap_or(rule_name)
+}
+
+/// The name the rule goes by upstream, eg for matching against shared configs
+/// or disable comments written for ESLint.
+pub fn get_upstream_rule_name(rule_name: &str) -> Option<&'static str> {
+    let rule_name = normalize_rule_name(rule_name);
+    UPSTREAM_RULE_NAMES
+        .iter()
+        .find(|&&(name, _)| name == rule_name)
+        .and_then(|&(_, upstream_name)| upstream_name)
+}
+
+/// Instantiates just the named rule, if it's compiled in.
+pub fn get_rule_by_name(rule_name: &str) -> Option<Arc<dyn Rule>> {
+    instantiate_rule(normalize_rule_name(rule_name))
+}
+
+/// `(name, upstream name)` for every compiled-in rule that has an upstream
+/// counterpart.
+pub fn get_upstream_rule_names() -> Vec<(&'static str, &'static str)> {
+    UPSTREAM_RULE_NAMES
+        .iter()
+        .filter(|&&(rule_name, _)| is_rule_available(rule_name))
+        .filter_map(|&(rule_name, upstream_name)| Some((rule_name, upstream_name?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::{instantiate, is_known_rule};
+
+    #[test]
+    fn test_normalize_rule_name() {
+        assert_eq!(normalize_rule_name("array-type"), "array-type");
+        assert_eq!(
+            normalize_rule_name("@typescript-eslint/array-type"),
+            "array-type"
+        );
+        assert_eq!(
+            normalize_rule_name("@typescript-eslint-foo/array-type"),
+            "@typescript-eslint-foo/array-type"
+        );
+        assert_eq!(normalize_rule_name("prefer-const"), "prefer-const");
+        assert_eq!(
+            normalize_rule_name("@typescript-eslint/prefer-const"),
+            "@typescript-eslint/prefer-const"
+        );
+    }
+
+    #[test]
+    fn test_get_upstream_rule_name() {
+        assert_eq!(
+            get_upstream_rule_name("array-type"),
+            Some("@typescript-eslint/array-type")
+        );
+        assert_eq!(
+            get_upstream_rule_name("@typescript-eslint/array-type"),
+            Some("@typescript-eslint/array-type")
+        );
+        assert_eq!(get_upstream_rule_name("prefer-const"), Some("prefer-const"));
+        assert_eq!(get_upstream_rule_name("no-restricted-ts-syntax"), None);
+        assert_eq!(get_upstream_rule_name("nonexistent"), None);
+    }
+
+    #[cfg(feature = "stylistic")]
+    #[test]
+    fn test_get_rule_by_name() {
+        for rule_name in ["array-type", "@typescript-eslint/array-type"] {
+            assert_eq!(
+                get_rule_by_name(rule_name).unwrap().meta().name,
+                "array-type"
+            );
+        }
+        assert!(get_rule_by_name("@typescript-eslint/nonexistent").is_none());
+        assert!(get_rule_by_name("eslint/array-type").is_none());
+    }
+
+    #[test]
+    fn test_get_rule_by_name_for_core_rule() {
+        assert_eq!(
+            get_rule_by_name("prefer-const").unwrap().meta().name,
+            "prefer-const"
+        );
+        assert!(get_rule_by_name("@typescript-eslint/prefer-const").is_none());
+    }
+
+    #[test]
+    fn test_get_upstream_rule_names() {
+        let upstream_rule_names = get_upstream_rule_names();
+        assert!(upstream_rule_names.contains(&("ban-types", "@typescript-eslint/ban-types")));
+        assert!(upstream_rule_names.contains(&("prefer-const", "prefer-const")));
+        assert!(!upstream_rule_names
+            .iter()
+            .any(|&(rule_name, _)| rule_name == "no-restricted-ts-syntax"));
+        for (rule_name, _) in upstream_rule_names {
+            assert!(get_rule_by_name(rule_name).is_some(), "{rule_name}");
+        }
+    }
+
+    #[test]
+    fn test_upstream_rule_names_cover_every_rule() {
+        assert_eq!(
+            get_rule_names().collect::<HashSet<_>>().len(),
+            UPSTREAM_RULE_NAMES.len()
+        );
+        for rule_name in get_rule_names() {
+            assert!(is_known_rule(rule_name), "{rule_name}");
+        }
+        for rule in instantiate().rules {
+            assert!(
+                get_rule_names().any(|rule_name| rule_name == rule.meta().name),
+                "{}",
+                rule.meta().name
+            );
+        }
+        let upstream_names = UPSTREAM_RULE_NAMES
+            .iter()
+            .filter_map(|&(_, upstream_name)| upstream_name)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            upstream_names.iter().collect::<HashSet<_>>().len(),
+            upstream_names.len()
+        );
+    }
+}
@@ -0,0 +1,162 @@
+//! Helpers for scanning the rule sources as text, eg for the cases of their
+//! `rule_tests!`.
+
+use std::path::{Path, PathBuf};
+
+pub fn get_rule_source_path(rule_name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src/rules")
+        .join(format!("{}.rs", rule_name.replace('-', "_")))
+}
+
+/// If a (possibly raw) string literal starts at `index`, the index just past
+/// its end.
+fn get_string_literal_end(source: &str, index: usize) -> Option<usize> {
+    let bytes = source.as_bytes();
+    let hashes = match bytes[index] {
+        b'"' => None,
+        b'r' if index == 0
+            || !(bytes[index - 1].is_ascii_alphanumeric() || bytes[index - 1] == b'_') =>
+        {
+            let hashes = source[index + 1..]
+                .bytes()
+                .take_while(|&byte| byte == b'#')
+                .count();
+            if bytes.get(index + 1 + hashes) != Some(&b'"') {
+                return None;
+            }
+            Some(hashes)
+        }
+        _ => return None,
+    };
+    let contents_start = index + 1 + hashes.map_or(0, |hashes| hashes + 1);
+    match hashes {
+        Some(hashes) => {
+            let terminator = format!("\"{}", "#".repeat(hashes));
+            Some(contents_start + source[contents_start..].find(&terminator)? + terminator.len())
+        }
+        None => {
+            let mut index = contents_start;
+            while index < bytes.len() {
+                match bytes[index] {
+                    b'\\' => index += 2,
+                    b'"' => return Some(index + 1),
+                    _ => index += 1,
+                }
+            }
+            None
+        }
+    }
+}
+
+fn get_line_end(source: &str, index: usize) -> usize {
+    source[index..]
+        .find('\n')
+        .map_or(source.len(), |offset| index + offset)
+}
+
+/// The index just past the bracket/brace that closes the one ending just
+/// before `start`, skipping over string literals.
+fn find_closing(source: &str, start: usize) -> usize {
+    let mut depth = 1;
+    let mut index = start;
+    while index < source.len() {
+        if let Some(end) = get_string_literal_end(source, index) {
+            index = end;
+            continue;
+        }
+        if source[index..].starts_with("//") {
+            index = get_line_end(source, index);
+            continue;
+        }
+        match source.as_bytes()[index] {
+            b'[' | b'{' | b'(' => depth += 1,
+            b']' | b'}' | b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return index + 1;
+                }
+            }
+            _ => (),
+        }
+        index += 1;
+    }
+    panic!("unbalanced brackets");
+}
+
+/// Parses the (possibly raw) Rust string literal at the start of `literal`.
+pub fn parse_string_literal(literal: &str) -> Option<String> {
+    let literal = literal.trim_start();
+    if let Some(raw) = literal.strip_prefix('r') {
+        let hashes = raw.bytes().take_while(|&byte| byte == b'#').count();
+        let contents = raw[hashes..].strip_prefix('"')?;
+        let terminator = format!("\"{}", "#".repeat(hashes));
+        return Some(contents[..contents.find(&terminator)?].to_owned());
+    }
+    let mut chars = literal.strip_prefix('"')?.chars();
+    let mut value = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return Some(value),
+            '\\' => match chars.next()? {
+                'n' => value.push('\n'),
+                't' => value.push('\t'),
+                'r' => value.push('\r'),
+                '0' => value.push('\0'),
+                '\n' => {
+                    chars = chars.as_str().trim_start().chars();
+                }
+                'u' => {
+                    let rest = chars.as_str().strip_prefix('{')?;
+                    let end = rest.find('}')?;
+                    value.push(char::from_u32(u32::from_str_radix(&rest[..end], 16).ok()?)?);
+                    chars = rest[end + 1..].chars();
+                }
+                escaped => value.push(escaped),
+            },
+            c => value.push(c),
+        }
+    }
+    None
+}
+
+/// The top-level comma-separated items of a list, given the source just past
+/// its opening bracket.
+pub fn get_list_items(source: &str) -> Vec<&str> {
+    let end = find_closing(source, 0) - 1;
+    let mut items: Vec<&str> = Default::default();
+    let mut item_start = 0;
+    let mut index = 0;
+    while index < end {
+        if let Some(string_literal_end) = get_string_literal_end(source, index) {
+            index = string_literal_end;
+            continue;
+        }
+        if source[index..].starts_with("//") {
+            index = get_line_end(source, index);
+            continue;
+        }
+        index = match source.as_bytes()[index] {
+            b'[' | b'{' | b'(' => find_closing(source, index + 1),
+            b',' => {
+                items.push(&source[item_start..index]);
+                item_start = index + 1;
+                index + 1
+            }
+            _ => index + 1,
+        };
+    }
+    items.push(&source[item_start..end]);
+    items
+        .into_iter()
+        .map(|item| {
+            // skip leading comments
+            let mut item = item.trim();
+            while item.starts_with("//") {
+                item = item[get_line_end(item, 0)..].trim();
+            }
+            item
+        })
+        .filter(|item| !item.is_empty())
+        .collect()
+}
@@ -12,8 +12,8 @@ use tree_sitter_lint_plugin_eslint_builtin::kind::{
 use crate::{
     ast_helpers::is_member_static,
     kind::{
-        AmbientDeclaration, CallSignature, ConstructSignature, FunctionSignature, MethodSignature,
-        ObjectType,
+        AbstractMethodSignature, AmbientDeclaration, CallSignature, ConstructSignature,
+        FunctionSignature, InterfaceBody, InterfaceDeclaration, MethodSignature, ObjectType,
     },
     util::{get_name_from_member, MemberName, MemberNameType},
 };
@@ -43,7 +43,7 @@ fn get_member_method<'a>(
             call_signature: false,
             type_: MemberNameType::Normal,
         }),
-        MethodDefinition | MethodSignature => {
+        MethodDefinition | MethodSignature | AbstractMethodSignature => {
             let MemberName { type_, name } = get_name_from_member(member, context);
             Some(Method {
                 name,
@@ -79,7 +79,7 @@ fn is_same_method(method1: &Method, method2: Option<&Method>) -> bool {
 
 fn get_members(node: Node) -> impl Iterator<Item = Node> {
     match node.kind() {
-        ObjectType | StatementBlock | Program | ClassBody => {
+        ObjectType | InterfaceBody | StatementBlock | Program | ClassBody => {
             node.non_comment_named_children(SupportedLanguage::Javascript)
         }
         _ => unreachable!(),
@@ -140,6 +140,19 @@ pub fn adjacent_overload_signatures_rule() -> Arc<dyn Rule> {
               (statement_block) @c
               (program) @c
               (class_body) @c
+            "# => |node, context| {
+                // (handled below)
+                if node.parent().matches(|parent| parent.kind() == InterfaceDeclaration) {
+                    return;
+                }
+                check_body_for_overload_methods(node, context);
+            },
+            // depending on the grammar version an interface's body is either
+            // an `object_type` or an `interface_body`
+            r#"
+              (interface_declaration
+                body: (_) @c
+              )
             "# => |node, context| {
                 check_body_for_overload_methods(node, context);
             },
@@ -147,29 +160,24 @@ pub fn adjacent_overload_signatures_rule() -> Arc<dyn Rule> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use tree_sitter_lint::{rule_tests, RuleTester};
-
-    use super::*;
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
 
-    #[test]
-    fn test_adjacent_overload_signatures_rule() {
-        RuleTester::run(
-            adjacent_overload_signatures_rule(),
-            rule_tests! {
-                valid => [
-                    {
-                      code => r#"
+    pub fn adjacent_overload_signatures_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                {
+                  code => r#"
 			function error(a: string);
 			function error(b: number);
 			function error(ab: string | number) {}
 			export { error };
                       "#,
-                      environment => { source_type => "module" },
-                    },
-                    {
-                      code => r#"
+                  environment => { source_type => "module" },
+                },
+                {
+                  code => r#"
 			import { connect } from 'react-redux';
 			export interface ErrorMessageModel {
 			  message: string;
@@ -178,65 +186,65 @@ mod tests {
 			function mapDispatchToProps() {}
 			export default connect(mapStateToProps, mapDispatchToProps)(ErrorMessage);
                       "#,
-                      environment => { source_type => "module" },
-                    },
-                    r#"
+                  environment => { source_type => "module" },
+                },
+                r#"
 			export const foo = 'a',
 			  bar = 'b';
 			export interface Foo {}
 			export class Foo {}
                     "#,
-                    r#"
+                r#"
 			export interface Foo {}
 			export const foo = 'a',
 			  bar = 'b';
 			export class Foo {}
                     "#,
-                    r#"
+                r#"
 			const foo = 'a',
 			  bar = 'b';
 			interface Foo {}
 			class Foo {}
                     "#,
-                    r#"
+                r#"
 			interface Foo {}
 			const foo = 'a',
 			  bar = 'b';
 			class Foo {}
                     "#,
-                    r#"
+                r#"
 			export class Foo {}
 			export class Bar {}
 			export type FooBar = Foo | Bar;
                     "#,
-                    r#"
+                r#"
 			export interface Foo {}
 			export class Foo {}
 			export class Bar {}
 			export type FooBar = Foo | Bar;
                     "#,
-                    r#"
+                r#"
 			export function foo(s: string);
 			export function foo(n: number);
 			export function foo(sn: string | number) {}
 			export function bar(): void {}
 			export function baz(): void {}
                     "#,
-                    r#"
+                r#"
 			function foo(s: string);
 			function foo(n: number);
 			function foo(sn: string | number) {}
 			function bar(): void {}
 			function baz(): void {}
                     "#,
-                    r#"
+                r#"
 			declare function foo(s: string);
 			declare function foo(n: number);
 			declare function foo(sn: string | number);
 			declare function bar(): void;
 			declare function baz(): void;
                     "#,
-                    r#"
+                r#"
 			declare module 'Foo' {
 			  export function foo(s: string): void;
 			  export function foo(n: number): void;
@@ -245,7 +253,7 @@ mod tests {
 			  export function baz(): void;
 			}
                     "#,
-                    r#"
+                r#"
 			declare namespace Foo {
 			  export function foo(s: string): void;
 			  export function foo(n: number): void;
@@ -254,7 +262,7 @@ mod tests {
 			  export function baz(): void;
 			}
                     "#,
-                    r#"
+                r#"
 			type Foo = {
 			  foo(s: string): void;
 			  foo(n: number): void;
@@ -263,7 +271,7 @@ mod tests {
 			  baz(): void;
 			};
                     "#,
-                    r#"
+                r#"
 			type Foo = {
 			  foo(s: string): void;
 			  ['foo'](n: number): void;
@@ -272,7 +280,7 @@ mod tests {
 			  baz(): void;
 			};
                     "#,
-                    r#"
+                r#"
 			interface Foo {
 			  (s: string): void;
 			  (n: number): void;
@@ -282,7 +290,7 @@ mod tests {
 			  baz(): void;
 			}
                     "#,
-                    r#"
+                r#"
 			interface Foo {
 			  (s: string): void;
 			  (n: number): void;
@@ -293,7 +301,7 @@ mod tests {
 			  call(): void;
 			}
                     "#,
-                    r#"
+                r#"
 			interface Foo {
 			  foo(s: string): void;
 			  foo(n: number): void;
@@ -302,7 +310,7 @@ mod tests {
 			  baz(): void;
 			}
                     "#,
-                    r#"
+                r#"
 			interface Foo {
 			  foo(s: string): void;
 			  ['foo'](n: number): void;
@@ -311,7 +319,7 @@ mod tests {
 			  baz(): void;
 			}
                     "#,
-                    r#"
+                r#"
 			interface Foo {
 			  foo(): void;
 			  bar: {
@@ -321,7 +329,7 @@ mod tests {
 			  };
 			}
                     "#,
-                    r#"
+                r#"
 			interface Foo {
 			  new (s: string);
 			  new (n: number);
@@ -329,7 +337,7 @@ mod tests {
 			  foo(): void;
 			}
                     "#,
-                    r#"
+                r#"
 			class Foo {
 			  constructor(s: string);
 			  constructor(n: number);
@@ -338,7 +346,7 @@ mod tests {
 			  baz(): void {}
 			}
                     "#,
-                    r#"
+                r#"
 			class Foo {
 			  foo(s: string): void;
 			  foo(n: number): void;
@@ -347,7 +355,7 @@ mod tests {
 			  baz(): void {}
 			}
                     "#,
-                    r#"
+                r#"
 			class Foo {
 			  foo(s: string): void;
 			  ['foo'](n: number): void;
@@ -356,7 +364,7 @@ mod tests {
 			  baz(): void {}
 			}
                     "#,
-                    r#"
+                r#"
 			class Foo {
 			  name => string;
 			  foo(s: string): void;
@@ -366,7 +374,7 @@ mod tests {
 			  baz(): void {}
 			}
                     "#,
-                    r#"
+                r#"
 			class Foo {
 			  name => string;
 			  static foo(s: string): void;
@@ -376,24 +384,24 @@ mod tests {
 			  baz(): void {}
 			}
                     "#,
-                    r#"
+                r#"
 			class Test {
 			  static test() {}
 			  untest() {}
 			  test() {}
 			}
                     "#,
-                    // examples from https://github.com/nzakas/eslint-plugin-typescript/issues/138
-                    "export default function <T>(foo: T) {}",
-                    "export default function named<T>(foo: T) {}",
-                    r#"
+                // examples from https://github.com/nzakas/eslint-plugin-typescript/issues/138
+                "export default function <T>(foo: T) {}",
+                "export default function named<T>(foo: T) {}",
+                r#"
 			interface Foo {
 			  [Symbol.toStringTag](): void;
 			  [Symbol.iterator](): void;
 			}
                     "#,
-                    // private members
-                    r#"
+                // private members
+                r#"
 			class Test {
 			  #private(): void;
 			  #private(arg: number): void {}
@@ -404,25 +412,89 @@ mod tests {
 			  '#private'(arg: number): void {}
 			}
                     "#,
-                    // block statement
-                    r#"
+                // block statement
+                r#"
 			function wrap() {
 			  function foo(s: string);
 			  function foo(n: number);
 			  function foo(sn: string | number) {}
 			}
                     "#,
-                    r#"
+                r#"
 			if (true) {
 			  function foo(s: string);
 			  function foo(n: number);
 			  function foo(sn: string | number) {}
 			}
                     "#,
+                r#"
+interface Foo {
+  foo(s: string): void;
+  foo(n: number): void;
+  bar: string;
+  baz(): void;
+}
+                    "#,
+              ],
+            invalid => [
+                {
+                  code => r#"
+interface Foo {
+  foo(s: string): void;
+  bar: string;
+  foo(n: number): void;
+}
+                      "#,
+                  errors => [
+                    {
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 5,
+                      column => 3,
+                    },
                   ],
-                invalid => [
+                },
+                {
+                  code => r#"
+export interface Foo {
+  foo(s: string): void;
+  [key: string]: unknown;
+  foo(n: number): void;
+}
+                      "#,
+                  errors => [{ message_id => "adjacent_signature", line => 5, column => 3 }],
+                },
+                {
+                  code => r#"
+declare global {
+  interface Window {
+    foo(s: string): void;
+    readonly bar: number;
+    foo(n: number): void;
+  }
+}
+                      "#,
+                  errors => [{ message_id => "adjacent_signature", line => 6, column => 5 }],
+                },
+                {
+                  code => r#"
+abstract class Foo {
+  abstract foo(s: string): void;
+  abstract bar(): void;
+  abstract foo(n: number): void;
+}
+                      "#,
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 5,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 function wrap() {
   function foo(s: string);
   function foo(n: number);
@@ -430,17 +502,17 @@ function wrap() {
   function foo(sn: string | number) {}
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 6,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 6,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 if (true) {
   function foo(s: string);
   function foo(n: number);
@@ -449,118 +521,118 @@ if (true) {
   foo(a);
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 6,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 6,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 export function foo(s: string);
 export function foo(n: number);
 export function bar(): void {}
 export function baz(): void {}
 export function foo(sn: string | number) {}
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 6,
-                          column => 1,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 6,
+                      column => 1,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 export function foo(s: string);
 export function foo(n: number);
 export type bar = number;
 export type baz = number | string;
 export function foo(sn: string | number) {}
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 6,
-                          column => 1,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 6,
+                      column => 1,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 function foo(s: string);
 function foo(n: number);
 function bar(): void {}
 function baz(): void {}
 function foo(sn: string | number) {}
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 6,
-                          column => 1,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 6,
+                      column => 1,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 function foo(s: string);
 function foo(n: number);
 type bar = number;
 type baz = number | string;
 function foo(sn: string | number) {}
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 6,
-                          column => 1,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 6,
+                      column => 1,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 function foo(s: string) {}
 function foo(n: number) {}
 const a = '';
 const b = '';
 function foo(sn: string | number) {}
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 6,
-                          column => 1,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 6,
+                      column => 1,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 function foo(s: string) {}
 function foo(n: number) {}
 class Bar {}
 function foo(sn: string | number) {}
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 5,
-                          column => 1,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 5,
+                      column => 1,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 function foo(s: string) {}
 function foo(n: number) {}
 function foo(sn: string | number) {}
@@ -571,51 +643,51 @@ class Bar {
   foo(sn: string | number) {}
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 9,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 9,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 declare function foo(s: string);
 declare function foo(n: number);
 declare function bar(): void;
 declare function baz(): void;
 declare function foo(sn: string | number);
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 6,
-                          column => 1,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 6,
+                      column => 1,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 declare function foo(s: string);
 declare function foo(n: number);
 const a = '';
 const b = '';
 declare function foo(sn: string | number);
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 6,
-                          column => 1,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 6,
+                      column => 1,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 declare module 'Foo' {
   export function foo(s: string): void;
   export function foo(n: number): void;
@@ -624,17 +696,17 @@ declare module 'Foo' {
   export function foo(sn: string | number): void;
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 7,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 7,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 declare module 'Foo' {
   export function foo(s: string): void;
   export function foo(n: number): void;
@@ -645,17 +717,17 @@ declare module 'Foo' {
   function baz(sn: string | number): void;
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "baz" },
-                          line => 8,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "baz" },
+                      line => 8,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 declare namespace Foo {
   export function foo(s: string): void;
   export function foo(n: number): void;
@@ -664,17 +736,17 @@ declare namespace Foo {
   export function foo(sn: string | number): void;
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 7,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 7,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 declare namespace Foo {
   export function foo(s: string): void;
   export function foo(n: number): void;
@@ -685,17 +757,17 @@ declare namespace Foo {
   function baz(sn: string | number): void;
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "baz" },
-                          line => 8,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "baz" },
+                      line => 8,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 type Foo = {
   foo(s: string): void;
   foo(n: number): void;
@@ -704,17 +776,17 @@ type Foo = {
   foo(sn: string | number): void;
 };
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 7,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 7,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 type Foo = {
   foo(s: string): void;
   ['foo'](n: number): void;
@@ -723,17 +795,17 @@ type Foo = {
   foo(sn: string | number): void;
 };
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 7,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 7,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 type Foo = {
   foo(s: string): void;
   name => string;
@@ -743,17 +815,17 @@ type Foo = {
   baz(): void;
 };
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 5,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 5,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 interface Foo {
   (s: string): void;
   foo(n: number): void;
@@ -764,17 +836,17 @@ interface Foo {
   call(): void;
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "call" },
-                          line => 5,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "call" },
+                      line => 5,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 interface Foo {
   foo(s: string): void;
   foo(n: number): void;
@@ -783,17 +855,17 @@ interface Foo {
   foo(sn: string | number): void;
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 7,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 7,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 interface Foo {
   foo(s: string): void;
   ['foo'](n: number): void;
@@ -802,17 +874,17 @@ interface Foo {
   foo(sn: string | number): void;
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 7,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 7,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 interface Foo {
   foo(s: string): void;
   'foo'(n: number): void;
@@ -821,17 +893,17 @@ interface Foo {
   foo(sn: string | number): void;
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 7,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 7,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 interface Foo {
   foo(s: string): void;
   name => string;
@@ -841,17 +913,17 @@ interface Foo {
   baz(): void;
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 5,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 5,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 interface Foo {
   foo(): void;
   bar: {
@@ -862,17 +934,17 @@ interface Foo {
   };
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "baz" },
-                          line => 8,
-                          column => 5,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "baz" },
+                      line => 8,
+                      column => 5,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 interface Foo {
   new (s: string);
   new (n: number);
@@ -881,17 +953,17 @@ interface Foo {
   new (sn: string | number);
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "new" },
-                          line => 7,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "new" },
+                      line => 7,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 interface Foo {
   new (s: string);
   foo(): void;
@@ -900,23 +972,23 @@ interface Foo {
   new (sn: string | number);
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "new" },
-                          line => 5,
-                          column => 3,
-                        },
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "new" },
-                          line => 7,
-                          column => 3,
-                        },
-                      ],
+                  errors => [
+                    {
+                      message_id => "adjacent_signature",
+                      data => { name => "new" },
+                      line => 5,
+                      column => 3,
                     },
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "new" },
+                      line => 7,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 class Foo {
   constructor(s: string);
   constructor(n: number);
@@ -925,17 +997,17 @@ class Foo {
   constructor(sn: string | number) {}
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "constructor" },
-                          line => 7,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "constructor" },
+                      line => 7,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 class Foo {
   foo(s: string): void;
   foo(n: number): void;
@@ -944,17 +1016,17 @@ class Foo {
   foo(sn: string | number): void {}
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 7,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 7,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 class Foo {
   foo(s: string): void;
   ['foo'](n: number): void;
@@ -963,17 +1035,17 @@ class Foo {
   foo(sn: string | number): void {}
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 7,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 7,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 class Foo {
   // prettier-ignore
   "foo"(s: string): void;
@@ -983,17 +1055,17 @@ class Foo {
   foo(sn: string | number): void {}
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 8,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 8,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 class Foo {
   constructor(s: string);
   name => string;
@@ -1003,17 +1075,17 @@ class Foo {
   baz(): void {}
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "constructor" },
-                          line => 5,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "constructor" },
+                      line => 5,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 class Foo {
   foo(s: string): void;
   name => string;
@@ -1023,17 +1095,17 @@ class Foo {
   baz(): void {}
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "foo" },
-                          line => 5,
-                          column => 3,
-                        },
-                      ],
-                    },
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "foo" },
+                      line => 5,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => r#"
 class Foo {
   static foo(s: string): void;
   name => string;
@@ -1043,18 +1115,18 @@ class Foo {
   baz(): void {}
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "static foo" },
-                          line => 5,
-                          column => 3,
-                        },
-                      ],
-                    },
-                    // private members
+                  errors => [
                     {
-                      code => r#"
+                      message_id => "adjacent_signature",
+                      data => { name => "static foo" },
+                      line => 5,
+                      column => 3,
+                    },
+                  ],
+                },
+                // private members
+                {
+                  code => r#"
 class Test {
   #private(): void;
   '#private'(): void;
@@ -1062,23 +1134,36 @@ class Test {
   '#private'(arg: number): void {}
 }
                       "#,
-                      errors => [
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "#private" },
-                          line => 5,
-                          column => 3,
-                        },
-                        {
-                          message_id => "adjacent_signature",
-                          data => { name => "\"#private\"" },
-                          line => 6,
-                          column => 3,
-                        },
-                      ],
+                  errors => [
+                    {
+                      message_id => "adjacent_signature",
+                      data => { name => "#private" },
+                      line => 5,
+                      column => 3,
+                    },
+                    {
+                      message_id => "adjacent_signature",
+                      data => { name => "\"#private\"" },
+                      line => 6,
+                      column => 3,
                     },
                   ],
-            },
-        )
+                },
+              ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_adjacent_overload_signatures_rule() {
+        run_for_typescript_and_tsx(
+            adjacent_overload_signatures_rule(),
+            test_cases::adjacent_overload_signatures_rule_tests(),
+        );
     }
 }
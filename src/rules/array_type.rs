@@ -6,26 +6,27 @@ use tree_sitter_lint::{
     range_between_ends, range_between_starts, rule, tree_sitter::Node,
     tree_sitter_grep::SupportedLanguage, violation, NodeExt, QueryMatchContext, Rule,
 };
-use tree_sitter_lint_plugin_eslint_builtin::kind::{Identifier, Undefined};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{Identifier, Null, Undefined};
 
 use crate::{
     ast_helpers::NodeExtTypescript,
     kind::{
-        ArrayType, ConstructorType, FunctionType, GenericType, InferType, IntersectionType,
-        LiteralType, NestedTypeIdentifier, PredefinedType, ReadonlyType, ThisType, TypeIdentifier,
-        UnionType,
+        ArrayType, ConditionalType, ConstructorType, FunctionType, GenericType, IndexTypeQuery,
+        InferType, IntersectionType, LiteralType, LookupType, NestedTypeIdentifier, PredefinedType,
+        ReadonlyType, ThisType, TypeIdentifier, UnionType,
     },
 };
 
 fn is_simple_type<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    let node = node.skip_parenthesized_types();
     match node.kind() {
         Identifier | PredefinedType | ArrayType | ThisType | TypeIdentifier
         | NestedTypeIdentifier => true,
-        LiteralType => {
+        LiteralType => matches!(
             node.first_non_comment_named_child(SupportedLanguage::Javascript)
-                .kind()
-                == Undefined
-        }
+                .kind(),
+            Undefined | Null
+        ),
         GenericType => {
             node.field("name")
                 .thrush(|name| name.kind() == TypeIdentifier && name.text(context) == "Array")
@@ -49,13 +50,26 @@ fn is_simple_type<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bo
 fn type_needs_parentheses<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
     match node.kind() {
         GenericType => type_needs_parentheses(node.field("name"), context),
-        UnionType | FunctionType | IntersectionType | InferType | ConstructorType => true,
+        UnionType | FunctionType | IntersectionType | InferType | ConstructorType
+        | ConditionalType | ReadonlyType | IndexTypeQuery => true,
         TypeIdentifier => node.text(context) == "ReadonlyArray",
         _ => false,
     }
 }
 
+/// Whether `node` would need to be wrapped in parentheses if it were
+/// replaced with a `readonly` array type, ie if it's the element type of an
+/// array type or the object type of an indexed access type.
+fn readonly_replacement_needs_parentheses(node: Node) -> bool {
+    node.parent().matches(|parent| match parent.kind() {
+        ArrayType => true,
+        LookupType => parent.first_non_comment_named_child(SupportedLanguage::Javascript) == node,
+        _ => false,
+    })
+}
+
 fn get_message_type<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Cow<'a, str> {
+    let node = node.skip_parenthesized_types();
     if is_simple_type(node, context) {
         node.text(context)
     } else {
@@ -124,6 +138,11 @@ pub fn array_type_rule() -> Arc<dyn Rule> {
                 } else {
                     ""
                 };
+                let class_name = if is_readonly_array_type {
+                    "ReadonlyArray"
+                } else {
+                    "Array"
+                };
                 let message_id = if current_option == ArrayOption::Array {
                     "error_string_array"
                 } else {
@@ -134,16 +153,26 @@ pub fn array_type_rule() -> Arc<dyn Rule> {
                     node => node_to_report,
                     message_id => message_id,
                     data => {
-                        class_name => if is_readonly_array_type {
-                            "ReadonlyArray"
+                        class_name => class_name,
+                        readonly_prefix => readonly_prefix,
+                        type => "any",
+                        banned_name => class_name,
+                        replacement => format!("{readonly_prefix}any[]"),
+                        option => if is_readonly_array_type {
+                            "readonly"
                         } else {
-                            "Array"
+                            "default"
                         },
-                        readonly_prefix => readonly_prefix,
-                        type_ => "any",
                     },
                     fix => |fixer| {
-                        fixer.replace_text(node_to_report, format!("{readonly_prefix}any[]"));
+                        fixer.replace_text(
+                            node_to_report,
+                            if is_readonly_array_type && readonly_replacement_needs_parentheses(node_to_report) {
+                                "(readonly any[])".to_owned()
+                            } else {
+                                format!("{readonly_prefix}any[]")
+                            },
+                        );
                     }
                 });
             }
@@ -178,21 +207,32 @@ pub fn array_type_rule() -> Arc<dyn Rule> {
                     node
                 };
 
+                let class_name = if is_readonly {
+                    "ReadonlyArray"
+                } else {
+                    "Array"
+                };
+                let readonly_prefix = if is_readonly {
+                    "readonly "
+                } else {
+                    ""
+                };
+                let message_type = get_message_type(item_type_node, context);
+
                 context.report(violation! {
                     node => error_node,
                     message_id => message_id,
                     data => {
-                        class_name => if is_readonly {
-                            "ReadonlyArray"
-                        } else {
-                            "Array"
-                        },
-                        readonly_prefix => if is_readonly {
-                            "readonly "
+                        class_name => class_name,
+                        readonly_prefix => readonly_prefix,
+                        type => message_type.clone().into_owned(),
+                        banned_name => format!("{readonly_prefix}{message_type}[]"),
+                        replacement => format!("{class_name}<{message_type}>"),
+                        option => if is_readonly {
+                            "readonly"
                         } else {
-                            ""
+                            "default"
                         },
-                        type => get_message_type(item_type_node, context).into_owned(),
                     },
                     fix => |fixer| {
                         let type_node = item_type_node.skip_parenthesized_types();
@@ -272,20 +312,30 @@ pub fn array_type_rule() -> Arc<dyn Rule> {
                 let type_ = first_type_argument.skip_parenthesized_types();
                 let type_parens = type_needs_parentheses(type_, context);
                 let parent_parens = !readonly_prefix.is_empty() &&
-                    node.parent().matches(|parent| parent.kind() == ArrayType);
+                    readonly_replacement_needs_parentheses(node);
+
 
+                let class_name = if is_readonly_array_type {
+                    "ReadonlyArray"
+                } else {
+                    "Array"
+                };
+                let message_type = get_message_type(type_, context);
 
                 context.report(violation! {
                     node => node,
                     message_id => message_id,
                     data => {
-                        class_name => if is_readonly_array_type {
-                            "ReadonlyArray"
+                        class_name => class_name,
+                        readonly_prefix => readonly_prefix,
+                        type => message_type.clone(),
+                        banned_name => format!("{class_name}<{message_type}>"),
+                        replacement => format!("{readonly_prefix}{message_type}[]"),
+                        option => if is_readonly_array_type {
+                            "readonly"
                         } else {
-                            "Array"
+                            "default"
                         },
-                        readonly_prefix => readonly_prefix,
-                        type_ => get_message_type(type_, context),
                     },
                     fix => |fixer| {
                         let start = format!(
@@ -330,1365 +380,502 @@ pub fn array_type_rule() -> Arc<dyn Rule> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use tree_sitter_lint::{rule_tests, RuleTester};
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
 
-    use super::*;
+    use crate::test_helpers::{expected_error, GeneratedCases, OptionMatrix};
 
-    #[test]
-    fn test_array_type_rule() {
-        // TODO: there are other tests in the typescript-eslint version
-        RuleTester::run(
-            array_type_rule(),
-            rule_tests! {
-                valid => [
-                    // Base cases from https://github.com/typescript-eslint/typescript-eslint/issues/2323#issuecomment-663977655
-                    {
-                      code => "let a: number[] = [];",
-                      options => { default => "array" },
-                    },
-                    {
-                      code => "let a: (string | number)[] = [];",
-                      options => { default => "array" },
-                    },
-                    {
-                      code => "let a: readonly number[] = [];",
-                      options => { default => "array" },
-                    },
-                    {
-                      code => "let a: readonly (string | number)[] = [];",
-                      options => { default => "array" },
-                    },
-                    {
-                      code => "let a: number[] = [];",
-                      options => { default => "array", readonly => "array" },
-                    },
-                    {
-                      code => "let a: (string | number)[] = [];",
-                      options => { default => "array", readonly => "array" },
-                    },
-                    {
-                      code => "let a: readonly number[] = [];",
-                      options => { default => "array", readonly => "array" },
-                    },
-                    {
-                      code => "let a: readonly (string | number)[] = [];",
-                      options => { default => "array", readonly => "array" },
-                    },
-                    {
-                      code => "let a: number[] = [];",
-                      options => { default => "array", readonly => "array-simple" },
-                    },
-                    {
-                      code => "let a: (string | number)[] = [];",
-                      options => { default => "array", readonly => "array-simple" },
-                    },
-                    {
-                      code => "let a: readonly number[] = [];",
-                      options => { default => "array", readonly => "array-simple" },
-                    },
-                    {
-                      code => "let a: ReadonlyArray<string | number> = [];",
-                      options => { default => "array", readonly => "array-simple" },
-                    },
-                    {
-                      code => "let a: number[] = [];",
-                      options => { default => "array", readonly => "generic" },
-                    },
-                    {
-                      code => "let a: (string | number)[] = [];",
-                      options => { default => "array", readonly => "generic" },
-                    },
-                    {
-                      code => "let a: ReadonlyArray<number> = [];",
-                      options => { default => "array", readonly => "generic" },
-                    },
-                    {
-                      code => "let a: ReadonlyArray<string | number> = [];",
-                      options => { default => "array", readonly => "generic" },
-                    },
-                    {
-                      code => "let a: number[] = [];",
-                      options => { default => "array-simple" },
-                    },
-                    {
-                      code => "let a: Array<string | number> = [];",
-                      options => { default => "array-simple" },
-                    },
-                    {
-                      code => "let a: readonly number[] = [];",
-                      options => { default => "array-simple" },
-                    },
-                    {
-                      code => "let a: ReadonlyArray<string | number> = [];",
-                      options => { default => "array-simple" },
-                    },
-                    {
-                      code => "let a: number[] = [];",
-                      options => { default => "array-simple", readonly => "array" },
-                    },
-                    {
-                      code => "let a: Array<string | number> = [];",
-                      options => { default => "array-simple", readonly => "array" },
-                    },
-                    {
-                      code => "let a: readonly number[] = [];",
-                      options => { default => "array-simple", readonly => "array" },
-                    },
-                    {
-                      code => "let a: readonly (string | number)[] = [];",
-                      options => { default => "array-simple", readonly => "array" },
-                    },
-                    {
-                      code => "let a: number[] = [];",
-                      options => { default => "array-simple", readonly => "array-simple" },
-                    },
-                    {
-                      code => "let a: Array<string | number> = [];",
-                      options => { default => "array-simple", readonly => "array-simple" },
-                    },
-                    {
-                      code => "let a: readonly number[] = [];",
-                      options => { default => "array-simple", readonly => "array-simple" },
-                    },
-                    {
-                      code => "let a: ReadonlyArray<string | number> = [];",
-                      options => { default => "array-simple", readonly => "array-simple" },
-                    },
-                    {
-                      code => "let a: number[] = [];",
-                      options => { default => "array-simple", readonly => "generic" },
-                    },
-                    {
-                      code => "let a: Array<string | number> = [];",
-                      options => { default => "array-simple", readonly => "generic" },
-                    },
-                    {
-                      code => "let a: ReadonlyArray<number> = [];",
-                      options => { default => "array-simple", readonly => "generic" },
-                    },
-                    {
-                      code => "let a: ReadonlyArray<string | number> = [];",
-                      options => { default => "array-simple", readonly => "generic" },
-                    },
-                    {
-                      code => "let a: Array<number> = [];",
-                      options => { default => "generic" },
-                    },
-                    {
-                      code => "let a: Array<string | number> = [];",
-                      options => { default => "generic" },
-                    },
-                    {
-                      code => "let a: ReadonlyArray<number> = [];",
-                      options => { default => "generic" },
-                    },
-                    {
-                      code => "let a: ReadonlyArray<string | number> = [];",
-                      options => { default => "generic" },
-                    },
-                    {
-                      code => "let a: Array<number> = [];",
-                      options => { default => "generic", readonly => "generic" },
-                    },
-                    {
-                      code => "let a: Array<string | number> = [];",
-                      options => { default => "generic", readonly => "generic" },
-                    },
-                    {
-                      code => "let a: ReadonlyArray<number> = [];",
-                      options => { default => "generic", readonly => "generic" },
-                    },
-                    {
-                      code => "let a: ReadonlyArray<string | number> = [];",
-                      options => { default => "generic", readonly => "generic" },
-                    },
-                    {
-                      code => "let a: Array<number> = [];",
-                      options => { default => "generic", readonly => "array" },
-                    },
-                    {
-                      code => "let a: Array<string | number> = [];",
-                      options => { default => "generic", readonly => "array" },
-                    },
-                    {
-                      code => "let a: readonly number[] = [];",
-                      options => { default => "generic", readonly => "array" },
-                    },
-                    {
-                      code => "let a: readonly (string | number)[] = [];",
-                      options => { default => "generic", readonly => "array" },
-                    },
-                    {
-                      code => "let a: Array<number> = [];",
-                      options => { default => "generic", readonly => "array-simple" },
-                    },
-                    {
-                      code => "let a: Array<string | number> = [];",
-                      options => { default => "generic", readonly => "array-simple" },
-                    },
-                    {
-                      code => "let a: readonly number[] = [];",
-                      options => { default => "generic", readonly => "array-simple" },
-                    },
-                    {
-                      code => "let a: ReadonlyArray<string | number> = [];",
-                      options => { default => "generic", readonly => "array-simple" },
-                    },
-                    {
-                      code => "let a: Array<bigint> = [];",
-                      options => { default => "generic", readonly => "array" },
-                    },
-                    {
-                      code => "let a: readonly bigint[] = [];",
-                      options => { default => "generic", readonly => "array" },
-                    },
-                    {
-                      code => "let a: readonly (string | bigint)[] = [];",
-                      options => { default => "generic", readonly => "array" },
-                    },
-                    {
-                      code => "let a: Array<bigint> = [];",
-                      options => { default => "generic", readonly => "array-simple" },
-                    },
-                    {
-                      code => "let a: Array<string | bigint> = [];",
-                      options => { default => "generic", readonly => "array-simple" },
-                    },
-                    {
-                      code => "let a: readonly bigint[] = [];",
-                      options => { default => "generic", readonly => "array-simple" },
-                    },
-                    {
-                      code => "let a: ReadonlyArray<string | bigint> = [];",
-                      options => { default => "generic", readonly => "array-simple" },
-                    },
+    /// Each element type, how it's written as an array's element and how the
+    /// messages refer to it.
+    const ELEMENT_TYPES: [(&str, &str, &str); 4] = [
+        ("number", "number", "number"),
+        ("string | number", "(string | number)", "T"),
+        ("bigint", "bigint", "bigint"),
+        ("string | bigint", "(string | bigint)", "T"),
+    ];
 
-                    // End of base cases
+    /// For each option and element type, whether the option wants it written
+    /// as an array (rather than generic) and the message id it reports when
+    /// it isn't written that way.
+    const EXPECTED: [(&str, &str, bool, &str); 12] = [
+        ("array", "number", true, "error_string_array"),
+        ("array", "string | number", true, "error_string_array"),
+        ("array", "bigint", true, "error_string_array"),
+        ("array", "string | bigint", true, "error_string_array"),
+        ("array-simple", "number", true, "error_string_array_simple"),
+        (
+            "array-simple",
+            "string | number",
+            false,
+            "error_string_generic_simple",
+        ),
+        ("array-simple", "bigint", true, "error_string_array_simple"),
+        (
+            "array-simple",
+            "string | bigint",
+            false,
+            "error_string_generic_simple",
+        ),
+        ("generic", "number", false, "error_string_generic"),
+        ("generic", "string | number", false, "error_string_generic"),
+        ("generic", "bigint", false, "error_string_generic"),
+        ("generic", "string | bigint", false, "error_string_generic"),
+    ];
 
-                    {
-                      code => "let a = new Array();",
-                      options => { default => "array" },
-                    },
-                    {
-                      code => "let a: { foo: Bar[] }[] = [];",
-                      options => { default => "array" },
-                    },
-                    {
-                      code => "function foo(a: Array<Bar>): Array<Bar> {}",
-                      options => { default => "generic" },
-                    },
-                    {
-                      code => "let yy: number[][] = [[4, 5], [6]];",
-                      options => { default => "array-simple" },
-                    },
-                    {
-                      code => r#"
+    // Base cases from https://github.com/typescript-eslint/typescript-eslint/issues/2323#issuecomment-663977655
+    fn base_cases() -> GeneratedCases {
+        OptionMatrix::default()
+            .axis("default", ["array", "array-simple", "generic"])
+            .optional_axis("readonly", ["array", "array-simple", "generic"])
+            .generate(|cases| {
+                let default = cases.str_option("default").unwrap();
+                let readonly = cases.str_option("readonly").unwrap_or(default);
+                for (element_type, array_element_type, message_type) in ELEMENT_TYPES {
+                    for is_readonly in [false, true] {
+                        let (option, class_name, readonly_prefix) = match is_readonly {
+                            false => (default, "Array", ""),
+                            true => (readonly, "ReadonlyArray", "readonly "),
+                        };
+                        let &(_, _, expects_array, message_id) = EXPECTED
+                            .iter()
+                            .find(|&&(expected_option, expected_element_type, ..)| {
+                                expected_option == option && expected_element_type == element_type
+                            })
+                            .unwrap();
+                        let array = format!("{readonly_prefix}{array_element_type}[]");
+                        let generic = format!("{class_name}<{element_type}>");
+                        let message_array = format!("{readonly_prefix}{message_type}[]");
+                        let message_generic = format!("{class_name}<{message_type}>");
+                        let (expected, unexpected, banned_name, replacement) = match expects_array {
+                            true => (&array, &generic, &message_generic, &message_array),
+                            false => (&generic, &array, &message_array, &message_generic),
+                        };
+                        cases.valid(format!("let a: {expected} = [];"));
+                        cases.invalid_with_output(
+                            format!("let a: {unexpected} = [];"),
+                            format!("let a: {expected} = [];"),
+                            vec![expected_error(
+                                message_id,
+                                [
+                                    ("class_name", class_name),
+                                    ("readonly_prefix", readonly_prefix),
+                                    ("type", message_type),
+                                    ("banned_name", banned_name),
+                                    ("replacement", replacement),
+                                    ("option", if is_readonly { "readonly" } else { "default" }),
+                                ],
+                            )
+                            .line(1)
+                            .column(8)
+                            .end_line(1)
+                            .end_column(8 + unexpected.len())
+                            .build()
+                            .unwrap()],
+                        );
+                    }
+                }
+            })
+    }
+
+    pub fn array_type_rule_tests() -> RuleTests {
+        // TODO: there are other tests in the typescript-eslint version
+        let base_cases = base_cases();
+        rule_tests! {
+            valid => [
+                ...base_cases.valid,
+
+                {
+                  code => "let a = new Array();",
+                  options => { default => "array" },
+                },
+                {
+                  code => "let a: { foo: Bar[] }[] = [];",
+                  options => { default => "array" },
+                },
+                {
+                  code => "function foo(a: Array<Bar>): Array<Bar> {}",
+                  options => { default => "generic" },
+                },
+                {
+                  code => "let yy: number[][] = [[4, 5], [6]];",
+                  options => { default => "array-simple" },
+                },
+                {
+                  code => r#"
                 function fooFunction(foo: Array<ArrayClass<string>>) {
                   return foo.map(e => e.foo);
                 }
                       "#,
-                      options => { default => "array-simple" },
-                    },
-                    {
-                      code => r#"
+                  options => { default => "array-simple" },
+                },
+                {
+                  code => r#"
                 function bazFunction(baz: Arr<ArrayClass<String>>) {
                   return baz.map(e => e.baz);
                 }
                       "#,
-                      options => { default => "array-simple" },
-                    },
-                    {
-                      code => "let fooVar: Array<(c: number) => number>;",
-                      options => { default => "array-simple" },
-                    },
-                    {
-                      code => "type fooUnion = Array<string | number | boolean>;",
-                      options => { default => "array-simple" },
-                    },
-                    {
-                      code => "type fooIntersection = Array<string & number>;",
-                      options => { default => "array-simple" },
-                    },
-                    {
-                      code => r#"
+                  options => { default => "array-simple" },
+                },
+                {
+                  code => "let fooVar: Array<(c: number) => number>;",
+                  options => { default => "array-simple" },
+                },
+                {
+                  code => "type fooUnion = Array<string | number | boolean>;",
+                  options => { default => "array-simple" },
+                },
+                {
+                  code => "type fooIntersection = Array<string & number>;",
+                  options => { default => "array-simple" },
+                },
+                {
+                  code => r#"
                 namespace fooName {
                   type BarType = { bar: string };
                   type BazType<T> = Arr<T>;
                 }
                       "#,
-                      options => { default => "array-simple" },
-                    },
-                    {
-                      code => r#"
+                  options => { default => "array-simple" },
+                },
+                {
+                  code => r#"
                 interface FooInterface {
                   ".bar": { baz: string[] };
                 }
                       "#,
-                      options => { default => "array-simple" },
-                    },
-                    {
-                      code => "let yy: number[][] = [[4, 5], [6]];",
-                      options => { default => "array" },
-                    },
-                    {
-                      code => "let ya = [[1, '2']] as [number, string][];",
-                      options => { default => "array" },
-                    },
-                    {
-                      code => r#"
+                  options => { default => "array-simple" },
+                },
+                {
+                  code => "let yy: number[][] = [[4, 5], [6]];",
+                  options => { default => "array" },
+                },
+                {
+                  code => "let ya = [[1, '2']] as [number, string][];",
+                  options => { default => "array" },
+                },
+                {
+                  code => r#"
                 function barFunction(bar: ArrayClass<String>[]) {
                   return bar.map(e => e.bar);
                 }
                       "#,
-                      options => { default => "array" },
-                    },
-                    {
-                      code => r#"
+                  options => { default => "array" },
+                },
+                {
+                  code => r#"
                 function bazFunction(baz: Arr<ArrayClass<String>>) {
                   return baz.map(e => e.baz);
                 }
                       "#,
-                      options => { default => "array" },
-                    },
-                    {
-                      code => "let barVar: ((c: number) => number)[];",
-                      options => { default => "array" },
-                    },
-                    {
-                      code => "type barUnion = (string | number | boolean)[];",
-                      options => { default => "array" },
-                    },
-                    {
-                      code => "type barIntersection = (string & number)[];",
-                      options => { default => "array" },
-                    },
-                    {
-                      code => r#"
+                  options => { default => "array" },
+                },
+                {
+                  code => "let barVar: ((c: number) => number)[];",
+                  options => { default => "array" },
+                },
+                {
+                  code => "type barUnion = (string | number | boolean)[];",
+                  options => { default => "array" },
+                },
+                {
+                  code => "type barIntersection = (string & number)[];",
+                  options => { default => "array" },
+                },
+                {
+                  code => r#"
                 interface FooInterface {
                   '.bar': { baz: string[] };
                 }
                       "#,
-                      options => { default => "array" },
-                    },
-                    {
-                      // https://github.com/typescript-eslint/typescript-eslint/issues/172
-                      code => "type Unwrap<T> = T extends (infer E)[] ? E : T;",
-                      options => { default => "array" },
-                    },
-                    {
-                      code => "let xx: Array<Array<number>> = [[1, 2], [3]];",
-                      options => { default => "generic" },
-                    },
-                    {
-                      code => "type Arr<T> = Array<T>;",
-                      options => { default => "generic" },
-                    },
-                    {
-                      code => r#"
+                  options => { default => "array" },
+                },
+                {
+                  // https://github.com/typescript-eslint/typescript-eslint/issues/172
+                  code => "type Unwrap<T> = T extends (infer E)[] ? E : T;",
+                  options => { default => "array" },
+                },
+                {
+                  code => "let xx: Array<Array<number>> = [[1, 2], [3]];",
+                  options => { default => "generic" },
+                },
+                {
+                  code => "type Arr<T> = Array<T>;",
+                  options => { default => "generic" },
+                },
+                {
+                  code => r#"
                 function fooFunction(foo: Array<ArrayClass<string>>) {
                   return foo.map(e => e.foo);
                 }
                       "#,
-                      options => { default => "generic" },
-                    },
-                    {
-                      code => r#"
+                  options => { default => "generic" },
+                },
+                {
+                  code => r#"
                 function bazFunction(baz: Arr<ArrayClass<String>>) {
                   return baz.map(e => e.baz);
                 }
                       "#,
-                      options => { default => "generic" },
-                    },
-                    {
-                      code => "let fooVar: Array<(c: number) => number>;",
-                      options => { default => "generic" },
-                    },
-                    {
-                      code => "type fooUnion = Array<string | number | boolean>;",
-                      options => { default => "generic" },
-                    },
-                    {
-                      code => "type fooIntersection = Array<string & number>;",
-                      options => { default => "generic" },
-                    },
-                    {
-                      // https://github.com/typescript-eslint/typescript-eslint/issues/172
-                      code => "type Unwrap<T> = T extends Array<infer E> ? E : T;",
-                      options => { default => "generic" },
-                    },
+                  options => { default => "generic" },
+                },
+                {
+                  code => "let fooVar: Array<(c: number) => number>;",
+                  options => { default => "generic" },
+                },
+                {
+                  code => "type fooUnion = Array<string | number | boolean>;",
+                  options => { default => "generic" },
+                },
+                {
+                  code => "type fooIntersection = Array<string & number>;",
+                  options => { default => "generic" },
+                },
+                {
+                  // https://github.com/typescript-eslint/typescript-eslint/issues/172
+                  code => "type Unwrap<T> = T extends Array<infer E> ? E : T;",
+                  options => { default => "generic" },
+                },
 
-                    // nested readonly
-                    {
-                      code => "let a: ReadonlyArray<number[]> = [[]];",
-                      options => { default => "array", readonly => "generic" },
-                    },
-                    {
-                      code => "let a: readonly Array<number>[] = [[]];",
-                      options => { default => "generic", readonly => "array" },
+                // nested readonly
+                {
+                  code => "let a: ReadonlyArray<number[]> = [[]];",
+                  options => { default => "array", readonly => "generic" },
+                },
+                {
+                  code => "let a: readonly Array<number>[] = [[]];",
+                  options => { default => "generic", readonly => "array" },
+                },
+              ],
+              invalid => [
+                ...base_cases.invalid,
+
+                {
+                  code => "let a: { foo: Array<Bar> }[] = [];",
+                  output => "let a: { foo: Bar[] }[] = [];",
+                  options => { default => "array" },
+                  errors => [
+                    {
+                      message_id => "error_string_array",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "Bar",
+                        banned_name => "Array<Bar>",
+                        replacement => "Bar[]",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 15,
                     },
                   ],
-                  invalid => [
-                    // Base cases from https://github.com/typescript-eslint/typescript-eslint/issues/2323#issuecomment-663977655
-                    {
-                      code => "let a: Array<number> = [];",
-                      output => "let a: number[] = [];",
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "number" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: Array<string | number> = [];",
-                      output => "let a: (string | number)[] = [];",
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
+                },
+                {
+                  code => "let a: Array<{ foo: Bar[] }> = [];",
+                  output => "let a: Array<{ foo: Array<Bar> }> = [];",
+                  options => { default => "generic" },
+                  errors => [
+                    {
+                      message_id => "error_string_generic",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "Bar",
+                        banned_name => "Bar[]",
+                        replacement => "Array<Bar>",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 21,
                     },
-                    {
-                      code => "let a: ReadonlyArray<number> = [];",
-                      output => "let a: readonly number[] = [];",
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "number",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "let a: Array<{ foo: Foo | Bar[] }> = [];",
+                  output => "let a: Array<{ foo: Foo | Array<Bar> }> = [];",
+                  options => { default => "generic" },
+                  errors => [
+                    {
+                      message_id => "error_string_generic",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "Bar",
+                        banned_name => "Bar[]",
+                        replacement => "Array<Bar>",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 27,
                     },
-                    {
-                      code => "let a: ReadonlyArray<string | number> = [];",
-                      output => "let a: readonly (string | number)[] = [];",
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "T",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "function foo(a: Array<Bar>): Array<Bar> {}",
+                  output => "function foo(a: Bar[]): Bar[] {}",
+                  options => { default => "array" },
+                  errors => [
+                    {
+                      message_id => "error_string_array",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "Bar",
+                        banned_name => "Array<Bar>",
+                        replacement => "Bar[]",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 17,
+                    },
+                    {
+                      message_id => "error_string_array",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "Bar",
+                        banned_name => "Array<Bar>",
+                        replacement => "Bar[]",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 30,
                     },
-                    {
-                      code => "let a: Array<number> = [];",
-                      output => "let a: number[] = [];",
-                      options => { default => "array", readonly => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "number" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "let x: Array<undefined> = [undefined] as undefined[];",
+                  output => "let x: undefined[] = [undefined] as undefined[];",
+                  options => { default => "array-simple" },
+                  errors => [
+                    {
+                      message_id => "error_string_array_simple",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "undefined",
+                        banned_name => "Array<undefined>",
+                        replacement => "undefined[]",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 8,
+                      end_line => 1,
+                      end_column => 24,
                     },
-                    {
-                      code => "let a: Array<string | number> = [];",
-                      output => "let a: (string | number)[] = [];",
-                      options => { default => "array", readonly => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "let y: string[] = <Array<string>>['2'];",
+                  output => "let y: string[] = <string[]>['2'];",
+                  options => { default => "array-simple" },
+                  errors => [
+                    {
+                      message_id => "error_string_array_simple",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "string",
+                        banned_name => "Array<string>",
+                        replacement => "string[]",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 20,
                     },
-                    {
-                      code => "let a: ReadonlyArray<number> = [];",
-                      output => "let a: readonly number[] = [];",
-                      options => { default => "array", readonly => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "number",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
+                  ],
+                  supported_language_languages => [Typescript],
+                },
+                {
+                  code => "let z: Array = [3, '4'];",
+                  output => "let z: any[] = [3, '4'];",
+                  options => { default => "array-simple" },
+                  errors => [
+                    {
+                      message_id => "error_string_array_simple",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "any",
+                        banned_name => "Array",
+                        replacement => "any[]",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 8,
+                      end_line => 1,
+                      end_column => 13,
                     },
-                    {
-                      code => "let a: ReadonlyArray<string | number> = [];",
-                      output => "let a: readonly (string | number)[] = [];",
-                      options => { default => "array", readonly => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "T",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "let ya = [[1, '2']] as [number, string][];",
+                  output => "let ya = [[1, '2']] as Array<[number, string]>;",
+                  options => { default => "array-simple" },
+                  errors => [
+                    {
+                      message_id => "error_string_generic_simple",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "T[]",
+                        replacement => "Array<T>",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 24,
                     },
-                    {
-                      code => "let a: Array<number> = [];",
-                      output => "let a: number[] = [];",
-                      options => { default => "array", readonly => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "number" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "type Arr<T> = Array<T>;",
+                  output => "type Arr<T> = T[];",
+                  options => { default => "array-simple" },
+                  errors => [
+                    {
+                      message_id => "error_string_array_simple",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "Array<T>",
+                        replacement => "T[]",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 15,
                     },
-                    {
-                      code => "let a: Array<string | number> = [];",
-                      output => "let a: (string | number)[] = [];",
-                      options => { default => "array", readonly => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => r#"
+// Ignore user defined aliases
+let yyyy: Arr<Array<Arr<string>>[]> = [[[['2']]]];
+                      "#,
+                  output => r#"
+// Ignore user defined aliases
+let yyyy: Arr<Array<Array<Arr<string>>>> = [[[['2']]]];
+                      "#,
+                  options => { default => "array-simple" },
+                  errors => [
+                    {
+                      message_id => "error_string_generic_simple",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "T[]",
+                        replacement => "Array<T>",
+                        option => "default",
+                      },
+                      line => 3,
+                      column => 15,
                     },
-                    {
-                      code => "let a: ReadonlyArray<number> = [];",
-                      output => "let a: readonly number[] = [];",
-                      options => { default => "array", readonly => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_array_simple",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "number",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: readonly (string | number)[] = [];",
-                      output => "let a: ReadonlyArray<string | number> = [];",
-                      options => { default => "array", readonly => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic_simple",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "T",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: Array<number> = [];",
-                      output => "let a: number[] = [];",
-                      options => { default => "array", readonly => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "number" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: Array<string | number> = [];",
-                      output => "let a: (string | number)[] = [];",
-                      options => { default => "array", readonly => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: readonly number[] = [];",
-                      output => "let a: ReadonlyArray<number> = [];",
-                      options => { default => "array", readonly => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "number",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: readonly (string | number)[] = [];",
-                      output => "let a: ReadonlyArray<string | number> = [];",
-                      options => { default => "array", readonly => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "T",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: Array<number> = [];",
-                      output => "let a: number[] = [];",
-                      options => { default => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_array_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "number" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: (string | number)[] = [];",
-                      output => "let a: Array<string | number> = [];",
-                      options => { default => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: ReadonlyArray<number> = [];",
-                      output => "let a: readonly number[] = [];",
-                      options => { default => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_array_simple",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "number",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: readonly (string | number)[] = [];",
-                      output => "let a: ReadonlyArray<string | number> = [];",
-                      options => { default => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic_simple",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "T",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: Array<number> = [];",
-                      output => "let a: number[] = [];",
-                      options => { default => "array-simple", readonly => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "number" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: (string | number)[] = [];",
-                      output => "let a: Array<string | number> = [];",
-                      options => { default => "array-simple", readonly => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: ReadonlyArray<number> = [];",
-                      output => "let a: readonly number[] = [];",
-                      options => { default => "array-simple", readonly => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "number",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: ReadonlyArray<string | number> = [];",
-                      output => "let a: readonly (string | number)[] = [];",
-                      options => { default => "array-simple", readonly => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "T",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: Array<number> = [];",
-                      output => "let a: number[] = [];",
-                      options => { default => "array-simple", readonly => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_array_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "number" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: (string | number)[] = [];",
-                      output => "let a: Array<string | number> = [];",
-                      options => { default => "array-simple", readonly => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: ReadonlyArray<number> = [];",
-                      output => "let a: readonly number[] = [];",
-                      options => { default => "array-simple", readonly => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_array_simple",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "number",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: readonly (string | number)[] = [];",
-                      output => "let a: ReadonlyArray<string | number> = [];",
-                      options => { default => "array-simple", readonly => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic_simple",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "T",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: Array<number> = [];",
-                      output => "let a: number[] = [];",
-                      options => { default => "array-simple", readonly => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_array_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "number" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: (string | number)[] = [];",
-                      output => "let a: Array<string | number> = [];",
-                      options => { default => "array-simple", readonly => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: readonly number[] = [];",
-                      output => "let a: ReadonlyArray<number> = [];",
-                      options => { default => "array-simple", readonly => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "number",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: readonly (string | number)[] = [];",
-                      output => "let a: ReadonlyArray<string | number> = [];",
-                      options => { default => "array-simple", readonly => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "T",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: number[] = [];",
-                      output => "let a: Array<number> = [];",
-                      options => { default => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "number" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: (string | number)[] = [];",
-                      output => "let a: Array<string | number> = [];",
-                      options => { default => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: readonly number[] = [];",
-                      output => "let a: ReadonlyArray<number> = [];",
-                      options => { default => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "number",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: readonly (string | number)[] = [];",
-                      output => "let a: ReadonlyArray<string | number> = [];",
-                      options => { default => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "T",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: number[] = [];",
-                      output => "let a: Array<number> = [];",
-                      options => { default => "generic", readonly => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "number" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: (string | number)[] = [];",
-                      output => "let a: Array<string | number> = [];",
-                      options => { default => "generic", readonly => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: ReadonlyArray<number> = [];",
-                      output => "let a: readonly number[] = [];",
-                      options => { default => "generic", readonly => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "number",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: ReadonlyArray<string | number> = [];",
-                      output => "let a: readonly (string | number)[] = [];",
-                      options => { default => "generic", readonly => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "T",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: number[] = [];",
-                      output => "let a: Array<number> = [];",
-                      options => { default => "generic", readonly => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "number" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: (string | number)[] = [];",
-                      output => "let a: Array<string | number> = [];",
-                      options => { default => "generic", readonly => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: ReadonlyArray<number> = [];",
-                      output => "let a: readonly number[] = [];",
-                      options => { default => "generic", readonly => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_array_simple",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "number",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: readonly (string | number)[] = [];",
-                      output => "let a: ReadonlyArray<string | number> = [];",
-                      options => { default => "generic", readonly => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic_simple",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "T",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: number[] = [];",
-                      output => "let a: Array<number> = [];",
-                      options => { default => "generic", readonly => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "number" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: (string | number)[] = [];",
-                      output => "let a: Array<string | number> = [];",
-                      options => { default => "generic", readonly => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: readonly number[] = [];",
-                      output => "let a: ReadonlyArray<number> = [];",
-                      options => { default => "generic", readonly => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "number",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: readonly (string | number)[] = [];",
-                      output => "let a: ReadonlyArray<string | number> = [];",
-                      options => { default => "generic", readonly => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "T",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: bigint[] = [];",
-                      output => "let a: Array<bigint> = [];",
-                      options => { default => "generic", readonly => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "bigint" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: (string | bigint)[] = [];",
-                      output => "let a: Array<string | bigint> = [];",
-                      options => { default => "generic", readonly => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: ReadonlyArray<bigint> = [];",
-                      output => "let a: readonly bigint[] = [];",
-                      options => { default => "generic", readonly => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_array_simple",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "bigint",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: (string | bigint)[] = [];",
-                      output => "let a: Array<string | bigint> = [];",
-                      options => { default => "generic", readonly => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: readonly bigint[] = [];",
-                      output => "let a: ReadonlyArray<bigint> = [];",
-                      options => { default => "generic", readonly => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "bigint",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: readonly (string | bigint)[] = [];",
-                      output => "let a: ReadonlyArray<string | bigint> = [];",
-                      options => { default => "generic", readonly => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "T",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-
-                    // End of base cases
-
-                    {
-                      code => "let a: { foo: Array<Bar> }[] = [];",
-                      output => "let a: { foo: Bar[] }[] = [];",
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "Bar" },
-                          line => 1,
-                          column => 15,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: Array<{ foo: Bar[] }> = [];",
-                      output => "let a: Array<{ foo: Array<Bar> }> = [];",
-                      options => { default => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "Bar" },
-                          line => 1,
-                          column => 21,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let a: Array<{ foo: Foo | Bar[] }> = [];",
-                      output => "let a: Array<{ foo: Foo | Array<Bar> }> = [];",
-                      options => { default => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "Bar" },
-                          line => 1,
-                          column => 27,
-                        },
-                      ],
-                    },
-                    {
-                      code => "function foo(a: Array<Bar>): Array<Bar> {}",
-                      output => "function foo(a: Bar[]): Bar[] {}",
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "Bar" },
-                          line => 1,
-                          column => 17,
-                        },
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "Bar" },
-                          line => 1,
-                          column => 30,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let x: Array<undefined> = [undefined] as undefined[];",
-                      output => "let x: undefined[] = [undefined] as undefined[];",
-                      options => { default => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_array_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "undefined" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let y: string[] = <Array<string>>['2'];",
-                      output => "let y: string[] = <string[]>['2'];",
-                      options => { default => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_array_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "string" },
-                          line => 1,
-                          column => 20,
-                        },
-                      ],
-                      supported_language_languages => [Typescript],
-                    },
-                    {
-                      code => "let z: Array = [3, '4'];",
-                      output => "let z: any[] = [3, '4'];",
-                      options => { default => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_array_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "any" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
-                    },
-                    {
-                      code => "let ya = [[1, '2']] as [number, string][];",
-                      output => "let ya = [[1, '2']] as Array<[number, string]>;",
-                      options => { default => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 24,
-                        },
-                      ],
-                    },
-                    {
-                      code => "type Arr<T> = Array<T>;",
-                      output => "type Arr<T> = T[];",
-                      options => { default => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_array_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 15,
-                        },
-                      ],
-                    },
-                    {
-                      code => r#"
-// Ignore user defined aliases
-let yyyy: Arr<Array<Arr<string>>[]> = [[[['2']]]];
-                      "#,
-                      output => r#"
-// Ignore user defined aliases
-let yyyy: Arr<Array<Array<Arr<string>>>> = [[[['2']]]];
-                      "#,
-                      options => { default => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 3,
-                          column => 15,
-                        },
-                      ],
-                    },
-                    {
-                      code => r#"
+                  ],
+                },
+                {
+                  code => r#"
 interface ArrayClass<T> {
   foo: Array<T>;
   bar: T[];
@@ -1696,7 +883,7 @@ interface ArrayClass<T> {
   xyz: this[];
 }
                       "#,
-                      output => r#"
+                  output => r#"
 interface ArrayClass<T> {
   foo: T[];
   bar: T[];
@@ -1704,560 +891,1066 @@ interface ArrayClass<T> {
   xyz: this[];
 }
                       "#,
-                      options => { default => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_array_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 3,
-                          column => 8,
-                        },
-                      ],
+                  options => { default => "array-simple" },
+                  errors => [
+                    {
+                      message_id => "error_string_array_simple",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "Array<T>",
+                        replacement => "T[]",
+                        option => "default",
+                      },
+                      line => 3,
+                      column => 8,
                     },
-                    {
-                      code => r#"
+                  ],
+                },
+                {
+                  code => r#"
 function barFunction(bar: ArrayClass<String>[]) {
   return bar.map(e => e.bar);
 }
                       "#,
-                      output => r#"
+                  output => r#"
 function barFunction(bar: Array<ArrayClass<String>>) {
   return bar.map(e => e.bar);
 }
                       "#,
-                      options => { default => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 2,
-                          column => 27,
-                        },
-                      ],
+                  options => { default => "array-simple" },
+                  errors => [
+                    {
+                      message_id => "error_string_generic_simple",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "T[]",
+                        replacement => "Array<T>",
+                        option => "default",
+                      },
+                      line => 2,
+                      column => 27,
                     },
-                    {
-                      code => "let barVar: ((c: number) => number)[];",
-                      output => "let barVar: Array<(c: number) => number>;",
-                      options => { default => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 13,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "let barVar: ((c: number) => number)[];",
+                  output => "let barVar: Array<(c: number) => number>;",
+                  options => { default => "array-simple" },
+                  errors => [
+                    {
+                      message_id => "error_string_generic_simple",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "T[]",
+                        replacement => "Array<T>",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 13,
                     },
-                    {
-                      code => "type barUnion = (string | number | boolean)[];",
-                      output => "type barUnion = Array<string | number | boolean>;",
-                      options => { default => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 17,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "type barUnion = (string | number | boolean)[];",
+                  output => "type barUnion = Array<string | number | boolean>;",
+                  options => { default => "array-simple" },
+                  errors => [
+                    {
+                      message_id => "error_string_generic_simple",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "T[]",
+                        replacement => "Array<T>",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 17,
                     },
-                    {
-                      code => "type barIntersection = (string & number)[];",
-                      output => "type barIntersection = Array<string & number>;",
-                      options => { default => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 24,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "type barIntersection = (string & number)[];",
+                  output => "type barIntersection = Array<string & number>;",
+                  options => { default => "array-simple" },
+                  errors => [
+                    {
+                      message_id => "error_string_generic_simple",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "T[]",
+                        replacement => "Array<T>",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 24,
                     },
-                    {
-                      code => "let v: Array<fooName.BarType> = [{ bar: 'bar' }];",
-                      output => "let v: fooName.BarType[] = [{ bar: 'bar' }];",
-                      options => { default => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_array_simple",
-                          data => {
-                            class_name => "Array",
-                            readonly_prefix => "",
-                            type => "fooName.BarType",
-                          },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "let v: Array<fooName.BarType> = [{ bar: 'bar' }];",
+                  output => "let v: fooName.BarType[] = [{ bar: 'bar' }];",
+                  options => { default => "array-simple" },
+                  errors => [
+                    {
+                      message_id => "error_string_array_simple",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "fooName.BarType",
+                        banned_name => "Array<fooName.BarType>",
+                        replacement => "fooName.BarType[]",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 8,
+                      end_line => 1,
+                      end_column => 30,
                     },
-                    {
-                      code => "let w: fooName.BazType<string>[] = [['baz']];",
-                      output => "let w: Array<fooName.BazType<string>> = [['baz']];",
-                      options => { default => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "let w: fooName.BazType<string>[] = [['baz']];",
+                  output => "let w: Array<fooName.BazType<string>> = [['baz']];",
+                  options => { default => "array-simple" },
+                  errors => [
+                    {
+                      message_id => "error_string_generic_simple",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "T[]",
+                        replacement => "Array<T>",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 8,
+                      end_line => 1,
+                      end_column => 33,
                     },
-                    {
-                      code => "let x: Array<undefined> = [undefined] as undefined[];",
-                      output => "let x: undefined[] = [undefined] as undefined[];",
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "undefined" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "let x: Array<undefined> = [undefined] as undefined[];",
+                  output => "let x: undefined[] = [undefined] as undefined[];",
+                  options => { default => "array" },
+                  errors => [
+                    {
+                      message_id => "error_string_array",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "undefined",
+                        banned_name => "Array<undefined>",
+                        replacement => "undefined[]",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 8,
+                      end_line => 1,
+                      end_column => 24,
                     },
-                    {
-                      code => "let y: string[] = <Array<string>>['2'];",
-                      output => "let y: string[] = <string[]>['2'];",
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "string" },
-                          line => 1,
-                          column => 20,
-                        },
-                      ],
-                      supported_language_languages => [Typescript],
+                  ],
+                },
+                {
+                  code => "let y: string[] = <Array<string>>['2'];",
+                  output => "let y: string[] = <string[]>['2'];",
+                  options => { default => "array" },
+                  errors => [
+                    {
+                      message_id => "error_string_array",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "string",
+                        banned_name => "Array<string>",
+                        replacement => "string[]",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 20,
                     },
-                    {
-                      code => "let z: Array = [3, '4'];",
-                      output => "let z: any[] = [3, '4'];",
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "any" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
+                  ],
+                  supported_language_languages => [Typescript],
+                },
+                {
+                  code => "let z: Array = [3, '4'];",
+                  output => "let z: any[] = [3, '4'];",
+                  options => { default => "array" },
+                  errors => [
+                    {
+                      message_id => "error_string_array",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "any",
+                        banned_name => "Array",
+                        replacement => "any[]",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 8,
+                      end_line => 1,
+                      end_column => 13,
                     },
-                    {
-                      code => "type Arr<T> = Array<T>;",
-                      output => "type Arr<T> = T[];",
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 15,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "type Arr<T> = Array<T>;",
+                  output => "type Arr<T> = T[];",
+                  options => { default => "array" },
+                  errors => [
+                    {
+                      message_id => "error_string_array",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "Array<T>",
+                        replacement => "T[]",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 15,
                     },
-                    {
-                      code => r#"
+                  ],
+                },
+                {
+                  code => r#"
 // Ignore user defined aliases
 let yyyy: Arr<Array<Arr<string>>[]> = [[[['2']]]];
                       "#,
-                      output => r#"
+                  output => r#"
 // Ignore user defined aliases
 let yyyy: Arr<Arr<string>[][]> = [[[['2']]]];
                       "#,
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 3,
-                          column => 15,
-                        },
-                      ],
+                  options => { default => "array" },
+                  errors => [
+                    {
+                      message_id => "error_string_array",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "Array<T>",
+                        replacement => "T[]",
+                        option => "default",
+                      },
+                      line => 3,
+                      column => 15,
                     },
-                    {
-                      code => r#"
+                  ],
+                },
+                {
+                  code => r#"
 interface ArrayClass<T> {
   foo: Array<T>;
   bar: T[];
   baz: Arr<T>;
 }
                       "#,
-                      output => r#"
+                  output => r#"
 interface ArrayClass<T> {
   foo: T[];
   bar: T[];
   baz: Arr<T>;
 }
                       "#,
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 3,
-                          column => 8,
-                        },
-                      ],
+                  options => { default => "array" },
+                  errors => [
+                    {
+                      message_id => "error_string_array",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "Array<T>",
+                        replacement => "T[]",
+                        option => "default",
+                      },
+                      line => 3,
+                      column => 8,
                     },
-                    {
-                      code => r#"
+                  ],
+                },
+                {
+                  code => r#"
 function fooFunction(foo: Array<ArrayClass<string>>) {
   return foo.map(e => e.foo);
 }
                       "#,
-                      output => r#"
+                  output => r#"
 function fooFunction(foo: ArrayClass<string>[]) {
   return foo.map(e => e.foo);
 }
                       "#,
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 2,
-                          column => 27,
-                        },
-                      ],
+                  options => { default => "array" },
+                  errors => [
+                    {
+                      message_id => "error_string_array",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "Array<T>",
+                        replacement => "T[]",
+                        option => "default",
+                      },
+                      line => 2,
+                      column => 27,
                     },
-                    {
-                      code => "let fooVar: Array<(c: number) => number>;",
-                      output => "let fooVar: ((c: number) => number)[];",
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 13,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "let fooVar: Array<(c: number) => number>;",
+                  output => "let fooVar: ((c: number) => number)[];",
+                  options => { default => "array" },
+                  errors => [
+                    {
+                      message_id => "error_string_array",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "Array<T>",
+                        replacement => "T[]",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 13,
                     },
-                    {
-                      code => "type fooUnion = Array<string | number | boolean>;",
-                      output => "type fooUnion = (string | number | boolean)[];",
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 17,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "type fooUnion = Array<string | number | boolean>;",
+                  output => "type fooUnion = (string | number | boolean)[];",
+                  options => { default => "array" },
+                  errors => [
+                    {
+                      message_id => "error_string_array",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "Array<T>",
+                        replacement => "T[]",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 17,
                     },
-                    {
-                      code => "type fooIntersection = Array<string & number>;",
-                      output => "type fooIntersection = (string & number)[];",
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 24,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "type fooIntersection = Array<string & number>;",
+                  output => "type fooIntersection = (string & number)[];",
+                  options => { default => "array" },
+                  errors => [
+                    {
+                      message_id => "error_string_array",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "Array<T>",
+                        replacement => "T[]",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 24,
                     },
-                    {
-                      code => "let x: Array;",
-                      output => "let x: any[];",
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "any" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "let x: Array;",
+                  output => "let x: any[];",
+                  options => { default => "array" },
+                  errors => [
+                    {
+                      message_id => "error_string_array",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "any",
+                        banned_name => "Array",
+                        replacement => "any[]",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 8,
+                      end_line => 1,
+                      end_column => 13,
                     },
-                    // TODO: should support this? Looks like it's not
-                    // syntactically valid according to Typescript.
-                    // tree-sitter-typescript is parsing it as a single
-                    // zero-width type_identifier (between the angle
-                    // brackets)
-                    // (see one other commented-out test case below)
-                    // {
-                    //   code => "let x: Array<>;",
-                    //   output => "let x: any[];",
-                    //   options => { default => "array" },
-                    //   errors => [
-                    //     {
-                    //       message_id => "error_string_array",
-                    //       data => { class_name => "Array", readonly_prefix => "", type => "any" },
-                    //       line => 1,
-                    //       column => 8,
-                    //     },
-                    //   ],
-                    // },
-                    {
-                      code => "let x: Array;",
-                      output => "let x: any[];",
-                      options => { default => "array-simple" },
-                      errors => [
-                        {
-                          message_id => "error_string_array_simple",
-                          data => { class_name => "Array", readonly_prefix => "", type => "any" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
+                  ],
+                },
+                // TODO: should support this? Looks like it's not
+                // syntactically valid according to Typescript.
+                // tree-sitter-typescript is parsing it as a single
+                // zero-width type_identifier (between the angle
+                // brackets)
+                // (see one other commented-out test case below)
+                // {
+                //   code => "let x: Array<>;",
+                //   output => "let x: any[];",
+                //   options => { default => "array" },
+                //   errors => [
+                //     {
+                //       message_id => "error_string_array",
+                //       data => { class_name => "Array", readonly_prefix => "", type => "any" },
+                //       line => 1,
+                //       column => 8,
+                //     },
+                //   ],
+                // },
+                {
+                  code => "let x: Array;",
+                  output => "let x: any[];",
+                  options => { default => "array-simple" },
+                  errors => [
+                    {
+                      message_id => "error_string_array_simple",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "any",
+                        banned_name => "Array",
+                        replacement => "any[]",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 8,
+                      end_line => 1,
+                      end_column => 13,
                     },
-                    // {
-                    //   code => "let x: Array<>;",
-                    //   output => "let x: any[];",
-                    //   options => { default => "array-simple" },
-                    //   errors => [
-                    //     {
-                    //       message_id => "error_string_array_simple",
-                    //       line => 1,
-                    //       column => 8,
-                    //     },
-                    //   ],
-                    // },
-                    {
-                      code => "let x: Array<number> = [1] as number[];",
-                      output => "let x: Array<number> = [1] as Array<number>;",
-                      options => { default => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "number" },
-                          line => 1,
-                          column => 31,
-                        },
-                      ],
+                  ],
+                },
+                // {
+                //   code => "let x: Array<>;",
+                //   output => "let x: any[];",
+                //   options => { default => "array-simple" },
+                //   errors => [
+                //     {
+                //       message_id => "error_string_array_simple",
+                //       line => 1,
+                //       column => 8,
+                //     },
+                //   ],
+                // },
+                {
+                  code => "let x: Array<number> = [1] as number[];",
+                  output => "let x: Array<number> = [1] as Array<number>;",
+                  options => { default => "generic" },
+                  errors => [
+                    {
+                      message_id => "error_string_generic",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "number",
+                        banned_name => "number[]",
+                        replacement => "Array<number>",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 31,
                     },
-                    {
-                      code => "let y: string[] = <Array<string>>['2'];",
-                      output => "let y: Array<string> = <Array<string>>['2'];",
-                      options => { default => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "string" },
-                          line => 1,
-                          column => 8,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "let y: string[] = <Array<string>>['2'];",
+                  output => "let y: Array<string> = <Array<string>>['2'];",
+                  options => { default => "generic" },
+                  errors => [
+                    {
+                      message_id => "error_string_generic",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "string",
+                        banned_name => "string[]",
+                        replacement => "Array<string>",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 8,
+                      end_line => 1,
+                      end_column => 16,
                     },
-                    {
-                      code => "let ya = [[1, '2']] as [number, string][];",
-                      output => "let ya = [[1, '2']] as Array<[number, string]>;",
-                      options => { default => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 24,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "let ya = [[1, '2']] as [number, string][];",
+                  output => "let ya = [[1, '2']] as Array<[number, string]>;",
+                  options => { default => "generic" },
+                  errors => [
+                    {
+                      message_id => "error_string_generic",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "T[]",
+                        replacement => "Array<T>",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 24,
                     },
-                    {
-                      code => r#"
+                  ],
+                },
+                {
+                  code => r#"
 // Ignore user defined aliases
 let yyyy: Arr<Array<Arr<string>>[]> = [[[['2']]]];
                       "#,
-                      output => r#"
+                  output => r#"
 // Ignore user defined aliases
 let yyyy: Arr<Array<Array<Arr<string>>>> = [[[['2']]]];
                       "#,
-                      options => { default => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 3,
-                          column => 15,
-                        },
-                      ],
+                  options => { default => "generic" },
+                  errors => [
+                    {
+                      message_id => "error_string_generic",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "T[]",
+                        replacement => "Array<T>",
+                        option => "default",
+                      },
+                      line => 3,
+                      column => 15,
                     },
-                    {
-                      code => r#"
+                  ],
+                },
+                {
+                  code => r#"
 interface ArrayClass<T> {
   foo: Array<T>;
   bar: T[];
   baz: Arr<T>;
 }
                       "#,
-                      output => r#"
+                  output => r#"
 interface ArrayClass<T> {
   foo: Array<T>;
   bar: Array<T>;
   baz: Arr<T>;
 }
                       "#,
-                      options => { default => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 4,
-                          column => 8,
-                        },
-                      ],
+                  options => { default => "generic" },
+                  errors => [
+                    {
+                      message_id => "error_string_generic",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "T[]",
+                        replacement => "Array<T>",
+                        option => "default",
+                      },
+                      line => 4,
+                      column => 8,
                     },
-                    {
-                      code => r#"
+                  ],
+                },
+                {
+                  code => r#"
 function barFunction(bar: ArrayClass<String>[]) {
   return bar.map(e => e.bar);
 }
                       "#,
-                      output => r#"
+                  output => r#"
 function barFunction(bar: Array<ArrayClass<String>>) {
   return bar.map(e => e.bar);
 }
                       "#,
-                      options => { default => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 2,
-                          column => 27,
-                        },
-                      ],
+                  options => { default => "generic" },
+                  errors => [
+                    {
+                      message_id => "error_string_generic",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "T[]",
+                        replacement => "Array<T>",
+                        option => "default",
+                      },
+                      line => 2,
+                      column => 27,
                     },
-                    {
-                      code => "let barVar: ((c: number) => number)[];",
-                      output => "let barVar: Array<(c: number) => number>;",
-                      options => { default => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 13,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "let barVar: ((c: number) => number)[];",
+                  output => "let barVar: Array<(c: number) => number>;",
+                  options => { default => "generic" },
+                  errors => [
+                    {
+                      message_id => "error_string_generic",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "T[]",
+                        replacement => "Array<T>",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 13,
                     },
-                    {
-                      code => "type barUnion = (string | number | boolean)[];",
-                      output => "type barUnion = Array<string | number | boolean>;",
-                      options => { default => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 17,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "type barUnion = (string | number | boolean)[];",
+                  output => "type barUnion = Array<string | number | boolean>;",
+                  options => { default => "generic" },
+                  errors => [
+                    {
+                      message_id => "error_string_generic",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "T[]",
+                        replacement => "Array<T>",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 17,
                     },
-                    {
-                      code => "type barIntersection = (string & number)[];",
-                      output => "type barIntersection = Array<string & number>;",
-                      options => { default => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 24,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "type barIntersection = (string & number)[];",
+                  output => "type barIntersection = Array<string & number>;",
+                  options => { default => "generic" },
+                  errors => [
+                    {
+                      message_id => "error_string_generic",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "T[]",
+                        replacement => "Array<T>",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 24,
                     },
-                    {
-                      code => r#"
+                  ],
+                },
+                {
+                  code => r#"
 interface FooInterface {
   '.bar': { baz: string[] };
 }
                       "#,
-                      output => r#"
+                  output => r#"
 interface FooInterface {
   '.bar': { baz: Array<string> };
 }
                       "#,
-                      options => { default => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "string" },
-                          line => 3,
-                          column => 18,
-                        },
-                      ],
+                  options => { default => "generic" },
+                  errors => [
+                    {
+                      message_id => "error_string_generic",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "string",
+                        banned_name => "string[]",
+                        replacement => "Array<string>",
+                        option => "default",
+                      },
+                      line => 3,
+                      column => 18,
                     },
-                    {
-                      // https://github.com/typescript-eslint/typescript-eslint/issues/172
-                      code => "type Unwrap<T> = T extends Array<infer E> ? E : T;",
-                      output => "type Unwrap<T> = T extends (infer E)[] ? E : T;",
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 28,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  // https://github.com/typescript-eslint/typescript-eslint/issues/172
+                  code => "type Unwrap<T> = T extends Array<infer E> ? E : T;",
+                  output => "type Unwrap<T> = T extends (infer E)[] ? E : T;",
+                  options => { default => "array" },
+                  errors => [
+                    {
+                      message_id => "error_string_array",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "Array<T>",
+                        replacement => "T[]",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 28,
                     },
-                    {
-                      // https://github.com/typescript-eslint/typescript-eslint/issues/172
-                      code => "type Unwrap<T> = T extends (infer E)[] ? E : T;",
-                      output => "type Unwrap<T> = T extends Array<infer E> ? E : T;",
-                      options => { default => "generic" },
-                      errors => [
-                        {
-                          message_id => "error_string_generic",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 28,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  // https://github.com/typescript-eslint/typescript-eslint/issues/172
+                  code => "type Unwrap<T> = T extends (infer E)[] ? E : T;",
+                  output => "type Unwrap<T> = T extends Array<infer E> ? E : T;",
+                  options => { default => "generic" },
+                  errors => [
+                    {
+                      message_id => "error_string_generic",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "T[]",
+                        replacement => "Array<T>",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 28,
                     },
-                    {
-                      code => "type Foo = ReadonlyArray<object>[];",
-                      output => "type Foo = (readonly object[])[];",
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "object",
-                          },
-                          line => 1,
-                          column => 12,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "type Foo = ReadonlyArray<object>[];",
+                  output => "type Foo = (readonly object[])[];",
+                  options => { default => "array" },
+                  errors => [
+                    {
+                      message_id => "error_string_array",
+                      data => {
+                        class_name => "ReadonlyArray",
+                        readonly_prefix => "readonly ",
+                        type => "object",
+                        banned_name => "ReadonlyArray<object>",
+                        replacement => "readonly object[]",
+                        option => "readonly",
+                      },
+                      line => 1,
+                      column => 12,
                     },
-                    {
-                      code => "const foo: Array<new (...args: any[]) => void> = [];",
-                      output => "const foo: (new (...args: any[]) => void)[] = [];",
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => { class_name => "Array", readonly_prefix => "", type => "T" },
-                          line => 1,
-                          column => 12,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "const foo: Array<new (...args: any[]) => void> = [];",
+                  output => "const foo: (new (...args: any[]) => void)[] = [];",
+                  options => { default => "array" },
+                  errors => [
+                    {
+                      message_id => "error_string_array",
+                      data => {
+                        class_name => "Array",
+                        readonly_prefix => "",
+                        type => "T",
+                        banned_name => "Array<T>",
+                        replacement => "T[]",
+                        option => "default",
+                      },
+                      line => 1,
+                      column => 12,
                     },
-                    {
-                      code => "const foo: ReadonlyArray<new (...args: any[]) => void> = [];",
-                      output => "const foo: readonly (new (...args: any[]) => void)[] = [];",
-                      options => { default => "array" },
-                      errors => [
-                        {
-                          message_id => "error_string_array",
-                          data => {
-                            class_name => "ReadonlyArray",
-                            readonly_prefix => "readonly ",
-                            type => "T",
-                          },
-                          line => 1,
-                          column => 12,
-                        },
-                      ],
+                  ],
+                },
+                {
+                  code => "const foo: ReadonlyArray<new (...args: any[]) => void> = [];",
+                  output => "const foo: readonly (new (...args: any[]) => void)[] = [];",
+                  options => { default => "array" },
+                  errors => [
+                    {
+                      message_id => "error_string_array",
+                      data => {
+                        class_name => "ReadonlyArray",
+                        readonly_prefix => "readonly ",
+                        type => "T",
+                        banned_name => "ReadonlyArray<T>",
+                        replacement => "readonly T[]",
+                        option => "readonly",
+                      },
+                      line => 1,
+                      column => 12,
                     },
                   ],
+                },
+              ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::rule_tests;
+
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_array_type_rule() {
+        run_for_typescript_and_tsx(array_type_rule(), test_cases::array_type_rule_tests());
+    }
+
+    #[test]
+    fn test_array_type_rule_nested() {
+        run_for_typescript_and_tsx(
+            array_type_rule(),
+            rule_tests! {
+                valid => [
+                  {
+                    code => "let a: (string)[] = [];",
+                    options => { default => "array-simple" },
+                  },
+                  {
+                    code => "let a: null[] = [];",
+                    options => { default => "array-simple" },
+                  },
+                  {
+                    code => "let a: readonly (Readonly<string[]>)[] = [];",
+                    options => { default => "array" },
+                  },
+                  {
+                    code => "let a: ReadonlyArray<Readonly<Array<string>>> = [];",
+                    options => { default => "generic" },
+                  },
+                ],
+                invalid => [
+                  {
+                    code => "let a: ({ foo: Array<Array<Bar> | Array<any>> })[] = [];",
+                    output => "let a: ({ foo: (Bar[] | any[])[] })[] = [];",
+                    options => { default => "array" },
+                    errors => [
+                      { message_id => "error_string_array", column => 16 },
+                      { message_id => "error_string_array", column => 22 },
+                      { message_id => "error_string_array", column => 35 },
+                    ],
+                  },
+                  {
+                    code => r#"
+class Foo<T = Array<Array<Bar>>> extends Bar<T, Array<T>> implements Baz<Array<T>> {
+  private s: Array<T>;
+
+  constructor(p: Array<T>) {
+    return new Array();
+  }
+}
+                    "#,
+                    output => r#"
+class Foo<T = Bar[][]> extends Bar<T, T[]> implements Baz<T[]> {
+  private s: T[];
+
+  constructor(p: T[]) {
+    return new Array();
+  }
+}
+                    "#,
+                    options => { default => "array" },
+                    errors => [
+                      { message_id => "error_string_array", line => 2 },
+                      { message_id => "error_string_array", line => 2 },
+                      { message_id => "error_string_array", line => 2 },
+                      { message_id => "error_string_array", line => 2 },
+                      { message_id => "error_string_array", line => 3 },
+                      { message_id => "error_string_array", line => 5 },
+                    ],
+                  },
+                  {
+                    code => r#"
+interface WorkingArray {
+  outerProperty: Array<
+    { innerPropertyOne: string } & { innerPropertyTwo: string }
+  >;
+}
+
+interface BrokenArray {
+  outerProperty: Array<
+    Array<{ innerPropertyOne: string } & { innerPropertyTwo: string }>
+  >;
+}
+                    "#,
+                    output => r#"
+interface WorkingArray {
+  outerProperty: ({ innerPropertyOne: string } & { innerPropertyTwo: string })[];
+}
+
+interface BrokenArray {
+  outerProperty: ({ innerPropertyOne: string } & { innerPropertyTwo: string })[][];
+}
+                    "#,
+                    options => { default => "array" },
+                    errors => [
+                      { message_id => "error_string_array", line => 3 },
+                      { message_id => "error_string_array", line => 9 },
+                      { message_id => "error_string_array", line => 10 },
+                    ],
+                  },
+                  {
+                    code => "let xx: Array<Array<number>> = [[1, 2], [3]];",
+                    output => "let xx: number[][] = [[1, 2], [3]];",
+                    options => { default => "array-simple" },
+                    errors => [
+                      { message_id => "error_string_array_simple", column => 9 },
+                      { message_id => "error_string_array_simple", column => 15 },
+                    ],
+                  },
+                  {
+                    code => "let a: Array<(string)> = [];",
+                    output => "let a: string[] = [];",
+                    options => { default => "array-simple" },
+                    errors => [
+                      {
+                        message_id => "error_string_array_simple",
+                        data => { class_name => "Array", readonly_prefix => "", type => "string" },
+                      },
+                    ],
+                  },
+
+                  // nested readonly
+                  {
+                    code => "let a: ReadonlyArray<Array<string>> = [[]];",
+                    output => "let a: readonly string[][] = [[]];",
+                    options => { default => "array" },
+                    errors => [
+                      { message_id => "error_string_array", column => 8 },
+                      { message_id => "error_string_array", column => 22 },
+                    ],
+                  },
+                  {
+                    code => "let a: ReadonlyArray<ReadonlyArray<string>> = [[]];",
+                    output => "let a: readonly (readonly string[])[] = [[]];",
+                    options => { default => "array" },
+                    errors => [
+                      { message_id => "error_string_array", column => 8 },
+                      { message_id => "error_string_array", column => 22 },
+                    ],
+                  },
+                  {
+                    code => "let a: Array<ReadonlyArray<string>> = [[]];",
+                    output => "let a: (readonly string[])[] = [[]];",
+                    options => { default => "array" },
+                    errors => [
+                      { message_id => "error_string_array", column => 8 },
+                      { message_id => "error_string_array", column => 14 },
+                    ],
+                  },
+                  {
+                    code => "let a: ReadonlyArray<readonly string[]> = [[]];",
+                    output => "let a: readonly (readonly string[])[] = [[]];",
+                    options => { default => "array" },
+                    errors => [
+                      {
+                        message_id => "error_string_array",
+                        data => { class_name => "ReadonlyArray", readonly_prefix => "readonly ", type => "T" },
+                      },
+                    ],
+                  },
+                  {
+                    code => "let a: Array<readonly string[]> = [[]];",
+                    output => "let a: (readonly string[])[] = [[]];",
+                    options => { default => "array" },
+                    errors => [{ message_id => "error_string_array" }],
+                  },
+                  {
+                    code => "let a: readonly string[][] = [[]];",
+                    output => "let a: ReadonlyArray<Array<string>> = [[]];",
+                    options => { default => "generic" },
+                    errors => [
+                      {
+                        message_id => "error_string_generic",
+                        data => { class_name => "ReadonlyArray", readonly_prefix => "readonly ", type => "string[]" },
+                      },
+                      {
+                        message_id => "error_string_generic",
+                        data => { class_name => "Array", readonly_prefix => "", type => "string" },
+                      },
+                    ],
+                  },
+                  {
+                    code => "let a: readonly (Readonly<string[]>)[] = [];",
+                    output => "let a: ReadonlyArray<Readonly<Array<string>>> = [];",
+                    options => { default => "generic" },
+                    errors => [
+                      { message_id => "error_string_generic", column => 8 },
+                      { message_id => "error_string_generic", column => 27 },
+                    ],
+                  },
+                  {
+                    code => "let a: ReadonlyArray<Readonly<string[]>> = [];",
+                    output => "let a: readonly Readonly<string[]>[] = [];",
+                    options => { default => "array" },
+                    errors => [{ message_id => "error_string_array" }],
+                  },
+                  {
+                    code => "let a: readonly (new () => Foo)[] = [];",
+                    output => "let a: ReadonlyArray<new () => Foo> = [];",
+                    options => { default => "generic" },
+                    errors => [{ message_id => "error_string_generic" }],
+                  },
+                  {
+                    code => "let a: ReadonlyArray<(new () => Foo)> = [];",
+                    output => "let a: readonly (new () => Foo)[] = [];",
+                    options => { default => "array" },
+                    errors => [{ message_id => "error_string_array" }],
+                  },
+                  {
+                    code => "type Foo = ReadonlyArray<string>[number];",
+                    output => "type Foo = (readonly string[])[number];",
+                    options => { default => "array" },
+                    errors => [{ message_id => "error_string_array" }],
+                  },
+                  {
+                    code => "let a: ReadonlyArray[] = [];",
+                    output => "let a: (readonly any[])[] = [];",
+                    options => { default => "array" },
+                    errors => [
+                      {
+                        message_id => "error_string_array",
+                        data => { class_name => "ReadonlyArray", readonly_prefix => "readonly ", type => "any" },
+                      },
+                    ],
+                  },
+
+                  // type operators
+                  {
+                    code => "type Keys<T> = Array<keyof T>;",
+                    output => "type Keys<T> = (keyof T)[];",
+                    options => { default => "array" },
+                    errors => [
+                      {
+                        message_id => "error_string_array",
+                        data => { class_name => "Array", readonly_prefix => "", type => "T" },
+                      },
+                    ],
+                  },
+                  {
+                    code => "type Keys<T> = (keyof T)[];",
+                    output => "type Keys<T> = Array<keyof T>;",
+                    options => { default => "generic" },
+                    errors => [{ message_id => "error_string_generic" }],
+                  },
+                  {
+                    code => "type Foo<T> = Array<T extends string ? 'a' : 'b'>;",
+                    output => "type Foo<T> = (T extends string ? 'a' : 'b')[];",
+                    options => { default => "array" },
+                    errors => [{ message_id => "error_string_array" }],
+                  },
+                  {
+                    code => "type Foo = Array<typeof foo>;",
+                    output => "type Foo = typeof foo[];",
+                    options => { default => "array" },
+                    errors => [{ message_id => "error_string_array" }],
+                  },
+                ],
             },
         )
     }
@@ -0,0 +1,443 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    kind::{
+        Array, ArrowFunction, BinaryExpression, CallExpression, Class, ConditionalExpression,
+        Function, Identifier, MemberExpression, MethodDefinition, NewExpression, Object, Pair,
+        PropertyIdentifier, Regex, ShorthandPropertyIdentifier, String as StringKind,
+        TemplateString, UnaryExpression,
+    },
+    utils::ast_utils::get_static_string_value,
+};
+
+use crate::{
+    ast_helpers::{is_async_function, NodeExtTypescript},
+    kind::{
+        ArrayType, AsExpression, FunctionSignature, FunctionType, GenericType, IntersectionType,
+        LiteralType, MethodSignature, ObjectType, PredefinedType, PropertySignature, ReadonlyType,
+        SatisfiesExpression, TemplateLiteralType, TupleType, TypeIdentifier, UnionType,
+    },
+    util::{
+        evaluate_constant_expression, get_declared_type, resolve_function,
+        skip_parenthesized_expressions,
+    },
+};
+
+const PROMISE_TYPE_NAMES: [&str; 2] = ["Promise", "PromiseLike"];
+
+const PROMISE_STATIC_METHODS: [&str; 6] = ["all", "allSettled", "any", "race", "reject", "resolve"];
+
+// (builtins whose instances are never Thenables)
+const NON_THENABLE_CONSTRUCTORS: [&str; 12] = [
+    "Array",
+    "Date",
+    "Error",
+    "Map",
+    "Object",
+    "RegExp",
+    "Set",
+    "TypeError",
+    "URL",
+    "WeakMap",
+    "WeakRef",
+    "WeakSet",
+];
+
+/// Whether a value that could be any of `values` may be a Thenable:
+/// `Some(true)` if any of them is, `Some(false)` if none of them is and
+/// `None` if it can't be told.
+fn any_thenable(values: impl IntoIterator<Item = Option<bool>>) -> Option<bool> {
+    let mut all_known = true;
+    for value in values {
+        match value {
+            Some(true) => return Some(true),
+            Some(false) => (),
+            None => all_known = false,
+        }
+    }
+    all_known.then_some(false)
+}
+
+fn is_then_name<'a>(name: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    match name.kind() {
+        PropertyIdentifier | ShorthandPropertyIdentifier => name.text(context) == "then",
+        _ => get_static_string_value(name, context).is_some_and(|name| name == "then"),
+    }
+}
+
+fn object_has_then_property<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<bool> {
+    let mut has_then = false;
+    for property in node.non_comment_named_children(SupportedLanguage::Javascript) {
+        has_then |= match property.kind() {
+            Pair => is_then_name(property.field("key"), context),
+            MethodDefinition => is_then_name(property.field("name"), context),
+            ShorthandPropertyIdentifier => is_then_name(property, context),
+            // eg spreads
+            _ => return None,
+        };
+    }
+    Some(has_then)
+}
+
+fn type_literal_has_then_member<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<bool> {
+    let mut has_then = false;
+    for member in node.non_comment_named_children(SupportedLanguage::Javascript) {
+        has_then |= match member.kind() {
+            PropertySignature | MethodSignature => is_then_name(member.field("name"), context),
+            // eg index signatures
+            _ => return None,
+        };
+    }
+    Some(has_then)
+}
+
+/// Approximates whether values of the type are Thenables from its syntax.
+/// `None` for `any`/`unknown` and anything that would need the type checker
+/// (eg references to other named types).
+fn is_thenable_type<'a>(type_: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Option<bool> {
+    let type_ = type_.skip_parenthesized_types();
+    match type_.kind() {
+        PredefinedType => match &*type_.text(context) {
+            "any" | "unknown" | "never" | "object" => None,
+            _ => Some(false),
+        },
+        LiteralType | ArrayType | TupleType | FunctionType | TemplateLiteralType => Some(false),
+        ReadonlyType => is_thenable_type(
+            type_.first_non_comment_named_child(SupportedLanguage::Javascript),
+            context,
+        ),
+        UnionType => any_thenable(
+            type_
+                .non_comment_named_children(SupportedLanguage::Javascript)
+                .map(|member| is_thenable_type(member, context)),
+        ),
+        IntersectionType => type_
+            .non_comment_named_children(SupportedLanguage::Javascript)
+            .any(|member| is_thenable_type(member, context) == Some(true))
+            .then_some(true),
+        GenericType => PROMISE_TYPE_NAMES
+            .contains(&&*type_.field("name").text(context))
+            .then_some(true),
+        TypeIdentifier => PROMISE_TYPE_NAMES
+            .contains(&&*type_.text(context))
+            .then_some(true),
+        ObjectType => type_literal_has_then_member(type_, context),
+        _ => None,
+    }
+}
+
+fn is_thenable_call<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Option<bool> {
+    let function = skip_parenthesized_expressions(node.field("function"));
+    match function.kind() {
+        MemberExpression => {
+            let object = function.field("object");
+            (object.kind() == Identifier
+                && object.text(context) == "Promise"
+                && PROMISE_STATIC_METHODS.contains(&&*function.field("property").text(context)))
+            .then_some(true)
+        }
+        Identifier => {
+            let function = resolve_function(function, context)?;
+            if function.kind() != FunctionSignature && is_async_function(function) {
+                return Some(true);
+            }
+            is_thenable_type(
+                function
+                    .child_by_field_name("return_type")?
+                    .first_non_comment_named_child(SupportedLanguage::Javascript),
+                context,
+            )
+        }
+        _ => None,
+    }
+}
+
+/// Approximates whether the awaited value is a Thenable from its syntax:
+/// literals, object/array/function/class expressions, operators whose result
+/// type is fixed, `as` assertions, calls of local functions with a known
+/// return type and variables/parameters with a type annotation.
+fn is_thenable_value<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Option<bool> {
+    let node = skip_parenthesized_expressions(node);
+    if evaluate_constant_expression(node, context).is_some() {
+        return Some(false);
+    }
+    match node.kind() {
+        StringKind | TemplateString | Regex | Array | ArrowFunction | Function | Class => {
+            Some(false)
+        }
+        Object => object_has_then_property(node, context),
+        NewExpression => {
+            let constructor = node.field("constructor");
+            if constructor.kind() != Identifier {
+                return None;
+            }
+            match &*constructor.text(context) {
+                "Promise" => Some(true),
+                name if NON_THENABLE_CONSTRUCTORS.contains(&name) => Some(false),
+                _ => None,
+            }
+        }
+        CallExpression => is_thenable_call(node, context),
+        UnaryExpression => Some(false),
+        BinaryExpression => match node.field("operator").kind() {
+            "&&" | "||" | "??" => any_thenable([
+                is_thenable_value(node.field("left"), context),
+                is_thenable_value(node.field("right"), context),
+            ]),
+            _ => Some(false),
+        },
+        ConditionalExpression => any_thenable([
+            is_thenable_value(node.field("consequence"), context),
+            is_thenable_value(node.field("alternative"), context),
+        ]),
+        AsExpression => is_thenable_type(
+            node.non_comment_named_children(SupportedLanguage::Javascript)
+                .last()
+                .unwrap(),
+            context,
+        ),
+        SatisfiesExpression => is_thenable_value(
+            node.first_non_comment_named_child(SupportedLanguage::Javascript),
+            context,
+        ),
+        Identifier => is_thenable_type(get_declared_type(node, context)?, context),
+        _ => None,
+    }
+}
+
+pub fn await_thenable_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "await-thenable",
+        languages => [Typescript],
+        messages => [
+            non_thenable_await => "Unexpected `await` of a non-Promise (non-\"Thenable\") value.",
+        ],
+        listeners => [
+            r#"
+              (await_expression) @c
+            "# => |node, context| {
+                let argument = node.first_non_comment_named_child(SupportedLanguage::Javascript);
+                if is_thenable_value(argument, context) != Some(false) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "non_thenable_await",
+                    // TODO: suggestions?
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn await_thenable_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "
+async function test() {
+  await Promise.resolve('value');
+  await Promise.reject(new Error('message'));
+}
+                    ",
+                "
+async function test() {
+  await (async () => true)();
+}
+                    ",
+                "
+async function test() {
+  function returnsPromise() {
+    return Promise.resolve('value');
+  }
+  await returnsPromise();
+}
+                    ",
+                "
+async function test() {
+  async function returnsPromise(): Promise<string> {
+    return 'value';
+  }
+  await returnsPromise();
+}
+                    ",
+                "
+async function test() {
+  function returnsPromise(): Promise<string> | number {
+    return 1;
+  }
+  await returnsPromise();
+}
+                    ",
+                "
+async function test() {
+  const value = new Promise<string>(resolve => resolve('value'));
+  await value;
+}
+                    ",
+                "
+async function test() {
+  await new Promise<string>(resolve => resolve('value'));
+}
+                    ",
+                "
+async function test(value: any) {
+  await value;
+}
+                    ",
+                "
+async function test(value: unknown) {
+  await value;
+}
+                    ",
+                "
+async function test(value: PromiseLike<string>) {
+  await value;
+}
+                    ",
+                "
+async function test(value: Promise<string> | undefined) {
+  await value;
+}
+                    ",
+                "
+async function test() {
+  await { then() {} };
+}
+                    ",
+                "
+async function test(thenable: { then(onfulfilled: () => void): void }) {
+  await thenable;
+}
+                    ",
+                "
+async function test(value: SomeInterface) {
+  await value;
+}
+                    ",
+                "
+async function test() {
+  await (foo as Promise<number>);
+}
+                    ",
+                "
+async function test() {
+  await foo.bar();
+}
+                    ",
+                "
+async function test() {
+  await (Math.random() > 0.5 ? foo() : 0);
+}
+                    ",
+            ],
+            invalid => [
+                {
+                  code => "
+async function test() {
+  await 0;
+  await 'value';
+  await `template`;
+  await null;
+  await undefined;
+}
+                      ",
+                  errors => [
+                    { message_id => "non_thenable_await", line => 3, column => 3 },
+                    { message_id => "non_thenable_await", line => 4, column => 3 },
+                    { message_id => "non_thenable_await", line => 5, column => 3 },
+                    { message_id => "non_thenable_await", line => 6, column => 3 },
+                    { message_id => "non_thenable_await", line => 7, column => 3 },
+                  ],
+                },
+                {
+                  code => "
+async function test() {
+  await (Math.random() > 0.5 ? '' : 0);
+}
+                      ",
+                  errors => [{ message_id => "non_thenable_await", line => 3 }],
+                },
+                {
+                  code => "
+async function test() {
+  await new Map();
+  await [];
+  await {};
+  await (() => {});
+}
+                      ",
+                  errors => [
+                    { message_id => "non_thenable_await", line => 3 },
+                    { message_id => "non_thenable_await", line => 4 },
+                    { message_id => "non_thenable_await", line => 5 },
+                    { message_id => "non_thenable_await", line => 6 },
+                  ],
+                },
+                {
+                  code => "
+async function test(value: number | string) {
+  await value;
+}
+                      ",
+                  errors => [{ message_id => "non_thenable_await", line => 3 }],
+                },
+                {
+                  code => "
+async function test() {
+  function getValue(): string {
+    return 'value';
+  }
+  await getValue();
+}
+                      ",
+                  errors => [{ message_id => "non_thenable_await", line => 6 }],
+                },
+                {
+                  code => "
+async function test() {
+  await (foo as { bar: number });
+}
+                      ",
+                  errors => [{ message_id => "non_thenable_await", line => 3 }],
+                },
+                {
+                  code => "
+async function test(value: readonly string[]) {
+  await value;
+}
+                      ",
+                  errors => [{ message_id => "non_thenable_await", line => 3 }],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_await_thenable_rule() {
+        run_for_typescript_and_tsx(
+            await_thenable_rule(),
+            test_cases::await_thenable_rule_tests(),
+        );
+    }
+}
@@ -4,12 +4,14 @@ use regex::Regex;
 use serde::Deserialize;
 use squalid::regex;
 use tree_sitter_lint::{rule, violation, Rule};
-use tree_sitter_lint_plugin_eslint_builtin::{
-    ast_helpers::{get_comment_contents, get_comment_type, CommentType},
-    AllComments,
+use tree_sitter_lint_plugin_eslint_builtin::ast_helpers::{
+    get_comment_contents, get_comment_type, CommentType,
 };
 
-use crate::util::get_string_length;
+use crate::{
+    config_warnings::{ConfigWarning, ValidateOptions},
+    util::get_string_length,
+};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "kebab-case")]
@@ -50,7 +52,7 @@ impl From<DescriptionFormat> for DirectiveConfig {
 
 #[derive(Debug, Default, Deserialize)]
 #[serde(default)]
-struct Options {
+pub(crate) struct Options {
     #[serde(rename = "ts-expect-error")]
     ts_expect_error: Option<DirectiveConfig>,
     #[serde(rename = "ts-ignore")]
@@ -86,6 +88,42 @@ impl Options {
     }
 }
 
+impl ValidateOptions for Options {
+    fn validate(&self) -> Vec<ConfigWarning> {
+        let Some(minimum_description_length) = self.minimum_description_length else {
+            return Default::default();
+        };
+        let requires_description = [
+            self.ts_expect_error(),
+            self.ts_ignore(),
+            self.ts_nocheck(),
+            self.ts_check(),
+        ]
+        .into_iter()
+        .any(|option| {
+            matches!(
+                option,
+                DirectiveConfig::AllowWithDescription(_) | DirectiveConfig::DescriptionFormat(_)
+            )
+        });
+        if !requires_description {
+            return vec![ConfigWarning::new(
+                "ban-ts-comment",
+                "minimum_description_length",
+                "`minimum_description_length` has no effect because no directive is configured as \"allow-with-description\" (or with a `description_format`).",
+            )];
+        }
+        if minimum_description_length == 0 {
+            return vec![ConfigWarning::new(
+                "ban-ts-comment",
+                "minimum_description_length",
+                "`minimum_description_length` is 0, so directives configured as \"allow-with-description\" don't actually need a description. To allow them outright, configure them as `false` instead.",
+            )];
+        }
+        Default::default()
+    }
+}
+
 fn populate_description_format(
     description_formats: &mut HashMap<&'static str, Regex>,
     option: DirectiveConfig,
@@ -126,1046 +164,1125 @@ pub fn ban_ts_comment_rule() -> Arc<dyn Rule> {
         },
         listeners => [
             r#"
-              (program) @c
-            "# => |node, context| {
-                for &comment in context.retrieve::<AllComments<'a>>().iter() {
-                    let reg_exp = match get_comment_type(comment, context) {
-                        CommentType::Line => regex!(r#"^/*\s*@ts-(?<directive>expect-error|ignore|check|nocheck)(?<description>.*)"#),
-                        CommentType::Block => regex!(r#"^\s*(?:/|\*)*\s*@ts-(?<directive>expect-error|ignore|check|nocheck)(?<description>.*)"#),
-                    };
+              (comment) @c
+            "# => |comment, context| {
+                let reg_exp = match get_comment_type(comment, context) {
+                    CommentType::Line => regex!(r#"^/*\s*@ts-(?<directive>expect-error|ignore|check|nocheck)(?<description>.*)"#),
+                    CommentType::Block => regex!(r#"^\s*(?:/|\*)*\s*@ts-(?<directive>expect-error|ignore|check|nocheck)(?<description>.*)"#),
+                };
 
-                    let comment_contents = get_comment_contents(comment, context);
-                    let Some(match_) = reg_exp.captures(&comment_contents) else {
-                        return;
-                    };
-                    let directive = &match_["directive"];
-                    let description = &match_["description"];
+                let comment_contents = get_comment_contents(comment, context);
+                let Some(match_) = reg_exp.captures(&comment_contents) else {
+                    return;
+                };
+                let directive = &match_["directive"];
+                let description = &match_["description"];
 
-                    let full_directive = format!("ts-{directive}");
+                let full_directive = format!("ts-{directive}");
 
-                    let option = match &*full_directive {
-                        "ts-expect-error" => &self.ts_expect_error,
-                        "ts-ignore" => &self.ts_ignore,
-                        "ts-nocheck" => &self.ts_nocheck,
-                        "ts-check" => &self.ts_check,
-                        _ => unreachable!(),
-                    };
-                    match option {
-                        DirectiveConfig::Bool(true) => {
-                            if directive == "ignore" {
-                                context.report(violation! {
-                                    node => comment,
-                                    message_id => "ts_ignore_instead_of_expect_error",
-                                    // TODO: suggestions
-                                });
-                            } else {
-                                context.report(violation! {
-                                    data => {
-                                        directive => directive,
-                                    },
-                                    node => comment,
-                                    message_id => "ts_directive_comment",
-                                });
-                            }
+                let option = match &*full_directive {
+                    "ts-expect-error" => &self.ts_expect_error,
+                    "ts-ignore" => &self.ts_ignore,
+                    "ts-nocheck" => &self.ts_nocheck,
+                    "ts-check" => &self.ts_check,
+                    _ => unreachable!(),
+                };
+                match option {
+                    DirectiveConfig::Bool(true) => {
+                        if directive == "ignore" {
+                            context.report(violation! {
+                                data => {
+                                    banned_name => "@ts-ignore",
+                                    replacement => "@ts-expect-error",
+                                    option => &*full_directive,
+                                },
+                                node => comment,
+                                message_id => "ts_ignore_instead_of_expect_error",
+                                // TODO: suggestions
+                            });
+                        } else {
+                            context.report(violation! {
+                                data => {
+                                    directive => directive,
+                                    banned_name => format!("@{full_directive}"),
+                                    option => &*full_directive,
+                                },
+                                node => comment,
+                                message_id => "ts_directive_comment",
+                            });
                         }
-                        DirectiveConfig::AllowWithDescription(_) | DirectiveConfig::DescriptionFormat(_) => {
-                            let format = self.description_formats.get(&&*full_directive);
-                            if get_string_length(description.trim()) < self.minimum_description_length {
-                                context.report(violation! {
-                                    data => {
-                                        directive => directive,
-                                        minimum_description_length => self.minimum_description_length,
-                                    },
-                                    node => comment,
-                                    message_id => "ts_directive_comment_requires_description",
-                                });
-                            } else if let Some(format) = format.filter(|format| {
-                                !format.is_match(description)
-                            }) {
-                                context.report(violation! {
-                                    data => {
-                                        directive => directive,
-                                        format => format.as_str(),
-                                    },
-                                    node => comment,
-                                    message_id => "ts_directive_comment_description_not_match_pattern",
-                                });
-                            }
+                    }
+                    DirectiveConfig::AllowWithDescription(_) | DirectiveConfig::DescriptionFormat(_) => {
+                        let format = self.description_formats.get(&&*full_directive);
+                        if get_string_length(description.trim()) < self.minimum_description_length {
+                            context.report(violation! {
+                                data => {
+                                    directive => directive,
+                                    minimum_description_length => self.minimum_description_length,
+                                    banned_name => format!("@{full_directive}"),
+                                    option => &*full_directive,
+                                },
+                                node => comment,
+                                message_id => "ts_directive_comment_requires_description",
+                            });
+                        } else if let Some(format) = format.filter(|format| {
+                            !format.is_match(description)
+                        }) {
+                            context.report(violation! {
+                                data => {
+                                    directive => directive,
+                                    format => format.as_str(),
+                                    banned_name => format!("@{full_directive}"),
+                                    option => &*full_directive,
+                                },
+                                node => comment,
+                                message_id => "ts_directive_comment_description_not_match_pattern",
+                            });
                         }
-                        _ => ()
                     }
+                    _ => ()
                 }
             },
         ],
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use tree_sitter_lint::{rule_tests, RuleTester};
-
-    use super::*;
-    use crate::get_instance_provider_factory;
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
 
-    #[test]
-    fn test_ban_ts_comment_rule() {
-        RuleTester::run_with_from_file_run_context_instance_provider(
-            ban_ts_comment_rule(),
-            rule_tests! {
-                valid => [
-                    "// just a comment containing @ts-expect-error somewhere",
-                    r#"
+    pub fn ban_ts_comment_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "// just a comment containing @ts-expect-error somewhere",
+                r#"
                         /*
                          @ts-expect-error running with long description in a block
                         */
                     "#,
-                    {
-                        code => "// @ts-expect-error",
-                        options => { "ts-expect-error" => false },
-                    },
-                    {
-                        code => "// @ts-expect-error here is why the error is expected",
-                        options => {
-                          "ts-expect-error" => "allow-with-description",
-                        },
+                {
+                    code => "// @ts-expect-error",
+                    options => { "ts-expect-error" => false },
+                },
+                {
+                    code => "// @ts-expect-error here is why the error is expected",
+                    options => {
+                      "ts-expect-error" => "allow-with-description",
                     },
-                    {
-                        code => "// @ts-expect-error exactly 21 characters",
-                        options => {
-                            "ts-expect-error" => "allow-with-description",
-                            minimum_description_length => 21,
-                        },
+                },
+                {
+                    code => "// @ts-expect-error exactly 21 characters",
+                    options => {
+                        "ts-expect-error" => "allow-with-description",
+                        minimum_description_length => 21,
                     },
-                    {
-                        code => "// @ts-expect-error: TS1234 because xyz",
-                        options => {
-                            "ts-expect-error" => {
-                                description_format => "^: TS\\d+ because .+$",
-                            },
-                            minimum_description_length => 10,
+                },
+                {
+                    code => "// @ts-expect-error: TS1234 because xyz",
+                    options => {
+                        "ts-expect-error" => {
+                            description_format => "^: TS\\d+ because .+$",
                         },
+                        minimum_description_length => 10,
                     },
-                    {
-                      code => r#"// @ts-expect-error 👨‍👩‍👧‍👦👨‍👩‍👧‍👦👨‍👩‍👧‍👦"#,
-                      options => {
-                          "ts-expect-error" => "allow-with-description",
-                      },
-                    },
-                    "// just a comment containing @ts-ignore somewhere",
-                    {
-                      code => "// @ts-ignore",
-                      options => { "ts-ignore" => false },
-                    },
-                    {
-                      code => "// @ts-ignore I think that I am exempted from any need to follow the rules!",
-                      options => { "ts-ignore" => "allow-with-description" },
-                    },
-                    {
-                      code => r#"
+                },
+                {
+                  code => r#"// @ts-expect-error 👨‍👩‍👧‍👦👨‍👩‍👧‍👦👨‍👩‍👧‍👦"#,
+                  options => {
+                      "ts-expect-error" => "allow-with-description",
+                  },
+                },
+                "// just a comment containing @ts-ignore somewhere",
+                {
+                  code => "// @ts-ignore",
+                  options => { "ts-ignore" => false },
+                },
+                {
+                  code => "// @ts-ignore I think that I am exempted from any need to follow the rules!",
+                  options => { "ts-ignore" => "allow-with-description" },
+                },
+                {
+                  code => r#"
                 /*
                  @ts-ignore running with long description in a block
                 */
                       "#,
-                      options => {
-                        "ts-ignore" => "allow-with-description",
-                        minimum_description_length => 21,
-                      },
-                    },
-                    {
-                      code => "// @ts-ignore: TS1234 because xyz",
-                      options => {
-                        "ts-ignore" => {
-                          description_format => "^: TS\\d+ because .+$",
-                        },
-                        minimum_description_length => 10,
-                      },
-                    },
-                    {
-                      code => r#"// @ts-ignore 👨‍👩‍👧‍👦👨‍👩‍👧‍👦👨‍👩‍👧‍👦"#,
-                      options => {
-                        "ts-ignore" => "allow-with-description",
-                      },
-                    },
-                    "// just a comment containing @ts-nocheck somewhere",
-                    {
-                      code => "// @ts-nocheck",
-                      options => { "ts-nocheck" => false },
-                    },
-                    {
-                      code => "// @ts-nocheck no doubt, people will put nonsense here from time to time just to get the rule to stop reporting, perhaps even long messages with other nonsense in them like other // @ts-nocheck or // @ts-ignore things",
-                      options => { "ts-nocheck" => "allow-with-description" },
+                  options => {
+                    "ts-ignore" => "allow-with-description",
+                    minimum_description_length => 21,
+                  },
+                },
+                {
+                  code => "// @ts-ignore: TS1234 because xyz",
+                  options => {
+                    "ts-ignore" => {
+                      description_format => "^: TS\\d+ because .+$",
                     },
-                    {
-                      code => r#"
+                    minimum_description_length => 10,
+                  },
+                },
+                {
+                  code => r#"// @ts-ignore 👨‍👩‍👧‍👦👨‍👩‍👧‍👦👨‍👩‍👧‍👦"#,
+                  options => {
+                    "ts-ignore" => "allow-with-description",
+                  },
+                },
+                "// just a comment containing @ts-nocheck somewhere",
+                {
+                  code => "// @ts-nocheck",
+                  options => { "ts-nocheck" => false },
+                },
+                {
+                  code => "// @ts-nocheck no doubt, people will put nonsense here from time to time just to get the rule to stop reporting, perhaps even long messages with other nonsense in them like other // @ts-nocheck or // @ts-ignore things",
+                  options => { "ts-nocheck" => "allow-with-description" },
+                },
+                {
+                  code => r#"
                 /*
                  @ts-nocheck running with long description in a block
                 */
                       "#,
-                      options => {
-                        "ts-nocheck" => "allow-with-description",
-                        minimum_description_length => 21,
-                      },
-                    },
-                    {
-                      code => "// @ts-nocheck: TS1234 because xyz",
-                      options => {
-                        "ts-nocheck" => {
-                          description_format => "^: TS\\d+ because .+$",
-                        },
-                        minimum_description_length => 10,
-                      },
-                    },
-                    {
-                      code => r#"// @ts-nocheck 👨‍👩‍👧‍👦👨‍👩‍👧‍👦👨‍👩‍👧‍👦"#,
-                      options => {
-                        "ts-nocheck" => "allow-with-description",
-                      },
+                  options => {
+                    "ts-nocheck" => "allow-with-description",
+                    minimum_description_length => 21,
+                  },
+                },
+                {
+                  code => "// @ts-nocheck: TS1234 because xyz",
+                  options => {
+                    "ts-nocheck" => {
+                      description_format => "^: TS\\d+ because .+$",
                     },
-                    "// just a comment containing @ts-check somewhere",
-                    r#"
+                    minimum_description_length => 10,
+                  },
+                },
+                {
+                  code => r#"// @ts-nocheck 👨‍👩‍👧‍👦👨‍👩‍👧‍👦👨‍👩‍👧‍👦"#,
+                  options => {
+                    "ts-nocheck" => "allow-with-description",
+                  },
+                },
+                "// just a comment containing @ts-check somewhere",
+                r#"
                 /*
                  @ts-check running with long description in a block
                 */
                     "#,
-                    {
-                      code => "// @ts-check",
-                      options => { "ts-check" => false },
-                    },
-                    {
-                      code => "// @ts-check with a description and also with a no-op // @ts-ignore",
-                      options => { "ts-check" => "allow-with-description", minimum_description_length => 3 },
-                    },
-                    {
-                      code => "// @ts-check: TS1234 because xyz",
-                      options => {
-                        "ts-check" => {
-                          description_format => "^: TS\\d+ because .+$",
-                        },
-                        minimum_description_length => 10,
-                      },
-                    },
-                    {
-                      code => r#"// @ts-check 👨‍👩‍👧‍👦👨‍👩‍👧‍👦👨‍👩‍👧‍👦"#,
-                      options => {
-                        "ts-check" => "allow-with-description",
-                      },
+                {
+                  code => "// @ts-check",
+                  options => { "ts-check" => false },
+                },
+                {
+                  code => "// @ts-check with a description and also with a no-op // @ts-ignore",
+                  options => { "ts-check" => "allow-with-description", minimum_description_length => 3 },
+                },
+                {
+                  code => "// @ts-check: TS1234 because xyz",
+                  options => {
+                    "ts-check" => {
+                      description_format => "^: TS\\d+ because .+$",
                     },
+                    minimum_description_length => 10,
+                  },
+                },
+                {
+                  code => r#"// @ts-check 👨‍👩‍👧‍👦👨‍👩‍👧‍👦👨‍👩‍👧‍👦"#,
+                  options => {
+                    "ts-check" => "allow-with-description",
+                  },
+                },
+            ],
+            invalid => [
+              {
+                code => "// @ts-expect-error",
+                options => { "ts-expect-error" => true },
+                errors => [
+                  {
+                    data => { directive => "expect-error" },
+                    message_id => "ts_directive_comment",
+                    line => 1,
+                    column => 1,
+                  },
                 ],
-                invalid => [
+              },
+              {
+                code => "/* @ts-expect-error */",
+                options => { "ts-expect-error" => true },
+                errors => [
                   {
-                    code => "// @ts-expect-error",
-                    options => { "ts-expect-error" => true },
-                    errors => [
-                      {
-                        data => { directive => "expect-error" },
-                        message_id => "ts_directive_comment",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "/* @ts-expect-error */",
-                    options => { "ts-expect-error" => true },
-                    errors => [
-                      {
-                        data => { directive => "expect-error" },
-                        message_id => "ts_directive_comment",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => r#"
+                    data => { directive => "expect-error" },
+                    message_id => "ts_directive_comment",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => r#"
 /*
 @ts-expect-error
 */
                     "#,
-                    options => { "ts-expect-error" => true },
-                    errors => [
-                      {
-                        data => { directive => "expect-error" },
-                        message_id => "ts_directive_comment",
-                        line => 2,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "/** @ts-expect-error */",
-                    options => { "ts-expect-error" => true },
-                    errors => [
-                      {
-                        data => { directive => "expect-error" },
-                        message_id => "ts_directive_comment",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "// @ts-expect-error: Suppress next line",
-                    options => { "ts-expect-error" => true },
-                    errors => [
-                      {
-                        data => { directive => "expect-error" },
-                        message_id => "ts_directive_comment",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "/////@ts-expect-error: Suppress next line",
-                    options => { "ts-expect-error" => true },
-                    errors => [
-                      {
-                        data => { directive => "expect-error" },
-                        message_id => "ts_directive_comment",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => r#"
+                options => { "ts-expect-error" => true },
+                errors => [
+                  {
+                    data => { directive => "expect-error" },
+                    message_id => "ts_directive_comment",
+                    line => 2,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "/** @ts-expect-error */",
+                options => { "ts-expect-error" => true },
+                errors => [
+                  {
+                    data => { directive => "expect-error" },
+                    message_id => "ts_directive_comment",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "// @ts-expect-error: Suppress next line",
+                options => { "ts-expect-error" => true },
+                errors => [
+                  {
+                    data => { directive => "expect-error" },
+                    message_id => "ts_directive_comment",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "/////@ts-expect-error: Suppress next line",
+                options => { "ts-expect-error" => true },
+                errors => [
+                  {
+                    data => { directive => "expect-error" },
+                    message_id => "ts_directive_comment",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => r#"
 if (false) {
   // @ts-expect-error: Unreachable code error
   console.log('hello');
 }
                     "#,
-                    options => { "ts-expect-error" => true },
-                    errors => [
-                      {
-                        data => { directive => "expect-error" },
-                        message_id => "ts_directive_comment",
-                        line => 3,
-                        column => 3,
-                      },
-                    ],
+                options => { "ts-expect-error" => true },
+                errors => [
+                  {
+                    data => { directive => "expect-error" },
+                    message_id => "ts_directive_comment",
+                    line => 3,
+                    column => 3,
                   },
+                ],
+              },
+              {
+                code => "// @ts-expect-error",
+                options => {
+                  "ts-expect-error" => "allow-with-description",
+                },
+                errors => [
                   {
-                    code => "// @ts-expect-error",
-                    options => {
-                      "ts-expect-error" => "allow-with-description",
+                    data => { directive => "expect-error", minimum_description_length => 3 },
+                    message_id => "ts_directive_comment_requires_description",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "// @ts-expect-error: TODO",
+                options => {
+                  "ts-expect-error" => "allow-with-description",
+                  minimum_description_length => 10,
+                },
+                errors => [
+                  {
+                    data => { directive => "expect-error", minimum_description_length => 10 },
+                    message_id => "ts_directive_comment_requires_description",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "// @ts-expect-error: TS1234 because xyz",
+                options => {
+                  "ts-expect-error" => {
+                    description_format => "^: TS\\d+ because .+$",
+                  },
+                  minimum_description_length => 25,
+                },
+                errors => [
+                  {
+                    data => { directive => "expect-error", minimum_description_length => 25 },
+                    message_id => "ts_directive_comment_requires_description",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "// @ts-expect-error: TS1234",
+                options => {
+                  "ts-expect-error" => {
+                    description_format => "^: TS\\d+ because .+$",
+                  },
+                },
+                errors => [
+                  {
+                    data => { directive => "expect-error", format => "^: TS\\d+ because .+$" },
+                    message_id => "ts_directive_comment_description_not_match_pattern",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => r#"// @ts-expect-error    : TS1234 because xyz"#,
+                options =>
+                  {
+                    "ts-expect-error" => {
+                      description_format => "^: TS\\d+ because .+$",
                     },
-                    errors => [
-                      {
-                        data => { directive => "expect-error", minimum_description_length => 3 },
-                        message_id => "ts_directive_comment_requires_description",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
                   },
+                errors => [
                   {
-                    code => "// @ts-expect-error: TODO",
-                    options => {
-                      "ts-expect-error" => "allow-with-description",
-                      minimum_description_length => 10,
+                    data => { directive => "expect-error", format => "^: TS\\d+ because .+$" },
+                    message_id => "ts_directive_comment_description_not_match_pattern",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => r#"// @ts-expect-error 👨‍👩‍👧‍👦"#,
+                options =>
+                  {
+                    "ts-expect-error" => "allow-with-description",
+                  },
+                errors => [
+                  {
+                    data => { directive => "expect-error", minimum_description_length => 3 },
+                    message_id => "ts_directive_comment_requires_description",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "// @ts-ignore",
+                options => { "ts-ignore" => true, "ts-expect-error" => true },
+                errors => [
+                  {
+                    message_id => "ts_ignore_instead_of_expect_error",
+                    data => {
+                      banned_name => "@ts-ignore",
+                      replacement => "@ts-expect-error",
+                      option => "ts-ignore",
                     },
-                    errors => [
-                      {
-                        data => { directive => "expect-error", minimum_description_length => 10 },
-                        message_id => "ts_directive_comment_requires_description",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
+                    line => 1,
+                    column => 1,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "replaceTsIgnoreWithTsExpectError",
+                    //     output: "// @ts-expect-error",
+                    //   },
+                    // ],
                   },
+                ],
+              },
+              {
+                code => "// @ts-ignore",
+                options =>
+                  { "ts-ignore" => true, "ts-expect-error" => "allow-with-description" },
+                errors => [
                   {
-                    code => "// @ts-expect-error: TS1234 because xyz",
-                    options => {
-                      "ts-expect-error" => {
-                        description_format => "^: TS\\d+ because .+$",
-                      },
-                      minimum_description_length => 25,
+                    message_id => "ts_ignore_instead_of_expect_error",
+                    data => {
+                      banned_name => "@ts-ignore",
+                      replacement => "@ts-expect-error",
+                      option => "ts-ignore",
                     },
-                    errors => [
-                      {
-                        data => { directive => "expect-error", minimum_description_length => 25 },
-                        message_id => "ts_directive_comment_requires_description",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
+                    line => 1,
+                    column => 1,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "replaceTsIgnoreWithTsExpectError",
+                    //     output: "// @ts-expect-error",
+                    //   },
+                    // ],
                   },
+                ],
+              },
+              {
+                code => "// @ts-ignore",
+                errors => [
                   {
-                    code => "// @ts-expect-error: TS1234",
-                    options => {
-                      "ts-expect-error" => {
-                        description_format => "^: TS\\d+ because .+$",
-                      },
+                    message_id => "ts_ignore_instead_of_expect_error",
+                    data => {
+                      banned_name => "@ts-ignore",
+                      replacement => "@ts-expect-error",
+                      option => "ts-ignore",
                     },
-                    errors => [
-                      {
-                        data => { directive => "expect-error", format => "^: TS\\d+ because .+$" },
-                        message_id => "ts_directive_comment_description_not_match_pattern",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => r#"// @ts-expect-error    : TS1234 because xyz"#,
-                    options =>
-                      {
-                        "ts-expect-error" => {
-                          description_format => "^: TS\\d+ because .+$",
-                        },
-                      },
-                    errors => [
-                      {
-                        data => { directive => "expect-error", format => "^: TS\\d+ because .+$" },
-                        message_id => "ts_directive_comment_description_not_match_pattern",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => r#"// @ts-expect-error 👨‍👩‍👧‍👦"#,
-                    options =>
-                      {
-                        "ts-expect-error" => "allow-with-description",
-                      },
-                    errors => [
-                      {
-                        data => { directive => "expect-error", minimum_description_length => 3 },
-                        message_id => "ts_directive_comment_requires_description",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "// @ts-ignore",
-                    options => { "ts-ignore" => true, "ts-expect-error" => true },
-                    errors => [
-                      {
-                        message_id => "ts_ignore_instead_of_expect_error",
-                        line => 1,
-                        column => 1,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "replaceTsIgnoreWithTsExpectError",
-                        //     output: "// @ts-expect-error",
-                        //   },
-                        // ],
-                      },
-                    ],
-                  },
-                  {
-                    code => "// @ts-ignore",
-                    options =>
-                      { "ts-ignore" => true, "ts-expect-error" => "allow-with-description" },
-                    errors => [
-                      {
-                        message_id => "ts_ignore_instead_of_expect_error",
-                        line => 1,
-                        column => 1,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "replaceTsIgnoreWithTsExpectError",
-                        //     output: "// @ts-expect-error",
-                        //   },
-                        // ],
-                      },
-                    ],
-                  },
-                  {
-                    code => "// @ts-ignore",
-                    errors => [
-                      {
-                        message_id => "ts_ignore_instead_of_expect_error",
-                        line => 1,
-                        column => 1,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "replaceTsIgnoreWithTsExpectError",
-                        //     output: "// @ts-expect-error",
-                        //   },
-                        // ],
-                      },
-                    ],
-                  },
-                  {
-                    code => "/* @ts-ignore */",
-                    options => { "ts-ignore" => true },
-                    errors => [
-                      {
-                        message_id => "ts_ignore_instead_of_expect_error",
-                        line => 1,
-                        column => 1,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "replaceTsIgnoreWithTsExpectError",
-                        //     output: "/* @ts-expect-error */",
-                        //   },
-                        // ],
-                      },
-                    ],
-                  },
-                  {
-                    code => r#"
+                    line => 1,
+                    column => 1,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "replaceTsIgnoreWithTsExpectError",
+                    //     output: "// @ts-expect-error",
+                    //   },
+                    // ],
+                  },
+                ],
+              },
+              {
+                code => "/* @ts-ignore */",
+                options => { "ts-ignore" => true },
+                errors => [
+                  {
+                    message_id => "ts_ignore_instead_of_expect_error",
+                    data => {
+                      banned_name => "@ts-ignore",
+                      replacement => "@ts-expect-error",
+                      option => "ts-ignore",
+                    },
+                    line => 1,
+                    column => 1,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "replaceTsIgnoreWithTsExpectError",
+                    //     output: "/* @ts-expect-error */",
+                    //   },
+                    // ],
+                  },
+                ],
+              },
+              {
+                code => r#"
 /*
  @ts-ignore
 */
                     "#,
-                    options => { "ts-ignore" => true },
-                    errors => [
-                      {
-                        message_id => "ts_ignore_instead_of_expect_error",
-                        line => 2,
-                        column => 1,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "replaceTsIgnoreWithTsExpectError",
-                        //     output: r#"
-              // /*
-               // @ts-expect-error
-              // */
-                    // "#,
-                        //   },
-                        // ],
-                      },
-                    ],
-                  },
-                  {
-                    code => "/** @ts-ignore */",
-                    options => { "ts-ignore" => true, "ts-expect-error" => false },
-                    errors => [
-                      {
-                        message_id => "ts_ignore_instead_of_expect_error",
-                        line => 1,
-                        column => 1,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "replaceTsIgnoreWithTsExpectError",
-                        //     output: "/** @ts-expect-error */",
-                        //   },
-                        // ],
-                      },
-                    ],
-                  },
-                  {
-                    code => "// @ts-ignore: Suppress next line",
-                    errors => [
-                      {
-                        message_id => "ts_ignore_instead_of_expect_error",
-                        line => 1,
-                        column => 1,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "replaceTsIgnoreWithTsExpectError",
-                        //     output: "// @ts-expect-error: Suppress next line",
-                        //   },
-                        // ],
-                      },
-                    ],
-                  },
-                  {
-                    code => "/////@ts-ignore: Suppress next line",
-                    errors => [
-                      {
-                        message_id => "ts_ignore_instead_of_expect_error",
-                        line => 1,
-                        column => 1,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "replaceTsIgnoreWithTsExpectError",
-                        //     output: "/////@ts-expect-error: Suppress next line",
-                        //   },
-                        // ],
-                      },
-                    ],
-                  },
-                  {
-                    code => r#"
+                options => { "ts-ignore" => true },
+                errors => [
+                  {
+                    message_id => "ts_ignore_instead_of_expect_error",
+                    data => {
+                      banned_name => "@ts-ignore",
+                      replacement => "@ts-expect-error",
+                      option => "ts-ignore",
+                    },
+                    line => 2,
+                    column => 1,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "replaceTsIgnoreWithTsExpectError",
+                    //     output: r#"
+          // /*
+           // @ts-expect-error
+          // */
+                // "#,
+                    //   },
+                    // ],
+                  },
+                ],
+              },
+              {
+                code => "/** @ts-ignore */",
+                options => { "ts-ignore" => true, "ts-expect-error" => false },
+                errors => [
+                  {
+                    message_id => "ts_ignore_instead_of_expect_error",
+                    data => {
+                      banned_name => "@ts-ignore",
+                      replacement => "@ts-expect-error",
+                      option => "ts-ignore",
+                    },
+                    line => 1,
+                    column => 1,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "replaceTsIgnoreWithTsExpectError",
+                    //     output: "/** @ts-expect-error */",
+                    //   },
+                    // ],
+                  },
+                ],
+              },
+              {
+                code => "// @ts-ignore: Suppress next line",
+                errors => [
+                  {
+                    message_id => "ts_ignore_instead_of_expect_error",
+                    data => {
+                      banned_name => "@ts-ignore",
+                      replacement => "@ts-expect-error",
+                      option => "ts-ignore",
+                    },
+                    line => 1,
+                    column => 1,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "replaceTsIgnoreWithTsExpectError",
+                    //     output: "// @ts-expect-error: Suppress next line",
+                    //   },
+                    // ],
+                  },
+                ],
+              },
+              {
+                code => "/////@ts-ignore: Suppress next line",
+                errors => [
+                  {
+                    message_id => "ts_ignore_instead_of_expect_error",
+                    data => {
+                      banned_name => "@ts-ignore",
+                      replacement => "@ts-expect-error",
+                      option => "ts-ignore",
+                    },
+                    line => 1,
+                    column => 1,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "replaceTsIgnoreWithTsExpectError",
+                    //     output: "/////@ts-expect-error: Suppress next line",
+                    //   },
+                    // ],
+                  },
+                ],
+              },
+              {
+                code => r#"
 if (false) {
   // @ts-ignore: Unreachable code error
   console.log('hello');
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "ts_ignore_instead_of_expect_error",
-                        line => 3,
-                        column => 3,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "replaceTsIgnoreWithTsExpectError",
-                        //     output: r#"
-              // if (false) {
-                // // @ts-expect-error: Unreachable code error
-                // console.log('hello');
-              // }
-                    // "#,
-                        //   },
-                        // ],
-                      },
-                    ],
-                  },
-                  {
-                    code => "// @ts-ignore",
-                    options => { "ts-ignore" => "allow-with-description" },
-                    errors => [
-                      {
-                        data => { directive => "ignore", minimum_description_length => 3 },
-                        message_id => "ts_directive_comment_requires_description",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => r#"// @ts-ignore         "#,
-                    options => { "ts-ignore" => "allow-with-description" },
-                    errors => [
-                      {
-                        data => { directive => "ignore", minimum_description_length => 3 },
-                        message_id => "ts_directive_comment_requires_description",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "// @ts-ignore    .",
-                    options => { "ts-ignore" => "allow-with-description" },
-                    errors => [
-                      {
-                        data => { directive => "ignore", minimum_description_length => 3 },
-                        message_id => "ts_directive_comment_requires_description",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "// @ts-ignore: TS1234 because xyz",
-                    options =>
-                      {
-                        "ts-ignore" => {
-                          description_format => "^: TS\\d+ because .+$",
-                        },
-                        minimum_description_length => 25,
-                      },
-                    errors => [
-                      {
-                        data => { directive => "ignore", minimum_description_length => 25 },
-                        message_id => "ts_directive_comment_requires_description",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "// @ts-ignore: TS1234",
-                    options =>
-                      {
-                        "ts-ignore" => {
-                          description_format => "^: TS\\d+ because .+$",
-                        },
-                      },
-                    errors => [
-                      {
-                        data => { directive => "ignore", format => "^: TS\\d+ because .+$" },
-                        message_id => "ts_directive_comment_description_not_match_pattern",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => r#"// @ts-ignore    : TS1234 because xyz"#,
-                    options =>
-                      {
-                        "ts-ignore" => {
-                          description_format => "^: TS\\d+ because .+$",
-                        },
-                      },
-                    errors => [
-                      {
-                        data => { directive => "ignore", format => "^: TS\\d+ because .+$" },
-                        message_id => "ts_directive_comment_description_not_match_pattern",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => r#"// @ts-ignore 👨‍👩‍👧‍👦"#,
-                    options =>
-                      {
-                        "ts-ignore" => "allow-with-description",
-                      },
-                    errors => [
-                      {
-                        data => { directive => "ignore", minimum_description_length => 3 },
-                        message_id => "ts_directive_comment_requires_description",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "// @ts-nocheck",
-                    options => { "ts-nocheck" => true },
-                    errors => [
-                      {
-                        data => { directive => "nocheck" },
-                        message_id => "ts_directive_comment",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "// @ts-nocheck",
-                    errors => [
-                      {
-                        data => { directive => "nocheck" },
-                        message_id => "ts_directive_comment",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "/* @ts-nocheck */",
-                    options => { "ts-nocheck" => true },
-                    errors => [
-                      {
-                        data => { directive => "nocheck" },
-                        message_id => "ts_directive_comment",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => r#"
+                errors => [
+                  {
+                    message_id => "ts_ignore_instead_of_expect_error",
+                    data => {
+                      banned_name => "@ts-ignore",
+                      replacement => "@ts-expect-error",
+                      option => "ts-ignore",
+                    },
+                    line => 3,
+                    column => 3,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "replaceTsIgnoreWithTsExpectError",
+                    //     output: r#"
+          // if (false) {
+            // // @ts-expect-error: Unreachable code error
+            // console.log('hello');
+          // }
+                // "#,
+                    //   },
+                    // ],
+                  },
+                ],
+              },
+              {
+                code => "// @ts-ignore",
+                options => { "ts-ignore" => "allow-with-description" },
+                errors => [
+                  {
+                    data => { directive => "ignore", minimum_description_length => 3 },
+                    message_id => "ts_directive_comment_requires_description",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => r#"// @ts-ignore         "#,
+                options => { "ts-ignore" => "allow-with-description" },
+                errors => [
+                  {
+                    data => { directive => "ignore", minimum_description_length => 3 },
+                    message_id => "ts_directive_comment_requires_description",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "// @ts-ignore    .",
+                options => { "ts-ignore" => "allow-with-description" },
+                errors => [
+                  {
+                    data => { directive => "ignore", minimum_description_length => 3 },
+                    message_id => "ts_directive_comment_requires_description",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "// @ts-ignore: TS1234 because xyz",
+                options =>
+                  {
+                    "ts-ignore" => {
+                      description_format => "^: TS\\d+ because .+$",
+                    },
+                    minimum_description_length => 25,
+                  },
+                errors => [
+                  {
+                    data => { directive => "ignore", minimum_description_length => 25 },
+                    message_id => "ts_directive_comment_requires_description",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "// @ts-ignore: TS1234",
+                options =>
+                  {
+                    "ts-ignore" => {
+                      description_format => "^: TS\\d+ because .+$",
+                    },
+                  },
+                errors => [
+                  {
+                    data => { directive => "ignore", format => "^: TS\\d+ because .+$" },
+                    message_id => "ts_directive_comment_description_not_match_pattern",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => r#"// @ts-ignore    : TS1234 because xyz"#,
+                options =>
+                  {
+                    "ts-ignore" => {
+                      description_format => "^: TS\\d+ because .+$",
+                    },
+                  },
+                errors => [
+                  {
+                    data => { directive => "ignore", format => "^: TS\\d+ because .+$" },
+                    message_id => "ts_directive_comment_description_not_match_pattern",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => r#"// @ts-ignore 👨‍👩‍👧‍👦"#,
+                options =>
+                  {
+                    "ts-ignore" => "allow-with-description",
+                  },
+                errors => [
+                  {
+                    data => { directive => "ignore", minimum_description_length => 3 },
+                    message_id => "ts_directive_comment_requires_description",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "// @ts-nocheck",
+                options => { "ts-nocheck" => true },
+                errors => [
+                  {
+                    data => { directive => "nocheck" },
+                    message_id => "ts_directive_comment",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "// @ts-nocheck",
+                errors => [
+                  {
+                    data => { directive => "nocheck" },
+                    message_id => "ts_directive_comment",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "/* @ts-nocheck */",
+                options => { "ts-nocheck" => true },
+                errors => [
+                  {
+                    data => { directive => "nocheck" },
+                    message_id => "ts_directive_comment",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => r#"
 /*
  @ts-nocheck
 */
                     "#,
-                    options => { "ts-nocheck" => true },
-                    errors => [
-                      {
-                        data => { directive => "nocheck" },
-                        message_id => "ts_directive_comment",
-                        line => 2,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "/** @ts-nocheck */",
-                    options => { "ts-nocheck" => true },
-                    errors => [
-                      {
-                        data => { directive => "nocheck" },
-                        message_id => "ts_directive_comment",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "// @ts-nocheck: Suppress next line",
-                    errors => [
-                      {
-                        data => { directive => "nocheck" },
-                        message_id => "ts_directive_comment",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "/////@ts-nocheck: Suppress next line",
-                    errors => [
-                      {
-                        data => { directive => "nocheck" },
-                        message_id => "ts_directive_comment",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => r#"
+                options => { "ts-nocheck" => true },
+                errors => [
+                  {
+                    data => { directive => "nocheck" },
+                    message_id => "ts_directive_comment",
+                    line => 2,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "/** @ts-nocheck */",
+                options => { "ts-nocheck" => true },
+                errors => [
+                  {
+                    data => { directive => "nocheck" },
+                    message_id => "ts_directive_comment",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "// @ts-nocheck: Suppress next line",
+                errors => [
+                  {
+                    data => { directive => "nocheck" },
+                    message_id => "ts_directive_comment",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "/////@ts-nocheck: Suppress next line",
+                errors => [
+                  {
+                    data => { directive => "nocheck" },
+                    message_id => "ts_directive_comment",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => r#"
 if (false) {
   // @ts-nocheck: Unreachable code error
   console.log('hello');
 }
                     "#,
-                    errors => [
-                      {
-                        data => { directive => "nocheck" },
-                        message_id => "ts_directive_comment",
-                        line => 3,
-                        column => 3,
-                      },
-                    ],
-                  },
-                  {
-                    code => "// @ts-nocheck",
-                    options => { "ts-nocheck" => "allow-with-description" },
-                    errors => [
-                      {
-                        data => { directive => "nocheck", minimum_description_length => 3 },
-                        message_id => "ts_directive_comment_requires_description",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "// @ts-nocheck: TS1234 because xyz",
-                    options =>
-                      {
-                        "ts-nocheck" => {
-                          description_format => "^: TS\\d+ because .+$",
-                        },
-                        minimum_description_length => 25,
-                      },
-                    errors => [
-                      {
-                        data => { directive => "nocheck", minimum_description_length => 25 },
-                        message_id => "ts_directive_comment_requires_description",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "// @ts-nocheck: TS1234",
-                    options =>
-                      {
-                        "ts-nocheck" => {
-                          description_format => "^: TS\\d+ because .+$",
-                        },
-                      },
-                    errors => [
-                      {
-                        data => { directive => "nocheck", format => "^: TS\\d+ because .+$" },
-                        message_id => "ts_directive_comment_description_not_match_pattern",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => r#"// @ts-nocheck    : TS1234 because xyz"#,
-                    options =>
-                      {
-                        "ts-nocheck" => {
-                          description_format => "^: TS\\d+ because .+$",
-                        },
-                      },
-                    errors => [
-                      {
-                        data => { directive => "nocheck", format => "^: TS\\d+ because .+$" },
-                        message_id => "ts_directive_comment_description_not_match_pattern",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => r#"// @ts-nocheck 👨‍👩‍👧‍👦"#,
-                    options =>
-                      {
-                        "ts-nocheck" => "allow-with-description",
-                      },
-                    errors => [
-                      {
-                        data => { directive => "nocheck", minimum_description_length => 3 },
-                        message_id => "ts_directive_comment_requires_description",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "// @ts-check",
-                    options => { "ts-check" => true },
-                    errors => [
-                      {
-                        data => { directive => "check" },
-                        message_id => "ts_directive_comment",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "/* @ts-check */",
-                    options => { "ts-check" => true },
-                    errors => [
-                      {
-                        data => { directive => "check" },
-                        message_id => "ts_directive_comment",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => r#"
+                errors => [
+                  {
+                    data => { directive => "nocheck" },
+                    message_id => "ts_directive_comment",
+                    line => 3,
+                    column => 3,
+                  },
+                ],
+              },
+              {
+                code => "// @ts-nocheck",
+                options => { "ts-nocheck" => "allow-with-description" },
+                errors => [
+                  {
+                    data => { directive => "nocheck", minimum_description_length => 3 },
+                    message_id => "ts_directive_comment_requires_description",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "// @ts-nocheck: TS1234 because xyz",
+                options =>
+                  {
+                    "ts-nocheck" => {
+                      description_format => "^: TS\\d+ because .+$",
+                    },
+                    minimum_description_length => 25,
+                  },
+                errors => [
+                  {
+                    data => { directive => "nocheck", minimum_description_length => 25 },
+                    message_id => "ts_directive_comment_requires_description",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "// @ts-nocheck: TS1234",
+                options =>
+                  {
+                    "ts-nocheck" => {
+                      description_format => "^: TS\\d+ because .+$",
+                    },
+                  },
+                errors => [
+                  {
+                    data => { directive => "nocheck", format => "^: TS\\d+ because .+$" },
+                    message_id => "ts_directive_comment_description_not_match_pattern",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => r#"// @ts-nocheck    : TS1234 because xyz"#,
+                options =>
+                  {
+                    "ts-nocheck" => {
+                      description_format => "^: TS\\d+ because .+$",
+                    },
+                  },
+                errors => [
+                  {
+                    data => { directive => "nocheck", format => "^: TS\\d+ because .+$" },
+                    message_id => "ts_directive_comment_description_not_match_pattern",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => r#"// @ts-nocheck 👨‍👩‍👧‍👦"#,
+                options =>
+                  {
+                    "ts-nocheck" => "allow-with-description",
+                  },
+                errors => [
+                  {
+                    data => { directive => "nocheck", minimum_description_length => 3 },
+                    message_id => "ts_directive_comment_requires_description",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "// @ts-check",
+                options => { "ts-check" => true },
+                errors => [
+                  {
+                    data => { directive => "check" },
+                    message_id => "ts_directive_comment",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "/* @ts-check */",
+                options => { "ts-check" => true },
+                errors => [
+                  {
+                    data => { directive => "check" },
+                    message_id => "ts_directive_comment",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => r#"
 /*
  @ts-check
 */
                     "#,
-                    options => { "ts-check" => true },
-                    errors => [
-                      {
-                        data => { directive => "check" },
-                        message_id => "ts_directive_comment",
-                        line => 2,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "/** @ts-check */",
-                    options => { "ts-check" => true },
-                    errors => [
-                      {
-                        data => { directive => "check" },
-                        message_id => "ts_directive_comment",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "// @ts-check: Suppress next line",
-                    options => { "ts-check" => true },
-                    errors => [
-                      {
-                        data => { directive => "check" },
-                        message_id => "ts_directive_comment",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "/////@ts-check: Suppress next line",
-                    options => { "ts-check" => true },
-
-                    errors => [
-                      {
-                        data => { directive => "check" },
-                        message_id => "ts_directive_comment",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
+                options => { "ts-check" => true },
+                errors => [
+                  {
+                    data => { directive => "check" },
+                    message_id => "ts_directive_comment",
+                    line => 2,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "/** @ts-check */",
+                options => { "ts-check" => true },
+                errors => [
+                  {
+                    data => { directive => "check" },
+                    message_id => "ts_directive_comment",
+                    line => 1,
+                    column => 1,
                   },
+                ],
+              },
+              {
+                code => "// @ts-check: Suppress next line",
+                options => { "ts-check" => true },
+                errors => [
+                  {
+                    data => { directive => "check" },
+                    message_id => "ts_directive_comment",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "/////@ts-check: Suppress next line",
+                options => { "ts-check" => true },
+
+                errors => [
                   {
-                    code => r#"
+                    data => { directive => "check" },
+                    message_id => "ts_directive_comment",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => r#"
 if (false) {
   // @ts-check: Unreachable code error
   console.log('hello');
 }
                     "#,
-                    options => { "ts-check" => true },
-                    errors => [
-                      {
-                        data => { directive => "check" },
-                        message_id => "ts_directive_comment",
-                        line => 3,
-                        column => 3,
-                      },
-                    ],
-                  },
-                  {
-                    code => "// @ts-check",
-                    options => { "ts-check" => "allow-with-description" },
-                    errors => [
-                      {
-                        data => { directive => "check", minimum_description_length => 3 },
-                        message_id => "ts_directive_comment_requires_description",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "// @ts-check: TS1234 because xyz",
-                    options =>
-                      {
-                        "ts-check" => {
-                          description_format => "^: TS\\d+ because .+$",
-                        },
-                        minimum_description_length => 25,
-                      },
-                    errors => [
-                      {
-                        data => { directive => "check", minimum_description_length => 25 },
-                        message_id => "ts_directive_comment_requires_description",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => "// @ts-check: TS1234",
-                    options =>
-                      {
-                        "ts-check" => {
-                          description_format => "^: TS\\d+ because .+$",
-                        },
-                      },
-                    errors => [
-                      {
-                        data => { directive => "check", format => "^: TS\\d+ because .+$" },
-                        message_id => "ts_directive_comment_description_not_match_pattern",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => r#"// @ts-check    : TS1234 because xyz"#,
-                    options =>
-                      {
-                        "ts-check" => {
-                          description_format => "^: TS\\d+ because .+$",
-                        },
-                      },
-                    errors => [
-                      {
-                        data => { directive => "check", format => "^: TS\\d+ because .+$" },
-                        message_id => "ts_directive_comment_description_not_match_pattern",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
-                  },
-                  {
-                    code => r#"// @ts-check 👨‍👩‍👧‍👦"#,
-                    options =>
-                      {
-                        "ts-check" => "allow-with-description",
-                      },
-                    errors => [
-                      {
-                        data => { directive => "check", minimum_description_length => 3 },
-                        message_id => "ts_directive_comment_requires_description",
-                        line => 1,
-                        column => 1,
-                      },
-                    ],
+                options => { "ts-check" => true },
+                errors => [
+                  {
+                    data => { directive => "check" },
+                    message_id => "ts_directive_comment",
+                    line => 3,
+                    column => 3,
                   },
                 ],
-            },
-            get_instance_provider_factory(),
-        )
+              },
+              {
+                code => "// @ts-check",
+                options => { "ts-check" => "allow-with-description" },
+                errors => [
+                  {
+                    data => { directive => "check", minimum_description_length => 3 },
+                    message_id => "ts_directive_comment_requires_description",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "// @ts-check: TS1234 because xyz",
+                options =>
+                  {
+                    "ts-check" => {
+                      description_format => "^: TS\\d+ because .+$",
+                    },
+                    minimum_description_length => 25,
+                  },
+                errors => [
+                  {
+                    data => { directive => "check", minimum_description_length => 25 },
+                    message_id => "ts_directive_comment_requires_description",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "// @ts-check: TS1234",
+                options =>
+                  {
+                    "ts-check" => {
+                      description_format => "^: TS\\d+ because .+$",
+                    },
+                  },
+                errors => [
+                  {
+                    data => { directive => "check", format => "^: TS\\d+ because .+$" },
+                    message_id => "ts_directive_comment_description_not_match_pattern",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => r#"// @ts-check    : TS1234 because xyz"#,
+                options =>
+                  {
+                    "ts-check" => {
+                      description_format => "^: TS\\d+ because .+$",
+                    },
+                  },
+                errors => [
+                  {
+                    data => { directive => "check", format => "^: TS\\d+ because .+$" },
+                    message_id => "ts_directive_comment_description_not_match_pattern",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => r#"// @ts-check 👨‍👩‍👧‍👦"#,
+                options =>
+                  {
+                    "ts-check" => "allow-with-description",
+                  },
+                errors => [
+                  {
+                    data => { directive => "check", minimum_description_length => 3 },
+                    message_id => "ts_directive_comment_requires_description",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => r#"
+// some other comment
+// @ts-ignore
+let x: string = 1;
+                    "#,
+                errors => [
+                  {
+                    message_id => "ts_ignore_instead_of_expect_error",
+                    data => {
+                      banned_name => "@ts-ignore",
+                      replacement => "@ts-expect-error",
+                      option => "ts-ignore",
+                    },
+                    line => 3,
+                    column => 1,
+                  },
+                ],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_ban_ts_comment_rule() {
+        run_for_typescript_and_tsx(
+            ban_ts_comment_rule(),
+            test_cases::ban_ts_comment_rule_tests(),
+        );
     }
 }
@@ -6,11 +6,32 @@ use tree_sitter_lint::{
     tree_sitter::{Point, Range},
     violation, Rule,
 };
-use tree_sitter_lint_plugin_eslint_builtin::{
-    ast_helpers::{get_comment_contents, get_comment_type, CommentType},
-    AllComments,
+use tree_sitter_lint_plugin_eslint_builtin::ast_helpers::{
+    get_comment_contents, get_comment_type, CommentType,
 };
 
+/// A `tslint:enable`/`tslint:disable` comment, taken apart.
+struct TslintDirective<'a> {
+    /// eg `disable-next-line`
+    directive: &'a str,
+    /// Empty if the directive applies to all rules.
+    rule_names: Vec<&'a str>,
+}
+
+/// Parses eg `tslint:disable-next-line:rule1 rule2`. As with tslint itself,
+/// rule names are only recognized after a `:`.
+fn parse_tslint_directive(comment_contents: &str) -> Option<TslintDirective> {
+    let captures =
+        regex!(r#"(?s)^\s*tslint:((?:enable|disable)(?:-(?:line|next-line))?)(?::(.*)|\s|$)"#)
+            .captures(comment_contents)?;
+    Some(TslintDirective {
+        directive: captures.get(1).unwrap().as_str(),
+        rule_names: captures.get(2).map_or_else(Vec::new, |rule_names| {
+            rule_names.as_str().split_whitespace().collect()
+        }),
+    })
+}
+
 fn to_text(text: &str, type_: CommentType) -> String {
     match type_ {
         CommentType::Line => ["//", text.trim()].join(" "),
@@ -28,192 +49,261 @@ pub fn ban_tslint_comment_rule() -> Arc<dyn Rule> {
         fixable => true,
         listeners => [
             r#"
-              (program) @c
-            "# => |node, context| {
-                for &c in context.retrieve::<AllComments<'a>>().iter() {
-                    let comment_contents = get_comment_contents(c, context);
-                    if regex!(r#"^\s*tslint:(enable|disable)(?:-(line|next-line))?(:|\s|$)"#).is_match(&comment_contents) {
-                        context.report(violation! {
-                            data => {
-                                text => to_text(&comment_contents, get_comment_type(c, context)),
-                            },
-                            node => c,
-                            message_id => "comment_detected",
-                            fix => |fixer| {
-                                let should_remove_byte_before_comment_start = c.start_position().column > 0;
-                                let should_remove_byte_after_comment_end = c.end_byte() < context.file_run_context.tree.root_node().end_byte();
-                                fixer.remove_range(Range {
-                                    start_byte: if should_remove_byte_before_comment_start {
-                                        c.start_byte() - 1
+              (comment) @c
+            "# => |c, context| {
+                let comment_contents = get_comment_contents(c, context);
+                if let Some(directive) = parse_tslint_directive(&comment_contents) {
+                    context.report(violation! {
+                        data => {
+                            text => to_text(&comment_contents, get_comment_type(c, context)),
+                            directive => directive.directive,
+                            rule_names => directive.rule_names.join(" "),
+                            banned_name => format!("tslint:{}", directive.directive),
+                        },
+                        node => c,
+                        message_id => "comment_detected",
+                        fix => |fixer| {
+                            let should_remove_byte_before_comment_start = c.start_position().column > 0;
+                            let should_remove_byte_after_comment_end = c.end_byte() < context.file_run_context.tree.root_node().end_byte();
+                            fixer.remove_range(Range {
+                                start_byte: if should_remove_byte_before_comment_start {
+                                    c.start_byte() - 1
+                                } else {
+                                    c.start_byte()
+                                },
+                                end_byte: if should_remove_byte_after_comment_end {
+                                    c.end_byte() + 1
+                                } else {
+                                    c.end_byte()
+                                },
+                                start_point: Point {
+                                    row: c.start_position().row,
+                                    column: if should_remove_byte_before_comment_start {
+                                        c.start_position().column - 1
                                     } else {
-                                        c.start_byte()
+                                        c.start_position().column
                                     },
-                                    end_byte: if should_remove_byte_after_comment_end {
-                                        c.end_byte() + 1
+                                },
+                                end_point: Point {
+                                    row: c.end_position().row,
+                                    column: if should_remove_byte_after_comment_end {
+                                        c.end_position().column + 1
                                     } else {
-                                        c.end_byte()
-                                    },
-                                    start_point: Point {
-                                        row: c.start_position().row,
-                                        column: if should_remove_byte_before_comment_start {
-                                            c.start_position().column - 1
-                                        } else {
-                                            c.start_position().column
-                                        },
+                                        c.end_position().column
                                     },
-                                    end_point: Point {
-                                        row: c.end_position().row,
-                                        column: if should_remove_byte_after_comment_end {
-                                            c.end_position().column + 1
-                                        } else {
-                                            c.end_position().column
-                                        },
-                                    },
-                                });
-                            }
-                        });
-                    }
+                                },
+                            });
+                        }
+                    });
                 }
             },
         ],
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use tree_sitter_lint::{rule_tests, RuleTester};
-
-    use super::*;
-    use crate::get_instance_provider_factory;
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
 
-    #[test]
-    fn test_ban_tslint_comment_rule() {
-        RuleTester::run_with_from_file_run_context_instance_provider(
-            ban_tslint_comment_rule(),
-            rule_tests! {
-                valid => [
+    pub fn ban_tslint_comment_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                {
+                  code => "let a: readonly any[] = [];",
+                },
+                {
+                  code => "let a = new Array();",
+                },
+                {
+                  code => "// some other comment",
+                },
+                {
+                  code => "// TODO: this is a comment that mentions tslint",
+                },
+                {
+                  code => "/* another comment that mentions tslint */",
+                },
+            ],
+            invalid => [
+              {
+                  code => "/* tslint:disable */",
+                  output => "",
+                  errors => [
                     {
-                      code => "let a: readonly any[] = [];",
+                      column => 1,
+                      line => 1,
+                      end_line => 1,
+                      end_column => 21,
+                      data => {
+                        text => "/* tslint:disable */",
+                        directive => "disable",
+                        rule_names => "",
+                      },
+                      message_id => "comment_detected",
                     },
+                  ],
+              }, // Disable all rules for the rest of the file
+              {
+                  code => "/* tslint:enable */",
+                  output => "",
+                  errors => [
                     {
-                      code => "let a = new Array();",
+                      column => 1,
+                      line => 1,
+                      end_line => 1,
+                      end_column => 20,
+                      data => {
+                        text => "/* tslint:enable */",
+                        directive => "enable",
+                        rule_names => "",
+                      },
+                      message_id => "comment_detected",
                     },
+                  ],
+              }, // Enable all rules for the rest of the file
+              {
+                code => "/* tslint:disable:rule1 rule2 rule3... */",
+                output => "",
+                  errors => [
                     {
-                      code => "// some other comment",
+                      column => 1,
+                      line => 1,
+                      end_line => 1,
+                      end_column => 42,
+                      data => {
+                        text => "/* tslint:disable:rule1 rule2 rule3... */",
+                        directive => "disable",
+                        rule_names => "rule1 rule2 rule3...",
+                      },
+                      message_id => "comment_detected",
                     },
+                  ],
+              }, // Disable the listed rules for the rest of the file
+              {
+                code => "/* tslint:enable:rule1 rule2 rule3... */",
+                output => "",
+                  errors => [
                     {
-                      code => "// TODO: this is a comment that mentions tslint",
+                      column => 1,
+                      line => 1,
+                      end_line => 1,
+                      end_column => 41,
+                      data => {
+                        text => "/* tslint:enable:rule1 rule2 rule3... */",
+                        directive => "enable",
+                        rule_names => "rule1 rule2 rule3...",
+                      },
+                      message_id => "comment_detected",
                     },
+                  ],
+              }, // Enable the listed rules for the rest of the file
+              {
+                  code => "// tslint:disable-next-line",
+                 output => "",
+                  errors => [
                     {
-                      code => "/* another comment that mentions tslint */",
+                      column => 1,
+                      line => 1,
+                      end_line => 1,
+                      end_column => 28,
+                      data => {
+                        text => "// tslint:disable-next-line",
+                        directive => "disable-next-line",
+                        rule_names => "",
+                      },
+                      message_id => "comment_detected",
                     },
-                ],
-                invalid => [
-                  {
-                      code => "/* tslint:disable */",
-                      output => "",
-                      errors => [
-                        {
-                          column => 1,
-                          line => 1,
-                          data => { text => "/* tslint:disable */" },
-                          message_id => "comment_detected",
-                        },
-                      ],
-                  }, // Disable all rules for the rest of the file
-                  {
-                      code => "/* tslint:enable */",
-                      output => "",
-                      errors => [
-                        {
-                          column => 1,
-                          line => 1,
-                          data => { text => "/* tslint:enable */" },
-                          message_id => "comment_detected",
-                        },
-                      ],
-                  }, // Enable all rules for the rest of the file
-                  {
-                    code => "/* tslint:disable:rule1 rule2 rule3... */",
-                    output => "",
-                      errors => [
-                        {
-                          column => 1,
-                          line => 1,
-                          data => { text => "/* tslint:disable:rule1 rule2 rule3... */" },
-                          message_id => "comment_detected",
-                        },
-                      ],
-                  }, // Disable the listed rules for the rest of the file
-                  {
-                    code => "/* tslint:enable:rule1 rule2 rule3... */",
-                    output => "",
-                      errors => [
-                        {
-                          column => 1,
-                          line => 1,
-                          data => { text => "/* tslint:enable:rule1 rule2 rule3... */" },
-                          message_id => "comment_detected",
-                        },
-                      ],
-                  }, // Enable the listed rules for the rest of the file
-                  {
-                      code => "// tslint:disable-next-line",
-                     output => "",
-                      errors => [
-                        {
-                          column => 1,
-                          line => 1,
-                          data => { text => "// tslint:disable-next-line" },
-                          message_id => "comment_detected",
-                        },
-                      ],
-                  }, // Disables all rules for the following line
-                  {
-                    code => "someCode(); // tslint:disable-line",
-                    output => "someCode();",
-                      errors => [
-                        {
-                          column => 13,
-                          line => 1,
-                          data => { text => "// tslint:disable-line" },
-                          message_id => "comment_detected",
-                        },
-                      ],
-                  }, // Disables all rules for the current line
-                  {
-                    code => "// tslint:disable-next-line =>rule1 rule2 rule3...",
-                   output => "",
-                      errors => [
-                        {
-                          column => 1,
-                          line => 1,
-                          data => { text => "// tslint:disable-next-line =>rule1 rule2 rule3..." },
-                          message_id => "comment_detected",
-                        },
-                      ],
-                  }, // Disables the listed rules for the next line
+                  ],
+              }, // Disables all rules for the following line
+              {
+                code => "someCode(); // tslint:disable-line",
+                output => "someCode();",
+                  errors => [
+                    {
+                      column => 13,
+                      line => 1,
+                      end_line => 1,
+                      end_column => 35,
+                      data => {
+                        text => "// tslint:disable-line",
+                        directive => "disable-line",
+                        rule_names => "",
+                      },
+                      message_id => "comment_detected",
+                    },
+                  ],
+              }, // Disables all rules for the current line
+              {
+                code => "// tslint:disable-next-line =>rule1 rule2 rule3...",
+               output => "",
+                  errors => [
+                    {
+                      column => 1,
+                      line => 1,
+                      end_line => 1,
+                      end_column => 51,
+                      data => {
+                        text => "// tslint:disable-next-line =>rule1 rule2 rule3...",
+                        directive => "disable-next-line",
+                        rule_names => "",
+                      },
+                      message_id => "comment_detected",
+                    },
+                  ],
+              }, // Disables the listed rules for the next line
+              {
+                code => "// tslint:disable-next-line:rule1 rule2",
+                output => "",
+                  errors => [
+                    {
+                      column => 1,
+                      line => 1,
+                      data => {
+                        text => "// tslint:disable-next-line:rule1 rule2",
+                        directive => "disable-next-line",
+                        rule_names => "rule1 rule2",
+                      },
+                      message_id => "comment_detected",
+                    },
+                  ],
+              },
 
-                  {
-                    code => r#"const woah = doSomeStuff();
+              {
+                code => r#"const woah = doSomeStuff();
 // tslint:disable-line
 console.log(woah);
                 "#,
-                    output => r#"const woah = doSomeStuff();
+                output => r#"const woah = doSomeStuff();
 console.log(woah);
                 "#,
-                      errors => [
-                        {
-                          column => 1,
-                          line => 2,
-                          data => { text => "// tslint:disable-line" },
-                          message_id => "comment_detected",
-                        },
-                      ],
-                  },
-                ],
-            },
-            get_instance_provider_factory(),
-        )
+                  errors => [
+                    {
+                      column => 1,
+                      line => 2,
+                      data => {
+                        text => "// tslint:disable-line",
+                        directive => "disable-line",
+                        rule_names => "",
+                      },
+                      message_id => "comment_detected",
+                    },
+                  ],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_ban_tslint_comment_rule() {
+        run_for_typescript_and_tsx(
+            ban_tslint_comment_rule(),
+            test_cases::ban_tslint_comment_rule_tests(),
+        );
     }
 }
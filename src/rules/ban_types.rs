@@ -9,7 +9,11 @@ use tree_sitter_lint::{
     QueryMatchContext, Rule,
 };
 
-use crate::ast_helpers::{is_type_literal, is_type_reference};
+use crate::{
+    ast_helpers::{is_type_literal, is_type_reference},
+    config_warnings::{ConfigWarning, ValidateOptions},
+    kind::{NestedTypeIdentifier, TypeIdentifier},
+};
 
 #[derive(Builder, Clone, Debug, Default, PartialEq, Eq, Deserialize)]
 #[builder(default, setter(strip_option, into))]
@@ -148,11 +152,56 @@ fn get_custom_message(banned_type: &BanConfig) -> String {
     }
 }
 
+/// A qualified type name pattern (from the `type_patterns` option) where a
+/// `*` segment matches any one name and a `**` segment any (non-zero) number
+/// of them, eg `NS.*` bans the types declared directly in `NS` and `NS.**`
+/// also those in namespaces nested in it.
+#[derive(Debug)]
+struct TypePattern {
+    segments: Vec<String>,
+    ban_config: BanConfig,
+}
+
+impl TypePattern {
+    fn new(pattern: &str, ban_config: BanConfig) -> Self {
+        Self {
+            segments: remove_spaces(pattern)
+                .split('.')
+                .map(ToOwned::to_owned)
+                .collect(),
+            ban_config,
+        }
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        matches_segments(&self.segments, &name.split('.').collect::<Vec<_>>())
+    }
+}
+
+fn matches_segments(pattern: &[String], name: &[&str]) -> bool {
+    let Some((pattern_first, pattern_rest)) = pattern.split_first() else {
+        return name.is_empty();
+    };
+    if name.is_empty() {
+        return false;
+    }
+    match &**pattern_first {
+        "**" => (1..=name.len()).any(|index| matches_segments(pattern_rest, &name[index..])),
+        "*" => matches_segments(pattern_rest, &name[1..]),
+        pattern_first => pattern_first == name[0] && matches_segments(pattern_rest, &name[1..]),
+    }
+}
+
 #[derive(Default, Debug, Deserialize)]
 #[serde(default)]
-struct Options {
+pub(crate) struct Options {
     types: Option<Types>,
     extend_defaults: Option<bool>,
+    /// Not in the upstream rule: bans type references by qualified name
+    /// pattern (see [`TypePattern`]). Exact matches in `types` take
+    /// precedence, so eg `{ "Legacy.Ok": false }` there exempts `Legacy.Ok`
+    /// from a `Legacy.*` pattern, as does a `false` pattern matching it here.
+    type_patterns: Option<Types>,
 }
 
 impl Options {
@@ -161,6 +210,25 @@ impl Options {
     }
 }
 
+impl ValidateOptions for Options {
+    fn validate(&self) -> Vec<ConfigWarning> {
+        if self.extend_defaults()
+            || [&self.types, &self.type_patterns]
+                .into_iter()
+                .flatten()
+                .flat_map(|types| types.values())
+                .any(|banned_type| *banned_type != BanConfig::Bool(false))
+        {
+            return Default::default();
+        }
+        vec![ConfigWarning::new(
+            "ban-types",
+            "extend_defaults",
+            "`extend_defaults` is false and `types` doesn't ban any types, so this rule can never report anything.",
+        )]
+    }
+}
+
 pub fn ban_types_rule() -> Arc<dyn Rule> {
     rule! {
         name => "ban-types",
@@ -184,15 +252,44 @@ pub fn ban_types_rule() -> Arc<dyn Rule> {
                 }
                 types
             },
+            banned_type_patterns: Vec<TypePattern> = {
+                let mut patterns = options.type_patterns.as_ref().map(|type_patterns| {
+                    type_patterns
+                        .iter()
+                        .map(|(pattern, data)| (pattern, TypePattern::new(pattern, data.clone())))
+                        .collect::<Vec<_>>()
+                }).unwrap_or_default();
+                // so that which pattern gets reported doesn't depend on
+                // `HashMap` iteration order
+                patterns.sort_by(|(a, _), (b, _)| a.cmp(b));
+                patterns.into_iter().map(|(_, pattern)| pattern).collect()
+            },
         },
         methods => {
             fn check_banned_types(&self, type_node: Node<'a>, context: &QueryMatchContext<'a, '_>) {
                 let name = stringify_node(type_node, context);
-                let Some(banned_type) = self.banned_types.get(&*name).filter(|&banned_type| {
-                    *banned_type != BanConfig::Bool(false)
+                let Some(banned_type) = self.banned_types.get(&*name).or_else(|| {
+                    if !matches!(type_node.kind(), TypeIdentifier | NestedTypeIdentifier) {
+                        return None;
+                    }
+                    let matching_patterns = self.banned_type_patterns
+                        .iter()
+                        .filter(|pattern| pattern.matches(&name))
+                        .collect::<Vec<_>>();
+                    // a `false` pattern exempts what it matches from the
+                    // others
+                    matching_patterns
+                        .iter()
+                        .find(|pattern| pattern.ban_config == BanConfig::Bool(false))
+                        .or_else(|| matching_patterns.first())
+                        .copied()
+                        .map(|pattern| &pattern.ban_config)
                 }) else {
                     return;
                 };
+                if *banned_type == BanConfig::Bool(false) {
+                    return;
+                }
 
                 let custom_message = get_custom_message(banned_type);
                 let fix_with = match banned_type {
@@ -206,6 +303,8 @@ pub fn ban_types_rule() -> Arc<dyn Rule> {
                     data => {
                         name => name,
                         custom_message => custom_message,
+                        banned_name => name,
+                        replacement => fix_with.map(String::as_str).unwrap_or_default(),
                     },
                     fix => |fixer| {
                         let Some(fix_with) = fix_with else {
@@ -268,17 +367,13 @@ pub fn ban_types_rule() -> Arc<dyn Rule> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use tree_sitter_lint::{
-        rule_tests, serde_json::json, RuleTestExpectedErrorBuilder, RuleTestInvalidBuilder,
-        RuleTester,
-    };
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, serde_json::json, RuleTests};
 
-    use super::*;
+    use crate::test_helpers::{expected_error, invalid_case};
 
-    #[test]
-    fn test_ban_types_rule() {
+    pub fn ban_types_rule_tests() -> RuleTests {
         let options = json!({
             "types": {
               "String": {
@@ -295,228 +390,266 @@ mod tests {
             },
             "extend_defaults": false,
         });
-        RuleTester::run(
-            ban_types_rule(),
-            rule_tests! {
-                valid => [
-                  "let f = Object();", // Should not fail if there is no options set
-                  "let f: { x: number; y: number } = { x: 1, y: 1 };",
-                  {
-                    code => "let f = Object();",
-                    options => options,
-                  },
-                  {
-                    code => "let g = Object.create(null);",
-                    options => options,
-                  },
-                  {
-                    code => "let h = String(false);",
-                    options => options,
-                  },
-                  {
-                    code => "let e: foo.String;",
-                    options => options,
-                  },
-                  {
-                    code => "let a: _.NS.Bad;",
-                    options => options,
-                  },
+        rule_tests! {
+            valid => [
+              "let f = Object();", // Should not fail if there is no options set
+              "let f: { x: number; y: number } = { x: 1, y: 1 };",
+              "interface String { foo(): void }",
+              "class Foo extends String {}",
+              "declare global { interface Object { bar: number } }",
+              {
+                code => "class Bad {}",
+                options => { types => { Bad => null } },
+              },
+              {
+                code => "let f = Object();",
+                options => options,
+              },
+              {
+                code => "let g = Object.create(null);",
+                options => options,
+              },
+              {
+                code => "let h = String(false);",
+                options => options,
+              },
+              {
+                code => "let e: foo.String;",
+                options => options,
+              },
+              {
+                code => "let a: _.NS.Bad;",
+                options => options,
+              },
+              {
+                code => "let a: NS.Bad._;",
+                options => options,
+              },
+              // Replace default options instead of merging with extend_defaults => false
+              {
+                code => "let a: String;",
+                options =>
                   {
-                    code => "let a: NS.Bad._;",
-                    options => options,
+                    types => {
+                      Number => {
+                        message => "Use number instead.",
+                        fix_with => "number",
+                      },
+                    },
+                    extend_defaults => false,
                   },
-                  // Replace default options instead of merging with extend_defaults => false
+              },
+              {
+                code => "let a: undefined;",
+                options =>
                   {
-                    code => "let a: String;",
-                    options =>
-                      {
-                        types => {
-                          Number => {
-                            message => "Use number instead.",
-                            fix_with => "number",
-                          },
-                        },
-                        extend_defaults => false,
+                    types => {
+                      null => {
+                        message => "Use undefined instead.",
+                        fix_with => "undefined",
                       },
+                    },
                   },
+              },
+              {
+                code => "let a: null;",
+                options =>
                   {
-                    code => "let a: undefined;",
-                    options =>
-                      {
-                        types => {
-                          null => {
-                            message => "Use undefined instead.",
-                            fix_with => "undefined",
-                          },
-                        },
-                      },
+                    types => {
+                      undefined => null,
+                    },
+                    extend_defaults => false,
                   },
+              },
+              {
+                code => "type Props = {};",
+                options =>
                   {
-                    code => "let a: null;",
-                    options =>
-                      {
-                        types => {
-                          undefined => null,
-                        },
-                        extend_defaults => false,
-                      },
+                    types => {
+                      "{}" => false,
+                    },
+                    extend_defaults => true,
                   },
+              },
+              "let a: [];",
+            ],
+            invalid => [
+              {
+                code => "let a: String;",
+                output => "let a: string;",
+                errors => [
                   {
-                    code => "type Props = {};",
-                    options =>
-                      {
-                        types => {
-                          "{}" => false,
-                        },
-                        extend_defaults => true,
-                      },
+                    message_id => "banned_type_message",
+                    line => 1,
+                    column => 8,
+                    end_line => 1,
+                    end_column => 14,
                   },
-                  "let a: [];",
                 ],
-                invalid => [
+              },
+              {
+                code => "class Foo extends Bar<String> {}",
+                output => "class Foo extends Bar<string> {}",
+                errors => [
                   {
-                    code => "let a: String;",
-                    output => "let a: string;",
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                        line => 1,
-                        column => 8,
-                      },
-                    ],
+                    message_id => "banned_type_message",
+                    line => 1,
+                    column => 23,
+                    end_column => 29,
                   },
+                ],
+              },
+              {
+                code => "let a: Object;",
+                errors => [
                   {
-                    code => "let a: Object;",
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "Object",
-                          custom_message => " Use '{}' instead.",
-                        },
-                        line => 1,
-                        column => 8,
-                      },
-                    ],
-                    options => options,
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "Object",
+                      banned_name => "Object",
+                      replacement => "",
+                      custom_message => " Use '{}' instead.",
+                    },
+                    line => 1,
+                    column => 8,
                   },
+                ],
+                options => options,
+              },
+              {
+                code => "let a: Object;",
+                errors => [
                   {
-                    code => "let a: Object;",
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "Object",
-                          custom_message => [
-                            " The `Object` type actually means \"any non-nullish value\", so it is marginally better than `unknown`.",
-                            "- If you want a type meaning \"any object\", you probably want `object` instead.",
-                            "- If you want a type meaning \"any value\", you probably want `unknown` instead.",
-                            "- If you really want a type meaning \"any non-nullish value\", you probably want `NonNullable<unknown>` instead.",
-                          ].join("\n"),
-                        },
-                        line => 1,
-                        column => 8,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "bannedTypeReplacement",
-                        //     data => { name => "Object", replacement => "object" },
-                        //     output => "let a: object;",
-                        //   },
-                        //   {
-                        //     message_id => "bannedTypeReplacement",
-                        //     data => { name => "Object", replacement => "unknown" },
-                        //     output => "let a: unknown;",
-                        //   },
-                        //   {
-                        //     message_id => "bannedTypeReplacement",
-                        //     data => { name => "Object", replacement => "NonNullable<unknown>" },
-                        //     output => "let a: NonNullable<unknown>;",
-                        //   },
-                        // ],
-                      },
-                    ],
-                    options => {},
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "Object",
+                      banned_name => "Object",
+                      replacement => "",
+                      custom_message => [
+                        " The `Object` type actually means \"any non-nullish value\", so it is marginally better than `unknown`.",
+                        "- If you want a type meaning \"any object\", you probably want `object` instead.",
+                        "- If you want a type meaning \"any value\", you probably want `unknown` instead.",
+                        "- If you really want a type meaning \"any non-nullish value\", you probably want `NonNullable<unknown>` instead.",
+                      ].join("\n"),
+                    },
+                    line => 1,
+                    column => 8,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "bannedTypeReplacement",
+                    //     data => { name => "Object", replacement => "object" },
+                    //     output => "let a: object;",
+                    //   },
+                    //   {
+                    //     message_id => "bannedTypeReplacement",
+                    //     data => { name => "Object", replacement => "unknown" },
+                    //     output => "let a: unknown;",
+                    //   },
+                    //   {
+                    //     message_id => "bannedTypeReplacement",
+                    //     data => { name => "Object", replacement => "NonNullable<unknown>" },
+                    //     output => "let a: NonNullable<unknown>;",
+                    //   },
+                    // ],
                   },
+                ],
+                options => {},
+              },
+              {
+                code => "let aa: Foo;",
+                errors => [
                   {
-                    code => "let aa: Foo;",
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "Foo",
-                          custom_message => "",
-                        },
-                      },
-                    ],
-                    options =>
-                      {
-                        types => {
-                          Foo => { message => "" },
-                        },
-                      },
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "Foo",
+                      banned_name => "Foo",
+                      replacement => "",
+                      custom_message => "",
+                    },
                   },
+                ],
+                options =>
                   {
-                    code => "let b: { c: String };",
-                    output => "let b: { c: string };",
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "String",
-                          custom_message => " Use string instead.",
-                        },
-                        line => 1,
-                        column => 13,
-                      },
-                    ],
-                    options => options,
+                    types => {
+                      Foo => { message => "" },
+                    },
                   },
+              },
+              {
+                code => "let b: { c: String };",
+                output => "let b: { c: string };",
+                errors => [
                   {
-                    code => "function foo(a: String) {}",
-                    output => "function foo(a: string) {}",
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "String",
-                          custom_message => " Use string instead.",
-                        },
-                        line => 1,
-                        column => 17,
-                      },
-                    ],
-                    options => options,
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "String",
+                      banned_name => "String",
+                      replacement => "string",
+                      custom_message => " Use string instead.",
+                    },
+                    line => 1,
+                    column => 13,
                   },
+                ],
+                options => options,
+              },
+              {
+                code => "function foo(a: String) {}",
+                output => "function foo(a: string) {}",
+                errors => [
                   {
-                    code => "'a' as String;",
-                    output => "'a' as string;",
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "String",
-                          custom_message => " Use string instead.",
-                        },
-                        line => 1,
-                        column => 8,
-                      },
-                    ],
-                    options => options,
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "String",
+                      banned_name => "String",
+                      replacement => "string",
+                      custom_message => " Use string instead.",
+                    },
+                    line => 1,
+                    column => 17,
+                    end_line => 1,
+                    end_column => 23,
                   },
+                ],
+                options => options,
+              },
+              {
+                code => "'a' as String;",
+                output => "'a' as string;",
+                errors => [
                   {
-                    code => "let c: F;",
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                        data => { name => "F", custom_message => "" },
-                        line => 1,
-                        column => 8,
-                      },
-                    ],
-                    options => options,
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "String",
+                      banned_name => "String",
+                      replacement => "string",
+                      custom_message => " Use string instead.",
+                    },
+                    line => 1,
+                    column => 8,
                   },
+                ],
+                options => options,
+              },
+              {
+                code => "let c: F;",
+                errors => [
                   {
-                    code => r#"
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "F",
+                      banned_name => "F",
+                      replacement => "",
+                      custom_message => "",
+                    },
+                    line => 1,
+                    column => 8,
+                  },
+                ],
+                options => options,
+              },
+              {
+                code => r#"
 class Foo<F = String> extends Bar<String> implements Baz<Object> {
   constructor(foo: String | Object) {}
 
@@ -525,7 +658,7 @@ class Foo<F = String> extends Bar<String> implements Baz<Object> {
   }
 }
                     "#,
-                    output => r#"
+                output => r#"
 class Foo<F = string> extends Bar<string> implements Baz<Object> {
   constructor(foo: string | Object) {}
 
@@ -534,495 +667,724 @@ class Foo<F = string> extends Bar<string> implements Baz<Object> {
   }
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "String",
-                          custom_message => " Use string instead.",
-                        },
-                        line => 2,
-                        column => 15,
-                      },
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "String",
-                          custom_message => " Use string instead.",
-                        },
-                        line => 2,
-                        column => 35,
-                      },
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "Object",
-                          custom_message => " Use '{}' instead.",
-                        },
-                        line => 2,
-                        column => 58,
-                      },
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "String",
-                          custom_message => " Use string instead.",
-                        },
-                        line => 3,
-                        column => 20,
-                      },
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "Object",
-                          custom_message => " Use '{}' instead.",
-                        },
-                        line => 3,
-                        column => 29,
-                      },
-                      {
-                        message_id => "banned_type_message",
-                        data => { name => "Array", custom_message => "" },
-                        line => 5,
-                        column => 11,
-                      },
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "String",
-                          custom_message => " Use string instead.",
-                        },
-                        line => 5,
-                        column => 17,
-                      },
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "String",
-                          custom_message => " Use string instead.",
-                        },
-                        line => 6,
-                        column => 16,
-                      },
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "String",
-                          custom_message => " Use string instead.",
-                        },
-                        line => 6,
-                        column => 30,
-                      },
-                    ],
-                    options => options,
-                  },
+                errors => [
                   {
-                    code => "let a: NS.Bad;",
-                    output => "let a: NS.Good;",
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "NS.Bad",
-                          custom_message => " Use NS.Good instead.",
-                        },
-                        line => 1,
-                        column => 8,
-                      },
-                    ],
-                    options => options,
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "String",
+                      banned_name => "String",
+                      replacement => "string",
+                      custom_message => " Use string instead.",
+                    },
+                    line => 2,
+                    column => 15,
                   },
                   {
-                    code => r#"
-let a: NS.Bad<Foo>;
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "String",
+                      banned_name => "String",
+                      replacement => "string",
+                      custom_message => " Use string instead.",
+                    },
+                    line => 2,
+                    column => 35,
+                  },
+                  {
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "Object",
+                      banned_name => "Object",
+                      replacement => "",
+                      custom_message => " Use '{}' instead.",
+                    },
+                    line => 2,
+                    column => 58,
+                  },
+                  {
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "String",
+                      banned_name => "String",
+                      replacement => "string",
+                      custom_message => " Use string instead.",
+                    },
+                    line => 3,
+                    column => 20,
+                  },
+                  {
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "Object",
+                      banned_name => "Object",
+                      replacement => "",
+                      custom_message => " Use '{}' instead.",
+                    },
+                    line => 3,
+                    column => 29,
+                  },
+                  {
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "Array",
+                      banned_name => "Array",
+                      replacement => "",
+                      custom_message => "",
+                    },
+                    line => 5,
+                    column => 11,
+                  },
+                  {
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "String",
+                      banned_name => "String",
+                      replacement => "string",
+                      custom_message => " Use string instead.",
+                    },
+                    line => 5,
+                    column => 17,
+                  },
+                  {
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "String",
+                      banned_name => "String",
+                      replacement => "string",
+                      custom_message => " Use string instead.",
+                    },
+                    line => 6,
+                    column => 16,
+                  },
+                  {
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "String",
+                      banned_name => "String",
+                      replacement => "string",
+                      custom_message => " Use string instead.",
+                    },
+                    line => 6,
+                    column => 30,
+                  },
+                ],
+                options => options,
+              },
+              {
+                code => "let a: NS.Bad;",
+                output => "let a: NS.Good;",
+                errors => [
+                  {
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "NS.Bad",
+                      banned_name => "NS.Bad",
+                      replacement => "NS.Good",
+                      custom_message => " Use NS.Good instead.",
+                    },
+                    line => 1,
+                    column => 8,
+                    end_line => 1,
+                    end_column => 14,
+                  },
+                ],
+                options => options,
+              },
+              {
+                code => r#"
+let a: NS.Bad<Foo>;
 let b: Foo<NS.Bad>;
                     "#,
-                    output => r#"
+                output => r#"
 let a: NS.Good<Foo>;
 let b: Foo<NS.Good>;
                     "#,
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "NS.Bad",
-                          custom_message => " Use NS.Good instead.",
-                        },
-                        line => 2,
-                        column => 8,
-                      },
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "NS.Bad",
-                          custom_message => " Use NS.Good instead.",
-                        },
-                        line => 3,
-                        column => 12,
-                      },
-                    ],
-                    options => options,
+                errors => [
+                  {
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "NS.Bad",
+                      banned_name => "NS.Bad",
+                      replacement => "NS.Good",
+                      custom_message => " Use NS.Good instead.",
+                    },
+                    line => 2,
+                    column => 8,
                   },
                   {
-                    code => "let foo: {} = {};",
-                    output => "let foo: object = {};",
-                    options =>
-                      {
-                        types => {
-                          "{}" => {
-                            message => "Use object instead.",
-                            fix_with => "object",
-                          },
-                        },
-                      },
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "{}",
-                          custom_message => " Use object instead.",
-                        },
-                        line => 1,
-                        column => 10,
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "NS.Bad",
+                      banned_name => "NS.Bad",
+                      replacement => "NS.Good",
+                      custom_message => " Use NS.Good instead.",
+                    },
+                    line => 3,
+                    column => 12,
+                  },
+                ],
+                options => options,
+              },
+              {
+                code => "let foo: {} = {};",
+                output => "let foo: object = {};",
+                options =>
+                  {
+                    types => {
+                      "{}" => {
+                        message => "Use object instead.",
+                        fix_with => "object",
                       },
-                    ],
+                    },
                   },
+                errors => [
                   {
-                    code => r#"
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "{}",
+                      banned_name => "{}",
+                      replacement => "object",
+                      custom_message => " Use object instead.",
+                    },
+                    line => 1,
+                    column => 10,
+                  },
+                ],
+              },
+              {
+                code => r#"
 let foo: {} = {};
 let bar: {     } = {};
 let baz: {
 } = {};
                     "#,
-                    output => r#"
+                output => r#"
 let foo: object = {};
 let bar: object = {};
 let baz: object = {};
                     "#,
-                    options =>
-                      {
-                        types => {
-                          "{   }" => {
-                            message => "Use object instead.",
-                            fix_with => "object",
-                          },
-                        },
-                      },
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "{}",
-                          custom_message => " Use object instead.",
-                        },
-                        line => 2,
-                        column => 10,
-                      },
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "{}",
-                          custom_message => " Use object instead.",
-                        },
-                        line => 3,
-                        column => 10,
-                      },
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "{}",
-                          custom_message => " Use object instead.",
-                        },
-                        line => 4,
-                        column => 10,
+                options =>
+                  {
+                    types => {
+                      "{   }" => {
+                        message => "Use object instead.",
+                        fix_with => "object",
                       },
-                    ],
+                    },
                   },
+                errors => [
                   {
-                    code => "let a: NS.Bad;",
-                    output => "let a: NS.Good;",
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "NS.Bad",
-                          custom_message => " Use NS.Good instead.",
-                        },
-                        line => 1,
-                        column => 8,
-                      },
-                    ],
-                    options =>
-                      {
-                        types => {
-                          "  NS.Bad  " => {
-                            message => "Use NS.Good instead.",
-                            fix_with => "NS.Good",
-                          },
-                        },
-                      },
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "{}",
+                      banned_name => "{}",
+                      replacement => "object",
+                      custom_message => " Use object instead.",
+                    },
+                    line => 2,
+                    column => 10,
                   },
                   {
-                    code => r#"let a: Foo<   F   >;"#,
-                    output => r#"let a: Foo<   T   >;"#,
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "F",
-                          custom_message => " Use T instead.",
-                        },
-                        line => 1,
-                        column => 15,
-                      },
-                    ],
-                    options =>
-                      {
-                        types => {
-                          "       F      " => {
-                            message => "Use T instead.",
-                            fix_with => "T",
-                          },
-                        },
-                      },
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "{}",
+                      banned_name => "{}",
+                      replacement => "object",
+                      custom_message => " Use object instead.",
+                    },
+                    line => 3,
+                    column => 10,
                   },
                   {
-                    code => "type Foo = Bar<any>;",
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "Bar<any>",
-                          custom_message => " Don't use `any` as a type parameter to `Bar`",
-                        },
-                        line => 1,
-                        column => 12,
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "{}",
+                      banned_name => "{}",
+                      replacement => "object",
+                      custom_message => " Use object instead.",
+                    },
+                    line => 4,
+                    column => 10,
+                  },
+                ],
+              },
+              {
+                code => "let a: NS.Bad;",
+                output => "let a: NS.Good;",
+                errors => [
+                  {
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "NS.Bad",
+                      banned_name => "NS.Bad",
+                      replacement => "NS.Good",
+                      custom_message => " Use NS.Good instead.",
+                    },
+                    line => 1,
+                    column => 8,
+                    end_line => 1,
+                    end_column => 14,
+                  },
+                ],
+                options =>
+                  {
+                    types => {
+                      "  NS.Bad  " => {
+                        message => "Use NS.Good instead.",
+                        fix_with => "NS.Good",
                       },
-                    ],
-                    options =>
-                      {
-                        types => {
-                          "Bar<any>" => "Don't use `any` as a type parameter to `Bar`",
-                        },
+                    },
+                  },
+              },
+              {
+                code => r#"let a: Foo<   F   >;"#,
+                output => r#"let a: Foo<   T   >;"#,
+                errors => [
+                  {
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "F",
+                      banned_name => "F",
+                      replacement => "T",
+                      custom_message => " Use T instead.",
+                    },
+                    line => 1,
+                    column => 15,
+                  },
+                ],
+                options =>
+                  {
+                    types => {
+                      "       F      " => {
+                        message => "Use T instead.",
+                        fix_with => "T",
                       },
+                    },
                   },
+              },
+              {
+                code => "type Foo = Bar<any>;",
+                errors => [
                   {
-                    code => r#"type Foo = Bar<A,B>;"#,
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "Bar<A,B>",
-                          custom_message => " Don't pass `A, B` as parameters to `Bar`",
-                        },
-                        line => 1,
-                        column => 12,
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "Bar<any>",
+                      banned_name => "Bar<any>",
+                      replacement => "",
+                      custom_message => " Don't use `any` as a type parameter to `Bar`",
+                    },
+                    line => 1,
+                    column => 12,
+                    end_line => 1,
+                    end_column => 20,
+                  },
+                ],
+                options =>
+                  {
+                    types => {
+                      "Bar<any>" => "Don't use `any` as a type parameter to `Bar`",
+                    },
+                  },
+              },
+              {
+                code => r#"type Foo = Bar<A,B>;"#,
+                errors => [
+                  {
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "Bar<A,B>",
+                      banned_name => "Bar<A,B>",
+                      replacement => "",
+                      custom_message => " Don't pass `A, B` as parameters to `Bar`",
+                    },
+                    line => 1,
+                    column => 12,
+                  },
+                ],
+                options =>
+                  {
+                    types => {
+                      "Bar<A, B>" => "Don't pass `A, B` as parameters to `Bar`",
+                    },
+                  },
+              },
+              {
+                code => "let a: [];",
+                errors => [
+                  {
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "[]",
+                      banned_name => "[]",
+                      replacement => "",
+                      custom_message => " `[]` does only allow empty arrays.",
+                    },
+                    line => 1,
+                    column => 8,
+                  },
+                ],
+                options =>
+                  {
+                    types => {
+                      "[]" => "`[]` does only allow empty arrays.",
+                    },
+                  },
+              },
+              {
+                code => r#"let a:  [ ] ;"#,
+                errors => [
+                  {
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "[]",
+                      banned_name => "[]",
+                      replacement => "",
+                      custom_message => " `[]` does only allow empty arrays.",
+                    },
+                    line => 1,
+                    column => 9,
+                  },
+                ],
+                options =>
+                  {
+                    types => {
+                      "[]" => "`[]` does only allow empty arrays.",
+                    },
+                  },
+              },
+              {
+                code => "let a: [];",
+                output => "let a: any[];",
+                errors => [
+                  {
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "[]",
+                      banned_name => "[]",
+                      replacement => "any[]",
+                      custom_message => " `[]` does only allow empty arrays.",
+                    },
+                    line => 1,
+                    column => 8,
+                  },
+                ],
+                options =>
+                  {
+                    types => {
+                      "[]" => {
+                        message => "`[]` does only allow empty arrays.",
+                        fix_with => "any[]",
                       },
-                    ],
-                    options =>
-                      {
-                        types => {
-                          "Bar<A, B>" => "Don't pass `A, B` as parameters to `Bar`",
-                        },
+                    },
+                  },
+              },
+              {
+                code => "let a: [[]];",
+                errors => [
+                  {
+                    message_id => "banned_type_message",
+                    data => {
+                      name => "[]",
+                      banned_name => "[]",
+                      replacement => "",
+                      custom_message => " `[]` does only allow empty arrays.",
+                    },
+                    line => 1,
+                    column => 9,
+                  },
+                ],
+                options =>
+                  {
+                    types => {
+                      "[]" => "`[]` does only allow empty arrays.",
+                    },
+                  },
+              },
+              {
+                code => "type Baz = 1 & Foo;",
+                errors => [
+                  {
+                    message_id => "banned_type_message",
+                  },
+                ],
+                options =>
+                  {
+                    types => {
+                      Foo => { message => "" },
+                    },
+                  },
+              },
+              {
+                code => "interface Foo extends Bar {}",
+                errors => [
+                  {
+                    message_id => "banned_type_message",
+                  },
+                ],
+                options =>
+                  {
+                    types => {
+                      Bar => { message => "" },
+                    },
+                  },
+              },
+              {
+                code => "interface Foo extends Bar, Baz {}",
+                errors => [
+                  {
+                    message_id => "banned_type_message",
+                  },
+                ],
+                options =>
+                  {
+                    types => {
+                      Bar => { message => "" },
+                    },
+                  },
+              },
+              {
+                code => "class Foo implements Bar {}",
+                errors => [
+                  {
+                    message_id => "banned_type_message",
+                  },
+                ],
+                options =>
+                  {
+                    types => {
+                      Bar => { message => "" },
+                    },
+                  },
+              },
+              {
+                code => "class Foo implements Bar, Baz {}",
+                errors => [
+                  {
+                    message_id => "banned_type_message",
+                  },
+                ],
+                options =>
+                  {
+                    types => {
+                      Bar => { message => "Bla" },
+                    },
+                  },
+              },
+              ...[
+                  "bigint",
+                  "boolean",
+                  "never",
+                  "null",
+                  "number",
+                  "object",
+                  "string",
+                  "symbol",
+                  "undefined",
+                  "unknown",
+                  "void",
+              ].into_iter()
+                  .map(|key| {
+                      invalid_case(
+                          format!("function foo(x: {key}) {{}}"),
+                          json!({
+                              "extend_defaults": false,
+                              "types": {
+                                key: null,
+                              },
+                          }),
+                          vec![
+                              expected_error(
+                                  "banned_type_message",
+                                  [
+                                      ("name", key),
+                                      ("banned_name", key),
+                                      ("replacement", ""),
+                                      ("custom_message", ""),
+                                  ],
+                              )
+                              .line(1)
+                              .column(17)
+                              .build()
+                              .unwrap(),
+                          ],
+                      )
+                  }),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::{rule_tests, serde_json::json};
+
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_ban_types_rule() {
+        run_for_typescript_and_tsx(ban_types_rule(), test_cases::ban_types_rule_tests());
+    }
+
+    #[test]
+    fn test_ban_types_type_patterns() {
+        let options = json!({
+            "type_patterns": {
+              "Legacy.*": "Use the v2 types instead.",
+              "Vendor.**": null,
+              "*.Internal": {
+                "message": "Internal types aren't exported.",
+              },
+              "Legacy.Ok": false,
+            },
+        });
+        run_for_typescript_and_tsx(
+            ban_types_rule(),
+            rule_tests! {
+                valid => [
+                  {
+                    code => "let a: Legacy;",
+                    options => options,
+                  },
+                  {
+                    code => "let a: Legacy.Nested.Foo;",
+                    options => options,
+                  },
+                  {
+                    code => "let a: Vendor;",
+                    options => options,
+                  },
+                  {
+                    code => "let a: Foo.Bar.Internal;",
+                    options => options,
+                  },
+                  {
+                    code => "let a: Internal;",
+                    options => options,
+                  },
+                  {
+                    code => "let a = Legacy.foo;",
+                    options => options,
+                  },
+                  {
+                    code => "let a: Legacy.Ok;",
+                    options => options,
+                  },
+                  {
+                    // a `false` pattern exempts what it matches from the
+                    // other patterns
+                    code => "let a: Legacy.Ok;",
+                    options => {
+                      type_patterns => {
+                        "Legacy.Ok" => false,
+                        "*.Ok" => null,
                       },
+                    },
                   },
                   {
-                    code => "let a: [];",
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                        data => {
-                          name => "[]",
-                          custom_message => " `[]` does only allow empty arrays.",
-                        },
-                        line => 1,
-                        column => 8,
+                    // as does `false` in `types`
+                    code => "let a: Legacy.Ok;",
+                    options => {
+                      types => {
+                        "Legacy.Ok" => false,
                       },
-                    ],
-                    options =>
-                      {
-                        types => {
-                          "[]" => "`[]` does only allow empty arrays.",
-                        },
+                      type_patterns => {
+                        "Legacy.*" => "Use the v2 types instead.",
                       },
+                    },
                   },
+                ],
+                invalid => [
                   {
-                    code => r#"let a:  [ ] ;"#,
+                    code => "let a: Legacy.Foo;",
                     errors => [
                       {
                         message_id => "banned_type_message",
                         data => {
-                          name => "[]",
-                          custom_message => " `[]` does only allow empty arrays.",
+                          name => "Legacy.Foo",
+                          custom_message => " Use the v2 types instead.",
                         },
                         line => 1,
-                        column => 9,
+                        column => 8,
+                        end_column => 18,
                       },
                     ],
-                    options =>
-                      {
-                        types => {
-                          "[]" => "`[]` does only allow empty arrays.",
-                        },
-                      },
+                    options => options,
                   },
                   {
-                    code => "let a: [];",
-                    output => "let a: any[];",
+                    // the pattern doesn't get to see the type arguments
+                    code => "let a: Legacy.Foo<string>;",
                     errors => [
                       {
                         message_id => "banned_type_message",
                         data => {
-                          name => "[]",
-                          custom_message => " `[]` does only allow empty arrays.",
+                          name => "Legacy.Foo",
                         },
-                        line => 1,
                         column => 8,
                       },
                     ],
-                    options =>
-                      {
-                        types => {
-                          "[]" => {
-                            message => "`[]` does only allow empty arrays.",
-                            fix_with => "any[]",
-                          },
-                        },
-                      },
+                    options => options,
                   },
                   {
-                    code => "let a: [[]];",
+                    code => "let a: Vendor . Lib.Foo;",
                     errors => [
                       {
                         message_id => "banned_type_message",
                         data => {
-                          name => "[]",
-                          custom_message => " `[]` does only allow empty arrays.",
+                          name => "Vendor.Lib.Foo",
+                          custom_message => "",
                         },
-                        line => 1,
-                        column => 9,
                       },
                     ],
-                    options =>
-                      {
-                        types => {
-                          "[]" => "`[]` does only allow empty arrays.",
-                        },
-                      },
+                    options => options,
                   },
                   {
-                    code => "type Baz = 1 & Foo;",
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                      },
-                    ],
-                    options =>
-                      {
-                        types => {
-                          Foo => { message => "" },
-                        },
-                      },
+                    code => "let a: Vendor.Foo;",
+                    errors => [{ message_id => "banned_type_message" }],
+                    options => options,
                   },
                   {
-                    code => "interface Foo extends Bar {}",
+                    code => "function f(x: Foo.Internal): void {}",
                     errors => [
                       {
                         message_id => "banned_type_message",
-                      },
-                    ],
-                    options =>
-                      {
-                        types => {
-                          Bar => { message => "" },
+                        data => {
+                          name => "Foo.Internal",
+                          custom_message => " Internal types aren't exported.",
                         },
                       },
-                  },
-                  {
-                    code => "interface Foo extends Bar, Baz {}",
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                      },
                     ],
-                    options =>
-                      {
-                        types => {
-                          Bar => { message => "" },
-                        },
-                      },
+                    options => options,
                   },
                   {
-                    code => "class Foo implements Bar {}",
+                    // an exact match in `types` takes precedence
+                    code => "let a: Legacy.Foo;",
+                    output => "let a: Modern.Foo;",
                     errors => [
                       {
                         message_id => "banned_type_message",
+                        data => {
+                          custom_message => "",
+                        },
                       },
                     ],
-                    options =>
-                      {
-                        types => {
-                          Bar => { message => "" },
+                    options => {
+                      types => {
+                        "Legacy.Foo" => {
+                          fix_with => "Modern.Foo",
                         },
                       },
+                      type_patterns => {
+                        "Legacy.*" => "Use the v2 types instead.",
+                      },
+                    },
                   },
                   {
-                    code => "class Foo implements Bar, Baz {}",
-                    errors => [
-                      {
-                        message_id => "banned_type_message",
-                      },
-                    ],
-                    options =>
-                      {
-                        types => {
-                          Bar => { message => "Bla" },
-                        },
-                      },
+                    code => "let a: String;",
+                    output => "let a: string;",
+                    errors => [{ message_id => "banned_type_message" }],
+                    options => options,
                   },
-                  ...[
-                      "bigint",
-                      "boolean",
-                      "never",
-                      "null",
-                      "number",
-                      "object",
-                      "string",
-                      "symbol",
-                      "undefined",
-                      "unknown",
-                      "void",
-                  ].into_iter()
-                      .map(|key| {
-                          RuleTestInvalidBuilder::default()
-                              .code(format!("function foo(x: {key}) {{}}"))
-                              .errors(vec![
-                                  RuleTestExpectedErrorBuilder::default()
-                                    .message_id("banned_type_message")
-                                    .data([
-                                        (
-                                            "name".to_owned(),
-                                            key.to_owned(),
-                                        ),
-                                        (
-                                            "custom_message".to_owned(),
-                                            "".to_owned(),
-                                        ),
-                                    ])
-                                    .line(1)
-                                    .column(17)
-                                    .build()
-                                    .unwrap()
-                              ])
-                              .options(json!({
-                                  "extend_defaults": false,
-                                  "types": {
-                                    key: null,
-                                  },
-                              }))
-                              .build()
-                              .unwrap()
-                      }),
                 ],
             },
         )
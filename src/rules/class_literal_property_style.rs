@@ -2,20 +2,20 @@ use std::sync::Arc;
 
 use serde::Deserialize;
 use tree_sitter_lint::{
-    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt, Rule,
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
 };
 use tree_sitter_lint_plugin_eslint_builtin::{
     assert_kind,
-    ast_helpers::{
-        get_method_definition_kind, is_simple_template_literal, is_tagged_template_expression,
-        MethodDefinitionKind,
-    },
-    kind::{
-        is_literal_kind, CallExpression, ComputedPropertyName, ReturnStatement, TemplateString,
-    },
+    ast_helpers::{get_method_definition_kind, MethodDefinitionKind},
+    kind::{ComputedPropertyName, ReturnStatement},
 };
 
-use crate::kind::PublicFieldDefinition;
+use crate::{
+    ast_helpers::{get_decorators, is_supported_literal},
+    kind::PublicFieldDefinition,
+    util::{ClassMemberIndex, ClassMemberReceiver},
+};
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -25,17 +25,6 @@ enum Style {
     Getters,
 }
 
-fn is_supported_literal(node: Node) -> bool {
-    match node.kind() {
-        kind if is_literal_kind(kind) => true,
-        CallExpression if is_tagged_template_expression(node) => {
-            is_simple_template_literal(node.field("arguments"))
-        }
-        TemplateString => is_simple_template_literal(node),
-        _ => false,
-    }
-}
-
 fn is_readonly_and_not_declare(node: Node) -> bool {
     assert_kind!(node, PublicFieldDefinition);
 
@@ -52,6 +41,27 @@ fn is_readonly_and_not_declare(node: Node) -> bool {
     false
 }
 
+/// Whether the field gets assigned to anywhere in the class (eg in the
+/// constructor), in which case it isn't really a literal.
+fn is_reassigned<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    let class_member_index = ClassMemberIndex::new(node.parent().unwrap(), context);
+    let Some(member) = class_member_index
+        .members
+        .iter()
+        .find(|member| member.node == node)
+    else {
+        return false;
+    };
+    class_member_index.usages_of(&member.name).any(|usage| {
+        usage.is_written
+            && usage.is_static == member.is_static
+            && matches!(
+                usage.receiver,
+                ClassMemberReceiver::This | ClassMemberReceiver::ClassName
+            )
+    })
+}
+
 pub fn class_literal_property_style_rule() -> Arc<dyn Rule> {
     rule! {
         name => "class-literal-property-style",
@@ -78,6 +88,11 @@ pub fn class_literal_property_style_rule() -> Arc<dyn Rule> {
                 if get_method_definition_kind(node, context) != MethodDefinitionKind::Get {
                     return;
                 }
+                // turning a decorated getter into a field would change what
+                // the decorator gets applied to
+                if get_decorators(node).next().is_some() {
+                    return;
+                }
                 let Some(statement) = node.field("body").non_comment_named_children(SupportedLanguage::Javascript).next().filter(|statement| {
                     statement.kind() == ReturnStatement
                 }) else {
@@ -85,7 +100,7 @@ pub fn class_literal_property_style_rule() -> Arc<dyn Rule> {
                 };
 
                 let Some(_argument) = statement.maybe_first_non_comment_named_child(SupportedLanguage::Javascript).filter(|&argument| {
-                    is_supported_literal(argument)
+                    is_supported_literal(argument, context)
                 }) else {
                     return;
                 };
@@ -93,6 +108,11 @@ pub fn class_literal_property_style_rule() -> Arc<dyn Rule> {
                 context.report(violation! {
                     node => node.field("name").skip_nodes_of_type(ComputedPropertyName, SupportedLanguage::Javascript),
                     message_id => "prefer_field_style",
+                    data => {
+                        banned_name => "getters",
+                        replacement => "fields",
+                        option => "fields",
+                    },
                     // TODO: suggestions?
                 });
             },
@@ -108,73 +128,98 @@ pub fn class_literal_property_style_rule() -> Arc<dyn Rule> {
                 }
 
                 let Some(_value) = node.child_by_field_name("value").filter(|&value| {
-                    is_supported_literal(value)
+                    is_supported_literal(value, context)
                 }) else {
                     return;
                 };
 
+                if is_reassigned(node, context) {
+                    return;
+                }
+
                 context.report(violation! {
                     node => node.field("name").skip_nodes_of_type(ComputedPropertyName, SupportedLanguage::Javascript),
                     message_id => "prefer_getter_style",
+                    data => {
+                        banned_name => "fields",
+                        replacement => "getters",
+                        option => "getters",
+                    },
                 });
             }
         ],
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use tree_sitter_lint::{rule_tests, RuleTester};
-
-    use super::*;
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
 
-    #[test]
-    fn test_class_literal_property_style_rule() {
-        RuleTester::run(
-            class_literal_property_style_rule(),
-            rule_tests! {
-                valid => [
-                  r#"
+    pub fn class_literal_property_style_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              r#"
+              class Mx {
+                @dec
+                get p1() {
+                  return 'hello world';
+                }
+              }
+                  "#,
+              r#"
               class Mx {
                 declare readonly p1 = 1;
               }
                   "#,
-                  r#"
+              r#"
+              class Mx {
+                get p1() {
+                  return -x;
+                }
+                get p2() {
+                  return Symbol.for('p2');
+                }
+                get p3() {
+                  return Symbol(description);
+                }
+              }
+                  "#,
+              r#"
               class Mx {
                 readonly p1 = 'hello world';
               }
                   "#,
-                  r#"
+              r#"
               class Mx {
                 p1 = 'hello world';
               }
                   "#,
-                  r#"
+              r#"
               class Mx {
                 static p1 = 'hello world';
               }
                   "#,
-                  r#"
+              r#"
               class Mx {
                 p1: string;
               }
                   "#,
-                  r#"
+              r#"
               class Mx {
                 get p1();
               }
                   "#,
-                  r#"
+              r#"
               class Mx {
                 get p1() {}
               }
                   "#,
-                  r#"
+              r#"
               abstract class Mx {
                 abstract get p1(): string;
               }
                   "#,
-                  r#"
+              r#"
                     class Mx {
                       get mySetting() {
                         if (this._aValue) {
@@ -185,14 +230,14 @@ mod tests {
                       }
                     }
                   "#,
-                  r#"
+              r#"
                     class Mx {
                       get mySetting() {
                         return `build-${process.env.build}`;
                       }
                     }
                   "#,
-                  r#"
+              r#"
                     class Mx {
                       getMySetting() {
                         if (this._aValue) {
@@ -203,15 +248,15 @@ mod tests {
                       }
                     }
                   "#,
-                  r#"
+              r#"
                     class Mx {
                       public readonly myButton = styled.button`
                         color: ${props => (props.primary ? 'hotpink' : 'turquoise')};
                       `;
                     }
                   "#,
-                  {
-                    code => r#"
+              {
+                code => r#"
                       class Mx {
                         public get myButton() {
                           return styled.button`
@@ -220,91 +265,91 @@ mod tests {
                         }
                       }
                     "#,
-                    options => "fields",
-                  },
-                  // TODO: uncomment if
-                  // https://github.com/tree-sitter/tree-sitter-typescript/issues/270 gets
-                  // resolved?
-                  // {
-                  //   code => r#"
-              // class Mx {
-                // public declare readonly foo = 1;
-              // }
-                  //   "#,
-                  //   options => "getters",
-                  // },
-                  {
-                    code => r#"
+                options => "fields",
+              },
+              // TODO: uncomment if
+              // https://github.com/tree-sitter/tree-sitter-typescript/issues/270 gets
+              // resolved?
+              // {
+              //   code => r#"
+          // class Mx {
+            // public declare readonly foo = 1;
+          // }
+              //   "#,
+              //   options => "getters",
+              // },
+              {
+                code => r#"
               class Mx {
                 declare public readonly foo = 1;
               }
                     "#,
-                    options => "getters",
-                  },
-                  {
-                    code => r#"
+                options => "getters",
+              },
+              {
+                code => r#"
               class Mx {
                 get p1() {
                   return 'hello world';
                 }
               }
                     "#,
-                    options => "getters",
-                  },
-                  {
-                    code => r#"
+                options => "getters",
+              },
+              {
+                code => r#"
               class Mx {
                 p1 = 'hello world';
               }
                     "#,
-                    options => "getters",
-                  },
-                  {
-                    code => r#"
+                options => "getters",
+              },
+              {
+                code => r#"
               class Mx {
                 p1: string;
               }
                     "#,
-                    options => "getters",
-                  },
-                  {
-                    code => r#"
+                options => "getters",
+              },
+              {
+                code => r#"
               class Mx {
                 readonly p1 = [1, 2, 3];
               }
                     "#,
-                    options => "getters",
-                  },
-                  {
-                    code => r#"
+                options => "getters",
+              },
+              {
+                code => r#"
               class Mx {
                 static p1: string;
               }
                     "#,
-                    options => "getters",
-                  },
-                  {
-                    code => r#"
+                options => "getters",
+              },
+              {
+                code => r#"
               class Mx {
                 static get p1() {
                   return 'hello world';
                 }
               }
                     "#,
-                    options => "getters",
-                  },
-                  {
-                    code => r#"
+                options => "getters",
+              },
+              {
+                code => r#"
                       class Mx {
                         public readonly myButton = styled.button`
                           color: ${props => (props.primary ? 'hotpink' : 'turquoise')};
                         `;
                       }
                     "#,
-                    options => "getters",
-                  },
-                  {
-                    code => r#"
+                options => "getters",
+              },
+              {
+                code => r#"
                       class Mx {
                         public get myButton() {
                           return styled.button`
@@ -313,371 +358,463 @@ mod tests {
                         }
                       }
                     "#,
-                    options => "getters",
-                  },
-                ],
-                invalid => [
-                  {
-                    code => r#"
+                options => "getters",
+              },
+              {
+                code => r#"
+class Mx {
+  readonly p1 = 'hello world';
+  constructor() {
+    this.p1 = 'goodbye';
+  }
+}
+                    "#,
+                options => "getters",
+              },
+              {
+                code => r#"
+class Mx {
+  readonly p1 = 'hello world';
+  reset() {
+    this.p1 = 'goodbye';
+  }
+}
+                    "#,
+                options => "getters",
+              },
+              {
+                code => r#"
 class Mx {
+  static readonly p1 = 'hello world';
+  static {
+    Mx.p1 = 'goodbye';
+  }
+}
+                    "#,
+                options => "getters",
+              },
+            ],
+            invalid => [
+              {
+                code => r#"
+abstract class Mx {
+  abstract get p0(): string;
   get p1() {
     return 'hello world';
   }
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_field_style",
-                        column => 7,
-                        line => 3,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "prefer_field_styleSuggestion",
-                        //     output: r#"
-              // class Mx {
-                // readonly p1 = 'hello world';
-              // }
-                    // "#,
-                        //   },
-                        // ],
-                      },
-                    ],
-                  },
+                errors => [{ message_id => "prefer_field_style", line => 4, column => 7 }],
+              },
+              {
+                code => r#"
+abstract class Mx {
+  abstract readonly p0: string;
+  readonly p1 = 'hello world';
+}
+                    "#,
+                errors => [{ message_id => "prefer_getter_style", line => 4, column => 12 }],
+                options => "getters",
+              },
+              {
+                code => r#"
+class Mx {
+  get p1() {
+    return 'hello world';
+  }
+}
+                    "#,
+                errors => [
                   {
-                    code => r#"
+                    message_id => "prefer_field_style",
+                    data => {
+                      banned_name => "getters",
+                      replacement => "fields",
+                      option => "fields",
+                    },
+                    column => 7,
+                    line => 3,
+                    end_line => 3,
+                    end_column => 9,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "prefer_field_styleSuggestion",
+                    //     output: r#"
+          // class Mx {
+            // readonly p1 = 'hello world';
+          // }
+                // "#,
+                    //   },
+                    // ],
+                  },
+                ],
+              },
+              {
+                code => r#"
 class Mx {
   get p1() {
     return `hello world`;
   }
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_field_style",
-                        column => 7,
-                        line => 3,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "prefer_field_styleSuggestion",
-                        //     output: r#"
-              // class Mx {
-                // readonly p1 = `hello world`;
-              // }
-                    // "#,
-                        //   },
-                        // ],
-                      },
-                    ],
-                  },
+                errors => [
                   {
-                    code => r#"
+                    message_id => "prefer_field_style",
+                    column => 7,
+                    line => 3,
+                    end_line => 3,
+                    end_column => 9,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "prefer_field_styleSuggestion",
+                    //     output: r#"
+          // class Mx {
+            // readonly p1 = `hello world`;
+          // }
+                // "#,
+                    //   },
+                    // ],
+                  },
+                ],
+              },
+              {
+                code => r#"
 class Mx {
   static get p1() {
     return 'hello world';
   }
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_field_style",
-                        column => 14,
-                        line => 3,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "prefer_field_styleSuggestion",
-                        //     output: r#"
-              // class Mx {
-                // static readonly p1 = 'hello world';
-              // }
-                    // "#,
-                        //   },
-                        // ],
-                      },
-                    ],
-                  },
+                errors => [
                   {
-                    code => r#"
+                    message_id => "prefer_field_style",
+                    column => 14,
+                    line => 3,
+                    end_line => 3,
+                    end_column => 16,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "prefer_field_styleSuggestion",
+                    //     output: r#"
+          // class Mx {
+            // static readonly p1 = 'hello world';
+          // }
+                // "#,
+                    //   },
+                    // ],
+                  },
+                ],
+              },
+              {
+                code => r#"
 class Mx {
   public static get foo() {
     return 1;
   }
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_field_style",
-                        column => 21,
-                        line => 3,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "prefer_field_styleSuggestion",
-                        //     output: r#"
-              // class Mx {
-                // public static readonly foo = 1;
-              // }
-                    // "#,
-                        //   },
-                        // ],
-                      },
-                    ],
-                  },
+                errors => [
                   {
-                    code => r#"
+                    message_id => "prefer_field_style",
+                    column => 21,
+                    line => 3,
+                    end_line => 3,
+                    end_column => 24,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "prefer_field_styleSuggestion",
+                    //     output: r#"
+          // class Mx {
+            // public static readonly foo = 1;
+          // }
+                // "#,
+                    //   },
+                    // ],
+                  },
+                ],
+              },
+              {
+                code => r#"
 class Mx {
   public get [myValue]() {
     return 'a literal value';
   }
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_field_style",
-                        column => 15,
-                        line => 3,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "prefer_field_styleSuggestion",
-                        //     output: r#"
-              // class Mx {
-                // public readonly [myValue] = 'a literal value';
-              // }
-                    // "#,
-                        //   },
-                        // ],
-                      },
-                    ],
-                  },
+                errors => [
                   {
-                    code => r#"
+                    message_id => "prefer_field_style",
+                    column => 15,
+                    line => 3,
+                    end_line => 3,
+                    end_column => 22,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "prefer_field_styleSuggestion",
+                    //     output: r#"
+          // class Mx {
+            // public readonly [myValue] = 'a literal value';
+          // }
+                // "#,
+                    //   },
+                    // ],
+                  },
+                ],
+              },
+              {
+                code => r#"
 class Mx {
   public get [myValue]() {
     return 12345n;
   }
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_field_style",
-                        column => 15,
-                        line => 3,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "prefer_field_styleSuggestion",
-                        //     output: r#"
-              // class Mx {
-                // public readonly [myValue] = 12345n;
-              // }
-                    // "#,
-                        //   },
-                        // ],
-                      },
-                    ],
-                  },
+                errors => [
                   {
-                    code => r#"
+                    message_id => "prefer_field_style",
+                    column => 15,
+                    line => 3,
+                    end_line => 3,
+                    end_column => 22,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "prefer_field_styleSuggestion",
+                    //     output: r#"
+          // class Mx {
+            // public readonly [myValue] = 12345n;
+          // }
+                // "#,
+                    //   },
+                    // ],
+                  },
+                ],
+              },
+              {
+                code => r#"
 class Mx {
   public readonly [myValue] = 'a literal value';
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_getter_style",
-                        column => 20,
-                        line => 3,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "prefer_getter_styleSuggestion",
-                        //     output: r#"
-              // class Mx {
-                // public get [myValue]() { return 'a literal value'; }
-              // }
-                    // "#,
-                        //   },
-                        // ],
-                      },
-                    ],
-                    options => "getters",
-                  },
+                errors => [
                   {
-                    code => r#"
+                    message_id => "prefer_getter_style",
+                    data => {
+                      banned_name => "fields",
+                      replacement => "getters",
+                      option => "getters",
+                    },
+                    column => 20,
+                    line => 3,
+                    end_line => 3,
+                    end_column => 27,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "prefer_getter_styleSuggestion",
+                    //     output: r#"
+          // class Mx {
+            // public get [myValue]() { return 'a literal value'; }
+          // }
+                // "#,
+                    //   },
+                    // ],
+                  },
+                ],
+                options => "getters",
+              },
+              {
+                code => r#"
 class Mx {
   readonly p1 = 'hello world';
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_getter_style",
-                        column => 12,
-                        line => 3,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "prefer_getter_styleSuggestion",
-                        //     output: r#"
-              // class Mx {
-                // get p1() { return 'hello world'; }
-              // }
-                    // "#,
-                        //   },
-                        // ],
-                      },
-                    ],
-                    options => "getters",
-                  },
+                errors => [
                   {
-                    code => r#"
+                    message_id => "prefer_getter_style",
+                    column => 12,
+                    line => 3,
+                    end_line => 3,
+                    end_column => 14,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "prefer_getter_styleSuggestion",
+                    //     output: r#"
+          // class Mx {
+            // get p1() { return 'hello world'; }
+          // }
+                // "#,
+                    //   },
+                    // ],
+                  },
+                ],
+                options => "getters",
+              },
+              {
+                code => r#"
 class Mx {
   readonly p1 = `hello world`;
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_getter_style",
-                        column => 12,
-                        line => 3,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "prefer_getter_styleSuggestion",
-                        //     output: r#"
-              // class Mx {
-                // get p1() { return `hello world`; }
-              // }
-                    // "#,
-                        //   },
-                        // ],
-                      },
-                    ],
-                    options => "getters",
-                  },
+                errors => [
                   {
-                    code => r#"
+                    message_id => "prefer_getter_style",
+                    column => 12,
+                    line => 3,
+                    end_line => 3,
+                    end_column => 14,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "prefer_getter_styleSuggestion",
+                    //     output: r#"
+          // class Mx {
+            // get p1() { return `hello world`; }
+          // }
+                // "#,
+                    //   },
+                    // ],
+                  },
+                ],
+                options => "getters",
+              },
+              {
+                code => r#"
 class Mx {
   static readonly p1 = 'hello world';
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_getter_style",
-                        column => 19,
-                        line => 3,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "prefer_getter_styleSuggestion",
-                        //     output: r#"
-              // class Mx {
-                // static get p1() { return 'hello world'; }
-              // }
-                    // "#,
-                        //   },
-                        // ],
-                      },
-                    ],
-                    options => "getters",
-                  },
+                errors => [
                   {
-                    code => r#"
+                    message_id => "prefer_getter_style",
+                    column => 19,
+                    line => 3,
+                    end_line => 3,
+                    end_column => 21,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "prefer_getter_styleSuggestion",
+                    //     output: r#"
+          // class Mx {
+            // static get p1() { return 'hello world'; }
+          // }
+                // "#,
+                    //   },
+                    // ],
+                  },
+                ],
+                options => "getters",
+              },
+              {
+                code => r#"
 class Mx {
   protected get p1() {
     return 'hello world';
   }
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_field_style",
-                        column => 17,
-                        line => 3,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "prefer_field_styleSuggestion",
-                        //     output: r#"
-              // class Mx {
-                // protected readonly p1 = 'hello world';
-              // }
-                    // "#,
-                        //   },
-                        // ],
-                      },
-                    ],
-                    options => "fields",
-                  },
+                errors => [
                   {
-                    code => r#"
+                    message_id => "prefer_field_style",
+                    column => 17,
+                    line => 3,
+                    end_line => 3,
+                    end_column => 19,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "prefer_field_styleSuggestion",
+                    //     output: r#"
+          // class Mx {
+            // protected readonly p1 = 'hello world';
+          // }
+                // "#,
+                    //   },
+                    // ],
+                  },
+                ],
+                options => "fields",
+              },
+              {
+                code => r#"
 class Mx {
   protected readonly p1 = 'hello world';
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_getter_style",
-                        column => 22,
-                        line => 3,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "prefer_getter_styleSuggestion",
-                        //     output: r#"
-              // class Mx {
-                // protected get p1() { return 'hello world'; }
-              // }
-                    // "#,
-                        //   },
-                        // ],
-                      },
-                    ],
-                    options => "getters",
-                  },
+                errors => [
                   {
-                    code => r#"
+                    message_id => "prefer_getter_style",
+                    column => 22,
+                    line => 3,
+                    end_line => 3,
+                    end_column => 24,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "prefer_getter_styleSuggestion",
+                    //     output: r#"
+          // class Mx {
+            // protected get p1() { return 'hello world'; }
+          // }
+                // "#,
+                    //   },
+                    // ],
+                  },
+                ],
+                options => "getters",
+              },
+              {
+                code => r#"
 class Mx {
   public static get p1() {
     return 'hello world';
   }
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_field_style",
-                        column => 21,
-                        line => 3,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "prefer_field_styleSuggestion",
-                        //     output: r#"
-              // class Mx {
-                // public static readonly p1 = 'hello world';
-              // }
-                    // "#,
-                        //   },
-                        // ],
-                      },
-                    ],
-                  },
+                errors => [
                   {
-                    code => r#"
+                    message_id => "prefer_field_style",
+                    column => 21,
+                    line => 3,
+                    end_line => 3,
+                    end_column => 23,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "prefer_field_styleSuggestion",
+                    //     output: r#"
+          // class Mx {
+            // public static readonly p1 = 'hello world';
+          // }
+                // "#,
+                    //   },
+                    // ],
+                  },
+                ],
+              },
+              {
+                code => r#"
 class Mx {
   public static readonly p1 = 'hello world';
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_getter_style",
-                        column => 26,
-                        line => 3,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "prefer_getter_styleSuggestion",
-                        //     output: r#"
-              // class Mx {
-                // public static get p1() { return 'hello world'; }
-              // }
-                    // "#,
-                        //   },
-                        // ],
-                      },
-                    ],
-                    options => "getters",
-                  },
+                errors => [
                   {
-                    code => r#"
+                    message_id => "prefer_getter_style",
+                    column => 26,
+                    line => 3,
+                    end_line => 3,
+                    end_column => 28,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "prefer_getter_styleSuggestion",
+                    //     output: r#"
+          // class Mx {
+            // public static get p1() { return 'hello world'; }
+          // }
+                // "#,
+                    //   },
+                    // ],
+                  },
+                ],
+                options => "getters",
+              },
+              {
+                code => r#"
 class Mx {
   public get myValue() {
     return gql`
@@ -691,33 +828,35 @@ class Mx {
   }
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_field_style",
-                        column => 14,
-                        line => 3,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "prefer_field_styleSuggestion",
-                        //     output: r#"
-              // class Mx {
-                // public readonly myValue = gql`
-                    // {
-                      // user(id: 5) {
-                        // firstName
-                        // lastName
-                      // }
-                    // }
-                  // `;
-              // }
-                    // "#,
-                        //   },
-                        // ],
-                      },
-                    ],
-                  },
+                errors => [
                   {
-                    code => r#"
+                    message_id => "prefer_field_style",
+                    column => 14,
+                    line => 3,
+                    end_line => 3,
+                    end_column => 21,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "prefer_field_styleSuggestion",
+                    //     output: r#"
+          // class Mx {
+            // public readonly myValue = gql`
+                // {
+                  // user(id: 5) {
+                    // firstName
+                    // lastName
+                  // }
+                // }
+              // `;
+          // }
+                // "#,
+                    //   },
+                    // ],
+                  },
+                ],
+              },
+              {
+                code => r#"
 class Mx {
   public readonly myValue = gql`
     {
@@ -729,34 +868,99 @@ class Mx {
   `;
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_getter_style",
-                        column => 19,
-                        line => 3,
-                        // suggestions: [
-                        //   {
-                        //     message_id => "prefer_getter_styleSuggestion",
-                        //     output: r#"
-              // class Mx {
-                // public get myValue() { return gql`
-                  // {
-                    // user(id: 5) {
-                      // firstName
-                      // lastName
-                    // }
-                  // }
-                // `; }
+                errors => [
+                  {
+                    message_id => "prefer_getter_style",
+                    column => 19,
+                    line => 3,
+                    end_line => 3,
+                    end_column => 26,
+                    // suggestions: [
+                    //   {
+                    //     message_id => "prefer_getter_styleSuggestion",
+                    //     output: r#"
+          // class Mx {
+            // public get myValue() { return gql`
+              // {
+                // user(id: 5) {
+                  // firstName
+                  // lastName
+                // }
               // }
-                    // "#,
-                        //   },
-                        // ],
-                      },
-                    ],
-                    options => "getters",
+            // `; }
+          // }
+                // "#,
+                    //   },
+                    // ],
                   },
                 ],
-            },
-        )
+                options => "getters",
+              },
+              {
+                code => r#"
+class Mx {
+  get p1() {
+    return 1n;
+  }
+  get p2() {
+    return -1;
+  }
+  get p3() {
+    return Symbol('p3');
+  }
+}
+                    "#,
+                errors => [
+                  { message_id => "prefer_field_style", line => 3, column => 7 },
+                  { message_id => "prefer_field_style", line => 6, column => 7 },
+                  { message_id => "prefer_field_style", line => 9, column => 7 },
+                ],
+              },
+              {
+                code => r#"
+class Mx {
+  readonly p1 = -1n;
+  readonly p2 = Symbol();
+}
+                    "#,
+                errors => [
+                  { message_id => "prefer_getter_style", line => 3, column => 12 },
+                  { message_id => "prefer_getter_style", line => 4, column => 12 },
+                ],
+                options => "getters",
+              },
+              {
+                code => r#"
+class Mx {
+  readonly p1 = 'hello world';
+  static readonly p2 = 'hello world';
+  constructor(other: Mx) {
+    other.p1 = 'goodbye';
+    this.p2 = 'goodbye';
+  }
+}
+                    "#,
+                errors => [
+                  { message_id => "prefer_getter_style", line => 3, column => 12 },
+                  { message_id => "prefer_getter_style", line => 4, column => 19 },
+                ],
+                options => "getters",
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_class_literal_property_style_rule() {
+        run_for_typescript_and_tsx(
+            class_literal_property_style_rule(),
+            test_cases::class_literal_property_style_rule_tests(),
+        );
     }
 }
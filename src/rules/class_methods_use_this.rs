@@ -1,4 +1,4 @@
-use std::{collections::HashSet, sync::Arc};
+use std::sync::Arc;
 
 use serde::Deserialize;
 use squalid::OptionExt;
@@ -13,8 +13,12 @@ use tree_sitter_lint_plugin_eslint_builtin::{
 };
 
 use crate::{
-    ast_helpers::{class_has_implements_clause, get_accessibility_modifier, has_override_modifier},
+    ast_helpers::{
+        class_has_implements_clause, get_accessibility_modifier, has_override_modifier,
+        skip_leading_decorators,
+    },
     kind::PublicFieldDefinition,
+    util::{matches_any_name_pattern, nearest_class, NamePattern},
 };
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
@@ -33,7 +37,7 @@ enum IgnoreClassesThatImplementAnInterface {
 #[derive(Default, Deserialize)]
 #[serde(default)]
 struct Options {
-    except_methods: Option<Vec<String>>,
+    except_methods: Option<Vec<NamePattern>>,
     enforce_for_class_fields: Option<bool>,
     ignore_override_methods: Option<bool>,
     ignore_classes_that_implement_an_interface: Option<IgnoreClassesThatImplementAnInterface>,
@@ -79,7 +83,7 @@ pub fn class_methods_use_this_rule() -> Arc<dyn Rule> {
         state => {
             [per-config]
             enforce_for_class_fields: bool = options.enforce_for_class_fields(),
-            except_methods: HashSet<String> = options.except_methods.clone().unwrap_or_default().into_iter().collect(),
+            except_methods: Vec<NamePattern> = options.except_methods.clone().unwrap_or_default(),
             ignore_override_methods: bool = options.ignore_override_methods(),
             ignore_classes_that_implement_an_interface: IgnoreClassesThatImplementAnInterface = options.ignore_classes_that_implement_an_interface(),
             [per-file-run]
@@ -92,7 +96,7 @@ pub fn class_methods_use_this_rule() -> Arc<dyn Rule> {
                 }) {
                     Some(member) => StackItem {
                         member: Some(member),
-                        class: Some(member.parent().unwrap().parent().unwrap()),
+                        class: nearest_class(member),
                         uses_this: false,
                     },
                     None => StackItem {
@@ -150,7 +154,7 @@ pub fn class_methods_use_this_rule() -> Arc<dyn Rule> {
                     }
                 };
 
-                !self.except_methods.contains(&*name)
+                !matches_any_name_pattern(&self.except_methods, &name)
             }
 
             fn exit_function(&mut self, node: Node<'a>, context: &QueryMatchContext<'a, '_>) {
@@ -176,7 +180,10 @@ pub fn class_methods_use_this_rule() -> Arc<dyn Rule> {
 
                 context.report(violation! {
                     node => node,
-                    range => ast_utils::get_function_head_range(node),
+                    range => skip_leading_decorators(
+                        ast_utils::get_function_head_range(node),
+                        stack_context_member,
+                    ),
                     message_id => "missing_this",
                     data => {
                         name => ast_utils::get_function_name_with_kind(node, context),
@@ -252,218 +259,228 @@ pub fn class_methods_use_this_rule() -> Arc<dyn Rule> {
     }
 }
 
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn class_methods_use_this_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                { code => "class A { constructor() {} }", environment => { ecma_version => 6 } },
+                { code => "class A { foo() {this} }", environment => { ecma_version => 6 } },
+                { code => "class A { foo() {this.bar = 'bar';} }", environment => { ecma_version => 6 } },
+                { code => "class A { foo() {bar(this);} }", environment => { ecma_version => 6 } },
+                { code => "class A extends B { foo() {super.foo();} }", environment => { ecma_version => 6 } },
+                { code => "class A { foo() { if(true) { return this; } } }", environment => { ecma_version => 6 } },
+                { code => "class A { static foo() {} }", environment => { ecma_version => 6 } },
+                { code => "({ a(){} });", environment => { ecma_version => 6 } },
+                { code => "class A { foo() { () => this; } }", environment => { ecma_version => 6 } },
+                { code => "({ a: function () {} });", environment => { ecma_version => 6 } },
+                { code => "class A { foo() {this} bar() {} }", options => { except_methods => ["bar"] }, environment => { ecma_version => 6 } },
+                { code => "class A { \"foo\"() { } }", options => { except_methods => ["foo"] }, environment => { ecma_version => 6 } },
+                { code => "class A { 42() { } }", options => { except_methods => ["42"] }, environment => { ecma_version => 6 } },
+                { code => "class A { foo = function() {this} }", environment => { ecma_version => 2022 } },
+                { code => "class A { foo = () => {this} }", environment => { ecma_version => 2022 } },
+                { code => "class A { foo = () => {super.toString} }", environment => { ecma_version => 2022 } },
+                { code => "class A { static foo = function() {} }", environment => { ecma_version => 2022 } },
+                { code => "class A { static foo = () => {} }", environment => { ecma_version => 2022 } },
+                { code => "class A { #bar() {} }", options => { except_methods => ["#bar"] }, environment => { ecma_version => 2022 } },
+                { code => "class A { foo = function () {} }", options => { enforce_for_class_fields => false }, environment => { ecma_version => 2022 } },
+                { code => "class A { foo = () => {} }", options => { enforce_for_class_fields => false }, environment => { ecma_version => 2022 } },
+                { code => "class A { foo() { return class { [this.foo] = 1 }; } }", environment => { ecma_version => 2022 } },
+                { code => "class A { static {} }", environment => { ecma_version => 2022 } }
+            ],
+            invalid => [
+                {
+                    code => "class A { foo() {} }",
+                    environment => { ecma_version => 6 },
+                    errors => [
+                        { type => MethodDefinition, line => 1, column => 11, message_id => "missing_this", data => { name => "method 'foo'" } }
+                    ],
+                },
+                {
+                    code => "class A { foo() {/**this**/} }",
+                    environment => { ecma_version => 6 },
+                    errors => [
+                        { type => MethodDefinition, line => 1, column => 11, message_id => "missing_this", data => { name => "method 'foo'" } }
+                    ]
+                },
+                {
+                    code => "class A { foo() {var a = function () {this};} }",
+                    environment => { ecma_version => 6 },
+                    errors => [
+                        { type => MethodDefinition, line => 1, column => 11, message_id => "missing_this", data => { name => "method 'foo'" } }
+                    ]
+                },
+                {
+                    code => "class A { foo() {var a = function () {var b = function(){this}};} }",
+                    environment => { ecma_version => 6 },
+                    errors => [
+                        { type => MethodDefinition, line => 1, column => 11, message_id => "missing_this", data => { name => "method 'foo'" } }
+                    ]
+                },
+                {
+                    code => "class A { foo() {window.this} }",
+                    environment => { ecma_version => 6 },
+                    errors => [
+                        { type => MethodDefinition, line => 1, column => 11, message_id => "missing_this", data => { name => "method 'foo'" } }
+                    ]
+                },
+                {
+                    code => "class A { foo() {that.this = 'this';} }",
+                    environment => { ecma_version => 6 },
+                    errors => [
+                        { type => MethodDefinition, line => 1, column => 11, message_id => "missing_this", data => { name => "method 'foo'" } }
+                    ]
+                },
+                {
+                    code => "class A { foo() { () => undefined; } }",
+                    environment => { ecma_version => 6 },
+                    errors => [
+                        { type => MethodDefinition, line => 1, column => 11, message_id => "missing_this", data => { name => "method 'foo'" } }
+                    ]
+                },
+                {
+                    code => "class A { foo() {} bar() {} }",
+                    options => { except_methods => ["bar"] },
+                    environment => { ecma_version => 6 },
+                    errors => [
+                        { type => MethodDefinition, line => 1, column => 11, message_id => "missing_this", data => { name => "method 'foo'" } }
+                    ]
+                },
+                {
+                    code => "class A { foo() {} hasOwnProperty() {} }",
+                    options => { except_methods => ["foo"] },
+                    environment => { ecma_version => 6 },
+                    errors => [
+                        { type => MethodDefinition, line => 1, column => 20, message_id => "missing_this", data => { name => "method 'hasOwnProperty'" } }
+                    ]
+                },
+                {
+                    code => "class A { [foo]() {} }",
+                    options => { except_methods => ["foo"] },
+                    environment => { ecma_version => 6 },
+                    errors => [
+                        { type => MethodDefinition, line => 1, column => 11, message_id => "missing_this", data => { name => "method" } }
+                    ]
+                },
+                {
+                    code => "class A { #foo() { } foo() {} #bar() {} }",
+                    options => { except_methods => ["#foo"] },
+                    environment => { ecma_version => 2022 },
+                    errors => [
+                        { type => MethodDefinition, line => 1, column => 22, message_id => "missing_this", data => { name => "method 'foo'" } },
+                        { type => MethodDefinition, line => 1, column => 31, message_id => "missing_this", data => { name => "private method #bar" } }
+                    ]
+                },
+                {
+                    code => "class A { foo(){} 'bar'(){} 123(){} [`baz`](){} [a](){} [f(a)](){} get quux(){} set[a](b){} *quuux(){} }",
+                    environment => { ecma_version => 6 },
+                    errors => [
+                        { message_id => "missing_this", data => { name => "method 'foo'" }, type => MethodDefinition, column => 11 },
+                        { message_id => "missing_this", data => { name => "method 'bar'" }, type => MethodDefinition, column => 19 },
+                        { message_id => "missing_this", data => { name => "method '123'" }, type => MethodDefinition, column => 29 },
+                        { message_id => "missing_this", data => { name => "method 'baz'" }, type => MethodDefinition, column => 37 },
+                        { message_id => "missing_this", data => { name => "method" }, type => MethodDefinition, column => 49 },
+                        { message_id => "missing_this", data => { name => "method" }, type => MethodDefinition, column => 57 },
+                        { message_id => "missing_this", data => { name => "getter 'quux'" }, type => MethodDefinition, column => 68 },
+                        { message_id => "missing_this", data => { name => "setter" }, type => MethodDefinition, column => 81 },
+                        { message_id => "missing_this", data => { name => "generator method 'quuux'" }, type => MethodDefinition, column => 93 }
+                    ]
+                },
+                {
+                    code => "class A { foo = function() {} }",
+                    environment => { ecma_version => 2022 },
+                    errors => [
+                        { message_id => "missing_this", data => { name => "method 'foo'" }, column => 11, end_column => 25 }
+                    ],
+                },
+                {
+                    code => "class A { foo = () => {} }",
+                    environment => { ecma_version => 2022 },
+                    errors => [
+                        { message_id => "missing_this", data => { name => "method 'foo'" }, column => 11, end_column => 17 }
+                    ]
+                },
+                {
+                    code => "class A { #foo = function() {} }",
+                    environment => { ecma_version => 2022 },
+                    errors => [
+                        { message_id => "missing_this", data => { name => "private method #foo" }, column => 11, end_column => 26 }
+                    ]
+                },
+                {
+                    code => "class A { #foo = () => {} }",
+                    environment => { ecma_version => 2022 },
+                    errors => [
+                        { message_id => "missing_this", data => { name => "private method #foo" }, column => 11, end_column => 18 }
+                    ]
+                },
+                {
+                    code => "class A { #foo() {} }",
+                    environment => { ecma_version => 2022 },
+                    errors => [
+                        { message_id => "missing_this", data => { name => "private method #foo" }, column => 11, end_column => 15 }
+                    ]
+                },
+                {
+                    code => "class A { get #foo() {} }",
+                    environment => { ecma_version => 2022 },
+                    errors => [
+                        { message_id => "missing_this", data => { name => "private getter #foo" }, column => 11, end_column => 19 }
+                    ]
+                },
+                {
+                    code => "class A { set #foo(x) {} }",
+                    environment => { ecma_version => 2022 },
+                    errors => [
+                        { message_id => "missing_this", data => { name => "private setter #foo" }, column => 11, end_column => 19 }
+                    ]
+                },
+                {
+                    code => "class A { foo () { return class { foo = this }; } }",
+                    environment => { ecma_version => 2022 },
+                    errors => [
+                        { message_id => "missing_this", data => { name => "method 'foo'" }, column => 11, end_column => 15 }
+                    ]
+                },
+                {
+                    code => "class A { foo () { return function () { foo = this }; } }",
+                    environment => { ecma_version => 2022 },
+                    errors => [
+                        { message_id => "missing_this", data => { name => "method 'foo'" }, column => 11, end_column => 15 }
+                    ]
+                },
+                {
+                    code => "class A { foo () { return class { static { this; } } } }",
+                    environment => { ecma_version => 2022 },
+                    errors => [
+                        { message_id => "missing_this", data => { name => "method 'foo'" }, column => 11, end_column => 15 }
+                    ]
+                }
+            ]
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use tree_sitter_lint::{rule_tests, RuleTester};
+    use tree_sitter_lint::rule_tests;
 
     use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
 
     #[test]
     fn test_class_methods_use_this_rule() {
-        RuleTester::run(
+        run_for_typescript_and_tsx(
             class_methods_use_this_rule(),
-            rule_tests! {
-                valid => [
-                    { code => "class A { constructor() {} }", environment => { ecma_version => 6 } },
-                    { code => "class A { foo() {this} }", environment => { ecma_version => 6 } },
-                    { code => "class A { foo() {this.bar = 'bar';} }", environment => { ecma_version => 6 } },
-                    { code => "class A { foo() {bar(this);} }", environment => { ecma_version => 6 } },
-                    { code => "class A extends B { foo() {super.foo();} }", environment => { ecma_version => 6 } },
-                    { code => "class A { foo() { if(true) { return this; } } }", environment => { ecma_version => 6 } },
-                    { code => "class A { static foo() {} }", environment => { ecma_version => 6 } },
-                    { code => "({ a(){} });", environment => { ecma_version => 6 } },
-                    { code => "class A { foo() { () => this; } }", environment => { ecma_version => 6 } },
-                    { code => "({ a: function () {} });", environment => { ecma_version => 6 } },
-                    { code => "class A { foo() {this} bar() {} }", options => { except_methods => ["bar"] }, environment => { ecma_version => 6 } },
-                    { code => "class A { \"foo\"() { } }", options => { except_methods => ["foo"] }, environment => { ecma_version => 6 } },
-                    { code => "class A { 42() { } }", options => { except_methods => ["42"] }, environment => { ecma_version => 6 } },
-                    { code => "class A { foo = function() {this} }", environment => { ecma_version => 2022 } },
-                    { code => "class A { foo = () => {this} }", environment => { ecma_version => 2022 } },
-                    { code => "class A { foo = () => {super.toString} }", environment => { ecma_version => 2022 } },
-                    { code => "class A { static foo = function() {} }", environment => { ecma_version => 2022 } },
-                    { code => "class A { static foo = () => {} }", environment => { ecma_version => 2022 } },
-                    { code => "class A { #bar() {} }", options => { except_methods => ["#bar"] }, environment => { ecma_version => 2022 } },
-                    { code => "class A { foo = function () {} }", options => { enforce_for_class_fields => false }, environment => { ecma_version => 2022 } },
-                    { code => "class A { foo = () => {} }", options => { enforce_for_class_fields => false }, environment => { ecma_version => 2022 } },
-                    { code => "class A { foo() { return class { [this.foo] = 1 }; } }", environment => { ecma_version => 2022 } },
-                    { code => "class A { static {} }", environment => { ecma_version => 2022 } }
-                ],
-                invalid => [
-                    {
-                        code => "class A { foo() {} }",
-                        environment => { ecma_version => 6 },
-                        errors => [
-                            { type => MethodDefinition, line => 1, column => 11, message_id => "missing_this", data => { name => "method 'foo'" } }
-                        ],
-                    },
-                    {
-                        code => "class A { foo() {/**this**/} }",
-                        environment => { ecma_version => 6 },
-                        errors => [
-                            { type => MethodDefinition, line => 1, column => 11, message_id => "missing_this", data => { name => "method 'foo'" } }
-                        ]
-                    },
-                    {
-                        code => "class A { foo() {var a = function () {this};} }",
-                        environment => { ecma_version => 6 },
-                        errors => [
-                            { type => MethodDefinition, line => 1, column => 11, message_id => "missing_this", data => { name => "method 'foo'" } }
-                        ]
-                    },
-                    {
-                        code => "class A { foo() {var a = function () {var b = function(){this}};} }",
-                        environment => { ecma_version => 6 },
-                        errors => [
-                            { type => MethodDefinition, line => 1, column => 11, message_id => "missing_this", data => { name => "method 'foo'" } }
-                        ]
-                    },
-                    {
-                        code => "class A { foo() {window.this} }",
-                        environment => { ecma_version => 6 },
-                        errors => [
-                            { type => MethodDefinition, line => 1, column => 11, message_id => "missing_this", data => { name => "method 'foo'" } }
-                        ]
-                    },
-                    {
-                        code => "class A { foo() {that.this = 'this';} }",
-                        environment => { ecma_version => 6 },
-                        errors => [
-                            { type => MethodDefinition, line => 1, column => 11, message_id => "missing_this", data => { name => "method 'foo'" } }
-                        ]
-                    },
-                    {
-                        code => "class A { foo() { () => undefined; } }",
-                        environment => { ecma_version => 6 },
-                        errors => [
-                            { type => MethodDefinition, line => 1, column => 11, message_id => "missing_this", data => { name => "method 'foo'" } }
-                        ]
-                    },
-                    {
-                        code => "class A { foo() {} bar() {} }",
-                        options => { except_methods => ["bar"] },
-                        environment => { ecma_version => 6 },
-                        errors => [
-                            { type => MethodDefinition, line => 1, column => 11, message_id => "missing_this", data => { name => "method 'foo'" } }
-                        ]
-                    },
-                    {
-                        code => "class A { foo() {} hasOwnProperty() {} }",
-                        options => { except_methods => ["foo"] },
-                        environment => { ecma_version => 6 },
-                        errors => [
-                            { type => MethodDefinition, line => 1, column => 20, message_id => "missing_this", data => { name => "method 'hasOwnProperty'" } }
-                        ]
-                    },
-                    {
-                        code => "class A { [foo]() {} }",
-                        options => { except_methods => ["foo"] },
-                        environment => { ecma_version => 6 },
-                        errors => [
-                            { type => MethodDefinition, line => 1, column => 11, message_id => "missing_this", data => { name => "method" } }
-                        ]
-                    },
-                    {
-                        code => "class A { #foo() { } foo() {} #bar() {} }",
-                        options => { except_methods => ["#foo"] },
-                        environment => { ecma_version => 2022 },
-                        errors => [
-                            { type => MethodDefinition, line => 1, column => 22, message_id => "missing_this", data => { name => "method 'foo'" } },
-                            { type => MethodDefinition, line => 1, column => 31, message_id => "missing_this", data => { name => "private method #bar" } }
-                        ]
-                    },
-                    {
-                        code => "class A { foo(){} 'bar'(){} 123(){} [`baz`](){} [a](){} [f(a)](){} get quux(){} set[a](b){} *quuux(){} }",
-                        environment => { ecma_version => 6 },
-                        errors => [
-                            { message_id => "missing_this", data => { name => "method 'foo'" }, type => MethodDefinition, column => 11 },
-                            { message_id => "missing_this", data => { name => "method 'bar'" }, type => MethodDefinition, column => 19 },
-                            { message_id => "missing_this", data => { name => "method '123'" }, type => MethodDefinition, column => 29 },
-                            { message_id => "missing_this", data => { name => "method 'baz'" }, type => MethodDefinition, column => 37 },
-                            { message_id => "missing_this", data => { name => "method" }, type => MethodDefinition, column => 49 },
-                            { message_id => "missing_this", data => { name => "method" }, type => MethodDefinition, column => 57 },
-                            { message_id => "missing_this", data => { name => "getter 'quux'" }, type => MethodDefinition, column => 68 },
-                            { message_id => "missing_this", data => { name => "setter" }, type => MethodDefinition, column => 81 },
-                            { message_id => "missing_this", data => { name => "generator method 'quuux'" }, type => MethodDefinition, column => 93 }
-                        ]
-                    },
-                    {
-                        code => "class A { foo = function() {} }",
-                        environment => { ecma_version => 2022 },
-                        errors => [
-                            { message_id => "missing_this", data => { name => "method 'foo'" }, column => 11, end_column => 25 }
-                        ],
-                    },
-                    {
-                        code => "class A { foo = () => {} }",
-                        environment => { ecma_version => 2022 },
-                        errors => [
-                            { message_id => "missing_this", data => { name => "method 'foo'" }, column => 11, end_column => 17 }
-                        ]
-                    },
-                    {
-                        code => "class A { #foo = function() {} }",
-                        environment => { ecma_version => 2022 },
-                        errors => [
-                            { message_id => "missing_this", data => { name => "private method #foo" }, column => 11, end_column => 26 }
-                        ]
-                    },
-                    {
-                        code => "class A { #foo = () => {} }",
-                        environment => { ecma_version => 2022 },
-                        errors => [
-                            { message_id => "missing_this", data => { name => "private method #foo" }, column => 11, end_column => 18 }
-                        ]
-                    },
-                    {
-                        code => "class A { #foo() {} }",
-                        environment => { ecma_version => 2022 },
-                        errors => [
-                            { message_id => "missing_this", data => { name => "private method #foo" }, column => 11, end_column => 15 }
-                        ]
-                    },
-                    {
-                        code => "class A { get #foo() {} }",
-                        environment => { ecma_version => 2022 },
-                        errors => [
-                            { message_id => "missing_this", data => { name => "private getter #foo" }, column => 11, end_column => 19 }
-                        ]
-                    },
-                    {
-                        code => "class A { set #foo(x) {} }",
-                        environment => { ecma_version => 2022 },
-                        errors => [
-                            { message_id => "missing_this", data => { name => "private setter #foo" }, column => 11, end_column => 19 }
-                        ]
-                    },
-                    {
-                        code => "class A { foo () { return class { foo = this }; } }",
-                        environment => { ecma_version => 2022 },
-                        errors => [
-                            { message_id => "missing_this", data => { name => "method 'foo'" }, column => 11, end_column => 15 }
-                        ]
-                    },
-                    {
-                        code => "class A { foo () { return function () { foo = this }; } }",
-                        environment => { ecma_version => 2022 },
-                        errors => [
-                            { message_id => "missing_this", data => { name => "method 'foo'" }, column => 11, end_column => 15 }
-                        ]
-                    },
-                    {
-                        code => "class A { foo () { return class { static { this; } } } }",
-                        environment => { ecma_version => 2022 },
-                        errors => [
-                            { message_id => "missing_this", data => { name => "method 'foo'" }, column => 11, end_column => 15 }
-                        ]
-                    }
-                ]
-            },
-        )
+            test_cases::class_methods_use_this_rule_tests(),
+        );
     }
 
     #[test]
     fn test_class_methods_use_this_rule_typescript() {
-        RuleTester::run(
+        run_for_typescript_and_tsx(
             class_methods_use_this_rule(),
             rule_tests! {
                 valid => [
@@ -804,6 +821,54 @@ mod tests {
                   },
                   {
                     code => r#"
+              abstract class Foo {
+                abstract bar(): void;
+                method() {}
+              }
+                    "#,
+                    options => {},
+                    errors => [
+                      {
+                        message_id => "missing_this",
+                        line => 4,
+                        column => 17,
+                      },
+                    ],
+                  },
+                  {
+                    code => r#"
+class Foo {
+  @dec
+  @other()
+  method() {}
+}
+                    "#,
+                    options => {},
+                    errors => [
+                      {
+                        message_id => "missing_this",
+                        line => 5,
+                        column => 3,
+                        end_line => 5,
+                        end_column => 9,
+                      },
+                    ],
+                  },
+                  {
+                    code => r#"
+              abstract class Foo implements Bar {
+                private method() {}
+              }
+                    "#,
+                    options => { ignore_classes_that_implement_an_interface => "public-fields" },
+                    errors => [
+                      {
+                        message_id => "missing_this",
+                      },
+                    ],
+                  },
+                  {
+                    code => r#"
               class Foo {
                 private method() {}
               }
@@ -1323,4 +1388,67 @@ mod tests {
             },
         )
     }
+
+    #[test]
+    fn test_class_methods_use_this_rule_except_methods_patterns() {
+        run_for_typescript_and_tsx(
+            class_methods_use_this_rule(),
+            rule_tests! {
+                valid => [
+                  {
+                    code => "class A { render() {} renderHeader() {} }",
+                    options => { except_methods => ["render*"] },
+                  },
+                  {
+                    code => "class A { onClick() {} onChange = () => {} }",
+                    options => { except_methods => ["/^on[A-Z]/"] },
+                  },
+                  {
+                    code => "class A { HANDLE() {} }",
+                    options => { except_methods => ["/^handle$/i"] },
+                  },
+                  {
+                    code => "class A { get1() {} get2() {} }",
+                    options => { except_methods => ["get?"] },
+                  },
+                  {
+                    code => "class A { #renderRow() {} }",
+                    options => { except_methods => ["#render*"] },
+                  },
+                  {
+                    code => "class A { 'a.b'() {} }",
+                    options => { except_methods => ["a.b"] },
+                  },
+                ],
+                invalid => [
+                  {
+                    code => "class A { rerender() {} }",
+                    options => { except_methods => ["render*"] },
+                    errors => [{ message_id => "missing_this", data => { name => "method 'rerender'" } }],
+                  },
+                  {
+                    code => "class A { one() {} onClick() {} }",
+                    options => { except_methods => ["/^on[A-Z]/"] },
+                    errors => [{ message_id => "missing_this", data => { name => "method 'one'" } }],
+                  },
+                  {
+                    // `.` is only special in regexes
+                    code => "class A { aXb() {} }",
+                    options => { except_methods => ["a.b"] },
+                    errors => [{ message_id => "missing_this", data => { name => "method 'aXb'" } }],
+                  },
+                  {
+                    code => "class A { get10() {} }",
+                    options => { except_methods => ["get?"] },
+                    errors => [{ message_id => "missing_this", data => { name => "method 'get10'" } }],
+                  },
+                  {
+                    code => "class A { #renderRow() {} }",
+                    options => { except_methods => ["render*"] },
+                    errors => [{ message_id => "missing_this", data => { name => "private method #renderRow" } }],
+                  },
+                ],
+            },
+        )
+    }
 }
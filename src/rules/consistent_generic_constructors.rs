@@ -8,8 +8,11 @@ use tree_sitter_lint::{
 };
 use tree_sitter_lint_plugin_eslint_builtin::kind::{Identifier, NewExpression, VariableDeclarator};
 
-use crate::kind::{
-    GenericType, OptionalParameter, PublicFieldDefinition, RequiredParameter, TypeIdentifier,
+use crate::{
+    ast_helpers::get_range_from,
+    kind::{
+        GenericType, OptionalParameter, PublicFieldDefinition, RequiredParameter, TypeIdentifier,
+    },
 };
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize)]
@@ -59,6 +62,12 @@ pub fn consistent_generic_constructors_rule() -> Arc<dyn Rule> {
                     ),
                     _ => unreachable!()
                 };
+                // upstream reports parameters as the AssignmentPattern, ie
+                // without any accessibility modifier or decorators
+                let report_range = match node.kind() {
+                    RequiredParameter | OptionalParameter => get_range_from(lhs_name, node),
+                    _ => node.range(),
+                };
                 let Some(rhs) = rhs.filter(|&rhs| {
                     rhs.kind() == NewExpression &&
                         rhs.field("constructor").kind() == Identifier
@@ -82,7 +91,13 @@ pub fn consistent_generic_constructors_rule() -> Arc<dyn Rule> {
                         );
                         context.report(violation! {
                             node => node,
+                            range => report_range,
                             message_id => "prefer_type_annotation",
+                            data => {
+                                banned_name => "constructor",
+                                replacement => "type-annotation",
+                                option => "type-annotation",
+                            },
                             fix => |fixer| {
                                 let id_to_attach_annotation = match node.kind() {
                                     PublicFieldDefinition => node.field("name"),
@@ -119,7 +134,13 @@ pub fn consistent_generic_constructors_rule() -> Arc<dyn Rule> {
                         });
                         context.report(violation! {
                             node => node,
+                            range => report_range,
                             message_id => "prefer_constructor",
+                            data => {
+                                banned_name => "type-annotation",
+                                replacement => "constructor",
+                                option => "constructor",
+                            },
                             fix => |fixer| {
                                 fixer.remove(lhs.parent().unwrap());
                                 for &comment in &extra_comments {
@@ -147,531 +168,574 @@ pub fn consistent_generic_constructors_rule() -> Arc<dyn Rule> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use tree_sitter_lint::{rule_tests, RuleTester};
-
-    use super::*;
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
 
-    #[test]
-    fn test_consistent_generic_constructors_rule() {
-        RuleTester::run(
-            consistent_generic_constructors_rule(),
-            rule_tests! {
-                valid => [
-                  // default: constructor
-                  "const a = new Foo();",
-                  "const a = new Foo<string>();",
-                  "const a: Foo<string> = new Foo<string>();",
-                  "const a: Foo = new Foo();",
-                  "const a: Bar<string> = new Foo();",
-                  "const a: Foo = new Foo<string>();",
-                  "const a: Bar = new Foo<string>();",
-                  "const a: Bar<string> = new Foo<string>();",
-                  "const a: Foo<string> = Foo<string>();",
-                  "const a: Foo<string> = Foo();",
-                  "const a: Foo = Foo<string>();",
-                  r#"
+    pub fn consistent_generic_constructors_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              // default: constructor
+              "const a = new Foo();",
+              "const a = new Foo<string>();",
+              "const a: Foo<string> = new Foo<string>();",
+              "const a: Foo = new Foo();",
+              "const a: Bar<string> = new Foo();",
+              "const a: Foo = new Foo<string>();",
+              "const a: Bar = new Foo<string>();",
+              "const a: Bar<string> = new Foo<string>();",
+              "const a: Foo<string> = Foo<string>();",
+              "const a: Foo<string> = Foo();",
+              "const a: Foo = Foo<string>();",
+              r#"
               class Foo {
                 a = new Foo<string>();
               }
                   "#,
-                  r#"
+              r#"
               function foo(a: Foo = new Foo<string>()) {}
                   "#,
-                  r#"
+              r#"
               function foo({ a }: Foo = new Foo<string>()) {}
                   "#,
-                  r#"
+              r#"
               function foo([a]: Foo = new Foo<string>()) {}
                   "#,
-                  r#"
+              r#"
               class A {
                 constructor(a: Foo = new Foo<string>()) {}
               }
                   "#,
-                  r#"
+              r#"
               const a = function (a: Foo = new Foo<string>()) {};
                   "#,
-                  // type-annotation
-                  {
-                    code => "const a = new Foo();",
-                    options => "type-annotation",
-                  },
-                  {
-                    code => "const a: Foo<string> = new Foo();",
-                    options => "type-annotation",
-                  },
-                  {
-                    code => "const a: Foo<string> = new Foo<string>();",
-                    options => "type-annotation",
-                  },
-                  {
-                    code => "const a: Foo = new Foo();",
-                    options => "type-annotation",
-                  },
-                  {
-                    code => "const a: Bar = new Foo<string>();",
-                    options => "type-annotation",
-                  },
-                  {
-                    code => "const a: Bar<string> = new Foo<string>();",
-                    options => "type-annotation",
-                  },
-                  {
-                    code => "const a: Foo<string> = Foo<string>();",
-                    options => "type-annotation",
-                  },
-                  {
-                    code => "const a: Foo<string> = Foo();",
-                    options => "type-annotation",
-                  },
-                  {
-                    code => "const a: Foo = Foo<string>();",
-                    options => "type-annotation",
-                  },
-                  {
-                    code => "const a = new (class C<T> {})<string>();",
-                    options => "type-annotation",
-                  },
-                  {
-                    code => r#"
+              // type-annotation
+              {
+                code => "const a = new Foo();",
+                options => "type-annotation",
+              },
+              {
+                code => "const a: Foo<string> = new Foo();",
+                options => "type-annotation",
+              },
+              {
+                code => "const a: Foo<string> = new Foo<string>();",
+                options => "type-annotation",
+              },
+              {
+                code => "const a: Foo = new Foo();",
+                options => "type-annotation",
+              },
+              {
+                code => "const a: Bar = new Foo<string>();",
+                options => "type-annotation",
+              },
+              {
+                code => "const a: Bar<string> = new Foo<string>();",
+                options => "type-annotation",
+              },
+              {
+                code => "const a: Foo<string> = Foo<string>();",
+                options => "type-annotation",
+              },
+              {
+                code => "const a: Foo<string> = Foo();",
+                options => "type-annotation",
+              },
+              {
+                code => "const a: Foo = Foo<string>();",
+                options => "type-annotation",
+              },
+              {
+                code => "const a = new (class C<T> {})<string>();",
+                options => "type-annotation",
+              },
+              {
+                code => r#"
               class Foo {
                 a: Foo<string> = new Foo();
               }
                     "#,
-                    options => "type-annotation",
-                  },
-                  {
-                    code => r#"
+                options => "type-annotation",
+              },
+              {
+                code => r#"
               function foo(a: Foo<string> = new Foo()) {}
                     "#,
-                    options => "type-annotation",
-                  },
-                  {
-                    code => r#"
+                options => "type-annotation",
+              },
+              {
+                code => r#"
               function foo({ a }: Foo<string> = new Foo()) {}
                     "#,
-                    options => "type-annotation",
-                  },
-                  {
-                    code => r#"
+                options => "type-annotation",
+              },
+              {
+                code => r#"
               function foo([a]: Foo<string> = new Foo()) {}
                     "#,
-                    options => "type-annotation",
-                  },
-                  {
-                    code => r#"
+                options => "type-annotation",
+              },
+              {
+                code => r#"
               class A {
                 constructor(a: Foo<string> = new Foo()) {}
               }
                     "#,
-                    options => "type-annotation",
-                  },
-                  {
-                    code => r#"
+                options => "type-annotation",
+              },
+              {
+                code => r#"
               const a = function (a: Foo<string> = new Foo()) {};
                     "#,
-                    options => "type-annotation",
-                  },
-                  {
-                    code => r#"
+                options => "type-annotation",
+              },
+              {
+                code => r#"
               const [a = new Foo<string>()] = [];
                     "#,
-                    options => "type-annotation",
-                  },
-                  {
-                    code => r#"
+                options => "type-annotation",
+              },
+              {
+                code => r#"
               function a([a = new Foo<string>()]) {}
                     "#,
-                    options => "type-annotation",
+                options => "type-annotation",
+              },
+            ],
+            invalid => [
+              {
+                code => "const a: Foo<string> = new Foo();",
+                errors => [
+                  {
+                    message_id => "prefer_constructor",
+                    data => {
+                      banned_name => "type-annotation",
+                      replacement => "constructor",
+                      option => "constructor",
+                    },
+                    line => 1,
+                    column => 7,
+                    end_line => 1,
+                    end_column => 33,
                   },
                 ],
-                invalid => [
-                  {
-                    code => "const a: Foo<string> = new Foo();",
-                    errors => [
-                      {
-                        message_id => "prefer_constructor",
-                      },
-                    ],
-                    output => "const a = new Foo<string>();",
-                  },
+                output => "const a = new Foo<string>();",
+              },
+              {
+                code => "const a: Map<string, number> = new Map();",
+                errors => [
                   {
-                    code => "const a: Map<string, number> = new Map();",
-                    errors => [
-                      {
-                        message_id => "prefer_constructor",
-                      },
-                    ],
-                    output => "const a = new Map<string, number>();",
+                    message_id => "prefer_constructor",
                   },
+                ],
+                output => "const a = new Map<string, number>();",
+              },
+              {
+                code => r#"const a: Map <string, number> = new Map();"#,
+                errors => [
                   {
-                    code => r#"const a: Map <string, number> = new Map();"#,
-                    errors => [
-                      {
-                        message_id => "prefer_constructor",
-                      },
-                    ],
-                    output => r#"const a = new Map<string, number>();"#,
+                    message_id => "prefer_constructor",
                   },
+                ],
+                output => r#"const a = new Map<string, number>();"#,
+              },
+              {
+                code => r#"const a: Map< string, number > = new Map();"#,
+                errors => [
                   {
-                    code => r#"const a: Map< string, number > = new Map();"#,
-                    errors => [
-                      {
-                        message_id => "prefer_constructor",
-                      },
-                    ],
-                    output => r#"const a = new Map< string, number >();"#,
+                    message_id => "prefer_constructor",
                   },
+                ],
+                output => r#"const a = new Map< string, number >();"#,
+              },
+              {
+                code => r#"const a: Map<string, number> = new Map ();"#,
+                errors => [
                   {
-                    code => r#"const a: Map<string, number> = new Map ();"#,
-                    errors => [
-                      {
-                        message_id => "prefer_constructor",
-                      },
-                    ],
-                    output => r#"const a = new Map<string, number> ();"#,
+                    message_id => "prefer_constructor",
                   },
+                ],
+                output => r#"const a = new Map<string, number> ();"#,
+              },
+              {
+                code => r#"const a: Foo<number> = new Foo;"#,
+                errors => [
                   {
-                    code => r#"const a: Foo<number> = new Foo;"#,
-                    errors => [
-                      {
-                        message_id => "prefer_constructor",
-                      },
-                    ],
-                    output => r#"const a = new Foo<number>();"#,
+                    message_id => "prefer_constructor",
                   },
+                ],
+                output => r#"const a = new Foo<number>();"#,
+              },
+              {
+                code => "const a: /* comment */ Foo/* another */ <string> = new Foo();",
+                errors => [
                   {
-                    code => "const a: /* comment */ Foo/* another */ <string> = new Foo();",
-                    errors => [
-                      {
-                        message_id => "prefer_constructor",
-                      },
-                    ],
-                    output => r#"const a = new Foo/* comment *//* another */<string>();"#,
+                    message_id => "prefer_constructor",
                   },
+                ],
+                output => r#"const a = new Foo/* comment *//* another */<string>();"#,
+              },
+              {
+                code => "const a: Foo/* comment */ <string> = new Foo /* another */();",
+                errors => [
                   {
-                    code => "const a: Foo/* comment */ <string> = new Foo /* another */();",
-                    errors => [
-                      {
-                        message_id => "prefer_constructor",
-                      },
-                    ],
-                    output => r#"const a = new Foo/* comment */<string> /* another */();"#,
+                    message_id => "prefer_constructor",
                   },
+                ],
+                output => r#"const a = new Foo/* comment */<string> /* another */();"#,
+              },
+              {
+                code => "const a: Foo<string> = new \n Foo \n ();",
+                errors => [
                   {
-                    code => "const a: Foo<string> = new \n Foo \n ();",
-                    errors => [
-                      {
-                        message_id => "prefer_constructor",
-                      },
-                    ],
-                    output => "const a = new \n Foo<string> \n ();",
+                    message_id => "prefer_constructor",
                   },
-                  {
-                    code => r#"
+                ],
+                output => "const a = new \n Foo<string> \n ();",
+              },
+              {
+                code => r#"
               class Foo {
                 a: Foo<string> = new Foo();
               }
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_constructor",
-                      },
-                    ],
-                    output => r#"
+                errors => [
+                  {
+                    message_id => "prefer_constructor",
+                  },
+                ],
+                output => r#"
               class Foo {
                 a = new Foo<string>();
               }
                     "#,
-                  },
-                  {
-                    code => r#"
+              },
+              {
+                code => r#"
               class Foo {
                 [a]: Foo<string> = new Foo();
               }
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_constructor",
-                      },
-                    ],
-                    output => r#"
+                errors => [
+                  {
+                    message_id => "prefer_constructor",
+                  },
+                ],
+                output => r#"
               class Foo {
                 [a] = new Foo<string>();
               }
                     "#,
-                  },
-                  {
-                    code => r#"
+              },
+              {
+                code => r#"
               function foo(a: Foo<string> = new Foo()) {}
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_constructor",
-                      },
-                    ],
-                    output => r#"
+                errors => [
+                  {
+                    message_id => "prefer_constructor",
+                  },
+                ],
+                output => r#"
               function foo(a = new Foo<string>()) {}
                     "#,
-                  },
-                  {
-                    code => r#"
+              },
+              {
+                code => r#"
               function foo({ a }: Foo<string> = new Foo()) {}
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_constructor",
-                      },
-                    ],
-                    output => r#"
+                errors => [
+                  {
+                    message_id => "prefer_constructor",
+                  },
+                ],
+                output => r#"
               function foo({ a } = new Foo<string>()) {}
                     "#,
-                  },
-                  {
-                    code => r#"
+              },
+              {
+                code => r#"
               function foo([a]: Foo<string> = new Foo()) {}
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_constructor",
-                      },
-                    ],
-                    output => r#"
+                errors => [
+                  {
+                    message_id => "prefer_constructor",
+                  },
+                ],
+                output => r#"
               function foo([a] = new Foo<string>()) {}
                     "#,
-                  },
-                  {
-                    code => r#"
+              },
+              {
+                code => r#"
               class A {
                 constructor(a: Foo<string> = new Foo()) {}
               }
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_constructor",
-                      },
-                    ],
-                    output => r#"
+                errors => [
+                  {
+                    message_id => "prefer_constructor",
+                  },
+                ],
+                output => r#"
               class A {
                 constructor(a = new Foo<string>()) {}
               }
                     "#,
-                  },
+              },
+              {
+                code => r#"
+              class A {
+                constructor(private a: Foo<string> = new Foo()) {}
+              }
+                    "#,
+                errors => [
                   {
-                    code => r#"
+                    message_id => "prefer_constructor",
+                    line => 3,
+                    column => 37,
+                    end_line => 3,
+                    end_column => 63,
+                  },
+                ],
+                output => r#"
+              class A {
+                constructor(private a = new Foo<string>()) {}
+              }
+                    "#,
+              },
+              {
+                code => r#"
               const a = function (a: Foo<string> = new Foo()) {};
                     "#,
-                    errors => [
-                      {
-                        message_id => "prefer_constructor",
-                      },
-                    ],
-                    output => r#"
+                errors => [
+                  {
+                    message_id => "prefer_constructor",
+                  },
+                ],
+                output => r#"
               const a = function (a = new Foo<string>()) {};
                     "#,
+              },
+              {
+                code => "const a = new Foo<string>();",
+                options => "type-annotation",
+                errors => [
+                  {
+                    message_id => "prefer_type_annotation",
+                    data => {
+                      banned_name => "constructor",
+                      replacement => "type-annotation",
+                      option => "type-annotation",
+                    },
+                  },
+                ],
+                output => "const a: Foo<string> = new Foo();",
+              },
+              {
+                code => "const a = new Map<string, number>();",
+                options => "type-annotation",
+                errors => [
+                  {
+                    message_id => "prefer_type_annotation",
                   },
+                ],
+                output => "const a: Map<string, number> = new Map();",
+              },
+              {
+                code => r#"const a = new Map <string, number> ();"#,
+                options => "type-annotation",
+                errors => [
                   {
-                    code => "const a = new Foo<string>();",
-                    options => "type-annotation",
-                    errors => [
-                      {
-                        message_id => "prefer_type_annotation",
-                      },
-                    ],
-                    output => "const a: Foo<string> = new Foo();",
-                  },
-                  {
-                    code => "const a = new Map<string, number>();",
-                    options => "type-annotation",
-                    errors => [
-                      {
-                        message_id => "prefer_type_annotation",
-                      },
-                    ],
-                    output => "const a: Map<string, number> = new Map();",
-                  },
-                  {
-                    code => r#"const a = new Map <string, number> ();"#,
-                    options => "type-annotation",
-                    errors => [
-                      {
-                        message_id => "prefer_type_annotation",
-                      },
-                    ],
-                    output => r#"const a: Map<string, number> = new Map  ();"#,
-                  },
-                  {
-                    code => r#"const a = new Map< string, number >();"#,
-                    options => "type-annotation",
-                    errors => [
-                      {
-                        message_id => "prefer_type_annotation",
-                      },
-                    ],
-                    output => r#"const a: Map< string, number > = new Map();"#,
-                  },
-                  {
-                    code => r#"const a = new \n Foo<string> \n ();"#,
-                    options => "type-annotation",
-                    errors => [
-                      {
-                        message_id => "prefer_type_annotation",
-                      },
-                    ],
-                    output => r#"const a: Foo<string> = new \n Foo \n ();"#,
-                  },
-                  {
-                    code => "const a = new Foo/* comment */ <string> /* another */();",
-                    options => "type-annotation",
-                    errors => [
-                      {
-                        message_id => "prefer_type_annotation",
-                      },
-                    ],
-                    output => r#"const a: Foo<string> = new Foo/* comment */  /* another */();"#,
-                  },
-                  {
-                    code => "const a = new Foo</* comment */ string, /* another */ number>();",
-                    options => "type-annotation",
-                    errors => [
-                      {
-                        message_id => "prefer_type_annotation",
-                      },
-                    ],
-                    output => r#"const a: Foo</* comment */ string, /* another */ number> = new Foo();"#,
-                  },
-                  {
-                    code => r#"
+                    message_id => "prefer_type_annotation",
+                  },
+                ],
+                output => r#"const a: Map<string, number> = new Map  ();"#,
+              },
+              {
+                code => r#"const a = new Map< string, number >();"#,
+                options => "type-annotation",
+                errors => [
+                  {
+                    message_id => "prefer_type_annotation",
+                  },
+                ],
+                output => r#"const a: Map< string, number > = new Map();"#,
+              },
+              {
+                code => r#"const a = new \n Foo<string> \n ();"#,
+                options => "type-annotation",
+                errors => [
+                  {
+                    message_id => "prefer_type_annotation",
+                  },
+                ],
+                output => r#"const a: Foo<string> = new \n Foo \n ();"#,
+              },
+              {
+                code => "const a = new Foo/* comment */ <string> /* another */();",
+                options => "type-annotation",
+                errors => [
+                  {
+                    message_id => "prefer_type_annotation",
+                  },
+                ],
+                output => r#"const a: Foo<string> = new Foo/* comment */  /* another */();"#,
+              },
+              {
+                code => "const a = new Foo</* comment */ string, /* another */ number>();",
+                options => "type-annotation",
+                errors => [
+                  {
+                    message_id => "prefer_type_annotation",
+                  },
+                ],
+                output => r#"const a: Foo</* comment */ string, /* another */ number> = new Foo();"#,
+              },
+              {
+                code => r#"
               class Foo {
                 a = new Foo<string>();
               }
                     "#,
-                    options => "type-annotation",
-                    errors => [
-                      {
-                        message_id => "prefer_type_annotation",
-                      },
-                    ],
-                    output => r#"
+                options => "type-annotation",
+                errors => [
+                  {
+                    message_id => "prefer_type_annotation",
+                  },
+                ],
+                output => r#"
               class Foo {
                 a: Foo<string> = new Foo();
               }
                     "#,
-                  },
-                  {
-                    code => r#"
+              },
+              {
+                code => r#"
               class Foo {
                 [a] = new Foo<string>();
               }
                     "#,
-                    options => "type-annotation",
-                    errors => [
-                      {
-                        message_id => "prefer_type_annotation",
-                      },
-                    ],
-                    output => r#"
+                options => "type-annotation",
+                errors => [
+                  {
+                    message_id => "prefer_type_annotation",
+                  },
+                ],
+                output => r#"
               class Foo {
                 [a]: Foo<string> = new Foo();
               }
                     "#,
-                  },
-                  {
-                    code => r#"
+              },
+              {
+                code => r#"
               class Foo {
                 [a + b] = new Foo<string>();
               }
                     "#,
-                    options => "type-annotation",
-                    errors => [
-                      {
-                        message_id => "prefer_type_annotation",
-                      },
-                    ],
-                    output => r#"
+                options => "type-annotation",
+                errors => [
+                  {
+                    message_id => "prefer_type_annotation",
+                  },
+                ],
+                output => r#"
               class Foo {
                 [a + b]: Foo<string> = new Foo();
               }
                     "#,
-                  },
-                  {
-                    code => r#"
+              },
+              {
+                code => r#"
               function foo(a = new Foo<string>()) {}
                     "#,
-                    options => "type-annotation",
-                    errors => [
-                      {
-                        message_id => "prefer_type_annotation",
-                      },
-                    ],
-                    output => r#"
+                options => "type-annotation",
+                errors => [
+                  {
+                    message_id => "prefer_type_annotation",
+                  },
+                ],
+                output => r#"
               function foo(a: Foo<string> = new Foo()) {}
                     "#,
-                  },
-                  {
-                    code => r#"
+              },
+              {
+                code => r#"
               function foo({ a } = new Foo<string>()) {}
                     "#,
-                    options => "type-annotation",
-                    errors => [
-                      {
-                        message_id => "prefer_type_annotation",
-                      },
-                    ],
-                    output => r#"
+                options => "type-annotation",
+                errors => [
+                  {
+                    message_id => "prefer_type_annotation",
+                  },
+                ],
+                output => r#"
               function foo({ a }: Foo<string> = new Foo()) {}
                     "#,
-                  },
-                  {
-                    code => r#"
+              },
+              {
+                code => r#"
               function foo([a] = new Foo<string>()) {}
                     "#,
-                    options => "type-annotation",
-                    errors => [
-                      {
-                        message_id => "prefer_type_annotation",
-                      },
-                    ],
-                    output => r#"
+                options => "type-annotation",
+                errors => [
+                  {
+                    message_id => "prefer_type_annotation",
+                  },
+                ],
+                output => r#"
               function foo([a]: Foo<string> = new Foo()) {}
                     "#,
-                  },
-                  {
-                    code => r#"
+              },
+              {
+                code => r#"
               class A {
                 constructor(a = new Foo<string>()) {}
               }
                     "#,
-                    options => "type-annotation",
-                    errors => [
-                      {
-                        message_id => "prefer_type_annotation",
-                      },
-                    ],
-                    output => r#"
+                options => "type-annotation",
+                errors => [
+                  {
+                    message_id => "prefer_type_annotation",
+                  },
+                ],
+                output => r#"
               class A {
                 constructor(a: Foo<string> = new Foo()) {}
               }
                     "#,
-                  },
-                  {
-                    code => r#"
+              },
+              {
+                code => r#"
               const a = function (a = new Foo<string>()) {};
                     "#,
-                    options => "type-annotation",
-                    errors => [
-                      {
-                        message_id => "prefer_type_annotation",
-                      },
-                    ],
-                    output => r#"
-              const a = function (a: Foo<string> = new Foo()) {};
-                    "#,
+                options => "type-annotation",
+                errors => [
+                  {
+                    message_id => "prefer_type_annotation",
                   },
                 ],
-            },
-        )
+                output => r#"
+              const a = function (a: Foo<string> = new Foo()) {};
+                    "#,
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_consistent_generic_constructors_rule() {
+        run_for_typescript_and_tsx(
+            consistent_generic_constructors_rule(),
+            test_cases::consistent_generic_constructors_rule_tests(),
+        );
     }
 }
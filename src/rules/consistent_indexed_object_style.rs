@@ -0,0 +1,407 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::Identifier;
+
+use crate::{
+    ast_helpers::{is_index_signature, is_type_literal},
+    kind::{ExtendsTypeClause, TypeAliasDeclaration, TypeAnnotation, TypeIdentifier},
+};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Options {
+    #[default]
+    Record,
+    IndexSignature,
+}
+
+/// The type alias that the type literal is (part of) the aliased type of,
+/// unless it's nested inside an annotation (eg of a function type's
+/// parameter).
+fn find_parent_declaration(node: Node) -> Option<Node> {
+    node.ancestors()
+        .take_while(|ancestor| ancestor.kind() != TypeAnnotation)
+        .find(|ancestor| ancestor.kind() == TypeAliasDeclaration)
+}
+
+fn references_type_name<'a>(
+    node: Node<'a>,
+    name: &str,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    if node.kind() == TypeIdentifier && node.text(context) == name {
+        return true;
+    }
+    node.non_comment_named_children(SupportedLanguage::Javascript)
+        .any(|child| references_type_name(child, name, context))
+}
+
+fn get_single_index_signature(body: Node) -> Option<Node> {
+    let mut members = body.non_comment_named_children(SupportedLanguage::Javascript);
+    let (Some(member), None) = (members.next(), members.next()) else {
+        return None;
+    };
+    if !is_index_signature(member) || member.field("name").kind() != Identifier {
+        return None;
+    }
+    Some(member)
+}
+
+pub fn consistent_indexed_object_style_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "consistent-indexed-object-style",
+        languages => [Typescript],
+        messages => [
+            prefer_record => "A record is preferred over an index signature.",
+            prefer_index_signature => "An index signature is preferred over a record.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            mode: Options = options,
+        },
+        methods => {
+            fn check_members(
+                &self,
+                node: Node<'a>,
+                parent_id: Option<Node<'a>>,
+                prefix: &str,
+                postfix: &str,
+                safe_fix: bool,
+                context: &QueryMatchContext<'a, '_>,
+            ) {
+                let body = node.child_by_field_name("body").unwrap_or(node);
+                let Some(member) = get_single_index_signature(body) else {
+                    return;
+                };
+
+                // eg `type Foo = { [key: string]: Foo }` can't be written as a
+                // `Record` since that would be a circular reference
+                if parent_id.is_some_and(|parent_id| {
+                    references_type_name(node, &parent_id.text(context), context)
+                }) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "prefer_record",
+                    data => {
+                        banned_name => "index-signature",
+                        replacement => "record",
+                        option => "record",
+                    },
+                    fix => |fixer| {
+                        if !safe_fix {
+                            return;
+                        }
+
+                        let key = member.field("index_type").text(context);
+                        let value = member
+                            .field("type")
+                            .first_non_comment_named_child(SupportedLanguage::Javascript)
+                            .text(context);
+                        let record = if member.has_child_of_kind("readonly") {
+                            format!("Readonly<Record<{key}, {value}>>")
+                        } else {
+                            format!("Record<{key}, {value}>")
+                        };
+                        fixer.replace_text(node, format!("{prefix}{record}{postfix}"));
+                    }
+                });
+            }
+        },
+        listeners => [
+            r#"
+              (generic_type
+                name: (type_identifier) @name (#eq? @name "Record")
+              ) @record
+            "# => |captures, context| {
+                if self.mode != Options::IndexSignature {
+                    return;
+                }
+                let node = captures["record"];
+                let mut params = node
+                    .field("type_arguments")
+                    .non_comment_named_children(SupportedLanguage::Javascript);
+                let (Some(key), Some(type_), None) = (params.next(), params.next(), params.next()) else {
+                    return;
+                };
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "prefer_index_signature",
+                    data => {
+                        banned_name => "record",
+                        replacement => "index-signature",
+                        option => "index-signature",
+                    },
+                    fix => |fixer| {
+                        fixer.replace_text(
+                            node,
+                            format!("{{ [key: {}]: {} }}", key.text(context), type_.text(context)),
+                        );
+                    }
+                });
+            },
+            r#"
+              (object_type) @c
+            "# => |node, context| {
+                if self.mode != Options::Record || !is_type_literal(node) {
+                    return;
+                }
+
+                let parent_id = find_parent_declaration(node).map(|parent| parent.field("name"));
+                self.check_members(node, parent_id, "", "", true, context);
+            },
+            r#"
+              (interface_declaration) @c
+            "# => |node, context| {
+                if self.mode != Options::Record {
+                    return;
+                }
+
+                let name = node.field("name");
+                let generic_types = node
+                    .child_by_field_name("type_parameters")
+                    .map(|type_parameters| {
+                        format!(
+                            "<{}>",
+                            type_parameters
+                                .non_comment_named_children(SupportedLanguage::Javascript)
+                                .map(|type_parameter| type_parameter.text(context))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        )
+                    })
+                    .unwrap_or_default();
+                self.check_members(
+                    node,
+                    Some(name),
+                    &format!("type {}{generic_types} = ", name.text(context)),
+                    ";",
+                    !node.has_child_of_kind(ExtendsTypeClause),
+                    context,
+                );
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn consistent_indexed_object_style_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "type Foo = Record<string, any>;",
+                "interface Foo {}",
+                "
+interface Foo {
+  bar: string;
+}
+                    ",
+                "
+interface Foo {
+  bar: string;
+  [key: string]: any;
+}
+                    ",
+                "
+interface Foo {
+  [key: string]: any;
+  bar: string;
+}
+                    ",
+                "type Foo = { [key: string]: string | Foo };",
+                "type Foo = { [key: string]: Foo };",
+                "type Foo = { [key: string]: Foo } | Foo;",
+                "
+interface Foo {
+  [key: string]: Foo;
+}
+                    ",
+                "
+interface Foo<T> {
+  [key: string]: Foo<T>;
+}
+                    ",
+                "
+interface Foo<T> {
+  [key: string]: Foo<T> | string;
+}
+                    ",
+                "type Foo = { [K in string]: number };",
+                "type Foo = { readonly [K in keyof Bar]: string };",
+                "type Foo = { [key: string]: any; bar: string };",
+                "type Foo = { bar: string };",
+                "type Foo = {};",
+                {
+                  code => "type Foo = { [key: string]: any };",
+                  options => "index-signature",
+                },
+                {
+                  code => "type Foo = Misc<string, unknown>;",
+                  options => "index-signature",
+                },
+                {
+                  code => "type Foo = Record;",
+                  options => "index-signature",
+                },
+                {
+                  code => "
+interface Foo {
+  [key: string]: any;
+}
+                      ",
+                  options => "index-signature",
+                },
+            ],
+            invalid => [
+                {
+                  code => "
+interface Foo {
+  [key: string]: any;
+}
+                      ",
+                  output => "
+type Foo = Record<string, any>;
+                      ",
+                  errors => [
+                    {
+                      message_id => "prefer_record",
+                      data => {
+                        banned_name => "index-signature",
+                        replacement => "record",
+                        option => "record",
+                      },
+                      line => 2,
+                      column => 1,
+                    },
+                  ],
+                },
+                {
+                  code => "
+interface Foo {
+  readonly [key: string]: any;
+}
+                      ",
+                  output => "
+type Foo = Readonly<Record<string, any>>;
+                      ",
+                  errors => [{ message_id => "prefer_record" }],
+                },
+                {
+                  code => "
+interface Foo<A, B extends string = string> {
+  [key: string]: any;
+}
+                      ",
+                  output => "
+type Foo<A, B extends string = string> = Record<string, any>;
+                      ",
+                  errors => [{ message_id => "prefer_record" }],
+                },
+                {
+                  code => "
+export interface Bar {
+  [key: string]: any;
+}
+                      ",
+                  output => "
+export type Bar = Record<string, any>;
+                      ",
+                  errors => [{ message_id => "prefer_record", line => 2, column => 8 }],
+                },
+                {
+                  code => "
+interface Foo extends Bar {
+  [key: string]: any;
+}
+                      ",
+                  errors => [{ message_id => "prefer_record" }],
+                },
+                {
+                  code => "type Foo = { [key: string]: any };",
+                  output => "type Foo = Record<string, any>;",
+                  errors => [{ message_id => "prefer_record", line => 1, column => 12 }],
+                },
+                {
+                  code => "type Foo = { readonly [key: string]: any };",
+                  output => "type Foo = Readonly<Record<string, any>>;",
+                  errors => [{ message_id => "prefer_record" }],
+                },
+                {
+                  code => "type Foo = Generic<{ [key: string]: any }>;",
+                  output => "type Foo = Generic<Record<string, any>>;",
+                  errors => [{ message_id => "prefer_record", column => 20 }],
+                },
+                {
+                  code => "function foo(arg: { [key: string]: any }) {}",
+                  output => "function foo(arg: Record<string, any>) {}",
+                  errors => [{ message_id => "prefer_record" }],
+                },
+                {
+                  code => "type Foo = { [key: string]: { [key: string]: Foo } };",
+                  output => "type Foo = { [key: string]: Record<string, Foo> };",
+                  errors => [{ message_id => "prefer_record", column => 29 }],
+                },
+                {
+                  code => "type Foo = (arg: { [key: string]: Foo }) => void;",
+                  output => "type Foo = (arg: Record<string, Foo>) => void;",
+                  errors => [{ message_id => "prefer_record" }],
+                },
+                {
+                  code => "type Foo = Record<string, any>;",
+                  options => "index-signature",
+                  output => "type Foo = { [key: string]: any };",
+                  errors => [
+                    {
+                      message_id => "prefer_index_signature",
+                      data => {
+                        banned_name => "record",
+                        replacement => "index-signature",
+                        option => "index-signature",
+                      },
+                      line => 1,
+                      column => 12,
+                    },
+                  ],
+                },
+                {
+                  code => "type Foo<T> = Record<string, T>;",
+                  options => "index-signature",
+                  output => "type Foo<T> = { [key: string]: T };",
+                  errors => [{ message_id => "prefer_index_signature" }],
+                },
+                {
+                  code => "function foo(arg: Record<string, any>) {}",
+                  options => "index-signature",
+                  output => "function foo(arg: { [key: string]: any }) {}",
+                  errors => [{ message_id => "prefer_index_signature" }],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_consistent_indexed_object_style_rule() {
+        run_for_typescript_and_tsx(
+            consistent_indexed_object_style_rule(),
+            test_cases::consistent_indexed_object_style_rule_tests(),
+        );
+    }
+}
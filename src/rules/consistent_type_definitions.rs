@@ -56,6 +56,11 @@ pub fn consistent_type_definitions_rule() -> Arc<dyn Rule> {
                 context.report(violation! {
                     node => node.field("name"),
                     message_id => "interface_over_type",
+                    data => {
+                        banned_name => "type",
+                        replacement => "interface",
+                        option => "interface",
+                    },
                     fix => |fixer| {
                         let type_node = node.child_by_field_name("type_parameters").unwrap_or_else(|| node.field("name"));
 
@@ -96,6 +101,11 @@ pub fn consistent_type_definitions_rule() -> Arc<dyn Rule> {
                 context.report(violation! {
                     node => node.field("name"),
                     message_id => "type_over_interface",
+                    data => {
+                        banned_name => "interface",
+                        replacement => "type",
+                        option => "type",
+                    },
                     fix => |fixer| {
                         if is_currently_traversed_node_within_module_declaration(node) {
                             return;
@@ -143,364 +153,514 @@ pub fn consistent_type_definitions_rule() -> Arc<dyn Rule> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use tree_sitter_lint::{rule_tests, RuleTester};
-
-    use super::*;
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
 
-    #[test]
-    fn test_consistent_type_definitions_rule() {
-        RuleTester::run(
-            consistent_type_definitions_rule(),
-            rule_tests! {
-                valid => [
-                  {
-                    code => "var foo = {};",
-                    options => "interface",
-                  },
-                  {
-                    code => "interface A {}",
-                    options => "interface",
-                  },
-                  {
-                    code => r#"
+    pub fn consistent_type_definitions_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              {
+                code => "var foo = {};",
+                options => "interface",
+              },
+              {
+                code => "interface A {}",
+                options => "interface",
+              },
+              {
+                code => r#"
               interface A extends B {
                 x: number;
               }
                     "#,
-                    options => "interface",
-                  },
-                  {
-                    code => "type U = string;",
-                    options => "interface",
-                  },
-                  {
-                    code => "type V = { x: number } | { y: string };",
-                    options => "interface",
-                  },
-                  {
-                    code => r#"
+                options => "interface",
+              },
+              {
+                code => "type U = string;",
+                options => "interface",
+              },
+              {
+                code => "type V = { x: number } | { y: string };",
+                options => "interface",
+              },
+              {
+                code => r#"
               type Record<T, U> = {
                 [K in T]: U;
               };
                     "#,
-                    options => "interface",
-                  },
-                  {
-                    code => "type T = { x: number };",
-                    options => "type",
-                  },
-                  {
-                    code => "type A = { x: number } & B & C;",
-                    options => "type",
-                  },
-                  {
-                    code => "type A = { x: number } & B<T1> & C<T2>;",
-                    options => "type",
-                  },
-                  {
-                    code => r#"
+                options => "interface",
+              },
+              {
+                code => "type T = { x: number };",
+                options => "type",
+              },
+              {
+                code => "type A = { x: number } & B & C;",
+                options => "type",
+              },
+              {
+                code => "type A = { x: number } & B<T1> & C<T2>;",
+                options => "type",
+              },
+              {
+                code => r#"
               export type W<T> = {
                 x: T;
               };
                     "#,
-                    options => "type",
-                  },
-                ],
-                invalid => [
+                options => "type",
+              },
+            ],
+            invalid => [
+              {
+                code => r#"type T = { x: number; };"#,
+                output => r#"interface T { x: number; }"#,
+                options => "interface",
+                errors => [
                   {
-                    code => r#"type T = { x: number; };"#,
-                    output => r#"interface T { x: number; }"#,
-                    options => "interface",
-                    errors => [
-                      {
-                        message_id => "interface_over_type",
-                        line => 1,
-                        column => 6,
-                      },
-                    ],
+                    message_id => "interface_over_type",
+                    data => {
+                      banned_name => "type",
+                      replacement => "interface",
+                      option => "interface",
+                    },
+                    line => 1,
+                    column => 6,
+                    end_line => 1,
+                    end_column => 7,
                   },
+                ],
+              },
+              {
+                code => r#"type T={ x: number; };"#,
+                output => r#"interface T { x: number; }"#,
+                options => "interface",
+                errors => [
                   {
-                    code => r#"type T={ x: number; };"#,
-                    output => r#"interface T { x: number; }"#,
-                    options => "interface",
-                    errors => [
-                      {
-                        message_id => "interface_over_type",
-                        line => 1,
-                        column => 6,
-                      },
-                    ],
+                    message_id => "interface_over_type",
+                    line => 1,
+                    column => 6,
+                    end_line => 1,
+                    end_column => 7,
                   },
+                ],
+              },
+              {
+                code => r#"type T=                         { x: number; };"#,
+                output => r#"interface T { x: number; }"#,
+                options => "interface",
+                errors => [
                   {
-                    code => r#"type T=                         { x: number; };"#,
-                    output => r#"interface T { x: number; }"#,
-                    options => "interface",
-                    errors => [
-                      {
-                        message_id => "interface_over_type",
-                        line => 1,
-                        column => 6,
-                      },
-                    ],
+                    message_id => "interface_over_type",
+                    line => 1,
+                    column => 6,
+                    end_line => 1,
+                    end_column => 7,
                   },
-                  {
-                    code => r#"
+                ],
+              },
+              {
+                code => r#"
 export type W<T> = {
   x: T;
 };
                     "#,
-                    output => r#"
+                output => r#"
 export interface W<T> {
   x: T;
 }
                     "#,
-                    options => "interface",
-                    errors => [
-                      {
-                        message_id => "interface_over_type",
-                        line => 2,
-                        column => 13,
-                      },
-                    ],
-                  },
+                options => "interface",
+                errors => [
                   {
-                    code => r#"interface T { x: number; }"#,
-                    output => r#"type T = { x: number; }"#,
-                    options => "type",
-                    errors => [
-                      {
-                        message_id => "type_over_interface",
-                        line => 1,
-                        column => 11,
-                      },
-                    ],
+                    message_id => "interface_over_type",
+                    line => 2,
+                    column => 13,
+                    end_line => 2,
+                    end_column => 14,
                   },
+                ],
+              },
+              {
+                code => r#"interface T { x: number; }"#,
+                output => r#"type T = { x: number; }"#,
+                options => "type",
+                errors => [
                   {
-                    code => r#"interface T{ x: number; }"#,
-                    output => r#"type T = { x: number; }"#,
-                    options => "type",
-                    errors => [
-                      {
-                        message_id => "type_over_interface",
-                        line => 1,
-                        column => 11,
-                      },
-                    ],
+                    message_id => "type_over_interface",
+                    data => {
+                      banned_name => "interface",
+                      replacement => "type",
+                      option => "type",
+                    },
+                    line => 1,
+                    column => 11,
+                    end_line => 1,
+                    end_column => 12,
                   },
+                ],
+              },
+              {
+                code => r#"interface T{ x: number; }"#,
+                output => r#"type T = { x: number; }"#,
+                options => "type",
+                errors => [
                   {
-                    code => r#"interface T                          { x: number; }"#,
-                    output => r#"type T = { x: number; }"#,
-                    options => "type",
-                    errors => [
-                      {
-                        message_id => "type_over_interface",
-                        line => 1,
-                        column => 11,
-                      },
-                    ],
+                    message_id => "type_over_interface",
+                    line => 1,
+                    column => 11,
+                    end_line => 1,
+                    end_column => 12,
                   },
+                ],
+              },
+              {
+                code => r#"interface T                          { x: number; }"#,
+                output => r#"type T = { x: number; }"#,
+                options => "type",
+                errors => [
                   {
-                    code => r#"interface A extends B, C { x: number; };"#,
-                    output => r#"type A = { x: number; } & B & C;"#,
-                    options => "type",
-                    errors => [
-                      {
-                        message_id => "type_over_interface",
-                        line => 1,
-                        column => 11,
-                      },
-                    ],
+                    message_id => "type_over_interface",
+                    line => 1,
+                    column => 11,
+                    end_line => 1,
+                    end_column => 12,
                   },
+                ],
+              },
+              {
+                code => r#"interface A extends B, C { x: number; };"#,
+                output => r#"type A = { x: number; } & B & C;"#,
+                options => "type",
+                errors => [
                   {
-                    code => r#"interface A extends B<T1>, C<T2> { x: number; };"#,
-                    output => r#"type A = { x: number; } & B<T1> & C<T2>;"#,
-                    options => "type",
-                    errors => [
-                      {
-                        message_id => "type_over_interface",
-                        line => 1,
-                        column => 11,
-                      },
-                    ],
+                    message_id => "type_over_interface",
+                    line => 1,
+                    column => 11,
+                    end_line => 1,
+                    end_column => 12,
                   },
+                ],
+              },
+              {
+                code => r#"interface A extends B<T1>, C<T2> { x: number; };"#,
+                output => r#"type A = { x: number; } & B<T1> & C<T2>;"#,
+                options => "type",
+                errors => [
                   {
-                    code => r#"
+                    message_id => "type_over_interface",
+                    line => 1,
+                    column => 11,
+                    end_line => 1,
+                    end_column => 12,
+                  },
+                ],
+              },
+              {
+                code => r#"
 export interface W<T> {
   x: T;
 }
                     "#,
-                    output => r#"
+                output => r#"
 export type W<T> = {
   x: T;
 }
                     "#,
-                    options => "type",
-                    errors => [
-                      {
-                        message_id => "type_over_interface",
-                        line => 2,
-                        column => 18,
-                      },
-                    ],
-                  },
+                options => "type",
+                errors => [
                   {
-                    code => r#"
+                    message_id => "type_over_interface",
+                    line => 2,
+                    column => 18,
+                    end_line => 2,
+                    end_column => 19,
+                  },
+                ],
+              },
+              {
+                code => r#"
 namespace JSX {
   interface Array<T> {
     foo(x: (x: number) => T): T[];
   }
 }
                     "#,
-                    output => r#"
+                output => r#"
 namespace JSX {
   type Array<T> = {
     foo(x: (x: number) => T): T[];
   }
 }
                     "#,
-                    options => "type",
-                    errors => [
-                      {
-                        message_id => "type_over_interface",
-                        line => 3,
-                        column => 13,
-                      },
-                    ],
-                  },
+                options => "type",
+                errors => [
                   {
-                    code => r#"
+                    message_id => "type_over_interface",
+                    line => 3,
+                    column => 13,
+                    end_line => 3,
+                    end_column => 18,
+                  },
+                ],
+              },
+              {
+                code => r#"
 global {
   interface Array<T> {
     foo(x: (x: number) => T): T[];
   }
 }
                     "#,
-                    output => r#"
+                output => r#"
 global {
   type Array<T> = {
     foo(x: (x: number) => T): T[];
   }
 }
                     "#,
-                    options => "type",
-                    errors => [
-                      {
-                        message_id => "type_over_interface",
-                        line => 3,
-                        column => 13,
-                      },
-                    ],
-                  },
+                options => "type",
+                errors => [
                   {
-                    code => r#"
+                    message_id => "type_over_interface",
+                    line => 3,
+                    column => 13,
+                    end_line => 3,
+                    end_column => 18,
+                  },
+                ],
+              },
+              {
+                code => r#"
 declare global {
   interface Array<T> {
     foo(x: (x: number) => T): T[];
   }
 }
                     "#,
-                    output => None,
-                    options => "type",
-                    errors => [
-                      {
-                        message_id => "type_over_interface",
-                        line => 3,
-                        column => 13,
-                      },
-                    ],
-                  },
+                output => None,
+                options => "type",
+                errors => [
                   {
-                    code => r#"
+                    message_id => "type_over_interface",
+                    line => 3,
+                    column => 13,
+                    end_line => 3,
+                    end_column => 18,
+                  },
+                ],
+              },
+              {
+                code => r#"
 declare global {
   namespace Foo {
     interface Bar {}
   }
 }
                     "#,
-                    output => None,
-                    options => "type",
-                    errors => [
-                      {
-                        message_id => "type_over_interface",
-                        line => 4,
-                        column => 15,
-                      },
-                    ],
-                  },
+                output => None,
+                options => "type",
+                errors => [
                   {
-                    // https://github.com/typescript-eslint/typescript-eslint/issues/3894
-                    code => r#"
+                    message_id => "type_over_interface",
+                    line => 4,
+                    column => 15,
+                    end_line => 4,
+                    end_column => 18,
+                  },
+                ],
+              },
+              {
+                // https://github.com/typescript-eslint/typescript-eslint/issues/3894
+                code => r#"
 export default interface Test {
   bar(): string;
   foo(): number;
 }
                     "#,
-                    output => r#"
+                output => r#"
 type Test = {
   bar(): string;
   foo(): number;
 }
 export default Test
                     "#,
-                    options => "type",
-                    errors => [
-                      {
-                        message_id => "type_over_interface",
-                        line => 2,
-                        column => 26,
-                      },
-                    ],
+                options => "type",
+                errors => [
+                  {
+                    message_id => "type_over_interface",
+                    line => 2,
+                    column => 26,
+                    end_line => 2,
+                    end_column => 30,
+                  },
+                ],
+              },
+              {
+                code => r#"
+export default interface Test<T> extends Base<T> {
+  foo: T;
+}
+                    "#,
+                output => r#"
+type Test<T> = {
+  foo: T;
+} & Base<T>
+export default Test
+                    "#,
+                options => "type",
+                errors => [
+                  {
+                    message_id => "type_over_interface",
+                    line => 2,
+                    column => 26,
+                    end_line => 2,
+                    end_column => 30,
+                  },
+                ],
+              },
+              {
+                code => r#"
+export {};
+
+declare global {
+  export interface Window {
+    foo: string;
+  }
+}
+                    "#,
+                output => None,
+                options => "type",
+                errors => [
+                  {
+                    message_id => "type_over_interface",
+                    line => 5,
+                    column => 20,
+                    end_line => 5,
+                    end_column => 26,
                   },
+                ],
+              },
+              {
+                // only global augmentations can't be converted
+                code => r#"
+declare module 'foo' {
+  interface Bar {
+    x: number;
+  }
+}
+                    "#,
+                output => r#"
+declare module 'foo' {
+  type Bar = {
+    x: number;
+  }
+}
+                    "#,
+                options => "type",
+                errors => [
                   {
-                    // https://github.com/typescript-eslint/typescript-eslint/issues/4333
-                    code => r#"
+                    message_id => "type_over_interface",
+                    line => 3,
+                    column => 13,
+                    end_line => 3,
+                    end_column => 16,
+                  },
+                ],
+              },
+              {
+                code => r#"
+declare global {
+  type Foo = {
+    x: number;
+  };
+}
+                    "#,
+                output => r#"
+declare global {
+  interface Foo {
+    x: number;
+  }
+}
+                    "#,
+                options => "interface",
+                errors => [
+                  {
+                    message_id => "interface_over_type",
+                    line => 3,
+                    column => 8,
+                    end_line => 3,
+                    end_column => 11,
+                  },
+                ],
+              },
+              {
+                // https://github.com/typescript-eslint/typescript-eslint/issues/4333
+                code => r#"
 export declare type Test = {
   foo: string;
   bar: string;
 };
                     "#,
-                    output => r#"
+                output => r#"
 export declare interface Test {
   foo: string;
   bar: string;
 }
                     "#,
-                    options => "interface",
-                    errors => [
-                      {
-                        message_id => "interface_over_type",
-                        line => 2,
-                        column => 21,
-                      },
-                    ],
-                  },
+                options => "interface",
+                errors => [
                   {
-                    // https://github.com/typescript-eslint/typescript-eslint/issues/4333
-                    code => r#"
+                    message_id => "interface_over_type",
+                    line => 2,
+                    column => 21,
+                    end_line => 2,
+                    end_column => 25,
+                  },
+                ],
+              },
+              {
+                // https://github.com/typescript-eslint/typescript-eslint/issues/4333
+                code => r#"
 export declare interface Test {
   foo: string;
   bar: string;
 }
                     "#,
-                    output => r#"
+                output => r#"
 export declare type Test = {
   foo: string;
   bar: string;
 }
                     "#,
-                    options => "type",
-                    errors => [
-                      {
-                        message_id => "type_over_interface",
-                        line => 2,
-                        column => 26,
-                      },
-                    ],
+                options => "type",
+                errors => [
+                  {
+                    message_id => "type_over_interface",
+                    line => 2,
+                    column => 26,
+                    end_line => 2,
+                    end_column => 30,
                   },
                 ],
-            },
-        )
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_consistent_type_definitions_rule() {
+        run_for_typescript_and_tsx(
+            consistent_type_definitions_rule(),
+            test_cases::consistent_type_definitions_rule_tests(),
+        );
     }
 }
@@ -22,6 +22,9 @@ pub fn default_param_last_rule() -> Arc<dyn Rule> {
             should_be_last => "Default parameters should be last.",
         ],
         listeners => [
+            // only implementations: overload signatures (`function_signature`,
+            // `method_signature`) and other bodiless signatures aren't
+            // checked, like upstream
             r#"
               (function_declaration) @c
               (function) @c
@@ -30,9 +33,13 @@ pub fn default_param_last_rule() -> Arc<dyn Rule> {
               (method_definition) @c
               (arrow_function) @c
             "# => |node, context| {
+                // (`a => {}`)
+                let Some(parameters) = node.child_by_field_name("parameters") else {
+                    return;
+                };
                 let mut has_seen_plain_param = false;
 
-                for param in node.field("parameters").non_comment_named_children(SupportedLanguage::Javascript).collect_vec().into_iter().rev() {
+                for param in parameters.non_comment_named_children(SupportedLanguage::Javascript).collect_vec().into_iter().rev() {
                     if is_plain_param(param) {
                         has_seen_plain_param = true;
                         continue;
@@ -53,69 +60,64 @@ pub fn default_param_last_rule() -> Arc<dyn Rule> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use tree_sitter_lint::{rule_tests, RuleTester};
-
-    use super::*;
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
 
-    #[test]
-    fn test_default_param_last_rule() {
-        RuleTester::run(
-            default_param_last_rule(),
-            rule_tests! {
-                valid => [
-                  "function foo() {}",
-                  "function foo(a: number) {}",
-                  "function foo(a = 1) {}",
-                  "function foo(a?: number) {}",
-                  "function foo(a: number, b: number) {}",
-                  "function foo(a: number, b: number, c?: number) {}",
-                  "function foo(a: number, b = 1) {}",
-                  "function foo(a: number, b = 1, c = 1) {}",
-                  "function foo(a: number, b = 1, c?: number) {}",
-                  "function foo(a: number, b?: number, c = 1) {}",
-                  "function foo(a: number, b = 1, ...c) {}",
+    pub fn default_param_last_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "function foo() {}",
+              "function foo(a: number) {}",
+              "function foo(a = 1) {}",
+              "function foo(a?: number) {}",
+              "function foo(a: number, b: number) {}",
+              "function foo(a: number, b: number, c?: number) {}",
+              "function foo(a: number, b = 1) {}",
+              "function foo(a: number, b = 1, c = 1) {}",
+              "function foo(a: number, b = 1, c?: number) {}",
+              "function foo(a: number, b?: number, c = 1) {}",
+              "function foo(a: number, b = 1, ...c) {}",
 
-                  "const foo = function () {};",
-                  "const foo = function (a: number) {};",
-                  "const foo = function (a = 1) {};",
-                  "const foo = function (a?: number) {};",
-                  "const foo = function (a: number, b: number) {};",
-                  "const foo = function (a: number, b: number, c?: number) {};",
-                  "const foo = function (a: number, b = 1) {};",
-                  "const foo = function (a: number, b = 1, c = 1) {};",
-                  "const foo = function (a: number, b = 1, c?: number) {};",
-                  "const foo = function (a: number, b?: number, c = 1) {};",
-                  "const foo = function (a: number, b = 1, ...c) {};",
+              "const foo = function () {};",
+              "const foo = function (a: number) {};",
+              "const foo = function (a = 1) {};",
+              "const foo = function (a?: number) {};",
+              "const foo = function (a: number, b: number) {};",
+              "const foo = function (a: number, b: number, c?: number) {};",
+              "const foo = function (a: number, b = 1) {};",
+              "const foo = function (a: number, b = 1, c = 1) {};",
+              "const foo = function (a: number, b = 1, c?: number) {};",
+              "const foo = function (a: number, b?: number, c = 1) {};",
+              "const foo = function (a: number, b = 1, ...c) {};",
 
-                  "const foo = () => {};",
-                  "const foo = (a: number) => {};",
-                  "const foo = (a = 1) => {};",
-                  "const foo = (a?: number) => {};",
-                  "const foo = (a: number, b: number) => {};",
-                  "const foo = (a: number, b: number, c?: number) => {};",
-                  "const foo = (a: number, b = 1) => {};",
-                  "const foo = (a: number, b = 1, c = 1) => {};",
-                  "const foo = (a: number, b = 1, c?: number) => {};",
-                  "const foo = (a: number, b?: number, c = 1) => {};",
-                  "const foo = (a: number, b = 1, ...c) => {};",
-                  r#"
+              "const foo = () => {};",
+              "const foo = (a: number) => {};",
+              "const foo = (a = 1) => {};",
+              "const foo = (a?: number) => {};",
+              "const foo = (a: number, b: number) => {};",
+              "const foo = (a: number, b: number, c?: number) => {};",
+              "const foo = (a: number, b = 1) => {};",
+              "const foo = (a: number, b = 1, c = 1) => {};",
+              "const foo = (a: number, b = 1, c?: number) => {};",
+              "const foo = (a: number, b?: number, c = 1) => {};",
+              "const foo = (a: number, b = 1, ...c) => {};",
+              r#"
               class Foo {
                 constructor(a: number, b: number, c: number) {}
               }
                   "#,
-                  r#"
+              r#"
               class Foo {
                 constructor(a: number, b?: number, c = 1) {}
               }
                   "#,
-                  r#"
+              r#"
               class Foo {
                 constructor(a: number, b = 1, c?: number) {}
               }
                   "#,
-                  r#"
+              r#"
               class Foo {
                 constructor(
                   public a: number,
@@ -124,7 +126,7 @@ mod tests {
                 ) {}
               }
                   "#,
-                  r#"
+              r#"
               class Foo {
                 constructor(
                   public a: number,
@@ -133,7 +135,7 @@ mod tests {
                 ) {}
               }
                   "#,
-                  r#"
+              r#"
               class Foo {
                 constructor(
                   public a: number,
@@ -142,7 +144,7 @@ mod tests {
                 ) {}
               }
                   "#,
-                  r#"
+              r#"
               class Foo {
                 constructor(
                   a: number,
@@ -151,7 +153,7 @@ mod tests {
                 ) {}
               }
                   "#,
-                  r#"
+              r#"
               class Foo {
                 constructor(
                   a: number,
@@ -160,7 +162,7 @@ mod tests {
                 ) {}
               }
                   "#,
-                  r#"
+              r#"
               class Foo {
                 constructor(
                   a: number,
@@ -169,493 +171,523 @@ mod tests {
                 ) {}
               }
                   "#,
+              "const foo = a => {};",
+              r#"
+function foo(a?: number, b: number): void;
+function foo(a: number | undefined, b: number) {}
+                  "#,
+              r#"
+export function foo(a?: string, b: string): void;
+export function foo(a: number, b?: string): void;
+export function foo(a: any, b?: any) {}
+                  "#,
+              "declare function foo(a?: number, b: number): void;",
+              r#"
+class Foo {
+  bar(a?: number, b: number): void;
+  bar(a: number, b = 1) {}
+}
+                  "#,
+              r#"
+abstract class Foo {
+  abstract bar(a = 1, b: number): void;
+}
+                  "#,
+              r#"
+interface Foo {
+  bar(a?: number, b: number): void;
+  (a?: number, b: number): void;
+  new (a?: number, b: number): Foo;
+}
+                  "#,
+              "type Foo = (a?: number, b: number) => void;",
+            ],
+            invalid => [
+              {
+                code => "function foo(a = 1, b: number) {}",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 19,
+                  },
+                ],
+              },
+              {
+                code => "function foo(a = 1, b = 2, c: number) {}",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 19,
+                  },
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 21,
+                    end_column => 26,
+                  },
+                ],
+              },
+              {
+                code => "function foo(a = 1, b: number, c = 2, d: number) {}",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 19,
+                  },
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 32,
+                    end_column => 37,
+                  },
+                ],
+              },
+              {
+                code => "function foo(a = 1, b: number, c = 2) {}",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 19,
+                  },
+                ],
+              },
+              {
+                code => "function foo(a = 1, b: number, ...c) {}",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 19,
+                  },
+                ],
+              },
+              {
+                code => "function foo(a?: number, b: number) {}",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 24,
+                  },
+                ],
+              },
+              {
+                code => "function foo(a: number, b?: number, c: number) {}",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 25,
+                    end_column => 35,
+                  },
+                ],
+              },
+              {
+                code => "function foo(a = 1, b?: number, c: number) {}",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 19,
+                  },
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 21,
+                    end_column => 31,
+                  },
+                ],
+              },
+              {
+                code => "function foo(a = 1, { b }) {}",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 19,
+                  },
+                ],
+              },
+              {
+                code => "function foo({ a } = {}, b) {}",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 24,
+                  },
+                ],
+              },
+              {
+                code => "function foo({ a, b } = { a: 1, b: 2 }, c) {}",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 39,
+                  },
+                ],
+              },
+              {
+                code => "function foo([a] = [], b) {}",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 22,
+                  },
+                ],
+              },
+              {
+                code => "function foo([a, b] = [1, 2], c) {}",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 29,
+                  },
+                ],
+              },
+              {
+                code => "const foo = function (a = 1, b: number) {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 23,
+                    end_column => 28,
+                  },
+                ],
+              },
+              {
+                code => "const foo = function (a = 1, b = 2, c: number) {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 23,
+                    end_column => 28,
+                  },
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 30,
+                    end_column => 35,
+                  },
+                ],
+              },
+              {
+                code => "const foo = function (a = 1, b: number, c = 2, d: number) {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 23,
+                    end_column => 28,
+                  },
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 41,
+                    end_column => 46,
+                  },
+                ],
+              },
+              {
+                code => "const foo = function (a = 1, b: number, c = 2) {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 23,
+                    end_column => 28,
+                  },
+                ],
+              },
+              {
+                code => "const foo = function (a = 1, b: number, ...c) {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 23,
+                    end_column => 28,
+                  },
+                ],
+              },
+              {
+                code => "const foo = function (a?: number, b: number) {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 23,
+                    end_column => 33,
+                  },
+                ],
+              },
+              {
+                code => "const foo = function (a: number, b?: number, c: number) {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 34,
+                    end_column => 44,
+                  },
+                ],
+              },
+              {
+                code => "const foo = function (a = 1, b?: number, c: number) {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 23,
+                    end_column => 28,
+                  },
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 30,
+                    end_column => 40,
+                  },
+                ],
+              },
+              {
+                code => "const foo = function (a = 1, { b }) {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 23,
+                    end_column => 28,
+                  },
+                ],
+              },
+              {
+                code => "const foo = function ({ a } = {}, b) {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 23,
+                    end_column => 33,
+                  },
+                ],
+              },
+              {
+                code => "const foo = function ({ a, b } = { a: 1, b: 2 }, c) {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 23,
+                    end_column => 48,
+                  },
+                ],
+              },
+              {
+                code => "const foo = function ([a] = [], b) {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 23,
+                    end_column => 31,
+                  },
+                ],
+              },
+              {
+                code => "const foo = function ([a, b] = [1, 2], c) {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 23,
+                    end_column => 38,
+                  },
+                ],
+              },
+              {
+                code => "const foo = (a = 1, b: number) => {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 19,
+                  },
+                ],
+              },
+              {
+                code => "const foo = (a = 1, b = 2, c: number) => {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 19,
+                  },
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 21,
+                    end_column => 26,
+                  },
+                ],
+              },
+              {
+                code => "const foo = (a = 1, b: number, c = 2, d: number) => {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 19,
+                  },
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 32,
+                    end_column => 37,
+                  },
+                ],
+              },
+              {
+                code => "const foo = (a = 1, b: number, c = 2) => {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 19,
+                  },
+                ],
+              },
+              {
+                code => "const foo = (a = 1, b: number, ...c) => {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 19,
+                  },
+                ],
+              },
+              {
+                code => "const foo = (a?: number, b: number) => {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 24,
+                  },
+                ],
+              },
+              {
+                code => "const foo = (a: number, b?: number, c: number) => {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 25,
+                    end_column => 35,
+                  },
+                ],
+              },
+              {
+                code => "const foo = (a = 1, b?: number, c: number) => {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 19,
+                  },
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 21,
+                    end_column => 31,
+                  },
+                ],
+              },
+              {
+                code => "const foo = (a = 1, { b }) => {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 19,
+                  },
+                ],
+              },
+              {
+                code => "const foo = ({ a } = {}, b) => {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 24,
+                  },
                 ],
-                invalid => [
-                  {
-                    code => "function foo(a = 1, b: number) {}",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 19,
-                      },
-                    ],
-                  },
-                  {
-                    code => "function foo(a = 1, b = 2, c: number) {}",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 19,
-                      },
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 21,
-                        end_column => 26,
-                      },
-                    ],
-                  },
-                  {
-                    code => "function foo(a = 1, b: number, c = 2, d: number) {}",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 19,
-                      },
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 32,
-                        end_column => 37,
-                      },
-                    ],
-                  },
-                  {
-                    code => "function foo(a = 1, b: number, c = 2) {}",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 19,
-                      },
-                    ],
-                  },
-                  {
-                    code => "function foo(a = 1, b: number, ...c) {}",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 19,
-                      },
-                    ],
-                  },
-                  {
-                    code => "function foo(a?: number, b: number) {}",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 24,
-                      },
-                    ],
-                  },
-                  {
-                    code => "function foo(a: number, b?: number, c: number) {}",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 25,
-                        end_column => 35,
-                      },
-                    ],
-                  },
-                  {
-                    code => "function foo(a = 1, b?: number, c: number) {}",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 19,
-                      },
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 21,
-                        end_column => 31,
-                      },
-                    ],
-                  },
-                  {
-                    code => "function foo(a = 1, { b }) {}",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 19,
-                      },
-                    ],
-                  },
-                  {
-                    code => "function foo({ a } = {}, b) {}",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 24,
-                      },
-                    ],
-                  },
-                  {
-                    code => "function foo({ a, b } = { a: 1, b: 2 }, c) {}",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 39,
-                      },
-                    ],
-                  },
-                  {
-                    code => "function foo([a] = [], b) {}",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 22,
-                      },
-                    ],
-                  },
-                  {
-                    code => "function foo([a, b] = [1, 2], c) {}",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 29,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = function (a = 1, b: number) {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 23,
-                        end_column => 28,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = function (a = 1, b = 2, c: number) {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 23,
-                        end_column => 28,
-                      },
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 30,
-                        end_column => 35,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = function (a = 1, b: number, c = 2, d: number) {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 23,
-                        end_column => 28,
-                      },
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 41,
-                        end_column => 46,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = function (a = 1, b: number, c = 2) {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 23,
-                        end_column => 28,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = function (a = 1, b: number, ...c) {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 23,
-                        end_column => 28,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = function (a?: number, b: number) {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 23,
-                        end_column => 33,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = function (a: number, b?: number, c: number) {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 34,
-                        end_column => 44,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = function (a = 1, b?: number, c: number) {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 23,
-                        end_column => 28,
-                      },
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 30,
-                        end_column => 40,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = function (a = 1, { b }) {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 23,
-                        end_column => 28,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = function ({ a } = {}, b) {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 23,
-                        end_column => 33,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = function ({ a, b } = { a: 1, b: 2 }, c) {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 23,
-                        end_column => 48,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = function ([a] = [], b) {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 23,
-                        end_column => 31,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = function ([a, b] = [1, 2], c) {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 23,
-                        end_column => 38,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = (a = 1, b: number) => {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 19,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = (a = 1, b = 2, c: number) => {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 19,
-                      },
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 21,
-                        end_column => 26,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = (a = 1, b: number, c = 2, d: number) => {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 19,
-                      },
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 32,
-                        end_column => 37,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = (a = 1, b: number, c = 2) => {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 19,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = (a = 1, b: number, ...c) => {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 19,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = (a?: number, b: number) => {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 24,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = (a: number, b?: number, c: number) => {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 25,
-                        end_column => 35,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = (a = 1, b?: number, c: number) => {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 19,
-                      },
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 21,
-                        end_column => 31,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = (a = 1, { b }) => {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 19,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = ({ a } = {}, b) => {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 24,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = ({ a, b } = { a: 1, b: 2 }, c) => {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 39,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = ([a] = [], b) => {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 22,
-                      },
-                    ],
-                  },
-                  {
-                    code => "const foo = ([a, b] = [1, 2], c) => {};",
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 1,
-                        column => 14,
-                        end_column => 29,
-                      },
-                    ],
-                  },
-                  {
-                    code => r#"
+              },
+              {
+                code => "const foo = ({ a, b } = { a: 1, b: 2 }, c) => {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 39,
+                  },
+                ],
+              },
+              {
+                code => "const foo = ([a] = [], b) => {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 22,
+                  },
+                ],
+              },
+              {
+                code => "const foo = ([a, b] = [1, 2], c) => {};",
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 1,
+                    column => 14,
+                    end_column => 29,
+                  },
+                ],
+              },
+              {
+                code => r#"
 class Foo {
   constructor(
     public a: number,
@@ -664,17 +696,17 @@ class Foo {
   ) {}
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 5,
-                        column => 5,
-                        end_column => 25,
-                      },
-                    ],
-                  },
+                errors => [
                   {
-                    code => r#"
+                    message_id => "should_be_last",
+                    line => 5,
+                    column => 5,
+                    end_column => 25,
+                  },
+                ],
+              },
+              {
+                code => r#"
 class Foo {
   constructor(
     public a: number,
@@ -683,17 +715,17 @@ class Foo {
   ) {}
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 5,
-                        column => 5,
-                        end_column => 20,
-                      },
-                    ],
-                  },
+                errors => [
                   {
-                    code => r#"
+                    message_id => "should_be_last",
+                    line => 5,
+                    column => 5,
+                    end_column => 20,
+                  },
+                ],
+              },
+              {
+                code => r#"
 class Foo {
   constructor(
     public a?: number,
@@ -701,17 +733,17 @@ class Foo {
   ) {}
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 4,
-                        column => 5,
-                        end_column => 22,
-                      },
-                    ],
-                  },
+                errors => [
                   {
-                    code => r#"
+                    message_id => "should_be_last",
+                    line => 4,
+                    column => 5,
+                    end_column => 22,
+                  },
+                ],
+              },
+              {
+                code => r#"
 class Foo {
   constructor(
     public a = 0,
@@ -719,47 +751,90 @@ class Foo {
   ) {}
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 4,
-                        column => 5,
-                        end_column => 17,
-                      },
-                    ],
-                  },
+                errors => [
                   {
-                    code => r#"
+                    message_id => "should_be_last",
+                    line => 4,
+                    column => 5,
+                    end_column => 17,
+                  },
+                ],
+              },
+              {
+                code => r#"
 class Foo {
   constructor(a = 0, b: number) {}
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 3,
-                        column => 15,
-                        end_column => 20,
-                      },
-                    ],
-                  },
+                errors => [
                   {
-                    code => r#"
+                    message_id => "should_be_last",
+                    line => 3,
+                    column => 15,
+                    end_column => 20,
+                  },
+                ],
+              },
+              {
+                code => r#"
 class Foo {
   constructor(a?: number, b: number) {}
 }
                     "#,
-                    errors => [
-                      {
-                        message_id => "should_be_last",
-                        line => 3,
-                        column => 15,
-                        end_column => 25,
-                      },
-                    ],
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 3,
+                    column => 15,
+                    end_column => 25,
                   },
                 ],
-            },
+              },
+              {
+                code => r#"
+function foo(a?: number): void;
+function foo(a?: number, b: number) {}
+                    "#,
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 3,
+                    column => 14,
+                    end_column => 24,
+                  },
+                ],
+              },
+              {
+                code => r#"
+class Foo {
+  bar(a: number): void;
+  bar(a = 1, b: number) {}
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "should_be_last",
+                    line => 4,
+                    column => 7,
+                    end_column => 12,
+                  },
+                ],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_default_param_last_rule() {
+        run_for_typescript_and_tsx(
+            default_param_last_rule(),
+            test_cases::default_param_last_rule_tests(),
         );
     }
 }
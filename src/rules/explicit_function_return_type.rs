@@ -0,0 +1,565 @@
+use std::{collections::HashSet, sync::Arc};
+
+use serde::Deserialize;
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    ArrowFunction, CallExpression, FunctionDeclaration, GeneratorFunctionDeclaration, Identifier,
+    MethodDefinition, Pair, ParenthesizedExpression, PropertyIdentifier, StatementBlock,
+    VariableDeclarator,
+};
+
+use crate::{
+    ast_helpers::is_void_operator_expression,
+    kind::PublicFieldDefinition,
+    util::{
+        ancestor_has_return_type, check_function_expression_return_type,
+        check_function_return_type, skip_parenthesized_expressions, ReturnTypeOptions,
+    },
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    allow_concise_arrow_function_expressions_starting_with_void: Option<bool>,
+    allow_direct_const_assertion_in_arrow_functions: Option<bool>,
+    allow_expressions: Option<bool>,
+    allow_functions_without_type_parameters: Option<bool>,
+    allow_higher_order_functions: Option<bool>,
+    allow_iifes: Option<bool>,
+    allow_typed_function_expressions: Option<bool>,
+    allowed_names: Option<Vec<String>>,
+}
+
+impl Options {
+    fn allow_concise_arrow_function_expressions_starting_with_void(&self) -> bool {
+        self.allow_concise_arrow_function_expressions_starting_with_void
+            .unwrap_or_default()
+    }
+
+    fn allow_functions_without_type_parameters(&self) -> bool {
+        self.allow_functions_without_type_parameters
+            .unwrap_or_default()
+    }
+
+    fn allow_iifes(&self) -> bool {
+        self.allow_iifes.unwrap_or_default()
+    }
+
+    fn allowed_names(&self) -> HashSet<String> {
+        self.allowed_names.iter().flatten().cloned().collect()
+    }
+
+    fn return_type_options(&self) -> ReturnTypeOptions {
+        ReturnTypeOptions {
+            allow_expressions: self.allow_expressions.unwrap_or_default(),
+            allow_typed_function_expressions: self.allow_typed_function_expressions.unwrap_or(true),
+            allow_higher_order_functions: self.allow_higher_order_functions.unwrap_or(true),
+            allow_direct_const_assertion_in_arrow_functions: self
+                .allow_direct_const_assertion_in_arrow_functions
+                .unwrap_or(true),
+        }
+    }
+}
+
+/// `() => void foo()`
+fn is_concise_arrow_function_starting_with_void(node: Node) -> bool {
+    if node.kind() != ArrowFunction {
+        return false;
+    }
+    let body = node.field("body");
+    body.kind() != StatementBlock
+        && is_void_operator_expression(skip_parenthesized_expressions(body))
+}
+
+fn is_iife(node: Node) -> bool {
+    let mut callee = node;
+    while callee.parent().unwrap().kind() == ParenthesizedExpression {
+        callee = callee.parent().unwrap();
+    }
+    let parent = callee.parent().unwrap();
+    parent.kind() == CallExpression && parent.field("function") == callee
+}
+
+/// The name a function is declared with or assigned to, only counting
+/// plain identifiers (so eg not computed keys).
+fn get_function_name(node: Node) -> Option<Node> {
+    if let Some(name) = node.child_by_field_name("name") {
+        return matches!(name.kind(), Identifier | PropertyIdentifier).then_some(name);
+    }
+    if matches!(
+        node.kind(),
+        FunctionDeclaration | GeneratorFunctionDeclaration
+    ) {
+        return None;
+    }
+    let parent = node.parent().unwrap();
+    let name = match parent.kind() {
+        VariableDeclarator | PublicFieldDefinition => parent.field("name"),
+        Pair => parent.field("key"),
+        _ => return None,
+    };
+    matches!(name.kind(), Identifier | PropertyIdentifier).then_some(name)
+}
+
+pub fn explicit_function_return_type_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "explicit-function-return-type",
+        languages => [Typescript],
+        messages => [
+            missing_return_type => "Missing return type on function.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            allow_concise_arrow_function_expressions_starting_with_void: bool = options.allow_concise_arrow_function_expressions_starting_with_void(),
+            allow_functions_without_type_parameters: bool = options.allow_functions_without_type_parameters(),
+            allow_iifes: bool = options.allow_iifes(),
+            allowed_names: HashSet<String> = options.allowed_names(),
+            return_type_options: ReturnTypeOptions = options.return_type_options(),
+        },
+        methods => {
+            fn is_allowed_function(&self, node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+                if self.allow_functions_without_type_parameters
+                    && node.child_by_field_name("type_parameters").is_none()
+                {
+                    return true;
+                }
+                if self.allow_iifes && node.kind() != MethodDefinition && is_iife(node) {
+                    return true;
+                }
+                if self.allowed_names.is_empty() {
+                    return false;
+                }
+
+                get_function_name(node)
+                    .is_some_and(|name| self.allowed_names.contains(&*name.text(context)))
+            }
+        },
+        listeners => [
+            r#"
+              (arrow_function) @c
+              (function) @c
+              (generator_function) @c
+              (method_definition) @c
+            "# => |node, context| {
+                if self.allow_concise_arrow_function_expressions_starting_with_void
+                    && is_concise_arrow_function_starting_with_void(node)
+                {
+                    return;
+                }
+                if self.is_allowed_function(node, context) {
+                    return;
+                }
+                if self.return_type_options.allow_typed_function_expressions
+                    && ancestor_has_return_type(node)
+                {
+                    return;
+                }
+
+                check_function_expression_return_type(node, &self.return_type_options, context, |range| {
+                    context.report(violation! {
+                        node => node,
+                        range => range,
+                        message_id => "missing_return_type",
+                    });
+                });
+            },
+            r#"
+              (function_declaration) @c
+              (generator_function_declaration) @c
+            "# => |node, context| {
+                if self.is_allowed_function(node, context) {
+                    return;
+                }
+
+                check_function_return_type(node, &self.return_type_options, context, |range| {
+                    context.report(violation! {
+                        node => node,
+                        range => range,
+                        message_id => "missing_return_type",
+                    });
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn explicit_function_return_type_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "
+function test(): void {
+  return;
+}
+                  ",
+              "
+var fn = function (): number {
+  return 1;
+};
+                  ",
+              "var arrowFn = (): string => 'test';",
+              "
+class Test {
+  constructor() {}
+  get prop(): number {
+    return 1;
+  }
+  set prop() {}
+  method(): void {
+    return;
+  }
+  arrow = (): string => 'arrow';
+}
+                  ",
+              {
+                code => "fn(() => {});",
+                options => { allow_expressions => true },
+              },
+              {
+                code => "fn(function () {});",
+                options => { allow_expressions => true },
+              },
+              {
+                code => "[function () {}, () => {}];",
+                options => { allow_expressions => true },
+              },
+              {
+                code => "(function () {});",
+                options => { allow_expressions => true },
+              },
+              {
+                code => "(() => {})();",
+                options => { allow_expressions => true },
+              },
+              {
+                code => "export default (): void => {};",
+                options => { allow_expressions => true },
+              },
+              "var arrowFn: Foo = () => 'test';",
+              "
+var funcExpr: Foo = function () {
+  return 'test';
+};
+                  ",
+              "const x = { foo: () => {} } as Foo;",
+              "const x = <Foo>{ foo: () => {} };",
+              "const x: Foo = { foo: () => {} };",
+              "
+type MethodType = () => void;
+
+class App {
+  private method: MethodType = () => {};
+}
+                  ",
+              "const myObj = { set myProp(val) { this.myProp = val; } };",
+              "() => (): void => {};",
+              "() => function (): void {};",
+              "
+() => {
+  return (): void => {};
+};
+                  ",
+              "
+function FunctionDeclaration() {
+  return function FunctionExpression_Within_FunctionDeclaration() {
+    return function FunctionExpression_Within_FunctionExpression() {
+      return () => {
+        // ArrowFunctionExpression_Within_FunctionExpression
+        return () =>
+          // ArrowFunctionExpression_Within_ArrowFunctionExpression
+          (): number =>
+            1; // ArrowFunctionExpression_Within_ArrowFunctionExpression_WithNoBody
+      };
+    };
+  };
+}
+                  ",
+              "const func = (value: number) => ({ type: 'X', value }) as const;",
+              "
+const func = (value: number) => {
+  return { type: 'X', value } as const;
+};
+                  ",
+              {
+                code => "const log = (message: string) => void console.log(message);",
+                options => { allow_concise_arrow_function_expressions_starting_with_void => true },
+              },
+              {
+                code => "const log = (message: string) => (void console.log(message));",
+                options => { allow_concise_arrow_function_expressions_starting_with_void => true },
+              },
+              {
+                code => "
+function test1() {
+  return;
+}
+
+const foo = function test2() {
+  return;
+};
+
+const bar = () => {};
+                    ",
+                options => { allowed_names => ["test1", "test2", "bar"] },
+              },
+              {
+                code => "
+const x = {
+  foo() {
+    return;
+  },
+  bar: () => {},
+};
+                    ",
+                options => { allowed_names => ["foo", "bar"] },
+              },
+              {
+                code => "
+class Foo {
+  baz() {}
+  qux = () => {};
+}
+                    ",
+                options => { allowed_names => ["baz", "qux"] },
+              },
+              {
+                code => "
+function foo() {}
+const bar = () => {};
+                    ",
+                options => { allow_functions_without_type_parameters => true },
+              },
+              {
+                code => "const foo = (function () { return 1; })();",
+                options => { allow_iifes => true },
+              },
+              {
+                code => "const foo = (() => 1)();",
+                options => { allow_iifes => true },
+              },
+            ],
+            invalid => [
+              {
+                code => "
+function test(a: number, b: number) {
+  return;
+}
+                    ",
+                errors => [
+                  {
+                    message_id => "missing_return_type",
+                    line => 2,
+                    end_line => 2,
+                    column => 1,
+                    end_column => 14,
+                  },
+                ],
+              },
+              {
+                code => "
+function test() {
+  return;
+}
+                    ",
+                errors => [
+                  {
+                    message_id => "missing_return_type",
+                    line => 2,
+                    column => 1,
+                    end_column => 14,
+                  },
+                ],
+              },
+              {
+                code => "
+var fn = function () {
+  return 1;
+};
+                    ",
+                errors => [
+                  {
+                    message_id => "missing_return_type",
+                    line => 2,
+                    column => 10,
+                    end_column => 19,
+                  },
+                ],
+              },
+              {
+                code => "var arrowFn = () => 'test';",
+                errors => [
+                  {
+                    message_id => "missing_return_type",
+                    line => 1,
+                    column => 18,
+                    end_column => 20,
+                  },
+                ],
+              },
+              {
+                code => "
+class Test {
+  constructor() {}
+  get prop() {
+    return 1;
+  }
+  set prop() {}
+  method() {
+    return;
+  }
+  arrow = () => 'arrow';
+  private method() {
+    return;
+  }
+}
+                    ",
+                errors => [
+                  { message_id => "missing_return_type", line => 4, column => 3, end_column => 11 },
+                  { message_id => "missing_return_type", line => 8, column => 3, end_column => 9 },
+                  { message_id => "missing_return_type", line => 11, column => 14, end_column => 16 },
+                  { message_id => "missing_return_type", line => 12, column => 3, end_column => 17 },
+                ],
+              },
+              {
+                code => "
+function test() {
+  return;
+}
+                    ",
+                options => { allow_expressions => true },
+                errors => [{ message_id => "missing_return_type", line => 2 }],
+              },
+              {
+                code => "const foo = () => {};",
+                options => { allow_expressions => true },
+                errors => [{ message_id => "missing_return_type", column => 16 }],
+              },
+              {
+                code => "const foo = function () {};",
+                options => { allow_expressions => true },
+                errors => [{ message_id => "missing_return_type", column => 13 }],
+              },
+              {
+                code => "var arrowFn = () => 'test';",
+                options => { allow_typed_function_expressions => true },
+                errors => [{ message_id => "missing_return_type" }],
+              },
+              {
+                code => "() => () => {};",
+                options => { allow_higher_order_functions => true },
+                errors => [{ message_id => "missing_return_type", column => 10 }],
+              },
+              {
+                code => "() => () => {};",
+                options => { allow_higher_order_functions => false },
+                errors => [
+                  { message_id => "missing_return_type", column => 4 },
+                  { message_id => "missing_return_type", column => 10 },
+                ],
+              },
+              {
+                code => "const func = (value: number) => ({ type: 'X', value }) as any;",
+                errors => [{ message_id => "missing_return_type", column => 30 }],
+              },
+              {
+                code => "const func = (value: number) => ({ type: 'X', value }) as const;",
+                options => { allow_direct_const_assertion_in_arrow_functions => false },
+                errors => [{ message_id => "missing_return_type", column => 30 }],
+              },
+              {
+                code => "const log = (message: string) => void console.log(message);",
+                errors => [
+                  {
+                    message_id => "missing_return_type",
+                    line => 1,
+                    column => 31,
+                    end_column => 33,
+                  },
+                ],
+              },
+              {
+                code => "const log = (message: string) => void console.log(message);",
+                options => { allow_concise_arrow_function_expressions_starting_with_void => false },
+                errors => [{ message_id => "missing_return_type", column => 31 }],
+              },
+              {
+                code => "
+const log = (message: string) => {
+  void console.log(message);
+};
+                    ",
+                options => { allow_concise_arrow_function_expressions_starting_with_void => true },
+                errors => [
+                  {
+                    message_id => "missing_return_type",
+                    line => 2,
+                    column => 31,
+                    end_column => 33,
+                  },
+                ],
+              },
+              {
+                code => "const log = (message: string) => !console.log(message);",
+                options => { allow_concise_arrow_function_expressions_starting_with_void => true },
+                errors => [{ message_id => "missing_return_type", column => 31 }],
+              },
+              {
+                code => "
+function test1() {
+  return;
+}
+
+const foo = function test2() {
+  return;
+};
+                    ",
+                options => { allowed_names => ["test1"] },
+                errors => [{ message_id => "missing_return_type", line => 6 }],
+              },
+              {
+                code => "
+const x = {
+  ['foo']() {
+    return;
+  },
+};
+                    ",
+                options => { allowed_names => ["foo"] },
+                errors => [{ message_id => "missing_return_type", line => 3 }],
+              },
+              {
+                code => "function foo<T>() {}",
+                options => { allow_functions_without_type_parameters => true },
+                errors => [{ message_id => "missing_return_type" }],
+              },
+              {
+                code => "const foo = (function () { return 1; })();",
+                errors => [{ message_id => "missing_return_type", column => 14 }],
+              },
+              {
+                code => "const foo = (function () { return () => 1; })();",
+                options => { allow_iifes => true },
+                errors => [{ message_id => "missing_return_type", column => 38 }],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_explicit_function_return_type_rule() {
+        run_for_typescript_and_tsx(
+            explicit_function_return_type_rule(),
+            test_cases::explicit_function_return_type_rule_tests(),
+        );
+    }
+}
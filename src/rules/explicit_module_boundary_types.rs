@@ -0,0 +1,972 @@
+use std::{borrow::Cow, collections::HashSet, sync::Arc};
+
+use serde::Deserialize;
+use squalid::OptionExt;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    kind::{
+        Array, ArrayPattern, ArrowFunction, AssignmentExpression, Class, ClassDeclaration,
+        ComputedPropertyName, ExportClause, ExpressionStatement, Function, FunctionDeclaration,
+        GeneratorFunction, GeneratorFunctionDeclaration, Identifier, LexicalDeclaration,
+        MethodDefinition, Object, ObjectPattern, Pair, PrivatePropertyIdentifier, Program,
+        PropertyIdentifier, RestPattern, ReturnStatement, ShorthandPropertyIdentifier,
+        String as StringKind, TemplateString, This, VariableDeclaration, VariableDeclarator,
+    },
+    utils::ast_utils::get_static_string_value,
+};
+
+use crate::{
+    ast_helpers::{get_accessibility_modifier, get_range_from},
+    kind::{
+        AbstractClassDeclaration, AbstractMethodSignature, MethodSignature, OptionalParameter,
+        PredefinedType, PublicFieldDefinition, RequiredParameter,
+    },
+    util::{
+        ancestor_has_return_type, check_function_expression_return_type,
+        check_function_return_type, does_immediately_return_function_expression, is_function,
+        is_typed_function_expression, skip_parenthesized_expressions, ReturnTypeOptions,
+    },
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    allow_arguments_explicitly_typed_as_any: Option<bool>,
+    allow_direct_const_assertion_in_arrow_functions: Option<bool>,
+    allow_higher_order_functions: Option<bool>,
+    allow_typed_function_expressions: Option<bool>,
+    allowed_names: Option<Vec<String>>,
+}
+
+impl Options {
+    fn allow_arguments_explicitly_typed_as_any(&self) -> bool {
+        self.allow_arguments_explicitly_typed_as_any
+            .unwrap_or_default()
+    }
+
+    fn allowed_names(&self) -> HashSet<String> {
+        self.allowed_names.iter().flatten().cloned().collect()
+    }
+
+    fn return_type_options(&self) -> ReturnTypeOptions {
+        ReturnTypeOptions {
+            allow_expressions: false,
+            allow_typed_function_expressions: self.allow_typed_function_expressions.unwrap_or(true),
+            allow_higher_order_functions: self.allow_higher_order_functions.unwrap_or(true),
+            allow_direct_const_assertion_in_arrow_functions: self
+                .allow_direct_const_assertion_in_arrow_functions
+                .unwrap_or(true),
+        }
+    }
+}
+
+fn is_private_member<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    node.field("name").kind() == PrivatePropertyIdentifier
+        || get_accessibility_modifier(node)
+            .matches(|accessibility_modifier| accessibility_modifier.text(context) == "private")
+}
+
+fn is_any_type(type_annotation: Node) -> bool {
+    let type_ = type_annotation.first_non_comment_named_child(SupportedLanguage::Javascript);
+    type_.kind() == PredefinedType
+        && type_
+            .first_non_comment_child(SupportedLanguage::Javascript)
+            .kind()
+            == "any"
+}
+
+// TODO: this only follows top-level declarations of and assignments to the
+// name, upstream uses scope analysis
+fn get_reference_targets<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Vec<Node<'a>> {
+    let name = node.text(context);
+    let program = node
+        .ancestors()
+        .find(|ancestor| ancestor.kind() == Program)
+        .unwrap();
+
+    let mut targets = vec![];
+    for statement in program.non_comment_named_children(SupportedLanguage::Javascript) {
+        let statement = statement
+            .child_by_field_name("declaration")
+            .unwrap_or(statement);
+        match statement.kind() {
+            FunctionDeclaration
+            | GeneratorFunctionDeclaration
+            | ClassDeclaration
+            | AbstractClassDeclaration => {
+                if statement.field("name").text(context) == name {
+                    targets.push(statement);
+                }
+            }
+            LexicalDeclaration | VariableDeclaration => {
+                targets.extend(
+                    statement
+                        .non_comment_named_children(SupportedLanguage::Javascript)
+                        .filter(|declarator| {
+                            declarator.kind() == VariableDeclarator
+                                && declarator.field("name").kind() == Identifier
+                                && declarator.field("name").text(context) == name
+                        }),
+                );
+            }
+            ExpressionStatement => {
+                let expression =
+                    statement.first_non_comment_named_child(SupportedLanguage::Javascript);
+                if expression.kind() == AssignmentExpression
+                    && expression.field("left").kind() == Identifier
+                    && expression.field("left").text(context) == name
+                {
+                    targets.push(expression.field("right"));
+                }
+            }
+            _ => (),
+        }
+    }
+    targets
+}
+
+// `foo`, `'foo'`, `['foo']` and `` [`foo`] `` all count, but eg `[foo]`
+// doesn't
+fn get_static_member_name<'a>(
+    member: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Cow<'a, str>> {
+    let key = member.field("name");
+    let key = if key.kind() == ComputedPropertyName {
+        key.first_non_comment_named_child(SupportedLanguage::Javascript)
+    } else {
+        key
+    };
+    match key.kind() {
+        PropertyIdentifier => Some(key.text(context)),
+        StringKind | TemplateString => get_static_string_value(key, context),
+        _ => None,
+    }
+}
+
+fn get_parameters(node: Node) -> Vec<Node> {
+    match node.child_by_field_name("parameters") {
+        Some(parameters) => parameters
+            .non_comment_named_children(SupportedLanguage::Javascript)
+            .collect(),
+        None => node.child_by_field_name("parameter").into_iter().collect(),
+    }
+}
+
+pub fn explicit_module_boundary_types_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "explicit-module-boundary-types",
+        languages => [Typescript],
+        messages => [
+            missing_return_type => "Missing return type on function.",
+            missing_arg_type => "Argument '{{name}}' should be typed.",
+            missing_arg_type_unnamed => "{{type}} argument should be typed.",
+            any_typed_arg => "Argument '{{name}}' should be typed with a non-any type.",
+            any_typed_arg_unnamed => "{{type}} argument should be typed with a non-any type.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            allow_arguments_explicitly_typed_as_any: bool = options.allow_arguments_explicitly_typed_as_any(),
+            return_type_options: ReturnTypeOptions = options.return_type_options(),
+            allowed_names: HashSet<String> = options.allowed_names(),
+            [per-file-run]
+            // tracks all of the functions we've already checked
+            checked_functions: HashSet<Node<'a>>,
+            // tracks functions that were found whilst traversing
+            found_functions: Vec<Node<'a>>,
+            // all nodes visited, avoids infinite recursion for cyclic references
+            already_visited: HashSet<Node<'a>>,
+        },
+        methods => {
+            fn is_allowed_name(&self, node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+                if self.allowed_names.is_empty() {
+                    return false;
+                }
+
+                let name = match node.kind() {
+                    VariableDeclarator => Some(node.field("name"))
+                        .filter(|name| name.kind() == Identifier)
+                        .map(|name| name.text(context)),
+                    FunctionDeclaration | GeneratorFunctionDeclaration => node
+                        .child_by_field_name("name")
+                        .map(|name| name.text(context)),
+                    MethodDefinition | PublicFieldDefinition => get_static_member_name(node, context),
+                    _ => None,
+                };
+                name.is_some_and(|name| self.allowed_names.contains(&*name))
+            }
+
+            fn report_parameter(
+                &self,
+                parameter: Node<'a>,
+                pattern: Node<'a>,
+                named_message_id: &str,
+                unnamed_message_id: &str,
+                context: &QueryMatchContext<'a, '_>,
+            ) {
+                let range = get_range_from(pattern, parameter);
+                let unnamed_type = match pattern.kind() {
+                    Identifier | This => None,
+                    ArrayPattern => Some("Array pattern"),
+                    ObjectPattern => Some("Object pattern"),
+                    RestPattern => {
+                        let argument = pattern.first_non_comment_named_child(SupportedLanguage::Javascript);
+                        if argument.kind() == Identifier {
+                            context.report(violation! {
+                                node => parameter,
+                                range => range,
+                                message_id => named_message_id,
+                                data => {
+                                    name => argument.text(context),
+                                },
+                            });
+                            return;
+                        }
+                        Some("Rest")
+                    }
+                    _ => return,
+                };
+                match unnamed_type {
+                    None => context.report(violation! {
+                        node => parameter,
+                        range => range,
+                        message_id => named_message_id,
+                        data => {
+                            name => pattern.text(context),
+                        },
+                    }),
+                    Some(unnamed_type) => context.report(violation! {
+                        node => parameter,
+                        range => range,
+                        message_id => unnamed_message_id,
+                        data => {
+                            type => unnamed_type,
+                        },
+                    }),
+                }
+            }
+
+            fn check_parameters(&self, node: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+                for parameter in get_parameters(node) {
+                    match parameter.kind() {
+                        Identifier => {
+                            self.report_parameter(parameter, parameter, "missing_arg_type", "missing_arg_type_unnamed", context);
+                        }
+                        RequiredParameter | OptionalParameter => {
+                            // ignored as it has a default value
+                            if parameter.child_by_field_name("value").is_some() {
+                                continue;
+                            }
+
+                            let pattern = parameter.field("pattern");
+                            match parameter.child_by_field_name("type") {
+                                None => self.report_parameter(parameter, pattern, "missing_arg_type", "missing_arg_type_unnamed", context),
+                                Some(type_annotation) if !self.allow_arguments_explicitly_typed_as_any && is_any_type(type_annotation) => {
+                                    self.report_parameter(parameter, pattern, "any_typed_arg", "any_typed_arg_unnamed", context);
+                                }
+                                _ => (),
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+            }
+
+            fn is_exported_higher_order_function(&self, node: Node<'a>) -> bool {
+                let mut current = node.parent();
+                while let Some(current_node) = current {
+                    if current_node.kind() == ReturnStatement {
+                        // the parent of a return will always be a block
+                        // statement, so we can skip over it
+                        current = current_node.parent().and_then(|parent| parent.parent());
+                        continue;
+                    }
+
+                    if !is_function(current_node) || !does_immediately_return_function_expression(current_node) {
+                        return false;
+                    }
+
+                    if self.checked_functions.contains(&current_node) {
+                        return true;
+                    }
+
+                    current = current_node.parent();
+                }
+                false
+            }
+
+            fn follow_reference(&mut self, node: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+                for target in get_reference_targets(node, context) {
+                    self.check_node(target, context);
+                }
+            }
+
+            fn check_node(&mut self, node: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+                let node = skip_parenthesized_expressions(node);
+                if !self.already_visited.insert(node) {
+                    return;
+                }
+
+                match node.kind() {
+                    ArrowFunction | Function | GeneratorFunction => {
+                        self.check_function_expression(node, context);
+                    }
+                    Array => {
+                        for element in node.non_comment_named_children(SupportedLanguage::Javascript) {
+                            self.check_node(element, context);
+                        }
+                    }
+                    PublicFieldDefinition => {
+                        if is_private_member(node, context) {
+                            return;
+                        }
+                        if let Some(value) = node.child_by_field_name("value") {
+                            self.check_node(value, context);
+                        }
+                    }
+                    ClassDeclaration | Class | AbstractClassDeclaration => {
+                        for member in node.field("body").non_comment_named_children(SupportedLanguage::Javascript) {
+                            self.check_node(member, context);
+                        }
+                    }
+                    FunctionDeclaration | GeneratorFunctionDeclaration => {
+                        self.check_function(node, context);
+                    }
+                    MethodDefinition => {
+                        if node.parent().unwrap().kind() != Object && is_private_member(node, context) {
+                            return;
+                        }
+                        self.check_function_expression(node, context);
+                    }
+                    MethodSignature | AbstractMethodSignature => {
+                        if is_private_member(node, context) {
+                            return;
+                        }
+                        self.check_empty_body_function_expression(node, context);
+                    }
+                    Identifier | ShorthandPropertyIdentifier => {
+                        self.follow_reference(node, context);
+                    }
+                    Object => {
+                        for property in node.non_comment_named_children(SupportedLanguage::Javascript) {
+                            self.check_node(property, context);
+                        }
+                    }
+                    Pair => {
+                        self.check_node(node.field("value"), context);
+                    }
+                    LexicalDeclaration | VariableDeclaration => {
+                        for declarator in node.non_comment_named_children(SupportedLanguage::Javascript) {
+                            self.check_node(declarator, context);
+                        }
+                    }
+                    VariableDeclarator => {
+                        if let Some(value) = node.child_by_field_name("value") {
+                            self.check_node(value, context);
+                        }
+                    }
+                    _ => (),
+                }
+            }
+
+            fn check_empty_body_function_expression(&self, node: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+                let is_constructor = node.field("name").text(context) == "constructor";
+                let is_set_accessor = node
+                    .non_comment_children_and_field_names(SupportedLanguage::Javascript)
+                    .take_while(|(_, field_name)| *field_name != Some("name"))
+                    .any(|(child, _)| child.kind() == "set");
+                if !is_constructor && !is_set_accessor && node.child_by_field_name("return_type").is_none() {
+                    let start = node
+                        .child_by_field_name("type_parameters")
+                        .unwrap_or_else(|| node.field("parameters"));
+                    context.report(violation! {
+                        node => node,
+                        range => get_range_from(start, node),
+                        message_id => "missing_return_type",
+                    });
+                }
+
+                self.check_parameters(node, context);
+            }
+
+            fn check_function_expression(&mut self, node: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+                if !self.checked_functions.insert(node) {
+                    return;
+                }
+
+                // (a method is its own "parent" here)
+                let owner = if node.kind() == MethodDefinition {
+                    node
+                } else {
+                    node.parent().unwrap()
+                };
+                if self.is_allowed_name(owner, context)
+                    || is_typed_function_expression(node, &self.return_type_options)
+                    || ancestor_has_return_type(node)
+                {
+                    return;
+                }
+
+                check_function_expression_return_type(node, &self.return_type_options, context, |range| {
+                    context.report(violation! {
+                        node => node,
+                        range => range,
+                        message_id => "missing_return_type",
+                    });
+                });
+
+                self.check_parameters(node, context);
+            }
+
+            fn check_function(&mut self, node: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+                if !self.checked_functions.insert(node) {
+                    return;
+                }
+
+                if self.is_allowed_name(node, context) || ancestor_has_return_type(node) {
+                    return;
+                }
+
+                check_function_return_type(node, &self.return_type_options, context, |range| {
+                    context.report(violation! {
+                        node => node,
+                        range => range,
+                        message_id => "missing_return_type",
+                    });
+                });
+
+                self.check_parameters(node, context);
+            }
+        },
+        listeners => [
+            r#"
+              (export_statement) @c
+            "# => |node, context| {
+                // re-exports
+                if node.child_by_field_name("source").is_some() {
+                    return;
+                }
+
+                if let Some(declaration) = node
+                    .child_by_field_name("declaration")
+                    .or_else(|| node.child_by_field_name("value"))
+                {
+                    self.check_node(declaration, context);
+                    return;
+                }
+
+                for child in node.non_comment_named_children(SupportedLanguage::Javascript) {
+                    match child.kind() {
+                        ExportClause => {
+                            for specifier in child.non_comment_named_children(SupportedLanguage::Javascript) {
+                                self.follow_reference(specifier.field("name"), context);
+                            }
+                        }
+                        // `export = foo`
+                        _ => self.check_node(child, context),
+                    }
+                }
+            },
+            r#"
+              (arrow_function) @c
+              (function) @c
+              (function_declaration) @c
+              (generator_function) @c
+              (generator_function_declaration) @c
+            "# => |node, context| {
+                self.found_functions.push(node);
+            },
+            r#"
+              program:exit
+            "# => |node, context| {
+                for function in self.found_functions.clone() {
+                    if self.is_exported_higher_order_function(function) {
+                        self.check_function(function, context);
+                    }
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn explicit_module_boundary_types_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              r#"
+function test() {
+  return;
+}
+                  "#,
+              r#"
+export function test(): void {
+  return;
+}
+                  "#,
+              r#"
+export var fn = function (): number {
+  return 1;
+};
+                  "#,
+              "export var arrowFn = (): string => 'test';",
+              r#"
+class Test {
+  constructor(one) {}
+  get prop(one) {
+    return 1;
+  }
+  set prop(one) {}
+  method(one) {
+    return;
+  }
+  arrow = one => 'arrow';
+}
+                  "#,
+              r#"
+export class Test {
+  constructor(one: string) {}
+  get prop(one: string): void {
+    return 1;
+  }
+  set prop(one: string) {}
+  method(one: string): void {
+    return;
+  }
+  arrow = (one: string): string => 'arrow';
+  private untyped(one) {
+    return;
+  }
+}
+                  "#,
+              r#"
+export class PrivateProperty {
+  #property = () => null;
+}
+                  "#,
+              r#"
+export class PrivateMethod {
+  #method() {}
+}
+                  "#,
+              "export var arrowFn: Foo = () => 'test';",
+              r#"
+export var funcExpr: Foo = function () {
+  return 'test';
+};
+                  "#,
+              "export const x: Foo = { foo: () => {} };",
+              "export const x = { foo: () => {} } as Foo;",
+              "export const foo: Foo = (arg) => arg;",
+              r#"
+export const myObj = {
+  set myProp(val: number) {
+    this.myProp = val;
+  },
+};
+                  "#,
+              "export default () => (): void => {};",
+              "export default () => function (): void {};",
+              r#"
+export default () => {
+  return (): void => {};
+};
+                  "#,
+              r#"
+export function foo(outer: string) {
+  return function (inner: string): void {};
+}
+                  "#,
+              "export const foo = (): ((n: number) => string) => n => String(n);",
+              "export const func1 = (value: number) => ({ type: 'X', value }) as const;",
+              r#"
+export const func2 = (value: number) => {
+  return { type: 'X', value } as const;
+};
+                  "#,
+              {
+                code => "export function foo(arg: any): void {}",
+                options => { allow_arguments_explicitly_typed_as_any => true },
+              },
+              "export function foo(...args: any[]): void {}",
+              "export function foo(a = 1): void {}",
+              "export default function (a: string): void {}",
+              "export { foo } from './foo';",
+              r#"
+export class Bar {
+  bar = (arg: string): string => arg;
+}
+                  "#,
+              {
+                code => r#"
+export function func1() {
+  return 0;
+}
+export const foo = {
+  func2() {
+    return 0;
+  },
+};
+export const func3 = (arg) => arg;
+                    "#,
+                options => { allowed_names => ["func1", "func2", "func3"] },
+              },
+              {
+                code => r#"
+export class Test {
+  method1() {
+    return;
+  }
+  'method2'() {
+    return;
+  }
+  ['method3']() {
+    return;
+  }
+  [`method4`]() {
+    return;
+  }
+  arrow = () => 'arrow';
+}
+                    "#,
+                options => { allowed_names => ["method1", "method2", "method3", "method4", "arrow"] },
+              },
+            ],
+            invalid => [
+              {
+                code => r#"
+export function test(a: number, b: number) {
+  return;
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "missing_return_type",
+                    line => 2,
+                    end_line => 2,
+                    column => 8,
+                    end_column => 21,
+                  },
+                ],
+              },
+              {
+                code => r#"
+export var fn = function () {
+  return 1;
+};
+                    "#,
+                errors => [
+                  {
+                    message_id => "missing_return_type",
+                    line => 2,
+                    end_line => 2,
+                    column => 17,
+                    end_column => 26,
+                  },
+                ],
+              },
+              {
+                code => "export var arrowFn = () => 'test';",
+                errors => [
+                  {
+                    message_id => "missing_return_type",
+                    line => 1,
+                    end_line => 1,
+                    column => 25,
+                    end_column => 27,
+                  },
+                ],
+              },
+              {
+                code => r#"
+export abstract class Test {
+  constructor() {}
+  get prop() {
+    return 1;
+  }
+  set prop(value) {}
+  method() {
+    return;
+  }
+  arrow = arg => 'arrow';
+  private method() {
+    return;
+  }
+  abstract abs(arg);
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "missing_return_type",
+                    line => 4,
+                    column => 3,
+                    end_column => 11,
+                  },
+                  {
+                    message_id => "missing_arg_type",
+                    data => { name => "value" },
+                    line => 7,
+                    column => 12,
+                    end_column => 17,
+                  },
+                  {
+                    message_id => "missing_return_type",
+                    line => 8,
+                    column => 3,
+                    end_column => 9,
+                  },
+                  {
+                    message_id => "missing_arg_type",
+                    data => { name => "arg" },
+                    line => 11,
+                    column => 11,
+                    end_column => 14,
+                  },
+                  {
+                    message_id => "missing_return_type",
+                    line => 11,
+                    column => 15,
+                    end_column => 17,
+                  },
+                  {
+                    message_id => "missing_return_type",
+                    line => 15,
+                    column => 15,
+                    end_column => 20,
+                  },
+                  {
+                    message_id => "missing_arg_type",
+                    data => { name => "arg" },
+                    line => 15,
+                    column => 16,
+                    end_column => 19,
+                  },
+                ],
+              },
+              {
+                code => "export function foo(arg: any): void {}",
+                errors => [
+                  {
+                    message_id => "any_typed_arg",
+                    data => { name => "arg" },
+                    line => 1,
+                    column => 21,
+                    end_column => 29,
+                  },
+                ],
+              },
+              {
+                code => "export function foo({ a }: any): void {}",
+                errors => [
+                  {
+                    message_id => "any_typed_arg_unnamed",
+                    data => { type => "Object pattern" },
+                    line => 1,
+                    column => 21,
+                  },
+                ],
+              },
+              {
+                code => "export function foo({ a }, [b], ...[c]): void {}",
+                errors => [
+                  {
+                    message_id => "missing_arg_type_unnamed",
+                    data => { type => "Object pattern" },
+                    line => 1,
+                    column => 21,
+                  },
+                  {
+                    message_id => "missing_arg_type_unnamed",
+                    data => { type => "Array pattern" },
+                    line => 1,
+                    column => 28,
+                  },
+                  {
+                    message_id => "missing_arg_type_unnamed",
+                    data => { type => "Rest" },
+                    line => 1,
+                    column => 33,
+                  },
+                ],
+              },
+              {
+                code => "export function foo(...args): void {}",
+                errors => [
+                  {
+                    message_id => "missing_arg_type",
+                    data => { name => "args" },
+                    line => 1,
+                    column => 21,
+                  },
+                ],
+              },
+              {
+                code => "export default () => () => {};",
+                errors => [
+                  {
+                    message_id => "missing_return_type",
+                    line => 1,
+                    column => 25,
+                    end_column => 27,
+                  },
+                ],
+              },
+              {
+                code => "export default () => () => {};",
+                options => { allow_higher_order_functions => false },
+                errors => [
+                  {
+                    message_id => "missing_return_type",
+                    line => 1,
+                    column => 19,
+                    end_column => 21,
+                  },
+                  {
+                    message_id => "missing_return_type",
+                    line => 1,
+                    column => 25,
+                    end_column => 27,
+                  },
+                ],
+              },
+              {
+                code => "export var arrowFn: Foo = () => 'test';",
+                options => { allow_typed_function_expressions => false },
+                errors => [
+                  {
+                    message_id => "missing_return_type",
+                    line => 1,
+                    column => 30,
+                    end_column => 32,
+                  },
+                ],
+              },
+              {
+                code => "export const func = (value: number) => ({ type: 'X', value }) as const;",
+                options => { allow_direct_const_assertion_in_arrow_functions => false },
+                errors => [
+                  {
+                    message_id => "missing_return_type",
+                    line => 1,
+                    column => 37,
+                    end_column => 39,
+                  },
+                ],
+              },
+              {
+                code => r#"
+function foo() {
+  return 1;
+}
+export { foo };
+                    "#,
+                errors => [
+                  {
+                    message_id => "missing_return_type",
+                    line => 2,
+                    column => 1,
+                    end_column => 13,
+                  },
+                ],
+              },
+              {
+                code => r#"
+const foo = (arg) => arg;
+export default foo;
+                    "#,
+                errors => [
+                  {
+                    message_id => "missing_arg_type",
+                    data => { name => "arg" },
+                    line => 2,
+                    column => 14,
+                  },
+                  {
+                    message_id => "missing_return_type",
+                    line => 2,
+                    column => 19,
+                    end_column => 21,
+                  },
+                ],
+              },
+              {
+                code => r#"
+export const foo = {
+  bar(arg) {
+    return arg;
+  },
+};
+                    "#,
+                errors => [
+                  {
+                    message_id => "missing_return_type",
+                    line => 3,
+                    column => 3,
+                    end_column => 6,
+                  },
+                  {
+                    message_id => "missing_arg_type",
+                    data => { name => "arg" },
+                    line => 3,
+                    column => 7,
+                  },
+                ],
+              },
+              {
+                code => r#"
+export function func1() {
+  return 0;
+}
+export const func2 = (value: number) => value;
+                    "#,
+                options => { allowed_names => ["func1"] },
+                errors => [
+                  {
+                    message_id => "missing_return_type",
+                    line => 5,
+                    column => 38,
+                    end_column => 40,
+                  },
+                ],
+              },
+              {
+                code => r#"
+export class Test {
+  [method]() {
+    return;
+  }
+  [`${method}`]() {
+    return;
+  }
+}
+                    "#,
+                options => { allowed_names => ["method"] },
+                errors => [
+                  { message_id => "missing_return_type", line => 3 },
+                  { message_id => "missing_return_type", line => 6 },
+                ],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_explicit_module_boundary_types_rule() {
+        run_for_typescript_and_tsx(
+            explicit_module_boundary_types_rule(),
+            test_cases::explicit_module_boundary_types_rule_tests(),
+        );
+    }
+}
@@ -0,0 +1,1510 @@
+use std::{borrow::Cow, cmp::Ordering, sync::Arc};
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    ast_helpers::{get_method_definition_kind, MethodDefinitionKind},
+    kind::{
+        ArrowFunction, ClassBody, ClassStaticBlock, Decorator, Function, Identifier,
+        MethodDefinition, PrivatePropertyIdentifier,
+    },
+};
+
+use crate::{
+    ast_helpers::{
+        get_accessibility_modifier, get_member_decorators, has_keyword_before_name,
+        is_index_signature, is_member_static, is_type_literal,
+    },
+    config_warnings::{ConfigWarning, ValidateOptions},
+    kind::{
+        AbstractMethodSignature, CallSignature, ConstructSignature, IndexSignature,
+        MethodSignature, PropertySignature, PublicFieldDefinition,
+    },
+    util::get_name_from_member,
+};
+
+/// Either a single member group or several that share a rank, eg
+/// `["field", "constructor"]`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum MemberType {
+    Single(String),
+    Group(Vec<String>),
+}
+
+impl MemberType {
+    fn contains(&self, member_group: &str) -> bool {
+        match self {
+            Self::Single(member_type) => member_type == member_group,
+            Self::Group(member_types) => member_types
+                .iter()
+                .any(|member_type| member_type == member_group),
+        }
+    }
+
+    /// eg `public static field, constructor`.
+    fn description(&self) -> String {
+        match self {
+            Self::Single(member_type) => member_type.replace('-', " "),
+            Self::Group(member_types) => member_types
+                .iter()
+                .map(|member_type| member_type.replace('-', " "))
+                .collect::<Vec<_>>()
+                .join(", "),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+enum Order {
+    #[default]
+    AsWritten,
+    Alphabetically,
+    AlphabeticallyCaseInsensitive,
+    Natural,
+    NaturalCaseInsensitive,
+}
+
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+enum OptionalityOrder {
+    OptionalFirst,
+    RequiredFirst,
+}
+
+#[derive(Copy, Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Never {
+    Never,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum MemberTypes {
+    Never(Never),
+    MemberTypes(Vec<MemberType>),
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+struct SortedOrderConfig {
+    member_types: Option<MemberTypes>,
+    order: Option<Order>,
+    optionality_order: Option<OptionalityOrder>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum OrderConfig {
+    Never(Never),
+    MemberTypes(Vec<MemberType>),
+    SortedOrderConfig(SortedOrderConfig),
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub(crate) struct Options {
+    default: Option<OrderConfig>,
+    classes: Option<OrderConfig>,
+    class_expressions: Option<OrderConfig>,
+    interfaces: Option<OrderConfig>,
+    type_literals: Option<OrderConfig>,
+}
+
+impl Options {
+    fn default_config(&self) -> OrderConfig {
+        self.default
+            .clone()
+            .unwrap_or_else(|| OrderConfig::MemberTypes(get_default_order()))
+    }
+
+    fn classes(&self) -> OrderConfig {
+        self.classes
+            .clone()
+            .unwrap_or_else(|| self.default_config())
+    }
+
+    fn class_expressions(&self) -> OrderConfig {
+        self.class_expressions
+            .clone()
+            .unwrap_or_else(|| self.default_config())
+    }
+
+    fn interfaces(&self) -> OrderConfig {
+        self.interfaces
+            .clone()
+            .unwrap_or_else(|| self.default_config())
+    }
+
+    fn type_literals(&self) -> OrderConfig {
+        self.type_literals
+            .clone()
+            .unwrap_or_else(|| self.default_config())
+    }
+}
+
+impl OrderConfig {
+    fn member_types(&self) -> Option<&[MemberType]> {
+        match self {
+            Self::MemberTypes(member_types)
+            | Self::SortedOrderConfig(SortedOrderConfig {
+                member_types: Some(MemberTypes::MemberTypes(member_types)),
+                ..
+            }) => Some(&member_types[..]),
+            _ => None,
+        }
+    }
+}
+
+impl ValidateOptions for Options {
+    /// A member type listed more than once (whether on its own or as part of
+    /// a group) only ever gets the first of its ranks, so the later mention
+    /// is dead.
+    fn validate(&self) -> Vec<ConfigWarning> {
+        [
+            ("default", &self.default),
+            ("classes", &self.classes),
+            ("class_expressions", &self.class_expressions),
+            ("interfaces", &self.interfaces),
+            ("type_literals", &self.type_literals),
+        ]
+        .into_iter()
+        .filter_map(|(option, config)| Some((option, config.as_ref()?.member_types()?)))
+        .flat_map(|(option, member_types)| {
+            let mut seen: Vec<&str> = Default::default();
+            member_types
+                .iter()
+                .flat_map(|member_type| match member_type {
+                    MemberType::Single(member_type) => std::slice::from_ref(member_type),
+                    MemberType::Group(member_types) => &member_types[..],
+                })
+                .filter_map(|member_type| {
+                    if !seen.contains(&&**member_type) {
+                        seen.push(member_type);
+                        return None;
+                    }
+                    Some(ConfigWarning::new(
+                        "member-ordering",
+                        option,
+                        format!(
+                            "`{member_type}` appears more than once in `{option}`, so only its first position is used."
+                        ),
+                    ))
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+    }
+}
+
+/// Same as upstream's `defaultOrder`: for each kind of member, by
+/// accessibility and scope, then the decorated ones, then progressively less
+/// specific groups.
+fn get_default_order() -> Vec<MemberType> {
+    let mut order = vec!["signature".to_owned(), "call-signature".to_owned()];
+    for kind in [
+        "field",
+        "static-initialization",
+        "constructor",
+        "accessor",
+        "get",
+        "set",
+        "method",
+    ] {
+        match kind {
+            "static-initialization" => order.push(kind.to_owned()),
+            "constructor" => {
+                for accessibility in ["public", "protected", "private"] {
+                    order.push(format!("{accessibility}-{kind}"));
+                }
+                order.push(kind.to_owned());
+            }
+            _ => {
+                for accessibility in ["public", "protected", "private", "#private"] {
+                    order.push(format!("{accessibility}-static-{kind}"));
+                }
+                for accessibility in ["public", "protected", "private"] {
+                    order.push(format!("{accessibility}-decorated-{kind}"));
+                }
+                for accessibility in ["public", "protected", "private", "#private"] {
+                    order.push(format!("{accessibility}-instance-{kind}"));
+                }
+                for accessibility in ["public", "protected"] {
+                    order.push(format!("{accessibility}-abstract-{kind}"));
+                }
+                for accessibility in ["public", "protected", "private", "#private"] {
+                    order.push(format!("{accessibility}-{kind}"));
+                }
+                for scope in ["static", "instance", "abstract"] {
+                    order.push(format!("{scope}-{kind}"));
+                }
+                order.push(format!("decorated-{kind}"));
+                order.push(kind.to_owned());
+            }
+        }
+    }
+    order.into_iter().map(MemberType::Single).collect()
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MemberKind {
+    CallSignature,
+    Constructor,
+    Accessor,
+    Field,
+    ReadonlyField,
+    Get,
+    Method,
+    Set,
+    Signature,
+    ReadonlySignature,
+    StaticInitialization,
+}
+
+impl MemberKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::CallSignature => "call-signature",
+            Self::Constructor => "constructor",
+            Self::Accessor => "accessor",
+            Self::Field => "field",
+            Self::ReadonlyField => "readonly-field",
+            Self::Get => "get",
+            Self::Method => "method",
+            Self::Set => "set",
+            Self::Signature => "signature",
+            Self::ReadonlySignature => "readonly-signature",
+            Self::StaticInitialization => "static-initialization",
+        }
+    }
+
+    fn is_decoratable(self) -> bool {
+        matches!(
+            self,
+            Self::ReadonlyField
+                | Self::Field
+                | Self::Method
+                | Self::Accessor
+                | Self::Get
+                | Self::Set
+        )
+    }
+}
+
+fn get_accessor_or_method_kind(node: Node) -> MemberKind {
+    if has_keyword_before_name(node, &["get"]) {
+        MemberKind::Get
+    } else if has_keyword_before_name(node, &["set"]) {
+        MemberKind::Set
+    } else {
+        MemberKind::Method
+    }
+}
+
+fn get_member_kind<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Option<MemberKind> {
+    Some(match node.kind() {
+        MethodDefinition => match get_method_definition_kind(node, context) {
+            MethodDefinitionKind::Constructor => MemberKind::Constructor,
+            MethodDefinitionKind::Get => MemberKind::Get,
+            MethodDefinitionKind::Set => MemberKind::Set,
+            _ => MemberKind::Method,
+        },
+        MethodSignature
+            if node.parent().unwrap().kind() == ClassBody
+                && node.field("name").text(context) == "constructor" =>
+        {
+            MemberKind::Constructor
+        }
+        MethodSignature | AbstractMethodSignature => get_accessor_or_method_kind(node),
+        CallSignature => MemberKind::CallSignature,
+        ConstructSignature => MemberKind::Constructor,
+        PublicFieldDefinition => {
+            if has_keyword_before_name(node, &["accessor"]) {
+                MemberKind::Accessor
+            } else if node
+                .child_by_field_name("value")
+                .is_some_and(|value| matches!(value.kind(), ArrowFunction | Function))
+            {
+                MemberKind::Method
+            } else if has_keyword_before_name(node, &["readonly"]) {
+                MemberKind::ReadonlyField
+            } else {
+                MemberKind::Field
+            }
+        }
+        PropertySignature => {
+            if has_keyword_before_name(node, &["readonly"]) {
+                MemberKind::ReadonlyField
+            } else {
+                MemberKind::Field
+            }
+        }
+        IndexSignature if is_index_signature(node) => {
+            if node.has_child_of_kind("readonly") {
+                MemberKind::ReadonlySignature
+            } else {
+                MemberKind::Signature
+            }
+        }
+        ClassStaticBlock => MemberKind::StaticInitialization,
+        _ => return None,
+    })
+}
+
+fn get_accessibility<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Cow<'a, str> {
+    if matches!(
+        node.kind(),
+        PublicFieldDefinition
+            | MethodSignature
+            | AbstractMethodSignature
+            | MethodDefinition
+            | PropertySignature
+    ) {
+        if let Some(accessibility_modifier) = get_accessibility_modifier(node) {
+            return accessibility_modifier.text(context);
+        }
+        if node.field("name").kind() == PrivatePropertyIdentifier {
+            return "#private".into();
+        }
+    }
+    "public".into()
+}
+
+fn get_scope(node: Node) -> &'static str {
+    let is_static = matches!(
+        node.kind(),
+        MethodDefinition | MethodSignature | AbstractMethodSignature | PublicFieldDefinition
+    ) && is_member_static(node);
+    if is_static {
+        "static"
+    } else if node.kind() == AbstractMethodSignature
+        || node.kind() == PublicFieldDefinition && has_keyword_before_name(node, &["abstract"])
+    {
+        "abstract"
+    } else {
+        "instance"
+    }
+}
+
+fn get_member_name<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Cow<'a, str> {
+    match node.kind() {
+        MethodDefinition
+            if get_method_definition_kind(node, context) == MethodDefinitionKind::Constructor =>
+        {
+            "constructor".into()
+        }
+        MethodDefinition
+        | MethodSignature
+        | AbstractMethodSignature
+        | PropertySignature
+        | PublicFieldDefinition => get_name_from_member(node, context).name,
+        ConstructSignature => "new".into(),
+        CallSignature => "call".into(),
+        IndexSignature => node
+            .non_comment_named_children(SupportedLanguage::Javascript)
+            .find(|child| child.kind() == Identifier)
+            .map_or_else(|| "".into(), |name| name.text(context)),
+        ClassStaticBlock => "static block".into(),
+        _ => unreachable!(),
+    }
+}
+
+fn is_member_optional(node: Node) -> bool {
+    match node.kind() {
+        PropertySignature
+        | MethodSignature
+        | AbstractMethodSignature
+        | PublicFieldDefinition
+        | MethodDefinition => node
+            .non_comment_children_and_field_names(SupportedLanguage::Javascript)
+            .skip_while(|(_, field_name)| *field_name != Some("name"))
+            .nth(1)
+            .is_some_and(|(child, _)| child.kind() == "?"),
+        _ => false,
+    }
+}
+
+/// All of the member groups that the member belongs to, most specific first
+/// (eg `public-decorated-field`, `decorated-field`,
+/// `public-instance-field`, `instance-field`, `public-field`, `field`).
+fn get_member_groups<'a>(
+    node: Node<'a>,
+    kind: MemberKind,
+    supports_modifiers: bool,
+    context: &QueryMatchContext<'a, '_>,
+) -> Vec<String> {
+    let type_ = kind.as_str();
+    let mut member_groups = vec![];
+
+    if supports_modifiers {
+        let accessibility = get_accessibility(node, context);
+        let scope = get_scope(node);
+
+        if kind.is_decoratable() && !get_member_decorators(node).is_empty() {
+            member_groups.push(format!("{accessibility}-decorated-{type_}"));
+            member_groups.push(format!("decorated-{type_}"));
+
+            if kind == MemberKind::ReadonlyField {
+                member_groups.push(format!("{accessibility}-decorated-field"));
+                member_groups.push("decorated-field".to_owned());
+            }
+        }
+
+        if !matches!(
+            kind,
+            MemberKind::ReadonlySignature
+                | MemberKind::Signature
+                | MemberKind::StaticInitialization
+        ) {
+            // constructors have no scope
+            if kind != MemberKind::Constructor {
+                member_groups.push(format!("{accessibility}-{scope}-{type_}"));
+                member_groups.push(format!("{scope}-{type_}"));
+            }
+
+            member_groups.push(format!("{accessibility}-{type_}"));
+            if kind == MemberKind::ReadonlyField {
+                member_groups.push(format!("{accessibility}-{scope}-field"));
+                member_groups.push(format!("{scope}-field"));
+                member_groups.push(format!("{accessibility}-field"));
+            }
+        }
+    }
+
+    member_groups.push(type_.to_owned());
+    match kind {
+        MemberKind::ReadonlySignature => member_groups.push("signature".to_owned()),
+        MemberKind::ReadonlyField => member_groups.push("field".to_owned()),
+        _ => (),
+    }
+
+    member_groups
+}
+
+/// The index of the first entry in `order` that the member's most specific
+/// listed group appears in, or `None` if none of its groups are listed.
+fn get_rank<'a>(
+    node: Node<'a>,
+    order: &[MemberType],
+    supports_modifiers: bool,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<usize> {
+    let Some(kind) = get_member_kind(node, context) else {
+        // shouldn't happen but just in case, put it on the end
+        return order.len().checked_sub(1);
+    };
+
+    get_member_groups(node, kind, supports_modifiers, context)
+        .iter()
+        .find_map(|member_group| {
+            order
+                .iter()
+                .position(|member_type| member_type.contains(member_group))
+        })
+}
+
+/// The description of the lowest-ranked group already seen that the member
+/// with rank `target` should've come before.
+fn get_lowest_rank(ranks: &[usize], target: usize, order: &[MemberType]) -> String {
+    let lowest = ranks
+        .iter()
+        .copied()
+        .filter(|&rank| rank > target)
+        .fold(*ranks.last().unwrap(), usize::min);
+    order[lowest].description()
+}
+
+/// Compares strings the way the `natural-compare` package does, ie with runs
+/// of digits compared by numeric value.
+fn natural_compare(a: &str, b: &str) -> Ordering {
+    let mut a_chars = a.chars().peekable();
+    let mut b_chars = b.chars().peekable();
+    loop {
+        match (a_chars.peek().copied(), b_chars.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a_char), Some(b_char)) if a_char.is_ascii_digit() && b_char.is_ascii_digit() => {
+                let mut a_digits = String::new();
+                while let Some(digit) = a_chars.next_if(char::is_ascii_digit) {
+                    a_digits.push(digit);
+                }
+                let mut b_digits = String::new();
+                while let Some(digit) = b_chars.next_if(char::is_ascii_digit) {
+                    b_digits.push(digit);
+                }
+                let a_digits = a_digits.trim_start_matches('0');
+                let b_digits = b_digits.trim_start_matches('0');
+                match a_digits
+                    .len()
+                    .cmp(&b_digits.len())
+                    .then_with(|| a_digits.cmp(b_digits))
+                {
+                    Ordering::Equal => (),
+                    ordering => return ordering,
+                }
+            }
+            (Some(a_char), Some(b_char)) => {
+                if a_char != b_char {
+                    return a_char.cmp(&b_char);
+                }
+                a_chars.next();
+                b_chars.next();
+            }
+        }
+    }
+}
+
+fn natural_out_of_order(name: &str, previous_name: &str, order: Order) -> bool {
+    if name == previous_name {
+        return false;
+    }
+    match order {
+        Order::AsWritten => false,
+        Order::Alphabetically => name < previous_name,
+        Order::AlphabeticallyCaseInsensitive => name.to_lowercase() < previous_name.to_lowercase(),
+        Order::Natural => natural_compare(name, previous_name) != Ordering::Greater,
+        Order::NaturalCaseInsensitive => {
+            natural_compare(&name.to_lowercase(), &previous_name.to_lowercase())
+                != Ordering::Greater
+        }
+    }
+}
+
+/// Runs of adjacent members that have the same rank.
+fn group_members_by_type<'a>(
+    members: &[Node<'a>],
+    member_types: &[MemberType],
+    supports_modifiers: bool,
+    context: &QueryMatchContext<'a, '_>,
+) -> Vec<Vec<Node<'a>>> {
+    let mut grouped_members: Vec<Vec<Node<'a>>> = vec![];
+    let member_ranks = members
+        .iter()
+        .map(|&member| get_rank(member, member_types, supports_modifiers, context))
+        .collect::<Vec<_>>();
+    let mut previous_rank: Option<Option<usize>> = None;
+    for (index, &member) in members.iter().enumerate() {
+        if index == members.len() - 1 {
+            break;
+        }
+        let rank_of_current_member = member_ranks[index];
+        let rank_of_next_member = member_ranks[index + 1];
+        if previous_rank == Some(rank_of_current_member) {
+            if let Some(group) = grouped_members.last_mut() {
+                group.push(member);
+            }
+        } else if rank_of_current_member == rank_of_next_member {
+            grouped_members.push(vec![member]);
+            previous_rank = Some(rank_of_current_member);
+        }
+    }
+    grouped_members
+}
+
+struct MembersOrderChecker<'a, 'b, 'c> {
+    context: &'b QueryMatchContext<'a, 'c>,
+    members: &'b [Node<'a>],
+    member_types: Option<&'b [MemberType]>,
+    order: Order,
+    supports_modifiers: bool,
+}
+
+impl<'a> MembersOrderChecker<'a, '_, '_> {
+    fn check(&self, optionality_order: Option<OptionalityOrder>) {
+        let Some(optionality_order) = optionality_order else {
+            self.check_order(self.members);
+            return;
+        };
+
+        match self.get_optionality_switch_index(self.members) {
+            Some(switch_index) => {
+                if !self.check_required_order(switch_index, optionality_order) {
+                    return;
+                }
+                self.check_order(&self.members[..switch_index]);
+                self.check_order(&self.members[switch_index..]);
+            }
+            None => self.check_order(self.members),
+        }
+    }
+
+    fn get_optionality_switch_index(&self, members: &[Node<'a>]) -> Option<usize> {
+        (1..members.len()).find(|&index| {
+            is_member_optional(members[index]) != is_member_optional(members[index - 1])
+        })
+    }
+
+    fn check_required_order(
+        &self,
+        switch_index: usize,
+        optionality_order: OptionalityOrder,
+    ) -> bool {
+        let report = |member: Node<'a>| {
+            self.context.report(violation! {
+                node => member,
+                message_id => "incorrect_required_members_order",
+                data => {
+                    member => get_member_name(member, self.context),
+                    optional_or_required => match optionality_order {
+                        OptionalityOrder::RequiredFirst => "required",
+                        OptionalityOrder::OptionalFirst => "optional",
+                    },
+                },
+            });
+        };
+
+        if is_member_optional(self.members[0])
+            != (optionality_order == OptionalityOrder::OptionalFirst)
+        {
+            report(self.members[0]);
+            return false;
+        }
+        let switch_member_is_optional = is_member_optional(self.members[switch_index]);
+        if self.members[switch_index + 1..]
+            .iter()
+            .any(|&member| is_member_optional(member) != switch_member_is_optional)
+        {
+            report(self.members[switch_index]);
+            return false;
+        }
+        true
+    }
+
+    fn has_alpha_sort(&self) -> bool {
+        self.order != Order::AsWritten
+    }
+
+    fn check_order(&self, member_set: &[Node<'a>]) {
+        match self.member_types {
+            Some(member_types) => {
+                let Some(grouped) = self.check_group_sort(member_set, member_types) else {
+                    self.check_alpha_sort_for_all_members(self.members);
+                    return;
+                };
+
+                if self.has_alpha_sort() {
+                    for group_members in grouped {
+                        self.check_alpha_sort(&group_members);
+                    }
+                }
+            }
+            None => {
+                if self.has_alpha_sort() {
+                    self.check_alpha_sort(member_set);
+                }
+            }
+        }
+    }
+
+    fn check_alpha_sort_for_all_members(&self, member_set: &[Node<'a>]) {
+        let Some(member_types) = self.member_types.filter(|_| self.has_alpha_sort()) else {
+            return;
+        };
+        for members in group_members_by_type(
+            member_set,
+            member_types,
+            self.supports_modifiers,
+            self.context,
+        ) {
+            self.check_alpha_sort(&members);
+        }
+    }
+
+    /// Reports members that come after a member of a later-ranked group.
+    /// Returns the members grouped by rank if they're all in order.
+    fn check_group_sort(
+        &self,
+        members: &[Node<'a>],
+        member_types: &[MemberType],
+    ) -> Option<Vec<Vec<Node<'a>>>> {
+        let mut previous_ranks: Vec<usize> = vec![];
+        let mut member_groups: Vec<Vec<Node<'a>>> = vec![];
+        let mut is_correctly_sorted = true;
+
+        for &member in members {
+            let Some(rank) = get_rank(member, member_types, self.supports_modifiers, self.context)
+            else {
+                continue;
+            };
+            let rank_last_member = previous_ranks.last().copied();
+
+            match rank_last_member {
+                Some(rank_last_member) if rank < rank_last_member => {
+                    self.context.report(violation! {
+                        node => member,
+                        message_id => "incorrect_group_order",
+                        data => {
+                            name => get_member_name(member, self.context),
+                            rank => get_lowest_rank(&previous_ranks, rank, member_types),
+                        },
+                    });
+
+                    is_correctly_sorted = false;
+                }
+                Some(rank_last_member) if rank == rank_last_member => {
+                    member_groups.last_mut().unwrap().push(member);
+                }
+                _ => {
+                    previous_ranks.push(rank);
+                    member_groups.push(vec![member]);
+                }
+            }
+        }
+
+        is_correctly_sorted.then_some(member_groups)
+    }
+
+    fn check_alpha_sort(&self, members: &[Node<'a>]) {
+        let mut previous_name: Cow<'a, str> = "".into();
+
+        for &member in members {
+            let name = get_member_name(member, self.context);
+            if name.is_empty() {
+                continue;
+            }
+            if natural_out_of_order(&name, &previous_name, self.order) {
+                self.context.report(violation! {
+                    node => member,
+                    message_id => "incorrect_order",
+                    data => {
+                        member => name.clone(),
+                        before_member => previous_name.clone(),
+                    },
+                });
+            }
+
+            previous_name = name;
+        }
+    }
+}
+
+fn validate_members_order<'a>(
+    members: &[Node<'a>],
+    order_config: &OrderConfig,
+    supports_modifiers: bool,
+    context: &QueryMatchContext<'a, '_>,
+) {
+    let (member_types, order, optionality_order) = match order_config {
+        OrderConfig::Never(_) => return,
+        OrderConfig::MemberTypes(member_types) => (Some(&**member_types), Order::AsWritten, None),
+        OrderConfig::SortedOrderConfig(config) => (
+            match config.member_types.as_ref() {
+                Some(MemberTypes::MemberTypes(member_types)) => Some(&**member_types),
+                _ => None,
+            },
+            config.order.unwrap_or_default(),
+            config.optionality_order,
+        ),
+    };
+
+    MembersOrderChecker {
+        context,
+        members,
+        member_types,
+        order,
+        supports_modifiers,
+    }
+    .check(optionality_order);
+}
+
+fn get_members<'a>(body: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Vec<Node<'a>> {
+    body.non_comment_named_children(SupportedLanguage::Javascript)
+        .filter(|&member| member.kind() != Decorator && get_member_kind(member, context).is_some())
+        .collect()
+}
+
+pub fn member_ordering_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "member-ordering",
+        languages => [Typescript],
+        messages => [
+            incorrect_group_order => "Member {{name}} should be declared before all {{rank}} definitions.",
+            incorrect_order => "Member {{member}} should be declared before member {{before_member}}.",
+            incorrect_required_members_order => "Member {{member}} should be declared after all {{optional_or_required}} members.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            classes: OrderConfig = options.classes(),
+            class_expressions: OrderConfig = options.class_expressions(),
+            interfaces: OrderConfig = options.interfaces(),
+            type_literals: OrderConfig = options.type_literals(),
+        },
+        listeners => [
+            r#"
+              (class_declaration) @c
+              (abstract_class_declaration) @c
+            "# => |node, context| {
+                validate_members_order(
+                    &get_members(node.field("body"), context),
+                    &self.classes,
+                    true,
+                    context,
+                );
+            },
+            r#"
+              (class) @c
+            "# => |node, context| {
+                validate_members_order(
+                    &get_members(node.field("body"), context),
+                    &self.class_expressions,
+                    true,
+                    context,
+                );
+            },
+            r#"
+              (interface_declaration) @c
+            "# => |node, context| {
+                validate_members_order(
+                    &get_members(node.field("body"), context),
+                    &self.interfaces,
+                    false,
+                    context,
+                );
+            },
+            r#"
+              (object_type) @c
+            "# => |node, context| {
+                if !is_type_literal(node) {
+                    return;
+                }
+                validate_members_order(
+                    &get_members(node, context),
+                    &self.type_literals,
+                    false,
+                    context,
+                );
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, serde_json::json, RuleTests};
+
+    use crate::test_helpers::{expected_error, GeneratedCases, OptionMatrix};
+
+    /// Pairs of member names in the order they're written, with the `order`s
+    /// that report the second one as belonging before the first.
+    const SORT_ORDER_CASES: [(&str, &str, &[&str]); 5] = [
+        ("a", "b", &[]),
+        (
+            "b",
+            "a",
+            &[
+                "alphabetically",
+                "alphabetically-case-insensitive",
+                "natural",
+                "natural-case-insensitive",
+            ],
+        ),
+        (
+            "B",
+            "a",
+            &[
+                "alphabetically-case-insensitive",
+                "natural-case-insensitive",
+            ],
+        ),
+        ("a10", "a2", &["natural", "natural-case-insensitive"]),
+        (
+            "a",
+            "A",
+            &["alphabetically", "natural", "natural-case-insensitive"],
+        ),
+    ];
+
+    fn sort_order_cases() -> GeneratedCases {
+        OptionMatrix::default()
+            .axis("member_types", ["never"])
+            .axis(
+                "order",
+                [
+                    "as-written",
+                    "alphabetically",
+                    "alphabetically-case-insensitive",
+                    "natural",
+                    "natural-case-insensitive",
+                ],
+            )
+            .wrap(|combination| json!({ "default": combination }))
+            .generate(|cases| {
+                let order = cases.str_option("order").unwrap();
+                for (first, second, reporting_orders) in SORT_ORDER_CASES {
+                    let code = format!(
+                        "\ninterface Foo {{\n  {first}: string;\n  {second}: string;\n}}\n"
+                    );
+                    if !reporting_orders.contains(&order) {
+                        cases.valid(code);
+                        continue;
+                    }
+                    cases.invalid(
+                        code,
+                        vec![expected_error(
+                            "incorrect_order",
+                            [("member", second), ("before_member", first)],
+                        )
+                        .line(4)
+                        .column(3)
+                        .build()
+                        .unwrap()],
+                    );
+                }
+            })
+    }
+
+    pub fn member_ordering_rule_tests() -> RuleTests {
+        let sort_order_cases = sort_order_cases();
+        rule_tests! {
+            valid => [
+                ...sort_order_cases.valid,
+                "
+interface Foo {
+  [Z: string]: any;
+  A: string;
+  B: string;
+  C: string;
+  D: string;
+  E: string;
+  F: string;
+  new ();
+  G();
+  H();
+  I();
+  J();
+  K();
+  L();
+}
+                    ",
+                {
+                  code => "
+interface Foo {
+  A: string;
+  J();
+  K();
+  D: string;
+  E: string;
+  F: string;
+}
+                      ",
+                  options => { default => "never" },
+                },
+                "
+type Foo = {
+  [Z: string]: any;
+  A: string;
+  B: string;
+  G();
+  H();
+};
+                    ",
+                "
+class Foo {
+  public static A: string;
+  protected static B: string = '';
+  private static C: string = '';
+  static #D: string = '';
+  public E: string = '';
+  protected F: string = '';
+  private G: string = '';
+  #H: string = '';
+  constructor() {}
+  public J() {}
+  protected K() {}
+  private L() {}
+  #M() {}
+}
+                    ",
+                "
+class Foo {
+  @Dec() A: string;
+  @Dec() private B: string;
+  C: string;
+  constructor() {}
+  @Dec() get D() {
+    return '';
+  }
+  E() {}
+}
+                    ",
+                "
+class Foo {
+  @Dec() public A: string;
+  @Dec() protected B: string;
+  @Dec() private C: string;
+  D: string;
+}
+                    ",
+                {
+                  code => "
+class Foo {
+  @Dec() A: string;
+  B: string;
+  @Dec() C() {}
+  D() {}
+}
+                      ",
+                  options => {
+                    default => ["decorated-field", "field", "decorated-method", "method"],
+                  },
+                },
+                {
+                  code => "
+class Foo {
+  B: string;
+  @Dec() A: string;
+  D() {}
+  @Dec() C() {}
+}
+                      ",
+                  options => {
+                    default => ["field", "decorated-field", "method", "decorated-method"],
+                  },
+                },
+                {
+                  code => "
+class Foo {
+  @Dec() set A(value: string) {}
+  @Dec() get B() {
+    return '';
+  }
+  @Dec() C() {}
+}
+                      ",
+                  options => {
+                    default => ["decorated-set", "decorated-get", "decorated-method"],
+                  },
+                },
+                {
+                  code => "
+class Foo {
+  @Dec() private A: string;
+  B: string;
+  @Dec() public C: string;
+}
+                      ",
+                  options => {
+                    default => ["private-decorated-field", "field", "decorated-field"],
+                  },
+                },
+                {
+                  code => "
+class Foo {
+  @Dec() readonly A: string;
+  @Dec() B: string;
+  C: string;
+}
+                      ",
+                  options => {
+                    default => ["decorated-readonly-field", "decorated-field", "field"],
+                  },
+                },
+                {
+                  code => "
+class Foo {
+  @Dec() A: string;
+  B: string;
+  @Dec() C: string;
+}
+                      ",
+                  options => {
+                    default => ["field"],
+                  },
+                },
+                {
+                  code => "
+class Foo {
+  A: string;
+  constructor() {}
+  B: string;
+  C() {}
+}
+                      ",
+                  options => {
+                    default => [["field", "constructor"], "method"],
+                  },
+                },
+                {
+                  code => "
+interface Foo {
+  a: string;
+  b?: string;
+  c(): void;
+}
+                      ",
+                  options => {
+                    default => {
+                      member_types => ["field", "method"],
+                      order => "alphabetically",
+                    },
+                  },
+                },
+                {
+                  code => "
+interface Foo {
+  b: string;
+  c: string;
+  a?: string;
+}
+                      ",
+                  options => {
+                    default => {
+                      member_types => "never",
+                      order => "alphabetically",
+                      optionality_order => "required-first",
+                    },
+                  },
+                },
+                {
+                  code => "
+interface Foo {
+  a1: string;
+  a2: string;
+  a10: string;
+}
+                      ",
+                  options => {
+                    default => {
+                      member_types => "never",
+                      order => "natural",
+                    },
+                  },
+                },
+            ],
+            invalid => [
+                ...sort_order_cases.invalid,
+                {
+                  code => "
+interface Foo {
+  A: string;
+  G();
+  B: string;
+}
+                      ",
+                  errors => [
+                    {
+                      message_id => "incorrect_group_order",
+                      data => {
+                        name => "B",
+                        rank => "method",
+                      },
+                      line => 5,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => "
+class Foo {
+  constructor() {}
+  A: string;
+}
+                      ",
+                  errors => [
+                    {
+                      message_id => "incorrect_group_order",
+                      data => {
+                        name => "A",
+                        rank => "public constructor",
+                      },
+                      line => 4,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => "
+class Foo {
+  A: string;
+  @Dec() B: string;
+}
+                      ",
+                  errors => [
+                    {
+                      message_id => "incorrect_group_order",
+                      data => {
+                        name => "B",
+                        rank => "public instance field",
+                      },
+                      line => 4,
+                    },
+                  ],
+                },
+                {
+                  code => "
+class Foo {
+  @Dec() private A: string;
+  @Dec() public B: string;
+}
+                      ",
+                  errors => [
+                    {
+                      message_id => "incorrect_group_order",
+                      data => {
+                        name => "B",
+                        rank => "private decorated field",
+                      },
+                      line => 4,
+                    },
+                  ],
+                },
+                {
+                  code => "
+class Foo {
+  @Dec() A() {}
+  B: string;
+}
+                      ",
+                  errors => [
+                    {
+                      message_id => "incorrect_group_order",
+                      data => {
+                        name => "B",
+                        rank => "public decorated method",
+                      },
+                      line => 4,
+                    },
+                  ],
+                },
+                {
+                  code => "
+class Foo {
+  @Dec() C() {}
+  D() {}
+  @Dec() A: string;
+  B: string;
+}
+                      ",
+                  options => {
+                    default => ["decorated-field", "field", "decorated-method", "method"],
+                  },
+                  errors => [
+                    {
+                      message_id => "incorrect_group_order",
+                      data => {
+                        name => "A",
+                        rank => "decorated method",
+                      },
+                      line => 5,
+                    },
+                    {
+                      message_id => "incorrect_group_order",
+                      data => {
+                        name => "B",
+                        rank => "decorated method",
+                      },
+                      line => 6,
+                    },
+                  ],
+                },
+                {
+                  code => "
+class Foo {
+  @Dec() get A() {
+    return '';
+  }
+  @Dec() set B(value: string) {}
+}
+                      ",
+                  options => {
+                    default => ["decorated-set", "decorated-get"],
+                  },
+                  errors => [
+                    {
+                      message_id => "incorrect_group_order",
+                      data => {
+                        name => "B",
+                        rank => "decorated get",
+                      },
+                      line => 6,
+                    },
+                  ],
+                },
+                {
+                  code => "
+class Foo {
+  B: string;
+  @Dec() A: string;
+}
+                      ",
+                  options => {
+                    default => ["decorated-field", "public-instance-field"],
+                  },
+                  errors => [
+                    {
+                      message_id => "incorrect_group_order",
+                      data => {
+                        name => "A",
+                        rank => "public instance field",
+                      },
+                      line => 4,
+                    },
+                  ],
+                },
+                {
+                  code => "
+class Foo {
+  @Dec() readonly A: string;
+  @Dec() B: string;
+}
+                      ",
+                  options => {
+                    default => ["decorated-field", "decorated-readonly-field"],
+                  },
+                  errors => [
+                    {
+                      message_id => "incorrect_group_order",
+                      data => {
+                        name => "B",
+                        rank => "decorated readonly field",
+                      },
+                      line => 4,
+                    },
+                  ],
+                },
+                {
+                  code => "
+const foo = class Foo {
+  public static A() {}
+  public static B: string;
+};
+                      ",
+                  errors => [
+                    {
+                      message_id => "incorrect_group_order",
+                      data => {
+                        name => "B",
+                        rank => "public static method",
+                      },
+                      line => 4,
+                    },
+                  ],
+                },
+                {
+                  code => "
+type Foo = {
+  A(): void;
+  B: string;
+};
+                      ",
+                  options => {
+                    type_literals => ["field", "method"],
+                  },
+                  errors => [
+                    {
+                      message_id => "incorrect_group_order",
+                      data => {
+                        name => "B",
+                        rank => "method",
+                      },
+                      line => 4,
+                    },
+                  ],
+                },
+                {
+                  code => "
+interface Foo {
+  b: string;
+  a: string;
+  d(): void;
+  c(): void;
+}
+                      ",
+                  options => {
+                    default => {
+                      member_types => ["field", "method"],
+                      order => "alphabetically",
+                    },
+                  },
+                  errors => [
+                    {
+                      message_id => "incorrect_order",
+                      data => {
+                        member => "a",
+                        before_member => "b",
+                      },
+                      line => 4,
+                    },
+                    {
+                      message_id => "incorrect_order",
+                      data => {
+                        member => "c",
+                        before_member => "d",
+                      },
+                      line => 6,
+                    },
+                  ],
+                },
+                {
+                  code => "
+interface Foo {
+  a?: string;
+  b: string;
+}
+                      ",
+                  options => {
+                    default => {
+                      member_types => "never",
+                      optionality_order => "required-first",
+                    },
+                  },
+                  errors => [
+                    {
+                      message_id => "incorrect_required_members_order",
+                      data => {
+                        member => "a",
+                        optional_or_required => "required",
+                      },
+                      line => 3,
+                    },
+                  ],
+                },
+                {
+                  code => "
+interface Foo {
+  a: string;
+  b?: string;
+  c: string;
+}
+                      ",
+                  options => {
+                    default => {
+                      member_types => "never",
+                      optionality_order => "required-first",
+                    },
+                  },
+                  errors => [
+                    {
+                      message_id => "incorrect_required_members_order",
+                      data => {
+                        member => "b",
+                        optional_or_required => "required",
+                      },
+                      line => 4,
+                    },
+                  ],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_member_ordering_rule() {
+        run_for_typescript_and_tsx(
+            member_ordering_rule(),
+            test_cases::member_ordering_rule_tests(),
+        );
+    }
+}
@@ -0,0 +1,547 @@
+use std::{borrow::Cow, sync::Arc};
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    range_between_starts, rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation,
+    NodeExt, QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    ComputedPropertyName, Number, String as StringKind,
+};
+
+use crate::{
+    ast_helpers::{is_global_ambient_declaration, NodeExtTypescript},
+    kind::{FunctionType, InterfaceBody, InternalModule, MethodSignature, Module, ObjectType},
+    util::{get_name_from_member, MemberName},
+};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum Options {
+    #[default]
+    Property,
+    Method,
+}
+
+#[derive(PartialEq, Eq)]
+struct MethodKey<'a> {
+    name: MemberName<'a>,
+    // `[foo]` names a different member than `foo` (unlike `["foo"]`)
+    is_computed_expression: bool,
+    is_optional: bool,
+    is_readonly: bool,
+}
+
+fn get_method_key<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> MethodKey<'a> {
+    let name = node.field("name");
+    MethodKey {
+        name: get_name_from_member(node, context),
+        is_computed_expression: name.kind() == ComputedPropertyName
+            && !matches!(
+                name.first_non_comment_named_child(SupportedLanguage::Javascript)
+                    .kind(),
+                StringKind | Number
+            ),
+        is_optional: is_optional(node),
+        is_readonly: is_readonly(node),
+    }
+}
+
+fn get_modifier_tokens(node: Node) -> impl Iterator<Item = Node> {
+    node.non_comment_children_and_field_names(SupportedLanguage::Javascript)
+        .take_while(|(_, field_name)| *field_name != Some("name"))
+        .map(|(child, _)| child)
+}
+
+fn is_readonly(node: Node) -> bool {
+    get_modifier_tokens(node).any(|child| child.kind() == "readonly")
+}
+
+fn is_optional(node: Node) -> bool {
+    node.field("name")
+        .next_sibling()
+        .is_some_and(|next| next.kind() == "?")
+}
+
+// ie not a `get`/`set` signature
+fn is_method(node: Node) -> bool {
+    !get_modifier_tokens(node).any(|child| matches!(child.kind(), "get" | "set"))
+}
+
+fn get_method_key_text<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> String {
+    format!(
+        "{}{}{}",
+        if is_readonly(node) { "readonly " } else { "" },
+        node.field("name").text(context),
+        if is_optional(node) { "?" } else { "" },
+    )
+}
+
+fn get_method_params<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> String {
+    format!(
+        "{}{}",
+        node.child_by_field_name("type_parameters")
+            .map(|type_parameters| type_parameters.text(context))
+            .unwrap_or_default(),
+        node.field("parameters").text(context),
+    )
+}
+
+fn get_method_return_type<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Cow<'a, str> {
+    node.child_by_field_name("return_type")
+        .map_or(Cow::Borrowed("any"), |return_type| {
+            return_type
+                .first_non_comment_named_child(SupportedLanguage::Javascript)
+                .text(context)
+        })
+}
+
+fn is_in_module_declaration(node: Node) -> bool {
+    node.ancestors().any(|ancestor| {
+        matches!(ancestor.kind(), InternalModule | Module)
+            || is_global_ambient_declaration(ancestor)
+    })
+}
+
+pub fn method_signature_style_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "method-signature-style",
+        languages => [Typescript],
+        messages => [
+            error_method => "Shorthand method signature is forbidden. Use a function property instead.",
+            error_property => "Function property signature is forbidden. Use a method shorthand instead.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            mode: Options = options,
+        },
+        listeners => [
+            r#"
+              (method_signature) @c
+            "# => |node, context| {
+                if self.mode != Options::Property {
+                    return;
+                }
+                // (class bodies have method signatures too, for overloads)
+                let parent = node.parent().unwrap();
+                if !matches!(parent.kind(), InterfaceBody | ObjectType) || !is_method(node) {
+                    return;
+                }
+
+                let key = get_method_key(node, context);
+                let duplicated_key_method_nodes = parent
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                    .filter(|&member| {
+                        member.kind() == MethodSignature
+                            && member != node
+                            && is_method(member)
+                            && get_method_key(member, context) == key
+                    })
+                    .collect::<Vec<_>>();
+                let is_fixable = !is_in_module_declaration(node);
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "error_method",
+                    data => {
+                        banned_name => "method",
+                        replacement => "property",
+                        option => "property",
+                    },
+                    fix => |fixer| {
+                        if !is_fixable {
+                            return;
+                        }
+
+                        let key_text = get_method_key_text(node, context);
+                        if duplicated_key_method_nodes.is_empty() {
+                            let mut type_string = format!(
+                                "{} => {}",
+                                get_method_params(node, context),
+                                get_method_return_type(node, context),
+                            );
+                            if key.is_optional {
+                                type_string = format!("({type_string})");
+                            }
+                            fixer.replace_text(node, format!("{key_text}: {type_string}"));
+                            return;
+                        }
+
+                        let mut method_nodes = duplicated_key_method_nodes.clone();
+                        method_nodes.push(node);
+                        method_nodes.sort_by_key(|method_node| method_node.start_byte());
+                        let type_string = method_nodes
+                            .iter()
+                            .map(|&method_node| {
+                                format!(
+                                    "({} => {})",
+                                    get_method_params(method_node, context),
+                                    get_method_return_type(method_node, context),
+                                )
+                            })
+                            .collect::<Vec<_>>()
+                            .join(" & ");
+                        fixer.replace_text(node, format!("{key_text}: {type_string}"));
+
+                        for &duplicated_node in &duplicated_key_method_nodes {
+                            // the delimiter is a sibling rather than part of
+                            // the member
+                            let last_token = context
+                                .maybe_get_token_after(duplicated_node, Option::<fn(Node) -> bool>::None)
+                                .filter(|token| matches!(token.kind(), ";" | ","))
+                                .unwrap_or(duplicated_node);
+                            if let Some(next_token) = context.maybe_get_token_after(
+                                last_token,
+                                Option::<fn(Node) -> bool>::None,
+                            ) {
+                                fixer.remove_range(range_between_starts(
+                                    duplicated_node.range(),
+                                    next_token.range(),
+                                ));
+                            }
+                        }
+                    }
+                });
+            },
+            r#"
+              (property_signature
+                type: (type_annotation)
+              ) @c
+            "# => |node, context| {
+                if self.mode != Options::Method {
+                    return;
+                }
+                let type_node = node
+                    .field("type")
+                    .first_non_comment_named_child(SupportedLanguage::Javascript)
+                    .skip_parenthesized_types();
+                if type_node.kind() != FunctionType {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "error_property",
+                    data => {
+                        banned_name => "property",
+                        replacement => "method",
+                        option => "method",
+                    },
+                    fix => |fixer| {
+                        fixer.replace_text(
+                            node,
+                            format!(
+                                "{}{}: {}",
+                                get_method_key_text(node, context),
+                                get_method_params(type_node, context),
+                                get_method_return_type(type_node, context),
+                            ),
+                        );
+                    }
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn method_signature_style_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "
+interface Test {
+  f: (a: string) => number;
+}
+                    ",
+                "
+interface Test {
+  ['f']: (a: boolean) => void;
+}
+                    ",
+                "
+interface Test {
+  f: <T>(a: T) => T;
+}
+                    ",
+                "
+interface Test {
+  ['f']: <T extends {}>(a: T, b: T) => T;
+}
+                    ",
+                "
+interface Test {
+  'f!': </* a */ T>(/* b */ x: any /* c */) => void;
+}
+                    ",
+                "
+interface Test {
+  get f(): number;
+}
+                    ",
+                "
+interface Test {
+  set f(value: number): void;
+}
+                    ",
+                "type Test = { readonly f: (a: string) => number };",
+                "type Test = { ['f']?: (a: boolean) => void };",
+                "type Test = { readonly f?: <T>(a?: T) => T };",
+                "type Test = { readonly ['f']?: <T>(a: T, b: T) => T };",
+                "type Test = { get f(): number };",
+                "type Test = { set f(value: number): void };",
+                "
+class Test {
+  foo(): void;
+  foo(a?: string): void {}
+}
+                    ",
+                {
+                  code => "
+interface Test {
+  f(a: string): number;
+}
+                      ",
+                  options => "method",
+                },
+                {
+                  code => "
+interface Test {
+  ['f'](a: boolean): void;
+}
+                      ",
+                  options => "method",
+                },
+                {
+                  code => "
+interface Test {
+  f: number;
+}
+                      ",
+                  options => "method",
+                },
+                {
+                  code => "type Test = { readonly f?: <T>(a?: T) => T } | undefined;",
+                  options => "property",
+                },
+            ],
+            invalid => [
+                {
+                  code => "
+interface Test {
+  f(a: string): number;
+}
+                      ",
+                  output => "
+interface Test {
+  f: (a: string) => number;
+}
+                      ",
+                  errors => [
+                    {
+                      message_id => "error_method",
+                      data => {
+                        banned_name => "method",
+                        replacement => "property",
+                        option => "property",
+                      },
+                      line => 3,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => "
+interface Test {
+  ['f'](a: boolean): void;
+}
+                      ",
+                  output => "
+interface Test {
+  ['f']: (a: boolean) => void;
+}
+                      ",
+                  errors => [{ message_id => "error_method" }],
+                },
+                {
+                  code => "
+interface Test {
+  f<T>(a: T): T;
+}
+                      ",
+                  output => "
+interface Test {
+  f: <T>(a: T) => T;
+}
+                      ",
+                  errors => [{ message_id => "error_method" }],
+                },
+                {
+                  code => "
+interface Test {
+  'f!'</* a */ T>(/* b */ x: any /* c */): void;
+}
+                      ",
+                  output => "
+interface Test {
+  'f!': </* a */ T>(/* b */ x: any /* c */) => void;
+}
+                      ",
+                  errors => [{ message_id => "error_method" }],
+                },
+                {
+                  code => "
+interface Test {
+  1(a: string): void;
+}
+                      ",
+                  output => "
+interface Test {
+  1: (a: string) => void;
+}
+                      ",
+                  errors => [{ message_id => "error_method" }],
+                },
+                {
+                  code => "
+interface Test {
+  f?(): void;
+}
+                      ",
+                  output => "
+interface Test {
+  f?: (() => void);
+}
+                      ",
+                  errors => [{ message_id => "error_method" }],
+                },
+                {
+                  code => "type Test = { f?<T>(a?: T): T };",
+                  output => "type Test = { f?: (<T>(a?: T) => T) };",
+                  errors => [{ message_id => "error_method" }],
+                },
+                {
+                  code => "
+interface Test {
+  f();
+}
+                      ",
+                  output => "
+interface Test {
+  f: () => any;
+}
+                      ",
+                  errors => [{ message_id => "error_method" }],
+                },
+                {
+                  code => "
+interface Foo {
+  foo(): one;
+  foo(): two;
+  foo(): three;
+}
+                      ",
+                  output => "
+interface Foo {
+  foo: (() => one) & (() => two) & (() => three);
+  }
+                      ",
+                  errors => [
+                    { message_id => "error_method", line => 3 },
+                    { message_id => "error_method", line => 4 },
+                    { message_id => "error_method", line => 5 },
+                  ],
+                },
+                {
+                  code => "
+interface Foo {
+  ['foo'](bar: string): one;
+  foo(bar: number): two;
+  [foo](): three;
+}
+                      ",
+                  output => "
+interface Foo {
+  ['foo']: ((bar: string) => one) & ((bar: number) => two);
+  [foo]: () => three;
+}
+                      ",
+                  errors => [
+                    { message_id => "error_method", line => 3 },
+                    { message_id => "error_method", line => 4 },
+                    { message_id => "error_method", line => 5 },
+                  ],
+                },
+                {
+                  code => "
+declare global {
+  namespace jest {
+    interface Matchers<R> {
+      toHaveProperty(object: object): R;
+    }
+  }
+}
+                      ",
+                  errors => [{ message_id => "error_method", line => 5 }],
+                },
+                {
+                  code => "
+interface Test {
+  f: (a: string) => number;
+}
+                      ",
+                  options => "method",
+                  output => "
+interface Test {
+  f(a: string): number;
+}
+                      ",
+                  errors => [
+                    {
+                      message_id => "error_property",
+                      data => {
+                        banned_name => "property",
+                        replacement => "method",
+                        option => "method",
+                      },
+                      line => 3,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => "
+interface Test {
+  readonly ['f']?: (<T>(a: T) => T);
+}
+                      ",
+                  options => "method",
+                  output => "
+interface Test {
+  readonly ['f']?<T>(a: T): T;
+}
+                      ",
+                  errors => [{ message_id => "error_property" }],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_method_signature_style_rule() {
+        run_for_typescript_and_tsx(
+            method_signature_style_rule(),
+            test_cases::method_signature_style_rule_tests(),
+        );
+    }
+}
@@ -1,21 +1,297 @@
+#[cfg(any(test, feature = "docs"))]
+use tree_sitter_lint::RuleTests;
+
+#[cfg(feature = "stylistic")]
 mod adjacent_overload_signatures;
+#[cfg(feature = "stylistic")]
 mod array_type;
+#[cfg(feature = "type-aware")]
+mod await_thenable;
 mod ban_ts_comment;
+#[cfg(feature = "stylistic")]
 mod ban_tslint_comment;
 mod ban_types;
+#[cfg(feature = "stylistic")]
 mod class_literal_property_style;
 mod class_methods_use_this;
+#[cfg(feature = "stylistic")]
 mod consistent_generic_constructors;
+#[cfg(feature = "stylistic")]
+mod consistent_indexed_object_style;
+#[cfg(feature = "stylistic")]
 mod consistent_type_definitions;
 mod default_param_last;
+mod explicit_function_return_type;
+mod explicit_module_boundary_types;
+mod member_ordering;
+mod method_signature_style;
+mod naming_convention;
+mod no_array_constructor;
+#[cfg(feature = "type-aware")]
+mod no_array_delete;
+mod no_array_method_misuse;
+mod no_async_promise_executor;
+#[cfg(feature = "type-aware")]
+mod no_confusing_void_expression;
+mod no_constant_condition;
+mod no_dynamic_delete;
+#[cfg(feature = "stylistic")]
+mod no_empty_function;
+#[cfg(feature = "stylistic")]
+mod no_empty_interface;
+#[cfg(feature = "formatting")]
+mod no_extra_semi;
+mod no_extraneous_class;
+#[cfg(feature = "stylistic")]
+mod no_inferrable_types;
+mod no_invalid_this;
+mod no_loss_of_precision;
+mod no_magic_numbers;
+#[cfg(feature = "type-aware")]
+mod no_meaningless_void_operator;
+mod no_misused_new;
+#[cfg(feature = "type-aware")]
+mod no_misused_promises;
+mod no_namespace;
+mod no_non_null_asserted_nullish_coalescing;
+mod no_non_null_asserted_optional_chain;
+mod no_non_null_assertion;
+mod no_require_imports;
+mod no_restricted_ts_syntax;
+mod no_shadow;
+mod no_this_alias;
+mod no_type_alias;
+#[cfg(feature = "type-aware")]
+mod no_unnecessary_boolean_literal_compare;
+#[cfg(feature = "type-aware")]
+mod no_unnecessary_type_assertion;
+#[cfg(feature = "type-aware")]
+mod no_unnecessary_type_parameters;
+#[cfg(feature = "type-aware")]
+mod no_unsafe_argument;
+#[cfg(feature = "type-aware")]
+mod no_unsafe_member_access;
+mod no_unused_private_class_members;
+mod no_useless_constructor;
+mod no_var_requires;
+mod parameter_properties;
+#[cfg(feature = "stylistic")]
+mod prefer_array_index_at;
+mod prefer_const;
+#[cfg(feature = "stylistic")]
+mod prefer_function_type;
+mod prefer_literal_enum_member;
+#[cfg(feature = "stylistic")]
+mod prefer_namespace_keyword;
+#[cfg(feature = "type-aware")]
+mod prefer_nullish_coalescing;
+#[cfg(feature = "type-aware")]
+mod prefer_string_starts_ends_with;
+#[cfg(feature = "type-aware")]
+mod restrict_plus_operands;
+#[cfg(feature = "type-aware")]
+mod restrict_template_expressions;
+#[cfg(feature = "type-aware")]
+mod return_await;
+mod typedef;
 
+#[cfg(feature = "stylistic")]
 pub use adjacent_overload_signatures::adjacent_overload_signatures_rule;
+#[cfg(feature = "stylistic")]
 pub use array_type::array_type_rule;
+#[cfg(feature = "type-aware")]
+pub use await_thenable::await_thenable_rule;
 pub use ban_ts_comment::ban_ts_comment_rule;
+#[cfg(feature = "stylistic")]
 pub use ban_tslint_comment::ban_tslint_comment_rule;
 pub use ban_types::ban_types_rule;
+#[cfg(feature = "stylistic")]
 pub use class_literal_property_style::class_literal_property_style_rule;
 pub use class_methods_use_this::class_methods_use_this_rule;
+#[cfg(feature = "stylistic")]
 pub use consistent_generic_constructors::consistent_generic_constructors_rule;
+#[cfg(feature = "stylistic")]
+pub use consistent_indexed_object_style::consistent_indexed_object_style_rule;
+#[cfg(feature = "stylistic")]
 pub use consistent_type_definitions::consistent_type_definitions_rule;
 pub use default_param_last::default_param_last_rule;
+pub use explicit_function_return_type::explicit_function_return_type_rule;
+pub use explicit_module_boundary_types::explicit_module_boundary_types_rule;
+pub use member_ordering::member_ordering_rule;
+pub use method_signature_style::method_signature_style_rule;
+pub use naming_convention::naming_convention_rule;
+pub use no_array_constructor::no_array_constructor_rule;
+#[cfg(feature = "type-aware")]
+pub use no_array_delete::no_array_delete_rule;
+pub use no_array_method_misuse::no_array_method_misuse_rule;
+pub use no_async_promise_executor::no_async_promise_executor_rule;
+#[cfg(feature = "type-aware")]
+pub use no_confusing_void_expression::no_confusing_void_expression_rule;
+pub use no_constant_condition::no_constant_condition_rule;
+pub use no_dynamic_delete::no_dynamic_delete_rule;
+#[cfg(feature = "stylistic")]
+pub use no_empty_function::no_empty_function_rule;
+#[cfg(feature = "stylistic")]
+pub use no_empty_interface::no_empty_interface_rule;
+#[cfg(feature = "formatting")]
+pub use no_extra_semi::no_extra_semi_rule;
+pub use no_extraneous_class::no_extraneous_class_rule;
+#[cfg(feature = "stylistic")]
+pub use no_inferrable_types::no_inferrable_types_rule;
+pub use no_invalid_this::no_invalid_this_rule;
+pub use no_loss_of_precision::no_loss_of_precision_rule;
+pub use no_magic_numbers::no_magic_numbers_rule;
+#[cfg(feature = "type-aware")]
+pub use no_meaningless_void_operator::no_meaningless_void_operator_rule;
+pub use no_misused_new::no_misused_new_rule;
+#[cfg(feature = "type-aware")]
+pub use no_misused_promises::no_misused_promises_rule;
+pub use no_namespace::no_namespace_rule;
+pub use no_non_null_asserted_nullish_coalescing::no_non_null_asserted_nullish_coalescing_rule;
+pub use no_non_null_asserted_optional_chain::no_non_null_asserted_optional_chain_rule;
+pub use no_non_null_assertion::no_non_null_assertion_rule;
+pub use no_require_imports::no_require_imports_rule;
+pub use no_restricted_ts_syntax::no_restricted_ts_syntax_rule;
+pub use no_shadow::no_shadow_rule;
+pub use no_this_alias::no_this_alias_rule;
+pub use no_type_alias::no_type_alias_rule;
+#[cfg(feature = "type-aware")]
+pub use no_unnecessary_boolean_literal_compare::no_unnecessary_boolean_literal_compare_rule;
+#[cfg(feature = "type-aware")]
+pub use no_unnecessary_type_assertion::no_unnecessary_type_assertion_rule;
+#[cfg(feature = "type-aware")]
+pub use no_unnecessary_type_parameters::no_unnecessary_type_parameters_rule;
+#[cfg(feature = "type-aware")]
+pub use no_unsafe_argument::no_unsafe_argument_rule;
+#[cfg(feature = "type-aware")]
+pub use no_unsafe_member_access::no_unsafe_member_access_rule;
+pub use no_unused_private_class_members::no_unused_private_class_members_rule;
+pub use no_useless_constructor::no_useless_constructor_rule;
+pub use no_var_requires::no_var_requires_rule;
+pub use parameter_properties::parameter_properties_rule;
+#[cfg(feature = "stylistic")]
+pub use prefer_array_index_at::prefer_array_index_at_rule;
+pub use prefer_const::prefer_const_rule;
+#[cfg(feature = "stylistic")]
+pub use prefer_function_type::prefer_function_type_rule;
+pub use prefer_literal_enum_member::prefer_literal_enum_member_rule;
+#[cfg(feature = "stylistic")]
+pub use prefer_namespace_keyword::prefer_namespace_keyword_rule;
+#[cfg(feature = "type-aware")]
+pub use prefer_nullish_coalescing::prefer_nullish_coalescing_rule;
+#[cfg(feature = "type-aware")]
+pub use prefer_string_starts_ends_with::prefer_string_starts_ends_with_rule;
+#[cfg(feature = "type-aware")]
+pub use restrict_plus_operands::restrict_plus_operands_rule;
+#[cfg(feature = "type-aware")]
+pub use restrict_template_expressions::restrict_template_expressions_rule;
+#[cfg(feature = "type-aware")]
+pub use return_await::return_await_rule;
+pub use typedef::typedef_rule;
+
+pub(crate) use ban_ts_comment::Options as BanTsCommentOptions;
+pub(crate) use ban_types::Options as BanTypesOptions;
+pub(crate) use member_ordering::Options as MemberOrderingOptions;
+#[cfg(feature = "type-aware")]
+pub(crate) use no_misused_promises::Options as NoMisusedPromisesOptions;
+
+/// The rule's test cases, which double as the examples in its docs.
+#[cfg(any(test, feature = "docs"))]
+pub fn get_rule_tests(rule_name: &str) -> Option<RuleTests> {
+    Some(match rule_name {
+        "ban-ts-comment" => ban_ts_comment::test_cases::ban_ts_comment_rule_tests(),
+        "ban-types" => ban_types::test_cases::ban_types_rule_tests(),
+        "class-methods-use-this" => class_methods_use_this::test_cases::class_methods_use_this_rule_tests(),
+        "default-param-last" => default_param_last::test_cases::default_param_last_rule_tests(),
+        "explicit-function-return-type" => explicit_function_return_type::test_cases::explicit_function_return_type_rule_tests(),
+        "explicit-module-boundary-types" => explicit_module_boundary_types::test_cases::explicit_module_boundary_types_rule_tests(),
+        "member-ordering" => member_ordering::test_cases::member_ordering_rule_tests(),
+        "method-signature-style" => method_signature_style::test_cases::method_signature_style_rule_tests(),
+        "naming-convention" => naming_convention::test_cases::naming_convention_rule_tests(),
+        "no-array-constructor" => no_array_constructor::test_cases::no_array_constructor_rule_tests(),
+        "no-array-method-misuse" => no_array_method_misuse::test_cases::no_array_method_misuse_rule_tests(),
+        "no-async-promise-executor" => no_async_promise_executor::test_cases::no_async_promise_executor_rule_tests(),
+        "no-constant-condition" => no_constant_condition::test_cases::no_constant_condition_rule_tests(),
+        "no-dynamic-delete" => no_dynamic_delete::test_cases::no_dynamic_delete_rule_tests(),
+        "no-extraneous-class" => no_extraneous_class::test_cases::no_extraneous_class_rule_tests(),
+        "no-invalid-this" => no_invalid_this::test_cases::no_invalid_this_rule_tests(),
+        "no-loss-of-precision" => no_loss_of_precision::test_cases::no_loss_of_precision_rule_tests(),
+        "no-magic-numbers" => no_magic_numbers::test_cases::no_magic_numbers_rule_tests(),
+        "no-misused-new" => no_misused_new::test_cases::no_misused_new_rule_tests(),
+        "no-namespace" => no_namespace::test_cases::no_namespace_rule_tests(),
+        "no-non-null-asserted-nullish-coalescing" => no_non_null_asserted_nullish_coalescing::test_cases::no_non_null_asserted_nullish_coalescing_rule_tests(),
+        "no-non-null-asserted-optional-chain" => no_non_null_asserted_optional_chain::test_cases::no_non_null_asserted_optional_chain_rule_tests(),
+        "no-non-null-assertion" => no_non_null_assertion::test_cases::no_non_null_assertion_rule_tests(),
+        "no-require-imports" => no_require_imports::test_cases::no_require_imports_rule_tests(),
+        "no-restricted-ts-syntax" => no_restricted_ts_syntax::test_cases::no_restricted_ts_syntax_rule_tests(),
+        "no-shadow" => no_shadow::test_cases::no_shadow_rule_tests(),
+        "no-this-alias" => no_this_alias::test_cases::no_this_alias_rule_tests(),
+        "no-type-alias" => no_type_alias::test_cases::no_type_alias_rule_tests(),
+        "no-unused-private-class-members" => no_unused_private_class_members::test_cases::no_unused_private_class_members_rule_tests(),
+        "no-useless-constructor" => no_useless_constructor::test_cases::no_useless_constructor_rule_tests(),
+        "no-var-requires" => no_var_requires::test_cases::no_var_requires_rule_tests(),
+        "parameter-properties" => parameter_properties::test_cases::parameter_properties_rule_tests(),
+        "prefer-const" => prefer_const::test_cases::prefer_const_rule_tests(),
+        "prefer-literal-enum-member" => prefer_literal_enum_member::test_cases::prefer_literal_enum_member_rule_tests(),
+        "typedef" => typedef::test_cases::typedef_rule_tests(),
+        #[cfg(feature = "stylistic")]
+        "adjacent-overload-signatures" => adjacent_overload_signatures::test_cases::adjacent_overload_signatures_rule_tests(),
+        #[cfg(feature = "stylistic")]
+        "array-type" => array_type::test_cases::array_type_rule_tests(),
+        #[cfg(feature = "stylistic")]
+        "ban-tslint-comment" => ban_tslint_comment::test_cases::ban_tslint_comment_rule_tests(),
+        #[cfg(feature = "stylistic")]
+        "class-literal-property-style" => class_literal_property_style::test_cases::class_literal_property_style_rule_tests(),
+        #[cfg(feature = "stylistic")]
+        "consistent-generic-constructors" => consistent_generic_constructors::test_cases::consistent_generic_constructors_rule_tests(),
+        #[cfg(feature = "stylistic")]
+        "consistent-indexed-object-style" => consistent_indexed_object_style::test_cases::consistent_indexed_object_style_rule_tests(),
+        #[cfg(feature = "stylistic")]
+        "consistent-type-definitions" => consistent_type_definitions::test_cases::consistent_type_definitions_rule_tests(),
+        #[cfg(feature = "stylistic")]
+        "no-empty-function" => no_empty_function::test_cases::no_empty_function_rule_tests(),
+        #[cfg(feature = "stylistic")]
+        "no-empty-interface" => no_empty_interface::test_cases::no_empty_interface_rule_tests(),
+        #[cfg(feature = "stylistic")]
+        "no-inferrable-types" => no_inferrable_types::test_cases::no_inferrable_types_rule_tests(),
+        #[cfg(feature = "stylistic")]
+        "prefer-array-index-at" => prefer_array_index_at::test_cases::prefer_array_index_at_rule_tests(),
+        #[cfg(feature = "stylistic")]
+        "prefer-function-type" => prefer_function_type::test_cases::prefer_function_type_rule_tests(),
+        #[cfg(feature = "stylistic")]
+        "prefer-namespace-keyword" => prefer_namespace_keyword::test_cases::prefer_namespace_keyword_rule_tests(),
+        #[cfg(feature = "type-aware")]
+        "await-thenable" => await_thenable::test_cases::await_thenable_rule_tests(),
+        #[cfg(feature = "type-aware")]
+        "no-array-delete" => no_array_delete::test_cases::no_array_delete_rule_tests(),
+        #[cfg(feature = "type-aware")]
+        "no-confusing-void-expression" => no_confusing_void_expression::test_cases::no_confusing_void_expression_rule_tests(),
+        #[cfg(feature = "type-aware")]
+        "no-meaningless-void-operator" => no_meaningless_void_operator::test_cases::no_meaningless_void_operator_rule_tests(),
+        #[cfg(feature = "type-aware")]
+        "no-misused-promises" => no_misused_promises::test_cases::no_misused_promises_rule_tests(),
+        #[cfg(feature = "type-aware")]
+        "no-unnecessary-boolean-literal-compare" => no_unnecessary_boolean_literal_compare::test_cases::no_unnecessary_boolean_literal_compare_rule_tests(),
+        #[cfg(feature = "type-aware")]
+        "no-unnecessary-type-assertion" => no_unnecessary_type_assertion::test_cases::no_unnecessary_type_assertion_rule_tests(),
+        #[cfg(feature = "type-aware")]
+        "no-unnecessary-type-parameters" => no_unnecessary_type_parameters::test_cases::no_unnecessary_type_parameters_rule_tests(),
+        #[cfg(feature = "type-aware")]
+        "no-unsafe-argument" => no_unsafe_argument::test_cases::no_unsafe_argument_rule_tests(),
+        #[cfg(feature = "type-aware")]
+        "no-unsafe-member-access" => no_unsafe_member_access::test_cases::no_unsafe_member_access_rule_tests(),
+        #[cfg(feature = "type-aware")]
+        "prefer-nullish-coalescing" => prefer_nullish_coalescing::test_cases::prefer_nullish_coalescing_rule_tests(),
+        #[cfg(feature = "type-aware")]
+        "prefer-string-starts-ends-with" => prefer_string_starts_ends_with::test_cases::prefer_string_starts_ends_with_rule_tests(),
+        #[cfg(feature = "type-aware")]
+        "restrict-plus-operands" => restrict_plus_operands::test_cases::restrict_plus_operands_rule_tests(),
+        #[cfg(feature = "type-aware")]
+        "restrict-template-expressions" => restrict_template_expressions::test_cases::restrict_template_expressions_rule_tests(),
+        #[cfg(feature = "type-aware")]
+        "return-await" => return_await::test_cases::return_await_rule_tests(),
+        #[cfg(feature = "formatting")]
+        "no-extra-semi" => no_extra_semi::test_cases::no_extra_semi_rule_tests(),
+        _ => return None,
+    })
+}
@@ -0,0 +1,2043 @@
+use std::{borrow::Cow, cmp::Ordering, collections::HashMap, sync::Arc};
+
+use itertools::Itertools;
+use regex::Regex;
+use serde::Deserialize;
+use squalid::OptionExt;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    kind::{
+        Array, ArrowFunction, ClassBody, ComputedPropertyName, False, Function, GeneratorFunction,
+        LexicalDeclaration, MethodDefinition, Number, Pair, PrivatePropertyIdentifier,
+        ShorthandPropertyIdentifierPattern, String as StringKind, TemplateString, True,
+        UnaryExpression, VariableDeclarator,
+    },
+    utils::ast_utils::get_static_string_value,
+};
+
+use crate::{
+    ast_helpers::{
+        get_import_bindings, get_pattern_identifiers, get_property_kind, is_async_function,
+        is_parameter_property, ImportBindingKind, NodeExtTypescript, PropertyKind,
+    },
+    kind::{
+        AbstractClassDeclaration, AbstractMethodSignature, AccessibilityModifier, ArrayType,
+        CallSignature, ConstructSignature, ConstructorType, EnumAssignment, FunctionSignature,
+        FunctionType, GenericType, LiteralType, MethodSignature, OptionalParameter,
+        OverrideModifier, PredefinedType, PropertySignature, PublicFieldDefinition, ReadonlyType,
+        RequiredParameter, TupleType, UnionType,
+    },
+    type_utils::requires_quoting,
+    util::{
+        is_binding_referenced, is_exported, is_global_binding, skip_parenthesized_expressions,
+        RegexOption,
+    },
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+enum Selector {
+    Variable,
+    Function,
+    Parameter,
+    ParameterProperty,
+    ClassicAccessor,
+    EnumMember,
+    ClassMethod,
+    ObjectLiteralMethod,
+    TypeMethod,
+    ClassProperty,
+    ObjectLiteralProperty,
+    TypeProperty,
+    AutoAccessor,
+    Class,
+    Interface,
+    TypeAlias,
+    Enum,
+    TypeParameter,
+    Import,
+}
+
+impl Selector {
+    const ALL: [Self; 19] = [
+        Self::Variable,
+        Self::Function,
+        Self::Parameter,
+        Self::ParameterProperty,
+        Self::ClassicAccessor,
+        Self::EnumMember,
+        Self::ClassMethod,
+        Self::ObjectLiteralMethod,
+        Self::TypeMethod,
+        Self::ClassProperty,
+        Self::ObjectLiteralProperty,
+        Self::TypeProperty,
+        Self::AutoAccessor,
+        Self::Class,
+        Self::Interface,
+        Self::TypeAlias,
+        Self::Enum,
+        Self::TypeParameter,
+        Self::Import,
+    ];
+
+    fn bits(self) -> i64 {
+        1 << self as u32
+    }
+
+    fn message_name(self) -> &'static str {
+        match self {
+            Self::Variable => "Variable",
+            Self::Function => "Function",
+            Self::Parameter => "Parameter",
+            Self::ParameterProperty => "Parameter Property",
+            Self::ClassicAccessor => "Classic Accessor",
+            Self::EnumMember => "Enum Member",
+            Self::ClassMethod => "Class Method",
+            Self::ObjectLiteralMethod => "Object Literal Method",
+            Self::TypeMethod => "Type Method",
+            Self::ClassProperty => "Class Property",
+            Self::ObjectLiteralProperty => "Object Literal Property",
+            Self::TypeProperty => "Type Property",
+            Self::AutoAccessor => "Auto Accessor",
+            Self::Class => "Class",
+            Self::Interface => "Interface",
+            Self::TypeAlias => "Type Alias",
+            Self::Enum => "Enum",
+            Self::TypeParameter => "Type Parameter",
+            Self::Import => "Import",
+        }
+    }
+}
+
+fn get_property_selector(property_kind: PropertyKind) -> Option<Selector> {
+    match property_kind {
+        PropertyKind::ObjectLiteralProperty => Some(Selector::ObjectLiteralProperty),
+        PropertyKind::ObjectLiteralMethod => Some(Selector::ObjectLiteralMethod),
+        PropertyKind::ObjectLiteralAccessor => None,
+        PropertyKind::ClassProperty => Some(Selector::ClassProperty),
+        PropertyKind::ClassMethod => Some(Selector::ClassMethod),
+        PropertyKind::ClassicAccessor => Some(Selector::ClassicAccessor),
+        PropertyKind::AutoAccessor => Some(Selector::AutoAccessor),
+        PropertyKind::TypeProperty => Some(Selector::TypeProperty),
+        PropertyKind::TypeMethod => Some(Selector::TypeMethod),
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum SelectorOption {
+    Default,
+    VariableLike,
+    MemberLike,
+    TypeLike,
+    Method,
+    Property,
+    Accessor,
+    Variable,
+    Function,
+    Parameter,
+    ParameterProperty,
+    ClassicAccessor,
+    EnumMember,
+    ClassMethod,
+    ObjectLiteralMethod,
+    TypeMethod,
+    ClassProperty,
+    ObjectLiteralProperty,
+    TypeProperty,
+    AutoAccessor,
+    Class,
+    Interface,
+    TypeAlias,
+    Enum,
+    TypeParameter,
+    Import,
+}
+
+impl SelectorOption {
+    fn bits(self) -> i64 {
+        match self {
+            Self::Default => -1,
+            Self::VariableLike => {
+                Selector::Variable.bits() | Selector::Function.bits() | Selector::Parameter.bits()
+            }
+            Self::MemberLike => {
+                Selector::ParameterProperty.bits()
+                    | Self::Method.bits()
+                    | Self::Property.bits()
+                    | Self::Accessor.bits()
+                    | Selector::EnumMember.bits()
+            }
+            Self::TypeLike => {
+                Selector::Class.bits()
+                    | Selector::Interface.bits()
+                    | Selector::TypeAlias.bits()
+                    | Selector::Enum.bits()
+                    | Selector::TypeParameter.bits()
+            }
+            Self::Method => {
+                Selector::ClassMethod.bits()
+                    | Selector::ObjectLiteralMethod.bits()
+                    | Selector::TypeMethod.bits()
+            }
+            Self::Property => {
+                Selector::ClassProperty.bits()
+                    | Selector::ObjectLiteralProperty.bits()
+                    | Selector::TypeProperty.bits()
+            }
+            Self::Accessor => Selector::ClassicAccessor.bits() | Selector::AutoAccessor.bits(),
+            Self::Variable => Selector::Variable.bits(),
+            Self::Function => Selector::Function.bits(),
+            Self::Parameter => Selector::Parameter.bits(),
+            Self::ParameterProperty => Selector::ParameterProperty.bits(),
+            Self::ClassicAccessor => Selector::ClassicAccessor.bits(),
+            Self::EnumMember => Selector::EnumMember.bits(),
+            Self::ClassMethod => Selector::ClassMethod.bits(),
+            Self::ObjectLiteralMethod => Selector::ObjectLiteralMethod.bits(),
+            Self::TypeMethod => Selector::TypeMethod.bits(),
+            Self::ClassProperty => Selector::ClassProperty.bits(),
+            Self::ObjectLiteralProperty => Selector::ObjectLiteralProperty.bits(),
+            Self::TypeProperty => Selector::TypeProperty.bits(),
+            Self::AutoAccessor => Selector::AutoAccessor.bits(),
+            Self::Class => Selector::Class.bits(),
+            Self::Interface => Selector::Interface.bits(),
+            Self::TypeAlias => Selector::TypeAlias.bits(),
+            Self::Enum => Selector::Enum.bits(),
+            Self::TypeParameter => Selector::TypeParameter.bits(),
+            Self::Import => Selector::Import.bits(),
+        }
+    }
+
+    fn is_meta(self) -> bool {
+        matches!(
+            self,
+            Self::Default
+                | Self::VariableLike
+                | Self::MemberLike
+                | Self::TypeLike
+                | Self::Method
+                | Self::Property
+                | Self::Accessor
+        )
+    }
+
+    fn is_method_or_property(self) -> bool {
+        matches!(self, Self::Method | Self::Property)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum Modifier {
+    Const,
+    Readonly,
+    Static,
+    Public,
+    Protected,
+    Private,
+    #[serde(rename = "#private")]
+    HashPrivate,
+    Abstract,
+    Destructured,
+    Global,
+    Exported,
+    Unused,
+    RequiresQuotes,
+    Override,
+    Async,
+    Default,
+    Namespace,
+}
+
+impl Modifier {
+    fn bits(self) -> u64 {
+        1 << self as u32
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+struct Modifiers(u64);
+
+impl Modifiers {
+    fn insert(&mut self, modifier: Modifier) {
+        self.0 |= modifier.bits();
+    }
+
+    fn contains(self, modifier: Modifier) -> bool {
+        self.0 & modifier.bits() != 0
+    }
+}
+
+impl<const N: usize> From<[Modifier; N]> for Modifiers {
+    fn from(value: [Modifier; N]) -> Self {
+        let mut modifiers = Self::default();
+        for modifier in value {
+            modifiers.insert(modifier);
+        }
+        modifiers
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum TypeModifier {
+    Boolean,
+    String,
+    Number,
+    Function,
+    Array,
+}
+
+impl TypeModifier {
+    fn bits(self) -> u64 {
+        1 << (17 + self as u32)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+enum PredefinedFormat {
+    #[serde(rename = "camelCase")]
+    CamelCase,
+    #[serde(rename = "strictCamelCase")]
+    StrictCamelCase,
+    PascalCase,
+    StrictPascalCase,
+    #[serde(rename = "snake_case")]
+    SnakeCase,
+    #[serde(rename = "UPPER_CASE")]
+    UpperCase,
+}
+
+impl PredefinedFormat {
+    fn name(self) -> &'static str {
+        match self {
+            Self::CamelCase => "camelCase",
+            Self::StrictCamelCase => "strictCamelCase",
+            Self::PascalCase => "PascalCase",
+            Self::StrictPascalCase => "StrictPascalCase",
+            Self::SnakeCase => "snake_case",
+            Self::UpperCase => "UPPER_CASE",
+        }
+    }
+
+    fn check(self, name: &str) -> bool {
+        if name.is_empty() {
+            return true;
+        }
+        match self {
+            Self::CamelCase => starts_with_lowercase(name) && !name.contains('_'),
+            Self::StrictCamelCase => {
+                starts_with_lowercase(name) && has_strict_camel_humps(name, false)
+            }
+            Self::PascalCase => starts_with_uppercase(name) && !name.contains('_'),
+            Self::StrictPascalCase => {
+                starts_with_uppercase(name) && has_strict_camel_humps(name, true)
+            }
+            Self::SnakeCase => name == name.to_lowercase() && validate_underscores(name),
+            Self::UpperCase => name == name.to_uppercase() && validate_underscores(name),
+        }
+    }
+}
+
+fn starts_with_uppercase(name: &str) -> bool {
+    let first = name.chars().next().unwrap();
+    first.to_uppercase().eq([first])
+}
+
+fn starts_with_lowercase(name: &str) -> bool {
+    let first = name.chars().next().unwrap();
+    first.to_lowercase().eq([first])
+}
+
+fn is_uppercase_char(c: char) -> bool {
+    c.is_uppercase() && !c.is_lowercase()
+}
+
+fn has_strict_camel_humps(name: &str, mut is_upper: bool) -> bool {
+    if name.starts_with('_') {
+        return false;
+    }
+    for c in name.chars().skip(1) {
+        if c == '_' {
+            return false;
+        }
+        if is_upper == is_uppercase_char(c) {
+            if is_upper {
+                return false;
+            }
+        } else {
+            is_upper = !is_upper;
+        }
+    }
+    true
+}
+
+/// Check for leading, trailing and adjacent underscores.
+fn validate_underscores(name: &str) -> bool {
+    if name.starts_with('_') {
+        return false;
+    }
+    let mut was_underscore = false;
+    for c in name.chars().skip(1) {
+        if c == '_' {
+            if was_underscore {
+                return false;
+            }
+            was_underscore = true;
+        } else {
+            was_underscore = false;
+        }
+    }
+    !was_underscore
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum UnderscoreOption {
+    Forbid,
+    Require,
+    RequireDouble,
+    Allow,
+    AllowDouble,
+    AllowSingleOrDouble,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct MatchRegexOption {
+    regex: RegexOption,
+    #[serde(rename = "match")]
+    match_: bool,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum FilterOption {
+    String(RegexOption),
+    MatchRegex(MatchRegexOption),
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum OneOrMany<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct NamingConventionOption {
+    selector: OneOrMany<SelectorOption>,
+    modifiers: Option<Vec<Modifier>>,
+    types: Option<Vec<TypeModifier>>,
+    format: Option<Vec<PredefinedFormat>>,
+    custom: Option<MatchRegexOption>,
+    leading_underscore: Option<UnderscoreOption>,
+    trailing_underscore: Option<UnderscoreOption>,
+    prefix: Option<Vec<String>>,
+    suffix: Option<Vec<String>>,
+    filter: Option<FilterOption>,
+}
+
+fn default_option(selector: SelectorOption, format: &[PredefinedFormat]) -> NamingConventionOption {
+    NamingConventionOption {
+        selector: OneOrMany::One(selector),
+        modifiers: None,
+        types: None,
+        format: Some(format.to_owned()),
+        custom: None,
+        leading_underscore: None,
+        trailing_underscore: None,
+        prefix: None,
+        suffix: None,
+        filter: None,
+    }
+}
+
+fn get_default_options() -> Vec<NamingConventionOption> {
+    vec![
+        NamingConventionOption {
+            leading_underscore: Some(UnderscoreOption::Allow),
+            trailing_underscore: Some(UnderscoreOption::Allow),
+            ..default_option(SelectorOption::Default, &[PredefinedFormat::CamelCase])
+        },
+        default_option(
+            SelectorOption::Import,
+            &[PredefinedFormat::CamelCase, PredefinedFormat::PascalCase],
+        ),
+        NamingConventionOption {
+            leading_underscore: Some(UnderscoreOption::Allow),
+            trailing_underscore: Some(UnderscoreOption::Allow),
+            ..default_option(
+                SelectorOption::Variable,
+                &[PredefinedFormat::CamelCase, PredefinedFormat::UpperCase],
+            )
+        },
+        default_option(SelectorOption::TypeLike, &[PredefinedFormat::PascalCase]),
+    ]
+}
+
+#[derive(Clone, Debug)]
+struct MatchRegex {
+    regex: Regex,
+    match_: bool,
+}
+
+impl From<&MatchRegexOption> for MatchRegex {
+    fn from(value: &MatchRegexOption) -> Self {
+        Self {
+            regex: value.regex.0.clone(),
+            match_: value.match_,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct NormalizedSelector {
+    selector: SelectorOption,
+    modifiers: Vec<Modifier>,
+    types: Option<Vec<TypeModifier>>,
+    format: Option<Vec<PredefinedFormat>>,
+    custom: Option<MatchRegex>,
+    leading_underscore: Option<UnderscoreOption>,
+    trailing_underscore: Option<UnderscoreOption>,
+    prefix: Vec<String>,
+    suffix: Vec<String>,
+    filter: Option<MatchRegex>,
+    modifier_weight: u64,
+}
+
+fn normalize_option(option: &NamingConventionOption) -> Vec<NormalizedSelector> {
+    let mut modifier_weight = 0;
+    for &modifier in option.modifiers.iter().flatten() {
+        modifier_weight |= modifier.bits();
+    }
+    for &type_ in option.types.iter().flatten() {
+        modifier_weight |= type_.bits();
+    }
+    // give selectors with a filter the _highest_ priority
+    if option.filter.is_some() {
+        modifier_weight |= 1 << 30;
+    }
+
+    let selectors = match &option.selector {
+        OneOrMany::One(selector) => vec![*selector],
+        OneOrMany::Many(selectors) => selectors.clone(),
+    };
+    selectors
+        .into_iter()
+        .map(|selector| NormalizedSelector {
+            selector,
+            modifiers: option.modifiers.clone().unwrap_or_default(),
+            types: option.types.clone(),
+            format: option.format.clone(),
+            custom: option.custom.as_ref().map(Into::into),
+            leading_underscore: option.leading_underscore,
+            trailing_underscore: option.trailing_underscore,
+            prefix: option.prefix.clone().unwrap_or_default(),
+            suffix: option.suffix.clone().unwrap_or_default(),
+            filter: option.filter.as_ref().map(|filter| match filter {
+                FilterOption::String(regex) => MatchRegex {
+                    regex: regex.0.clone(),
+                    match_: true,
+                },
+                FilterOption::MatchRegex(match_regex) => match_regex.into(),
+            }),
+            modifier_weight,
+        })
+        .collect()
+}
+
+fn compare_configs(a: &NormalizedSelector, b: &NormalizedSelector) -> Ordering {
+    if a.selector == b.selector {
+        // in the event of the same selector, order by modifier weight
+        return b.modifier_weight.cmp(&a.modifier_weight);
+    }
+
+    // non-meta selectors should go ahead of meta selectors
+    match (a.selector.is_meta(), b.selector.is_meta()) {
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        _ => (),
+    }
+
+    // method and property have higher precedence than other meta selectors
+    match (
+        a.selector.is_method_or_property(),
+        b.selector.is_method_or_property(),
+    ) {
+        (true, false) => return Ordering::Less,
+        (false, true) => return Ordering::Greater,
+        _ => (),
+    }
+
+    // the meta selectors are "bigger" than the non-meta selectors
+    b.selector.bits().cmp(&a.selector.bits())
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Position {
+    Leading,
+    Trailing,
+    Prefix,
+    Suffix,
+}
+
+impl Position {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Leading => "leading",
+            Self::Trailing => "trailing",
+            Self::Prefix => "prefix",
+            Self::Suffix => "suffix",
+        }
+    }
+
+    fn is_start(self) -> bool {
+        matches!(self, Self::Leading | Self::Prefix)
+    }
+
+    fn has_affix(self, name: &str, affix: &str) -> bool {
+        if self.is_start() {
+            name.starts_with(affix)
+        } else {
+            name.ends_with(affix)
+        }
+    }
+
+    fn trim_affix(self, name: &str, affix: &str) -> String {
+        if self.is_start() {
+            name[affix.len()..].to_owned()
+        } else {
+            name[..name.len() - affix.len()].to_owned()
+        }
+    }
+}
+
+fn get_declared_type<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<TypeModifier> {
+    let parent = node.parent()?;
+    let (type_annotation, initializer) = match parent.kind() {
+        VariableDeclarator | PublicFieldDefinition | PropertySignature
+            if parent.field("name") == node =>
+        {
+            (
+                parent.child_by_field_name("type"),
+                parent.child_by_field_name("value"),
+            )
+        }
+        RequiredParameter | OptionalParameter if parent.field("pattern") == node => (
+            parent.child_by_field_name("type"),
+            parent.child_by_field_name("value"),
+        ),
+        Pair if parent.field("key") == node => (None, parent.child_by_field_name("value")),
+        _ => return None,
+    };
+    match type_annotation {
+        Some(type_annotation) => get_type_modifier_of_type(
+            type_annotation.first_non_comment_named_child(SupportedLanguage::Javascript),
+            context,
+        ),
+        None => get_type_modifier_of_expression(initializer?),
+    }
+}
+
+fn get_type_modifier_of_type<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<TypeModifier> {
+    let node = node.skip_parenthesized_types();
+    match node.kind() {
+        PredefinedType | LiteralType => {
+            // predefined `string`/`number` keywords and string/number literal
+            // types share their node kinds
+            let child = node.first_non_comment_child(SupportedLanguage::Javascript);
+            match child.kind() {
+                "boolean" | True | False => Some(TypeModifier::Boolean),
+                StringKind => Some(TypeModifier::String),
+                Number => Some(TypeModifier::Number),
+                _ => None,
+            }
+        }
+        UnionType => {
+            let mut members = vec![];
+            collect_non_nullish_union_members(node, &mut members);
+            members
+                .into_iter()
+                .map(|member| get_type_modifier_of_type(member, context))
+                .all_equal_value()
+                .ok()
+                .flatten()
+        }
+        ArrayType | TupleType => Some(TypeModifier::Array),
+        ReadonlyType => get_type_modifier_of_type(
+            node.first_non_comment_named_child(SupportedLanguage::Javascript),
+            context,
+        )
+        .filter(|&type_| type_ == TypeModifier::Array),
+        GenericType => matches!(
+            &*node.field("name").text(context),
+            "Array" | "ReadonlyArray"
+        )
+        .then_some(TypeModifier::Array),
+        FunctionType => Some(TypeModifier::Function),
+        _ => None,
+    }
+}
+
+fn collect_non_nullish_union_members<'a>(node: Node<'a>, members: &mut Vec<Node<'a>>) {
+    for member in node.non_comment_named_children(SupportedLanguage::Javascript) {
+        let member = member.skip_parenthesized_types();
+        match member.kind() {
+            UnionType => collect_non_nullish_union_members(member, members),
+            PredefinedType | LiteralType
+                if matches!(
+                    member
+                        .first_non_comment_child(SupportedLanguage::Javascript)
+                        .kind(),
+                    "null" | "undefined"
+                ) => {}
+            _ => members.push(member),
+        }
+    }
+}
+
+fn get_type_modifier_of_expression(node: Node) -> Option<TypeModifier> {
+    let node = skip_parenthesized_expressions(node);
+    match node.kind() {
+        True | False => Some(TypeModifier::Boolean),
+        UnaryExpression if node.field("operator").kind() == "!" => Some(TypeModifier::Boolean),
+        StringKind | TemplateString => Some(TypeModifier::String),
+        Number => Some(TypeModifier::Number),
+        ArrowFunction | Function | GeneratorFunction => Some(TypeModifier::Function),
+        Array => Some(TypeModifier::Array),
+        _ => None,
+    }
+}
+
+// TODO: upstream asks the type checker, this only looks at explicit type
+// annotations and literal initializers
+fn is_correct_type<'a>(
+    node: Node<'a>,
+    types: Option<&[TypeModifier]>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    let Some(types) = types else {
+        return true;
+    };
+    get_declared_type(node, context).matches(|type_| types.contains(&type_))
+}
+
+fn get_name<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Cow<'a, str> {
+    match node.kind() {
+        PrivatePropertyIdentifier => node.text(context)[1..].to_owned().into(),
+        StringKind | Number => get_static_string_value(node, context).unwrap(),
+        _ => node.text(context),
+    }
+}
+
+struct Name<'a, 'b> {
+    selector: Selector,
+    node: Node<'a>,
+    original: &'b str,
+}
+
+impl<'a, 'b> Name<'a, 'b> {
+    fn validate_underscore(
+        &self,
+        position: Position,
+        option: Option<UnderscoreOption>,
+        name: String,
+        context: &QueryMatchContext<'a, '_>,
+    ) -> Option<String> {
+        let Some(option) = option else {
+            return Some(name);
+        };
+        let has_single_underscore = position.has_affix(&name, "_");
+        let has_double_underscore = position.has_affix(&name, "__");
+
+        match option {
+            UnderscoreOption::Allow => Some(if has_single_underscore {
+                position.trim_affix(&name, "_")
+            } else {
+                name
+            }),
+            UnderscoreOption::AllowDouble => Some(if has_double_underscore {
+                position.trim_affix(&name, "__")
+            } else {
+                name
+            }),
+            UnderscoreOption::AllowSingleOrDouble => Some(if has_double_underscore {
+                position.trim_affix(&name, "__")
+            } else if has_single_underscore {
+                position.trim_affix(&name, "_")
+            } else {
+                name
+            }),
+            UnderscoreOption::Forbid => {
+                if has_single_underscore {
+                    context.report(violation! {
+                        node => self.node,
+                        message_id => "unexpected_underscore",
+                        data => {
+                            type => self.selector.message_name(),
+                            name => self.original,
+                            position => position.name(),
+                        },
+                    });
+                    return None;
+                }
+                Some(name)
+            }
+            UnderscoreOption::Require | UnderscoreOption::RequireDouble => {
+                let (affix, has_underscore, count) = if option == UnderscoreOption::Require {
+                    ("_", has_single_underscore, "one")
+                } else {
+                    ("__", has_double_underscore, "two")
+                };
+                if !has_underscore {
+                    context.report(violation! {
+                        node => self.node,
+                        message_id => "missing_underscore",
+                        data => {
+                            type => self.selector.message_name(),
+                            name => self.original,
+                            position => position.name(),
+                            count => count,
+                        },
+                    });
+                    return None;
+                }
+                Some(position.trim_affix(&name, affix))
+            }
+        }
+    }
+
+    fn validate_affix(
+        &self,
+        position: Position,
+        affixes: &[String],
+        name: String,
+        context: &QueryMatchContext<'a, '_>,
+    ) -> Option<String> {
+        if affixes.is_empty() {
+            return Some(name);
+        }
+
+        if let Some(affix) = affixes
+            .iter()
+            .find(|affix| position.has_affix(&name, affix))
+        {
+            return Some(position.trim_affix(&name, affix));
+        }
+
+        context.report(violation! {
+            node => self.node,
+            message_id => "missing_affix",
+            data => {
+                type => self.selector.message_name(),
+                name => self.original,
+                position => position.name(),
+                affixes => affixes.join(", "),
+            },
+        });
+        None
+    }
+
+    fn validate_custom(
+        &self,
+        custom: Option<&MatchRegex>,
+        name: &str,
+        context: &QueryMatchContext<'a, '_>,
+    ) -> bool {
+        let Some(custom) = custom else {
+            return true;
+        };
+
+        if custom.regex.is_match(name) == custom.match_ {
+            return true;
+        }
+
+        context.report(violation! {
+            node => self.node,
+            message_id => "satisfy_custom",
+            data => {
+                type => self.selector.message_name(),
+                name => self.original,
+                regex => format!("/{}/u", custom.regex.as_str()),
+                regex_match => if custom.match_ { "match" } else { "not match" },
+            },
+        });
+        false
+    }
+
+    fn validate_predefined_format(
+        &self,
+        formats: Option<&[PredefinedFormat]>,
+        name: &str,
+        modifiers: Modifiers,
+        context: &QueryMatchContext<'a, '_>,
+    ) -> bool {
+        let Some(formats) = formats.filter(|formats| !formats.is_empty()) else {
+            return true;
+        };
+
+        if !modifiers.contains(Modifier::RequiresQuotes)
+            && formats.iter().any(|format| format.check(name))
+        {
+            return true;
+        }
+
+        let message_id = if name == self.original {
+            "does_not_match_format"
+        } else {
+            "does_not_match_format_trimmed"
+        };
+        context.report(violation! {
+            node => self.node,
+            message_id => message_id,
+            data => {
+                type => self.selector.message_name(),
+                name => self.original,
+                processed_name => name,
+                formats => formats.iter().map(|format| format.name()).join(", "),
+            },
+        });
+        false
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Validators {
+    configs_by_selector: HashMap<Selector, Vec<NormalizedSelector>>,
+}
+
+impl Validators {
+    fn new(options: &[NamingConventionOption]) -> Self {
+        let configs = options.iter().flat_map(normalize_option).collect_vec();
+        Self {
+            configs_by_selector: Selector::ALL
+                .into_iter()
+                .map(|selector| {
+                    (
+                        selector,
+                        configs
+                            .iter()
+                            .filter(|config| config.selector.bits() & selector.bits() != 0)
+                            .cloned()
+                            .sorted_by(compare_configs)
+                            .collect(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn validate<'a>(
+        &self,
+        selector: Selector,
+        node: Node<'a>,
+        modifiers: Modifiers,
+        context: &QueryMatchContext<'a, '_>,
+    ) {
+        let original_name = get_name(node, context);
+        let name_to_validate = Name {
+            selector,
+            node,
+            original: &original_name,
+        };
+
+        for config in &self.configs_by_selector[&selector] {
+            if config
+                .filter
+                .as_ref()
+                .matches(|filter| filter.regex.is_match(&original_name) != filter.match_)
+            {
+                continue;
+            }
+
+            if !config
+                .modifiers
+                .iter()
+                .all(|&modifier| modifiers.contains(modifier))
+            {
+                continue;
+            }
+
+            if !is_correct_type(node, config.types.as_deref(), context) {
+                continue;
+            }
+
+            let name = original_name.clone().into_owned();
+            let Some(name) = name_to_validate.validate_underscore(
+                Position::Leading,
+                config.leading_underscore,
+                name,
+                context,
+            ) else {
+                return;
+            };
+            let Some(name) = name_to_validate.validate_underscore(
+                Position::Trailing,
+                config.trailing_underscore,
+                name,
+                context,
+            ) else {
+                return;
+            };
+            let Some(name) =
+                name_to_validate.validate_affix(Position::Prefix, &config.prefix, name, context)
+            else {
+                return;
+            };
+            let Some(name) =
+                name_to_validate.validate_affix(Position::Suffix, &config.suffix, name, context)
+            else {
+                return;
+            };
+            if !name_to_validate.validate_custom(config.custom.as_ref(), &name, context) {
+                return;
+            }
+            name_to_validate.validate_predefined_format(
+                config.format.as_deref(),
+                &name,
+                modifiers,
+                context,
+            );
+
+            // it's valid (or already reported) for this config, so we don't
+            // need to check any more configs
+            return;
+        }
+    }
+
+    fn validate_member<'a>(
+        &self,
+        selector: Selector,
+        node: Node<'a>,
+        mut modifiers: Modifiers,
+        context: &QueryMatchContext<'a, '_>,
+    ) {
+        let key = node.field(match node.kind() {
+            Pair => "key",
+            _ => "name",
+        });
+        if key.kind() == ComputedPropertyName {
+            return;
+        }
+        if matches!(key.kind(), StringKind | Number) && requires_quoting(&get_name(key, context)) {
+            modifiers.insert(Modifier::RequiresQuotes);
+        }
+        self.validate(selector, key, modifiers, context);
+    }
+}
+
+fn get_member_modifiers<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Modifiers {
+    let mut modifiers = Modifiers::default();
+    let mut has_accessibility = false;
+    for (child, _) in node
+        .non_comment_children_and_field_names(SupportedLanguage::Javascript)
+        .take_while(|(_, field_name)| !matches!(field_name, Some("name" | "pattern")))
+    {
+        match child.kind() {
+            AccessibilityModifier => {
+                has_accessibility = true;
+                modifiers.insert(match &*child.text(context) {
+                    "private" => Modifier::Private,
+                    "protected" => Modifier::Protected,
+                    _ => Modifier::Public,
+                });
+            }
+            "static" => modifiers.insert(Modifier::Static),
+            "readonly" => modifiers.insert(Modifier::Readonly),
+            "abstract" => modifiers.insert(Modifier::Abstract),
+            OverrideModifier => modifiers.insert(Modifier::Override),
+            _ => (),
+        }
+    }
+    if node
+        .child_by_field_name("name")
+        .matches(|name| name.kind() == PrivatePropertyIdentifier)
+    {
+        modifiers.insert(Modifier::HashPrivate);
+    } else if !has_accessibility {
+        modifiers.insert(Modifier::Public);
+    }
+    if node.kind() == AbstractMethodSignature {
+        modifiers.insert(Modifier::Abstract);
+    }
+    modifiers
+}
+
+/// `async` methods, and properties or variables initialized with an `async`
+/// function.
+fn is_async_member_or_value(node: Node) -> bool {
+    match node.kind() {
+        MethodDefinition => is_async_function(node),
+        _ => node
+            .child_by_field_name("value")
+            .map(skip_parenthesized_expressions)
+            .is_some_and(|value| {
+                matches!(value.kind(), ArrowFunction | Function) && is_async_function(value)
+            }),
+    }
+}
+
+/// `{ foo }` or `{ foo = 1 }`, but not eg `{ bar: foo }` or `[foo]`.
+fn is_destructured(identifier: Node) -> bool {
+    identifier.kind() == ShorthandPropertyIdentifierPattern
+}
+
+/// Adds the modifiers of `identifier`, bound by `declaration` (a variable
+/// declaration or a `for`/`in`/`of`), to `modifiers`.
+fn get_variable_modifiers<'a>(
+    identifier: Node<'a>,
+    declaration: Node<'a>,
+    mut modifiers: Modifiers,
+    context: &QueryMatchContext<'a, '_>,
+) -> Modifiers {
+    if is_global_binding(identifier) {
+        modifiers.insert(Modifier::Global);
+    }
+    if is_destructured(identifier) {
+        modifiers.insert(Modifier::Destructured);
+    }
+    add_exported_or_unused(identifier, declaration, &mut modifiers, context);
+    modifiers
+}
+
+/// Exported bindings count as used.
+fn add_exported_or_unused<'a>(
+    name: Node<'a>,
+    declaration: Node<'a>,
+    modifiers: &mut Modifiers,
+    context: &QueryMatchContext<'a, '_>,
+) {
+    if is_exported(declaration, &name.text(context), context) {
+        modifiers.insert(Modifier::Exported);
+    } else if !is_binding_referenced(name, context) {
+        modifiers.insert(Modifier::Unused);
+    }
+}
+
+/// `exported` or `unused`, for the name of a declaration.
+fn get_declaration_modifiers<'a>(
+    name: Node<'a>,
+    declaration: Node<'a>,
+    mut modifiers: Modifiers,
+    context: &QueryMatchContext<'a, '_>,
+) -> Modifiers {
+    add_exported_or_unused(name, declaration, &mut modifiers, context);
+    modifiers
+}
+
+fn get_parameter_modifiers<'a>(
+    identifier: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Modifiers {
+    let mut modifiers = Modifiers::default();
+    if is_destructured(identifier) {
+        modifiers.insert(Modifier::Destructured);
+    }
+    if !is_binding_referenced(identifier, context) {
+        modifiers.insert(Modifier::Unused);
+    }
+    modifiers
+}
+
+fn has_checked_parameters(node: Node) -> bool {
+    match node.kind() {
+        // only overload signatures in classes, not interface members
+        MethodSignature | AbstractMethodSignature => node.parent().unwrap().kind() == ClassBody,
+        CallSignature | ConstructSignature | FunctionType | ConstructorType => false,
+        _ => true,
+    }
+}
+
+pub fn naming_convention_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "naming-convention",
+        languages => [Typescript],
+        messages => [
+            unexpected_underscore => "{{type}} name `{{name}}` must not have a {{position}} underscore.",
+            missing_underscore => "{{type}} name `{{name}}` must have {{count}} {{position}} underscore(s).",
+            missing_affix => "{{type}} name `{{name}}` must have one of the following {{position}}es: {{affixes}}",
+            satisfy_custom => "{{type}} name `{{name}}` must {{regex_match}} the RegExp: {{regex}}",
+            does_not_match_format => "{{type}} name `{{name}}` must match one of the following formats: {{formats}}",
+            does_not_match_format_trimmed => "{{type}} name `{{name}}` trimmed as `{{processed_name}}` must match one of the following formats: {{formats}}",
+        ],
+        options_type => Option<Vec<NamingConventionOption>>,
+        state => {
+            [per-config]
+            validators: Validators = Validators::new(
+                options.as_deref().unwrap_or(&get_default_options())
+            ),
+        },
+        listeners => [
+            r#"
+              (import_clause) @c
+            "# => |node, context| {
+                for (local, kind) in get_import_bindings(node, context) {
+                    let modifier = match kind {
+                        ImportBindingKind::Default => Modifier::Default,
+                        ImportBindingKind::Namespace => Modifier::Namespace,
+                        // the name is the one chosen by the imported module
+                        ImportBindingKind::Named => continue,
+                    };
+                    self.validators.validate(Selector::Import, local, [modifier].into(), context);
+                }
+            },
+            r#"
+              (variable_declarator) @c
+            "# => |node, context| {
+                let mut modifiers = Modifiers::default();
+                let parent = node.parent().unwrap();
+                if parent.kind() == LexicalDeclaration && parent.field("kind").kind() == "const" {
+                    modifiers.insert(Modifier::Const);
+                }
+                let name = node.field("name");
+                for identifier in get_pattern_identifiers(name) {
+                    let mut modifiers = get_variable_modifiers(identifier, parent, modifiers, context);
+                    if identifier == name && is_async_member_or_value(node) {
+                        modifiers.insert(Modifier::Async);
+                    }
+                    self.validators.validate(Selector::Variable, identifier, modifiers, context);
+                }
+            },
+            r#"
+              (for_in_statement) @c
+            "# => |node, context| {
+                // only declarations, not eg `for (foo in bar)`
+                let Some(kind) = node.child_by_field_name("kind") else {
+                    return;
+                };
+                let mut modifiers = Modifiers::default();
+                if kind.kind() == "const" {
+                    modifiers.insert(Modifier::Const);
+                }
+                for identifier in get_pattern_identifiers(node.field("left")) {
+                    let modifiers = get_variable_modifiers(identifier, node, modifiers, context);
+                    self.validators.validate(Selector::Variable, identifier, modifiers, context);
+                }
+            },
+            r#"
+              (function_declaration) @c
+              (generator_function_declaration) @c
+              (function_signature) @c
+              (function
+                name: (_)
+              ) @c
+              (generator_function
+                name: (_)
+              ) @c
+            "# => |node, context| {
+                let name = node.field("name");
+                let mut modifiers = get_declaration_modifiers(name, node, Default::default(), context);
+                if is_global_binding(name) {
+                    modifiers.insert(Modifier::Global);
+                }
+                if node.kind() != FunctionSignature && is_async_function(node) {
+                    modifiers.insert(Modifier::Async);
+                }
+                self.validators.validate(Selector::Function, name, modifiers, context);
+            },
+            r#"
+              (formal_parameters) @c
+            "# => |node, context| {
+                if !has_checked_parameters(node.parent().unwrap()) {
+                    return;
+                }
+                for parameter in node.non_comment_named_children(SupportedLanguage::Javascript) {
+                    if !matches!(parameter.kind(), RequiredParameter | OptionalParameter) {
+                        continue;
+                    }
+                    let is_parameter_property = is_parameter_property(parameter);
+                    for identifier in get_pattern_identifiers(parameter.field("pattern")) {
+                        if is_parameter_property {
+                            self.validators.validate(
+                                Selector::ParameterProperty,
+                                identifier,
+                                get_member_modifiers(parameter, context),
+                                context,
+                            );
+                        } else {
+                            self.validators.validate(
+                                Selector::Parameter,
+                                identifier,
+                                get_parameter_modifiers(identifier, context),
+                                context,
+                            );
+                        }
+                    }
+                }
+            },
+            r#"
+              (arrow_function
+                parameter: (_)
+              ) @c
+            "# => |node, context| {
+                let parameter = node.field("parameter");
+                self.validators.validate(Selector::Parameter, parameter, get_parameter_modifiers(parameter, context), context);
+            },
+            r#"
+              (pair) @c
+              (object
+                (method_definition) @c
+              )
+            "# => |node, context| {
+                let Some(selector) = get_property_kind(node, context).and_then(get_property_selector) else {
+                    return;
+                };
+                let mut modifiers: Modifiers = [Modifier::Public].into();
+                if is_async_member_or_value(node) {
+                    modifiers.insert(Modifier::Async);
+                }
+                self.validators.validate_member(selector, node, modifiers, context);
+            },
+            r#"
+              (shorthand_property_identifier) @c
+            "# => |node, context| {
+                self.validators.validate(Selector::ObjectLiteralProperty, node, [Modifier::Public].into(), context);
+            },
+            r#"
+              (class_body
+                [
+                  (public_field_definition)
+                  (method_definition)
+                  (method_signature)
+                  (abstract_method_signature)
+                ] @c
+              )
+            "# => |node, context| {
+                let Some(selector) = get_property_kind(node, context).and_then(get_property_selector) else {
+                    return;
+                };
+                let mut modifiers = get_member_modifiers(node, context);
+                if is_async_member_or_value(node) {
+                    modifiers.insert(Modifier::Async);
+                }
+                self.validators.validate_member(selector, node, modifiers, context);
+            },
+            r#"
+              (object_type
+                [
+                  (property_signature)
+                  (method_signature)
+                ] @c
+              )
+            "# => |node, context| {
+                let Some(selector) = get_property_kind(node, context).and_then(get_property_selector) else {
+                    return;
+                };
+                let mut modifiers: Modifiers = [Modifier::Public].into();
+                if selector == Selector::TypeProperty
+                    && get_member_modifiers(node, context).contains(Modifier::Readonly)
+                {
+                    modifiers.insert(Modifier::Readonly);
+                }
+                self.validators.validate_member(selector, node, modifiers, context);
+            },
+            r#"
+              (enum_body) @c
+            "# => |node, context| {
+                for member in node.non_comment_named_children(SupportedLanguage::Javascript) {
+                    let name = match member.kind() {
+                        EnumAssignment => member.field("name"),
+                        _ => member,
+                    };
+                    if name.kind() == ComputedPropertyName {
+                        continue;
+                    }
+                    let mut modifiers = Modifiers::default();
+                    if name.kind() == StringKind
+                        && requires_quoting(&get_name(name, context))
+                    {
+                        modifiers.insert(Modifier::RequiresQuotes);
+                    }
+                    self.validators.validate(Selector::EnumMember, name, modifiers, context);
+                }
+            },
+            r#"
+              (class_declaration) @c
+              (abstract_class_declaration) @c
+              (class
+                name: (_)
+              ) @c
+            "# => |node, context| {
+                let mut modifiers = Modifiers::default();
+                if node.kind() == AbstractClassDeclaration {
+                    modifiers.insert(Modifier::Abstract);
+                }
+                let name = node.field("name");
+                let modifiers = get_declaration_modifiers(name, node, modifiers, context);
+                self.validators.validate(Selector::Class, name, modifiers, context);
+            },
+            r#"
+              (interface_declaration) @c
+            "# => |node, context| {
+                let name = node.field("name");
+                let modifiers = get_declaration_modifiers(name, node, Default::default(), context);
+                self.validators.validate(Selector::Interface, name, modifiers, context);
+            },
+            r#"
+              (type_alias_declaration) @c
+            "# => |node, context| {
+                let name = node.field("name");
+                let modifiers = get_declaration_modifiers(name, node, Default::default(), context);
+                self.validators.validate(Selector::TypeAlias, name, modifiers, context);
+            },
+            r#"
+              (enum_declaration) @c
+            "# => |node, context| {
+                let name = node.field("name");
+                let modifiers = get_declaration_modifiers(name, node, Default::default(), context);
+                self.validators.validate(Selector::Enum, name, modifiers, context);
+            },
+            r#"
+              (type_parameter) @c
+            "# => |node, context| {
+                let name = node.field("name");
+                let mut modifiers = Modifiers::default();
+                if !is_binding_referenced(name, context) {
+                    modifiers.insert(Modifier::Unused);
+                }
+                self.validators.validate(Selector::TypeParameter, name, modifiers, context);
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, serde_json::json, RuleTests};
+
+    use crate::test_helpers::{expected_error, GeneratedCases, OptionMatrix};
+
+    /// Variable names, with the formats that they match.
+    const FORMAT_CASES: [(&str, &[&str]); 7] = [
+        ("fooBar", &["camelCase", "strictCamelCase"]),
+        ("fooBAR", &["camelCase"]),
+        ("foo", &["camelCase", "strictCamelCase", "snake_case"]),
+        ("FooBar", &["PascalCase", "StrictPascalCase"]),
+        ("FOO", &["PascalCase", "UPPER_CASE"]),
+        ("foo_bar", &["snake_case"]),
+        ("FOO_BAR", &["UPPER_CASE"]),
+    ];
+
+    fn format_cases() -> GeneratedCases {
+        OptionMatrix::default()
+            .axis("selector", ["variable"])
+            .axis(
+                "format",
+                [
+                    "camelCase",
+                    "strictCamelCase",
+                    "PascalCase",
+                    "StrictPascalCase",
+                    "snake_case",
+                    "UPPER_CASE",
+                ]
+                .map(|format| json!([format])),
+            )
+            .wrap(|combination| json!([combination]))
+            .generate(|cases| {
+                let format = cases.option("format").unwrap()[0].as_str().unwrap();
+                for (name, matching_formats) in FORMAT_CASES {
+                    let code = format!("const {name} = 1;");
+                    if matching_formats.contains(&format) {
+                        cases.valid(code);
+                        continue;
+                    }
+                    cases.invalid(
+                        code,
+                        vec![expected_error(
+                            "does_not_match_format",
+                            [("type", "Variable"), ("name", name), ("formats", format)],
+                        )
+                        .line(1)
+                        .column(7)
+                        .build()
+                        .unwrap()],
+                    );
+                }
+            })
+    }
+
+    pub fn naming_convention_rule_tests() -> RuleTests {
+        let format_cases = format_cases();
+        rule_tests! {
+            valid => [
+              ...format_cases.valid,
+              "const fooBar = 1;",
+              "const FOO_BAR = 1;",
+              "let _foo = 1;",
+              "const { fooBar, baz: quxQuux } = obj;",
+              "function fooBar(bazQux) {}",
+              "class FooBar {}",
+              "interface FooBar {}",
+              "type FooBar = {};",
+              "enum FooBar {}",
+              "function foo<TBar>() {}",
+              "const foo = { 'fooBar': 1 };",
+              "import React from 'react';",
+              "import * as fs from 'fs';",
+              "import { default as Foo } from 'foo';",
+              "import { some_thing } from 'foo';",
+              {
+                code => "const headers = { 'Content-Type': 'json' };",
+                options => [
+                  { selector => "objectLiteralProperty", modifiers => ["requiresQuotes"], format => [] },
+                ],
+              },
+              {
+                code => "interface Foo { some_prop: string; someMethod(): void; }",
+                options => [{ selector => "typeProperty", format => ["snake_case"] }],
+              },
+              {
+                code => "class Foo { get Bar() { return 1; } set Bar(value) {} baz() {} }",
+                options => [{ selector => "classicAccessor", format => ["PascalCase"] }],
+              },
+              {
+                code => "class Foo { accessor FOO_BAR = 1; fooBar = 2; }",
+                options => [{ selector => "autoAccessor", format => ["UPPER_CASE"] }],
+              },
+              {
+                code => "class Foo { static get FOO() { return 1; } get bar() { return 2; } }",
+                options => [
+                  { selector => "accessor", modifiers => ["static"], format => ["UPPER_CASE"] },
+                ],
+              },
+              {
+                code => "const fooBar = 1; const _foo_bar = 2;",
+                options => [
+                  { selector => "variable", format => ["camelCase"] },
+                  {
+                    selector => "variable",
+                    filter => "^_",
+                    format => ["snake_case"],
+                    leading_underscore => "allow",
+                  },
+                ],
+              },
+              {
+                code => "const isReady: boolean = true; const count = 1;",
+                options => [
+                  {
+                    selector => "variable",
+                    types => ["boolean"],
+                    format => ["PascalCase"],
+                    prefix => ["is", "should", "has"],
+                  },
+                ],
+              },
+              {
+                code => "class Foo { private _bar = 1; baz = 2; }",
+                options => [
+                  { selector => "default", format => ["camelCase"] },
+                  { selector => "typeLike", format => ["PascalCase"] },
+                  {
+                    selector => "memberLike",
+                    modifiers => ["private"],
+                    format => ["camelCase"],
+                    leading_underscore => "require",
+                  },
+                ],
+              },
+              {
+                code => "class Foo { constructor(readonly FOO: string, bar: string) {} }",
+                options => [
+                  { selector => "parameterProperty", modifiers => ["readonly"], format => ["UPPER_CASE"] },
+                ],
+              },
+              {
+                code => "enum Foo { Bar, Baz = 1 }",
+                options => [{ selector => "enumMember", format => ["PascalCase"] }],
+              },
+              {
+                code => "interface Foo {}",
+                options => [
+                  {
+                    selector => "interface",
+                    format => ["PascalCase"],
+                    custom => { regex => "^I[A-Z]", match => false },
+                  },
+                ],
+              },
+              {
+                code => "const { some_prop } = obj; const { a: someProp } = obj;",
+                options => [
+                  { selector => "variable", format => ["camelCase"] },
+                  { selector => "variable", modifiers => ["destructured"], format => [] },
+                ],
+              },
+              {
+                code => "function foo(_unused_arg, usedArg) { return usedArg; }",
+                options => [
+                  { selector => "parameter", format => ["camelCase"] },
+                  { selector => "parameter", modifiers => ["unused"], format => [], leading_underscore => "require" },
+                ],
+              },
+              {
+                code => "export const FOO_BAR = 1; const BAZ = 2; export { BAZ }; function foo() { const fooBar = 1; return fooBar; }",
+                options => [
+                  { selector => "variable", format => ["camelCase"] },
+                  { selector => "variable", modifiers => ["exported"], format => ["UPPER_CASE"] },
+                ],
+              },
+              {
+                code => "const FOO = 1; function foo() { const bar = 1; return bar; }",
+                options => [
+                  { selector => "variable", format => ["camelCase"] },
+                  { selector => "variable", modifiers => ["global"], format => ["UPPER_CASE"] },
+                ],
+              },
+              {
+                code => "const fetchFoo = async () => {}; async function fetchBar() {} class Foo { async fetchBaz() {} }",
+                options => [
+                  { selector => "default", modifiers => ["async"], format => ["PascalCase"], prefix => ["fetch"] },
+                ],
+              },
+              {
+                code => "type Foo<_T> = string; class Bar<T> { baz: T; }",
+                options => [
+                  { selector => "typeParameter", format => ["PascalCase"] },
+                  { selector => "typeParameter", modifiers => ["unused"], format => ["PascalCase"], leading_underscore => "require" },
+                ],
+              },
+            ],
+            invalid => [
+              ...format_cases.invalid,
+              {
+                code => "const foo_bar = 1;",
+                errors => [
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Variable", name => "foo_bar", formats => "camelCase, UPPER_CASE" },
+                    line => 1,
+                    column => 7,
+                  },
+                ],
+              },
+              {
+                code => "const [foo_bar] = arr;",
+                errors => [{ message_id => "does_not_match_format", line => 1, column => 8 }],
+              },
+              {
+                code => "for (const foo_bar of list) {}",
+                errors => [{ message_id => "does_not_match_format", line => 1, column => 12 }],
+              },
+              {
+                code => "let _foo_bar = 1;",
+                errors => [
+                  {
+                    message_id => "does_not_match_format_trimmed",
+                    data => {
+                      type => "Variable",
+                      name => "_foo_bar",
+                      processed_name => "foo_bar",
+                      formats => "camelCase, UPPER_CASE",
+                    },
+                    line => 1,
+                    column => 5,
+                  },
+                ],
+              },
+              {
+                code => "import my_react from 'react';",
+                errors => [
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Import", name => "my_react", formats => "camelCase, PascalCase" },
+                    line => 1,
+                    column => 8,
+                  },
+                ],
+              },
+              {
+                code => "import * as my_fs from 'fs';",
+                errors => [
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Import", name => "my_fs", formats => "camelCase, PascalCase" },
+                    line => 1,
+                    column => 13,
+                  },
+                ],
+              },
+              {
+                code => "import { default as my_foo } from 'foo';",
+                errors => [
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Import", name => "my_foo", formats => "camelCase, PascalCase" },
+                    line => 1,
+                    column => 21,
+                  },
+                ],
+              },
+              {
+                code => "class foo {}",
+                errors => [
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Class", name => "foo", formats => "PascalCase" },
+                    line => 1,
+                    column => 7,
+                  },
+                ],
+              },
+              {
+                code => "function foo(Bar_baz) {}",
+                errors => [
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Parameter", name => "Bar_baz", formats => "camelCase" },
+                    line => 1,
+                    column => 14,
+                  },
+                ],
+              },
+              {
+                code => "function foo<t_val>() {}",
+                errors => [
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Type Parameter", name => "t_val", formats => "PascalCase" },
+                    line => 1,
+                    column => 14,
+                  },
+                ],
+              },
+              {
+                code => "enum Foo { bar_baz }",
+                errors => [
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Enum Member", name => "bar_baz", formats => "camelCase" },
+                    line => 1,
+                    column => 12,
+                  },
+                ],
+              },
+              {
+                code => "const foo = { Bar_baz: 1 };",
+                errors => [
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Object Literal Property", name => "Bar_baz", formats => "camelCase" },
+                    line => 1,
+                    column => 15,
+                  },
+                ],
+              },
+              {
+                code => "const foo = { 'Content-Type': 1 };",
+                errors => [
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Object Literal Property", name => "Content-Type", formats => "camelCase" },
+                    line => 1,
+                    column => 15,
+                  },
+                ],
+              },
+              {
+                code => "const foo = { bar() {}, baz: () => {}, qux: 1 };",
+                options => [{ selector => "objectLiteralMethod", format => ["PascalCase"] }],
+                errors => [
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Object Literal Method", name => "bar", formats => "PascalCase" },
+                    line => 1,
+                    column => 15,
+                  },
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Object Literal Method", name => "baz", formats => "PascalCase" },
+                    line => 1,
+                    column => 25,
+                  },
+                ],
+              },
+              {
+                code => "interface Foo { Bar: string; }",
+                errors => [
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Type Property", name => "Bar", formats => "camelCase" },
+                    line => 1,
+                    column => 17,
+                  },
+                ],
+              },
+              {
+                code => "interface Foo { bar(): void; baz: () => void; qux: string; }",
+                options => [{ selector => "typeMethod", format => ["PascalCase"] }],
+                errors => [
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Type Method", name => "bar", formats => "PascalCase" },
+                    line => 1,
+                    column => 17,
+                  },
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Type Method", name => "baz", formats => "PascalCase" },
+                    line => 1,
+                    column => 30,
+                  },
+                ],
+              },
+              {
+                code => "class Foo { get bar() { return 1; } }",
+                options => [{ selector => "classicAccessor", format => ["PascalCase"] }],
+                errors => [
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Classic Accessor", name => "bar", formats => "PascalCase" },
+                    line => 1,
+                    column => 17,
+                  },
+                ],
+              },
+              {
+                code => "class Foo { accessor fooBar = 1; }",
+                options => [{ selector => "autoAccessor", format => ["UPPER_CASE"] }],
+                errors => [
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Auto Accessor", name => "fooBar", formats => "UPPER_CASE" },
+                    line => 1,
+                    column => 22,
+                  },
+                ],
+              },
+              {
+                code => "class foo {} interface bar {}",
+                options => [{ selector => ["class", "interface"], format => ["UPPER_CASE"] }],
+                errors => [
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Class", name => "foo", formats => "UPPER_CASE" },
+                    line => 1,
+                    column => 7,
+                  },
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Interface", name => "bar", formats => "UPPER_CASE" },
+                    line => 1,
+                    column => 24,
+                  },
+                ],
+              },
+              {
+                code => "const _foo = 1;",
+                options => [
+                  { selector => "variable", format => ["camelCase"], leading_underscore => "forbid" },
+                ],
+                errors => [
+                  {
+                    message_id => "unexpected_underscore",
+                    data => { type => "Variable", name => "_foo", position => "leading" },
+                    line => 1,
+                    column => 7,
+                  },
+                ],
+              },
+              {
+                code => "const foo = 1;",
+                options => [
+                  { selector => "variable", format => ["camelCase"], trailing_underscore => "requireDouble" },
+                ],
+                errors => [
+                  {
+                    message_id => "missing_underscore",
+                    data => { type => "Variable", name => "foo", position => "trailing", count => "two" },
+                    line => 1,
+                    column => 7,
+                  },
+                ],
+              },
+              {
+                code => "const ready: boolean = true;",
+                options => [
+                  {
+                    selector => "variable",
+                    types => ["boolean"],
+                    format => ["PascalCase"],
+                    prefix => ["is", "has"],
+                  },
+                ],
+                errors => [
+                  {
+                    message_id => "missing_affix",
+                    data => { type => "Variable", name => "ready", position => "prefix", affixes => "is, has" },
+                    line => 1,
+                    column => 7,
+                  },
+                ],
+              },
+              {
+                code => "interface IFoo {}",
+                options => [
+                  {
+                    selector => "interface",
+                    format => ["PascalCase"],
+                    custom => { regex => "^I[A-Z]", match => false },
+                  },
+                ],
+                errors => [
+                  {
+                    message_id => "satisfy_custom",
+                    data => { type => "Interface", name => "IFoo", regex => "/^I[A-Z]/u", regex_match => "not match" },
+                    line => 1,
+                    column => 11,
+                  },
+                ],
+              },
+              {
+                code => "class Foo { private bar = 1; }",
+                options => [
+                  {
+                    selector => "memberLike",
+                    modifiers => ["private"],
+                    format => ["camelCase"],
+                    leading_underscore => "require",
+                  },
+                ],
+                errors => [
+                  {
+                    message_id => "missing_underscore",
+                    data => { type => "Class Property", name => "bar", position => "leading", count => "one" },
+                    line => 1,
+                    column => 21,
+                  },
+                ],
+              },
+              {
+                code => "class Foo { #bar = 1; }",
+                options => [
+                  { selector => "classProperty", modifiers => ["#private"], format => ["UPPER_CASE"] },
+                ],
+                errors => [
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Class Property", name => "bar", formats => "UPPER_CASE" },
+                    line => 1,
+                    column => 13,
+                  },
+                ],
+              },
+              {
+                code => "class Foo { constructor(private bar: string) {} }",
+                options => [{ selector => "parameterProperty", format => ["UPPER_CASE"] }],
+                errors => [
+                  {
+                    message_id => "does_not_match_format",
+                    data => { type => "Parameter Property", name => "bar", formats => "UPPER_CASE" },
+                    line => 1,
+                    column => 33,
+                  },
+                ],
+              },
+              {
+                code => "const { foo_bar } = obj;",
+                options => [
+                  { selector => "variable", modifiers => ["destructured"], format => ["camelCase"] },
+                ],
+                errors => [{ message_id => "does_not_match_format", line => 1, column => 9 }],
+              },
+              {
+                code => "function foo(unusedArg, usedArg) { return usedArg; } const bar = (baz) => {};",
+                options => [
+                  { selector => "parameter", modifiers => ["unused"], format => ["camelCase"], leading_underscore => "require" },
+                ],
+                errors => [
+                  {
+                    message_id => "missing_underscore",
+                    data => { type => "Parameter", name => "unusedArg", count => "one", position => "leading" },
+                    line => 1,
+                    column => 14,
+                  },
+                  { message_id => "missing_underscore", line => 1, column => 67 },
+                ],
+              },
+              {
+                code => "export const fooBar = 1; const bazQux = 2; export default bazQux; export class foo_class {}",
+                options => [
+                  { selector => "default", modifiers => ["exported"], format => ["UPPER_CASE"] },
+                ],
+                errors => [
+                  { message_id => "does_not_match_format", line => 1, column => 14 },
+                  { message_id => "does_not_match_format", line => 1, column => 32 },
+                  { message_id => "does_not_match_format", line => 1, column => 80 },
+                ],
+              },
+              {
+                code => "let fooBar = 1; function foo_bar() { let baz = 1; return baz; }",
+                options => [
+                  { selector => ["variable", "function"], modifiers => ["global"], format => ["UPPER_CASE"] },
+                ],
+                errors => [
+                  { message_id => "does_not_match_format", line => 1, column => 5 },
+                  { message_id => "does_not_match_format", line => 1, column => 26 },
+                ],
+              },
+              {
+                code => "const foo = async () => {}; async function bar() {} const baz = { async qux() {} }; function quux() {}",
+                options => [
+                  { selector => "default", modifiers => ["async"], format => ["camelCase"], suffix => ["Async"] },
+                ],
+                errors => [
+                  { message_id => "missing_affix", line => 1, column => 7 },
+                  { message_id => "missing_affix", line => 1, column => 44 },
+                  { message_id => "missing_affix", line => 1, column => 73 },
+                ],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::serde_json::{self, json};
+
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_naming_convention_rule() {
+        run_for_typescript_and_tsx(
+            naming_convention_rule(),
+            test_cases::naming_convention_rule_tests(),
+        );
+    }
+
+    #[test]
+    fn test_invalid_regex_is_an_options_error() {
+        let parse = |option: serde_json::Value| {
+            serde_json::from_value::<Vec<NamingConventionOption>>(json!([option]))
+        };
+        assert!(parse(json!({
+            "selector": "interface",
+            "custom": { "regex": "^I[A-Z]", "match": false },
+            "filter": "^_",
+        }))
+        .is_ok());
+        assert!(parse(json!({
+            "selector": "interface",
+            "custom": { "regex": "^I[A-Z", "match": false },
+        }))
+        .is_err());
+        assert!(parse(json!({ "selector": "interface", "filter": "(" })).is_err());
+        assert!(parse(json!({
+            "selector": "interface",
+            "filter": { "regex": "(", "match": true },
+        }))
+        .is_err());
+    }
+}
@@ -0,0 +1,196 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{CallExpression, Comment};
+
+use crate::ast_helpers::{get_call_arguments, CallArgument};
+
+/// Whether the call could be passing `Array` a single argument, in which
+/// case it's the array's length rather than an element. With spread
+/// arguments that's the case unless there are at least two others.
+fn could_have_single_argument(arguments: &[CallArgument]) -> bool {
+    match arguments
+        .iter()
+        .filter(|argument| !argument.is_spread())
+        .count()
+    {
+        0 => arguments.iter().any(CallArgument::is_spread),
+        1 => true,
+        _ => false,
+    }
+}
+
+fn has_comments_outside_arguments(node: Node) -> bool {
+    (0..node.child_count()).any(|index| node.child(index).unwrap().kind() == Comment)
+}
+
+fn get_array_literal_text<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> String {
+    match node.child_by_field_name("arguments") {
+        None => "[]".to_owned(),
+        Some(arguments) => {
+            let arguments_text = arguments.text(context);
+            format!("[{}]", &arguments_text[1..arguments_text.len() - 1])
+        }
+    }
+}
+
+pub fn no_array_constructor_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-array-constructor",
+        languages => [Typescript],
+        messages => [
+            use_literal => "The array literal notation [] is preferable.",
+        ],
+        fixable => true,
+        listeners => [
+            r#"
+              (call_expression
+                function: (identifier) @callee (#eq? @callee "Array")
+              ) @call
+              (new_expression
+                constructor: (identifier) @callee (#eq? @callee "Array")
+              ) @call
+            "# => |captures, context| {
+                let node = captures["call"];
+                // `Array<Foo>()` is how you'd give an empty array a type
+                // without a type annotation
+                if node.child_by_field_name("type_arguments").is_some() {
+                    return;
+                }
+                if node.kind() == CallExpression
+                    && node.child_by_field_name("optional_chain").is_some()
+                {
+                    return;
+                }
+                let arguments = get_call_arguments(node).collect::<Vec<_>>();
+                if could_have_single_argument(&arguments) {
+                    return;
+                }
+                context.report(violation! {
+                    node => node,
+                    message_id => "use_literal",
+                    fix => |fixer| {
+                        // (comments inside the parentheses get carried over
+                        // but there's nowhere to put any others)
+                        if has_comments_outside_arguments(node) {
+                            return;
+                        }
+                        fixer.replace_text(node, get_array_literal_text(node, context));
+                    },
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_array_constructor_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "new Array(x);",
+              "Array(x);",
+              "new Array(9);",
+              "Array(9);",
+              "new foo.Array();",
+              "foo.Array();",
+              "new Array.foo();",
+              "Array.foo();",
+              "new Array<Foo>(1, 2, 3);",
+              "new Array<Foo>();",
+              "Array<Foo>(1, 2, 3);",
+              "Array<Foo>();",
+              "Array?.(1, 2, 3);",
+              "Array?.();",
+              "Array(...xs);",
+              "new Array(...xs, ...ys);",
+              "Array(x, ...xs);",
+            ],
+            invalid => [
+              {
+                code => "new Array();",
+                output => "[];",
+                errors => [
+                  {
+                    message_id => "use_literal",
+                    line => 1,
+                    column => 1,
+                    end_column => 12,
+                  },
+                ],
+              },
+              {
+                code => "Array();",
+                output => "[];",
+                errors => [{ message_id => "use_literal" }],
+              },
+              {
+                code => "new Array;",
+                output => "[];",
+                errors => [{ message_id => "use_literal" }],
+              },
+              {
+                code => "new Array(x, y);",
+                output => "[x, y];",
+                errors => [{ message_id => "use_literal" }],
+              },
+              {
+                code => "Array(0, 1, 2);",
+                output => "[0, 1, 2];",
+                errors => [{ message_id => "use_literal" }],
+              },
+              {
+                code => r#"
+Array(
+  0,
+  1,
+  2,
+);
+                    "#,
+                output => r#"
+[
+  0,
+  1,
+  2,
+];
+                    "#,
+                errors => [{ message_id => "use_literal", line => 2 }],
+              },
+              {
+                code => "Array(x, y, ...xs);",
+                output => "[x, y, ...xs];",
+                errors => [{ message_id => "use_literal" }],
+              },
+              {
+                code => "const xs = new Array(/* first */ 1, /* second */ 2);",
+                output => "const xs = [/* first */ 1, /* second */ 2];",
+                errors => [{ message_id => "use_literal", column => 12 }],
+              },
+              {
+                code => "new /* foo */ Array(1, 2);",
+                errors => [{ message_id => "use_literal" }],
+              },
+              {
+                code => "Array /* foo */ (1, 2);",
+                errors => [{ message_id => "use_literal" }],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_array_constructor_rule() {
+        run_for_typescript_and_tsx(
+            no_array_constructor_rule(),
+            test_cases::no_array_constructor_rule_tests(),
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{Identifier, SubscriptExpression};
+
+use crate::{
+    ast_helpers::{is_side_effect_free, NodeExtTypescript},
+    kind::{LiteralType, TupleType, UnionType},
+    type_utils::get_array_element_type,
+    util::{get_declared_type, skip_parenthesized_expressions},
+};
+
+fn is_nullish_type<'a>(type_: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    type_.kind() == LiteralType && matches!(&*type_.text(context), "null" | "undefined")
+}
+
+/// Whether the type is an array or tuple type, or a union of them (plus
+/// possibly `null`/`undefined`, as with `arr?.[0]`).
+fn is_array_type<'a>(type_: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    let type_ = type_.skip_parenthesized_types();
+    match type_.kind() {
+        TupleType => true,
+        UnionType => {
+            let mut members = type_
+                .non_comment_named_children(SupportedLanguage::Javascript)
+                .map(|member| member.skip_parenthesized_types())
+                .filter(|&member| !is_nullish_type(member, context))
+                .peekable();
+            members.peek().is_some() && members.all(|member| is_array_type(member, context))
+        }
+        _ => get_array_element_type(type_, context).is_some(),
+    }
+}
+
+/// The declared type of a variable, or of an element of one (eg `foo[0][1]`
+/// where `foo: string[][][]`).
+fn get_value_type<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Option<Node<'a>> {
+    let node = skip_parenthesized_expressions(node);
+    match node.kind() {
+        Identifier => get_declared_type(node, context),
+        SubscriptExpression => {
+            get_array_element_type(get_value_type(node.field("object"), context)?, context)
+        }
+        _ => None,
+    }
+}
+
+pub fn no_array_delete_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-array-delete",
+        languages => [Typescript],
+        messages => [
+            no_array_delete => "Using the `delete` operator with an array expression is unsafe.",
+            use_splice => "Using the `delete` operator with an array expression is unsafe. Use `{{ replacement }}` instead.",
+        ],
+        listeners => [
+            r#"
+              (unary_expression
+                operator: "delete"
+              ) @c
+            "# => |node, context| {
+                let member = skip_parenthesized_expressions(node.field("argument"));
+                if member.kind() != SubscriptExpression {
+                    return;
+                }
+                let object = member.field("object");
+                if !get_value_type(object, context).is_some_and(|type_| is_array_type(type_, context)) {
+                    return;
+                }
+                let index = member.field("index");
+
+                // `delete` evaluates the receiver and index before anything
+                // else happens, whereas `.splice()` looks up the method in
+                // between, so only spell out the rewrite when the order can't
+                // matter
+                // TODO: this should be a suggestion rather than part of the
+                // message, but it can't be an automatic fix since `splice()`
+                // shifts the later elements down (whereas `delete` leaves a
+                // hole) and returns the removed elements rather than `true`
+                if !is_side_effect_free(object) || !is_side_effect_free(index) {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "no_array_delete",
+                    });
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "use_splice",
+                    data => {
+                        replacement => format!(
+                            "{}{}splice({}, 1)",
+                            object.text(context),
+                            if member.child_by_field_name("optional_chain").is_some() {
+                                "?."
+                            } else {
+                                "."
+                            },
+                            skip_parenthesized_expressions(index).text(context),
+                        ),
+                    },
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_array_delete_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "
+declare const obj: { a: 1; b: 2 };
+delete obj.a;
+                    ",
+                "
+declare const obj: { a: 1; b: 2 };
+delete obj['a'];
+                    ",
+                "
+declare const arr: { a: 1; b: 2 }[][][][];
+delete arr[0][0][0][1].a;
+                    ",
+                "
+declare const maybeArray: any;
+delete maybeArray[0];
+                    ",
+                "
+declare const maybeArray: unknown;
+delete maybeArray[0];
+                    ",
+                "
+declare const test: never;
+delete test[0];
+                    ",
+                "
+declare const mixed: number[] | { [key: number]: number };
+delete mixed[0];
+                    ",
+            ],
+            invalid => [
+                {
+                  code => "
+declare const arr: number[];
+delete arr[0];
+                      ",
+                  errors => [
+                    {
+                      message_id => "use_splice",
+                      data => { replacement => "arr.splice(0, 1)" },
+                      line => 3,
+                      column => 1,
+                      end_column => 14,
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare const arr: number[];
+declare const key: number;
+delete arr[key];
+                      ",
+                  errors => [
+                    {
+                      message_id => "use_splice",
+                      data => { replacement => "arr.splice(key, 1)" },
+                      line => 4,
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare const arr: number[];
+enum Keys {
+  A,
+  B,
+}
+delete arr[Keys.A];
+                      ",
+                  errors => [
+                    {
+                      message_id => "use_splice",
+                      data => { replacement => "arr.splice(Keys.A, 1)" },
+                      line => 7,
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare const arr: number[];
+declare function doWork(): number;
+delete arr[doWork()];
+                      ",
+                  errors => [{ message_id => "no_array_delete", line => 4 }],
+                },
+                {
+                  code => "
+declare const arr: number[];
+let i = 0;
+delete arr[i++];
+                      ",
+                  errors => [{ message_id => "no_array_delete", line => 4 }],
+                },
+                {
+                  code => "
+declare const arr: number[];
+delete arr[Math.random() ? 1 : 0];
+                      ",
+                  errors => [{ message_id => "no_array_delete", line => 3 }],
+                },
+                {
+                  code => "
+declare const arr: number[];
+declare const a: number;
+declare const b: number;
+delete arr[a + b];
+                      ",
+                  errors => [
+                    {
+                      message_id => "use_splice",
+                      data => { replacement => "arr.splice(a + b, 1)" },
+                      line => 5,
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare const arr: { a: 1; b: 2 }[][][][];
+delete arr[0][0][0][1];
+                      ",
+                  errors => [
+                    {
+                      message_id => "use_splice",
+                      data => { replacement => "arr[0][0][0].splice(1, 1)" },
+                      line => 3,
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare const arr: number[];
+delete ((arr))[((0))];
+                      ",
+                  errors => [
+                    {
+                      message_id => "use_splice",
+                      data => { replacement => "((arr)).splice(0, 1)" },
+                      line => 3,
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare const arr: number[];
+declare const i: number;
+delete arr[(i, 0)];
+                      ",
+                  errors => [{ message_id => "no_array_delete", line => 4 }],
+                },
+                {
+                  code => "
+declare const arr: number[] | undefined;
+delete arr?.[0];
+                      ",
+                  errors => [
+                    {
+                      message_id => "use_splice",
+                      data => { replacement => "arr?.splice(0, 1)" },
+                      line => 3,
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare const arr: ReadonlyArray<number> | [string, number];
+delete arr[1];
+                      ",
+                  errors => [
+                    {
+                      message_id => "use_splice",
+                      data => { replacement => "arr.splice(1, 1)" },
+                      line => 3,
+                    },
+                  ],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_array_delete_rule() {
+        run_for_typescript_and_tsx(
+            no_array_delete_rule(),
+            test_cases::no_array_delete_rule_tests(),
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule};
+use tree_sitter_lint_plugin_eslint_builtin::kind::Number;
+
+use crate::util::{parse_numeric_literal, skip_parenthesized_expressions, MethodCallMatcher};
+
+const FLAT_WITH_DEPTH: MethodCallMatcher<'static> = MethodCallMatcher {
+    methods: &["flat"],
+    receiver: None,
+    argument_counts: &[1],
+    argument_kinds: &[&[Number]],
+    allow_optional_chain: true,
+};
+
+const EMPTY_CONCAT: MethodCallMatcher<'static> = MethodCallMatcher {
+    methods: &["concat"],
+    receiver: None,
+    argument_counts: &[0],
+    argument_kinds: &[],
+    allow_optional_chain: true,
+};
+
+const MUTATING_SORT: MethodCallMatcher<'static> = MethodCallMatcher {
+    methods: &["sort", "reverse"],
+    receiver: None,
+    argument_counts: &[],
+    argument_kinds: &[],
+    allow_optional_chain: true,
+};
+
+const FULL_SLICE: MethodCallMatcher<'static> = MethodCallMatcher {
+    methods: &["slice"],
+    receiver: None,
+    argument_counts: &[0, 1],
+    argument_kinds: &[&[Number]],
+    allow_optional_chain: false,
+};
+
+fn is_number_literal<'a>(node: Node<'a>, value: f64, context: &QueryMatchContext<'a, '_>) -> bool {
+    let node = skip_parenthesized_expressions(node);
+    node.kind() == Number
+        && parse_numeric_literal(&node.text(context))
+            .is_some_and(|literal| literal.value.to_number() == value)
+}
+
+/// `foo.slice()` or `foo.slice(0)`.
+fn is_full_slice<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    FULL_SLICE
+        .matches(node, context)
+        .is_some_and(|call| match call.arguments.first() {
+            None => true,
+            Some(&start) => is_number_literal(start, 0., context),
+        })
+}
+
+pub fn no_array_method_misuse_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-array-method-misuse",
+        languages => [Typescript],
+        messages => [
+            unnecessary_flat_depth => "Passing a depth of 1 to `flat()` is unnecessary, since it's the default.",
+            empty_concat => "Calling `concat()` without arguments just makes a shallow copy.",
+            mutated_slice_copy => "Use `{{replacement}}()` instead of copying with `slice()` and then calling `{{method}}()`.",
+        ],
+        listeners => [
+            r#"
+              (call_expression) @c
+            "# => |node, context| {
+                if let Some(call) = FLAT_WITH_DEPTH.matches(node, context) {
+                    let depth = call.arguments[0];
+                    if !is_number_literal(depth, 1., context) {
+                        return;
+                    }
+                    context.report(violation! {
+                        node => depth,
+                        message_id => "unnecessary_flat_depth",
+                        // TODO: suggestions?
+                    });
+                    return;
+                }
+
+                if EMPTY_CONCAT.matches(node, context).is_some() {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "empty_concat",
+                        // TODO: suggestions?
+                    });
+                    return;
+                }
+
+                let Some(call) = MUTATING_SORT.matches(node, context) else {
+                    return;
+                };
+                if !is_full_slice(skip_parenthesized_expressions(call.receiver), context) {
+                    return;
+                }
+                context.report(violation! {
+                    node => node,
+                    message_id => "mutated_slice_copy",
+                    data => {
+                        replacement => if call.method == "sort" { "toSorted" } else { "toReversed" },
+                        method => call.method,
+                        banned_name => call.method,
+                    },
+                    // TODO: suggestions?
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_array_method_misuse_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "foo.flat();",
+              "foo.flat(2);",
+              "foo.flat(Infinity);",
+              "foo.flat(depth);",
+              "foo.concat(bar);",
+              "foo.concat(...bars);",
+              "foo.sort();",
+              "foo.slice(1).sort();",
+              "foo.slice(0, 2).reverse();",
+              "[...foo].sort();",
+              "foo.slice().map(bar);",
+            ],
+            invalid => [
+              {
+                code => "foo.flat(1);",
+                errors => [
+                  {
+                    message_id => "unnecessary_flat_depth",
+                    line => 1,
+                    column => 10,
+                    end_column => 11,
+                  },
+                ],
+              },
+              {
+                code => "foo.flat((1.0));",
+                errors => [{ message_id => "unnecessary_flat_depth" }],
+              },
+              {
+                code => "const copy = foo.concat();",
+                errors => [{ message_id => "empty_concat", column => 14 }],
+              },
+              {
+                code => "foo?.concat();",
+                errors => [{ message_id => "empty_concat" }],
+              },
+              {
+                code => "const sorted = foo.slice().sort();",
+                errors => [
+                  {
+                    message_id => "mutated_slice_copy",
+                    data => {
+                      method => "sort",
+                      replacement => "toSorted",
+                      banned_name => "sort",
+                    },
+                    column => 16,
+                  },
+                ],
+              },
+              {
+                code => "const reversed = (foo.slice(0)).reverse();",
+                errors => [
+                  {
+                    message_id => "mutated_slice_copy",
+                    data => {
+                      method => "reverse",
+                      replacement => "toReversed",
+                      banned_name => "reverse",
+                    },
+                  },
+                ],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_array_method_misuse_rule() {
+        run_for_typescript_and_tsx(
+            no_array_method_misuse_rule(),
+            test_cases::no_array_method_misuse_rule_tests(),
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{rule, violation, NodeExt, Rule};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{ArrowFunction, Function};
+
+use crate::ast_helpers::{is_async_function, skip_parentheses_and_assertions};
+
+pub fn no_async_promise_executor_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-async-promise-executor",
+        languages => [Typescript],
+        messages => [
+            async_ => "Promise executor functions should not be async.",
+        ],
+        listeners => [
+            r#"
+              (new_expression
+                constructor: (identifier) @constructor (#match? @constructor "^Promise$")
+                arguments: (arguments
+                  .
+                  (_) @executor
+                )
+              )
+            "# => |captures, context| {
+                let executor = skip_parentheses_and_assertions(captures["executor"]);
+                if !matches!(executor.kind(), ArrowFunction | Function) || !is_async_function(executor) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => executor.first_child_of_kind("async"),
+                    message_id => "async_",
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_async_promise_executor_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "new Promise((resolve, reject) => {})",
+              "new Promise((resolve, reject) => {}, async function unrelated() {})",
+              "new Foo(async (resolve, reject) => {})",
+              "new Promise<void>((resolve: () => void) => {})",
+              "new Promise(executor as (resolve: () => void) => void)",
+              "new Promise(function (resolve: (value: number) => void) { resolve(1); })",
+            ],
+            invalid => [
+              {
+                code => "new Promise(async function foo(resolve, reject) {})",
+                errors => [
+                  {
+                    message_id => "async_",
+                    line => 1,
+                    column => 13,
+                    end_line => 1,
+                    end_column => 18,
+                  },
+                ],
+              },
+              {
+                code => "new Promise(async (resolve, reject) => {})",
+                errors => [{ message_id => "async_", line => 1, column => 13 }],
+              },
+              {
+                code => "new Promise(((((async () => {})))))",
+                errors => [{ message_id => "async_", line => 1, column => 17 }],
+              },
+              {
+                code => "new Promise<number>(async (resolve: (value: number) => void): Promise<void> => {})",
+                errors => [{ message_id => "async_", line => 1, column => 21 }],
+              },
+              {
+                code => "new Promise<void>(async function (resolve: () => void, reject: (reason?: unknown) => void) {})",
+                errors => [{ message_id => "async_", line => 1, column => 19 }],
+              },
+              {
+                code => "new Promise((async (resolve) => {}) as (resolve: () => void) => void)",
+                errors => [{ message_id => "async_", line => 1, column => 14 }],
+              },
+              {
+                code => "new Promise(<Executor>(async (resolve) => {}))",
+                errors => [{ message_id => "async_", line => 1, column => 24 }],
+              },
+              {
+                code => "new Promise((async (resolve) => {}) satisfies Executor)",
+                errors => [{ message_id => "async_", line => 1, column => 14 }],
+              },
+              {
+                code => "new Promise((async (resolve) => {})!)",
+                errors => [{ message_id => "async_", line => 1, column => 14 }],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_async_promise_executor_rule() {
+        run_for_typescript_and_tsx(
+            no_async_promise_executor_rule(),
+            test_cases::no_async_promise_executor_rule_tests(),
+        );
+    }
+}
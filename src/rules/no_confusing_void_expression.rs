@@ -0,0 +1,561 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use squalid::OptionExt;
+use tree_sitter_lint::{
+    rule,
+    tree_sitter::{Node, Range},
+    tree_sitter_grep::SupportedLanguage,
+    violation, NodeExt, QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    ArrowFunction, BinaryExpression, ConditionalExpression, ExpressionStatement,
+    ParenthesizedExpression, ReturnStatement, SequenceExpression, StatementBlock, UnaryExpression,
+};
+
+use crate::{
+    ast_helpers::is_void_operator_expression,
+    type_utils::is_void_expression,
+    util::{is_function, skip_parenthesized_expressions, IndentedText},
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    ignore_arrow_shorthand: Option<bool>,
+    ignore_void_operator: Option<bool>,
+}
+
+impl Options {
+    fn ignore_arrow_shorthand(&self) -> bool {
+        self.ignore_arrow_shorthand.unwrap_or_default()
+    }
+
+    fn ignore_void_operator(&self) -> bool {
+        self.ignore_void_operator.unwrap_or_default()
+    }
+}
+
+/// Whether the expression as a whole is `void`, ie whether it can be moved
+/// out of a `return` without losing anything.
+fn is_void_value<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    let node = skip_parenthesized_expressions(node);
+    match node.kind() {
+        ConditionalExpression => {
+            is_void_value(node.field("consequence"), context)
+                && is_void_value(node.field("alternative"), context)
+        }
+        SequenceExpression => is_void_value(
+            node.non_comment_named_children(SupportedLanguage::Javascript)
+                .last()
+                .unwrap(),
+            context,
+        ),
+        _ if is_void_operator_expression(node) => true,
+        _ => is_void_expression(node, context),
+    }
+}
+
+// `return` in a function body's last statement
+fn is_final_return(node: Node) -> bool {
+    let block = node.parent().unwrap();
+    block.kind() == StatementBlock
+        && block.parent().matches(is_function)
+        && block
+            .non_comment_named_children(SupportedLanguage::Javascript)
+            .last()
+            == Some(node)
+}
+
+// (see https://standardjs.com/rules.html#semicolons)
+fn is_preventing_asi(text: &str) -> bool {
+    text.starts_with(['(', '[', '`'])
+}
+
+pub fn no_confusing_void_expression_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-confusing-void-expression",
+        languages => [Typescript],
+        messages => [
+            invalid_void_expr => "Placing a void expression inside another expression is forbidden. Move it to its own statement instead.",
+            invalid_void_expr_wrap_void => "Void expressions used inside another expression must be moved to its own statement or marked explicitly with the `void` operator.",
+            invalid_void_expr_arrow => "Returning a void expression from an arrow function shorthand is forbidden. Please add braces to the arrow function.",
+            invalid_void_expr_arrow_wrap_void => "Void expressions returned from an arrow function shorthand must be marked explicitly with the `void` operator.",
+            invalid_void_expr_return => "Returning a void expression from a function is forbidden. Please move it before the `return` statement.",
+            invalid_void_expr_return_last => "Returning a void expression from a function is forbidden. Please remove the `return` statement.",
+            invalid_void_expr_return_wrap_void => "Void expressions returned from a function must be marked explicitly with the `void` operator.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            ignore_arrow_shorthand: bool = options.ignore_arrow_shorthand(),
+            ignore_void_operator: bool = options.ignore_void_operator(),
+        },
+        methods => {
+            fn find_invalid_ancestor(&self, node: Node<'a>) -> Option<Node<'a>> {
+                let parent = node.parent().unwrap();
+                match parent.kind() {
+                    ParenthesizedExpression => self.find_invalid_ancestor(parent),
+                    // eg `console.log("foo"), bar` isn't using the `void`
+                    SequenceExpression => {
+                        if parent.non_comment_named_children(SupportedLanguage::Javascript).last() != Some(node) {
+                            return None;
+                        }
+                        self.find_invalid_ancestor(parent)
+                    }
+                    ExpressionStatement => None,
+                    // eg `x && console.log(x)` is valid only if the `&&` is
+                    BinaryExpression
+                        if matches!(parent.field("operator").kind(), "&&" | "||" | "??")
+                            && parent.field("right") == node =>
+                    {
+                        self.find_invalid_ancestor(parent)
+                    }
+                    ConditionalExpression
+                        if parent.field("consequence") == node || parent.field("alternative") == node =>
+                    {
+                        self.find_invalid_ancestor(parent)
+                    }
+                    ArrowFunction if self.ignore_arrow_shorthand => None,
+                    UnaryExpression
+                        if self.ignore_void_operator && parent.field("operator").kind() == "void" =>
+                    {
+                        None
+                    }
+                    _ => Some(parent),
+                }
+            }
+
+            fn report_return(&self, node: Node<'a>, return_statement: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+                if self.ignore_void_operator {
+                    context.report(violation! {
+                        node => node,
+                        message_id => "invalid_void_expr_return_wrap_void",
+                        fix => |fixer| {
+                            fixer.replace_text(node, format!("void {}", node.text(context)));
+                        },
+                    });
+                    return;
+                }
+
+                let return_value = skip_parenthesized_expressions(
+                    return_statement.first_non_comment_named_child(SupportedLanguage::Javascript),
+                );
+                let return_value_text = return_value.text(context);
+                let asi_prefix = if is_preventing_asi(&return_value_text) { ";" } else { "" };
+
+                if is_final_return(return_statement) {
+                    let can_fix = is_void_value(return_value, context);
+                    context.report(violation! {
+                        node => node,
+                        message_id => "invalid_void_expr_return_last",
+                        fix => |fixer| {
+                            if !can_fix {
+                                return;
+                            }
+                            fixer.replace_text(
+                                return_statement,
+                                format!("{asi_prefix}{return_value_text};"),
+                            );
+                        },
+                    });
+                    return;
+                }
+
+                // (unlike removing the `return`, this doesn't change what the
+                // function returns)
+                context.report(violation! {
+                    node => node,
+                    message_id => "invalid_void_expr_return",
+                    fix => |fixer| {
+                        let mut new_text = format!("{asi_prefix}{return_value_text}; return;");
+                        // eg `if (cond) return console.error();`
+                        if return_statement.parent().unwrap().kind() != StatementBlock {
+                            new_text = format!("{{ {new_text} }}");
+                        }
+                        fixer.replace_text(return_statement, new_text);
+                    },
+                });
+            }
+        },
+        listeners => [
+            r#"
+              (call_expression) @c
+              (await_expression) @c
+            "# => |node, context| {
+                if !is_void_expression(node, context) {
+                    return;
+                }
+                let Some(invalid_ancestor) = self.find_invalid_ancestor(node) else {
+                    return;
+                };
+
+                match invalid_ancestor.kind() {
+                    ArrowFunction => {
+                        if self.ignore_void_operator {
+                            context.report(violation! {
+                                node => node,
+                                message_id => "invalid_void_expr_arrow_wrap_void",
+                                fix => |fixer| {
+                                    fixer.replace_text(node, format!("void {}", node.text(context)));
+                                },
+                            });
+                            return;
+                        }
+
+                        let body = invalid_ancestor.field("body");
+                        let can_fix = is_void_value(body, context);
+                        context.report(violation! {
+                            node => node,
+                            message_id => "invalid_void_expr_arrow",
+                            fix => |fixer| {
+                                if !can_fix {
+                                    return;
+                                }
+
+                                // (dropping any parentheses around the body)
+                                let body_text = skip_parenthesized_expressions(body).text(context);
+                                let arrow = invalid_ancestor
+                                    .non_comment_children(SupportedLanguage::Javascript)
+                                    .find(|child| child.kind() == "=>")
+                                    .unwrap();
+                                if body.start_position().row == arrow.end_position().row {
+                                    fixer.replace_text(body, format!("{{ {body_text}; }}"));
+                                    return;
+                                }
+
+                                // the body is on its own line, so it gets to
+                                // stay on its own line inside the block
+                                fixer.replace_text_range(
+                                    Range {
+                                        start_byte: arrow.end_byte(),
+                                        end_byte: body.end_byte(),
+                                        start_point: arrow.end_position(),
+                                        end_point: body.end_position(),
+                                    },
+                                    IndentedText::new(arrow, context)
+                                        .push(" {")
+                                        .newline(1)
+                                        .push(&body_text)
+                                        .push(";")
+                                        .newline(0)
+                                        .push("}")
+                                        .build(),
+                                );
+                            },
+                        });
+                    }
+                    ReturnStatement => self.report_return(node, invalid_ancestor, context),
+                    _ => {
+                        // TODO: suggestions?
+                        context.report(violation! {
+                            node => node,
+                            message_id => if self.ignore_void_operator {
+                                "invalid_void_expr_wrap_void"
+                            } else {
+                                "invalid_void_expr"
+                            },
+                        });
+                    }
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_confusing_void_expression_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "() => Math.random();",
+              "console.log('foo');",
+              "foo && console.log(foo);",
+              "foo || console.log(foo);",
+              "foo ? console.log(true) : console.log(false);",
+              "(console.log('foo'));",
+              r#"
+function cool(input: string) {
+  return console.log(input), input;
+}
+                  "#,
+              r#"
+function doThing(): void {}
+function foo() {
+  doThing();
+}
+                  "#,
+              r#"
+function getNumber(): number {
+  return 1;
+}
+const x = getNumber();
+                  "#,
+              r#"
+async function doThing() {}
+async function foo() {
+  await doThing();
+  const promise = doThing();
+}
+                  "#,
+              r#"
+function* gen() {}
+const iterator = gen();
+                  "#,
+              {
+                code => "() => console.log('foo');",
+                options => { ignore_arrow_shorthand => true },
+              },
+              {
+                code => "foo => foo && console.log(foo);",
+                options => { ignore_arrow_shorthand => true },
+              },
+              {
+                code => "!void console.log('foo');",
+                options => { ignore_void_operator => true },
+              },
+              {
+                code => "const x = void console.log('foo');",
+                options => { ignore_void_operator => true },
+              },
+            ],
+            invalid => [
+              {
+                code => "const x = console.log('foo');",
+                errors => [
+                  {
+                    message_id => "invalid_void_expr",
+                    line => 1,
+                    column => 11,
+                  },
+                ],
+              },
+              {
+                code => "const x = console?.log('foo');",
+                errors => [{ message_id => "invalid_void_expr", column => 11 }],
+              },
+              {
+                code => "console.error(console.log('foo'));",
+                errors => [{ message_id => "invalid_void_expr", column => 15 }],
+              },
+              {
+                code => "[console.log('foo')];",
+                errors => [{ message_id => "invalid_void_expr", column => 2 }],
+              },
+              {
+                code => "({ x: console.log('foo') });",
+                errors => [{ message_id => "invalid_void_expr", column => 7 }],
+              },
+              {
+                code => "void console.log('foo');",
+                errors => [{ message_id => "invalid_void_expr", column => 6 }],
+              },
+              {
+                code => "console.log('foo') ? true : false;",
+                errors => [{ message_id => "invalid_void_expr", column => 1 }],
+              },
+              {
+                code => "(console.log('foo') && true) || false;",
+                errors => [{ message_id => "invalid_void_expr", column => 2 }],
+              },
+              {
+                code => "(cond && console.log('ok')) || console.log('error');",
+                errors => [{ message_id => "invalid_void_expr", column => 10 }],
+              },
+              {
+                code => "!console.log('foo');",
+                errors => [{ message_id => "invalid_void_expr", column => 2 }],
+              },
+              {
+                code => "if (console.log('foo')) {}",
+                errors => [{ message_id => "invalid_void_expr", column => 5 }],
+              },
+              {
+                code => r#"
+const doThing = () => {};
+const x = doThing();
+                    "#,
+                errors => [{ message_id => "invalid_void_expr", line => 3, column => 11 }],
+              },
+              {
+                code => r#"
+function foo(callback: () => void) {
+  const x = callback();
+}
+                    "#,
+                errors => [{ message_id => "invalid_void_expr", line => 3, column => 13 }],
+              },
+              {
+                code => r#"
+async function doThing(): Promise<void> {}
+async function foo() {
+  const x = await doThing();
+}
+                    "#,
+                errors => [{ message_id => "invalid_void_expr", line => 4, column => 13 }],
+              },
+              {
+                code => "() => console.log('foo');",
+                output => "() => { console.log('foo'); };",
+                errors => [{ message_id => "invalid_void_expr_arrow", column => 7 }],
+              },
+              {
+                code => "() => (console.log('foo'));",
+                output => "() => { console.log('foo'); };",
+                errors => [{ message_id => "invalid_void_expr_arrow", column => 8 }],
+              },
+              {
+                code => "foo => foo && console.log(foo);",
+                errors => [{ message_id => "invalid_void_expr_arrow", column => 15 }],
+              },
+              {
+                code => r#"
+const f = () =>
+  console.log('foo');
+                    "#,
+                output => r#"
+const f = () => {
+  console.log('foo');
+};
+                    "#,
+                errors => [{ message_id => "invalid_void_expr_arrow", line => 3, column => 3 }],
+              },
+              {
+                code => r#"
+class Foo {
+  log = (message: string) =>
+    console.log(message);
+}
+                    "#,
+                output => r#"
+class Foo {
+  log = (message: string) => {
+    console.log(message);
+  };
+}
+                    "#,
+                errors => [{ message_id => "invalid_void_expr_arrow", line => 4, column => 5 }],
+              },
+              {
+                code => "() => console.log('foo');",
+                options => { ignore_void_operator => true },
+                output => "() => void console.log('foo');",
+                errors => [{ message_id => "invalid_void_expr_arrow_wrap_void", column => 7 }],
+              },
+              {
+                code => "foo => foo && console.log(foo);",
+                options => { ignore_void_operator => true },
+                output => "foo => foo && void console.log(foo);",
+                errors => [{ message_id => "invalid_void_expr_arrow_wrap_void", column => 15 }],
+              },
+              {
+                code => r#"
+function notcool(input: string) {
+  return input, console.log(input);
+}
+                    "#,
+                output => r#"
+function notcool(input: string) {
+  input, console.log(input);
+}
+                    "#,
+                errors => [{ message_id => "invalid_void_expr_return_last", line => 3, column => 17 }],
+              },
+              {
+                code => r#"
+function f() {
+  return (console.log('foo'));
+}
+                    "#,
+                output => r#"
+function f() {
+  console.log('foo');
+}
+                    "#,
+                errors => [{ message_id => "invalid_void_expr_return_last", line => 3, column => 11 }],
+              },
+              {
+                code => r#"
+function f() {
+  return cond && console.log('foo');
+}
+                    "#,
+                errors => [{ message_id => "invalid_void_expr_return_last", line => 3 }],
+              },
+              {
+                code => r#"
+function doThing(): void {}
+function f() {
+  if (cond) return doThing();
+  return doThing();
+}
+                    "#,
+                output => r#"
+function doThing(): void {}
+function f() {
+  if (cond) { doThing(); return; }
+  doThing();
+}
+                    "#,
+                errors => [
+                  { message_id => "invalid_void_expr_return", line => 4, column => 20 },
+                  { message_id => "invalid_void_expr_return_last", line => 5, column => 10 },
+                ],
+              },
+              {
+                code => r#"
+function f() {
+  for (const x of xs) {
+    return console.log(x);
+  }
+}
+                    "#,
+                output => r#"
+function f() {
+  for (const x of xs) {
+    console.log(x); return;
+  }
+}
+                    "#,
+                errors => [{ message_id => "invalid_void_expr_return", line => 4 }],
+              },
+              {
+                code => r#"
+function f() {
+  return console.log('foo');
+}
+                    "#,
+                options => { ignore_void_operator => true },
+                output => r#"
+function f() {
+  return void console.log('foo');
+}
+                    "#,
+                errors => [{ message_id => "invalid_void_expr_return_wrap_void", line => 3 }],
+              },
+              {
+                code => "const x = console.log('foo');",
+                options => { ignore_void_operator => true },
+                errors => [{ message_id => "invalid_void_expr_wrap_void", column => 11 }],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_confusing_void_expression_rule() {
+        run_for_typescript_and_tsx(
+            no_confusing_void_expression_rule(),
+            test_cases::no_confusing_void_expression_rule_tests(),
+        );
+    }
+}
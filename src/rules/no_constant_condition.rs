@@ -0,0 +1,400 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    Array, ArrowFunction, AssignmentExpression, AugmentedAssignmentExpression, BinaryExpression,
+    CallExpression, Class, EmptyStatement, ExpressionStatement, Function, GeneratorFunction,
+    Identifier, MemberExpression, NewExpression, Object, PropertyIdentifier, Regex,
+    SequenceExpression, StringFragment, TemplateString, TemplateSubstitution, UnaryExpression,
+    YieldExpression,
+};
+
+use crate::{
+    ast_helpers::{find_enum_member, skip_parentheses_and_assertions},
+    kind::EnumAssignment,
+    type_declarations::{TypeDeclarationKind, TypeDeclarations},
+    util::{evaluate_constant_expression, is_function},
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    check_loops: Option<bool>,
+}
+
+impl Options {
+    fn check_loops(&self) -> bool {
+        self.check_loops.unwrap_or(true)
+    }
+}
+
+/// `Enum.Member`, where `Enum` is declared in this file and `Member` has a
+/// constant value.
+fn is_constant_enum_member<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+    type_declarations: &TypeDeclarations<'a>,
+) -> bool {
+    let object = node.field("object");
+    let property = node.field("property");
+    if object.kind() != Identifier || property.kind() != PropertyIdentifier {
+        return false;
+    }
+    let property = property.text(context);
+    type_declarations
+        .get_of_kind(&object.text(context), TypeDeclarationKind::Enum)
+        .filter_map(|declaration| find_enum_member(declaration, &property, context))
+        .any(|member| {
+            member.kind() != EnumAssignment
+                || evaluate_constant_expression(member.field("value"), context).is_some()
+        })
+}
+
+/// Whether `node` is truthy or falsy such that `||` or `&&` (respectively)
+/// always evaluates to the other operand.
+fn is_logical_identity(node: Node, operator: &str, context: &QueryMatchContext) -> bool {
+    let node = skip_parentheses_and_assertions(node);
+    let is_truthy = match evaluate_constant_expression(node, context) {
+        Some(value) => value.is_truthy(),
+        None => match node.kind() {
+            Object | Array | ArrowFunction | Function | Class | Regex => true,
+            UnaryExpression if node.field("operator").kind() == "void" => false,
+            _ => return false,
+        },
+    };
+    match operator {
+        "||" => is_truthy,
+        "&&" => !is_truthy,
+        _ => false,
+    }
+}
+
+fn is_constant<'a>(
+    node: Node<'a>,
+    in_boolean_position: bool,
+    context: &QueryMatchContext<'a, '_>,
+    type_declarations: &TypeDeclarations<'a>,
+) -> bool {
+    let node = skip_parentheses_and_assertions(node);
+    if evaluate_constant_expression(node, context).is_some() {
+        return true;
+    }
+    match node.kind() {
+        Object | Array | ArrowFunction | Function | GeneratorFunction | Class | Regex => true,
+        TemplateString => {
+            in_boolean_position
+                && node
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                    .any(|child| child.kind() == StringFragment)
+                || node
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                    .filter(|child| child.kind() == TemplateSubstitution)
+                    .all(|substitution| {
+                        is_constant(
+                            substitution
+                                .first_non_comment_named_child(SupportedLanguage::Javascript),
+                            false,
+                            context,
+                            type_declarations,
+                        )
+                    })
+        }
+        UnaryExpression => match node.field("operator").kind() {
+            "void" => true,
+            "typeof" => in_boolean_position,
+            "!" => is_constant(node.field("argument"), true, context, type_declarations),
+            _ => is_constant(node.field("argument"), false, context, type_declarations),
+        },
+        BinaryExpression => {
+            let operator = node.field("operator").kind();
+            let left = node.field("left");
+            let right = node.field("right");
+            match operator {
+                "||" | "&&" | "??" => {
+                    let is_left_constant =
+                        is_constant(left, in_boolean_position, context, type_declarations);
+                    let is_right_constant =
+                        is_constant(right, in_boolean_position, context, type_declarations);
+                    is_left_constant && is_right_constant
+                        || is_left_constant && is_logical_identity(left, operator, context)
+                        || in_boolean_position
+                            && is_right_constant
+                            && is_logical_identity(right, operator, context)
+                }
+                "in" => false,
+                _ => {
+                    is_constant(left, false, context, type_declarations)
+                        && is_constant(right, false, context, type_declarations)
+                }
+            }
+        }
+        AssignmentExpression => is_constant(
+            node.field("right"),
+            in_boolean_position,
+            context,
+            type_declarations,
+        ),
+        AugmentedAssignmentExpression => match node.field("operator").kind() {
+            "||=" | "&&=" if in_boolean_position => {
+                let operator = node.field("operator").kind();
+                is_logical_identity(node.field("right"), &operator[..2], context)
+            }
+            _ => false,
+        },
+        SequenceExpression => is_constant(
+            node.non_comment_named_children(SupportedLanguage::Javascript)
+                .last()
+                .unwrap(),
+            in_boolean_position,
+            context,
+            type_declarations,
+        ),
+        CallExpression => {
+            let callee = node.field("function");
+            if callee.kind() != Identifier || callee.text(context) != "Boolean" {
+                return false;
+            }
+            node.field("arguments")
+                .non_comment_named_children(SupportedLanguage::Javascript)
+                .next()
+                .map_or(true, |argument| {
+                    is_constant(argument, true, context, type_declarations)
+                })
+        }
+        NewExpression => in_boolean_position,
+        MemberExpression => is_constant_enum_member(node, context, type_declarations),
+        _ => false,
+    }
+}
+
+/// A `yield` in a loop in a generator can make an otherwise constant loop
+/// condition intentional.
+fn contains_yield(node: Node) -> bool {
+    node.kind() == YieldExpression
+        || !is_function(node)
+            && node
+                .non_comment_named_children(SupportedLanguage::Javascript)
+                .any(contains_yield)
+}
+
+pub fn no_constant_condition_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-constant-condition",
+        languages => [Typescript],
+        messages => [
+            unexpected => "Unexpected constant condition.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            check_loops: bool = options.check_loops(),
+        },
+        methods => {
+            fn check_condition(&self, condition: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+                let condition = match condition.kind() {
+                    ExpressionStatement => condition.first_non_comment_named_child(SupportedLanguage::Javascript),
+                    EmptyStatement => return,
+                    _ => condition,
+                };
+                if !is_constant(condition, true, context, &context.retrieve::<TypeDeclarations<'a>>()) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => skip_parentheses_and_assertions(condition),
+                    message_id => "unexpected",
+                });
+            }
+        },
+        listeners => [
+            r#"
+              (if_statement
+                condition: (_) @c
+              )
+              (ternary_expression
+                condition: (_) @c
+              )
+            "# => |node, context| {
+                self.check_condition(node, context);
+            },
+            r#"
+              (while_statement
+                condition: (_) @c
+              )
+              (do_statement
+                condition: (_) @c
+              )
+              (for_statement
+                condition: (_) @c
+              )
+            "# => |node, context| {
+                if !self.check_loops {
+                    return;
+                }
+                if contains_yield(node.parent().unwrap().field("body")) {
+                    return;
+                }
+
+                self.check_condition(node, context);
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_constant_condition_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "if (a);",
+              "if (a == 0);",
+              "if (a = f());",
+              "if (typeof x === 'undefined');",
+              "if (`${foo}` === 'bar');",
+              "while (~!a);",
+              "for (;;);",
+              "do {} while (x)",
+              "q > 0 ? 1 : 2;",
+              "if (x && 'abc' in y);",
+              "function* foo() { while (true) { yield 'foo'; } }",
+              r#"
+let x = 1;
+if (x);
+                  "#,
+              r#"
+enum Direction { Up = foo() }
+if (Direction.Up);
+                  "#,
+              r#"
+const DEBUG = false;
+function f(DEBUG: boolean) {
+  if (DEBUG) {
+  }
+}
+                  "#,
+              r#"
+const x = 0;
+{
+  let x = g();
+  while (x) {}
+}
+                  "#,
+              r#"
+if (Direction.Up);
+                  "#,
+              {
+                code => "while (true);",
+                options => { check_loops => false },
+              },
+            ],
+            invalid => [
+              {
+                code => "if (true);",
+                errors => [
+                  {
+                    message_id => "unexpected",
+                    line => 1,
+                    column => 5,
+                    end_line => 1,
+                    end_column => 9,
+                  },
+                ],
+              },
+              {
+                code => "if ({});",
+                errors => [{ message_id => "unexpected" }],
+              },
+              {
+                code => "if (0 < 1);",
+                errors => [{ message_id => "unexpected" }],
+              },
+              {
+                code => "if (typeof x);",
+                errors => [{ message_id => "unexpected" }],
+              },
+              {
+                code => "if (x || true);",
+                errors => [{ message_id => "unexpected" }],
+              },
+              {
+                code => "if (`foo${x}`);",
+                errors => [{ message_id => "unexpected" }],
+              },
+              {
+                code => "while (true);",
+                errors => [{ message_id => "unexpected" }],
+              },
+              {
+                code => "do {} while (1)",
+                errors => [{ message_id => "unexpected" }],
+              },
+              {
+                code => "for (; 'abc';);",
+                errors => [{ message_id => "unexpected" }],
+              },
+              {
+                code => "const x = y ? 1 : (true as boolean) ? 2 : 3;",
+                errors => [{ message_id => "unexpected", line => 1, column => 20 }],
+              },
+              {
+                code => "if (<boolean>true);",
+                errors => [{ message_id => "unexpected", line => 1, column => 14 }],
+              },
+              {
+                code => "if (Boolean(1));",
+                errors => [{ message_id => "unexpected" }],
+              },
+              {
+                code => "if (new Foo());",
+                errors => [{ message_id => "unexpected" }],
+              },
+              {
+                code => r#"
+const DEBUG = false;
+if (DEBUG);
+                    "#,
+                errors => [{ message_id => "unexpected", line => 3, column => 5 }],
+              },
+              {
+                code => r#"
+const prefix = 'a';
+const key = `${prefix}b`;
+if (key === 'ab');
+                    "#,
+                errors => [{ message_id => "unexpected", line => 4, column => 5 }],
+              },
+              {
+                code => r#"
+enum Flags { None, Verbose = 1 << 1 }
+if (Flags.None);
+if (Flags.Verbose);
+                    "#,
+                errors => [
+                  { message_id => "unexpected", line => 3, column => 5 },
+                  { message_id => "unexpected", line => 4, column => 5 },
+                ],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx_with_provided_types;
+
+    #[test]
+    fn test_no_constant_condition_rule() {
+        run_for_typescript_and_tsx_with_provided_types(
+            no_constant_condition_rule(),
+            test_cases::no_constant_condition_rule_tests(),
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    kind::{Number, String as StringKind, SubscriptExpression, UnaryExpression},
+    utils::ast_utils::get_static_string_value,
+};
+
+use crate::{
+    ast_helpers::get_range_from,
+    util::{is_valid_identifier, skip_parenthesized_expressions},
+};
+
+fn dive_into_wrapper_expressions(node: Node) -> Node {
+    let node = skip_parenthesized_expressions(node);
+    match node.kind() {
+        UnaryExpression => dive_into_wrapper_expressions(node.field("argument")),
+        _ => node,
+    }
+}
+
+/// Numeric keys (eg array indices) and keys that couldn't be written with
+/// dot notation anyway.
+fn is_necessary_dynamic_access<'a>(
+    property: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    match property.kind() {
+        Number => true,
+        StringKind => !is_valid_identifier(&get_static_string_value(property, context).unwrap()),
+        _ => false,
+    }
+}
+
+pub fn no_dynamic_delete_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-dynamic-delete",
+        languages => [Typescript],
+        messages => [
+            dynamic_delete => "Do not delete dynamically computed property keys.",
+        ],
+        fixable => true,
+        listeners => [
+            r#"
+              (unary_expression
+                operator: "delete"
+              ) @c
+            "# => |node, context| {
+                let member = skip_parenthesized_expressions(node.field("argument"));
+                if member.kind() != SubscriptExpression {
+                    return;
+                }
+                let property = member.field("index");
+                if is_necessary_dynamic_access(dive_into_wrapper_expressions(property), context) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => property,
+                    message_id => "dynamic_delete",
+                    fix => |fixer| {
+                        // template literals and other expressions are left
+                        // alone
+                        if property.kind() != StringKind {
+                            return;
+                        }
+                        let name = get_static_string_value(property, context).unwrap();
+                        let (start, replacement) = match member.child_by_field_name("optional_chain") {
+                            Some(optional_chain) => (optional_chain, format!("?.{name}")),
+                            None => (
+                                member
+                                    .non_comment_children(SupportedLanguage::Javascript)
+                                    .find(|child| child.kind() == "[")
+                                    .unwrap(),
+                                format!(".{name}"),
+                            ),
+                        };
+                        fixer.replace_text_range(get_range_from(start, member), replacement);
+                    }
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_dynamic_delete_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "
+const container: { [i: string]: 0 } = {};
+delete container.aaa;
+                    ",
+                "
+const container: { [i: string]: 0 } = {};
+delete container.delete;
+                    ",
+                "
+const container: { [i: string]: 0 } = {};
+delete container[7];
+                    ",
+                "
+const container: { [i: string]: 0 } = {};
+delete container[-7];
+                    ",
+                "
+const container: { [i: string]: 0 } = {};
+delete container[+7];
+                    ",
+                "
+const container: { [i: string]: 0 } = {};
+delete container['-Infinity'];
+                    ",
+                "
+const container: { [i: string]: 0 } = {};
+delete container['+Infinity'];
+                    ",
+                "
+const value = 1;
+delete value;
+                    ",
+                "
+const value = 1;
+delete -value;
+                    ",
+                "
+const container: { [i: string]: 0 } = {};
+delete container['aa-a'];
+                    ",
+                "
+const container: { [i: string]: 0 } = {};
+delete container[''];
+                    ",
+            ],
+            invalid => [
+                {
+                  code => "
+const container: { [i: string]: 0 } = {};
+delete container['aaa'];
+                      ",
+                  output => "
+const container: { [i: string]: 0 } = {};
+delete container.aaa;
+                      ",
+                  errors => [
+                    {
+                      message_id => "dynamic_delete",
+                      line => 3,
+                      column => 18,
+                    },
+                  ],
+                },
+                {
+                  code => "
+const container: { [i: string]: 0 } = {};
+delete container [ 'aaa' ] ;
+                      ",
+                  output => "
+const container: { [i: string]: 0 } = {};
+delete container .aaa ;
+                      ",
+                  errors => [{ message_id => "dynamic_delete" }],
+                },
+                {
+                  code => "
+const container: { [i: string]: 0 } = {};
+delete container[\"aaa\"];
+                      ",
+                  output => "
+const container: { [i: string]: 0 } = {};
+delete container.aaa;
+                      ",
+                  errors => [{ message_id => "dynamic_delete" }],
+                },
+                {
+                  code => "
+const container: { [i: string]: 0 } = {};
+delete container?.['aaa'];
+                      ",
+                  output => "
+const container: { [i: string]: 0 } = {};
+delete container?.aaa;
+                      ",
+                  errors => [{ message_id => "dynamic_delete" }],
+                },
+                {
+                  code => "
+const container: { [i: string]: 0 } = {};
+delete container['delete'];
+                      ",
+                  output => "
+const container: { [i: string]: 0 } = {};
+delete container.delete;
+                      ",
+                  errors => [{ message_id => "dynamic_delete" }],
+                },
+                {
+                  code => "
+const container: { [i: string]: 0 } = {};
+delete container[`aaa`];
+                      ",
+                  errors => [{ message_id => "dynamic_delete" }],
+                },
+                {
+                  code => "
+const container: { [i: string]: 0 } = {};
+const name = 'name';
+delete container[name];
+                      ",
+                  errors => [{ message_id => "dynamic_delete", line => 4, column => 18 }],
+                },
+                {
+                  code => "
+const container: { [i: string]: 0 } = {};
+const getName = () => 'aaa';
+delete container[getName()];
+                      ",
+                  errors => [{ message_id => "dynamic_delete" }],
+                },
+                {
+                  code => "
+const container: { [i: string]: 0 } = {};
+const name = { foo: { bar: 'bar' } };
+delete container[name.foo.bar];
+                      ",
+                  errors => [{ message_id => "dynamic_delete" }],
+                },
+                {
+                  code => "
+const container: { [i: string]: 0 } = {};
+delete container[+'Infinity'];
+                      ",
+                  errors => [{ message_id => "dynamic_delete" }],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_dynamic_delete_rule() {
+        run_for_typescript_and_tsx(
+            no_dynamic_delete_rule(),
+            test_cases::no_dynamic_delete_rule_tests(),
+        );
+    }
+}
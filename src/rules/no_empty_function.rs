@@ -0,0 +1,458 @@
+use std::{collections::HashSet, sync::Arc};
+
+use serde::Deserialize;
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    ast_helpers::{get_method_definition_kind, MethodDefinitionKind},
+    kind::{
+        ArrowFunction, ClassBody, GeneratorFunction, GeneratorFunctionDeclaration,
+        MethodDefinition, Object, StatementBlock,
+    },
+    utils::ast_utils,
+};
+
+use crate::{
+    ast_helpers::{
+        get_accessibility_modifier, get_decorators, has_override_modifier, is_async_function,
+    },
+    util::{constructor_has_parameter_properties, nearest_class},
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum Allow {
+    Functions,
+    ArrowFunctions,
+    GeneratorFunctions,
+    Methods,
+    GeneratorMethods,
+    Getters,
+    Setters,
+    Constructors,
+    #[serde(rename = "private-constructors")]
+    PrivateConstructors,
+    #[serde(rename = "protected-constructors")]
+    ProtectedConstructors,
+    AsyncFunctions,
+    AsyncMethods,
+    DecoratedFunctions,
+    OverrideMethods,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    allow: Option<Vec<Allow>>,
+}
+
+impl Options {
+    fn allow(&self) -> HashSet<Allow> {
+        self.allow.clone().unwrap_or_default().into_iter().collect()
+    }
+}
+
+fn get_kind(node: Node, context: &QueryMatchContext) -> Allow {
+    if node.kind() == ArrowFunction {
+        return Allow::ArrowFunctions;
+    }
+
+    let is_method = node.kind() == MethodDefinition;
+    if is_method {
+        match get_method_definition_kind(node, context) {
+            MethodDefinitionKind::Get => return Allow::Getters,
+            MethodDefinitionKind::Set => return Allow::Setters,
+            // (an object literal method named `constructor` is just a method)
+            MethodDefinitionKind::Constructor if node.parent().unwrap().kind() == ClassBody => {
+                return Allow::Constructors;
+            }
+            _ => (),
+        }
+    }
+
+    let is_generator = match node.kind() {
+        GeneratorFunction | GeneratorFunctionDeclaration => true,
+        MethodDefinition => node.has_child_of_kind("*"),
+        _ => false,
+    };
+    match (is_method, is_generator, is_async_function(node)) {
+        (false, true, _) => Allow::GeneratorFunctions,
+        (false, false, true) => Allow::AsyncFunctions,
+        (false, false, false) => Allow::Functions,
+        (true, true, _) => Allow::GeneratorMethods,
+        (true, false, true) => Allow::AsyncMethods,
+        (true, false, false) => Allow::Methods,
+    }
+}
+
+fn is_body_empty(node: Node) -> bool {
+    let body = node.field("body");
+    // (comments count as content)
+    body.kind() == StatementBlock && body.named_child_count() == 0
+}
+
+fn is_class_method(node: Node) -> bool {
+    node.kind() == MethodDefinition && node.parent().unwrap().kind() != Object
+}
+
+pub fn no_empty_function_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-empty-function",
+        languages => [Typescript],
+        messages => [
+            unexpected => "Unexpected empty {{name}}.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            allow: HashSet<Allow> = options.allow(),
+        },
+        methods => {
+            fn is_allowed_empty_constructor(&self, node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+                if get_kind(node, context) != Allow::Constructors {
+                    return false;
+                }
+                match get_accessibility_modifier(node).map(|modifier| modifier.text(context)).as_deref() {
+                    Some("protected") if self.allow.contains(&Allow::ProtectedConstructors) => true,
+                    Some("private") if self.allow.contains(&Allow::PrivateConstructors) => true,
+                    _ => constructor_has_parameter_properties(node),
+                }
+            }
+
+            // methods of decorated classes count too, since frameworks
+            // (eg Angular) expect lifecycle methods to exist even if empty
+            fn is_allowed_empty_decorated_function(&self, node: Node<'a>) -> bool {
+                self.allow.contains(&Allow::DecoratedFunctions)
+                    && is_class_method(node)
+                    && (get_decorators(node).next().is_some()
+                        || nearest_class(node).is_some_and(|class| get_decorators(class).next().is_some()))
+            }
+
+            fn is_allowed_empty_override_method(&self, node: Node<'a>) -> bool {
+                self.allow.contains(&Allow::OverrideMethods)
+                    && is_class_method(node)
+                    && has_override_modifier(node)
+            }
+        },
+        listeners => [
+            r#"
+              (function_declaration) @c
+              (function) @c
+              (generator_function_declaration) @c
+              (generator_function) @c
+              (arrow_function) @c
+              (method_definition) @c
+            "# => |node, context| {
+                if !is_body_empty(node) || self.allow.contains(&get_kind(node, context)) {
+                    return;
+                }
+                if self.is_allowed_empty_constructor(node, context)
+                    || self.is_allowed_empty_decorated_function(node)
+                    || self.is_allowed_empty_override_method(node)
+                {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    range => node.field("body").range(),
+                    message_id => "unexpected",
+                    data => {
+                        name => ast_utils::get_function_name_with_kind(node, context),
+                    },
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_empty_function_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "function foo() { bar(); }",
+                "function foo() { /* empty */ }",
+                "const foo = () => {\n  // empty\n};",
+                "const foo = () => bar;",
+                {
+                  code => "function foo() {}",
+                  options => { allow => ["functions"] },
+                },
+                {
+                  code => "const foo = () => {};",
+                  options => { allow => ["arrowFunctions"] },
+                },
+                {
+                  code => "function* foo() {}",
+                  options => { allow => ["generatorFunctions"] },
+                },
+                {
+                  code => "async function foo() {}",
+                  options => { allow => ["asyncFunctions"] },
+                },
+                {
+                  code => "const foo = { bar() {} };",
+                  options => { allow => ["methods"] },
+                },
+                {
+                  code => "const foo = { *bar() {} };",
+                  options => { allow => ["generatorMethods"] },
+                },
+                {
+                  code => "class Foo { async bar() {} }",
+                  options => { allow => ["asyncMethods"] },
+                },
+                {
+                  code => "const foo = { get bar() {} };",
+                  options => { allow => ["getters"] },
+                },
+                {
+                  code => "class Foo { set bar(value) {} }",
+                  options => { allow => ["setters"] },
+                },
+                {
+                  code => "class Foo { constructor() {} }",
+                  options => { allow => ["constructors"] },
+                },
+                "
+class Person {
+  private name: string;
+  constructor(name: string) {
+    this.name = name;
+  }
+}
+                    ",
+                "
+class Person {
+  constructor(private name: string) {}
+}
+                    ",
+                "
+class Person {
+  constructor(name: string) {}
+}
+                    ",
+                {
+                  code => "
+class Person {
+  private constructor(name: string) {}
+}
+                      ",
+                  options => { allow => ["private-constructors"] },
+                },
+                {
+                  code => "
+class Person {
+  protected constructor(name: string) {}
+}
+                      ",
+                  options => { allow => ["protected-constructors"] },
+                },
+                "
+function foo() {
+  const a = null;
+}
+                    ",
+                {
+                  code => "
+@decorator()
+class Foo {}
+                      ",
+                  options => { allow => ["decoratedFunctions"] },
+                },
+                {
+                  code => "
+class Foo {
+  @decorator()
+  foo() {}
+}
+                      ",
+                  options => { allow => ["decoratedFunctions"] },
+                },
+                {
+                  code => "
+@Component({})
+class Foo {
+  ngOnInit() {}
+}
+                      ",
+                  options => { allow => ["decoratedFunctions"] },
+                },
+                {
+                  code => "
+class Foo extends Base {
+  override foo() {}
+}
+                      ",
+                  options => { allow => ["overrideMethods"] },
+                },
+            ],
+            invalid => [
+                {
+                  code => "function foo() {}",
+                  errors => [
+                    {
+                      message_id => "unexpected",
+                      data => { name => "function 'foo'" },
+                      line => 1,
+                      column => 16,
+                    },
+                  ],
+                },
+                {
+                  code => "const foo = () => {};",
+                  errors => [
+                    {
+                      message_id => "unexpected",
+                      data => { name => "arrow function 'foo'" },
+                      column => 19,
+                    },
+                  ],
+                },
+                {
+                  code => "const foo = { bar() {} };",
+                  options => { allow => ["functions"] },
+                  errors => [
+                    {
+                      message_id => "unexpected",
+                      data => { name => "method 'bar'" },
+                    },
+                  ],
+                },
+                {
+                  code => "
+class Person {
+  constructor(name: string) {}
+}
+                      ",
+                  options => { allow => ["private-constructors"] },
+                  errors => [
+                    {
+                      message_id => "unexpected",
+                      data => { name => "constructor" },
+                      line => 3,
+                      column => 29,
+                    },
+                  ],
+                },
+                {
+                  code => "
+class Person {
+  private constructor(name: string) {}
+}
+                      ",
+                  errors => [
+                    {
+                      message_id => "unexpected",
+                      data => { name => "constructor" },
+                      line => 3,
+                      column => 37,
+                    },
+                  ],
+                },
+                {
+                  code => "
+class Person {
+  protected constructor(name: string) {}
+}
+                      ",
+                  options => { allow => ["private-constructors"] },
+                  errors => [
+                    {
+                      message_id => "unexpected",
+                      data => { name => "constructor" },
+                      line => 3,
+                      column => 39,
+                    },
+                  ],
+                },
+                {
+                  code => "
+function foo() {}
+                      ",
+                  errors => [
+                    {
+                      message_id => "unexpected",
+                      data => { name => "function 'foo'" },
+                      line => 2,
+                      column => 16,
+                    },
+                  ],
+                },
+                {
+                  code => "
+class Foo {
+  @decorator()
+  foo() {}
+}
+                      ",
+                  errors => [
+                    {
+                      message_id => "unexpected",
+                      data => { name => "method 'foo'" },
+                      line => 4,
+                      column => 9,
+                    },
+                  ],
+                },
+                {
+                  code => "
+@Component({})
+class Foo {
+  helper = function () {};
+}
+                      ",
+                  options => { allow => ["decoratedFunctions"] },
+                  errors => [{ message_id => "unexpected", line => 4 }],
+                },
+                {
+                  code => "
+class Foo extends Base {
+  override foo() {}
+}
+                      ",
+                  errors => [
+                    {
+                      message_id => "unexpected",
+                      data => { name => "method 'foo'" },
+                      line => 3,
+                      column => 18,
+                    },
+                  ],
+                },
+                {
+                  code => "
+class Foo extends Base {
+  foo() {}
+}
+                      ",
+                  options => { allow => ["overrideMethods"] },
+                  errors => [
+                    {
+                      message_id => "unexpected",
+                      data => { name => "method 'foo'" },
+                      line => 3,
+                      column => 9,
+                    },
+                  ],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_empty_function_rule() {
+        run_for_typescript_and_tsx(
+            no_empty_function_rule(),
+            test_cases::no_empty_function_rule_tests(),
+        );
+    }
+}
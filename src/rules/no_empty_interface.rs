@@ -0,0 +1,457 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use squalid::OptionExt;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt, Rule,
+};
+
+use crate::{
+    kind::{AmbientDeclaration, ExtendsTypeClause, InternalModule, Module},
+    type_declarations::{TypeDeclarationKind, TypeDeclarations},
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    allow_single_extends: Option<bool>,
+}
+
+impl Options {
+    fn allow_single_extends(&self) -> bool {
+        self.allow_single_extends.unwrap_or_default()
+    }
+}
+
+fn is_in_ambient_module_declaration(node: Node) -> bool {
+    node.ancestors()
+        .find(|ancestor| matches!(ancestor.kind(), InternalModule | Module))
+        .and_then(|module| module.parent())
+        .matches(|parent| parent.kind() == AmbientDeclaration)
+}
+
+pub fn no_empty_interface_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-empty-interface",
+        languages => [Typescript],
+        messages => [
+            no_empty => "An empty interface is equivalent to `{}`.",
+            no_empty_with_super => "An interface declaring no members is equivalent to its supertype.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            allow_single_extends: bool = options.allow_single_extends(),
+        },
+        listeners => [
+            r#"
+              (interface_declaration) @c
+            "# => |node, context| {
+                if node.field("body").num_non_comment_named_children(SupportedLanguage::Javascript) != 0 {
+                    return;
+                }
+
+                let extends = node
+                    .maybe_first_child_of_kind(ExtendsTypeClause)
+                    .map(|extends| extends.non_comment_named_children(SupportedLanguage::Javascript).collect::<Vec<_>>())
+                    .unwrap_or_default();
+
+                if extends.is_empty() {
+                    context.report(violation! {
+                        node => node.field("name"),
+                        message_id => "no_empty",
+                    });
+                    return;
+                }
+
+                if extends.len() != 1 || self.allow_single_extends {
+                    return;
+                }
+
+                let name = node.field("name").text(context);
+                let type_declarations = context.retrieve::<TypeDeclarations<'a>>();
+                let merged_declaration_kinds = type_declarations
+                    .get_merged_declarations(node, &name)
+                    .filter_map(TypeDeclarationKind::from_node)
+                    .collect::<Vec<_>>();
+
+                // an empty interface that merges with another interface still
+                // contributes its supertype to the merged declaration
+                if merged_declaration_kinds.contains(&TypeDeclarationKind::Interface) {
+                    return;
+                }
+
+                let merged_with_class_declaration =
+                    merged_declaration_kinds.contains(&TypeDeclarationKind::Class);
+                // TODO: upstream only treats this as ambient in .d.ts files
+                let is_in_ambient_declaration = is_in_ambient_module_declaration(node);
+                let use_auto_fix = !(is_in_ambient_declaration || merged_with_class_declaration);
+
+                context.report(violation! {
+                    node => node.field("name"),
+                    message_id => "no_empty_with_super",
+                    fix => |fixer| {
+                        if !use_auto_fix {
+                            return;
+                        }
+
+                        fixer.replace_text(
+                            node,
+                            format!(
+                                "type {}{} = {}",
+                                name,
+                                node.child_by_field_name("type_parameters")
+                                    .map(|type_parameters| type_parameters.text(context))
+                                    .unwrap_or_default(),
+                                extends[0].text(context),
+                            ),
+                        );
+                    },
+                    // TODO: suggestions?
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_empty_interface_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              r#"
+interface Foo {
+  name: string;
+}
+                  "#,
+              r#"
+interface Foo {
+  name: string;
+}
+
+interface Bar {
+  age: number;
+}
+
+// valid because extending multiple interfaces can be used instead of a union type
+interface Baz extends Foo, Bar {}
+                  "#,
+              {
+                code => r#"
+interface Foo {
+  name: string;
+}
+
+interface Bar extends Foo {}
+                    "#,
+                options => { allow_single_extends => true },
+              },
+              {
+                code => r#"
+interface Foo {
+  props: string;
+}
+
+interface Bar extends Foo {}
+
+class Bar {}
+                    "#,
+                options => { allow_single_extends => true },
+              },
+              r#"
+interface Foo {
+  props: string;
+}
+
+interface Bar extends Foo {}
+
+interface Bar {
+  other: number;
+}
+                  "#,
+            ],
+            invalid => [
+              {
+                code => "interface Foo {}",
+                errors => [
+                  {
+                    message_id => "no_empty",
+                    line => 1,
+                    column => 11,
+                    end_line => 1,
+                    end_column => 14,
+                  },
+                ],
+              },
+              {
+                code => r#"
+interface Foo {}
+
+interface Foo {
+  props: string;
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "no_empty",
+                    line => 2,
+                    column => 11,
+                    end_line => 2,
+                    end_column => 14,
+                  },
+                ],
+              },
+              {
+                code => r#"
+interface Foo {
+  props: string;
+}
+
+interface Bar extends Foo {}
+
+class Baz {}
+                    "#,
+                output => r#"
+interface Foo {
+  props: string;
+}
+
+type Bar = Foo
+
+class Baz {}
+                    "#,
+                options => { allow_single_extends => false },
+                errors => [
+                  {
+                    message_id => "no_empty_with_super",
+                    line => 6,
+                    column => 11,
+                    end_line => 6,
+                    end_column => 14,
+                  },
+                ],
+              },
+              {
+                code => r#"
+interface Foo {
+  props: string;
+}
+
+interface Bar extends Foo {}
+
+class Bar {}
+                    "#,
+                output => None,
+                options => { allow_single_extends => false },
+                errors => [
+                  {
+                    message_id => "no_empty_with_super",
+                    line => 6,
+                    column => 11,
+                    end_line => 6,
+                    end_column => 14,
+                  },
+                ],
+              },
+              {
+                code => r#"
+interface Foo {
+  props: string;
+}
+
+export interface Bar extends Foo {}
+
+export class Bar {}
+                    "#,
+                output => None,
+                options => { allow_single_extends => false },
+                errors => [
+                  {
+                    message_id => "no_empty_with_super",
+                    line => 6,
+                    column => 18,
+                    end_line => 6,
+                    end_column => 21,
+                  },
+                ],
+              },
+              {
+                code => r#"
+interface Foo {
+  props: string;
+}
+
+interface Bar extends Foo {}
+
+const bar = class Bar {};
+                    "#,
+                output => r#"
+interface Foo {
+  props: string;
+}
+
+type Bar = Foo
+
+const bar = class Bar {};
+                    "#,
+                options => { allow_single_extends => false },
+                errors => [
+                  {
+                    message_id => "no_empty_with_super",
+                    line => 6,
+                    column => 11,
+                    end_line => 6,
+                    end_column => 14,
+                  },
+                ],
+              },
+              {
+                code => r#"
+interface Foo {
+  name: string;
+}
+
+interface Bar extends Foo {}
+                    "#,
+                output => r#"
+interface Foo {
+  name: string;
+}
+
+type Bar = Foo
+                    "#,
+                options => { allow_single_extends => false },
+                errors => [
+                  {
+                    message_id => "no_empty_with_super",
+                    line => 6,
+                    column => 11,
+                    end_line => 6,
+                    end_column => 14,
+                  },
+                ],
+              },
+              {
+                code => "interface Foo extends Array<number> {}",
+                output => "type Foo = Array<number>",
+                errors => [
+                  {
+                    message_id => "no_empty_with_super",
+                    line => 1,
+                    column => 11,
+                    end_line => 1,
+                    end_column => 14,
+                  },
+                ],
+              },
+              {
+                code => "interface Foo extends Array<number | {}> {}",
+                output => "type Foo = Array<number | {}>",
+                errors => [
+                  {
+                    message_id => "no_empty_with_super",
+                    line => 1,
+                    column => 11,
+                    end_line => 1,
+                    end_column => 14,
+                  },
+                ],
+              },
+              {
+                code => r#"
+interface Bar {
+  bar: string;
+}
+interface Foo<T> extends Array<T> {}
+                    "#,
+                output => r#"
+interface Bar {
+  bar: string;
+}
+type Foo<T> = Array<T>
+                    "#,
+                errors => [
+                  {
+                    message_id => "no_empty_with_super",
+                    line => 5,
+                    column => 11,
+                    end_line => 5,
+                    end_column => 14,
+                  },
+                ],
+              },
+              {
+                code => r#"
+type R = Record<string, unknown>;
+interface Foo<T> extends R {}
+                    "#,
+                output => r#"
+type R = Record<string, unknown>;
+type Foo<T> = R
+                    "#,
+                errors => [
+                  {
+                    message_id => "no_empty_with_super",
+                    line => 3,
+                    column => 11,
+                    end_line => 3,
+                    end_column => 14,
+                  },
+                ],
+              },
+              {
+                code => r#"
+interface Foo<T> extends Bar<T> {}
+                    "#,
+                output => r#"
+type Foo<T> = Bar<T>
+                    "#,
+                errors => [
+                  {
+                    message_id => "no_empty_with_super",
+                    line => 2,
+                    column => 11,
+                    end_line => 2,
+                    end_column => 14,
+                  },
+                ],
+              },
+              {
+                code => r#"
+declare module FooBar {
+  type Baz = typeof baz;
+  export interface Bar extends Baz {}
+}
+                    "#,
+                output => None,
+                errors => [
+                  {
+                    message_id => "no_empty_with_super",
+                    line => 4,
+                    column => 20,
+                    end_line => 4,
+                    end_column => 23,
+                  },
+                ],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx_with_provided_types;
+
+    #[test]
+    fn test_no_empty_interface_rule() {
+        run_for_typescript_and_tsx_with_provided_types(
+            no_empty_interface_rule(),
+            test_cases::no_empty_interface_rule_tests(),
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    Comment, DoStatement, ElseClause, ForInStatement, ForStatement, IfStatement, LabeledStatement,
+    WhileStatement, WithStatement,
+};
+
+use crate::kind::{
+    AbstractMethodSignature, IndexSignature, MethodSignature, PublicFieldDefinition,
+};
+
+// (tree-sitter also uses an `empty_statement` for an empty `for` initializer)
+fn is_allowed_empty_statement_parent(parent: Node) -> bool {
+    matches!(
+        parent.kind(),
+        ForStatement
+            | ForInStatement
+            | WhileStatement
+            | DoStatement
+            | IfStatement
+            | ElseClause
+            | LabeledStatement
+            | WithStatement
+    )
+}
+
+// in ESTree these members include their terminating semicolon, whereas
+// tree-sitter makes it a sibling, so the first `;` after one of them isn't
+// extra
+fn is_semicolon_terminated_member(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        PublicFieldDefinition | IndexSignature | MethodSignature | AbstractMethodSignature
+    )
+}
+
+pub fn no_extra_semi_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-extra-semi",
+        languages => [Typescript],
+        messages => [
+            unexpected => "Unnecessary semicolon.",
+        ],
+        fixable => true,
+        methods => {
+            fn report(&self, semicolon: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+                context.report(violation! {
+                    node => semicolon,
+                    message_id => "unexpected",
+                    fix => |fixer| {
+                        fixer.remove(semicolon);
+                    },
+                });
+            }
+        },
+        listeners => [
+            r#"
+              (empty_statement) @c
+            "# => |node, context| {
+                if is_allowed_empty_statement_parent(node.parent().unwrap()) {
+                    return;
+                }
+
+                self.report(node, context);
+            },
+            r#"
+              (class_body) @c
+            "# => |node, context| {
+                let mut previous: Option<Node<'a>> = None;
+                for child in node.children(&mut node.walk()) {
+                    if child.kind() == Comment {
+                        continue;
+                    }
+                    if child.kind() == ";"
+                        && !previous.is_some_and(is_semicolon_terminated_member)
+                    {
+                        self.report(child, context);
+                    }
+                    previous = Some(child);
+                }
+            },
+            // tree-sitter-typescript accepts a separator right after the `{`
+            // of an interface body or type literal. (Other stray semicolons
+            // in them, and any in an enum body, are syntax errors.)
+            r#"
+              (interface_body) @c
+              (object_type) @c
+            "# => |node, context| {
+                if let Some(semicolon) = node
+                    .non_comment_children(SupportedLanguage::Javascript)
+                    .nth(1)
+                    .filter(|child| child.kind() == ";")
+                {
+                    self.report(semicolon, context);
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_extra_semi_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "var x = 5;",
+              "function foo() {}",
+              "for (;;);",
+              "while (0);",
+              "do; while (0);",
+              "for (a in b);",
+              "for (a of b);",
+              "if (true);",
+              "if (true); else;",
+              "foo: ;",
+              "class A {}",
+              "var A = class {};",
+              "class A { a() { this; } }",
+              "class A {} a;",
+              "class A { field; }",
+              "class A { field = 0; }",
+              "class A { static { foo; } }",
+              "class Foo { [key: string]: any; }",
+              "class Foo { foo(): void; foo() {} }",
+              "abstract class Foo { abstract foo(): void; }",
+              "interface Foo { a: string; b: number; }",
+              "type Foo = { a: string; b: number };",
+              "enum Foo { A, B, }",
+            ],
+            invalid => [
+              {
+                code => "var x = 5;;",
+                output => "var x = 5;",
+                errors => [
+                  {
+                    message_id => "unexpected",
+                    line => 1,
+                    column => 11,
+                  },
+                ],
+              },
+              {
+                code => "function foo() {};",
+                output => "function foo() {}",
+                errors => [{ message_id => "unexpected" }],
+              },
+              {
+                code => "for (;;);;",
+                output => "for (;;);",
+                errors => [{ message_id => "unexpected" }],
+              },
+              {
+                code => "do; while (0);;",
+                output => "do; while (0);",
+                errors => [{ message_id => "unexpected" }],
+              },
+              {
+                code => "if (true) {;} else {;}",
+                output => "if (true) {} else {}",
+                errors => [
+                  { message_id => "unexpected", column => 12 },
+                  { message_id => "unexpected", column => 21 },
+                ],
+              },
+              {
+                code => "foo: ;;",
+                output => "foo: ;",
+                errors => [{ message_id => "unexpected" }],
+              },
+              {
+                code => "class A { ; }",
+                output => "class A {  }",
+                errors => [{ message_id => "unexpected", column => 11 }],
+              },
+              {
+                code => "class A { /*a*/; }",
+                output => "class A { /*a*/ }",
+                errors => [{ message_id => "unexpected", column => 16 }],
+              },
+              {
+                code => "class A { a() {}; }",
+                output => "class A { a() {} }",
+                errors => [{ message_id => "unexpected", column => 17 }],
+              },
+              {
+                code => "class A {; a() {}; b() {}; }",
+                output => "class A { a() {} b() {} }",
+                errors => [
+                  { message_id => "unexpected", column => 10 },
+                  { message_id => "unexpected", column => 18 },
+                  { message_id => "unexpected", column => 26 },
+                ],
+              },
+              {
+                code => "class A { field;; }",
+                output => "class A { field; }",
+                errors => [{ message_id => "unexpected", column => 17 }],
+              },
+              {
+                code => "class A { static {}; }",
+                output => "class A { static {} }",
+                errors => [{ message_id => "unexpected", column => 20 }],
+              },
+              {
+                code => "class Foo { [key: string]: any;; }",
+                output => "class Foo { [key: string]: any; }",
+                errors => [{ message_id => "unexpected", column => 32 }],
+              },
+              {
+                code => "class Foo { foo(): void;; foo() {} }",
+                output => "class Foo { foo(): void; foo() {} }",
+                errors => [{ message_id => "unexpected", column => 25 }],
+              },
+              {
+                code => "abstract class Foo { abstract foo(): void;; }",
+                output => "abstract class Foo { abstract foo(): void; }",
+                errors => [{ message_id => "unexpected", column => 43 }],
+              },
+              {
+                code => "interface Foo { ; a: string; }",
+                output => "interface Foo {  a: string; }",
+                errors => [{ message_id => "unexpected", column => 17 }],
+              },
+              {
+                code => "type Foo = { ; a: string };",
+                output => "type Foo = {  a: string };",
+                errors => [{ message_id => "unexpected", column => 14 }],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_extra_semi_rule() {
+        run_for_typescript_and_tsx(no_extra_semi_rule(), test_cases::no_extra_semi_rule_tests());
+    }
+}
@@ -0,0 +1,306 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use squalid::OptionExt;
+use tree_sitter_lint::{rule, violation, NodeExt, Rule};
+
+use crate::{
+    ast_helpers::{get_class_heritage, get_decorators},
+    kind::ExtendsClause,
+    util::{classify_class_body, ClassBodyKind},
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    allow_constructor_only: Option<bool>,
+    allow_empty: Option<bool>,
+    allow_static_only: Option<bool>,
+    allow_with_decorator: Option<bool>,
+}
+
+impl Options {
+    fn allow_constructor_only(&self) -> bool {
+        self.allow_constructor_only.unwrap_or_default()
+    }
+
+    fn allow_empty(&self) -> bool {
+        self.allow_empty.unwrap_or_default()
+    }
+
+    fn allow_static_only(&self) -> bool {
+        self.allow_static_only.unwrap_or_default()
+    }
+
+    fn allow_with_decorator(&self) -> bool {
+        self.allow_with_decorator.unwrap_or_default()
+    }
+}
+
+pub fn no_extraneous_class_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-extraneous-class",
+        languages => [Typescript],
+        messages => [
+            empty => "Unexpected empty class.",
+            only_constructor => "Unexpected class with only a constructor.",
+            only_static => "Unexpected class with only static properties.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            allow_constructor_only: bool = options.allow_constructor_only(),
+            allow_empty: bool = options.allow_empty(),
+            allow_static_only: bool = options.allow_static_only(),
+            allow_with_decorator: bool = options.allow_with_decorator(),
+        },
+        listeners => [
+            r#"
+              (class_body) @c
+            "# => |node, context| {
+                let class = node.parent().unwrap();
+                if get_class_heritage(class).matches(|heritage| heritage.has_child_of_kind(ExtendsClause)) {
+                    return;
+                }
+                if self.allow_with_decorator && get_decorators(class).next().is_some() {
+                    return;
+                }
+
+                let message_id = match classify_class_body(node, context) {
+                    ClassBodyKind::Empty if !self.allow_empty => "empty",
+                    ClassBodyKind::OnlyConstructor if !self.allow_constructor_only => "only_constructor",
+                    ClassBodyKind::OnlyStatic if !self.allow_static_only => "only_static",
+                    _ => return,
+                };
+
+                context.report(violation! {
+                    node => class.child_by_field_name("name").unwrap_or(class),
+                    message_id => message_id,
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_extraneous_class_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "
+class Foo {
+  public prop = 1;
+  constructor() {}
+}
+                    ",
+                "
+export class CClass extends BaseClass {
+  public static helper(): void {}
+  private static privateHelper(): boolean {
+    return true;
+  }
+  constructor() {}
+}
+                    ",
+                "
+class Foo {
+  constructor(public bar: string) {}
+}
+                    ",
+                {
+                  code => "class Foo {}",
+                  options => { allow_empty => true },
+                },
+                {
+                  code => "
+class Foo {
+  constructor() {}
+}
+                      ",
+                  options => { allow_constructor_only => true },
+                },
+                {
+                  code => "
+export class Bar {
+  public static helper(): void {}
+  private static privateHelper(): boolean {
+    return true;
+  }
+}
+                      ",
+                  options => { allow_static_only => true },
+                },
+                "
+export default class {
+  hello() {
+    return 'I am foo!';
+  }
+}
+                    ",
+                {
+                  code => "
+@FooDecorator
+class Foo {}
+                      ",
+                  options => { allow_with_decorator => true },
+                },
+                {
+                  code => "
+@FooDecorator
+class Foo {
+  constructor(foo: Foo) {
+    foo.subscribe(a => {
+      console.log(a);
+    });
+  }
+}
+                      ",
+                  options => { allow_with_decorator => true },
+                },
+                "
+abstract class Foo {
+  abstract property: string;
+}
+                    ",
+                "
+abstract class Foo {
+  abstract method(): string;
+}
+                    ",
+                "
+class Foo {
+  [key: string]: unknown;
+}
+                    ",
+                {
+                  code => "
+class Foo {
+  static {
+    init();
+  }
+}
+                      ",
+                  options => { allow_static_only => true },
+                },
+            ],
+            invalid => [
+                {
+                  code => "class Foo {}",
+                  errors => [
+                    {
+                      message_id => "empty",
+                      line => 1,
+                      column => 7,
+                    },
+                  ],
+                },
+                {
+                  code => "
+class Foo {
+  public prop = 1;
+  constructor() {
+    class Bar {
+      static PROP = 2;
+    }
+  }
+}
+export class Bar {
+  public static helper(): void {}
+  private static privateHelper(): boolean {
+    return true;
+  }
+}
+                      ",
+                  errors => [
+                    { message_id => "only_static", line => 5, column => 11 },
+                    { message_id => "only_static", line => 10, column => 14 },
+                  ],
+                },
+                {
+                  code => "
+class Foo {
+  constructor() {}
+}
+                      ",
+                  errors => [{ message_id => "only_constructor", line => 2, column => 7 }],
+                },
+                {
+                  code => "
+export class AClass {
+  public static helper(): void {}
+  private static privateHelper(): boolean {
+    return true;
+  }
+  constructor() {
+    class nestedClass {}
+  }
+}
+                      ",
+                  errors => [
+                    { message_id => "only_static", line => 2, column => 14 },
+                    { message_id => "empty", line => 8, column => 11 },
+                  ],
+                },
+                {
+                  code => "
+export default class {
+  static hello() {}
+}
+                      ",
+                  errors => [{ message_id => "only_static", line => 2, column => 16 }],
+                },
+                {
+                  code => "
+@FooDecorator
+class Foo {}
+                      ",
+                  options => { allow_with_decorator => false },
+                  errors => [{ message_id => "empty", line => 3, column => 7 }],
+                },
+                {
+                  code => "
+const foo = class {
+  static bar = 1;
+};
+                      ",
+                  errors => [{ message_id => "only_static", line => 2, column => 13 }],
+                },
+                {
+                  code => "
+class Foo {
+  static {
+    init();
+  }
+}
+                      ",
+                  errors => [{ message_id => "only_static", line => 2, column => 7 }],
+                },
+                {
+                  code => "
+class Foo {
+  static {}
+  static bar = 1;
+}
+                      ",
+                  errors => [{ message_id => "only_static" }],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_extraneous_class_rule() {
+        run_for_typescript_and_tsx(
+            no_extraneous_class_rule(),
+            test_cases::no_extraneous_class_rule_tests(),
+        );
+    }
+}
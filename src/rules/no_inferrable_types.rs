@@ -0,0 +1,499 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    CallExpression, Identifier, NewExpression, UnaryExpression, Undefined,
+};
+
+use crate::{
+    ast_helpers::is_parameter_property,
+    kind::{LiteralType, PredefinedType, TypeIdentifier},
+    util::{get_literal_kind, LiteralKind},
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    ignore_parameters: Option<bool>,
+    ignore_properties: Option<bool>,
+}
+
+impl Options {
+    fn ignore_parameters(&self) -> bool {
+        self.ignore_parameters.unwrap_or_default()
+    }
+
+    fn ignore_properties(&self) -> bool {
+        self.ignore_properties.unwrap_or_default()
+    }
+}
+
+fn get_annotated_type_name(type_: Node, context: &QueryMatchContext) -> Option<&'static str> {
+    Some(match type_.kind() {
+        PredefinedType => match &*type_.text(context) {
+            "bigint" => "bigint",
+            "boolean" => "boolean",
+            "number" => "number",
+            "string" => "string",
+            "symbol" => "symbol",
+            _ => return None,
+        },
+        LiteralType => match &*type_.text(context) {
+            "null" => "null",
+            "undefined" => "undefined",
+            _ => return None,
+        },
+        TypeIdentifier if type_.text(context) == "RegExp" => "RegExp",
+        _ => return None,
+    })
+}
+
+fn has_unary_prefix(init: Node, operators: &[&str]) -> bool {
+    init.kind() == UnaryExpression && operators.contains(&init.field("operator").kind())
+}
+
+fn skip_unary_prefix<'a>(init: Node<'a>, operators: &[&str]) -> Node<'a> {
+    if has_unary_prefix(init, operators) {
+        init.field("argument")
+    } else {
+        init
+    }
+}
+
+fn is_identifier(init: Node, names: &[&str], context: &QueryMatchContext) -> bool {
+    init.kind() == Identifier && names.contains(&&*init.text(context))
+}
+
+// (including optional calls, eg `Number?.("1")`)
+fn is_function_call(init: Node, name: &str, context: &QueryMatchContext) -> bool {
+    init.kind() == CallExpression && is_identifier(init.field("function"), &[name], context)
+}
+
+fn is_literal(init: Node, kind: LiteralKind, context: &QueryMatchContext) -> bool {
+    get_literal_kind(init, context) == Some(kind)
+}
+
+fn is_inferrable(type_name: &str, init: Node, context: &QueryMatchContext) -> bool {
+    match type_name {
+        "bigint" => {
+            // bigints can't have a `+` prefix
+            let init = skip_unary_prefix(init, &["-"]);
+            is_function_call(init, "BigInt", context)
+                || is_literal(init, LiteralKind::BigInt, context)
+        }
+        "boolean" => {
+            has_unary_prefix(init, &["!"])
+                || is_function_call(init, "Boolean", context)
+                || is_literal(init, LiteralKind::Boolean, context)
+        }
+        "number" => {
+            let init = skip_unary_prefix(init, &["+", "-"]);
+            is_identifier(init, &["Infinity", "NaN"], context)
+                || is_function_call(init, "Number", context)
+                || is_literal(init, LiteralKind::Number, context)
+        }
+        "null" => is_literal(init, LiteralKind::Null, context),
+        "string" => {
+            is_function_call(init, "String", context)
+                || is_literal(init, LiteralKind::String, context)
+        }
+        "symbol" => is_function_call(init, "Symbol", context),
+        "RegExp" => {
+            is_literal(init, LiteralKind::RegExp, context)
+                || is_function_call(init, "RegExp", context)
+                || init.kind() == NewExpression
+                    && is_identifier(init.field("constructor"), &["RegExp"], context)
+        }
+        "undefined" => has_unary_prefix(init, &["void"]) || init.kind() == Undefined,
+        _ => false,
+    }
+}
+
+fn report_inferrable_type<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+    let (Some(type_annotation), Some(init)) = (
+        node.child_by_field_name("type"),
+        node.child_by_field_name("value"),
+    ) else {
+        return;
+    };
+    let Some(type_name) = get_annotated_type_name(
+        type_annotation.first_non_comment_named_child(SupportedLanguage::Javascript),
+        context,
+    ) else {
+        return;
+    };
+    if !is_inferrable(type_name, init, context) {
+        return;
+    }
+
+    context.report(violation! {
+        node => node,
+        message_id => "no_inferrable_type",
+        data => {
+            type => type_name,
+        },
+        fix => |fixer| {
+            // the `?` of an optional parameter or the `!` of a definite
+            // property would be left dangling
+            if let Some(optional_or_definite) = type_annotation
+                .prev_sibling()
+                .filter(|prev| matches!(prev.kind(), "?" | "!"))
+            {
+                fixer.remove(optional_or_definite);
+            }
+            fixer.remove(type_annotation);
+        }
+    });
+}
+
+pub fn no_inferrable_types_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-inferrable-types",
+        languages => [Typescript],
+        messages => [
+            no_inferrable_type => "Type {{type}} trivially inferred from a {{type}} literal, remove type annotation.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            ignore_parameters: bool = options.ignore_parameters(),
+            ignore_properties: bool = options.ignore_properties(),
+        },
+        listeners => [
+            r#"
+              (variable_declarator) @c
+            "# => |node, context| {
+                report_inferrable_type(node, context);
+            },
+            r#"
+              (required_parameter) @c
+              (optional_parameter) @c
+            "# => |node, context| {
+                if self.ignore_parameters || is_parameter_property(node) {
+                    return;
+                }
+
+                report_inferrable_type(node, context);
+            },
+            r#"
+              (public_field_definition) @c
+            "# => |node, context| {
+                // `readonly` properties are ignored because without a type
+                // annotation their type would be the literal type (see
+                // microsoft/TypeScript#14416)
+                if self.ignore_properties
+                    || node.has_child_of_kind("readonly")
+                    || node.has_child_of_kind("?")
+                {
+                    return;
+                }
+
+                report_inferrable_type(node, context);
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, serde_json::json, RuleTests};
+
+    use crate::test_helpers::{expected_error, invalid_case_with_output, valid_case};
+
+    const TEST_CASES: &[(&str, &[&str])] = &[
+        (
+            "bigint",
+            &[
+                "10n",
+                "-10n",
+                "BigInt(10)",
+                "-BigInt(10)",
+                "BigInt?.(10)",
+                "-BigInt?.(10)",
+            ],
+        ),
+        (
+            "boolean",
+            &["false", "true", "Boolean(null)", "Boolean?.(null)", "!0"],
+        ),
+        (
+            "number",
+            &[
+                "10",
+                "+10",
+                "-10",
+                "Number('1')",
+                "+Number('1')",
+                "-Number('1')",
+                "Number?.('1')",
+                "+Number?.('1')",
+                "-Number?.('1')",
+                "Infinity",
+                "+Infinity",
+                "-Infinity",
+                "NaN",
+                "+NaN",
+                "-NaN",
+            ],
+        ),
+        ("null", &["null"]),
+        (
+            "RegExp",
+            &["/a/", "RegExp('a')", "RegExp?.('a')", "new RegExp('a')"],
+        ),
+        (
+            "string",
+            &["'str'", "\"str\"", "`str`", "String(1)", "String?.(1)"],
+        ),
+        ("symbol", &["Symbol('a')", "Symbol?.('a')"]),
+        ("undefined", &["undefined", "void someValue"]),
+    ];
+
+    pub fn no_inferrable_types_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                ...TEST_CASES.iter().flat_map(|(_, inits)| {
+                    inits
+                        .iter()
+                        .map(|init| valid_case(format!("const a = {init}"), json!({})))
+                }),
+                "const fn = (a = 5, b = true, c = 'foo') => {};",
+                "const fn = function (a = 5, b = true, c = 'foo') {};",
+                "function fn(a = 5, b = true, c = 'foo') {}",
+                "function fn(a: number, b: boolean, c: string) {}",
+                "
+class Foo {
+  a = 5;
+  b = true;
+  c = 'foo';
+}
+                    ",
+                "
+class Foo {
+  readonly a: number = 5;
+}
+                    ",
+                "const a: any = 5;",
+                "const fn = function (a: any = 5, b: any = true, c: any = 'foo') {};",
+                "const a: string = `${foo}`;",
+                "const a: number = 10 as number;",
+                "let a: number;",
+                {
+                  code => "const fn = (a: number = 5, b: boolean = true, c: string = 'foo') => {};",
+                  options => { ignore_parameters => true },
+                },
+                {
+                  code => "function fn(a: number = 5, b: boolean = true, c: string = 'foo') {}",
+                  options => { ignore_parameters => true },
+                },
+                {
+                  code => "const fn = function (a: number = 5, b: boolean = true, c: string = 'foo') {};",
+                  options => { ignore_parameters => true },
+                },
+                {
+                  code => "
+class Foo {
+  a: number = 5;
+  b: boolean = true;
+  c: string = 'foo';
+}
+                      ",
+                  options => { ignore_properties => true },
+                },
+                "
+class Foo {
+  a?: number = 5;
+  b?: boolean = true;
+  c?: string = 'foo';
+}
+                    ",
+                "
+class Foo {
+  constructor(public a = true) {}
+}
+                    ",
+                "
+class Foo {
+  constructor(private a: number = 5) {}
+}
+                    ",
+                "
+class Foo {
+  accessor a = 5;
+}
+                    ",
+            ],
+            invalid => [
+                ...TEST_CASES.iter().flat_map(|(type_, inits)| {
+                    inits.iter().map(|init| {
+                        invalid_case_with_output(
+                            format!("const a: {type_} = {init}"),
+                            format!("const a = {init}"),
+                            json!({}),
+                            vec![expected_error("no_inferrable_type", [("type", *type_)])
+                                .line(1)
+                                .column(7)
+                                .build()
+                                .unwrap()],
+                        )
+                    })
+                }),
+                {
+                  code => "const fn = (a?: number = 5) => {};",
+                  output => "const fn = (a = 5) => {};",
+                  options => { ignore_parameters => false },
+                  errors => [
+                    {
+                      message_id => "no_inferrable_type",
+                      data => { type => "number" },
+                      line => 1,
+                      column => 13,
+                    },
+                  ],
+                },
+                {
+                  code => "
+class A {
+  a!: number = 1;
+}
+                      ",
+                  output => "
+class A {
+  a = 1;
+}
+                      ",
+                  options => { ignore_properties => false },
+                  errors => [
+                    {
+                      message_id => "no_inferrable_type",
+                      data => { type => "number" },
+                      line => 3,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => "const fn = (a: number = 5, b: boolean = true, c: string = 'foo') => {};",
+                  output => "const fn = (a = 5, b = true, c = 'foo') => {};",
+                  options => { ignore_parameters => false, ignore_properties => false },
+                  errors => [
+                    {
+                      message_id => "no_inferrable_type",
+                      data => { type => "number" },
+                      line => 1,
+                      column => 13,
+                    },
+                    {
+                      message_id => "no_inferrable_type",
+                      data => { type => "boolean" },
+                      line => 1,
+                      column => 28,
+                    },
+                    {
+                      message_id => "no_inferrable_type",
+                      data => { type => "string" },
+                      line => 1,
+                      column => 47,
+                    },
+                  ],
+                },
+                {
+                  code => "
+class Foo {
+  a: number = 5;
+  b: boolean = true;
+  c: string = 'foo';
+}
+                      ",
+                  output => "
+class Foo {
+  a = 5;
+  b = true;
+  c = 'foo';
+}
+                      ",
+                  options => { ignore_parameters => false, ignore_properties => false },
+                  errors => [
+                    {
+                      message_id => "no_inferrable_type",
+                      data => { type => "number" },
+                      line => 3,
+                      column => 3,
+                    },
+                    {
+                      message_id => "no_inferrable_type",
+                      data => { type => "boolean" },
+                      line => 4,
+                      column => 3,
+                    },
+                    {
+                      message_id => "no_inferrable_type",
+                      data => { type => "string" },
+                      line => 5,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => "
+class Foo {
+  constructor(a: number = 5) {}
+}
+                      ",
+                  output => "
+class Foo {
+  constructor(a = 5) {}
+}
+                      ",
+                  errors => [
+                    {
+                      message_id => "no_inferrable_type",
+                      data => { type => "number" },
+                      line => 3,
+                      column => 15,
+                    },
+                  ],
+                },
+                {
+                  code => "
+class Foo {
+  accessor a: number = 5;
+}
+                      ",
+                  output => "
+class Foo {
+  accessor a = 5;
+}
+                      ",
+                  errors => [
+                    {
+                      message_id => "no_inferrable_type",
+                      data => { type => "number" },
+                      line => 3,
+                      column => 3,
+                    },
+                  ],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_inferrable_types_rule() {
+        run_for_typescript_and_tsx(
+            no_inferrable_types_rule(),
+            test_cases::no_inferrable_types_rule_tests(),
+        );
+    }
+}
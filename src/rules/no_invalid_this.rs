@@ -0,0 +1,362 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    Arguments, ArrowFunction, AssignmentExpression, BinaryExpression, CallExpression,
+    ClassStaticBlock, Function, FunctionDeclaration, GeneratorFunction,
+    GeneratorFunctionDeclaration, Identifier, MemberExpression, MethodDefinition, Null, Pair,
+    ParenthesizedExpression, PropertyIdentifier, ReturnStatement, TernaryExpression, Undefined,
+    VariableDeclarator,
+};
+
+use crate::{
+    ast_helpers::{get_this_parameter, is_this_parameter, is_void_operator_expression},
+    kind::PublicFieldDefinition,
+    util::{is_function, is_inside_type_context},
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    cap_is_constructor: Option<bool>,
+}
+
+impl Options {
+    fn cap_is_constructor(&self) -> bool {
+        self.cap_is_constructor.unwrap_or(true)
+    }
+}
+
+const METHODS_WITH_THIS_ARG: [&str; 9] = [
+    "every",
+    "filter",
+    "find",
+    "findIndex",
+    "findLast",
+    "findLastIndex",
+    "forEach",
+    "map",
+    "some",
+];
+
+fn skip_parens_upward(mut node: Node) -> Node {
+    while node.parent().unwrap().kind() == ParenthesizedExpression {
+        node = node.parent().unwrap();
+    }
+    node
+}
+
+fn is_descendant_of(node: Node, ancestor: Node) -> bool {
+    ancestor.start_byte() <= node.start_byte() && node.end_byte() <= ancestor.end_byte()
+}
+
+fn starts_with_upper_case(name: &str) -> bool {
+    name.chars().next().is_some_and(|ch| ch.is_uppercase())
+}
+
+fn is_null_or_undefined(node: Node) -> bool {
+    match node.kind() {
+        Null | Undefined => true,
+        _ => is_void_operator_expression(node),
+    }
+}
+
+fn is_callee(node: Node) -> bool {
+    let node = skip_parens_upward(node);
+    let parent = node.parent().unwrap();
+    parent.kind() == CallExpression && parent.field("function") == node
+}
+
+fn is_member_named(
+    node: Node,
+    object_name: Option<&str>,
+    names: &[&str],
+    context: &QueryMatchContext,
+) -> bool {
+    if node.kind() != MemberExpression {
+        return false;
+    }
+    let property = node.field("property");
+    if property.kind() != PropertyIdentifier || !names.contains(&&*property.text(context)) {
+        return false;
+    }
+    object_name.map_or(true, |object_name| {
+        let object = node.field("object");
+        object.kind() == Identifier && object.text(context) == object_name
+    })
+}
+
+/// Whether `this` inside the (non-arrow) function `node` gets the default
+/// binding, ie is `undefined` (all code is treated as strict, as in modules
+/// and classes).
+fn is_default_this_binding(
+    node: Node,
+    cap_is_constructor: bool,
+    context: &QueryMatchContext,
+) -> bool {
+    if get_this_parameter(node).is_some() {
+        return false;
+    }
+    let name = node.child_by_field_name("name");
+    if cap_is_constructor && name.is_some_and(|name| starts_with_upper_case(&name.text(context))) {
+        return false;
+    }
+    let is_anonymous = name.is_none();
+
+    let mut current = node;
+    loop {
+        current = skip_parens_upward(current);
+        let parent = current.parent().unwrap();
+        match parent.kind() {
+            BinaryExpression if matches!(parent.field("operator").kind(), "||" | "&&" | "??") => {
+                current = parent;
+            }
+            TernaryExpression if parent.field("condition") != current => {
+                current = parent;
+            }
+            ReturnStatement => {
+                let Some(function) = parent.ancestors().find(|&ancestor| is_function(ancestor))
+                else {
+                    return true;
+                };
+                if !is_callee(function) {
+                    return true;
+                }
+                current = skip_parens_upward(function).parent().unwrap();
+            }
+            ArrowFunction => {
+                if parent.field("body") != current || !is_callee(parent) {
+                    return true;
+                }
+                current = skip_parens_upward(parent).parent().unwrap();
+            }
+            Pair | PublicFieldDefinition => {
+                return parent.child_by_field_name("value") != Some(current);
+            }
+            AssignmentExpression => {
+                let left = parent.field("left");
+                if left.kind() == MemberExpression {
+                    return false;
+                }
+                return !(cap_is_constructor
+                    && is_anonymous
+                    && parent.field("right") == current
+                    && left.kind() == Identifier
+                    && starts_with_upper_case(&left.text(context)));
+            }
+            VariableDeclarator => {
+                let declarator_name = parent.field("name");
+                return !(cap_is_constructor
+                    && is_anonymous
+                    && parent.child_by_field_name("value") == Some(current)
+                    && declarator_name.kind() == Identifier
+                    && starts_with_upper_case(&declarator_name.text(context)));
+            }
+            // eg `foo.bind(obj)`
+            MemberExpression => {
+                if parent.field("object") != current
+                    || !is_member_named(parent, None, &["apply", "bind", "call"], context)
+                    || !is_callee(parent)
+                {
+                    return true;
+                }
+                return skip_parens_upward(parent)
+                    .parent()
+                    .unwrap()
+                    .field("arguments")
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                    .next()
+                    .map_or(true, is_null_or_undefined);
+            }
+            // eg `foo.forEach(function () {}, obj)`
+            Arguments => {
+                let call = parent.parent().unwrap();
+                if call.kind() != CallExpression {
+                    return true;
+                }
+                let callee = call.field("function");
+                let arguments = parent
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                    .collect::<Vec<_>>();
+                let (arguments_count, function_index, this_arg_index) =
+                    if is_member_named(callee, Some("Reflect"), &["apply"], context) {
+                        (3, 0, 1)
+                    } else if is_member_named(callee, Some("Array"), &["from"], context) {
+                        (3, 1, 2)
+                    } else if is_member_named(callee, None, &METHODS_WITH_THIS_ARG, context) {
+                        (2, 0, 1)
+                    } else {
+                        return true;
+                    };
+                return arguments.len() != arguments_count
+                    || arguments[function_index] != current
+                    || is_null_or_undefined(arguments[this_arg_index]);
+            }
+            _ => return true,
+        }
+    }
+}
+
+pub fn no_invalid_this_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-invalid-this",
+        languages => [Typescript],
+        messages => [
+            unexpected_this => "Unexpected 'this'.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            cap_is_constructor: bool = options.cap_is_constructor(),
+        },
+        listeners => [
+            r#"
+              (this) @c
+            "# => |node, context| {
+                if is_this_parameter(node.parent().unwrap()) || is_inside_type_context(node) {
+                    return;
+                }
+                let is_valid = node
+                    .ancestors()
+                    .find_map(|ancestor| match ancestor.kind() {
+                        ClassStaticBlock => Some(true),
+                        // (a `this` in a computed member name refers to the
+                        // enclosing scope)
+                        MethodDefinition => {
+                            (!is_descendant_of(node, ancestor.field("name"))).then_some(true)
+                        }
+                        PublicFieldDefinition => ancestor
+                            .child_by_field_name("value")
+                            .filter(|&value| is_descendant_of(node, value))
+                            .map(|_| true),
+                        Function | FunctionDeclaration | GeneratorFunction | GeneratorFunctionDeclaration => {
+                            Some(!is_default_this_binding(ancestor, self.cap_is_constructor, context))
+                        }
+                        _ => None,
+                    })
+                    // `this` is `undefined` at the top level of a module
+                    .unwrap_or(false);
+                if is_valid {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "unexpected_this",
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_invalid_this_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "describe('foo', () => {});",
+              "function Foo() { this.a = 0; }",
+              "var Foo = function () { this.a = 0; };",
+              "Foo = function () { this.a = 0; };",
+              "var obj = { foo: function () { this.a = 0; } };",
+              "var obj = { foo() { this.a = 0; } };",
+              "obj.foo = function () { this.a = 0; };",
+              "class A { constructor() { this.a = 0; } foo() { this.a = 0; } }",
+              "class A { a = this.b; static { this.c = 1; } }",
+              "class A { foo = function () { this.a = 0; }; }",
+              "class A { foo = () => { this.a = 0; }; }",
+              "var foo = (function () { this.a = 0; }).bind(obj);",
+              "foo.call(function () { this.a = 0; }.call(obj));",
+              "(function foo() { this.a = 0; }).apply(obj);",
+              "[].forEach(function () { this.a = 0; }, obj);",
+              "Array.from([], function () { this.a = 0; }, obj);",
+              "Reflect.apply(function () { this.a = 0; }, obj, []);",
+              "obj.foo = (function () { return function () { this.a = 0; }; })();",
+              "var obj = { foo: (function () { return function () { this.a = 0; }; })() };",
+              "function foo(this: Foo) { this.a = 0; }",
+              "const foo = function (this: Window, a: string) { this.a = a; };",
+              "class A { method(cb: (this: A) => void) {} }",
+              "function isFoo(this: Foo): this is Bar { return this.a; }",
+            ],
+            invalid => [
+              {
+                code => "this.a = 0;",
+                errors => [
+                  {
+                    message_id => "unexpected_this",
+                    line => 1,
+                    column => 1,
+                    end_line => 1,
+                    end_column => 5,
+                  },
+                ],
+              },
+              {
+                code => "function foo() { this.a = 0; }",
+                errors => [{ message_id => "unexpected_this", column => 18 }],
+              },
+              {
+                code => "var foo = function () { this.a = 0; };",
+                errors => [{ message_id => "unexpected_this" }],
+              },
+              {
+                code => "foo(function () { this.a = 0; });",
+                errors => [{ message_id => "unexpected_this" }],
+              },
+              {
+                code => "var foo = (function () { this.a = 0; }).bind(null);",
+                errors => [{ message_id => "unexpected_this" }],
+              },
+              {
+                code => "[].forEach(function () { this.a = 0; });",
+                errors => [{ message_id => "unexpected_this" }],
+              },
+              {
+                code => "class A { foo() { function bar() { this.a = 0; } } }",
+                errors => [{ message_id => "unexpected_this" }],
+              },
+              {
+                code => "var obj = { foo: function () { return function () { this.a = 0; }; } };",
+                errors => [{ message_id => "unexpected_this" }],
+              },
+              {
+                code => "var foo = (function () { return function () { this.a = 0; }; })();",
+                errors => [{ message_id => "unexpected_this" }],
+              },
+              {
+                code => "() => { this.a = 0; };",
+                errors => [{ message_id => "unexpected_this" }],
+              },
+              {
+                code => "function Foo() { this.a = 0; }",
+                options => { cap_is_constructor => false },
+                errors => [{ message_id => "unexpected_this" }],
+              },
+              {
+                code => "function foo(a: string) { this.a = a; }",
+                errors => [{ message_id => "unexpected_this", line => 1, column => 27 }],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_invalid_this_rule() {
+        run_for_typescript_and_tsx(
+            no_invalid_this_rule(),
+            test_cases::no_invalid_this_rule_tests(),
+        );
+    }
+}
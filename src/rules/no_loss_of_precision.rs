@@ -0,0 +1,266 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{rule, violation, NodeExt, Rule};
+
+use crate::util::{parse_numeric_literal, NumericLiteral, NumericLiteralValue};
+
+const MAX_REQUESTED_PRECISION: usize = 100;
+const MANTISSA_BITS: usize = 53;
+const MAX_EXPONENT_BITS: usize = 1024;
+
+/// A non-decimal literal is representable exactly if its significant bits fit
+/// in the mantissa (and it doesn't overflow to `Infinity`).
+fn not_base_ten_loses_precision(radix: u32, digits: &str) -> bool {
+    let bits_per_digit = radix.trailing_zeros() as usize;
+    let bits = digits
+        .chars()
+        .map(|digit| {
+            format!(
+                "{:0width$b}",
+                digit.to_digit(radix).unwrap_or_default(),
+                width = bits_per_digit
+            )
+        })
+        .collect::<String>();
+    let bits = bits.trim_start_matches('0');
+    if bits.len() > MAX_EXPONENT_BITS {
+        return true;
+    }
+    bits.trim_end_matches('0').len() > MANTISSA_BITS
+}
+
+fn remove_leading_zeros(text: &str) -> &str {
+    match text.trim_start_matches('0') {
+        "" => text,
+        trimmed => trimmed,
+    }
+}
+
+fn remove_trailing_zeros(text: &str) -> &str {
+    match text.trim_end_matches('0') {
+        "" => text,
+        trimmed => trimmed,
+    }
+}
+
+fn add_decimal_point(digits: &str) -> String {
+    match digits.get(..1) {
+        Some(first) => format!("{first}.{}", &digits[1..]),
+        None => ".".to_owned(),
+    }
+}
+
+struct NormalizedNumber {
+    magnitude: i64,
+    coefficient: String,
+}
+
+fn normalize_integer(text: &str) -> NormalizedNumber {
+    let significant_digits = remove_trailing_zeros(remove_leading_zeros(text));
+    NormalizedNumber {
+        magnitude: if text.starts_with('0') {
+            text.len() as i64 - 2
+        } else {
+            text.len() as i64 - 1
+        },
+        coefficient: add_decimal_point(significant_digits),
+    }
+}
+
+fn normalize_float(text: &str) -> NormalizedNumber {
+    let trimmed = remove_leading_zeros(text);
+    if let Some(decimal_digits) = trimmed.strip_prefix('.') {
+        let significant_digits = remove_leading_zeros(decimal_digits);
+        return NormalizedNumber {
+            magnitude: significant_digits.len() as i64 - decimal_digits.len() as i64 - 1,
+            coefficient: add_decimal_point(significant_digits),
+        };
+    }
+    NormalizedNumber {
+        magnitude: trimmed.find('.').unwrap() as i64 - 1,
+        coefficient: add_decimal_point(&trimmed.replace('.', "")),
+    }
+}
+
+/// Eg `"12.30"` -> `"1.230e1"`, so that the literal as written and the
+/// number as stored can be compared digit-for-digit.
+fn convert_number_to_scientific_notation(text: &str) -> String {
+    let (coefficient, exponent) = match text.find(['e', 'E']) {
+        Some(index) => (&text[..index], Some(&text[index + 1..])),
+        None => (text, None),
+    };
+    let normalized = if text.contains('.') {
+        normalize_float(coefficient)
+    } else {
+        normalize_integer(coefficient)
+    };
+    let magnitude = normalized.magnitude
+        + exponent
+            .and_then(|exponent| exponent.parse::<i64>().ok())
+            .unwrap_or_default();
+    format!("{}e{}", normalized.coefficient, magnitude)
+}
+
+fn base_ten_loses_precision(text: &str, value: f64) -> bool {
+    if value.is_infinite() {
+        return true;
+    }
+    let normalized_raw = convert_number_to_scientific_notation(text);
+    let requested_precision = normalized_raw
+        .split('e')
+        .next()
+        .unwrap()
+        .replace('.', "")
+        .len();
+    if requested_precision > MAX_REQUESTED_PRECISION {
+        return true;
+    }
+    // the equivalent of JS `value.toPrecision(requested_precision)`
+    let stored = format!("{:.*e}", requested_precision - 1, value);
+    normalized_raw != convert_number_to_scientific_notation(&stored)
+}
+
+fn loses_precision(literal: &NumericLiteral) -> bool {
+    let NumericLiteralValue::Number(value) = literal.value else {
+        // bigints are arbitrary-precision
+        return false;
+    };
+    if value == 0. {
+        return false;
+    }
+    match literal.radix {
+        10 => base_ten_loses_precision(&literal.digits, value),
+        radix => not_base_ten_loses_precision(radix, &literal.digits),
+    }
+}
+
+pub fn no_loss_of_precision_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-loss-of-precision",
+        languages => [Typescript],
+        messages => [
+            no_loss_of_precision => "This number literal will lose precision at runtime.",
+        ],
+        listeners => [
+            r#"
+              (number) @c
+            "# => |node, context| {
+                let Some(literal) = parse_numeric_literal(&node.text(context)) else {
+                    return;
+                };
+                if !loses_precision(&literal) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "no_loss_of_precision",
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_loss_of_precision_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "const x = 12345;",
+                "const x = 123.456;",
+                "const x = -123.456;",
+                "const x = 0;",
+                "const x = 0.0;",
+                "const x = 0.000000000000001;",
+                "const x = 123e34;",
+                "const x = 123.0e34;",
+                "const x = 12300000000000000000000000;",
+                "const x = 0.00000000000000000000000123;",
+                "const x = 9007199254740991;",
+                "const x = 9007199254740992;",
+                "const x = 1e300;",
+                "const x = 0x1FFFFFFFFFFFFF;",
+                "const x = 0X20000000000000;",
+                "const x = 0b11111111111111111111111111111111111111111111111111111;",
+                "const x = 0o377777777777777777;",
+                "const x = 0377777777777777777;",
+                "const x = 0.1;",
+                "const x = 1.0;",
+                "const x = .5;",
+                "const x = 1_2.3_4;",
+                "const x = 9_007_199_254_740_991;",
+                "const x = 0x1_FFFF_FFFF_FFFF;",
+                "const x = 123_456n;",
+                "const x = 9007199254740993n;",
+                "const x = 0x20000000000001n;",
+                "const x = 1_000_000_000_000_000_000_000n;",
+            ],
+            invalid => [
+                {
+                  code => "const x = 9007199254740993;",
+                  errors => [{ message_id => "no_loss_of_precision", line => 1, column => 11 }],
+                },
+                {
+                  code => "const x = 9_007_199_254_740_993;",
+                  errors => [{ message_id => "no_loss_of_precision" }],
+                },
+                {
+                  code => "const x = 9_007_199_254_740.993e3;",
+                  errors => [{ message_id => "no_loss_of_precision" }],
+                },
+                {
+                  code => "const x = 5123000000000000000000000000001;",
+                  errors => [{ message_id => "no_loss_of_precision" }],
+                },
+                {
+                  code => "const x = 1.0000000000000000000000123;",
+                  errors => [{ message_id => "no_loss_of_precision" }],
+                },
+                {
+                  code => "const x = 2e999;",
+                  errors => [{ message_id => "no_loss_of_precision" }],
+                },
+                {
+                  code => "const x = .1234567891234567891;",
+                  errors => [{ message_id => "no_loss_of_precision" }],
+                },
+                {
+                  code => "const x = 0x20000000000001;",
+                  errors => [{ message_id => "no_loss_of_precision" }],
+                },
+                {
+                  code => "const x = 0x2_0000_0000_0000_1;",
+                  errors => [{ message_id => "no_loss_of_precision" }],
+                },
+                {
+                  code => "const x = 0b100000000000000000000000000000000000000000000000000001;",
+                  errors => [{ message_id => "no_loss_of_precision" }],
+                },
+                {
+                  code => "const x = 0o400000000000000001;",
+                  errors => [{ message_id => "no_loss_of_precision" }],
+                },
+                {
+                  code => "const x = 0400000000000000001;",
+                  errors => [{ message_id => "no_loss_of_precision" }],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_loss_of_precision_rule() {
+        run_for_typescript_and_tsx(
+            no_loss_of_precision_rule(),
+            test_cases::no_loss_of_precision_rule_tests(),
+        );
+    }
+}
@@ -0,0 +1,902 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    Arguments, AssignmentExpression, AssignmentPattern, AugmentedAssignmentExpression,
+    CallExpression, ComputedPropertyName, Identifier, MemberExpression, ObjectAssignmentPattern,
+    Pair, ParenthesizedExpression, SubscriptExpression, UnaryExpression, VariableDeclarator,
+};
+
+use crate::{
+    kind::{
+        EnumAssignment, IntersectionType, LiteralType, LookupType, OptionalParameter,
+        ParenthesizedType, PublicFieldDefinition, RequiredParameter, TemplateLiteralType,
+        TemplateType, TypeAliasDeclaration, UnionType,
+    },
+    util::{
+        evaluate_constant_expression, parse_numeric_literal, skip_parenthesized_expressions,
+        ConstantValue, NumericLiteralValue,
+    },
+};
+
+const MAX_ARRAY_LENGTH: f64 = 4294967295.;
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum IgnoreValue {
+    Number(f64),
+    /// eg `"100n"` (or `"1_000n"`, `"0x10n"`)
+    BigInt(String),
+}
+
+impl IgnoreValue {
+    fn to_constant_value(&self) -> Option<ConstantValue> {
+        match self {
+            Self::Number(value) => Some(ConstantValue::Number(*value)),
+            Self::BigInt(value) => match parse_numeric_literal(value)?.value {
+                NumericLiteralValue::BigInt(value) => Some(ConstantValue::BigInt(value)),
+                NumericLiteralValue::Number(_) => None,
+            },
+        }
+    }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    ignore: Option<Vec<IgnoreValue>>,
+    ignore_array_indexes: Option<bool>,
+    ignore_default_values: Option<bool>,
+    ignore_class_field_initial_values: Option<bool>,
+    enforce_const: Option<bool>,
+    detect_objects: Option<bool>,
+    ignore_enums: Option<bool>,
+    ignore_numeric_literal_types: Option<bool>,
+    ignore_readonly_class_properties: Option<bool>,
+    ignore_type_indexes: Option<bool>,
+}
+
+impl Options {
+    fn ignore(&self) -> Vec<ConstantValue> {
+        self.ignore
+            .iter()
+            .flatten()
+            .filter_map(IgnoreValue::to_constant_value)
+            .collect()
+    }
+
+    fn ignore_array_indexes(&self) -> bool {
+        self.ignore_array_indexes.unwrap_or_default()
+    }
+
+    fn ignore_default_values(&self) -> bool {
+        self.ignore_default_values.unwrap_or_default()
+    }
+
+    fn ignore_class_field_initial_values(&self) -> bool {
+        self.ignore_class_field_initial_values.unwrap_or_default()
+    }
+
+    fn enforce_const(&self) -> bool {
+        self.enforce_const.unwrap_or_default()
+    }
+
+    fn detect_objects(&self) -> bool {
+        self.detect_objects.unwrap_or_default()
+    }
+
+    fn ignore_enums(&self) -> bool {
+        self.ignore_enums.unwrap_or_default()
+    }
+
+    fn ignore_numeric_literal_types(&self) -> bool {
+        self.ignore_numeric_literal_types.unwrap_or_default()
+    }
+
+    fn ignore_readonly_class_properties(&self) -> bool {
+        self.ignore_readonly_class_properties.unwrap_or_default()
+    }
+
+    fn ignore_type_indexes(&self) -> bool {
+        self.ignore_type_indexes.unwrap_or_default()
+    }
+}
+
+/// Where a numeric literal is, as far as the TS-specific options are
+/// concerned.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum NumericLiteralContext {
+    /// `enum Foo { Bar = 1 }`
+    EnumInitializer,
+    /// The whole type (or a member of the union that is the whole type) of a
+    /// type alias, eg `type Foo = 1 | 2;`
+    TypeAliasLiteralType,
+    /// (Part of) the index type of an indexed access type, eg `Foo[0]` or
+    /// `Foo[0 | 1]`
+    TypeIndex,
+    /// `readonly foo = 1;`
+    ReadonlyClassProperty,
+    /// Anywhere else, which is up to the base rule
+    Other,
+}
+
+/// The parent of `node`, looking through parentheses (which don't have
+/// nodes of their own in ESTree).
+fn get_parent(node: Node) -> Node {
+    node.ancestors()
+        .find(|ancestor| ancestor.kind() != ParenthesizedExpression)
+        .unwrap()
+}
+
+fn is_field_value(parent: Node, field_name: &str, node: Node) -> bool {
+    parent
+        .child_by_field_name(field_name)
+        .is_some_and(|value| skip_parenthesized_expressions(value) == node)
+}
+
+fn is_signed(node: Node, operators: &[&str]) -> bool {
+    node.kind() == UnaryExpression && operators.contains(&node.field("operator").kind())
+}
+
+/// The `-1` of `-1` when given its `1`.
+fn get_full_number_node(node: Node) -> Node {
+    let parent = get_parent(node);
+    if is_signed(parent, &["-"]) {
+        parent
+    } else {
+        node
+    }
+}
+
+fn get_outermost_type<'a>(node: Node<'a>, kinds: &[&str]) -> Node<'a> {
+    let mut node = node;
+    while let Some(parent) = node
+        .parent()
+        .filter(|parent| kinds.contains(&parent.kind()))
+    {
+        node = parent;
+    }
+    node
+}
+
+/// A template literal type made up of literal types (eg `` `${1}px` ``) is
+/// classified the same as a literal type in its place would be.
+fn get_literal_type_context(literal_type: Node) -> NumericLiteralContext {
+    let index_type = get_outermost_type(
+        literal_type,
+        &[
+            UnionType,
+            IntersectionType,
+            ParenthesizedType,
+            TemplateType,
+            TemplateLiteralType,
+        ],
+    );
+    if index_type.parent().is_some_and(|parent| {
+        parent.kind() == LookupType
+            && parent.first_non_comment_named_child(SupportedLanguage::Javascript) != index_type
+    }) {
+        return NumericLiteralContext::TypeIndex;
+    }
+
+    let aliased_type = get_outermost_type(
+        literal_type,
+        &[
+            UnionType,
+            ParenthesizedType,
+            TemplateType,
+            TemplateLiteralType,
+        ],
+    );
+    if aliased_type.parent().is_some_and(|parent| {
+        parent.kind() == TypeAliasDeclaration && parent.field("value") == aliased_type
+    }) {
+        return NumericLiteralContext::TypeAliasLiteralType;
+    }
+
+    NumericLiteralContext::Other
+}
+
+fn get_numeric_literal_context(node: Node) -> NumericLiteralContext {
+    let parent = get_parent(node);
+    let (literal, parent) = if is_signed(parent, &["-", "+"]) {
+        (parent, get_parent(parent))
+    } else {
+        (node, parent)
+    };
+    match parent.kind() {
+        EnumAssignment if is_field_value(parent, "value", literal) => {
+            NumericLiteralContext::EnumInitializer
+        }
+        PublicFieldDefinition
+            if is_field_value(parent, "value", literal) && parent.has_child_of_kind("readonly") =>
+        {
+            NumericLiteralContext::ReadonlyClassProperty
+        }
+        LiteralType => get_literal_type_context(parent),
+        _ => NumericLiteralContext::Other,
+    }
+}
+
+fn is_default_value(full_number_node: Node) -> bool {
+    let parent = get_parent(full_number_node);
+    match parent.kind() {
+        AssignmentPattern | ObjectAssignmentPattern => {
+            is_field_value(parent, "right", full_number_node)
+        }
+        RequiredParameter | OptionalParameter => is_field_value(parent, "value", full_number_node),
+        _ => false,
+    }
+}
+
+fn is_class_field_initial_value(full_number_node: Node) -> bool {
+    let parent = get_parent(full_number_node);
+    parent.kind() == PublicFieldDefinition && is_field_value(parent, "value", full_number_node)
+}
+
+fn is_parse_int_radix<'a>(full_number_node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    let parent = get_parent(full_number_node);
+    if parent.kind() != Arguments {
+        return false;
+    }
+    let call = parent.parent().unwrap();
+    if call.kind() != CallExpression
+        || parent
+            .non_comment_named_children(SupportedLanguage::Javascript)
+            .nth(1)
+            .map(skip_parenthesized_expressions)
+            != Some(full_number_node)
+    {
+        return false;
+    }
+    let callee = skip_parenthesized_expressions(call.field("function"));
+    match callee.kind() {
+        Identifier => callee.text(context) == "parseInt",
+        MemberExpression => {
+            let object = skip_parenthesized_expressions(callee.field("object"));
+            object.kind() == Identifier
+                && object.text(context) == "Number"
+                && callee.field("property").text(context) == "parseInt"
+        }
+        _ => false,
+    }
+}
+
+fn is_array_index(full_number_node: Node, value: &ConstantValue) -> bool {
+    let parent = get_parent(full_number_node);
+    parent.kind() == SubscriptExpression
+        && is_field_value(parent, "index", full_number_node)
+        && match *value {
+            ConstantValue::Number(value) => {
+                value.fract() == 0. && (0.0..MAX_ARRAY_LENGTH).contains(&value)
+            }
+            ConstantValue::BigInt(value) => value >= 0 && (value as f64) < MAX_ARRAY_LENGTH,
+            _ => false,
+        }
+}
+
+/// The places that (unless `detect_objects` is set) a number is taken to be
+/// named by the property it's assigned to.
+fn is_object_property_value(full_number_node: Node) -> bool {
+    let parent = get_parent(full_number_node);
+    match parent.kind() {
+        Pair => true,
+        ComputedPropertyName => parent.parent().unwrap().kind() == Pair,
+        AssignmentExpression | AugmentedAssignmentExpression => {
+            skip_parenthesized_expressions(parent.field("left")).kind() != Identifier
+        }
+        _ => false,
+    }
+}
+
+fn is_const_declarator(declarator: Node) -> bool {
+    declarator
+        .parent()
+        .unwrap()
+        .child_by_field_name("kind")
+        .is_some_and(|kind| kind.kind() == "const")
+}
+
+pub fn no_magic_numbers_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-magic-numbers",
+        languages => [Typescript],
+        messages => [
+            use_const => "Number constants declarations must use 'const'.",
+            no_magic => "No magic number: {{raw}}.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            ignore: Vec<ConstantValue> = options.ignore(),
+            ignore_array_indexes: bool = options.ignore_array_indexes(),
+            ignore_default_values: bool = options.ignore_default_values(),
+            ignore_class_field_initial_values: bool = options.ignore_class_field_initial_values(),
+            enforce_const: bool = options.enforce_const(),
+            detect_objects: bool = options.detect_objects(),
+            ignore_enums: bool = options.ignore_enums(),
+            ignore_numeric_literal_types: bool = options.ignore_numeric_literal_types(),
+            ignore_readonly_class_properties: bool = options.ignore_readonly_class_properties(),
+            ignore_type_indexes: bool = options.ignore_type_indexes(),
+        },
+        listeners => [
+            r#"
+              (number) @c
+            "# => |node, context| {
+                let full_number_node = get_full_number_node(node);
+                let Some(value) = evaluate_constant_expression(full_number_node, context) else {
+                    return;
+                };
+                if self.ignore.iter().any(|ignored| ignored.strict_equals(&value)) {
+                    return;
+                }
+
+                let raw = if full_number_node == node {
+                    node.text(context).into_owned()
+                } else {
+                    format!("-{}", node.text(context))
+                };
+
+                let is_allowed = match get_numeric_literal_context(node) {
+                    NumericLiteralContext::EnumInitializer => Some(self.ignore_enums),
+                    NumericLiteralContext::TypeAliasLiteralType => Some(self.ignore_numeric_literal_types),
+                    NumericLiteralContext::TypeIndex => Some(self.ignore_type_indexes),
+                    NumericLiteralContext::ReadonlyClassProperty => Some(self.ignore_readonly_class_properties),
+                    NumericLiteralContext::Other => None,
+                };
+                match is_allowed {
+                    Some(true) => return,
+                    Some(false) => {
+                        context.report(violation! {
+                            node => full_number_node,
+                            message_id => "no_magic",
+                            data => {
+                                raw => raw,
+                            },
+                        });
+                        return;
+                    }
+                    None => (),
+                }
+
+                if self.ignore_default_values && is_default_value(full_number_node) ||
+                    self.ignore_class_field_initial_values && is_class_field_initial_value(full_number_node) ||
+                    is_parse_int_radix(full_number_node, context) ||
+                    self.ignore_array_indexes && is_array_index(full_number_node, &value) {
+                    return;
+                }
+
+                let parent = get_parent(full_number_node);
+                if parent.kind() == VariableDeclarator {
+                    if self.enforce_const && !is_const_declarator(parent) {
+                        context.report(violation! {
+                            node => full_number_node,
+                            message_id => "use_const",
+                        });
+                    }
+                    return;
+                }
+
+                if !self.detect_objects && is_object_property_value(full_number_node) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => full_number_node,
+                    message_id => "no_magic",
+                    data => {
+                        raw => raw,
+                    },
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, serde_json::json, RuleTests};
+
+    pub fn no_magic_numbers_rule_tests() -> RuleTests {
+        let ignore_negative = json!({ "ignore": [0, 1, 2, -2] });
+        rule_tests! {
+            valid => [
+              "var x = parseInt(y, 10);",
+              "var x = parseInt(y, -10);",
+              "var x = Number.parseInt(y, 10);",
+              "var x = (parseInt)(y, (10));",
+              {
+                code => "const foo = 42;",
+                options => { enforce_const => true },
+              },
+              "var foo = 42;",
+              "var foo = -42;",
+              "var foo = (42);",
+              {
+                code => "var foo = 0 + 1 - 2 + -2;",
+                options => ignore_negative,
+              },
+              {
+                code => "var foo = 0 + 1 + 2 + 3 + 4;",
+                options => { ignore => [0, 1, 2, 3, 4] },
+              },
+              "var foo = { bar:10 }",
+              "var foo = { 10: 'bar' }",
+              "var foo = { [10]: 'bar' }",
+              "foo.bar = 10;",
+              "foo[bar] += 10;",
+              {
+                code => "setTimeout(function() {return 1;}, 0);",
+                options => { ignore => [0, 1] },
+              },
+              {
+                code => "var data = ['foo', 'bar', 'baz']; var third = data[3];",
+                options => { ignore_array_indexes => true },
+              },
+              {
+                code => "foo[0]",
+                options => { ignore_array_indexes => true },
+              },
+              {
+                code => "foo[-0]",
+                options => { ignore_array_indexes => true },
+              },
+              {
+                code => "foo[1n]",
+                options => { ignore_array_indexes => true },
+              },
+              {
+                code => "foo[4294967294]",
+                options => { ignore_array_indexes => true },
+              },
+              {
+                code => "foo[1e3]",
+                options => { ignore_array_indexes => true },
+              },
+              {
+                code => "f(100n)",
+                options => { ignore => ["100n"] },
+              },
+              {
+                code => "f(-100n)",
+                options => { ignore => ["-100n"] },
+              },
+              {
+                code => "f(1_000n, 0x10n)",
+                options => { ignore => ["1000n", "0x1_0n"] },
+              },
+              {
+                code => "function foo(a = 1, b = -2) {}",
+                options => { ignore_default_values => true },
+              },
+              {
+                code => "const { param = 123 } = sourceObject;",
+                options => { ignore_default_values => true },
+              },
+              {
+                code => "const [one = 1, two = 2] = [];",
+                options => { ignore_default_values => true },
+              },
+              {
+                code => "class C { foo = 2; bar = -3; #baz = 4; static qux = 5; }",
+                options => { ignore_class_field_initial_values => true },
+              },
+            ],
+            invalid => [
+              {
+                code => "var foo = 42",
+                options => { enforce_const => true },
+                errors => [{ message_id => "use_const", line => 1, column => 11 }],
+              },
+              {
+                code => "let foo = -42",
+                options => { enforce_const => true },
+                errors => [{ message_id => "use_const", column => 11 }],
+              },
+              {
+                code => "var foo = 0 + 1;",
+                errors => [
+                  { message_id => "no_magic", data => { raw => "0" }, column => 11 },
+                  { message_id => "no_magic", data => { raw => "1" }, column => 15 },
+                ],
+              },
+              {
+                code => "a = a + 5;",
+                errors => [{ message_id => "no_magic", data => { raw => "5" }, column => 9 }],
+              },
+              {
+                code => "a += 5;",
+                errors => [{ message_id => "no_magic", data => { raw => "5" }, column => 6 }],
+              },
+              {
+                code => "a = 5;",
+                errors => [{ message_id => "no_magic", data => { raw => "5" }, column => 5 }],
+              },
+              {
+                code => "var foo = 0 + 1 + -2 + 2;",
+                errors => [
+                  { message_id => "no_magic", data => { raw => "0" }, column => 11 },
+                  { message_id => "no_magic", data => { raw => "1" }, column => 15 },
+                  { message_id => "no_magic", data => { raw => "-2" }, column => 19 },
+                  { message_id => "no_magic", data => { raw => "2" }, column => 24 },
+                ],
+              },
+              {
+                code => "var foo = -(2);",
+                options => { enforce_const => true },
+                errors => [{ message_id => "use_const", column => 11 }],
+              },
+              {
+                code => "f(-(2));",
+                errors => [{ message_id => "no_magic", data => { raw => "-2" }, column => 3 }],
+              },
+              {
+                code => "var foo = { bar:10 }",
+                options => { detect_objects => true },
+                errors => [{ message_id => "no_magic", column => 17 }],
+              },
+              {
+                code => "foo.bar = 10;",
+                options => { detect_objects => true },
+                errors => [{ message_id => "no_magic", column => 11 }],
+              },
+              {
+                code => "var data = ['foo', 'bar', 'baz']; var third = data[3];",
+                errors => [{ message_id => "no_magic", data => { raw => "3" }, column => 52 }],
+              },
+              {
+                code => "foo[-1]",
+                options => { ignore_array_indexes => true },
+                errors => [{ message_id => "no_magic", data => { raw => "-1" }, column => 5 }],
+              },
+              {
+                code => "foo[0.1]",
+                options => { ignore_array_indexes => true },
+                errors => [{ message_id => "no_magic", data => { raw => "0.1" } }],
+              },
+              {
+                code => "foo[4294967295]",
+                options => { ignore_array_indexes => true },
+                errors => [{ message_id => "no_magic", data => { raw => "4294967295" } }],
+              },
+              {
+                code => "foo[-1n]",
+                options => { ignore_array_indexes => true },
+                errors => [{ message_id => "no_magic", data => { raw => "-1n" } }],
+              },
+              {
+                code => "foo.bar(1)",
+                options => { ignore_array_indexes => true },
+                errors => [{ message_id => "no_magic", data => { raw => "1" }, column => 9 }],
+              },
+              {
+                code => "f(100n)",
+                options => { ignore => [100] },
+                errors => [{ message_id => "no_magic", data => { raw => "100n" } }],
+              },
+              {
+                code => "parseInt(10, y)",
+                errors => [{ message_id => "no_magic", data => { raw => "10" }, column => 10 }],
+              },
+              {
+                code => "foo.parseInt(y, 10)",
+                errors => [{ message_id => "no_magic", data => { raw => "10" }, column => 17 }],
+              },
+              {
+                code => "function foo(a = 1) {}",
+                errors => [{ message_id => "no_magic", column => 18 }],
+              },
+              {
+                code => "class C { foo = 2; }",
+                errors => [{ message_id => "no_magic", column => 17 }],
+              },
+              {
+                code => "class C { foo = 2 + 3; }",
+                options => { ignore_class_field_initial_values => true },
+                errors => [
+                  { message_id => "no_magic", data => { raw => "2" }, column => 17 },
+                  { message_id => "no_magic", data => { raw => "3" }, column => 21 },
+                ],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::rule_tests;
+
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_magic_numbers_rule() {
+        run_for_typescript_and_tsx(
+            no_magic_numbers_rule(),
+            test_cases::no_magic_numbers_rule_tests(),
+        );
+    }
+
+    #[test]
+    fn test_no_magic_numbers_rule_typescript() {
+        run_for_typescript_and_tsx(
+            no_magic_numbers_rule(),
+            rule_tests! {
+                valid => [
+                  "type Foo = 'bar';",
+                  "type Foo = true;",
+                  {
+                    code => "const FOO = 10;",
+                    options => { ignore_numeric_literal_types => true },
+                  },
+                  {
+                    code => "type Foo = 1;",
+                    options => { ignore_numeric_literal_types => true },
+                  },
+                  {
+                    code => "type Foo = -1;",
+                    options => { ignore_numeric_literal_types => true },
+                  },
+                  {
+                    code => "type Foo = 1 | 2 | 3;",
+                    options => { ignore_numeric_literal_types => true },
+                  },
+                  {
+                    code => "type Foo = 1 | -1;",
+                    options => { ignore_numeric_literal_types => true },
+                  },
+                  {
+                    code => "type Foo = 'bar' | (1 | 2n);",
+                    options => { ignore_numeric_literal_types => true },
+                  },
+                  {
+                    code => "export type Foo = 0x10;",
+                    options => { ignore_numeric_literal_types => true },
+                  },
+                  {
+                    code => "type Foo = `${1}px`;",
+                    options => { ignore_numeric_literal_types => true },
+                  },
+                  {
+                    code => "type Foo = `${1 | 2}px` | 3;",
+                    options => { ignore_numeric_literal_types => true },
+                  },
+                  {
+                    code => "type Foo = 1;",
+                    options => { ignore => [1] },
+                  },
+                  {
+                    code => "enum foo { SECOND = 1000, NUM = '0123456789', NEG = -1, POS = +1 }",
+                    options => { ignore_enums => true },
+                  },
+                  {
+                    code => "const enum foo { A = 1 }",
+                    options => { ignore_enums => true },
+                  },
+                  {
+                    code => r#"
+class Foo {
+  readonly A = 1;
+  readonly B = 2;
+  public static readonly C = 1;
+  static readonly D = 1;
+  readonly E = -1;
+  readonly F = +1;
+  private readonly G = 100n;
+}
+                    "#,
+                    options => { ignore_readonly_class_properties => true },
+                  },
+                  {
+                    code => "type Foo = Bar[0];",
+                    options => { ignore_type_indexes => true },
+                  },
+                  {
+                    code => "type Foo = Bar[-1];",
+                    options => { ignore_type_indexes => true },
+                  },
+                  {
+                    code => "type Foo = Bar[0xab];",
+                    options => { ignore_type_indexes => true },
+                  },
+                  {
+                    code => "type Foo = Bar[5.6e1];",
+                    options => { ignore_type_indexes => true },
+                  },
+                  {
+                    code => "type Foo = Bar[10n];",
+                    options => { ignore_type_indexes => true },
+                  },
+                  {
+                    code => "type Foo = Bar[1 | -2];",
+                    options => { ignore_type_indexes => true },
+                  },
+                  {
+                    code => "type Foo = Bar[1 & -2];",
+                    options => { ignore_type_indexes => true },
+                  },
+                  {
+                    code => "type Foo = Bar[1 & number];",
+                    options => { ignore_type_indexes => true },
+                  },
+                  {
+                    code => "type Foo = Bar[((1 & -2) | 3) | 4];",
+                    options => { ignore_type_indexes => true },
+                  },
+                  {
+                    code => "type Foo = Parameters<Bar>[2];",
+                    options => { ignore_type_indexes => true },
+                  },
+                  {
+                    code => "type Foo = Bar[0][1];",
+                    options => { ignore_type_indexes => true },
+                  },
+                  {
+                    code => "type Foo = Bar[`${1}`];",
+                    options => { ignore_type_indexes => true },
+                  },
+                  {
+                    code => "function foo(a: Bar[1]): Baz[2] {}",
+                    options => { ignore_type_indexes => true },
+                  },
+                  {
+                    code => "let foo: Bar[0] = bar;",
+                    options => { ignore_type_indexes => true },
+                  },
+                  "type Foo = Bar['baz'];",
+                  {
+                    code => r#"
+type Others = [['a'], ['b']];
+
+type Foo = {
+  [K in keyof Others[0]]: Others[K];
+};
+                    "#,
+                    options => { ignore_type_indexes => true },
+                  },
+                ],
+                invalid => [
+                  {
+                    code => "type Foo = 1;",
+                    options => { ignore_numeric_literal_types => false },
+                    errors => [{ message_id => "no_magic", data => { raw => "1" }, line => 1, column => 12 }],
+                  },
+                  {
+                    code => "type Foo = -1;",
+                    errors => [{ message_id => "no_magic", data => { raw => "-1" }, column => 12 }],
+                  },
+                  {
+                    code => "type Foo = 1 | 2 | 3 | 4;",
+                    errors => [
+                      { message_id => "no_magic", data => { raw => "1" }, column => 12 },
+                      { message_id => "no_magic", data => { raw => "2" }, column => 16 },
+                      { message_id => "no_magic", data => { raw => "3" }, column => 20 },
+                      { message_id => "no_magic", data => { raw => "4" }, column => 24 },
+                    ],
+                  },
+                  {
+                    code => "type Foo = `${1}px`;",
+                    errors => [{ message_id => "no_magic", data => { raw => "1" }, column => 15 }],
+                  },
+                  {
+                    code => "type Foo = 2;",
+                    options => { ignore => [1] },
+                    errors => [{ message_id => "no_magic", data => { raw => "2" } }],
+                  },
+                  {
+                    // only (unions of) literal types that are the whole
+                    // aliased type count
+                    code => "interface Foo { bar: 1; }",
+                    options => { ignore_numeric_literal_types => true },
+                    errors => [{ message_id => "no_magic", data => { raw => "1" }, column => 22 }],
+                  },
+                  {
+                    code => "let foo: 1 = 1;",
+                    options => { ignore_numeric_literal_types => true },
+                    errors => [{ message_id => "no_magic", data => { raw => "1" }, column => 10 }],
+                  },
+                  {
+                    code => "type Foo = [1, 2];",
+                    options => { ignore_numeric_literal_types => true },
+                    errors => [
+                      { message_id => "no_magic", data => { raw => "1" }, column => 13 },
+                      { message_id => "no_magic", data => { raw => "2" }, column => 16 },
+                    ],
+                  },
+                  {
+                    code => "type Foo = 1 & 2;",
+                    options => { ignore_numeric_literal_types => true },
+                    errors => [
+                      { message_id => "no_magic", data => { raw => "1" }, column => 12 },
+                      { message_id => "no_magic", data => { raw => "2" }, column => 16 },
+                    ],
+                  },
+                  {
+                    code => "let x: `${1}px`;",
+                    options => { ignore_numeric_literal_types => true },
+                    errors => [{ message_id => "no_magic", data => { raw => "1" }, column => 11 }],
+                  },
+                  {
+                    code => "type Foo = Bar<1>;",
+                    options => { ignore_numeric_literal_types => true },
+                    errors => [{ message_id => "no_magic", data => { raw => "1" }, column => 16 }],
+                  },
+                  {
+                    code => "enum foo { SECOND = 1000, NUM = '0123456789', NEG = -1, POS = +1 }",
+                    errors => [
+                      { message_id => "no_magic", data => { raw => "1000" }, column => 21 },
+                      { message_id => "no_magic", data => { raw => "-1" }, column => 53 },
+                      { message_id => "no_magic", data => { raw => "1" }, column => 64 },
+                    ],
+                  },
+                  {
+                    code => "enum foo { SECOND = 1000 }",
+                    options => { ignore_enums => false },
+                    errors => [{ message_id => "no_magic", data => { raw => "1000" }, column => 21 }],
+                  },
+                  {
+                    // only initializers that are a literal count
+                    code => "enum Foo { A = 1 << 2 }",
+                    options => { ignore_enums => true },
+                    errors => [
+                      { message_id => "no_magic", data => { raw => "1" }, column => 16 },
+                      { message_id => "no_magic", data => { raw => "2" }, column => 21 },
+                    ],
+                  },
+                  {
+                    // readonly fields are up to
+                    // `ignore_readonly_class_properties` instead
+                    code => "class Foo { readonly A = 1; }",
+                    options => { ignore_class_field_initial_values => true },
+                    errors => [{ message_id => "no_magic", data => { raw => "1" }, column => 26 }],
+                  },
+                  {
+                    code => "class Foo { private readonly G = -100n; }",
+                    errors => [{ message_id => "no_magic", data => { raw => "-100n" }, column => 34 }],
+                  },
+                  {
+                    code => "class Foo { A = 1; }",
+                    options => { ignore_readonly_class_properties => true },
+                    errors => [{ message_id => "no_magic", data => { raw => "1" }, column => 17 }],
+                  },
+                  {
+                    code => "type Foo = Bar[0];",
+                    options => { ignore_type_indexes => false },
+                    errors => [{ message_id => "no_magic", data => { raw => "0" }, column => 16 }],
+                  },
+                  {
+                    code => "type Foo = Bar[1 | -2];",
+                    errors => [
+                      { message_id => "no_magic", data => { raw => "1" }, column => 16 },
+                      { message_id => "no_magic", data => { raw => "-2" }, column => 20 },
+                    ],
+                  },
+                  {
+                    code => "function foo(a: Bar[1]) {}",
+                    options => { ignore_numeric_literal_types => true },
+                    errors => [{ message_id => "no_magic", data => { raw => "1" }, column => 21 }],
+                  },
+                  {
+                    code => "type Other = { [0]: 3 };",
+                    options => { ignore_type_indexes => true },
+                    errors => [
+                      { message_id => "no_magic", data => { raw => "0" }, column => 17 },
+                      { message_id => "no_magic", data => { raw => "3" }, column => 21 },
+                    ],
+                  },
+                  {
+                    // the type index options don't affect value indexes
+                    code => "foo[0];",
+                    options => { ignore_type_indexes => true },
+                    errors => [{ message_id => "no_magic", data => { raw => "0" }, column => 5 }],
+                  },
+                ],
+            },
+        )
+    }
+}
@@ -0,0 +1,304 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    range_between_starts, rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{CallExpression, Identifier, Undefined};
+
+use crate::{
+    ast_helpers::is_void_operator_expression,
+    kind::{LiteralType, PredefinedType},
+    type_utils::{get_call_return_type_kind, is_void_expression, ReturnTypeKind},
+    util::{get_declared_type, skip_parenthesized_expressions},
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    check_never: Option<bool>,
+}
+
+impl Options {
+    fn check_never(&self) -> bool {
+        self.check_never.unwrap_or_default()
+    }
+}
+
+/// `void`, `undefined` or `never`, if that's known to be the type of
+/// `node`.
+fn get_ignorable_type<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<&'static str> {
+    let node = skip_parenthesized_expressions(node);
+    match node.kind() {
+        Undefined => Some("undefined"),
+        _ if is_void_operator_expression(node) => Some("undefined"),
+        Identifier => {
+            let type_ = get_declared_type(node, context)?;
+            match (type_.kind(), &*type_.text(context)) {
+                (PredefinedType, "void") => Some("void"),
+                (PredefinedType, "never") => Some("never"),
+                (LiteralType, "undefined") => Some("undefined"),
+                _ => None,
+            }
+        }
+        CallExpression => match get_call_return_type_kind(node, context)? {
+            ReturnTypeKind::Void => Some("void"),
+            ReturnTypeKind::Never => Some("never"),
+            _ => None,
+        },
+        // `await`ing a `Promise<void>`
+        _ => is_void_expression(node, context).then_some("void"),
+    }
+}
+
+pub fn no_meaningless_void_operator_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-meaningless-void-operator",
+        languages => [Typescript],
+        messages => [
+            meaningless_void_operator => "void operator shouldn't be used on {{type}}; it should convey that a return value is being ignored",
+            remove_void => "Remove 'void'",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            check_never: bool = options.check_never(),
+        },
+        listeners => [
+            r#"
+              (unary_expression
+                operator: "void"
+              ) @c
+            "# => |node, context| {
+                let argument = node.field("argument");
+                let Some(type_) = get_ignorable_type(argument, context) else {
+                    return;
+                };
+
+                if type_ == "never" {
+                    if !self.check_never {
+                        return;
+                    }
+                    context.report(violation! {
+                        node => node,
+                        message_id => "meaningless_void_operator",
+                        data => {
+                            type => type_,
+                        },
+                        // TODO: suggestions?
+                    });
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "meaningless_void_operator",
+                    data => {
+                        type => type_,
+                    },
+                    fix => |fixer| {
+                        fixer.remove_range(range_between_starts(node.range(), argument.range()));
+                    },
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_meaningless_void_operator_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "
+(() => {})();
+
+function foo() {}
+foo(); // nothing to discard
+
+function bar(x: number) {
+  void x;
+  return 2;
+}
+void bar(); // discarding a number
+                    ",
+                "
+function bar(x: never) {
+  void x;
+}
+                    ",
+                "
+function baz(): never {
+  throw new Error();
+}
+void baz();
+                    ",
+                "void unknownFunction();",
+                "void foo.bar();",
+                "
+async function qux() {
+  return 1;
+}
+void qux();
+                    ",
+                "
+async function quux() {}
+void quux();
+                    ",
+            ],
+            invalid => [
+                {
+                  code => "void (() => {})();",
+                  output => "(() => {})();",
+                  errors => [
+                    {
+                      message_id => "meaningless_void_operator",
+                      data => { type => "void" },
+                      line => 1,
+                      column => 1,
+                    },
+                  ],
+                },
+                {
+                  code => "
+function foo() {}
+void foo();
+                      ",
+                  output => "
+function foo() {}
+foo();
+                      ",
+                  errors => [
+                    {
+                      message_id => "meaningless_void_operator",
+                      data => { type => "void" },
+                      line => 3,
+                      column => 1,
+                    },
+                  ],
+                },
+                {
+                  code => "
+function bar(x: never) {
+  void x;
+}
+                      ",
+                  options => { check_never => true },
+                  errors => [
+                    {
+                      message_id => "meaningless_void_operator",
+                      data => { type => "never" },
+                      line => 3,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => "
+function baz(): never {
+  throw new Error();
+}
+void baz();
+                      ",
+                  options => { check_never => true },
+                  errors => [
+                    {
+                      message_id => "meaningless_void_operator",
+                      data => { type => "never" },
+                      line => 5,
+                    },
+                  ],
+                },
+                {
+                  code => "void undefined;",
+                  output => "undefined;",
+                  errors => [
+                    {
+                      message_id => "meaningless_void_operator",
+                      data => { type => "undefined" },
+                    },
+                  ],
+                },
+                {
+                  code => "void void 0;",
+                  output => "void 0;",
+                  errors => [
+                    {
+                      message_id => "meaningless_void_operator",
+                      data => { type => "undefined" },
+                    },
+                  ],
+                },
+                {
+                  code => "void console.log('foo');",
+                  output => "console.log('foo');",
+                  errors => [
+                    {
+                      message_id => "meaningless_void_operator",
+                      data => { type => "void" },
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare const x: undefined;
+void (x);
+                      ",
+                  output => "
+declare const x: undefined;
+(x);
+                      ",
+                  errors => [
+                    {
+                      message_id => "meaningless_void_operator",
+                      data => { type => "undefined" },
+                      line => 3,
+                    },
+                  ],
+                },
+                {
+                  code => "
+async function foo() {}
+async function bar() {
+  void await foo();
+}
+                      ",
+                  output => "
+async function foo() {}
+async function bar() {
+  await foo();
+}
+                      ",
+                  errors => [
+                    {
+                      message_id => "meaningless_void_operator",
+                      data => { type => "void" },
+                      line => 4,
+                      column => 3,
+                    },
+                  ],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_meaningless_void_operator_rule() {
+        run_for_typescript_and_tsx(
+            no_meaningless_void_operator_rule(),
+            test_cases::no_meaningless_void_operator_rule_tests(),
+        );
+    }
+}
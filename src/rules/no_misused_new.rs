@@ -0,0 +1,312 @@
+use std::{borrow::Cow, sync::Arc};
+
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::ClassBody;
+
+use crate::{
+    kind::{GenericType, InterfaceBody, ObjectType, TypeIdentifier},
+    module_kind::is_in_ambient_context,
+    type_declarations::TypeDeclarations,
+};
+
+/// The name of a (possibly generic) type reference like `Foo` or `Foo<T>`,
+/// but not `foo.Foo`.
+fn get_type_reference_name<'a>(
+    type_annotation: Option<Node<'a>>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Cow<'a, str>> {
+    let type_ = type_annotation?.first_non_comment_named_child(SupportedLanguage::Javascript);
+    let name = match type_.kind() {
+        TypeIdentifier => type_,
+        GenericType => type_.field("name"),
+        _ => return None,
+    };
+    (name.kind() == TypeIdentifier).then(|| name.text(context))
+}
+
+/// Whether the signature returns the class or interface it's declared in.
+fn is_matching_parent_type<'a>(
+    parent: Node<'a>,
+    return_type: Option<Node<'a>>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    get_type_reference_name(return_type, context).is_some_and(|return_type_name| {
+        context
+            .retrieve::<TypeDeclarations<'a>>()
+            .get(&return_type_name)
+            .contains(&parent)
+    })
+}
+
+pub fn no_misused_new_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-misused-new",
+        languages => [Typescript],
+        messages => [
+            error_message_interface => "Interfaces cannot be constructed, only classes.",
+            error_message_class => "Class cannot have method named `new`.",
+        ],
+        listeners => [
+            r#"
+              (interface_body
+                (construct_signature) @c
+              )
+            "# => |node, context| {
+                if !is_matching_parent_type(
+                    node.parent().unwrap().parent().unwrap(),
+                    node.child_by_field_name("type"),
+                    context,
+                ) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "error_message_interface",
+                });
+            },
+            r#"
+              (method_signature
+                name: (property_identifier) @name (#match? @name "^(constructor|new)$")
+              ) @c
+            "# => |captures, context| {
+                let node = captures["c"];
+                let parent = node.parent().unwrap();
+                match parent.kind() {
+                    InterfaceBody | ObjectType => {
+                        if captures["name"].text(context) != "constructor" {
+                            return;
+                        }
+
+                        context.report(violation! {
+                            node => node,
+                            message_id => "error_message_interface",
+                        });
+                    }
+                    ClassBody => {
+                        if captures["name"].text(context) != "new" {
+                            return;
+                        }
+                        // a bodiless `new()` in an ambient class is just
+                        // declaring a method that happens to be called `new`,
+                        // whereas outside of one it's an overload without an
+                        // implementation, presumably meant as a constructor
+                        if is_in_ambient_context(node, context) {
+                            return;
+                        }
+                        if !is_matching_parent_type(
+                            parent.parent().unwrap(),
+                            node.child_by_field_name("return_type"),
+                            context,
+                        ) {
+                            return;
+                        }
+
+                        context.report(violation! {
+                            node => node,
+                            message_id => "error_message_class",
+                        });
+                    }
+                    _ => (),
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_misused_new_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "
+declare abstract class C {
+  foo() {}
+  get new();
+  bar();
+}
+                    ",
+                "
+class C {
+  constructor();
+}
+                    ",
+                "
+const foo = class {
+  constructor();
+};
+                    ",
+                "
+const foo = class {
+  new(): X;
+};
+                    ",
+                "
+class C {
+  new() {}
+}
+                    ",
+                "
+class C {
+  constructor() {}
+}
+                    ",
+                "
+const foo = class {
+  new() {}
+};
+                    ",
+                "
+const foo = class {
+  constructor() {}
+};
+                    ",
+                "
+interface I {
+  new (): {};
+}
+                    ",
+                "type T = { new (): T };",
+                "
+export default class {
+  constructor();
+}
+                    ",
+                "
+interface foo {
+  new <T>(): bar<T>;
+}
+                    ",
+                "
+interface foo {
+  new <T>(): 'x';
+}
+                    ",
+                "
+interface FooConstructor {
+  new (): Foo;
+}
+                    ",
+                "
+class C {
+  new(): foo.C;
+}
+                    ",
+                // unlike upstream, since in an ambient class there's no
+                // implementation to be missing
+                "
+declare abstract class C {
+  new(): C;
+}
+                    ",
+                "
+declare class C {
+  new(): C;
+}
+                    ",
+                "
+declare namespace foo {
+  class C {
+    new(): C;
+  }
+}
+                    ",
+            ],
+            invalid => [
+                {
+                  code => "
+interface I {
+  new (): I;
+  constructor(): void;
+}
+                      ",
+                  errors => [
+                    { message_id => "error_message_interface", line => 3, column => 3 },
+                    { message_id => "error_message_interface", line => 4, column => 3 },
+                  ],
+                },
+                {
+                  code => "
+interface G {
+  new <T>(): G<T>;
+}
+                      ",
+                  errors => [
+                    { message_id => "error_message_interface", line => 3, column => 3 },
+                  ],
+                },
+                {
+                  code => "
+type T = {
+  constructor(): void;
+};
+                      ",
+                  errors => [
+                    { message_id => "error_message_interface", line => 3, column => 3 },
+                  ],
+                },
+                {
+                  code => "
+class C {
+  new(): C;
+}
+                      ",
+                  errors => [
+                    { message_id => "error_message_class", line => 3, column => 3 },
+                  ],
+                },
+                {
+                  code => "
+export class C<T> {
+  new(): C<T>;
+}
+                      ",
+                  errors => [
+                    { message_id => "error_message_class", line => 3, column => 3 },
+                  ],
+                },
+                {
+                  code => "
+interface I {
+  constructor(): '';
+}
+                      ",
+                  errors => [
+                    { message_id => "error_message_interface", line => 3, column => 3 },
+                  ],
+                },
+                {
+                  code => "
+declare namespace foo {
+  interface I {
+    new (): I;
+  }
+}
+                      ",
+                  errors => [
+                    { message_id => "error_message_interface", line => 4, column => 5 },
+                  ],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx_with_provided_types;
+
+    #[test]
+    fn test_no_misused_new_rule() {
+        run_for_typescript_and_tsx_with_provided_types(
+            no_misused_new_rule(),
+            test_cases::no_misused_new_rule_tests(),
+        );
+    }
+}
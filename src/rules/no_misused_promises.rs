@@ -0,0 +1,736 @@
+use std::{borrow::Cow, collections::HashSet, sync::Arc};
+
+use serde::Deserialize;
+use squalid::OptionExt;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    assert_kind,
+    kind::{
+        ArrowFunction, Function, FunctionDeclaration, Identifier, MemberExpression,
+        MethodDefinition, Object, Pair, PropertyIdentifier, StatementBlock, String as StringKind,
+    },
+    utils::ast_utils::get_static_string_value,
+};
+
+use crate::{
+    ast_helpers::{is_async_function, NodeExtTypescript},
+    config_warnings::{ConfigWarning, ValidateOptions},
+    kind::{
+        CallSignature, FunctionType, GenericType, IntersectionType, MethodSignature, ObjectType,
+        OptionalParameter, PredefinedType, PropertySignature, RequiredParameter, TypeIdentifier,
+        UnionType,
+    },
+    type_declarations::{TypeDeclarationKind, TypeDeclarations},
+    util::{nearest_enclosing_function, resolve_function, skip_parenthesized_expressions},
+};
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+struct ChecksVoidReturnOptions {
+    arguments: Option<bool>,
+    attributes: Option<bool>,
+    properties: Option<bool>,
+    returns: Option<bool>,
+    variables: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum ChecksVoidReturnOption {
+    Bool(bool),
+    Object(ChecksVoidReturnOptions),
+}
+
+#[derive(Copy, Clone, Debug)]
+struct ChecksVoidReturn {
+    arguments: bool,
+    properties: bool,
+    returns: bool,
+    variables: bool,
+}
+
+impl From<Option<&ChecksVoidReturnOption>> for ChecksVoidReturn {
+    fn from(value: Option<&ChecksVoidReturnOption>) -> Self {
+        match value {
+            None | Some(ChecksVoidReturnOption::Bool(true)) => Self {
+                arguments: true,
+                properties: true,
+                returns: true,
+                variables: true,
+            },
+            Some(ChecksVoidReturnOption::Bool(false)) => Self {
+                arguments: false,
+                properties: false,
+                returns: false,
+                variables: false,
+            },
+            Some(ChecksVoidReturnOption::Object(options)) => Self {
+                arguments: options.arguments.unwrap_or(true),
+                properties: options.properties.unwrap_or(true),
+                returns: options.returns.unwrap_or(true),
+                variables: options.variables.unwrap_or(true),
+            },
+        }
+    }
+}
+
+// TODO: checksConditionals and checksSpreads
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub(crate) struct Options {
+    checks_void_return: Option<ChecksVoidReturnOption>,
+}
+
+impl Options {
+    fn checks_void_return(&self) -> ChecksVoidReturn {
+        self.checks_void_return.as_ref().into()
+    }
+}
+
+impl ValidateOptions for Options {
+    // TODO: check JSX attributes once there's some way of knowing the
+    // components' prop types
+    fn validate(&self) -> Vec<ConfigWarning> {
+        match &self.checks_void_return {
+            Some(ChecksVoidReturnOption::Object(ChecksVoidReturnOptions {
+                attributes: Some(true),
+                ..
+            })) => vec![ConfigWarning::new(
+                "no-misused-promises",
+                "checks_void_return",
+                "`checks_void_return.attributes` has no effect because JSX attributes aren't checked.",
+            )],
+            _ => Default::default(),
+        }
+    }
+}
+
+fn get_annotated_type(type_annotation: Node) -> Node {
+    type_annotation
+        .first_non_comment_named_child(SupportedLanguage::Javascript)
+        .skip_parenthesized_types()
+}
+
+fn is_void_type<'a>(type_: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    let type_ = type_.skip_parenthesized_types();
+    type_.kind() == PredefinedType && type_.text(context) == "void"
+}
+
+fn has_void_return_type_annotation<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    node.child_by_field_name("return_type")
+        .matches(|return_type| is_void_type(get_annotated_type(return_type), context))
+}
+
+/// Whether the (in-file) type is a function type with a `void` return, eg
+/// `() => void` or an interface with such a call signature.
+fn is_void_returning_function_type<'a>(
+    type_: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+    type_declarations: &TypeDeclarations<'a>,
+    seen: &mut HashSet<Node<'a>>,
+) -> bool {
+    let type_ = type_.skip_parenthesized_types();
+    match type_.kind() {
+        FunctionType => is_void_type(type_.field("return_type"), context),
+        UnionType | IntersectionType => type_
+            .non_comment_named_children(SupportedLanguage::Javascript)
+            .any(|child| is_void_returning_function_type(child, context, type_declarations, seen)),
+        ObjectType => type_
+            .non_comment_named_children(SupportedLanguage::Javascript)
+            .any(|member| {
+                member.kind() == CallSignature && has_void_return_type_annotation(member, context)
+            }),
+        _ => get_referenced_type_declarations(type_, context, type_declarations, seen)
+            .into_iter()
+            .any(|declaration| {
+                is_void_returning_function_type(
+                    get_declared_type(declaration),
+                    context,
+                    type_declarations,
+                    seen,
+                )
+            }),
+    }
+}
+
+fn get_referenced_type_declarations<'a>(
+    type_: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+    type_declarations: &TypeDeclarations<'a>,
+    seen: &mut HashSet<Node<'a>>,
+) -> Vec<Node<'a>> {
+    let name = match type_.kind() {
+        TypeIdentifier => type_,
+        GenericType => type_.field("name"),
+        _ => return Default::default(),
+    };
+    if name.kind() != TypeIdentifier {
+        return Default::default();
+    }
+    type_declarations
+        .get(&name.text(context))
+        .iter()
+        .copied()
+        .filter(|&declaration| {
+            matches!(
+                TypeDeclarationKind::from_node(declaration),
+                Some(TypeDeclarationKind::Interface | TypeDeclarationKind::TypeAlias)
+            )
+        })
+        .filter(|&declaration| seen.insert(declaration))
+        .collect()
+}
+
+fn get_declared_type(declaration: Node) -> Node {
+    match TypeDeclarationKind::from_node(declaration) {
+        Some(TypeDeclarationKind::Interface) => declaration.field("body"),
+        Some(TypeDeclarationKind::TypeAlias) => declaration.field("value"),
+        _ => unreachable!(),
+    }
+}
+
+fn get_property_name<'a>(
+    key: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Cow<'a, str>> {
+    match key.kind() {
+        PropertyIdentifier => Some(key.text(context)),
+        StringKind => get_static_string_value(key, context),
+        _ => None,
+    }
+}
+
+/// The members (of the object types the type resolves to in-file) that are
+/// named `name`.
+fn get_object_type_members<'a>(
+    type_: Node<'a>,
+    name: &str,
+    context: &QueryMatchContext<'a, '_>,
+    type_declarations: &TypeDeclarations<'a>,
+    seen: &mut HashSet<Node<'a>>,
+) -> Vec<Node<'a>> {
+    let type_ = type_.skip_parenthesized_types();
+    match type_.kind() {
+        ObjectType => type_
+            .non_comment_named_children(SupportedLanguage::Javascript)
+            .filter(|member| {
+                matches!(member.kind(), PropertySignature | MethodSignature)
+                    && get_property_name(member.field("name"), context)
+                        .matches(|member_name| member_name == name)
+            })
+            .collect(),
+        IntersectionType | UnionType => type_
+            .non_comment_named_children(SupportedLanguage::Javascript)
+            .flat_map(|child| {
+                get_object_type_members(child, name, context, type_declarations, seen)
+            })
+            .collect(),
+        _ => get_referenced_type_declarations(type_, context, type_declarations, seen)
+            .into_iter()
+            .flat_map(|declaration| {
+                get_object_type_members(
+                    get_declared_type(declaration),
+                    name,
+                    context,
+                    type_declarations,
+                    seen,
+                )
+            })
+            .collect(),
+    }
+}
+
+fn is_void_returning_member<'a>(
+    member: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+    type_declarations: &TypeDeclarations<'a>,
+) -> bool {
+    match member.kind() {
+        PropertySignature => member.child_by_field_name("type").matches(|type_| {
+            is_void_returning_function_type(
+                get_annotated_type(type_),
+                context,
+                type_declarations,
+                &mut Default::default(),
+            )
+        }),
+        MethodSignature => has_void_return_type_annotation(member, context),
+        _ => unreachable!(),
+    }
+}
+
+fn is_promise_returning_function<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    let node = skip_parenthesized_expressions(node);
+    match node.kind() {
+        ArrowFunction | Function | FunctionDeclaration | MethodDefinition => {
+            is_async_function(node)
+                || node
+                    .child_by_field_name("return_type")
+                    .matches(|return_type| {
+                        let return_type = get_annotated_type(return_type);
+                        return_type.kind() == GenericType
+                            && return_type.field("name").text(context) == "Promise"
+                    })
+        }
+        Identifier => resolve_function(node, context)
+            .matches(|function| is_promise_returning_function(function, context)),
+        _ => false,
+    }
+}
+
+fn is_void_return_expected_for_argument<'a>(
+    argument: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+    type_declarations: &TypeDeclarations<'a>,
+) -> bool {
+    let arguments = argument.parent().unwrap();
+    let index = arguments
+        .non_comment_named_children(SupportedLanguage::Javascript)
+        .position(|child| child == argument)
+        .unwrap();
+    let callee = skip_parenthesized_expressions(arguments.parent().unwrap().field("function"));
+    match callee.kind() {
+        // Array/Map/Set/NodeList etc all take a void-returning callback
+        MemberExpression => index == 0 && callee.field("property").text(context) == "forEach",
+        Identifier => {
+            let Some(function) = resolve_function(callee, context) else {
+                return false;
+            };
+            // (arrow functions with a lone unparenthesized parameter can't
+            // have annotated it)
+            let Some(parameters) = function.child_by_field_name("parameters") else {
+                return false;
+            };
+            parameters
+                .non_comment_named_children(SupportedLanguage::Javascript)
+                .nth(index)
+                .filter(|parameter| {
+                    matches!(parameter.kind(), RequiredParameter | OptionalParameter)
+                })
+                .and_then(|parameter| parameter.child_by_field_name("type"))
+                .matches(|type_| {
+                    is_void_returning_function_type(
+                        get_annotated_type(type_),
+                        context,
+                        type_declarations,
+                        &mut Default::default(),
+                    )
+                })
+        }
+        _ => false,
+    }
+}
+
+pub fn no_misused_promises_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-misused-promises",
+        languages => [Typescript],
+        messages => [
+            void_return_argument => "Promise returned in function argument where a void return was expected.",
+            void_return_variable => "Promise-returning function provided to variable where a void return was expected.",
+            void_return_property => "Promise-returning function provided to property where a void return was expected.",
+            void_return_return_value => "Promise-returning function provided to return value where a void return was expected.",
+            void_return_attribute => "Promise-returning function provided to attribute where a void return was expected.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            checks_void_return: ChecksVoidReturn = options.checks_void_return(),
+        },
+        listeners => [
+            r#"
+              (call_expression
+                arguments: (arguments
+                  (_) @c
+                )
+              )
+            "# => |node, context| {
+                if !self.checks_void_return.arguments {
+                    return;
+                }
+                if !is_promise_returning_function(node, context) {
+                    return;
+                }
+                if !is_void_return_expected_for_argument(
+                    node,
+                    context,
+                    &context.retrieve::<TypeDeclarations<'a>>(),
+                ) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "void_return_argument",
+                });
+            },
+            r#"
+              (variable_declarator
+                type: (_)
+                value: (_)
+              ) @c
+            "# => |node, context| {
+                if !self.checks_void_return.variables {
+                    return;
+                }
+                let value = node.field("value");
+                if !is_promise_returning_function(value, context) {
+                    return;
+                }
+                if !is_void_returning_function_type(
+                    get_annotated_type(node.field("type")),
+                    context,
+                    &context.retrieve::<TypeDeclarations<'a>>(),
+                    &mut Default::default(),
+                ) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => value,
+                    message_id => "void_return_variable",
+                });
+            },
+            r#"
+              (variable_declarator
+                type: (_)
+                value: (object
+                  [
+                    (pair)
+                    (method_definition)
+                  ] @c
+                )
+              )
+            "# => |node, context| {
+                if !self.checks_void_return.properties {
+                    return;
+                }
+                let value = match node.kind() {
+                    Pair => node.field("value"),
+                    _ => node,
+                };
+                if !is_promise_returning_function(value, context) {
+                    return;
+                }
+                let Some(name) = get_property_name(node.field(if node.kind() == Pair {
+                    "key"
+                } else {
+                    "name"
+                }), context) else {
+                    return;
+                };
+                let object = node.parent().unwrap();
+                assert_kind!(object, Object);
+                let type_declarations = context.retrieve::<TypeDeclarations<'a>>();
+                if !get_object_type_members(
+                    get_annotated_type(object.parent().unwrap().field("type")),
+                    &name,
+                    context,
+                    &type_declarations,
+                    &mut Default::default(),
+                )
+                .into_iter()
+                .any(|member| is_void_returning_member(member, context, &type_declarations))
+                {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => value,
+                    message_id => "void_return_property",
+                });
+            },
+            r#"
+              (return_statement
+                (_) @c
+              )
+              (arrow_function
+                return_type: (_)
+                body: (_) @c
+              )
+            "# => |node, context| {
+                if !self.checks_void_return.returns {
+                    return;
+                }
+                if node.kind() == StatementBlock {
+                    return;
+                }
+                if !is_promise_returning_function(node, context) {
+                    return;
+                }
+                let Some(function) = nearest_enclosing_function(node) else {
+                    return;
+                };
+                let Some(return_type) = function.child_by_field_name("return_type") else {
+                    return;
+                };
+                if !is_void_returning_function_type(
+                    get_annotated_type(return_type),
+                    context,
+                    &context.retrieve::<TypeDeclarations<'a>>(),
+                    &mut Default::default(),
+                ) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "void_return_return_value",
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_misused_promises_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "[1, 2, 3].forEach(val => {});",
+              "[1, 2, 3].map(async val => {});",
+              r#"
+function foo(callback: () => Promise<void>) {}
+foo(async () => {});
+                  "#,
+              r#"
+function foo(callback: () => void) {}
+foo(() => {});
+                  "#,
+              r#"
+const foo: () => Promise<void> = async () => {};
+                  "#,
+              r#"
+const foo: () => void = () => {};
+                  "#,
+              r#"
+interface Handlers {
+  onClick: () => Promise<void>;
+}
+const handlers: Handlers = {
+  onClick: async () => {},
+};
+                  "#,
+              r#"
+function makeCallback(): () => Promise<void> {
+  return async () => {};
+}
+                  "#,
+              {
+                code => "[1, 2, 3].forEach(async val => {});",
+                options => { checks_void_return => false },
+              },
+              {
+                code => "[1, 2, 3].forEach(async val => {});",
+                options => { checks_void_return => { arguments => false } },
+              },
+              {
+                code => "const foo: () => void = async () => {};",
+                options => { checks_void_return => { variables => false } },
+              },
+            ],
+            invalid => [
+              {
+                code => "[1, 2, 3].forEach(async val => {});",
+                errors => [
+                  {
+                    message_id => "void_return_argument",
+                    line => 1,
+                    column => 19,
+                    end_line => 1,
+                    end_column => 34,
+                  },
+                ],
+              },
+              {
+                code => "[1, 2, 3].forEach((async (val) => {}));",
+                errors => [
+                  {
+                    message_id => "void_return_argument",
+                    line => 1,
+                    column => 19,
+                  },
+                ],
+              },
+              {
+                code => r#"
+async function handle(val: number) {}
+[1, 2, 3].forEach(handle);
+                    "#,
+                errors => [
+                  {
+                    message_id => "void_return_argument",
+                    line => 3,
+                    column => 19,
+                  },
+                ],
+              },
+              {
+                code => r#"
+const handle = (val: number): Promise<void> => fetchSomething(val);
+[1, 2, 3].forEach(handle);
+                    "#,
+                errors => [
+                  {
+                    message_id => "void_return_argument",
+                    line => 3,
+                    column => 19,
+                  },
+                ],
+              },
+              {
+                code => r#"
+function foo(a: number, callback: () => void) {}
+foo(1, async () => {});
+                    "#,
+                errors => [
+                  {
+                    message_id => "void_return_argument",
+                    line => 3,
+                    column => 8,
+                  },
+                ],
+              },
+              {
+                code => r#"
+type Callback = (value: string) => void;
+function foo(callback?: Callback) {}
+foo(async value => {});
+                    "#,
+                errors => [
+                  {
+                    message_id => "void_return_argument",
+                    line => 4,
+                    column => 5,
+                  },
+                ],
+              },
+              {
+                code => r#"
+interface Callback {
+  (value: string): void;
+}
+function foo(callback: Callback) {}
+foo(async function (value) {});
+                    "#,
+                errors => [
+                  {
+                    message_id => "void_return_argument",
+                    line => 6,
+                    column => 5,
+                  },
+                ],
+              },
+              {
+                code => "const foo: () => void = async () => {};",
+                errors => [
+                  {
+                    message_id => "void_return_variable",
+                    line => 1,
+                    column => 25,
+                    end_line => 1,
+                    end_column => 39,
+                  },
+                ],
+              },
+              {
+                code => r#"
+type Handler = (() => void) | undefined;
+let foo: Handler = async () => {};
+                    "#,
+                errors => [
+                  {
+                    message_id => "void_return_variable",
+                    line => 3,
+                    column => 20,
+                  },
+                ],
+              },
+              {
+                code => r#"
+interface Handlers {
+  onClick: () => void;
+  onHover(): void;
+}
+const handlers: Handlers = {
+  onClick: async () => {},
+  async onHover() {},
+};
+                    "#,
+                errors => [
+                  {
+                    message_id => "void_return_property",
+                    line => 7,
+                    column => 12,
+                  },
+                  {
+                    message_id => "void_return_property",
+                    line => 8,
+                    column => 3,
+                  },
+                ],
+              },
+              {
+                code => r#"
+const handlers: { 'on-click': () => void } = {
+  'on-click': async () => {},
+};
+                    "#,
+                errors => [
+                  {
+                    message_id => "void_return_property",
+                    line => 3,
+                    column => 15,
+                  },
+                ],
+              },
+              {
+                code => r#"
+function makeCallback(): () => void {
+  return async () => {};
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "void_return_return_value",
+                    line => 3,
+                    column => 10,
+                  },
+                ],
+              },
+              {
+                code => "const makeCallback = (): (() => void) => async () => {};",
+                errors => [
+                  {
+                    message_id => "void_return_return_value",
+                    line => 1,
+                    column => 42,
+                  },
+                ],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx_with_provided_types;
+
+    #[test]
+    fn test_no_misused_promises_rule() {
+        run_for_typescript_and_tsx_with_provided_types(
+            no_misused_promises_rule(),
+            test_cases::no_misused_promises_rule_tests(),
+        );
+    }
+}
@@ -0,0 +1,325 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, Rule};
+
+use crate::{ast_helpers::is_external_module_declaration, kind::AmbientDeclaration};
+
+// TODO: upstream also has `allow_definition_files` (which defaults to
+// allowing namespaces in .d.ts files) but rules don't get to see the file
+// name
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    allow_declarations: Option<bool>,
+}
+
+impl Options {
+    fn allow_declarations(&self) -> bool {
+        self.allow_declarations.unwrap_or_default()
+    }
+}
+
+fn is_declaration(node: Node) -> bool {
+    node.ancestors()
+        .any(|ancestor| ancestor.kind() == AmbientDeclaration)
+}
+
+pub fn no_namespace_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-namespace",
+        languages => [Typescript],
+        messages => [
+            module_syntax_is_preferred => "ES2015 module syntax is preferred over namespaces.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            allow_declarations: bool = options.allow_declarations(),
+        },
+        listeners => [
+            // a dotted name (`namespace A.B.C {}`) is a single node whose
+            // name is a `nested_identifier`, so it only gets reported once
+            r#"
+              (internal_module) @c
+              (module) @c
+            "# => |node, context| {
+                if is_external_module_declaration(node) {
+                    return;
+                }
+
+                if self.allow_declarations && is_declaration(node) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node.parent().filter(|parent| parent.kind() == AmbientDeclaration).unwrap_or(node),
+                    message_id => "module_syntax_is_preferred",
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_namespace_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "declare global {}",
+              "declare module 'foo' {}",
+              "declare module 'foo' { export const bar: string; }",
+              {
+                code => "declare module foo {}",
+                options => { allow_declarations => true },
+              },
+              {
+                code => "declare namespace foo {}",
+                options => { allow_declarations => true },
+              },
+              {
+                code => "declare global { namespace foo {} }",
+                options => { allow_declarations => true },
+              },
+              {
+                code => "declare module foo { namespace bar {} }",
+                options => { allow_declarations => true },
+              },
+              {
+                code => r#"
+declare global {
+  namespace foo {
+    namespace bar {}
+  }
+}
+                    "#,
+                options => { allow_declarations => true },
+              },
+              {
+                code => r#"
+declare namespace foo {
+  namespace bar {
+    namespace baz {}
+  }
+}
+                    "#,
+                options => { allow_declarations => true },
+              },
+              {
+                code => r#"
+export declare namespace foo {
+  export namespace bar {
+    namespace baz {}
+  }
+}
+                    "#,
+                options => { allow_declarations => true },
+              },
+              {
+                code => "declare namespace foo.bar.baz {}",
+                options => { allow_declarations => true },
+              },
+            ],
+            invalid => [
+              {
+                code => "module foo {}",
+                errors => [
+                  {
+                    message_id => "module_syntax_is_preferred",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "namespace foo {}",
+                errors => [
+                  {
+                    message_id => "module_syntax_is_preferred",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "module foo {}",
+                options => { allow_declarations => false },
+                errors => [{ message_id => "module_syntax_is_preferred", column => 1 }],
+              },
+              {
+                code => "namespace foo {}",
+                options => { allow_declarations => true },
+                errors => [{ message_id => "module_syntax_is_preferred", column => 1 }],
+              },
+              {
+                code => "declare module foo {}",
+                errors => [{ message_id => "module_syntax_is_preferred", column => 1 }],
+              },
+              {
+                code => "declare namespace foo {}",
+                errors => [{ message_id => "module_syntax_is_preferred", column => 1 }],
+              },
+              {
+                code => "declare namespace foo {}",
+                options => { allow_declarations => false },
+                errors => [{ message_id => "module_syntax_is_preferred", column => 1 }],
+              },
+              {
+                code => "namespace Foo.Bar {}",
+                errors => [
+                  {
+                    message_id => "module_syntax_is_preferred",
+                    line => 1,
+                    column => 1,
+                    end_column => 21,
+                  },
+                ],
+              },
+              {
+                code => r#"
+namespace Foo.Bar {
+  namespace Baz.Bas {
+    interface X {}
+  }
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "module_syntax_is_preferred",
+                    line => 2,
+                    column => 1,
+                  },
+                  {
+                    message_id => "module_syntax_is_preferred",
+                    line => 3,
+                    column => 3,
+                  },
+                ],
+              },
+              {
+                code => r#"
+namespace A {
+  namespace B {
+    declare namespace C {}
+  }
+}
+                    "#,
+                options => { allow_declarations => true },
+                errors => [
+                  {
+                    message_id => "module_syntax_is_preferred",
+                    line => 2,
+                    column => 1,
+                  },
+                  {
+                    message_id => "module_syntax_is_preferred",
+                    line => 3,
+                    column => 3,
+                  },
+                ],
+              },
+              {
+                code => r#"
+namespace A {
+  namespace B {
+    export declare namespace C {}
+  }
+}
+                    "#,
+                options => { allow_declarations => true },
+                errors => [
+                  {
+                    message_id => "module_syntax_is_preferred",
+                    line => 2,
+                    column => 1,
+                  },
+                  {
+                    message_id => "module_syntax_is_preferred",
+                    line => 3,
+                    column => 3,
+                  },
+                ],
+              },
+              {
+                code => r#"
+namespace A {
+  declare namespace B {
+    namespace C {}
+  }
+}
+                    "#,
+                options => { allow_declarations => true },
+                errors => [
+                  {
+                    message_id => "module_syntax_is_preferred",
+                    line => 2,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => r#"
+export declare namespace A {
+  namespace B {
+    namespace C {}
+  }
+}
+                    "#,
+                options => { allow_declarations => false },
+                errors => [
+                  {
+                    message_id => "module_syntax_is_preferred",
+                    line => 2,
+                    column => 8,
+                  },
+                  {
+                    message_id => "module_syntax_is_preferred",
+                    line => 3,
+                    column => 3,
+                  },
+                  {
+                    message_id => "module_syntax_is_preferred",
+                    line => 4,
+                    column => 5,
+                  },
+                ],
+              },
+              {
+                code => r#"
+export namespace A {
+  namespace B {
+    declare namespace C {}
+  }
+}
+                    "#,
+                options => { allow_declarations => true },
+                errors => [
+                  {
+                    message_id => "module_syntax_is_preferred",
+                    line => 2,
+                    column => 8,
+                  },
+                  {
+                    message_id => "module_syntax_is_preferred",
+                    line => 3,
+                    column => 3,
+                  },
+                ],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_namespace_rule() {
+        run_for_typescript_and_tsx(no_namespace_rule(), test_cases::no_namespace_rule_tests());
+    }
+}
@@ -0,0 +1,295 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    AssignmentExpression, AugmentedAssignmentExpression, Identifier, Program, StatementBlock,
+    UpdateExpression, VariableDeclarator,
+};
+
+use crate::util::{find_declaration, skip_parenthesized_expressions};
+
+/// Whether `name` gets assigned to anywhere within `node` before `before`.
+/// Shadowing isn't taken into account, which errs on the side of not
+/// reporting.
+fn has_assignment_before<'a>(
+    node: Node<'a>,
+    name: &str,
+    before: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    if node.start_byte() >= before.end_byte() {
+        return false;
+    }
+    let target = match node.kind() {
+        AssignmentExpression | AugmentedAssignmentExpression => Some(node.field("left")),
+        UpdateExpression => Some(node.field("argument")),
+        _ => None,
+    };
+    if target.is_some_and(|target| {
+        let target = skip_parenthesized_expressions(target);
+        target.kind() == Identifier
+            && target.end_byte() < before.end_byte()
+            && target.text(context) == name
+    }) {
+        return true;
+    }
+    node.non_comment_named_children(SupportedLanguage::Javascript)
+        .any(|child| has_assignment_before(child, name, before, context))
+}
+
+/// Whether the variable `identifier` refers to has definitely been assigned
+/// by the time `node` is evaluated, in which case TypeScript wouldn't need
+/// the `!` to accept it. Unresolved variables are assumed to be.
+fn is_assigned_before<'a>(
+    identifier: Node<'a>,
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    let Some(declaration) = find_declaration(identifier, context) else {
+        return true;
+    };
+    if declaration.kind() != VariableDeclarator {
+        return true;
+    }
+    let has_definite_assignment_assertion = declaration
+        .non_comment_children(SupportedLanguage::Javascript)
+        .any(|child| child.kind() == "!");
+    if has_definite_assignment_assertion || declaration.child_by_field_name("value").is_some() {
+        return declaration.end_byte() < node.end_byte();
+    }
+    let scope = declaration
+        .ancestors()
+        .find(|ancestor| matches!(ancestor.kind(), Program | StatementBlock))
+        .unwrap();
+    has_assignment_before(scope, &identifier.text(context), node, context)
+}
+
+pub fn no_non_null_asserted_nullish_coalescing_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-non-null-asserted-nullish-coalescing",
+        languages => [Typescript],
+        messages => [
+            no_non_null_asserted_nullish_coalescing => "The nullish coalescing operator is designed to handle undefined and null - using a non-null assertion is not needed.",
+            suggest_removing_non_null => "Remove the non-null assertion.",
+        ],
+        listeners => [
+            r#"
+              (binary_expression
+                left: (non_null_expression) @c
+                operator: "??"
+              )
+            "# => |node, context| {
+                let expression = skip_parenthesized_expressions(
+                    node.first_non_comment_named_child(SupportedLanguage::Javascript),
+                );
+                // eg `let x: string; x! ?? ''`, where the `!` is what keeps
+                // TypeScript from complaining that `x` is used before being
+                // assigned
+                if expression.kind() == Identifier && !is_assigned_before(expression, node, context) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "no_non_null_asserted_nullish_coalescing",
+                    // TODO: suggestions? (`suggest_removing_non_null` upstream,
+                    // where dropping the `!` can still surface a type error,
+                    // eg a use-before-assignment this rule didn't catch)
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_non_null_asserted_nullish_coalescing_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "foo ?? bar;",
+                "foo ?? bar!;",
+                "foo.bazz ?? bar;",
+                "foo.bazz ?? bar!;",
+                "foo!.bazz ?? bar;",
+                "foo!.bazz ?? bar!;",
+                "foo() ?? bar;",
+                "foo() ?? bar!;",
+                "(foo ?? bar)!;",
+                "
+let x: string;
+x! ?? '';
+                    ",
+                "
+let x: string;
+x ?? '';
+                    ",
+                "
+let x!: string;
+x ?? '';
+                    ",
+                "
+let x: string;
+foo(x);
+x! ?? '';
+                    ",
+                "
+let x: string;
+x! ?? '';
+x = foo();
+                    ",
+                "
+let x: string;
+foo(x);
+x! ?? '';
+x = foo();
+                    ",
+                "
+let x = foo();
+x ?? '';
+                    ",
+                "
+function foo() {
+  let x: string;
+  return x ?? '';
+}
+                    ",
+                "
+let x: string;
+function foo() {
+  return x ?? '';
+}
+                    ",
+                "
+function foo() {
+  let x: string;
+  return x! ?? '';
+}
+                    ",
+            ],
+            invalid => [
+                {
+                  code => "foo! ?? bar;",
+                  errors => [
+                    {
+                      message_id => "no_non_null_asserted_nullish_coalescing",
+                      line => 1,
+                      column => 1,
+                      end_column => 5,
+                    },
+                  ],
+                },
+                {
+                  code => "foo! ?? bar!;",
+                  errors => [{ message_id => "no_non_null_asserted_nullish_coalescing" }],
+                },
+                {
+                  code => "foo.bazz! ?? bar;",
+                  errors => [{ message_id => "no_non_null_asserted_nullish_coalescing" }],
+                },
+                {
+                  code => "foo.bazz! ?? bar!;",
+                  errors => [{ message_id => "no_non_null_asserted_nullish_coalescing" }],
+                },
+                {
+                  code => "foo().bazz! ?? bar;",
+                  errors => [{ message_id => "no_non_null_asserted_nullish_coalescing" }],
+                },
+                {
+                  code => "foo()! ?? bar;",
+                  errors => [{ message_id => "no_non_null_asserted_nullish_coalescing" }],
+                },
+                {
+                  code => "foo()! ?? bar!;",
+                  errors => [{ message_id => "no_non_null_asserted_nullish_coalescing" }],
+                },
+                {
+                  code => "
+let x!: string;
+x! ?? '';
+                      ",
+                  errors => [{ message_id => "no_non_null_asserted_nullish_coalescing", line => 3 }],
+                },
+                {
+                  code => "
+let x = foo();
+x! ?? '';
+                      ",
+                  errors => [{ message_id => "no_non_null_asserted_nullish_coalescing", line => 3 }],
+                },
+                {
+                  code => "
+let x: string;
+x = foo();
+x! ?? '';
+                      ",
+                  errors => [{ message_id => "no_non_null_asserted_nullish_coalescing", line => 4 }],
+                },
+                {
+                  code => "
+let x: string;
+x = foo();
+x! ?? '';
+x = foo();
+                      ",
+                  errors => [{ message_id => "no_non_null_asserted_nullish_coalescing", line => 4 }],
+                },
+                {
+                  code => "
+function foo() {
+  let x!: string;
+  return x! ?? '';
+}
+                      ",
+                  errors => [{ message_id => "no_non_null_asserted_nullish_coalescing", line => 4 }],
+                },
+                {
+                  code => "
+let x!: string;
+function foo() {
+  return x! ?? '';
+}
+                      ",
+                  errors => [{ message_id => "no_non_null_asserted_nullish_coalescing", line => 4 }],
+                },
+                {
+                  code => "
+let x = foo();
+function bar() {
+  return x! ?? '';
+}
+                      ",
+                  errors => [{ message_id => "no_non_null_asserted_nullish_coalescing", line => 4 }],
+                },
+                {
+                  code => "
+let x: string;
+x = foo();
+function bar() {
+  return (x)! ?? '';
+}
+                      ",
+                  errors => [{ message_id => "no_non_null_asserted_nullish_coalescing", line => 5 }],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_non_null_asserted_nullish_coalescing_rule() {
+        run_for_typescript_and_tsx(
+            no_non_null_asserted_nullish_coalescing_rule(),
+            test_cases::no_non_null_asserted_nullish_coalescing_rule_tests(),
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    CallExpression, MemberExpression, ParenthesizedExpression, SubscriptExpression,
+};
+
+use crate::{kind::NonNullExpression, util::skip_parenthesized_expressions};
+
+/// Whether there's a `?.` anywhere along the (unparenthesized) chain that
+/// ends at `node`.
+fn has_optional_chain(node: Node) -> bool {
+    match node.kind() {
+        MemberExpression | SubscriptExpression => {
+            node.child_by_field_name("optional_chain").is_some()
+                || has_optional_chain(node.field("object"))
+        }
+        CallExpression => {
+            node.child_by_field_name("optional_chain").is_some()
+                || has_optional_chain(node.field("function"))
+        }
+        NonNullExpression => {
+            has_optional_chain(node.first_non_comment_named_child(SupportedLanguage::Javascript))
+        }
+        _ => false,
+    }
+}
+
+/// Whether the chain carries on past `node`, eg the `foo?.bar!` in
+/// `foo?.bar!.baz`, in which case the `!` is asserting something about
+/// `bar` that the `?.` doesn't already cover.
+fn is_continued_chain(node: Node) -> bool {
+    let parent = node.parent().unwrap();
+    match parent.kind() {
+        MemberExpression | SubscriptExpression => parent.field("object") == node,
+        CallExpression => parent.field("function") == node,
+        _ => false,
+    }
+}
+
+pub fn no_non_null_asserted_optional_chain_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-non-null-asserted-optional-chain",
+        languages => [Typescript],
+        messages => [
+            no_non_null_optional_chain => "Optional chain expressions can return undefined by design - using a non-null assertion is unsafe and wrong.",
+            suggest_removing_non_null => "You should remove the non-null assertion.",
+        ],
+        listeners => [
+            r#"
+              (non_null_expression) @c
+            "# => |node, context| {
+                let expression = node.first_non_comment_named_child(SupportedLanguage::Javascript);
+                let is_reportable = if expression.kind() == ParenthesizedExpression {
+                    // eg `(foo?.bar)!.baz`, the parens end the chain
+                    has_optional_chain(skip_parenthesized_expressions(expression))
+                } else {
+                    has_optional_chain(expression) && !is_continued_chain(node)
+                };
+                if !is_reportable {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "no_non_null_optional_chain",
+                    // TODO: suggestions? (upstream suggests
+                    // `suggest_removing_non_null`, which isn't a fix since
+                    // the expression's type then includes `undefined`)
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_non_null_asserted_optional_chain_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "foo.bar!;",
+                "foo.bar!.baz;",
+                "foo.bar!.baz();",
+                "foo.bar()!;",
+                "foo.bar()!();",
+                "foo.bar()!.baz;",
+                "foo?.bar;",
+                "foo?.bar();",
+                "(foo?.bar).baz!;",
+                "(foo?.bar()).baz!;",
+                "foo?.bar!.baz;",
+                "foo?.bar!();",
+                "foo?.['bar']!.baz;",
+            ],
+            invalid => [
+                {
+                  code => "foo?.bar!;",
+                  errors => [
+                    {
+                      message_id => "no_non_null_optional_chain",
+                      line => 1,
+                      column => 1,
+                      end_column => 10,
+                    },
+                  ],
+                },
+                {
+                  code => "foo?.['bar']!;",
+                  errors => [{ message_id => "no_non_null_optional_chain" }],
+                },
+                {
+                  code => "foo?.bar()!;",
+                  errors => [{ message_id => "no_non_null_optional_chain" }],
+                },
+                {
+                  code => "foo.bar?.()!;",
+                  errors => [{ message_id => "no_non_null_optional_chain" }],
+                },
+                {
+                  code => "foo?.bar.baz!;",
+                  errors => [{ message_id => "no_non_null_optional_chain" }],
+                },
+                {
+                  code => "(foo?.bar)!.baz",
+                  errors => [
+                    {
+                      message_id => "no_non_null_optional_chain",
+                      line => 1,
+                      column => 1,
+                      end_column => 11,
+                    },
+                  ],
+                },
+                {
+                  code => "(foo?.bar)!().baz",
+                  errors => [{ message_id => "no_non_null_optional_chain" }],
+                },
+                {
+                  code => "(foo?.bar)!",
+                  errors => [{ message_id => "no_non_null_optional_chain" }],
+                },
+                {
+                  code => "(foo?.bar)!()",
+                  errors => [{ message_id => "no_non_null_optional_chain" }],
+                },
+                {
+                  code => "(foo?.bar!)",
+                  errors => [
+                    {
+                      message_id => "no_non_null_optional_chain",
+                      line => 1,
+                      column => 2,
+                      end_column => 11,
+                    },
+                  ],
+                },
+                {
+                  code => "(foo?.bar!)()",
+                  errors => [{ message_id => "no_non_null_optional_chain" }],
+                },
+                {
+                  code => "foo?.bar!.baz!;",
+                  errors => [{ message_id => "no_non_null_optional_chain" }],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_non_null_asserted_optional_chain_rule() {
+        run_for_typescript_and_tsx(
+            no_non_null_asserted_optional_chain_rule(),
+            test_cases::no_non_null_asserted_optional_chain_rule_tests(),
+        );
+    }
+}
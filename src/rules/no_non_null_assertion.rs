@@ -0,0 +1,165 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    CallExpression, MemberExpression, SubscriptExpression,
+};
+
+/// The member access or call that `node` is the receiver of, eg the `x!.y`
+/// in `x!.y`.
+fn get_chained_parent(node: Node) -> Option<Node> {
+    let parent = node.parent().unwrap();
+    match parent.kind() {
+        MemberExpression | SubscriptExpression => {
+            (parent.field("object") == node).then_some(parent)
+        }
+        CallExpression => (parent.field("function") == node).then_some(parent),
+        _ => None,
+    }
+}
+
+pub fn no_non_null_assertion_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-non-null-assertion",
+        languages => [Typescript],
+        messages => [
+            no_non_null => "Forbidden non-null assertion.",
+            suggest_optional_chain => "Consider using the optional chain operator `?.` instead. This operator includes runtime checks, so it is safer than the compile-only non-null assertion operator.",
+        ],
+        fixable => true,
+        listeners => [
+            r#"
+              (non_null_expression) @c
+            "# => |node, context| {
+                let is_already_optional = get_chained_parent(node).is_some_and(|parent| {
+                    parent.child_by_field_name("optional_chain").is_some()
+                });
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "no_non_null",
+                    // TODO: suggestions? (upstream suggests `x?.y`/`x?.[y]`/
+                    // `x?.()` for `x!.y`/`x![y]`/`x!()`, which can't be a fix
+                    // since it changes what happens when `x` is nullish)
+                    fix => |fixer| {
+                        // eg `x!?.y`, where the `!` is redundant anyway
+                        if !is_already_optional {
+                            return;
+                        }
+                        let bang = node
+                            .non_comment_children(SupportedLanguage::Javascript)
+                            .last()
+                            .unwrap();
+                        fixer.remove(bang);
+                    },
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_non_null_assertion_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "x;",
+                "x.y;",
+                "x.y.z;",
+                "x?.y.z;",
+                "x?.y?.z;",
+                "!x;",
+            ],
+            invalid => [
+                {
+                  code => "x!;",
+                  errors => [
+                    {
+                      message_id => "no_non_null",
+                      line => 1,
+                      column => 1,
+                      end_column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => "x!.y;",
+                  errors => [{ message_id => "no_non_null", column => 1, end_column => 3 }],
+                },
+                {
+                  code => "x.y!;",
+                  errors => [{ message_id => "no_non_null", column => 1, end_column => 5 }],
+                },
+                {
+                  code => "!x!.y;",
+                  errors => [{ message_id => "no_non_null", column => 2, end_column => 4 }],
+                },
+                {
+                  code => "x!.y?.z;",
+                  errors => [{ message_id => "no_non_null" }],
+                },
+                {
+                  code => "x![y];",
+                  errors => [{ message_id => "no_non_null" }],
+                },
+                {
+                  code => "x![y]?.z;",
+                  errors => [{ message_id => "no_non_null" }],
+                },
+                {
+                  code => "x.y.z!();",
+                  errors => [{ message_id => "no_non_null", column => 1, end_column => 7 }],
+                },
+                {
+                  code => "x!!!;",
+                  errors => [
+                    { message_id => "no_non_null" },
+                    { message_id => "no_non_null" },
+                    { message_id => "no_non_null" },
+                  ],
+                },
+                {
+                  code => "x!?.y;",
+                  output => "x?.y;",
+                  errors => [{ message_id => "no_non_null", column => 1, end_column => 3 }],
+                },
+                {
+                  code => "x!?.[y];",
+                  output => "x?.[y];",
+                  errors => [{ message_id => "no_non_null" }],
+                },
+                {
+                  code => "x.y.z!?.();",
+                  output => "x.y.z?.();",
+                  errors => [{ message_id => "no_non_null" }],
+                },
+                {
+                  code => "x!!?.y;",
+                  output => "x!?.y;",
+                  errors => [
+                    { message_id => "no_non_null" },
+                    { message_id => "no_non_null" },
+                  ],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_non_null_assertion_rule() {
+        run_for_typescript_and_tsx(
+            no_non_null_assertion_rule(),
+            test_cases::no_non_null_assertion_rule_tests(),
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use std::sync::Arc;
+
+use regex::Regex;
+use serde::Deserialize;
+use tree_sitter_lint::{rule, tree_sitter_grep::SupportedLanguage, violation, NodeExt, Rule};
+
+use crate::{
+    ast_helpers::get_range_from,
+    module_kind::FileModuleKind,
+    util::{get_require_path, is_global_require, is_require_path_allowed, RegexOption},
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    allow: Option<Vec<RegexOption>>,
+    allow_as_import: Option<bool>,
+    allow_in_common_js: Option<bool>,
+}
+
+impl Options {
+    fn allow(&self) -> Vec<Regex> {
+        self.allow
+            .iter()
+            .flatten()
+            .map(|allow| allow.0.clone())
+            .collect()
+    }
+
+    fn allow_as_import(&self) -> bool {
+        self.allow_as_import.unwrap_or_default()
+    }
+
+    fn allow_in_common_js(&self) -> bool {
+        self.allow_in_common_js.unwrap_or_default()
+    }
+}
+
+pub fn no_require_imports_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-require-imports",
+        languages => [Typescript],
+        messages => [
+            no_require_imports => "A `require()` style import is forbidden.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            allow_patterns: Vec<Regex> = options.allow(),
+            allow_as_import: bool = options.allow_as_import(),
+            allow_in_common_js: bool = options.allow_in_common_js(),
+        },
+        listeners => [
+            r#"
+              (call_expression
+                function: (identifier) @callee (#eq? @callee "require")
+              ) @call
+            "# => |captures, context| {
+                let node = captures["call"];
+                if self.allow_in_common_js && context.retrieve::<FileModuleKind>().is_script() {
+                    return;
+                }
+                if node
+                    .field("arguments")
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                    .next()
+                    .and_then(|argument| get_require_path(argument, context))
+                    .is_some_and(|path| is_require_path_allowed(&path, &self.allow_patterns))
+                {
+                    return;
+                }
+                // a locally declared `require` is something custom rather
+                // than CommonJS
+                if !is_global_require(captures["callee"], context) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "no_require_imports",
+                    data => {
+                        banned_name => "require",
+                    },
+                });
+            },
+            r#"
+              (import_require_clause) @c
+            "# => |node, context| {
+                if self.allow_as_import {
+                    return;
+                }
+                if get_require_path(node.field("source"), context)
+                    .is_some_and(|path| is_require_path_allowed(&path, &self.allow_patterns))
+                {
+                    return;
+                }
+
+                let require_keyword = node
+                    .non_comment_children(SupportedLanguage::Javascript)
+                    .find(|child| child.kind() == "require")
+                    .unwrap();
+                context.report(violation! {
+                    node => node,
+                    range => get_range_from(require_keyword, node),
+                    message_id => "no_require_imports",
+                    data => {
+                        banned_name => "require",
+                    },
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_require_imports_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "import { l } from 'lib';",
+                "var lib3 = load('not_an_import');",
+                "var lib4 = lib2.subImport;",
+                "var lib7 = 700;",
+                "import lib9 = lib2.anotherSubImport;",
+                "import lib10 from 'lib10';",
+                "var lib3 = load?.('not_an_import');",
+                "
+import { createRequire } from 'module';
+const require = createRequire();
+require('remark-preset-prettier');
+                    ",
+                {
+                  code => "const pkg = require('./package.json');",
+                  options => { allow => ["/package\\.json$"] },
+                },
+                {
+                  code => "const pkg = require(`./package.json`);",
+                  options => { allow => ["/package\\.json$"] },
+                },
+                {
+                  code => "import pkg = require('./package.json');",
+                  options => { allow => ["/package\\.json$"] },
+                },
+                {
+                  code => "import foo = require('foo');",
+                  options => { allow_as_import => true },
+                },
+                {
+                  code => "
+const foo = require('foo');
+require('bar');
+                      ",
+                  options => { allow_in_common_js => true },
+                },
+            ],
+            invalid => [
+                {
+                  code => "var lib = require('lib');",
+                  errors => [
+                    {
+                      message_id => "no_require_imports",
+                      data => {
+                        banned_name => "require",
+                      },
+                      line => 1,
+                      column => 11,
+                    },
+                  ],
+                },
+                {
+                  code => "let lib2 = require('lib2');",
+                  errors => [{ message_id => "no_require_imports", column => 12 }],
+                },
+                {
+                  code => "
+var lib5 = require('lib5'),
+  lib6 = require('lib6');
+                      ",
+                  errors => [
+                    { message_id => "no_require_imports", line => 2, column => 12 },
+                    { message_id => "no_require_imports", line => 3, column => 10 },
+                  ],
+                },
+                {
+                  code => "import lib8 = require('lib8');",
+                  errors => [
+                    {
+                      message_id => "no_require_imports",
+                      line => 1,
+                      column => 15,
+                      end_column => 30,
+                    },
+                  ],
+                },
+                {
+                  code => "var lib9 = require?.('lib9');",
+                  errors => [{ message_id => "no_require_imports", column => 12 }],
+                },
+                {
+                  code => "const pkg = require('./package.json');",
+                  options => { allow => ["^some-package$"] },
+                  errors => [{ message_id => "no_require_imports" }],
+                },
+                {
+                  code => "import pkg = require('./package.json');",
+                  options => { allow_as_import => false, allow => ["^some-package$"] },
+                  errors => [{ message_id => "no_require_imports" }],
+                },
+                {
+                  code => "
+export {};
+const foo = require('foo');
+                      ",
+                  options => { allow_in_common_js => true },
+                  errors => [{ message_id => "no_require_imports", line => 3 }],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::serde_json::{self, json};
+
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx_with_provided_types;
+
+    #[test]
+    fn test_no_require_imports_rule() {
+        run_for_typescript_and_tsx_with_provided_types(
+            no_require_imports_rule(),
+            test_cases::no_require_imports_rule_tests(),
+        );
+    }
+
+    #[test]
+    fn test_invalid_allow_pattern_is_an_options_error() {
+        assert!(
+            serde_json::from_value::<Options>(json!({ "allow": ["/package\\.json$"] })).is_ok()
+        );
+        assert!(serde_json::from_value::<Options>(json!({ "allow": ["package(.json"] })).is_err());
+    }
+}
@@ -0,0 +1,432 @@
+use std::{borrow::Cow, collections::HashSet, sync::Arc};
+
+use serde::Deserialize;
+use squalid::regex;
+use tree_sitter_lint::{
+    rule,
+    tree_sitter::{Language, Node, Query, QueryCursor, QueryMatch},
+    tree_sitter_grep::SupportedLanguage,
+    violation, NodeExt, QueryMatchContext, Rule,
+};
+
+use crate::{
+    restricted_syntax::get_restricted_syntax_preset,
+    util::{get_tsx_language, is_tsx_language},
+};
+
+#[derive(Clone, Deserialize)]
+#[serde(untagged)]
+enum RestrictionOption {
+    Selector(String),
+    WithMessage {
+        selector: String,
+        message: Option<String>,
+    },
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    // preset names (see `restricted_syntax_presets()`) or raw tree-sitter
+    // queries, optionally with a message (which can interpolate the text of
+    // captures, eg `{{name}}` for `@name`)
+    restrictions: Vec<RestrictionOption>,
+}
+
+struct Restriction {
+    selector: String,
+    message: Option<String>,
+    /// The query compiled against each of the TypeScript and TSX grammars
+    /// (which differ, eg TSX has no `type_assertion`), or `None` for a
+    /// grammar that lacks some node kind the query refers to.
+    typescript_query: Option<Query>,
+    tsx_query: Option<Query>,
+}
+
+impl Restriction {
+    fn get_query(&self, language: Language) -> Option<&Query> {
+        if is_tsx_language(language) {
+            self.tsx_query.as_ref()
+        } else {
+            self.typescript_query.as_ref()
+        }
+    }
+}
+
+fn get_message_placeholders(message: &str) -> impl Iterator<Item = &str> {
+    regex!(r#"\{\{\s*(\w+)\s*\}\}"#)
+        .captures_iter(message)
+        .map(|captures| captures.get(1).unwrap().as_str())
+}
+
+/// Resolves presets and checks that each query compiles (against the
+/// TypeScript and/or TSX grammar) and that each message only refers to
+/// captures of its query, so that misconfigurations surface when the config
+/// is loaded rather than when linting some file.
+fn get_restrictions(options: &Options) -> Vec<Restriction> {
+    options
+        .restrictions
+        .iter()
+        .map(|restriction_option| {
+            let (selector, message) = match restriction_option {
+                RestrictionOption::Selector(selector) => (selector.trim(), None),
+                RestrictionOption::WithMessage { selector, message } => {
+                    (selector.trim(), message.clone())
+                }
+            };
+            let query_source = get_restricted_syntax_preset(selector)
+                .map_or(selector, |preset| preset.query);
+            let typescript_query =
+                Query::new(SupportedLanguage::Typescript.language(None), query_source);
+            let tsx_query = Query::new(get_tsx_language(), query_source);
+            if let (Err(error), Err(_)) = (&typescript_query, &tsx_query) {
+                panic!("invalid no-restricted-ts-syntax query {selector:?}: {error}");
+            }
+            let restriction = Restriction {
+                selector: selector.to_owned(),
+                message,
+                typescript_query: typescript_query.ok(),
+                tsx_query: tsx_query.ok(),
+            };
+            if let Some(message) = restriction.message.as_ref() {
+                for placeholder in get_message_placeholders(message) {
+                    let is_capture = restriction
+                        .typescript_query
+                        .iter()
+                        .chain(&restriction.tsx_query)
+                        .all(|query| query.capture_names().iter().any(|name| name == placeholder));
+                    if !is_capture {
+                        panic!(
+                            "no-restricted-ts-syntax message {message:?} refers to nonexistent capture @{placeholder}"
+                        );
+                    }
+                }
+            }
+            restriction
+        })
+        .collect()
+}
+
+fn interpolate_captures<'a>(
+    message: &str,
+    query: &Query,
+    query_match: &QueryMatch<'_, 'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> String {
+    regex!(r#"\{\{\s*(\w+)\s*\}\}"#)
+        .replace_all(message, |captures: &regex::Captures| {
+            let capture_index = query.capture_index_for_name(&captures[1]).unwrap();
+            query_match
+                .nodes_for_capture_index(capture_index)
+                .next()
+                .map_or(Cow::Borrowed(""), |node| node.text(context))
+                .into_owned()
+        })
+        .into_owned()
+}
+
+/// The file's source, for evaluating query predicates. The root node doesn't
+/// include leading whitespace, so that gets padded back in to keep byte
+/// offsets lined up.
+fn get_source_bytes(root: Node, context: &QueryMatchContext) -> Vec<u8> {
+    let mut source = vec![b' '; root.start_byte()];
+    source.extend_from_slice(root.text(context).as_bytes());
+    source
+}
+
+pub fn no_restricted_ts_syntax_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-restricted-ts-syntax",
+        languages => [Typescript],
+        messages => [
+            restricted_syntax => "Using '{{selector}}' is not allowed.",
+            restricted_syntax_custom => "{{message}}",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            restrictions: Vec<Restriction> = get_restrictions(&options),
+        },
+        listeners => [
+            r#"
+              (program) @c
+            "# => |node, context| {
+                if self.restrictions.is_empty() {
+                    return;
+                }
+                let source = get_source_bytes(node, context);
+                let language = context.file_run_context.tree.language();
+                for restriction in &self.restrictions {
+                    let Some(query) = restriction.get_query(language) else {
+                        continue;
+                    };
+                    // (eg a parameter property can match more than one of a
+                    // preset's patterns)
+                    let mut reported: HashSet<usize> = Default::default();
+                    let mut cursor = QueryCursor::new();
+                    for query_match in cursor.matches(query, node, &*source) {
+                        for capture in query_match.captures {
+                            if query.capture_names()[capture.index as usize].starts_with('_')
+                                || !reported.insert(capture.node.id())
+                            {
+                                continue;
+                            }
+                            match restriction.message.as_ref() {
+                                Some(message) => context.report(violation! {
+                                    node => capture.node,
+                                    message_id => "restricted_syntax_custom",
+                                    data => {
+                                        message => interpolate_captures(message, query, &query_match, context),
+                                        banned_name => &restriction.selector,
+                                    },
+                                }),
+                                None => context.report(violation! {
+                                    node => capture.node,
+                                    message_id => "restricted_syntax",
+                                    data => {
+                                        selector => &restriction.selector,
+                                        banned_name => &restriction.selector,
+                                    },
+                                }),
+                            }
+                        }
+                    }
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_restricted_ts_syntax_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "enum Foo { A }",
+              {
+                code => "const x = { a: 1 } as const;",
+                options => { restrictions => ["as-expression", "non-null-assertion"] },
+              },
+              {
+                code => "declare module 'foo';",
+                options => { restrictions => ["namespace-with-body"] },
+              },
+              {
+                code => "let x: unknown;",
+                options => { restrictions => ["any-type"] },
+              },
+              {
+                code => "class Foo { constructor(foo: string) {} }",
+                options => { restrictions => ["parameter-property"] },
+              },
+            ],
+            invalid => [
+              {
+                code => "enum Foo { A }",
+                options => { restrictions => ["enum-declaration"] },
+                errors => [
+                  {
+                    message_id => "restricted_syntax",
+                    data => {
+                      selector => "enum-declaration",
+                      banned_name => "enum-declaration",
+                    },
+                    line => 1,
+                    column => 1,
+                    end_line => 1,
+                    end_column => 15,
+                  },
+                ],
+              },
+              {
+                code => "const enum Foo { A }\nenum Bar { B }",
+                options => { restrictions => ["const-enum"] },
+                errors => [{ message_id => "restricted_syntax", line => 1 }],
+              },
+              {
+                code => "namespace Foo { export const x = 1; }",
+                options => { restrictions => ["namespace-with-body"] },
+                errors => [
+                  {
+                    message_id => "restricted_syntax",
+                    data => {
+                      selector => "namespace-with-body",
+                      banned_name => "namespace-with-body",
+                    },
+                  },
+                ],
+              },
+              {
+                code => "foo!.bar;",
+                options => { restrictions => ["non-null-assertion"] },
+                errors => [{ message_id => "restricted_syntax", column => 1, end_column => 5 }],
+              },
+              {
+                code => "let x: any = foo;",
+                options => { restrictions => ["any-type"] },
+                errors => [
+                  {
+                    message_id => "restricted_syntax",
+                    data => { selector => "any-type", banned_name => "any-type" },
+                    column => 8,
+                  },
+                ],
+              },
+              {
+                code => "const x = <string>foo;",
+                options => { restrictions => ["type-assertion"] },
+                errors => [{ message_id => "restricted_syntax", column => 11, end_column => 22 }],
+              },
+              {
+                code => "const x = foo as string;",
+                options => { restrictions => ["as-expression"] },
+                errors => [{ message_id => "restricted_syntax", column => 11, end_column => 24 }],
+              },
+              {
+                code => "class Foo { constructor(private foo: string, readonly bar?: number, private readonly baz) {} }",
+                options => { restrictions => ["parameter-property"] },
+                errors => [
+                  { message_id => "restricted_syntax", column => 25 },
+                  { message_id => "restricted_syntax", column => 46 },
+                  { message_id => "restricted_syntax", column => 69 },
+                ],
+              },
+              {
+                code => "abstract class Foo {}",
+                options => { restrictions => ["abstract-class"] },
+                errors => [{ message_id => "restricted_syntax", column => 1 }],
+              },
+              {
+                code => "class Foo { @Input() bar; }",
+                options => { restrictions => ["decorator"] },
+                errors => [{ message_id => "restricted_syntax", column => 13, end_column => 21 }],
+              },
+              {
+                code => "enum Direction { Up }",
+                options => {
+                  restrictions => [
+                    {
+                      selector => "(enum_declaration name: (identifier) @_name) @enum",
+                      message => "Use a union type instead of enum {{ _name }}.",
+                    },
+                  ],
+                },
+                errors => [
+                  {
+                    message_id => "restricted_syntax_custom",
+                    data => {
+                      message => "Use a union type instead of enum Direction.",
+                      banned_name => "(enum_declaration name: (identifier) @_name) @enum",
+                    },
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "namespace Foo {}",
+                options => {
+                  restrictions => [
+                    {
+                      selector => "namespace-with-body",
+                      message => "Use ES modules instead.",
+                    },
+                  ],
+                },
+                errors => [
+                  {
+                    message_id => "restricted_syntax_custom",
+                    data => {
+                      message => "Use ES modules instead.",
+                      banned_name => "namespace-with-body",
+                    },
+                  },
+                ],
+              },
+              {
+                code => "foo(<string>bar);",
+                options => { restrictions => ["(call_expression arguments: (arguments (type_assertion) @_assertion)) @call"] },
+                errors => [
+                  {
+                    message_id => "restricted_syntax",
+                    data => {
+                      selector => "(call_expression arguments: (arguments (type_assertion) @_assertion)) @call",
+                      banned_name => "(call_expression arguments: (arguments (type_assertion) @_assertion)) @call",
+                    },
+                    column => 1,
+                    end_column => 17,
+                  },
+                ],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::tree_sitter::Parser;
+
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    fn options(restrictions: Vec<RestrictionOption>) -> Options {
+        Options { restrictions }
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid no-restricted-ts-syntax query")]
+    fn test_invalid_query() {
+        get_restrictions(&options(vec![RestrictionOption::Selector(
+            "(nonexistent_kind) @foo".to_owned(),
+        )]));
+    }
+
+    #[test]
+    #[should_panic(expected = "refers to nonexistent capture @name")]
+    fn test_message_with_nonexistent_capture() {
+        get_restrictions(&options(vec![RestrictionOption::WithMessage {
+            selector: "(enum_declaration) @enum".to_owned(),
+            message: Some("Don't use {{name}}".to_owned()),
+        }]));
+    }
+
+    #[test]
+    fn test_tsx() {
+        let restrictions = get_restrictions(&options(vec![
+            RestrictionOption::Selector("type-assertion".to_owned()),
+            RestrictionOption::Selector("as-expression".to_owned()),
+        ]));
+        let typescript = SupportedLanguage::Typescript.language(None);
+        let tsx = get_tsx_language();
+        assert!(!is_tsx_language(typescript));
+        assert!(is_tsx_language(tsx));
+        assert!(restrictions[0].get_query(typescript).is_some());
+        assert!(restrictions[0].get_query(tsx).is_none());
+
+        let source = "const x = <div /> as Foo;";
+        let mut parser = Parser::new();
+        parser.set_language(tsx).unwrap();
+        let tree = parser.parse(source, None).unwrap();
+        assert!(!tree.root_node().has_error());
+        assert_eq!(
+            QueryCursor::new()
+                .matches(
+                    restrictions[1].get_query(tsx).unwrap(),
+                    tree.root_node(),
+                    source.as_bytes(),
+                )
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_no_restricted_ts_syntax_rule() {
+        run_for_typescript_and_tsx(
+            no_restricted_ts_syntax_rule(),
+            test_cases::no_restricted_ts_syntax_rule_tests(),
+        );
+    }
+}
@@ -0,0 +1,579 @@
+use std::{cmp::Reverse, collections::HashSet, sync::Arc};
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    ArrowFunction, CatchClause, ForInStatement, Function, GeneratorFunction, ImportSpecifier,
+    ImportStatement, MethodDefinition, Program, StatementBlock, VariableDeclarator,
+};
+
+use crate::{
+    ast_helpers::{get_import_bindings, get_pattern_identifiers, is_this_parameter},
+    kind::{
+        AbstractMethodSignature, CallSignature, ConstructSignature, ConstructorType,
+        FunctionSignature, FunctionType, MethodSignature, OptionalParameter, RequiredParameter,
+    },
+    lib_globals::get_lib_global,
+    module_kind::FileModuleKind,
+    util::{get_binding_scope, is_function},
+};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Hoist {
+    All,
+    Functions,
+    #[default]
+    FunctionsAndTypes,
+    Never,
+    Types,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    allow: Option<Vec<String>>,
+    builtin_globals: Option<bool>,
+    hoist: Option<Hoist>,
+    ignore_on_initialization: Option<bool>,
+    ignore_type_value_shadow: Option<bool>,
+    ignore_function_type_parameter_name_value_shadow: Option<bool>,
+}
+
+impl Options {
+    fn allow(&self) -> HashSet<String> {
+        self.allow.iter().flatten().cloned().collect()
+    }
+
+    fn builtin_globals(&self) -> bool {
+        self.builtin_globals.unwrap_or_default()
+    }
+
+    fn hoist(&self) -> Hoist {
+        self.hoist.unwrap_or_default()
+    }
+
+    fn ignore_on_initialization(&self) -> bool {
+        self.ignore_on_initialization.unwrap_or_default()
+    }
+
+    fn ignore_type_value_shadow(&self) -> bool {
+        self.ignore_type_value_shadow.unwrap_or(true)
+    }
+
+    fn ignore_function_type_parameter_name_value_shadow(&self) -> bool {
+        self.ignore_function_type_parameter_name_value_shadow
+            .unwrap_or(true)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum BindingKind {
+    Variable,
+    Parameter,
+    /// A parameter of eg a function type or an overload signature, which
+    /// only names the parameter.
+    SignatureParameter,
+    Function,
+    Class,
+    Enum,
+    /// An interface or a type alias.
+    Type,
+    TypeParameter,
+    Import,
+    TypeImport,
+}
+
+impl BindingKind {
+    fn is_value(self) -> bool {
+        !matches!(self, Self::Type | Self::TypeParameter | Self::TypeImport)
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Binding<'a> {
+    name: Node<'a>,
+    kind: BindingKind,
+    scope: Node<'a>,
+}
+
+impl<'a> Binding<'a> {
+    /// A function's (or a `catch` clause's) body counts as part of the
+    /// function, so that eg a `let` in the body isn't seen as shadowing a
+    /// parameter.
+    fn new(name: Node<'a>, kind: BindingKind) -> Option<Self> {
+        let scope = match kind {
+            BindingKind::Import | BindingKind::TypeImport => name
+                .ancestors()
+                .find(|ancestor| ancestor.kind() == ImportStatement)?
+                .parent()?,
+            _ => get_binding_scope(name)?,
+        };
+        let scope = match scope.kind() {
+            StatementBlock
+                if scope
+                    .parent()
+                    .is_some_and(|parent| is_function(parent) || parent.kind() == CatchClause) =>
+            {
+                scope.parent().unwrap()
+            }
+            _ => scope,
+        };
+        Some(Self { name, kind, scope })
+    }
+
+    /// Whether this binding's scope is nested inside `other`'s.
+    fn is_inside(&self, other: &Self) -> bool {
+        self.scope != other.scope
+            && self
+                .scope
+                .ancestors()
+                .any(|ancestor| ancestor == other.scope)
+    }
+}
+
+fn is_signature(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        FunctionType
+            | ConstructorType
+            | CallSignature
+            | ConstructSignature
+            | MethodSignature
+            | AbstractMethodSignature
+            | FunctionSignature
+    )
+}
+
+/// `class Foo<T> { static method<T>() {} }`, where the class's `T` isn't
+/// usable in the static method anyway.
+fn is_generic_of_static_method(inner: &Binding, outer: &Binding) -> bool {
+    inner.kind == BindingKind::TypeParameter
+        && outer.kind == BindingKind::TypeParameter
+        && inner.scope.kind() == MethodDefinition
+        && inner.scope.has_child_of_kind("static")
+        && inner
+            .scope
+            .parent()
+            .and_then(|class_body| class_body.parent())
+            == Some(outer.scope)
+}
+
+/// eg `const x = foo(x => x)`, where the inner binding belongs to a function
+/// in the outer variable's initializer.
+fn is_in_initializer(inner: &Binding, outer: &Binding) -> bool {
+    let Some(function) = std::iter::once(inner.scope)
+        .chain(inner.scope.ancestors())
+        .find(|&node| is_function(node))
+    else {
+        return false;
+    };
+    if !matches!(
+        function.kind(),
+        ArrowFunction | Function | GeneratorFunction
+    ) {
+        return false;
+    }
+    let Some(initializer) = outer
+        .name
+        .ancestors()
+        .find_map(|ancestor| match ancestor.kind() {
+            VariableDeclarator => ancestor.child_by_field_name("value"),
+            ForInStatement => ancestor.child_by_field_name("right"),
+            _ => None,
+        })
+    else {
+        return false;
+    };
+    initializer.start_byte() <= function.start_byte()
+        && function.end_byte() <= initializer.end_byte()
+}
+
+pub fn no_shadow_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-shadow",
+        languages => [Typescript],
+        messages => [
+            no_shadow => "'{{name}}' is already declared in the upper scope on line {{shadowed_line}} column {{shadowed_column}}.",
+            no_shadow_global => "'{{name}}' is already a global variable.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            allow: HashSet<String> = options.allow(),
+            builtin_globals: bool = options.builtin_globals(),
+            hoist: Hoist = options.hoist(),
+            ignore_on_initialization: bool = options.ignore_on_initialization(),
+            ignore_type_value_shadow: bool = options.ignore_type_value_shadow(),
+            ignore_function_type_parameter_name_value_shadow: bool =
+                options.ignore_function_type_parameter_name_value_shadow(),
+            [per-file-run]
+            bindings: Vec<Binding<'a>>,
+        },
+        methods => {
+            fn push_bindings(&mut self, names: impl IntoIterator<Item = Node<'a>>, kind: BindingKind) {
+                self.bindings.extend(names.into_iter().filter_map(|name| Binding::new(name, kind)));
+            }
+
+            /// The outer binding is declared after the inner one and (per
+            /// the `hoist` option) shouldn't count until then.
+            fn is_in_tdz(&self, inner: &Binding, outer: &Binding) -> bool {
+                if inner.name.end_byte() >= outer.name.start_byte() {
+                    return false;
+                }
+                match self.hoist {
+                    Hoist::All => false,
+                    Hoist::Functions => outer.kind != BindingKind::Function,
+                    Hoist::Types => outer.kind != BindingKind::Type,
+                    Hoist::FunctionsAndTypes => {
+                        !matches!(outer.kind, BindingKind::Function | BindingKind::Type)
+                    }
+                    Hoist::Never => true,
+                }
+            }
+
+            fn should_report(&self, inner: &Binding, outer: &Binding) -> bool {
+                if self.ignore_type_value_shadow && inner.kind.is_value() != outer.kind.is_value() {
+                    return false;
+                }
+                if self.ignore_function_type_parameter_name_value_shadow
+                    && inner.kind == BindingKind::SignatureParameter
+                    && outer.kind.is_value()
+                {
+                    return false;
+                }
+                if is_generic_of_static_method(inner, outer) {
+                    return false;
+                }
+                if self.ignore_on_initialization && is_in_initializer(inner, outer) {
+                    return false;
+                }
+                !self.is_in_tdz(inner, outer)
+            }
+
+            fn check_global(&self, inner: &Binding, context: &QueryMatchContext<'a, '_>) {
+                // top-level declarations in a script are the globals
+                if inner.scope.kind() == Program && context.retrieve::<FileModuleKind>().is_script() {
+                    return;
+                }
+                let name = inner.name.text(context);
+                let Some(global) = get_lib_global(&name) else {
+                    return;
+                };
+                if self.ignore_type_value_shadow && inner.kind.is_value() != global.is_value() {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => inner.name,
+                    message_id => "no_shadow_global",
+                    data => {
+                        name => name,
+                    },
+                });
+            }
+        },
+        listeners => [
+            r#"
+              (variable_declarator) @c
+            "# => |node, context| {
+                self.push_bindings(get_pattern_identifiers(node.field("name")), BindingKind::Variable);
+            },
+            r#"
+              (for_in_statement
+                kind: _
+              ) @c
+            "# => |node, context| {
+                self.push_bindings(get_pattern_identifiers(node.field("left")), BindingKind::Variable);
+            },
+            r#"
+              (catch_clause
+                parameter: (_) @c
+              )
+            "# => |node, context| {
+                self.push_bindings(get_pattern_identifiers(node), BindingKind::Variable);
+            },
+            r#"
+              (formal_parameters) @c
+            "# => |node, context| {
+                let kind = if is_signature(node.parent().unwrap()) {
+                    BindingKind::SignatureParameter
+                } else {
+                    BindingKind::Parameter
+                };
+                for parameter in node.non_comment_named_children(SupportedLanguage::Javascript) {
+                    if !matches!(parameter.kind(), RequiredParameter | OptionalParameter)
+                        || is_this_parameter(parameter)
+                    {
+                        continue;
+                    }
+                    self.push_bindings(get_pattern_identifiers(parameter.field("pattern")), kind);
+                }
+            },
+            r#"
+              (arrow_function
+                parameter: (_) @c
+              )
+            "# => |node, context| {
+                self.push_bindings([node], BindingKind::Parameter);
+            },
+            r#"
+              (function_declaration) @c
+              (generator_function_declaration) @c
+            "# => |node, context| {
+                self.push_bindings([node.field("name")], BindingKind::Function);
+            },
+            r#"
+              (class_declaration) @c
+              (abstract_class_declaration) @c
+            "# => |node, context| {
+                self.push_bindings([node.field("name")], BindingKind::Class);
+            },
+            r#"
+              (enum_declaration) @c
+            "# => |node, context| {
+                self.push_bindings([node.field("name")], BindingKind::Enum);
+            },
+            r#"
+              (interface_declaration) @c
+              (type_alias_declaration) @c
+            "# => |node, context| {
+                self.push_bindings([node.field("name")], BindingKind::Type);
+            },
+            r#"
+              (type_parameter) @c
+            "# => |node, context| {
+                self.push_bindings([node.field("name")], BindingKind::TypeParameter);
+            },
+            r#"
+              (import_clause) @c
+            "# => |node, context| {
+                let is_type_only_statement = node.parent().unwrap().has_child_of_kind("type");
+                for (local, _) in get_import_bindings(node, context) {
+                    let is_type_only = is_type_only_statement
+                        || local.ancestors().find(|ancestor| ancestor.kind() == ImportSpecifier).is_some_and(|specifier| specifier.has_child_of_kind("type"));
+                    self.push_bindings(
+                        [local],
+                        if is_type_only { BindingKind::TypeImport } else { BindingKind::Import },
+                    );
+                }
+            },
+            r#"
+              program:exit
+            "# => |node, context| {
+                for inner in &self.bindings {
+                    let name = inner.name.text(context);
+                    if self.allow.contains(&*name) {
+                        continue;
+                    }
+                    // the innermost of the enclosing scopes declaring the
+                    // same name
+                    let shadowed = self
+                        .bindings
+                        .iter()
+                        .filter(|outer| inner.is_inside(outer) && outer.name.text(context) == name)
+                        .min_by_key(|outer| {
+                            (
+                                Reverse(outer.scope.start_byte()),
+                                outer.scope.end_byte(),
+                                outer.name.start_byte(),
+                            )
+                        });
+                    let Some(shadowed) = shadowed else {
+                        if self.builtin_globals {
+                            self.check_global(inner, context);
+                        }
+                        continue;
+                    };
+                    if !self.should_report(inner, shadowed) {
+                        continue;
+                    }
+
+                    let shadowed_position = shadowed.name.start_position();
+                    context.report(violation! {
+                        node => inner.name,
+                        message_id => "no_shadow",
+                        data => {
+                            name => name,
+                            shadowed_line => shadowed_position.row + 1,
+                            shadowed_column => shadowed_position.column + 1,
+                        },
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_shadow_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "var a = 3; function b(x) { a++; return x + a; }",
+              "function foo(a) { if (a) { var a; } }",
+              "function foo(a) { let b = a; }",
+              "try {} catch (e) {} try {} catch (e) {}",
+              "function foo() { let a; } let a;",
+              "function foo<T>(arg: T) {} function bar<T>(arg: T) {}",
+              "class Foo<T> { static method<T>(arg: T) {} }",
+              "class Foo<T> { static method<T>(arg: T) {} static other<U>(arg: U) {} }",
+              "const T = 1; function foo<T>(arg: T) {}",
+              "type Foo = 1; function bar(Foo: string) {}",
+              "const a = 1; type Fn = (a: string) => void;",
+              "const a = 1; interface Foo { method(a: string): void; }",
+              "function foo(this: Foo) {} const self = 1;",
+              {
+                code => "const done = 1; function foo(done) {}",
+                options => { allow => ["done"] },
+              },
+              {
+                code => "const x = foo(x => x);",
+                options => { ignore_on_initialization => true },
+              },
+              // top-level declarations in a script are the globals
+              {
+                code => "const Object = 1;",
+                options => { builtin_globals => true },
+              },
+              {
+                code => "function foo() { const Partial = 1; }",
+                options => { builtin_globals => true },
+              },
+              "function foo() { const Object = 1; }",
+            ],
+            invalid => [
+              {
+                code => "var a = 3; function b() { var a = 10; }",
+                errors => [
+                  {
+                    message_id => "no_shadow",
+                    data => { name => "a", shadowed_line => 1, shadowed_column => 5 },
+                    line => 1,
+                    column => 31,
+                  },
+                ],
+              },
+              {
+                code => "function foo(a) { { let a; } }",
+                errors => [{ message_id => "no_shadow", column => 25 }],
+              },
+              {
+                code => "const foo = 1; const bar = (foo) => foo;",
+                errors => [{ message_id => "no_shadow", column => 29 }],
+              },
+              {
+                code => "function foo() { let a; } function a() {}",
+                errors => [
+                  {
+                    message_id => "no_shadow",
+                    data => { name => "a", shadowed_line => 1, shadowed_column => 36 },
+                    column => 22,
+                  },
+                ],
+              },
+              {
+                code => "function foo() { let a; } let a;",
+                options => { hoist => "all" },
+                errors => [{ message_id => "no_shadow", column => 22 }],
+              },
+              {
+                code => r#"
+class Foo<T> {
+  method<T>(arg: T) {}
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "no_shadow",
+                    data => { name => "T", shadowed_line => 2, shadowed_column => 11 },
+                    line => 3,
+                    column => 10,
+                  },
+                ],
+              },
+              {
+                code => r#"
+class Foo<T> {
+  static method<T>(arg: T) {}
+  get<T>(arg: T) {}
+}
+                    "#,
+                errors => [{ message_id => "no_shadow", line => 4, column => 7 }],
+              },
+              {
+                code => "function foo<T>() { return function bar<T>() {}; }",
+                errors => [{ message_id => "no_shadow", column => 41 }],
+              },
+              {
+                code => "type T = 1; function foo<T>(arg: T) {}",
+                errors => [{ message_id => "no_shadow", column => 26 }],
+              },
+              {
+                code => "const T = 1; function foo<T>(arg: T) {}",
+                options => { ignore_type_value_shadow => false },
+                errors => [{ message_id => "no_shadow", column => 27 }],
+              },
+              {
+                code => "const a = 1; type Fn = (a: string) => void;",
+                options => { ignore_function_type_parameter_name_value_shadow => false },
+                errors => [{ message_id => "no_shadow", column => 25 }],
+              },
+              {
+                code => "const x = foo(x => x);",
+                errors => [{ message_id => "no_shadow", column => 15 }],
+              },
+              {
+                code => "import { foo } from 'foo'; function bar(foo) {}",
+                errors => [{ message_id => "no_shadow", column => 41 }],
+              },
+              {
+                code => "function foo() { const Object = 1; }",
+                options => { builtin_globals => true },
+                errors => [
+                  {
+                    message_id => "no_shadow_global",
+                    data => { name => "Object" },
+                    line => 1,
+                    column => 24,
+                  },
+                ],
+              },
+              {
+                code => "export const Promise = 1;",
+                options => { builtin_globals => true },
+                errors => [{ message_id => "no_shadow_global", column => 14 }],
+              },
+              {
+                code => "function foo<Record>(arg: Record) {}",
+                options => { builtin_globals => true },
+                errors => [{ message_id => "no_shadow_global", column => 14 }],
+              },
+              {
+                code => "function foo() { const Partial = 1; }",
+                options => { builtin_globals => true, ignore_type_value_shadow => false },
+                errors => [{ message_id => "no_shadow_global", column => 24 }],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx_with_provided_types;
+
+    #[test]
+    fn test_no_shadow_rule() {
+        run_for_typescript_and_tsx_with_provided_types(
+            no_shadow_rule(),
+            test_cases::no_shadow_rule_tests(),
+        );
+    }
+}
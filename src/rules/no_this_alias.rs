@@ -0,0 +1,182 @@
+use std::{collections::HashSet, sync::Arc};
+
+use serde::Deserialize;
+use tree_sitter_lint::{rule, violation, NodeExt, Rule};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    ArrayPattern, AssignmentExpression, Identifier, ObjectPattern, This,
+};
+
+use crate::util::skip_parenthesized_expressions;
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    allow_destructuring: Option<bool>,
+    allowed_names: Option<Vec<String>>,
+}
+
+impl Options {
+    fn allow_destructuring(&self) -> bool {
+        self.allow_destructuring.unwrap_or(true)
+    }
+
+    fn allowed_names(&self) -> HashSet<String> {
+        self.allowed_names.iter().flatten().cloned().collect()
+    }
+}
+
+pub fn no_this_alias_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-this-alias",
+        languages => [Typescript],
+        messages => [
+            this_assignment => "Unexpected aliasing of 'this' to local variable.",
+            this_destructure => "Unexpected aliasing of members of 'this' to local variables.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            allow_destructuring: bool = options.allow_destructuring(),
+            allowed_names: HashSet<String> = options.allowed_names(),
+        },
+        listeners => [
+            r#"
+              (variable_declarator
+                value: (_)
+              ) @c
+              (assignment_expression) @c
+            "# => |node, context| {
+                let (id, value) = match node.kind() {
+                    AssignmentExpression => (node.field("left"), node.field("right")),
+                    _ => (node.field("name"), node.field("value")),
+                };
+                if skip_parenthesized_expressions(value).kind() != This {
+                    return;
+                }
+                let id = skip_parenthesized_expressions(id);
+                let message_id = match id.kind() {
+                    Identifier => {
+                        if self.allowed_names.contains(&*id.text(context)) {
+                            return;
+                        }
+                        "this_assignment"
+                    }
+                    ObjectPattern | ArrayPattern => {
+                        if self.allow_destructuring {
+                            return;
+                        }
+                        "this_destructure"
+                    }
+                    // eg `this.self = this`, which isn't a local variable
+                    _ => return,
+                };
+
+                context.report(violation! {
+                    node => id,
+                    message_id => message_id,
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_this_alias_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "const self = foo(this);",
+                r#"
+const { props, state } = this;
+const { length } = this;
+const { length, toString } = this;
+const [foo] = this;
+const [foo, bar] = this;
+                    "#,
+                {
+                  code => "const self = this;",
+                  options => { allowed_names => ["self"] },
+                },
+                r#"
+declare module 'foo' {
+  declare const aVar: string;
+}
+                    "#,
+                "this.self = this;",
+                "const foo = bar;",
+            ],
+            invalid => [
+                {
+                  code => "const self = this;",
+                  errors => [
+                    {
+                      message_id => "this_assignment",
+                      line => 1,
+                      column => 7,
+                      end_column => 11,
+                    },
+                  ],
+                },
+                {
+                  code => "const self = (this);",
+                  errors => [{ message_id => "this_assignment" }],
+                },
+                {
+                  code => r#"
+let that;
+that = this;
+                      "#,
+                  errors => [{ message_id => "this_assignment", line => 3, column => 1 }],
+                },
+                {
+                  code => "const { props, state } = this;",
+                  options => { allow_destructuring => false },
+                  errors => [{ message_id => "this_destructure", line => 1, column => 7 }],
+                },
+                {
+                  code => "const [foo] = this;",
+                  options => { allow_destructuring => false },
+                  errors => [{ message_id => "this_destructure" }],
+                },
+                {
+                  code => r#"
+let foo;
+({ foo } = this);
+                      "#,
+                  options => { allow_destructuring => false },
+                  errors => [{ message_id => "this_destructure", line => 3, column => 2 }],
+                },
+                {
+                  code => "const that = this;",
+                  options => { allowed_names => ["self"] },
+                  errors => [{ message_id => "this_assignment" }],
+                },
+                {
+                  code => r#"
+class TestClass {
+  methodName() {
+    const self = this;
+    const that = this;
+  }
+}
+                      "#,
+                  options => { allowed_names => ["self"] },
+                  errors => [{ message_id => "this_assignment", line => 5, column => 11 }],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_this_alias_rule() {
+        run_for_typescript_and_tsx(no_this_alias_rule(), test_cases::no_this_alias_rule_tests());
+    }
+}
@@ -0,0 +1,556 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+
+use crate::{
+    ast_helpers::{is_mapped_type, NodeExtTypescript},
+    kind::{
+        ArrayType, ConditionalType, ConstructorType, FunctionType, GenericType, IndexTypeQuery,
+        IntersectionType, LiteralType, LookupType, NestedTypeIdentifier, ObjectType,
+        PredefinedType, ReadonlyType, TemplateLiteralType, TupleType, TypeIdentifier, TypeQuery,
+        UnionType,
+    },
+};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Values {
+    Always,
+    #[default]
+    Never,
+    InUnions,
+    InIntersections,
+    InUnionsAndIntersections,
+}
+
+impl Values {
+    fn is_composition(self) -> bool {
+        matches!(
+            self,
+            Self::InUnions | Self::InIntersections | Self::InUnionsAndIntersections
+        )
+    }
+
+    fn allows_unions(self) -> bool {
+        matches!(
+            self,
+            Self::Always | Self::InUnions | Self::InUnionsAndIntersections
+        )
+    }
+
+    fn allows_intersections(self) -> bool {
+        matches!(
+            self,
+            Self::Always | Self::InIntersections | Self::InUnionsAndIntersections
+        )
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum AlwaysOrNever {
+    Always,
+    #[default]
+    Never,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    allow_aliases: Option<Values>,
+    allow_callbacks: Option<AlwaysOrNever>,
+    allow_conditional_types: Option<AlwaysOrNever>,
+    allow_constructors: Option<AlwaysOrNever>,
+    allow_literals: Option<Values>,
+    allow_mapped_types: Option<Values>,
+    allow_tuple_types: Option<Values>,
+    allow_generics: Option<AlwaysOrNever>,
+}
+
+impl Options {
+    fn allow_aliases(&self) -> Values {
+        self.allow_aliases.unwrap_or_default()
+    }
+
+    fn allow_callbacks(&self) -> AlwaysOrNever {
+        self.allow_callbacks.unwrap_or_default()
+    }
+
+    fn allow_conditional_types(&self) -> AlwaysOrNever {
+        self.allow_conditional_types.unwrap_or_default()
+    }
+
+    fn allow_constructors(&self) -> AlwaysOrNever {
+        self.allow_constructors.unwrap_or_default()
+    }
+
+    fn allow_literals(&self) -> Values {
+        self.allow_literals.unwrap_or_default()
+    }
+
+    fn allow_mapped_types(&self) -> Values {
+        self.allow_mapped_types.unwrap_or_default()
+    }
+
+    fn allow_tuple_types(&self) -> Values {
+        self.allow_tuple_types.unwrap_or_default()
+    }
+
+    fn allow_generics(&self) -> AlwaysOrNever {
+        self.allow_generics.unwrap_or_default()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum CompositionType {
+    Union,
+    Intersection,
+}
+
+impl CompositionType {
+    fn from_kind(kind: &str) -> Option<Self> {
+        match kind {
+            UnionType => Some(Self::Union),
+            IntersectionType => Some(Self::Intersection),
+            _ => None,
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::Union => "union types",
+            Self::Intersection => "intersection types",
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct TypeWithComposition<'a> {
+    node: Node<'a>,
+    composition_type: Option<CompositionType>,
+}
+
+/// The members of a (possibly nested) union or intersection, or else just
+/// the type itself.
+fn get_types(node: Node, composition_type: Option<CompositionType>) -> Vec<TypeWithComposition> {
+    let node = node.skip_parenthesized_types();
+    match CompositionType::from_kind(node.kind()) {
+        Some(node_composition_type) => node
+            .non_comment_named_children(SupportedLanguage::Javascript)
+            .flat_map(|member| get_types(member, Some(node_composition_type)))
+            .collect(),
+        None => vec![TypeWithComposition {
+            node,
+            composition_type,
+        }],
+    }
+}
+
+fn is_supported_composition(
+    is_top_level: bool,
+    composition_type: Option<CompositionType>,
+    allowed: Values,
+) -> bool {
+    !allowed.is_composition()
+        || !is_top_level
+            && match composition_type {
+                Some(CompositionType::Union) => allowed.allows_unions(),
+                Some(CompositionType::Intersection) => allowed.allows_intersections(),
+                None => false,
+            }
+}
+
+fn is_alias_type(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        PredefinedType
+            | TypeIdentifier
+            | NestedTypeIdentifier
+            | LiteralType
+            | ArrayType
+            | TypeQuery
+            | LookupType
+            | TemplateLiteralType
+    )
+}
+
+/// `[string, number]`, `readonly [string]` or `keyof [string]`.
+fn is_tuple_type(node: Node) -> bool {
+    match node.kind() {
+        TupleType => true,
+        ReadonlyType | IndexTypeQuery => {
+            node.first_non_comment_named_child(SupportedLanguage::Javascript)
+                .skip_parenthesized_types()
+                .kind()
+                == TupleType
+        }
+        _ => false,
+    }
+}
+
+pub fn no_type_alias_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-type-alias",
+        languages => [Typescript],
+        messages => [
+            no_type_alias => "Type {{alias}} are not allowed.",
+            no_composition_alias => "{{type_name}} types are not allowed in {{composition_type}}.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            allow_aliases: Values = options.allow_aliases(),
+            allow_callbacks: AlwaysOrNever = options.allow_callbacks(),
+            allow_conditional_types: AlwaysOrNever = options.allow_conditional_types(),
+            allow_constructors: AlwaysOrNever = options.allow_constructors(),
+            allow_literals: Values = options.allow_literals(),
+            allow_mapped_types: Values = options.allow_mapped_types(),
+            allow_tuple_types: Values = options.allow_tuple_types(),
+            allow_generics: AlwaysOrNever = options.allow_generics(),
+        },
+        methods => {
+            fn report(
+                &self,
+                type_: TypeWithComposition<'a>,
+                is_top_level: bool,
+                label: &str,
+                context: &QueryMatchContext<'a, '_>,
+            ) {
+                if is_top_level {
+                    context.report(violation! {
+                        node => type_.node,
+                        message_id => "no_type_alias",
+                        data => {
+                            alias => label.to_lowercase(),
+                        },
+                    });
+                } else {
+                    context.report(violation! {
+                        node => type_.node,
+                        message_id => "no_composition_alias",
+                        data => {
+                            type_name => label,
+                            composition_type => type_.composition_type.unwrap().description(),
+                        },
+                    });
+                }
+            }
+
+            fn check_always_or_never(
+                &self,
+                option: AlwaysOrNever,
+                type_: TypeWithComposition<'a>,
+                is_top_level: bool,
+                label: &str,
+                context: &QueryMatchContext<'a, '_>,
+            ) {
+                if option == AlwaysOrNever::Never {
+                    self.report(type_, is_top_level, label, context);
+                }
+            }
+
+            fn check_values(
+                &self,
+                option: Values,
+                type_: TypeWithComposition<'a>,
+                is_top_level: bool,
+                label: &str,
+                context: &QueryMatchContext<'a, '_>,
+            ) {
+                if option == Values::Never
+                    || !is_supported_composition(is_top_level, type_.composition_type, option)
+                {
+                    self.report(type_, is_top_level, label, context);
+                }
+            }
+
+            fn validate(
+                &self,
+                type_: TypeWithComposition<'a>,
+                is_top_level: bool,
+                context: &QueryMatchContext<'a, '_>,
+            ) {
+                let node = type_.node;
+                match node.kind() {
+                    FunctionType => {
+                        self.check_always_or_never(self.allow_callbacks, type_, is_top_level, "Callbacks", context);
+                    }
+                    ConditionalType => {
+                        self.check_always_or_never(self.allow_conditional_types, type_, is_top_level, "Conditional types", context);
+                    }
+                    ConstructorType => {
+                        self.check_always_or_never(self.allow_constructors, type_, is_top_level, "Constructors", context);
+                    }
+                    ObjectType if is_mapped_type(node) => {
+                        self.check_values(self.allow_mapped_types, type_, is_top_level, "Mapped types", context);
+                    }
+                    ObjectType => {
+                        self.check_values(self.allow_literals, type_, is_top_level, "Literals", context);
+                    }
+                    _ if is_tuple_type(node) => {
+                        self.check_values(self.allow_tuple_types, type_, is_top_level, "Tuple Types", context);
+                    }
+                    GenericType => {
+                        self.check_always_or_never(self.allow_generics, type_, is_top_level, "Generics", context);
+                    }
+                    IndexTypeQuery => {
+                        self.check_values(self.allow_aliases, type_, is_top_level, "Aliases", context);
+                    }
+                    ReadonlyType
+                        if is_alias_type(
+                            node.first_non_comment_named_child(SupportedLanguage::Javascript)
+                                .skip_parenthesized_types(),
+                        ) =>
+                    {
+                        self.check_values(self.allow_aliases, type_, is_top_level, "Aliases", context);
+                    }
+                    _ if is_alias_type(node) => {
+                        self.check_values(self.allow_aliases, type_, is_top_level, "Aliases", context);
+                    }
+                    // eg `this`, which upstream doesn't handle either
+                    _ => self.report(type_, is_top_level, "Unhandled", context),
+                }
+            }
+        },
+        listeners => [
+            r#"
+              (type_alias_declaration) @c
+            "# => |node, context| {
+                let types = get_types(node.field("value"), None);
+                if let [type_] = &types[..] {
+                    self.validate(*type_, true, context);
+                } else {
+                    for type_ in types {
+                        self.validate(type_, false, context);
+                    }
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_type_alias_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                {
+                  code => "type A = string;",
+                  options => { allow_aliases => "always" },
+                },
+                {
+                  code => "type Foo = 'a';",
+                  options => { allow_aliases => "always" },
+                },
+                {
+                  code => "type Foo = 'a' | 'b';",
+                  options => { allow_aliases => "in-unions" },
+                },
+                {
+                  code => "type Foo = string & number;",
+                  options => { allow_aliases => "in-intersections" },
+                },
+                {
+                  code => "type Foo = (string | number) & boolean;",
+                  options => { allow_aliases => "in-unions-and-intersections" },
+                },
+                {
+                  code => "type Foo = keyof typeof bar;",
+                  options => { allow_aliases => "always" },
+                },
+                {
+                  code => "type Foo = readonly string[];",
+                  options => { allow_aliases => "always" },
+                },
+                {
+                  code => "type Foo = Bar['baz'];",
+                  options => { allow_aliases => "always" },
+                },
+                {
+                  code => "type Foo = `a-${string}`;",
+                  options => { allow_aliases => "always" },
+                },
+                {
+                  code => "type Foo = () => void;",
+                  options => { allow_callbacks => "always" },
+                },
+                {
+                  code => "type Foo<T> = T extends string ? 'a' : 'b';",
+                  options => { allow_conditional_types => "always" },
+                },
+                {
+                  code => "type Foo = new () => void;",
+                  options => { allow_constructors => "always" },
+                },
+                {
+                  code => "type Foo = { name: string };",
+                  options => { allow_literals => "always" },
+                },
+                {
+                  code => "type Foo = { name: string } | { age: number };",
+                  options => { allow_literals => "in-unions" },
+                },
+                {
+                  code => "type Foo<T> = { readonly [P in keyof T]: T[P] };",
+                  options => { allow_mapped_types => "always" },
+                },
+                {
+                  code => "type Foo = [number, string];",
+                  options => { allow_tuple_types => "always" },
+                },
+                {
+                  code => "type Foo = readonly [number] | keyof [string];",
+                  options => { allow_tuple_types => "in-unions" },
+                },
+                {
+                  code => "type Foo = Record<string, number>;",
+                  options => { allow_generics => "always" },
+                },
+            ],
+            invalid => [
+                {
+                  code => "type Foo = 'a';",
+                  errors => [
+                    {
+                      message_id => "no_type_alias",
+                      data => { alias => "aliases" },
+                      line => 1,
+                      column => 12,
+                    },
+                  ],
+                },
+                {
+                  code => "type Foo = 'a' | 'b';",
+                  errors => [
+                    {
+                      message_id => "no_composition_alias",
+                      data => { type_name => "Aliases", composition_type => "union types" },
+                      line => 1,
+                      column => 12,
+                    },
+                    {
+                      message_id => "no_composition_alias",
+                      data => { type_name => "Aliases", composition_type => "union types" },
+                      line => 1,
+                      column => 18,
+                    },
+                  ],
+                },
+                {
+                  code => "type Foo = 'a' | 'b';",
+                  options => { allow_aliases => "in-intersections" },
+                  errors => [
+                    { message_id => "no_composition_alias", column => 12 },
+                    { message_id => "no_composition_alias", column => 18 },
+                  ],
+                },
+                {
+                  code => "type Foo = string;",
+                  options => { allow_aliases => "in-unions-and-intersections" },
+                  errors => [{ message_id => "no_type_alias", data => { alias => "aliases" } }],
+                },
+                {
+                  code => "type Foo = string | (number & boolean);",
+                  options => { allow_aliases => "in-unions" },
+                  errors => [
+                    {
+                      message_id => "no_composition_alias",
+                      data => { type_name => "Aliases", composition_type => "intersection types" },
+                      column => 22,
+                    },
+                    {
+                      message_id => "no_composition_alias",
+                      data => { type_name => "Aliases", composition_type => "intersection types" },
+                      column => 31,
+                    },
+                  ],
+                },
+                {
+                  code => "type Foo = () => void;",
+                  errors => [{ message_id => "no_type_alias", data => { alias => "callbacks" } }],
+                },
+                {
+                  code => "type Foo = string | (() => void);",
+                  options => { allow_aliases => "in-unions" },
+                  errors => [
+                    {
+                      message_id => "no_composition_alias",
+                      data => { type_name => "Callbacks", composition_type => "union types" },
+                      column => 22,
+                    },
+                  ],
+                },
+                {
+                  code => "type Foo<T> = T extends string ? 'a' : 'b';",
+                  errors => [{ message_id => "no_type_alias", data => { alias => "conditional types" } }],
+                },
+                {
+                  code => "type Foo = new () => void;",
+                  errors => [{ message_id => "no_type_alias", data => { alias => "constructors" } }],
+                },
+                {
+                  code => "type Foo = { name: string };",
+                  errors => [{ message_id => "no_type_alias", data => { alias => "literals" } }],
+                },
+                {
+                  code => "type Foo = { name: string };",
+                  options => { allow_literals => "in-unions" },
+                  errors => [{ message_id => "no_type_alias", data => { alias => "literals" } }],
+                },
+                {
+                  code => "type Foo<T> = { readonly [P in keyof T]: T[P] };",
+                  errors => [{ message_id => "no_type_alias", data => { alias => "mapped types" } }],
+                },
+                {
+                  code => "type Foo = [number, string];",
+                  errors => [{ message_id => "no_type_alias", data => { alias => "tuple types" } }],
+                },
+                {
+                  code => "type Foo = readonly [number];",
+                  errors => [{ message_id => "no_type_alias", data => { alias => "tuple types" } }],
+                },
+                {
+                  code => "type Foo = [number] & [string];",
+                  options => { allow_tuple_types => "in-unions" },
+                  errors => [
+                    {
+                      message_id => "no_composition_alias",
+                      data => { type_name => "Tuple Types", composition_type => "intersection types" },
+                      column => 12,
+                    },
+                    { message_id => "no_composition_alias", column => 23 },
+                  ],
+                },
+                {
+                  code => "type Foo = Record<string, number>;",
+                  errors => [{ message_id => "no_type_alias", data => { alias => "generics" } }],
+                },
+                {
+                  code => "type Foo = keyof Bar;",
+                  errors => [{ message_id => "no_type_alias", data => { alias => "aliases" } }],
+                },
+                {
+                  code => "type Foo = this;",
+                  errors => [{ message_id => "no_type_alias", data => { alias => "unhandled" } }],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_type_alias_rule() {
+        run_for_typescript_and_tsx(no_type_alias_rule(), test_cases::no_type_alias_rule_tests());
+    }
+}
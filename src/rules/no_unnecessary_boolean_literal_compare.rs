@@ -0,0 +1,493 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    is_literal_kind, Array, BinaryExpression, CallExpression, False, Identifier, MemberExpression,
+    NewExpression, Object, ParenthesizedExpression, SubscriptExpression, This, True,
+    UnaryExpression,
+};
+
+use crate::{
+    ast_helpers::unwrap_type_assertion,
+    kind::{NonNullExpression, PredefinedType},
+    type_utils::{
+        collect_union_members, get_declared_type_members, is_literal_type, is_nullish_member,
+    },
+    util::skip_parenthesized_expressions,
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    allow_comparing_nullable_booleans_to_true: Option<bool>,
+    allow_comparing_nullable_booleans_to_false: Option<bool>,
+}
+
+impl Options {
+    fn allow_comparing_nullable_booleans_to_true(&self) -> bool {
+        self.allow_comparing_nullable_booleans_to_true
+            .unwrap_or(true)
+    }
+
+    fn allow_comparing_nullable_booleans_to_false(&self) -> bool {
+        self.allow_comparing_nullable_booleans_to_false
+            .unwrap_or(true)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum BooleanKind {
+    Boolean,
+    NullableBoolean,
+}
+
+fn classify_members<'a>(
+    members: &[Option<Node<'a>>],
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<BooleanKind> {
+    let mut has_boolean = false;
+    let mut has_nullish = false;
+    for &member in members {
+        if is_nullish_member(member, context) {
+            has_nullish = true;
+            continue;
+        }
+        let member = member.unwrap();
+        let is_boolean = (member.kind() == PredefinedType && member.text(context) == "boolean")
+            || is_literal_type(member, "true", context)
+            || is_literal_type(member, "false", context);
+        if !is_boolean {
+            return None;
+        }
+        has_boolean = true;
+    }
+    has_boolean.then_some(if has_nullish {
+        BooleanKind::NullableBoolean
+    } else {
+        BooleanKind::Boolean
+    })
+}
+
+/// Whether the expression is known to be a boolean (possibly `null` or
+/// `undefined` too), as far as can be told from the source.
+fn get_boolean_kind<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<BooleanKind> {
+    let node = skip_parenthesized_expressions(node);
+    match node.kind() {
+        True | False => Some(BooleanKind::Boolean),
+        UnaryExpression => (node.field("operator").kind() == "!").then_some(BooleanKind::Boolean),
+        BinaryExpression => matches!(
+            node.field("operator").kind(),
+            "<" | ">" | "<=" | ">=" | "==" | "===" | "!=" | "!==" | "instanceof" | "in"
+        )
+        .then_some(BooleanKind::Boolean),
+        CallExpression => {
+            let function = node.field("function");
+            (function.kind() == Identifier && function.text(context) == "Boolean")
+                .then_some(BooleanKind::Boolean)
+        }
+        Identifier => classify_members(&get_declared_type_members(node, context)?, context),
+        _ => {
+            let type_ = unwrap_type_assertion(node, context)?.type_?;
+            let mut members = vec![];
+            collect_union_members(type_, &mut members);
+            classify_members(&members, context)
+        }
+    }
+}
+
+/// Whether the expression can be negated with a leading `!` without
+/// parenthesizing it.
+fn is_strong_precedence_node(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        Identifier
+            | MemberExpression
+            | SubscriptExpression
+            | CallExpression
+            | NewExpression
+            | NonNullExpression
+            | ParenthesizedExpression
+            | Array
+            | Object
+            | This
+    ) || is_literal_kind(node.kind())
+}
+
+fn get_boolean_literal(node: Node) -> Option<bool> {
+    match skip_parenthesized_expressions(node).kind() {
+        True => Some(true),
+        False => Some(false),
+        _ => None,
+    }
+}
+
+pub fn no_unnecessary_boolean_literal_compare_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-unnecessary-boolean-literal-compare",
+        languages => [Typescript],
+        messages => [
+            direct => "This expression unnecessarily compares a boolean value to a boolean instead of using it directly.",
+            negated => "This expression unnecessarily compares a boolean value to a boolean instead of negating it.",
+            comparing_nullable_to_true_direct => "This expression unnecessarily compares a nullable boolean value to true instead of using it directly.",
+            comparing_nullable_to_true_negated => "This expression unnecessarily compares a nullable boolean value to true instead of negating it.",
+            comparing_nullable_to_false => "This expression unnecessarily compares a nullable boolean value to false instead of using the ?? operator to provide a default.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            allow_comparing_nullable_booleans_to_true: bool = options.allow_comparing_nullable_booleans_to_true(),
+            allow_comparing_nullable_booleans_to_false: bool = options.allow_comparing_nullable_booleans_to_false(),
+        },
+        listeners => [
+            r#"
+              (binary_expression
+                operator: ["==" "===" "!=" "!=="]
+              ) @c
+            "# => |node, context| {
+                let operator = node.field("operator").kind();
+                let left = node.field("left");
+                let right = node.field("right");
+                let (expression, literal) = match (get_boolean_literal(left), get_boolean_literal(right)) {
+                    (_, Some(literal)) => (left, literal),
+                    (Some(literal), None) => (right, literal),
+                    (None, None) => return,
+                };
+                let Some(boolean_kind) = get_boolean_kind(expression, context) else {
+                    return;
+                };
+                let negated = operator.starts_with('!');
+
+                let is_nullable = boolean_kind == BooleanKind::NullableBoolean;
+                if is_nullable {
+                    if literal && self.allow_comparing_nullable_booleans_to_true {
+                        return;
+                    }
+                    if !literal && self.allow_comparing_nullable_booleans_to_false {
+                        return;
+                    }
+                }
+
+                let message_id = match (is_nullable, literal, negated) {
+                    (true, true, true) => "comparing_nullable_to_true_negated",
+                    (true, true, false) => "comparing_nullable_to_true_direct",
+                    (true, false, _) => "comparing_nullable_to_false",
+                    (false, _, true) => "negated",
+                    (false, _, false) => "direct",
+                };
+
+                context.report(violation! {
+                    node => node,
+                    message_id => message_id,
+                    fix => |fixer| {
+                        // `!(x === true)` gets folded into a single negation
+                        let unary_negation_parent = node
+                            .parent()
+                            .filter(|parent| {
+                                parent.kind() == ParenthesizedExpression
+                            })
+                            .and_then(|parent| parent.parent())
+                            .filter(|grandparent| {
+                                grandparent.kind() == UnaryExpression
+                                    && grandparent.field("operator").kind() == "!"
+                            });
+                        let mutated_node = unary_negation_parent.unwrap_or(node);
+                        let needs_negation =
+                            (negated != literal) == unary_negation_parent.is_some();
+
+                        let mut replacement = expression.text(context).into_owned();
+                        if needs_negation && !is_strong_precedence_node(expression) {
+                            replacement = format!("({replacement})");
+                        }
+                        if is_nullable && !literal {
+                            replacement = format!("({replacement} ?? true)");
+                        }
+                        if needs_negation {
+                            replacement = format!("!{replacement}");
+                        }
+                        fixer.replace_text(mutated_node, replacement);
+                    },
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_unnecessary_boolean_literal_compare_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "
+declare const varAny: any;
+varAny === true;
+                    ",
+                "
+declare const varAny: any;
+varAny == false;
+                    ",
+                "
+declare const varString: string;
+varString === false;
+                    ",
+                "
+declare const varString: string;
+varString === true;
+                    ",
+                "
+declare const varObject: {};
+varObject === true;
+                    ",
+                "
+declare const varBooleanOrString: boolean | string;
+varBooleanOrString === false;
+                    ",
+                "
+declare const varBooleanOrUndefined: boolean | undefined;
+varBooleanOrUndefined === true;
+                    ",
+                "
+declare const varBooleanOrUndefined: boolean | undefined;
+varBooleanOrUndefined === false;
+                    ",
+                {
+                  code => "
+declare const varBooleanOrUndefined: boolean | undefined;
+varBooleanOrUndefined === true;
+                      ",
+                  options => { allow_comparing_nullable_booleans_to_false => false },
+                },
+                {
+                  code => "
+declare const varBooleanOrUndefined: boolean | undefined;
+varBooleanOrUndefined === false;
+                      ",
+                  options => { allow_comparing_nullable_booleans_to_true => false },
+                },
+                "
+declare const varNullOrUndefined: null | undefined;
+varNullOrUndefined === false;
+                    ",
+                "'false' === true;",
+                "
+function test(a?: string) {
+  a === true;
+}
+                    ",
+            ],
+            invalid => [
+                {
+                  code => "true === true",
+                  errors => [{ message_id => "direct" }],
+                  output => "true",
+                },
+                {
+                  code => "false !== true",
+                  errors => [{ message_id => "negated" }],
+                  output => "!false",
+                },
+                {
+                  code => "
+declare const varBoolean: boolean;
+if (varBoolean !== false) {
+}
+                      ",
+                  errors => [{ message_id => "negated", line => 3, column => 5 }],
+                  output => "
+declare const varBoolean: boolean;
+if (varBoolean) {
+}
+                      ",
+                },
+                {
+                  code => "
+declare const varTrue: true;
+if (varTrue !== true) {
+}
+                      ",
+                  errors => [{ message_id => "negated" }],
+                  output => "
+declare const varTrue: true;
+if (!varTrue) {
+}
+                      ",
+                },
+                {
+                  code => "
+declare const varBoolean: boolean;
+if (false == varBoolean) {
+}
+                      ",
+                  errors => [{ message_id => "direct" }],
+                  output => "
+declare const varBoolean: boolean;
+if (!varBoolean) {
+}
+                      ",
+                },
+                {
+                  code => "
+declare const x: number;
+declare const y: number;
+if ((x > y) === true) {
+}
+                      ",
+                  errors => [{ message_id => "direct" }],
+                  output => "
+declare const x: number;
+declare const y: number;
+if ((x > y)) {
+}
+                      ",
+                },
+                {
+                  code => "
+declare const x: number;
+declare const y: number;
+if (x > y === false) {
+}
+                      ",
+                  errors => [{ message_id => "direct" }],
+                  output => "
+declare const x: number;
+declare const y: number;
+if (!(x > y)) {
+}
+                      ",
+                },
+                {
+                  code => "
+declare const varBoolean: boolean;
+if (!(varBoolean === false)) {
+}
+                      ",
+                  errors => [{ message_id => "direct" }],
+                  output => "
+declare const varBoolean: boolean;
+if (varBoolean) {
+}
+                      ",
+                },
+                {
+                  code => "
+declare const varBoolean: boolean;
+if (!(varBoolean !== false)) {
+}
+                      ",
+                  errors => [{ message_id => "negated" }],
+                  output => "
+declare const varBoolean: boolean;
+if (!varBoolean) {
+}
+                      ",
+                },
+                {
+                  code => "
+declare const varBooleanOrUndefined: boolean | undefined;
+if (varBooleanOrUndefined === true) {
+}
+                      ",
+                  options => { allow_comparing_nullable_booleans_to_true => false },
+                  errors => [{ message_id => "comparing_nullable_to_true_direct" }],
+                  output => "
+declare const varBooleanOrUndefined: boolean | undefined;
+if (varBooleanOrUndefined) {
+}
+                      ",
+                },
+                {
+                  code => "
+declare const varBooleanOrUndefined: boolean | undefined;
+if (varBooleanOrUndefined !== true) {
+}
+                      ",
+                  options => { allow_comparing_nullable_booleans_to_true => false },
+                  errors => [{ message_id => "comparing_nullable_to_true_negated" }],
+                  output => "
+declare const varBooleanOrUndefined: boolean | undefined;
+if (!varBooleanOrUndefined) {
+}
+                      ",
+                },
+                {
+                  code => "
+declare const varBooleanOrUndefined: boolean | undefined;
+if (varBooleanOrUndefined === false) {
+}
+                      ",
+                  options => { allow_comparing_nullable_booleans_to_false => false },
+                  errors => [{ message_id => "comparing_nullable_to_false" }],
+                  output => "
+declare const varBooleanOrUndefined: boolean | undefined;
+if (!(varBooleanOrUndefined ?? true)) {
+}
+                      ",
+                },
+                {
+                  code => "
+declare const varBooleanOrUndefined: boolean | null;
+if (varBooleanOrUndefined !== false) {
+}
+                      ",
+                  options => { allow_comparing_nullable_booleans_to_false => false },
+                  errors => [{ message_id => "comparing_nullable_to_false" }],
+                  output => "
+declare const varBooleanOrUndefined: boolean | null;
+if ((varBooleanOrUndefined ?? true)) {
+}
+                      ",
+                },
+                {
+                  code => "
+function test(a?: boolean) {
+  a === false;
+}
+                      ",
+                  options => { allow_comparing_nullable_booleans_to_false => false },
+                  errors => [{ message_id => "comparing_nullable_to_false", line => 3, column => 3 }],
+                  output => "
+function test(a?: boolean) {
+  !(a ?? true);
+}
+                      ",
+                },
+                {
+                  code => "
+declare const x: unknown;
+(x as boolean) === true;
+                      ",
+                  errors => [{ message_id => "direct" }],
+                  output => "
+declare const x: unknown;
+(x as boolean);
+                      ",
+                },
+                {
+                  code => "Boolean(foo) !== true;",
+                  errors => [{ message_id => "negated" }],
+                  output => "!Boolean(foo);",
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_unnecessary_boolean_literal_compare_rule() {
+        run_for_typescript_and_tsx(
+            no_unnecessary_boolean_literal_compare_rule(),
+            test_cases::no_unnecessary_boolean_literal_compare_rule_tests(),
+        );
+    }
+}
@@ -0,0 +1,395 @@
+use std::{collections::HashSet, sync::Arc};
+
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use tree_sitter_lint::{
+    rule,
+    tree_sitter::{Node, Query, QueryCursor},
+    tree_sitter_grep::SupportedLanguage,
+    violation, NodeExt, QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    is_literal_kind, Array, CallExpression, Identifier, LexicalDeclaration, MemberExpression,
+    ParenthesizedExpression, SubscriptExpression, This, VariableDeclarator,
+};
+
+use crate::{
+    ast_helpers::{unwrap_type_assertion, NodeExtTypescript},
+    kind::LiteralType,
+    type_declarations::TypeDeclarations,
+    type_utils::are_types_equivalent,
+    util::{get_declared_type, is_tsx_language, skip_parenthesized_expressions},
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    types_to_ignore: Option<Vec<String>>,
+}
+
+impl Options {
+    fn types_to_ignore(&self) -> HashSet<String> {
+        self.types_to_ignore.iter().flatten().cloned().collect()
+    }
+}
+
+/// The type the expression already has, as far as can be told from the
+/// source: a variable's declared type or the type asserted by a nested
+/// assertion.
+fn get_expression_type<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    let node = skip_parenthesized_expressions(node);
+    match node.kind() {
+        Identifier => get_declared_type(node, context),
+        _ => unwrap_type_assertion(node, context)?
+            .type_
+            .map(|type_| type_.skip_parenthesized_types()),
+    }
+}
+
+/// Eg `const x = 3 as 3;`, where the literal type is what would've been
+/// inferred anyway.
+fn is_redundant_const_literal_assertion<'a>(
+    node: Node<'a>,
+    expression: Node<'a>,
+    type_: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+    parent.kind() == VariableDeclarator
+        && parent.child_by_field_name("value") == Some(node)
+        && parent.parent().unwrap().kind() == LexicalDeclaration
+        && parent
+            .parent()
+            .unwrap()
+            .first_non_comment_child(SupportedLanguage::Javascript)
+            .kind()
+            == "const"
+        && is_literal_kind(expression.kind())
+        && type_.kind() == LiteralType
+        && expression.text(context) == type_.text(context)
+}
+
+static TYPE_ASSERTION_QUERY: Lazy<Query> = Lazy::new(|| {
+    Query::new(
+        SupportedLanguage::Typescript.language(None),
+        "(type_assertion) @c",
+    )
+    .unwrap()
+});
+
+/// Whether parentheses around the expression are redundant once the
+/// assertion they were grouping is gone, eg `(foo as Foo).bar`.
+fn is_primary_expression(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        Identifier | This | MemberExpression | SubscriptExpression | CallExpression | Array
+    ) || is_literal_kind(node.kind())
+}
+
+fn check_assertion<'a>(
+    node: Node<'a>,
+    types_to_ignore: &HashSet<String>,
+    context: &QueryMatchContext<'a, '_>,
+) {
+    let Some(assertion) = unwrap_type_assertion(node, context) else {
+        return;
+    };
+    let Some(type_) = assertion
+        .type_
+        .map(|type_| type_.skip_parenthesized_types())
+    else {
+        return;
+    };
+    if types_to_ignore.contains(&*type_.text(context)) {
+        return;
+    }
+    let expression = skip_parenthesized_expressions(assertion.expression);
+
+    let type_declarations = context.retrieve::<TypeDeclarations<'a>>();
+    let is_unnecessary =
+        get_expression_type(expression, context).is_some_and(|expression_type| {
+            are_types_equivalent(expression_type, type_, Some(type_declarations), context)
+        }) || is_redundant_const_literal_assertion(node, expression, type_, context);
+    if !is_unnecessary {
+        return;
+    }
+
+    context.report(violation! {
+        node => node,
+        message_id => "unnecessary_assertion",
+        fix => |fixer| {
+            let parent = node.parent().unwrap();
+            if parent.kind() != ParenthesizedExpression {
+                fixer.replace_text(node, assertion.expression.text(context));
+            } else if is_primary_expression(expression) {
+                fixer.replace_text(parent, expression.text(context));
+            } else if assertion.expression.kind() == ParenthesizedExpression {
+                // eg `((foo as Foo) as Foo)`, only one set of
+                // parens needs to survive
+                fixer.replace_text(parent, assertion.expression.text(context));
+            } else {
+                fixer.replace_text(node, assertion.expression.text(context));
+            }
+        },
+    });
+}
+
+pub fn no_unnecessary_type_assertion_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-unnecessary-type-assertion",
+        languages => [Typescript],
+        messages => [
+            unnecessary_assertion => "This assertion is unnecessary since it does not change the type of the expression.",
+            contextually_unnecessary => "This assertion is unnecessary since the receiver accepts the original type of the expression.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            types_to_ignore: HashSet<String> = options.types_to_ignore(),
+        },
+        listeners => [
+            r#"
+              (as_expression) @c
+            "# => |node, context| {
+                check_assertion(node, &self.types_to_ignore, context);
+            },
+            // the TSX grammar has no `type_assertion` (so a query mentioning
+            // it wouldn't compile for `.tsx` files), so angle-bracket
+            // assertions get queried for separately in TypeScript files
+            r#"
+              (program) @c
+            "# => |node, context| {
+                let language = context.file_run_context.tree.language();
+                if is_tsx_language(language) {
+                    return;
+                }
+                let mut cursor = QueryCursor::new();
+                for query_match in cursor.matches(&TYPE_ASSERTION_QUERY, node, "".as_bytes()) {
+                    for capture in query_match.captures {
+                        check_assertion(capture.node, &self.types_to_ignore, context);
+                    }
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_unnecessary_type_assertion_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "
+import { TSESTree } from '@typescript-eslint/utils';
+declare const member: TSESTree.TSEnumMember;
+if (
+  member.id.type === AST_NODE_TYPES.Literal &&
+  typeof member.id.value === 'string'
+) {
+  const name = member.id as TSESTree.StringLiteral;
+}
+                    ",
+                "
+const c = 1;
+let z = c as number;
+                    ",
+                "
+const c = 1;
+let z = c as const;
+                    ",
+                "
+const c = 1;
+let z = <const>c;
+                    ",
+                "let x = 1 as 1;",
+                "const foo = 3 as number;",
+                "const foo = <number>3;",
+                "
+declare const y: number | string;
+const x = y as number;
+                    ",
+                "
+declare const y: number | string;
+const x = <number>y;
+                    ",
+                "
+type Foo = number;
+declare const y: string;
+const x = y as Foo;
+                    ",
+                {
+                  code => "
+type Foo = 3;
+const foo = <Foo>3;
+                      ",
+                  options => { types_to_ignore => ["Foo"] },
+                },
+                {
+                  code => "
+type Foo = number;
+declare const y: Foo;
+const x = y as Foo;
+                      ",
+                  options => { types_to_ignore => ["Foo"] },
+                },
+                "
+declare const x: string;
+const y = (x as unknown) as number;
+                    ",
+                "
+declare const x: any;
+const y = x as string;
+                    ",
+            ],
+            invalid => [
+                {
+                  code => "const foo = 3 as 3;",
+                  output => "const foo = 3;",
+                  errors => [
+                    {
+                      message_id => "unnecessary_assertion",
+                      line => 1,
+                      column => 13,
+                      end_column => 19,
+                    },
+                  ],
+                },
+                {
+                  code => "const foo = <3>3;",
+                  output => "const foo = 3;",
+                  errors => [
+                    {
+                      message_id => "unnecessary_assertion",
+                      line => 1,
+                      column => 13,
+                      end_column => 17,
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare const foo: number;
+const bar = foo as number;
+                      ",
+                  output => "
+declare const foo: number;
+const bar = foo;
+                      ",
+                  errors => [{ message_id => "unnecessary_assertion", line => 3 }],
+                },
+                {
+                  code => "
+declare const foo: number;
+const bar = <number>foo;
+                      ",
+                  output => "
+declare const foo: number;
+const bar = foo;
+                      ",
+                  errors => [{ message_id => "unnecessary_assertion", line => 3 }],
+                },
+                {
+                  code => "
+type Foo = number;
+declare const foo: Foo;
+const bar = foo as number;
+                      ",
+                  output => "
+type Foo = number;
+declare const foo: Foo;
+const bar = foo;
+                      ",
+                  errors => [{ message_id => "unnecessary_assertion", line => 4 }],
+                },
+                {
+                  code => "
+declare const foo: { bar: string };
+const baz = (foo as { bar: string }).bar;
+                      ",
+                  output => "
+declare const foo: { bar: string };
+const baz = foo.bar;
+                      ",
+                  errors => [{ message_id => "unnecessary_assertion", line => 3 }],
+                },
+                {
+                  code => "
+declare const foo: { bar: string };
+const baz = (<{ bar: string }>foo).bar;
+                      ",
+                  output => "
+declare const foo: { bar: string };
+const baz = foo.bar;
+                      ",
+                  errors => [{ message_id => "unnecessary_assertion", line => 3 }],
+                },
+                {
+                  code => "
+declare const foo: number[];
+((foo) as number[]).push(1);
+                      ",
+                  output => "
+declare const foo: number[];
+foo.push(1);
+                      ",
+                  errors => [{ message_id => "unnecessary_assertion", line => 3 }],
+                },
+                {
+                  code => "
+declare const foo: string;
+const bar = ((foo as unknown) as unknown) as number;
+                      ",
+                  output => "
+declare const foo: string;
+const bar = (foo as unknown) as number;
+                      ",
+                  errors => [{ message_id => "unnecessary_assertion", line => 3 }],
+                },
+                {
+                  code => "
+declare const foo: string;
+const bar = <number>(<unknown>(<unknown>foo));
+                      ",
+                  output => "
+declare const foo: string;
+const bar = <number>(<unknown>foo);
+                      ",
+                  errors => [{ message_id => "unnecessary_assertion", line => 3 }],
+                },
+                {
+                  code => "
+declare const foo: string | undefined;
+const bar = foo as (string | undefined);
+                      ",
+                  output => "
+declare const foo: string | undefined;
+const bar = foo;
+                      ",
+                  errors => [{ message_id => "unnecessary_assertion", line => 3 }],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx_with_provided_types;
+
+    #[test]
+    fn test_no_unnecessary_type_assertion_rule() {
+        run_for_typescript_and_tsx_with_provided_types(
+            no_unnecessary_type_assertion_rule(),
+            test_cases::no_unnecessary_type_assertion_rule_tests(),
+        );
+    }
+}
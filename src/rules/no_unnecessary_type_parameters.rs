@@ -0,0 +1,397 @@
+use std::sync::Arc;
+
+use squalid::OptionExt;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+
+use crate::{
+    ast_helpers::get_signature_children,
+    kind::{
+        GenericType, IntersectionType, TypeArguments, TypeIdentifier, TypeParameters, UnionType,
+    },
+};
+
+#[derive(Default)]
+struct TypeParameterUsage {
+    count: usize,
+    is_type_argument: bool,
+}
+
+impl TypeParameterUsage {
+    fn is_repeated(&self) -> bool {
+        self.is_type_argument || self.count >= 2
+    }
+}
+
+fn declares_type_parameter<'a>(
+    node: Node<'a>,
+    name: &str,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    node.child_by_field_name("type_parameters")
+        .matches(|type_parameters| {
+            type_parameters
+                .non_comment_named_children(SupportedLanguage::Javascript)
+                .any(|type_parameter| type_parameter.field("name").text(context) == name)
+        })
+}
+
+fn is_type_argument<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    let mut parent = node.parent().unwrap();
+    while matches!(parent.kind(), UnionType | IntersectionType) {
+        parent = parent.parent().unwrap();
+    }
+    if parent.kind() != TypeArguments {
+        return false;
+    }
+
+    // being an element type of a mutable array doesn't require multiple
+    // types to be related
+    !parent.parent().matches(|generic_type| {
+        generic_type.kind() == GenericType && generic_type.field("name").text(context) == "Array"
+    })
+}
+
+fn collect_usage<'a>(
+    node: Node<'a>,
+    name: &str,
+    context: &QueryMatchContext<'a, '_>,
+    usage: &mut TypeParameterUsage,
+) {
+    if node.kind() == TypeIdentifier && node.text(context) == name {
+        if is_type_argument(node, context) {
+            usage.is_type_argument = true;
+        } else {
+            usage.count += 1;
+        }
+        return;
+    }
+
+    // an inner type parameter with the same name shadows this one
+    if declares_type_parameter(node, name, context) {
+        return;
+    }
+
+    for child in node.non_comment_named_children(SupportedLanguage::Javascript) {
+        collect_usage(child, name, context, usage);
+    }
+}
+
+fn get_usage<'a>(
+    node: Node<'a>,
+    type_parameter: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> TypeParameterUsage {
+    let name = type_parameter.field("name").text(context);
+    let mut usage = TypeParameterUsage::default();
+    for child in get_signature_children(node) {
+        if child.kind() == TypeParameters {
+            // references inside the type parameter's own definition don't
+            // count
+            for other_type_parameter in child
+                .non_comment_named_children(SupportedLanguage::Javascript)
+                .filter(|&other_type_parameter| other_type_parameter != type_parameter)
+            {
+                collect_usage(other_type_parameter, &name, context, &mut usage);
+            }
+            continue;
+        }
+        collect_usage(child, &name, context, &mut usage);
+    }
+    usage
+}
+
+pub fn no_unnecessary_type_parameters_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-unnecessary-type-parameters",
+        languages => [Typescript],
+        messages => [
+            sole => "Type parameter {{name}} is {{uses}} in the {{descriptor}} signature.",
+        ],
+        listeners => [
+            r#"
+              (function_declaration type_parameters: (_)) @c
+              (function type_parameters: (_)) @c
+              (generator_function_declaration type_parameters: (_)) @c
+              (generator_function type_parameters: (_)) @c
+              (arrow_function type_parameters: (_)) @c
+              (method_definition type_parameters: (_)) @c
+              (function_signature type_parameters: (_)) @c
+              (method_signature type_parameters: (_)) @c
+              (abstract_method_signature type_parameters: (_)) @c
+              (call_signature type_parameters: (_)) @c
+              (construct_signature type_parameters: (_)) @c
+              (function_type type_parameters: (_)) @c
+              (constructor_type type_parameters: (_)) @c
+            "# => |node, context| {
+                for type_parameter in node
+                    .field("type_parameters")
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                {
+                    let usage = get_usage(node, type_parameter, context);
+                    if usage.is_repeated() {
+                        continue;
+                    }
+
+                    context.report(violation! {
+                        node => type_parameter,
+                        message_id => "sole",
+                        data => {
+                            descriptor => "function",
+                            name => type_parameter.field("name").text(context),
+                            uses => if usage.count == 0 {
+                                "never used"
+                            } else {
+                                "used only once"
+                            },
+                        },
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_unnecessary_type_parameters_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              r#"
+class ClassyArray<T> {
+  arr: T[];
+}
+                  "#,
+              "function identity<T>(arg: T): T { return arg; }",
+              "function fn<T>(a: T, b: T): void {}",
+              "declare function fn<T>(a: T): T;",
+              "function printMap<K, V>(map: Map<K, V>): void {}",
+              "function getSet<T>(): Set<T> { return new Set(); }",
+              "function lengthOf<T>(values: Promise<T | null>): void {}",
+              "const identity = <T,>(arg: T): T => arg;",
+              r#"
+interface Identity {
+  <T>(arg: T): T;
+}
+                  "#,
+              r#"
+interface Factory {
+  new <T>(arg: T): Wrapper<T>;
+}
+                  "#,
+              r#"
+type Identity = {
+  <T>(arg: T): T;
+};
+                  "#,
+              "type Identity = <T>(arg: T) => T;",
+              r#"
+interface Mapper {
+  map<T>(values: T[]): T;
+}
+                  "#,
+              r#"
+class Mapper {
+  map<T>(values: T[]): T {
+    return values[0];
+  }
+}
+                  "#,
+            ],
+            invalid => [
+              {
+                code => "function second<A, B>(a: A, b: B): B { return b; }",
+                errors => [
+                  {
+                    message_id => "sole",
+                    data => {
+                      descriptor => "function",
+                      name => "A",
+                      uses => "used only once",
+                    },
+                    line => 1,
+                    column => 17,
+                  },
+                ],
+              },
+              {
+                code => "function fn<T>(param: string) {}",
+                errors => [
+                  {
+                    message_id => "sole",
+                    data => {
+                      descriptor => "function",
+                      name => "T",
+                      uses => "never used",
+                    },
+                    line => 1,
+                    column => 13,
+                  },
+                ],
+              },
+              {
+                code => "function fn<T>(param: T) {}",
+                errors => [
+                  {
+                    message_id => "sole",
+                    data => {
+                      descriptor => "function",
+                      name => "T",
+                      uses => "used only once",
+                    },
+                    line => 1,
+                    column => 13,
+                  },
+                ],
+              },
+              {
+                code => "function fn<T>(param: T[]) {}",
+                errors => [
+                  {
+                    message_id => "sole",
+                    line => 1,
+                    column => 13,
+                  },
+                ],
+              },
+              {
+                code => "function fn<T>(param: Array<T>) {}",
+                errors => [
+                  {
+                    message_id => "sole",
+                    line => 1,
+                    column => 13,
+                  },
+                ],
+              },
+              {
+                code => r#"
+function fn<T>(param: T) {
+  const copy: T = param;
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "sole",
+                    line => 2,
+                    column => 13,
+                  },
+                ],
+              },
+              {
+                code => "function compare<T, U extends T>(t: T, u: U): boolean { return t === u; }",
+                errors => [
+                  {
+                    message_id => "sole",
+                    data => {
+                      descriptor => "function",
+                      name => "U",
+                      uses => "used only once",
+                    },
+                    line => 1,
+                    column => 21,
+                  },
+                ],
+              },
+              {
+                code => r#"
+function outer<T>(param: T) {
+  return <T,>(inner: T): T => inner;
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "sole",
+                    line => 2,
+                    column => 16,
+                  },
+                ],
+              },
+              {
+                code => r#"
+interface Logger {
+  <T>(param: T): void;
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "sole",
+                    line => 3,
+                    column => 4,
+                  },
+                ],
+              },
+              {
+                code => r#"
+interface Factory {
+  new <T>(param: T): Factory;
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "sole",
+                    line => 3,
+                    column => 8,
+                  },
+                ],
+              },
+              {
+                code => r#"
+type Logger = {
+  <T>(param: T): void;
+};
+                    "#,
+                errors => [
+                  {
+                    message_id => "sole",
+                    line => 3,
+                    column => 4,
+                  },
+                ],
+              },
+              {
+                code => r#"
+interface Logger {
+  log<T>(param: T): void;
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "sole",
+                    line => 3,
+                    column => 7,
+                  },
+                ],
+              },
+              {
+                code => "type Logger = <T>(param: T) => void;",
+                errors => [
+                  {
+                    message_id => "sole",
+                    line => 1,
+                    column => 16,
+                  },
+                ],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_unnecessary_type_parameters_rule() {
+        run_for_typescript_and_tsx(
+            no_unnecessary_type_parameters_rule(),
+            test_cases::no_unnecessary_type_parameters_rule_tests(),
+        );
+    }
+}
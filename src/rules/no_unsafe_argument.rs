@@ -0,0 +1,317 @@
+use std::{borrow::Cow, sync::Arc};
+
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{CallExpression, Identifier, RestPattern};
+
+use crate::{
+    ast_helpers::{get_call_arguments, is_this_parameter, CallArgument, NodeExtTypescript},
+    kind::TypeIdentifier,
+    type_utils::{
+        get_array_element_type, get_expression_anyness, is_definitely_any, is_predefined_type,
+        Anyness,
+    },
+    util::{resolve_function, skip_parenthesized_expressions},
+};
+
+fn get_annotated_type(parameter: Node) -> Option<Node> {
+    Some(
+        parameter
+            .child_by_field_name("type")?
+            .first_non_comment_named_child(SupportedLanguage::Javascript)
+            .skip_parenthesized_types(),
+    )
+}
+
+/// The (annotated) type of the parameter that the `index`th argument gets
+/// passed to, looking through a rest parameter's array type.
+fn get_parameter_type<'a>(
+    parameters: &[Node<'a>],
+    index: usize,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    let (rest_parameter, parameters) = match parameters.split_last() {
+        Some((&last, init)) if last.field("pattern").kind() == RestPattern => (Some(last), init),
+        _ => (None, parameters),
+    };
+    if let Some(&parameter) = parameters.get(index) {
+        return get_annotated_type(parameter);
+    }
+    get_array_element_type(get_annotated_type(rest_parameter?)?, context)
+}
+
+fn get_type_parameter_names<'a>(
+    function: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Vec<Cow<'a, str>> {
+    function
+        .child_by_field_name("type_parameters")
+        .map(|type_parameters| {
+            type_parameters
+                .non_comment_named_children(SupportedLanguage::Javascript)
+                .map(|type_parameter| type_parameter.field("name").text(context))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Whether passing something `any` (or `any[]`) where `receiver` is expected
+/// loses type safety. `any`/`unknown` receivers are fine, as are the
+/// callee's own type parameters (which just get inferred as `any`).
+fn is_unsafe_assignment(
+    anyness: Anyness,
+    receiver: Node,
+    type_parameter_names: &[Cow<'_, str>],
+    context: &QueryMatchContext,
+) -> bool {
+    let receiver = receiver.skip_parenthesized_types();
+    if is_predefined_type(receiver, "any", context)
+        || is_predefined_type(receiver, "unknown", context)
+    {
+        return false;
+    }
+    if receiver.kind() == TypeIdentifier && type_parameter_names.contains(&receiver.text(context)) {
+        return false;
+    }
+    match anyness {
+        Anyness::Any => true,
+        Anyness::AnyArray => {
+            get_array_element_type(receiver, context).is_some_and(|element_type| {
+                is_unsafe_assignment(Anyness::Any, element_type, type_parameter_names, context)
+            })
+        }
+    }
+}
+
+pub fn no_unsafe_argument_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-unsafe-argument",
+        languages => [Typescript],
+        messages => [
+            unsafe_argument => "Unsafe argument of type `{{sender}}` assigned to a parameter of type `{{receiver}}`.",
+            unsafe_array_spread => "Unsafe spread of an `any` array type.",
+            unsafe_spread => "Unsafe spread of an `any` type.",
+        ],
+        listeners => [
+            r#"
+              (call_expression) @c
+              (new_expression) @c
+            "# => |node, context| {
+                let is_call = node.kind() == CallExpression;
+                let callee = skip_parenthesized_expressions(
+                    node.field(if is_call { "function" } else { "constructor" }),
+                );
+                // (calling something `any` is no-unsafe-call's concern)
+                if is_definitely_any(callee, context) {
+                    return;
+                }
+                // only calls of functions declared in-file have known
+                // parameter types
+                let function = (is_call && callee.kind() == Identifier)
+                    .then(|| resolve_function(callee, context))
+                    .flatten();
+                let parameters = function
+                    .and_then(|function| function.child_by_field_name("parameters"))
+                    .map(|parameters| {
+                        parameters
+                            .non_comment_named_children(SupportedLanguage::Javascript)
+                            .filter(|&parameter| !is_this_parameter(parameter))
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default();
+                let type_parameter_names = function
+                    .map(|function| get_type_parameter_names(function, context))
+                    .unwrap_or_default();
+
+                // (after a spread, which parameter each argument lines up
+                // with isn't known)
+                let mut is_position_known = true;
+                for (index, argument) in get_call_arguments(node).enumerate() {
+                    match argument {
+                        CallArgument::Spread { node: spread, argument } => {
+                            is_position_known = false;
+                            // TODO: check the elements of spread tuples
+                            // against the parameters they line up with
+                            match get_expression_anyness(argument, context) {
+                                Some(Anyness::Any) => context.report(violation! {
+                                    node => spread,
+                                    message_id => "unsafe_spread",
+                                }),
+                                Some(Anyness::AnyArray) => context.report(violation! {
+                                    node => spread,
+                                    message_id => "unsafe_array_spread",
+                                }),
+                                None => (),
+                            }
+                        }
+                        CallArgument::Expression(argument) => {
+                            if !is_position_known {
+                                continue;
+                            }
+                            let Some(anyness) = get_expression_anyness(argument, context) else {
+                                continue;
+                            };
+                            let Some(parameter_type) = get_parameter_type(&parameters, index, context) else {
+                                continue;
+                            };
+                            if !is_unsafe_assignment(anyness, parameter_type, &type_parameter_names, context) {
+                                continue;
+                            }
+                            context.report(violation! {
+                                node => argument,
+                                message_id => "unsafe_argument",
+                                data => {
+                                    sender => anyness.as_str(),
+                                    receiver => parameter_type.text(context),
+                                },
+                            });
+                        }
+                    }
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_unsafe_argument_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "foo(1 as any);",
+              "declare function foo(arg: any): void; foo(1 as any);",
+              "declare function foo(arg: unknown): void; declare const x: any; foo(x);",
+              "function foo<T>(arg: T) {} declare const x: any; foo(x);",
+              "declare function foo(...args: any[]): void; foo(1 as any, 2 as any);",
+              "declare function foo(arg: unknown[]): void; declare const x: any[]; foo(x);",
+              "declare function foo(arg: string[]): void; declare const x: any; foo(...[1, 2]);",
+              "declare const x: string[]; foo(...x);",
+              "declare const foo: any; declare const x: any[]; foo(...x);",
+              r#"
+declare const x: any;
+function foo(x: string) {
+  bar(...x);
+}
+                  "#,
+              "const foo = (a) => {}; foo(1 as any);",
+            ],
+            invalid => [
+              {
+                code => "declare function foo(arg: number): void; foo(1 as any);",
+                errors => [
+                  {
+                    message_id => "unsafe_argument",
+                    data => { sender => "any", receiver => "number" },
+                    line => 1,
+                    column => 46,
+                    end_column => 54,
+                  },
+                ],
+              },
+              {
+                code => "declare function foo(arg1: string, arg2: number): void; declare const x: any; foo('a', x);",
+                errors => [
+                  {
+                    message_id => "unsafe_argument",
+                    data => { sender => "any", receiver => "number" },
+                    column => 88,
+                  },
+                ],
+              },
+              {
+                code => "declare function foo(...args: string[]): void; declare const x: any; foo('a', 'b', x);",
+                errors => [
+                  {
+                    message_id => "unsafe_argument",
+                    data => { sender => "any", receiver => "string" },
+                    column => 84,
+                  },
+                ],
+              },
+              {
+                code => "declare function foo(arg: string[]): void; declare const x: any[]; foo(x);",
+                errors => [
+                  {
+                    message_id => "unsafe_argument",
+                    data => { sender => "any[]", receiver => "string[]" },
+                  },
+                ],
+              },
+              {
+                code => r#"
+function foo(x: any) {
+  return bar(x);
+}
+function bar(y: number) {}
+                    "#,
+                errors => [
+                  {
+                    message_id => "unsafe_argument",
+                    data => { sender => "any", receiver => "number" },
+                    line => 3,
+                    column => 14,
+                  },
+                ],
+              },
+              {
+                code => "const foo = (a: string) => {}; foo(<any>1);",
+                errors => [{ message_id => "unsafe_argument", column => 36 }],
+              },
+              {
+                code => "declare const x: any; foo(...x);",
+                errors => [
+                  {
+                    message_id => "unsafe_spread",
+                    line => 1,
+                    column => 27,
+                    end_column => 31,
+                  },
+                ],
+              },
+              {
+                code => "declare const x: any[]; foo(...x);",
+                errors => [{ message_id => "unsafe_array_spread", column => 29 }],
+              },
+              {
+                code => "declare const x: ReadonlyArray<any>; new Foo(...x);",
+                errors => [{ message_id => "unsafe_array_spread", column => 46 }],
+              },
+              {
+                code => "declare const x: readonly any[]; foo.bar(...x);",
+                errors => [{ message_id => "unsafe_array_spread", column => 42 }],
+              },
+              {
+                code => "foo(...(x as any[]));",
+                errors => [{ message_id => "unsafe_array_spread", column => 5 }],
+              },
+              {
+                code => r#"
+declare function foo(a: string, b: number): void;
+declare const x: any[];
+declare const y: any;
+foo(...x, y);
+                    "#,
+                errors => [{ message_id => "unsafe_array_spread", line => 5, column => 5 }],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_unsafe_argument_rule() {
+        run_for_typescript_and_tsx(
+            no_unsafe_argument_rule(),
+            test_cases::no_unsafe_argument_rule_tests(),
+        );
+    }
+}
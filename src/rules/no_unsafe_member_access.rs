@@ -0,0 +1,258 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{rule, violation, NodeExt, Rule};
+use tree_sitter_lint_plugin_eslint_builtin::kind::MemberExpression;
+
+use crate::type_utils::is_definitely_any;
+
+pub fn no_unsafe_member_access_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-unsafe-member-access",
+        languages => [Typescript],
+        messages => [
+            unsafe_member_expression => "Unsafe member access {{property}} on an `any` value.",
+            unsafe_computed_member_access => "Computed name {{property}} resolves to an `any` value.",
+        ],
+        listeners => [
+            r#"
+              (member_expression) @c
+              (subscript_expression) @c
+            "# => |node, context| {
+                if is_definitely_any(node.field("object"), context) {
+                    let property = if node.kind() == MemberExpression {
+                        format!(".{}", node.field("property").text(context))
+                    } else {
+                        format!("[{}]", node.field("index").text(context))
+                    };
+                    context.report(violation! {
+                        node => node.field(if node.kind() == MemberExpression { "property" } else { "index" }),
+                        message_id => "unsafe_member_expression",
+                        data => {
+                            property => property,
+                        },
+                    });
+                    return;
+                }
+
+                if node.kind() == MemberExpression {
+                    return;
+                }
+                let index = node.field("index");
+                if !is_definitely_any(index, context) {
+                    return;
+                }
+                context.report(violation! {
+                    node => index,
+                    message_id => "unsafe_computed_member_access",
+                    data => {
+                        property => format!("[{}]", index.text(context)),
+                    },
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_unsafe_member_access_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "function foo(x: { a: number }) { x.a; }",
+              "function foo(x: { a: number }, y: string) { x[y]; }",
+              "function foo(x?: { a: number }) { x?.a; }",
+              "function foo(x: unknown[]) { x[0]; }",
+              "function foo(x: any[]) { x[1]; }",
+              "function foo(x: any[]) { x.length; }",
+              r#"
+declare const xs: string[];
+for (const x of xs) {
+  x.length;
+}
+                  "#,
+              r#"
+declare const xs: any;
+for (const key in xs) {
+  key.length;
+}
+                  "#,
+              r#"
+declare const xs: any[];
+for (const x of xs) {
+  const x = 'shadowed';
+  x.length;
+}
+                  "#,
+              r#"
+declare const y: any;
+function foo(y: string) {
+  const x = y;
+  x.length;
+}
+                  "#,
+              r#"
+declare const value: any;
+let x;
+x = value;
+x = 'not any';
+x.length;
+                  "#,
+              r#"
+declare const value: any;
+let x = value;
+x += 1;
+x.toFixed();
+                  "#,
+              r#"
+declare const value: any;
+declare const values: string[];
+let x = value;
+[x] = values;
+x.length;
+                  "#,
+            ],
+            invalid => [
+              {
+                code => "function foo(x: any) { x.a; }",
+                errors => [
+                  {
+                    message_id => "unsafe_member_expression",
+                    data => { property => ".a" },
+                    line => 1,
+                    column => 26,
+                    end_column => 27,
+                  },
+                ],
+              },
+              {
+                code => "function foo(x: any) { x.a.b.c; }",
+                errors => [
+                  {
+                    message_id => "unsafe_member_expression",
+                    data => { property => ".a" },
+                    column => 26,
+                  },
+                ],
+              },
+              {
+                code => "function foo(x: any) { x?.a; }",
+                errors => [{ message_id => "unsafe_member_expression", data => { property => ".a" } }],
+              },
+              {
+                code => "function foo(x: any) { x['a']; }",
+                errors => [
+                  {
+                    message_id => "unsafe_member_expression",
+                    data => { property => "['a']" },
+                    column => 26,
+                  },
+                ],
+              },
+              {
+                code => "function foo(x: any[]) { x[1].a; }",
+                errors => [{ message_id => "unsafe_member_expression", data => { property => ".a" } }],
+              },
+              {
+                code => "(x as any).a;",
+                errors => [{ message_id => "unsafe_member_expression", column => 12 }],
+              },
+              {
+                code => "function foo(x: string[], y: any) { x[y]; }",
+                errors => [
+                  {
+                    message_id => "unsafe_computed_member_access",
+                    data => { property => "[y]" },
+                    column => 39,
+                  },
+                ],
+              },
+              {
+                code => r#"
+declare const xs: any[];
+for (const x of xs) {
+  x.a;
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "unsafe_member_expression",
+                    data => { property => ".a" },
+                    line => 4,
+                    column => 5,
+                  },
+                ],
+              },
+              {
+                code => r#"
+declare const xs: any;
+for (const x of xs) {
+  x.a;
+}
+                    "#,
+                errors => [{ message_id => "unsafe_member_expression", line => 4 }],
+              },
+              {
+                code => r#"
+function foo(xs: Array<any>) {
+  for (const x of xs) {
+    const y = x;
+    y.a;
+  }
+}
+                    "#,
+                errors => [{ message_id => "unsafe_member_expression", line => 5 }],
+              },
+              {
+                code => r#"
+declare const value: any;
+const alias = value;
+let other = (alias);
+other.a;
+                    "#,
+                errors => [{ message_id => "unsafe_member_expression", line => 5, column => 7 }],
+              },
+              {
+                code => r#"
+declare const value: any;
+let x;
+if (value) {
+  x = value;
+} else {
+  x = value as any;
+}
+x.a;
+                    "#,
+                errors => [{ message_id => "unsafe_member_expression", line => 9, column => 3 }],
+              },
+              {
+                code => r#"
+function foo(value: any) {
+  let x = value;
+  const reset = () => {
+    x = JSON.parse('{}') as any;
+  };
+  return x.a;
+}
+                    "#,
+                errors => [{ message_id => "unsafe_member_expression", line => 7, column => 12 }],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_unsafe_member_access_rule() {
+        run_for_typescript_and_tsx(
+            no_unsafe_member_access_rule(),
+            test_cases::no_unsafe_member_access_rule_tests(),
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use std::{collections::HashSet, sync::Arc};
+
+use tree_sitter_lint::{rule, violation, Rule};
+
+use crate::util::{ClassMemberIndex, ClassMemberKind};
+
+pub fn no_unused_private_class_members_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-unused-private-class-members",
+        languages => [Typescript],
+        messages => [
+            unused_private_class_member => "'{{class_member_name}}' is defined but never used.",
+        ],
+        listeners => [
+            r#"
+              (class_body) @c
+            "# => |node, context| {
+                let class_member_index = ClassMemberIndex::new(node, context);
+
+                let mut reported: HashSet<&str> = Default::default();
+                for private_member in class_member_index.members.iter().filter(|member| {
+                    member.is_private() && member.kind != ClassMemberKind::Constructor
+                }) {
+                    if class_member_index.is_read(&private_member.name)
+                        || private_member.is_accessor()
+                            && class_member_index.is_written(&private_member.name)
+                    {
+                        continue;
+                    }
+                    // only report the first of eg overload signatures
+                    if !reported.insert(&private_member.name) {
+                        continue;
+                    }
+
+                    context.report(violation! {
+                        node => private_member.name_node,
+                        message_id => "unused_private_class_member",
+                        data => {
+                            class_member_name => &private_member.name,
+                        },
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_unused_private_class_members_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "class Foo {}",
+              "class Foo { publicMember = 42; }",
+              "class Foo { #usedMember = 42; method() { return this.#usedMember; } }",
+              "class Foo { private usedMember = 42; method() { return this.usedMember; } }",
+              "class Foo { #usedMethod() { return 42; } anotherMethod() { return this.#usedMethod(); } }",
+              "class Foo { constructor(private used: number) {} method() { return this.used; } }",
+              "class Foo { constructor(public notPrivate: number) {} }",
+              "class Foo { private used = 1; other: typeof this.used; }",
+              "class Foo { private used = 1; method() { const { used } = this; return used; } }",
+              "class Foo { private used = 1; method() { return this['used']; } }",
+              "class Foo { #used = 1; static isFoo(obj) { return #used in obj; } }",
+              "class Foo { private used = 1; equals(other: Foo) { return other.used === this.used; } }",
+              "class Foo { get #accessor() { return 1; } set #accessor(value) {} method() { this.#accessor = 42; } }",
+              "class Foo { #usedInOuter = 1; method() { return class { inner() { return this.#usedInOuter; } }; } }",
+              "class Foo { private used = 1; method() { foo(this.used++); } }",
+              "class Foo { private static used = 1; method() { return Foo.used; } }",
+              "class Foo { private constructor() {} }",
+            ],
+            invalid => [
+              {
+                code => "class Foo { #unusedMember = 5; }",
+                errors => [
+                  {
+                    message_id => "unused_private_class_member",
+                    data => { class_member_name => "#unusedMember" },
+                    line => 1,
+                    column => 13,
+                    end_line => 1,
+                    end_column => 26,
+                  },
+                ],
+              },
+              {
+                code => "class Foo { private unusedMember = 5; }",
+                errors => [
+                  {
+                    message_id => "unused_private_class_member",
+                    data => { class_member_name => "unusedMember" },
+                    line => 1,
+                    column => 21,
+                  },
+                ],
+              },
+              {
+                code => "class Foo { constructor(private unused: number) {} }",
+                errors => [
+                  {
+                    message_id => "unused_private_class_member",
+                    data => { class_member_name => "unused" },
+                    line => 1,
+                    column => 33,
+                  },
+                ],
+              },
+              {
+                code => "class Foo { #writeOnly = 1; method() { this.#writeOnly = 42; } }",
+                errors => [{ message_id => "unused_private_class_member", line => 1, column => 13 }],
+              },
+              {
+                code => "class Foo { private writeOnly = 1; method() { this.writeOnly += 1; (this.writeOnly)++; } }",
+                errors => [{ message_id => "unused_private_class_member", line => 1, column => 21 }],
+              },
+              {
+                code => "class Foo { private unusedMethod() {} }",
+                errors => [{ message_id => "unused_private_class_member", line => 1, column => 21 }],
+              },
+              {
+                code => r#"
+class Foo {
+  private overloaded(): void;
+  private overloaded(a?: number) {}
+}
+                    "#,
+                errors => [{ message_id => "unused_private_class_member", line => 3, column => 11 }],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_unused_private_class_members_rule() {
+        run_for_typescript_and_tsx(
+            no_unused_private_class_members_rule(),
+            test_cases::no_unused_private_class_members_rule_tests(),
+        );
+    }
+}
@@ -0,0 +1,545 @@
+use std::sync::Arc;
+
+use squalid::OptionExt;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    ast_helpers::{get_method_definition_kind, MethodDefinitionKind},
+    kind::{
+        CallExpression, ClassBody, ExpressionStatement, Identifier, RestPattern, SpreadElement,
+        Super,
+    },
+};
+
+use crate::{
+    ast_helpers::{get_accessibility_modifier, get_class_heritage, get_decorators},
+    kind::{ExtendsClause, OptionalParameter, RequiredParameter},
+    util::constructor_has_parameter_properties,
+};
+
+fn has_superclass(class: Node) -> bool {
+    get_class_heritage(class).matches(|heritage| heritage.has_child_of_kind(ExtendsClause))
+}
+
+// a non-public constructor restricts who can instantiate the class, and a
+// public one in a subclass may widen a non-public parent constructor, so
+// neither is useless
+fn check_accessibility<'a>(
+    node: Node<'a>,
+    class: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    match get_accessibility_modifier(node)
+        .map(|modifier| modifier.text(context))
+        .as_deref()
+    {
+        Some("protected" | "private") => false,
+        Some("public") => !has_superclass(class),
+        _ => true,
+    }
+}
+
+// parameter properties and parameter decorators both have effects beyond
+// the constructor call itself
+fn check_params(node: Node) -> bool {
+    !constructor_has_parameter_properties(node)
+        && !node
+            .field("parameters")
+            .non_comment_named_children(SupportedLanguage::Javascript)
+            .any(|parameter| get_decorators(parameter).next().is_some())
+}
+
+/// The identifier a parameter binds (and whether it's a rest parameter), if
+/// it's a plain (possibly optional or type-annotated) identifier without a
+/// default value.
+fn get_simple_parameter_name(parameter: Node) -> Option<(Node, bool)> {
+    if !matches!(parameter.kind(), RequiredParameter | OptionalParameter)
+        || parameter.child_by_field_name("value").is_some()
+    {
+        return None;
+    }
+    let pattern = parameter.field("pattern");
+    match pattern.kind() {
+        Identifier => Some((pattern, false)),
+        RestPattern => Some(pattern.first_non_comment_named_child(SupportedLanguage::Javascript))
+            .filter(|argument| argument.kind() == Identifier)
+            .map(|argument| (argument, true)),
+        _ => None,
+    }
+}
+
+/// The arguments of the constructor body's `super()` call, if that call is
+/// the body's only statement.
+fn get_single_super_call_arguments(body: Node) -> Option<Vec<Node>> {
+    let mut statements = body.non_comment_named_children(SupportedLanguage::Javascript);
+    let (Some(statement), None) = (statements.next(), statements.next()) else {
+        return None;
+    };
+    if statement.kind() != ExpressionStatement {
+        return None;
+    }
+    let call = statement.first_non_comment_named_child(SupportedLanguage::Javascript);
+    if call.kind() != CallExpression || call.field("function").kind() != Super {
+        return None;
+    }
+    Some(
+        call.field("arguments")
+            .non_comment_named_children(SupportedLanguage::Javascript)
+            .collect(),
+    )
+}
+
+fn is_spread_arguments<'a>(arguments: &[Node<'a>], context: &QueryMatchContext<'a, '_>) -> bool {
+    match arguments {
+        [argument] => {
+            argument.kind() == SpreadElement && {
+                let spread = argument.first_non_comment_named_child(SupportedLanguage::Javascript);
+                spread.kind() == Identifier && spread.text(context) == "arguments"
+            }
+        }
+        _ => false,
+    }
+}
+
+fn is_valid_pair<'a>(
+    parameter: Node<'a>,
+    argument: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    let Some((name, is_rest)) = get_simple_parameter_name(parameter) else {
+        return false;
+    };
+    let argument = if is_rest {
+        if argument.kind() != SpreadElement {
+            return false;
+        }
+        argument.first_non_comment_named_child(SupportedLanguage::Javascript)
+    } else {
+        argument
+    };
+    argument.kind() == Identifier && argument.text(context) == name.text(context)
+}
+
+/// Whether the constructor's parameters are all passed straight through to
+/// `super()`, in the same order.
+fn is_passing_through<'a>(
+    parameters: &[Node<'a>],
+    arguments: &[Node<'a>],
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    parameters.len() == arguments.len()
+        && parameters
+            .iter()
+            .zip(arguments)
+            .all(|(&parameter, &argument)| is_valid_pair(parameter, argument, context))
+}
+
+fn is_redundant_super_call<'a>(
+    body: Node<'a>,
+    parameters: &[Node<'a>],
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    let Some(arguments) = get_single_super_call_arguments(body) else {
+        return false;
+    };
+    parameters
+        .iter()
+        .all(|&parameter| get_simple_parameter_name(parameter).is_some())
+        && (is_spread_arguments(&arguments, context)
+            || is_passing_through(parameters, &arguments, context))
+}
+
+pub fn no_useless_constructor_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-useless-constructor",
+        languages => [Typescript],
+        messages => [
+            no_useless_constructor => "Useless constructor.",
+        ],
+        listeners => [
+            r#"
+              (method_definition) @c
+            "# => |node, context| {
+                if node.parent().unwrap().kind() != ClassBody
+                    || !matches!(
+                        get_method_definition_kind(node, context),
+                        MethodDefinitionKind::Constructor
+                    )
+                {
+                    return;
+                }
+                let class = node.parent().unwrap().parent().unwrap();
+                if !check_accessibility(node, class, context) || !check_params(node) {
+                    return;
+                }
+
+                let body = node.field("body");
+                let parameters = node
+                    .field("parameters")
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                    .collect::<Vec<_>>();
+                let is_useless = if has_superclass(class) {
+                    is_redundant_super_call(body, &parameters, context)
+                } else {
+                    body.non_comment_named_children(SupportedLanguage::Javascript)
+                        .next()
+                        .is_none()
+                };
+                if !is_useless {
+                    return;
+                }
+
+                // TODO: suggestions?
+                context.report(violation! {
+                    node => node,
+                    message_id => "no_useless_constructor",
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_useless_constructor_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "class A {}",
+              r#"
+class A {
+  constructor() {
+    doSomething();
+  }
+}
+                  "#,
+              r#"
+class A extends B {
+  constructor() {}
+}
+                  "#,
+              r#"
+class A extends B {
+  constructor() {
+    super('foo');
+  }
+}
+                  "#,
+              r#"
+class A extends B {
+  constructor(foo, bar) {
+    super(foo, bar, 1);
+  }
+}
+                  "#,
+              r#"
+class A extends B {
+  constructor() {
+    super();
+    doSomething();
+  }
+}
+                  "#,
+              r#"
+class A extends B {
+  constructor(...args) {
+    super(...args);
+    doSomething();
+  }
+}
+                  "#,
+              r#"
+class A {
+  dummyMethod() {
+    doSomething();
+  }
+}
+                  "#,
+              r#"
+class A extends B.C {
+  constructor() {
+    super(foo);
+  }
+}
+                  "#,
+              r#"
+class A extends B.C {
+  constructor([a, b, c]) {
+    super(...arguments);
+  }
+}
+                  "#,
+              r#"
+class A extends B.C {
+  constructor(a = f()) {
+    super(...arguments);
+  }
+}
+                  "#,
+              r#"
+class A extends B {
+  constructor(a, b, c) {
+    super(a, b);
+  }
+}
+                  "#,
+              r#"
+class A extends B {
+  constructor(foo, bar) {
+    super(foo);
+  }
+}
+                  "#,
+              r#"
+class A extends B {
+  constructor(test) {
+    super();
+  }
+}
+                  "#,
+              r#"
+class A extends B {
+  constructor() {
+    foo;
+  }
+}
+                  "#,
+              r#"
+class A extends B {
+  constructor(foo, bar) {
+    super(bar);
+  }
+}
+                  "#,
+              "declare class A { constructor(); }",
+              r#"
+class A {
+  constructor();
+}
+                  "#,
+              r#"
+abstract class A {
+  constructor();
+}
+                  "#,
+              r#"
+class A {
+  constructor(private name: string) {}
+}
+                  "#,
+              r#"
+class A {
+  constructor(public name: string) {}
+}
+                  "#,
+              r#"
+class A {
+  constructor(protected name: string) {}
+}
+                  "#,
+              r#"
+class A {
+  private constructor() {}
+}
+                  "#,
+              r#"
+class A {
+  protected constructor() {}
+}
+                  "#,
+              r#"
+abstract class A {
+  protected constructor() {}
+}
+                  "#,
+              r#"
+class A extends B {
+  public constructor() {}
+}
+                  "#,
+              r#"
+class A extends B {
+  protected constructor(foo, bar) {
+    super(bar);
+  }
+}
+                  "#,
+              r#"
+class A extends B {
+  private constructor(foo, bar) {
+    super(bar);
+  }
+}
+                  "#,
+              r#"
+class A extends B {
+  public constructor(foo) {
+    super(foo);
+  }
+}
+                  "#,
+              r#"
+class A extends B {
+  public constructor(foo) {}
+}
+                  "#,
+              r#"
+class A {
+  constructor(foo);
+}
+                  "#,
+              r#"
+class A extends Object {
+  constructor(@Foo foo: string) {
+    super(foo);
+  }
+}
+                  "#,
+              r#"
+class A extends Object {
+  constructor(foo: string, @Bar() bar) {
+    super(foo, bar);
+  }
+}
+                  "#,
+              r#"
+class A {
+  constructor(readonly foo: string) {}
+}
+                  "#,
+              "const foo = { constructor() {} };",
+            ],
+            invalid => [
+              {
+                code => r#"
+class A {
+  constructor() {}
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "no_useless_constructor",
+                    line => 3,
+                    column => 3,
+                  },
+                ],
+              },
+              {
+                code => r#"
+class A extends B {
+  constructor() {
+    super();
+  }
+}
+                    "#,
+                errors => [{ message_id => "no_useless_constructor" }],
+              },
+              {
+                code => r#"
+class A extends B {
+  constructor(foo) {
+    super(foo);
+  }
+}
+                    "#,
+                errors => [{ message_id => "no_useless_constructor" }],
+              },
+              {
+                code => r#"
+class A extends B {
+  constructor(foo, bar) {
+    super(foo, bar);
+  }
+}
+                    "#,
+                errors => [{ message_id => "no_useless_constructor" }],
+              },
+              {
+                code => r#"
+class A extends B {
+  constructor(...args) {
+    super(...args);
+  }
+}
+                    "#,
+                errors => [{ message_id => "no_useless_constructor" }],
+              },
+              {
+                code => r#"
+class A extends B.C {
+  constructor() {
+    super(...arguments);
+  }
+}
+                    "#,
+                errors => [{ message_id => "no_useless_constructor" }],
+              },
+              {
+                code => r#"
+class A extends B {
+  constructor(a, b, ...c) {
+    super(...arguments);
+  }
+}
+                    "#,
+                errors => [{ message_id => "no_useless_constructor" }],
+              },
+              {
+                code => r#"
+class A extends B {
+  constructor(a, b, ...c) {
+    super(a, b, ...c);
+  }
+}
+                    "#,
+                errors => [{ message_id => "no_useless_constructor" }],
+              },
+              {
+                code => r#"
+class A extends B {
+  constructor(foo: string, bar?: number) {
+    super(foo, bar);
+  }
+}
+                    "#,
+                errors => [{ message_id => "no_useless_constructor" }],
+              },
+              {
+                code => r#"
+class A {
+  public constructor() {}
+}
+                    "#,
+                errors => [{ message_id => "no_useless_constructor" }],
+              },
+              {
+                code => r#"
+class A {
+  constructor() {
+    // nothing to see here
+  }
+}
+                    "#,
+                errors => [{ message_id => "no_useless_constructor" }],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_no_useless_constructor_rule() {
+        run_for_typescript_and_tsx(
+            no_useless_constructor_rule(),
+            test_cases::no_useless_constructor_rule_tests(),
+        );
+    }
+}
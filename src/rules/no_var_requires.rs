@@ -0,0 +1,226 @@
+use std::sync::Arc;
+
+use regex::Regex;
+use serde::Deserialize;
+use tree_sitter_lint::{rule, tree_sitter_grep::SupportedLanguage, violation, NodeExt, Rule};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    Arguments, CallExpression, MemberExpression, NewExpression, ParenthesizedExpression,
+    SubscriptExpression, VariableDeclarator,
+};
+
+use crate::{
+    kind::{AsExpression, TypeAssertion},
+    module_kind::FileModuleKind,
+    util::{get_require_path, is_global_require, is_require_path_allowed, RegexOption},
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    allow: Option<Vec<RegexOption>>,
+    allow_in_common_js: Option<bool>,
+}
+
+impl Options {
+    fn allow(&self) -> Vec<Regex> {
+        self.allow
+            .iter()
+            .flatten()
+            .map(|allow| allow.0.clone())
+            .collect()
+    }
+
+    fn allow_in_common_js(&self) -> bool {
+        self.allow_in_common_js.unwrap_or_default()
+    }
+}
+
+pub fn no_var_requires_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "no-var-requires",
+        languages => [Typescript],
+        messages => [
+            no_var_reqs => "Require statement not part of import statement.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            allow_patterns: Vec<Regex> = options.allow(),
+            allow_in_common_js: bool = options.allow_in_common_js(),
+        },
+        listeners => [
+            r#"
+              (call_expression
+                function: (identifier) @callee (#eq? @callee "require")
+              ) @call
+            "# => |captures, context| {
+                let node = captures["call"];
+                if self.allow_in_common_js && context.retrieve::<FileModuleKind>().is_script() {
+                    return;
+                }
+                if node
+                    .field("arguments")
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                    .next()
+                    .and_then(|argument| get_require_path(argument, context))
+                    .is_some_and(|path| is_require_path_allowed(&path, &self.allow_patterns))
+                {
+                    return;
+                }
+
+                let parent = node
+                    .ancestors()
+                    .find(|ancestor| ancestor.kind() != ParenthesizedExpression)
+                    .unwrap();
+                if !matches!(
+                    parent.kind(),
+                    CallExpression
+                        | Arguments
+                        | MemberExpression
+                        | SubscriptExpression
+                        | NewExpression
+                        | AsExpression
+                        | TypeAssertion
+                        | VariableDeclarator
+                ) {
+                    return;
+                }
+                if !is_global_require(captures["callee"], context) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "no_var_reqs",
+                    data => {
+                        banned_name => "require",
+                    },
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn no_var_requires_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "import foo = require('foo');",
+                "require('foo');",
+                "require?.('foo');",
+                "
+import { createRequire } from 'module';
+const require = createRequire('foo');
+const json = require('./some.json');
+                    ",
+                {
+                  code => "const pkg = require('./package.json');",
+                  options => { allow => ["/package\\.json$"] },
+                },
+                {
+                  code => "const pkg = require('../package.json');",
+                  options => { allow => ["/package\\.json$"] },
+                },
+                {
+                  code => "const pkg = require(`./package.json`);",
+                  options => { allow => ["/package\\.json$"] },
+                },
+                {
+                  code => "const pkg = require('./package.json') as Package;",
+                  options => { allow => ["/package\\.json$"] },
+                },
+                {
+                  code => "const foo = require('foo');",
+                  options => { allow_in_common_js => true },
+                },
+            ],
+            invalid => [
+                {
+                  code => "var foo = require('foo');",
+                  errors => [
+                    {
+                      message_id => "no_var_reqs",
+                      data => {
+                        banned_name => "require",
+                      },
+                      line => 1,
+                      column => 11,
+                    },
+                  ],
+                },
+                {
+                  code => "const foo = require('foo');",
+                  errors => [{ message_id => "no_var_reqs", column => 13 }],
+                },
+                {
+                  code => "let foo = require('foo');",
+                  errors => [{ message_id => "no_var_reqs", column => 11 }],
+                },
+                {
+                  code => "let foo = trick(require('foo'));",
+                  errors => [{ message_id => "no_var_reqs", column => 17 }],
+                },
+                {
+                  code => "var foo = require?.('foo').bar;",
+                  errors => [{ message_id => "no_var_reqs", column => 11 }],
+                },
+                {
+                  code => "let foo = (require('foo'));",
+                  errors => [{ message_id => "no_var_reqs", column => 12 }],
+                },
+                {
+                  code => "new (require('foo'))();",
+                  errors => [{ message_id => "no_var_reqs", column => 6 }],
+                },
+                {
+                  code => "const foo = require('foo') as Foo;",
+                  errors => [{ message_id => "no_var_reqs", column => 13 }],
+                },
+                {
+                  code => "const foo = <Foo>require('foo');",
+                  errors => [{ message_id => "no_var_reqs", column => 18 }],
+                },
+                {
+                  code => "const pkg = require('./package.json');",
+                  options => { allow => ["^some-package$"] },
+                  errors => [{ message_id => "no_var_reqs", column => 13 }],
+                },
+                {
+                  code => "
+import { bar } from 'bar';
+const foo = require('foo');
+                      ",
+                  options => { allow_in_common_js => true },
+                  errors => [{ message_id => "no_var_reqs", line => 3, column => 13 }],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::serde_json::{self, json};
+
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx_with_provided_types;
+
+    #[test]
+    fn test_no_var_requires_rule() {
+        run_for_typescript_and_tsx_with_provided_types(
+            no_var_requires_rule(),
+            test_cases::no_var_requires_rule_tests(),
+        );
+    }
+
+    #[test]
+    fn test_invalid_allow_pattern_is_an_options_error() {
+        assert!(
+            serde_json::from_value::<Options>(json!({ "allow": ["/package\\.json$"] })).is_ok()
+        );
+        assert!(serde_json::from_value::<Options>(json!({ "allow": ["package(.json"] })).is_err());
+    }
+}
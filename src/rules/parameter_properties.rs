@@ -0,0 +1,645 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    rule,
+    tree_sitter::{Node, Range},
+    tree_sitter_grep::SupportedLanguage,
+    violation, NodeExt, QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    ast_helpers::{get_method_definition_kind, MethodDefinitionKind},
+    kind::{
+        AssignmentExpression, ExpressionStatement, Identifier, MemberExpression, MethodDefinition,
+        PropertyIdentifier, This,
+    },
+};
+
+use crate::{
+    ast_helpers::{get_accessibility_modifier, get_decorators, is_member_static},
+    kind::{OptionalParameter, PublicFieldDefinition, RequiredParameter},
+    type_utils::are_types_equivalent,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize)]
+enum Modifier {
+    #[serde(rename = "readonly")]
+    Readonly,
+    #[serde(rename = "private")]
+    Private,
+    #[serde(rename = "protected")]
+    Protected,
+    #[serde(rename = "public")]
+    Public,
+    #[serde(rename = "private readonly")]
+    PrivateReadonly,
+    #[serde(rename = "protected readonly")]
+    ProtectedReadonly,
+    #[serde(rename = "public readonly")]
+    PublicReadonly,
+}
+
+impl Modifier {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Readonly => "readonly",
+            Self::Private => "private",
+            Self::Protected => "protected",
+            Self::Public => "public",
+            Self::PrivateReadonly => "private readonly",
+            Self::ProtectedReadonly => "protected readonly",
+            Self::PublicReadonly => "public readonly",
+        }
+    }
+}
+
+/// The accessibility and `readonly` modifiers of a class field or parameter
+/// (`None` if it has neither, ie for a parameter if it isn't a parameter
+/// property).
+fn get_modifier(node: Node, context: &QueryMatchContext) -> Option<Modifier> {
+    let is_readonly = node.has_child_of_kind("readonly");
+    Some(
+        match (
+            get_accessibility_modifier(node)
+                .map(|accessibility_modifier| accessibility_modifier.text(context))
+                .as_deref(),
+            is_readonly,
+        ) {
+            (None, false) => return None,
+            (None, true) => Modifier::Readonly,
+            (Some("private"), false) => Modifier::Private,
+            (Some("private"), true) => Modifier::PrivateReadonly,
+            (Some("protected"), false) => Modifier::Protected,
+            (Some("protected"), true) => Modifier::ProtectedReadonly,
+            (_, false) => Modifier::Public,
+            (_, true) => Modifier::PublicReadonly,
+        },
+    )
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Prefer {
+    #[default]
+    ClassProperty,
+    ParameterProperty,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    allow: Option<Vec<Modifier>>,
+    prefer: Option<Prefer>,
+}
+
+impl Options {
+    fn allow(&self) -> Vec<Modifier> {
+        self.allow.clone().unwrap_or_default()
+    }
+
+    fn prefer(&self) -> Prefer {
+        self.prefer.unwrap_or_default()
+    }
+}
+
+#[derive(Copy, Clone, Default)]
+struct PropertyNodes<'a> {
+    class_property: Option<Node<'a>>,
+    constructor_parameter: Option<Node<'a>>,
+    constructor_assignment: Option<Node<'a>>,
+}
+
+/// The entry for `name`, appended if there isn't one yet (so entries are in
+/// the order names are first seen, which is the order they get reported in).
+fn get_property_nodes<'a, 'b>(
+    property_nodes_by_name: &'b mut Vec<(String, PropertyNodes<'a>)>,
+    name: String,
+) -> &'b mut PropertyNodes<'a> {
+    let index = match property_nodes_by_name
+        .iter()
+        .position(|(existing_name, _)| *existing_name == name)
+    {
+        Some(index) => index,
+        None => {
+            property_nodes_by_name.push((name, Default::default()));
+            property_nodes_by_name.len() - 1
+        }
+    };
+    &mut property_nodes_by_name[index].1
+}
+
+fn type_annotations_match<'a>(
+    class_property: Node<'a>,
+    constructor_parameter: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    match (
+        class_property.child_by_field_name("type"),
+        constructor_parameter.child_by_field_name("type"),
+    ) {
+        (Some(class_property_type), Some(constructor_parameter_type)) => are_types_equivalent(
+            class_property_type,
+            constructor_parameter_type,
+            None,
+            context,
+        ),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// The `this.foo = foo` statements at the start of the constructor's body,
+/// keyed by name.
+fn get_leading_constructor_assignments<'a>(
+    constructor: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Vec<(String, Node<'a>)> {
+    constructor
+        .field("body")
+        .non_comment_named_children(SupportedLanguage::Javascript)
+        .map_while(|statement| {
+            if statement.kind() != ExpressionStatement {
+                return None;
+            }
+            let assignment = statement.first_non_comment_named_child(SupportedLanguage::Javascript);
+            if assignment.kind() != AssignmentExpression {
+                return None;
+            }
+            let left = assignment.field("left");
+            let right = assignment.field("right");
+            if left.kind() != MemberExpression
+                || left.field("object").kind() != This
+                || left.field("property").kind() != PropertyIdentifier
+                || right.kind() != Identifier
+            {
+                return None;
+            }
+            Some((
+                left.field("property").text(context),
+                right.text(context),
+                statement,
+            ))
+        })
+        .filter(|(property_name, name, _)| property_name == name)
+        .map(|(_, name, statement)| (name.into_owned(), statement))
+        .collect()
+}
+
+/// From the end of whatever precedes `node` (so including any whitespace
+/// before it) through the end of `last`.
+fn get_removal_range(node: Node, last: Node) -> Range {
+    let previous = node.prev_sibling().unwrap();
+    Range {
+        start_byte: previous.end_byte(),
+        end_byte: last.end_byte(),
+        start_point: previous.end_position(),
+        end_point: last.end_position(),
+    }
+}
+
+pub fn parameter_properties_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "parameter-properties",
+        languages => [Typescript],
+        messages => [
+            prefer_class_property => "Property {{parameter}} should be declared as a class property.",
+            prefer_parameter_property => "Property {{parameter}} should be declared as a parameter property.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            allow: Vec<Modifier> = options.allow(),
+            prefer: Prefer = options.prefer(),
+        },
+        listeners => [
+            r#"
+              (required_parameter) @c
+              (optional_parameter) @c
+            "# => |node, context| {
+                if self.prefer != Prefer::ClassProperty {
+                    return;
+                }
+                let Some(modifier) = get_modifier(node, context) else {
+                    return;
+                };
+                if self.allow.contains(&modifier) {
+                    return;
+                }
+                let pattern = node.field("pattern");
+                // (TS only allows identifiers, possibly with a default value)
+                if pattern.kind() != Identifier {
+                    return;
+                }
+                context.report(violation! {
+                    node => node,
+                    message_id => "prefer_class_property",
+                    data => {
+                        parameter => pattern.text(context),
+                        banned_name => "parameter-property",
+                        replacement => "class-property",
+                        option => "prefer",
+                    },
+                });
+            },
+            r#"
+              (class_body) @c
+            "# => |node, context| {
+                if self.prefer != Prefer::ParameterProperty {
+                    return;
+                }
+                let mut property_nodes_by_name: Vec<(String, PropertyNodes<'a>)> = Default::default();
+                for member in node.non_comment_named_children(SupportedLanguage::Javascript) {
+                    match member.kind() {
+                        PublicFieldDefinition => {
+                            if member.field("name").kind() != PropertyIdentifier
+                                || member.child_by_field_name("value").is_some()
+                                || is_member_static(member)
+                                // (decorated fields have no parameter
+                                // property equivalent)
+                                || get_decorators(member).next().is_some()
+                                || get_modifier(member, context)
+                                    .is_some_and(|modifier| self.allow.contains(&modifier))
+                            {
+                                continue;
+                            }
+                            get_property_nodes(
+                                &mut property_nodes_by_name,
+                                member.field("name").text(context).into_owned(),
+                            )
+                            .class_property = Some(member);
+                        }
+                        MethodDefinition
+                            if get_method_definition_kind(member, context)
+                                == MethodDefinitionKind::Constructor =>
+                        {
+                            for parameter in member
+                                .field("parameters")
+                                .non_comment_named_children(SupportedLanguage::Javascript)
+                            {
+                                if !matches!(parameter.kind(), RequiredParameter | OptionalParameter)
+                                    || parameter.field("pattern").kind() != Identifier
+                                    || parameter.child_by_field_name("value").is_some()
+                                    || get_modifier(parameter, context).is_some()
+                                {
+                                    continue;
+                                }
+                                get_property_nodes(
+                                    &mut property_nodes_by_name,
+                                    parameter.field("pattern").text(context).into_owned(),
+                                )
+                                .constructor_parameter = Some(parameter);
+                            }
+                            for (name, statement) in
+                                get_leading_constructor_assignments(member, context)
+                            {
+                                get_property_nodes(&mut property_nodes_by_name, name)
+                                    .constructor_assignment = Some(statement);
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+
+                for (name, property_nodes) in &property_nodes_by_name {
+                    let PropertyNodes {
+                        class_property: Some(class_property),
+                        constructor_parameter: Some(constructor_parameter),
+                        constructor_assignment: Some(constructor_assignment),
+                    } = *property_nodes
+                    else {
+                        continue;
+                    };
+                    if !type_annotations_match(class_property, constructor_parameter, context) {
+                        continue;
+                    }
+                    context.report(violation! {
+                        node => class_property,
+                        message_id => "prefer_parameter_property",
+                        data => {
+                            parameter => name,
+                            banned_name => "class-property",
+                            replacement => "parameter-property",
+                            option => "prefer",
+                        },
+                        fix => |fixer| {
+                            let class_property_end = class_property
+                                .next_sibling()
+                                .filter(|next_sibling| next_sibling.kind() == ";")
+                                .unwrap_or(class_property);
+                            fixer.remove_range(get_removal_range(class_property, class_property_end));
+                            fixer.remove_range(get_removal_range(
+                                constructor_assignment,
+                                constructor_assignment,
+                            ));
+                            // (a parameter property needs at least one
+                            // modifier)
+                            fixer.insert_text_before(
+                                constructor_parameter,
+                                format!(
+                                    "{} ",
+                                    get_modifier(class_property, context)
+                                        .unwrap_or(Modifier::Public)
+                                        .as_str()
+                                ),
+                            );
+                        },
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn parameter_properties_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "class Foo {}",
+              "class Foo { private constructor() {} }",
+              "class Foo { constructor(name: string) {} }",
+              "class Foo { constructor(name: string = 'x') {} }",
+              {
+                code => "class Foo { constructor(readonly name: string) {} }",
+                options => { allow => ["readonly"] },
+              },
+              {
+                code => "class Foo { constructor(private readonly name: string, protected age?: number) {} }",
+                options => { allow => ["private readonly", "protected"] },
+              },
+              {
+                code => "class Foo { constructor(private name: string) {} }",
+                options => { prefer => "parameter-property" },
+              },
+              {
+                code => r#"
+class Foo {
+  member: string;
+  constructor(member: string) {}
+}
+                    "#,
+                options => { prefer => "parameter-property" },
+              },
+              {
+                code => r#"
+class Foo {
+  member: string;
+  constructor(member: string) {
+    log();
+    this.member = member;
+  }
+}
+                    "#,
+                options => { prefer => "parameter-property" },
+              },
+              {
+                code => r#"
+class Foo {
+  member: string;
+  constructor(member: number) {
+    this.member = member;
+  }
+}
+                    "#,
+                options => { prefer => "parameter-property" },
+              },
+              {
+                code => r#"
+class Foo {
+  member = '';
+  constructor(member: string) {
+    this.member = member;
+  }
+}
+                    "#,
+                options => { prefer => "parameter-property" },
+              },
+              {
+                code => r#"
+class Foo {
+  member: string;
+  constructor(other: string) {
+    this.member = other;
+  }
+}
+                    "#,
+                options => { prefer => "parameter-property" },
+              },
+              {
+                code => r#"
+class Foo {
+  static member: string;
+  constructor(member: string) {
+    this.member = member;
+  }
+}
+                    "#,
+                options => { prefer => "parameter-property" },
+              },
+              {
+                code => r#"
+class Foo {
+  @Input() member: string;
+  constructor(member: string) {
+    this.member = member;
+  }
+}
+                    "#,
+                options => { prefer => "parameter-property" },
+              },
+              {
+                code => r#"
+class Foo {
+  private member: string;
+  constructor(member: string) {
+    this.member = member;
+  }
+}
+                    "#,
+                options => { prefer => "parameter-property", allow => ["private"] },
+              },
+            ],
+            invalid => [
+              {
+                code => "class Foo { constructor(readonly name: string) {} }",
+                errors => [
+                  {
+                    message_id => "prefer_class_property",
+                    data => {
+                      parameter => "name",
+                      banned_name => "parameter-property",
+                      replacement => "class-property",
+                      option => "prefer",
+                    },
+                    line => 1,
+                    column => 25,
+                    end_column => 46,
+                  },
+                ],
+              },
+              {
+                code => "class Foo { constructor(private name?: string) {} }",
+                errors => [
+                  {
+                    message_id => "prefer_class_property",
+                    data => {
+                      parameter => "name",
+                      banned_name => "parameter-property",
+                      replacement => "class-property",
+                      option => "prefer",
+                    },
+                  },
+                ],
+              },
+              {
+                code => "class Foo { constructor(public name: string = 'x') {} }",
+                errors => [
+                  {
+                    message_id => "prefer_class_property",
+                    data => {
+                      parameter => "name",
+                      banned_name => "parameter-property",
+                      replacement => "class-property",
+                      option => "prefer",
+                    },
+                  },
+                ],
+              },
+              {
+                code => "class Foo { constructor(private readonly name: string) {} }",
+                options => { allow => ["readonly"] },
+                errors => [
+                  {
+                    message_id => "prefer_class_property",
+                    data => {
+                      parameter => "name",
+                      banned_name => "parameter-property",
+                      replacement => "class-property",
+                      option => "prefer",
+                    },
+                  },
+                ],
+              },
+              {
+                code => r#"
+class Foo {
+  member: string;
+
+  constructor(member: string) {
+    this.member = member;
+  }
+}
+                    "#,
+                options => { prefer => "parameter-property" },
+                output => r#"
+class Foo {
+
+  constructor(public member: string) {
+  }
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "prefer_parameter_property",
+                    data => {
+                      parameter => "member",
+                      banned_name => "class-property",
+                      replacement => "parameter-property",
+                      option => "prefer",
+                    },
+                    line => 3,
+                    column => 3,
+                    end_line => 3,
+                    end_column => 17,
+                  },
+                ],
+              },
+              {
+                code => r#"
+class Foo {
+  member: (string|number);
+
+  constructor(member: string | number) {
+    this.member = member;
+  }
+}
+                    "#,
+                options => { prefer => "parameter-property" },
+                output => r#"
+class Foo {
+
+  constructor(public member: string | number) {
+  }
+}
+                    "#,
+                errors => [{ message_id => "prefer_parameter_property", line => 3 }],
+              },
+              {
+                code => r#"
+class Foo {
+  private readonly member?: string;
+  constructor(foo: number, member?: string) {
+    this.member = member;
+    init();
+  }
+}
+                    "#,
+                options => { prefer => "parameter-property" },
+                output => r#"
+class Foo {
+  constructor(foo: number, private readonly member?: string) {
+    init();
+  }
+}
+                    "#,
+                errors => [{ message_id => "prefer_parameter_property", line => 3 }],
+              },
+              {
+                code => r#"
+const Foo = class {
+  protected member;
+  constructor(member) {
+    this.member = member;
+  }
+};
+                    "#,
+                options => { prefer => "parameter-property" },
+                output => r#"
+const Foo = class {
+  constructor(protected member) {
+  }
+};
+                    "#,
+                errors => [
+                  {
+                    message_id => "prefer_parameter_property",
+                    data => {
+                      parameter => "member",
+                      banned_name => "class-property",
+                      replacement => "parameter-property",
+                      option => "prefer",
+                    },
+                  },
+                ],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_parameter_properties_rule() {
+        run_for_typescript_and_tsx(
+            parameter_properties_rule(),
+            test_cases::parameter_properties_rule_tests(),
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    ArrayPattern, AssignmentExpression, AssignmentPattern, AugmentedAssignmentExpression,
+    BinaryExpression, CallExpression, ForInStatement, Identifier, MemberExpression, Number,
+    PairPattern, RestPattern, UnaryExpression, UpdateExpression,
+};
+
+use crate::{
+    ast_helpers::is_same_expression,
+    util::{parse_numeric_literal, skip_parenthesized_expressions},
+};
+
+/// `n` in `[receiver.length - n]`, if it's a positive integer literal (since
+/// eg `foo[foo.length - 0]` is `undefined` but `foo.at(-0)` isn't).
+fn get_index_from_end<'a>(
+    receiver: Node<'a>,
+    index: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    let index = skip_parenthesized_expressions(index);
+    if index.kind() != BinaryExpression || index.field("operator").kind() != "-" {
+        return None;
+    }
+    let length = skip_parenthesized_expressions(index.field("left"));
+    if length.kind() != MemberExpression
+        || length.child_by_field_name("optional_chain").is_some()
+        || length.field("property").text(context) != "length"
+        || !is_same_expression(length.field("object"), receiver, context)
+    {
+        return None;
+    }
+    let offset = skip_parenthesized_expressions(index.field("right"));
+    if offset.kind() != Number {
+        return None;
+    }
+    let value = parse_numeric_literal(&offset.text(context))?
+        .value
+        .to_number();
+    (value > 0. && value.fract() == 0.).then_some(offset)
+}
+
+/// `.at()` only reads, and calling it as a method would change `this`.
+fn is_read_only_usage(node: Node) -> bool {
+    let parent = node.parent().unwrap();
+    match parent.kind() {
+        AssignmentExpression
+        | AugmentedAssignmentExpression
+        | AssignmentPattern
+        | ForInStatement => parent.field("left") != node,
+        // a destructuring assignment target, eg `[foo[foo.length - 1]] = bar`
+        ArrayPattern | PairPattern | RestPattern => false,
+        UpdateExpression => false,
+        UnaryExpression => parent.field("operator").kind() != "delete",
+        CallExpression => parent.field("function") != node,
+        _ => true,
+    }
+}
+
+pub fn prefer_array_index_at_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "prefer-array-index-at",
+        languages => [Typescript],
+        messages => [
+            prefer_at => "Prefer `.at(…)` over `[….length - index]`.",
+        ],
+        fixable => true,
+        listeners => [
+            r#"
+              (subscript_expression) @c
+            "# => |node, context| {
+                if node.child_by_field_name("optional_chain").is_some() {
+                    return;
+                }
+                let receiver = node.field("object");
+                let Some(offset) = get_index_from_end(receiver, node.field("index"), context) else {
+                    return;
+                };
+                if !is_read_only_usage(node) {
+                    return;
+                }
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "prefer_at",
+                    fix => |fixer| {
+                        // TODO: this should be a suggestion (at least when the
+                        // receiver isn't known to be an array, since eg a
+                        // `NodeList` doesn't have `.at()`), but rewriting
+                        // anything other than a plain variable could also
+                        // change how many times the receiver gets evaluated
+                        let receiver_inner = skip_parenthesized_expressions(receiver);
+                        if receiver_inner.kind() != Identifier
+                            || receiver_inner.text(context) == "arguments"
+                        {
+                            return;
+                        }
+
+                        fixer.replace_text(
+                            node,
+                            format!("{}.at(-{})", receiver.text(context), offset.text(context)),
+                        );
+                    },
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn prefer_array_index_at_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "foo[0];",
+              "foo[foo.length];",
+              "foo[foo.length - 0];",
+              "foo[bar.length - 1];",
+              "foo[foo.length + 1];",
+              "foo[foo.length - 1.5];",
+              "foo[foo.length - i];",
+              "foo[foo.size - 1];",
+              "foo?.[foo.length - 1];",
+              "foo[foo?.length - 1];",
+              "foo[foo.length - 1] = 1;",
+              "foo[foo.length - 1] += 1;",
+              "foo[foo.length - 1]++;",
+              "delete foo[foo.length - 1];",
+              "foo[foo.length - 1]();",
+              "[foo[foo.length - 1]] = bar;",
+              "[...foo[foo.length - 1]] = bar;",
+              "[foo[foo.length - 1] = 1] = bar;",
+              "({ baz: foo[foo.length - 1] } = bar);",
+              "for (foo[foo.length - 1] of bar);",
+              "for (foo[foo.length - 1] in bar);",
+              "foo.bar[foo.baz.length - 1];",
+            ],
+            invalid => [
+              {
+                code => "const last = foo[foo.length - 1];",
+                output => "const last = foo.at(-1);",
+                errors => [{ message_id => "prefer_at", line => 1, column => 14 }],
+              },
+              {
+                code => "bar(foo[foo.length - 2]);",
+                output => "bar(foo.at(-2));",
+                errors => [{ message_id => "prefer_at" }],
+              },
+              {
+                code => "const last = (foo)[(foo).length - (1)];",
+                output => "const last = (foo).at(-1);",
+                errors => [{ message_id => "prefer_at" }],
+              },
+              {
+                code => "foo[foo.length - 1].bar();",
+                output => "foo.at(-1).bar();",
+                errors => [{ message_id => "prefer_at" }],
+              },
+              {
+                code => "const last = this.items[this.items.length - 1];",
+                errors => [{ message_id => "prefer_at", column => 14 }],
+              },
+              {
+                code => "const last = foo.bar['baz'][foo /* same */ .bar[ 'baz' ].length - 1];",
+                errors => [{ message_id => "prefer_at" }],
+              },
+              {
+                code => "const last = foo[i][foo[i].length - 1];",
+                errors => [{ message_id => "prefer_at" }],
+              },
+              {
+                code => "const last = foo()[foo().length - 1];",
+                errors => [{ message_id => "prefer_at" }],
+              },
+              {
+                code => "const last = arguments[arguments.length - 1];",
+                errors => [{ message_id => "prefer_at" }],
+              },
+              {
+                code => "const last = foo[foo.length - 1_0];",
+                output => "const last = foo.at(-1_0);",
+                errors => [{ message_id => "prefer_at" }],
+              },
+              {
+                code => "[foo] = [foo[foo.length - 1]];",
+                output => "[foo] = [foo.at(-1)];",
+                errors => [{ message_id => "prefer_at" }],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_prefer_array_index_at_rule() {
+        run_for_typescript_and_tsx(
+            prefer_array_index_at_rule(),
+            test_cases::prefer_array_index_at_rule_tests(),
+        );
+    }
+}
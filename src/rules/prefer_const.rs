@@ -0,0 +1,300 @@
+use std::{borrow::Cow, collections::HashSet, sync::Arc};
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    ArrayPattern, AssignmentExpression, AugmentedAssignmentExpression, ExportStatement,
+    ForInStatement, Identifier, ObjectPattern, ParenthesizedExpression, StatementBlock,
+    UpdateExpression, VariableDeclarator,
+};
+
+use crate::{
+    ast_helpers::get_pattern_identifiers,
+    kind::{AmbientDeclaration, InternalModule, Module},
+};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Destructuring {
+    #[default]
+    Any,
+    All,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    destructuring: Option<Destructuring>,
+}
+
+impl Options {
+    fn destructuring(&self) -> Destructuring {
+        self.destructuring.unwrap_or_default()
+    }
+}
+
+fn get_assignment_target_identifiers(node: Node) -> Vec<Node> {
+    match node.kind() {
+        Identifier => vec![node],
+        ArrayPattern | ObjectPattern => get_pattern_identifiers(node),
+        ParenthesizedExpression => get_assignment_target_identifiers(
+            node.first_non_comment_named_child(SupportedLanguage::Javascript),
+        ),
+        _ => vec![],
+    }
+}
+
+/// The names of everything (re)assigned anywhere within `node`. Shadowing
+/// isn't taken into account, which errs on the side of not reporting.
+fn collect_assigned_names<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+    names: &mut HashSet<Cow<'a, str>>,
+) {
+    let target = match node.kind() {
+        AssignmentExpression | AugmentedAssignmentExpression => Some(node.field("left")),
+        UpdateExpression => Some(node.field("argument")),
+        ForInStatement if node.child_by_field_name("kind").is_none() => Some(node.field("left")),
+        _ => None,
+    };
+    if let Some(target) = target {
+        names.extend(
+            get_assignment_target_identifiers(target)
+                .into_iter()
+                .map(|identifier| identifier.text(context)),
+        );
+    }
+    for child in node.non_comment_named_children(SupportedLanguage::Javascript) {
+        collect_assigned_names(child, context, names);
+    }
+}
+
+/// `declare let` (including anything inside `declare namespace`/`declare
+/// module`), and `export let` inside a namespace (which other code can
+/// reassign as a property of the namespace).
+fn is_ignored_declaration(declaration: Node) -> bool {
+    let parent = declaration.parent().unwrap();
+    if parent.kind() == ExportStatement
+        && parent.parent().is_some_and(|grandparent| {
+            grandparent.kind() == StatementBlock
+                && grandparent
+                    .parent()
+                    .is_some_and(|module| matches!(module.kind(), InternalModule | Module))
+        })
+    {
+        return true;
+    }
+    declaration
+        .ancestors()
+        .any(|ancestor| ancestor.kind() == AmbientDeclaration)
+}
+
+pub fn prefer_const_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "prefer-const",
+        languages => [Typescript],
+        messages => [
+            use_const => "'{{name}}' is never reassigned. Use 'const' instead.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            destructuring: Destructuring = options.destructuring(),
+        },
+        methods => {
+            // `bindings` are the identifiers declared by each declarator (or
+            // by the `for`/`of` left side), `kind` is the `let` keyword
+            fn check(
+                &self,
+                kind: Node<'a>,
+                bindings: Vec<Vec<Node<'a>>>,
+                context: &QueryMatchContext<'a, '_>,
+            ) {
+                let declaration = kind.parent().unwrap();
+                if is_ignored_declaration(declaration) {
+                    return;
+                }
+                let scope = match declaration.kind() {
+                    ForInStatement => declaration,
+                    _ => declaration.parent().unwrap(),
+                };
+                let mut assigned_names: HashSet<Cow<'a, str>> = Default::default();
+                collect_assigned_names(scope, context, &mut assigned_names);
+
+                let mut to_report: Vec<Node<'a>> = Default::default();
+                let mut is_fixable = true;
+                for identifiers in bindings {
+                    let unassigned = identifiers
+                        .iter()
+                        .copied()
+                        .filter(|identifier| !assigned_names.contains(&identifier.text(context)))
+                        .collect::<Vec<_>>();
+                    if unassigned.len() != identifiers.len() {
+                        is_fixable = false;
+                        if self.destructuring == Destructuring::All {
+                            continue;
+                        }
+                    }
+                    to_report.extend(unassigned);
+                }
+
+                for identifier in to_report {
+                    context.report(violation! {
+                        node => identifier,
+                        message_id => "use_const",
+                        data => {
+                            name => identifier.text(context),
+                        },
+                        fix => |fixer| {
+                            if !is_fixable {
+                                return;
+                            }
+                            fixer.replace_text(kind, "const");
+                        },
+                    });
+                }
+            }
+        },
+        listeners => [
+            r#"
+              (lexical_declaration) @c
+            "# => |node, context| {
+                let kind = node.field("kind");
+                if kind.kind() != "let" {
+                    return;
+                }
+                let declarators = node
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                    .filter(|child| child.kind() == VariableDeclarator)
+                    .collect::<Vec<_>>();
+                // uninitialized declarations would need to check for a single
+                // later assignment
+                if declarators
+                    .iter()
+                    .any(|declarator| declarator.child_by_field_name("value").is_none())
+                {
+                    return;
+                }
+                self.check(
+                    kind,
+                    declarators
+                        .into_iter()
+                        .map(|declarator| get_pattern_identifiers(declarator.field("name")))
+                        .collect(),
+                    context,
+                );
+            },
+            r#"
+              (for_in_statement
+                kind: "let"
+              ) @c
+            "# => |node, context| {
+                self.check(
+                    node.field("kind"),
+                    vec![get_pattern_identifiers(node.field("left"))],
+                    context,
+                );
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn prefer_const_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "const x = 1;",
+              "let x;",
+              "let x = 1; x = 2;",
+              "let x = 1; x += 2;",
+              "let x = 1; x++;",
+              "let x = 1; function foo() { x = 2; }",
+              "let a = 1, b = 2; [a, b] = [b, a];",
+              "let a = 1; ({ a } = obj);",
+              "for (let i = 0; i < 10; i++) {}",
+              "for (let x of xs) { x = 2; }",
+              "let x = 1; for (x of xs) {}",
+              "declare let x: number;",
+              "declare namespace Foo { let x: number; }",
+              "declare module 'foo' { let x: number; }",
+              "namespace Foo { export let x = 1; }",
+              "module Foo { export let x = 1; }",
+              "let x = 1; namespace Foo { x = 2; }",
+              {
+                code => "let { a, b } = obj; a = 1;",
+                options => { destructuring => "all" },
+              },
+            ],
+            invalid => [
+              {
+                code => "let x = 1;",
+                output => "const x = 1;",
+                errors => [
+                  {
+                    message_id => "use_const",
+                    data => { name => "x" },
+                    line => 1,
+                    column => 5,
+                    end_line => 1,
+                    end_column => 6,
+                  },
+                ],
+              },
+              {
+                code => "let x = 1, y = 2;",
+                output => "const x = 1, y = 2;",
+                errors => [
+                  { message_id => "use_const", data => { name => "x" } },
+                  { message_id => "use_const", data => { name => "y" } },
+                ],
+              },
+              {
+                code => "let x = 1, y = 2; y = 3;",
+                errors => [{ message_id => "use_const", data => { name => "x" } }],
+              },
+              {
+                code => "let { a, b } = obj; a = 1;",
+                errors => [{ message_id => "use_const", data => { name => "b" } }],
+              },
+              {
+                code => "for (let x of xs) { foo(x); }",
+                output => "for (const x of xs) { foo(x); }",
+                errors => [{ message_id => "use_const", column => 10 }],
+              },
+              {
+                code => "export let x = 1;",
+                output => "export const x = 1;",
+                errors => [{ message_id => "use_const" }],
+              },
+              {
+                code => "namespace Foo { let x = 1; }",
+                output => "namespace Foo { const x = 1; }",
+                errors => [{ message_id => "use_const" }],
+              },
+              {
+                code => "enum Foo { A } let x = Foo.A;",
+                output => "enum Foo { A } const x = Foo.A;",
+                errors => [{ message_id => "use_const" }],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_prefer_const_rule() {
+        run_for_typescript_and_tsx(prefer_const_rule(), test_cases::prefer_const_rule_tests());
+    }
+}
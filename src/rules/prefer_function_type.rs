@@ -0,0 +1,524 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    ast_helpers::is_export_default,
+    kind::{Comment, ExportStatement},
+};
+
+use crate::{
+    ast_helpers::is_type_literal,
+    kind::{
+        ArrayType, CallSignature, ConstructSignature, ExtendsTypeClause, InterfaceDeclaration,
+        IntersectionType, ObjectType, ThisType, TypeIdentifier, UnionType,
+    },
+    util::IndentedText,
+};
+
+fn has_one_supertype(node: Node, context: &QueryMatchContext) -> bool {
+    let Some(extends) = node.maybe_first_child_of_kind(ExtendsTypeClause) else {
+        return true;
+    };
+    let mut supertypes = extends.non_comment_named_children(SupportedLanguage::Javascript);
+    match (supertypes.next(), supertypes.next()) {
+        (Some(supertype), None) => {
+            supertype.kind() == TypeIdentifier && supertype.text(context) == "Function"
+        }
+        _ => false,
+    }
+}
+
+fn should_wrap_suggestion(parent: Node) -> bool {
+    matches!(parent.kind(), UnionType | IntersectionType | ArrayType)
+}
+
+// `this` inside a nested type literal is invalid code anyway, so we don't
+// claim that it refers to the interface
+fn find_this_type(node: Node) -> Option<Node> {
+    if node.kind() == ThisType {
+        return Some(node);
+    }
+    if node.kind() == ObjectType {
+        return None;
+    }
+    node.non_comment_named_children(SupportedLanguage::Javascript)
+        .find_map(find_this_type)
+}
+
+fn get_return_type(member: Node) -> Option<Node> {
+    match member.kind() {
+        CallSignature => member.child_by_field_name("return_type"),
+        ConstructSignature => member.child_by_field_name("type"),
+        _ => None,
+    }
+}
+
+fn get_single_member(body: Node) -> Option<Node> {
+    let mut members = body.non_comment_named_children(SupportedLanguage::Javascript);
+    match (members.next(), members.next()) {
+        (Some(member), None) => Some(member),
+        _ => None,
+    }
+}
+
+pub fn prefer_function_type_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "prefer-function-type",
+        languages => [Typescript],
+        messages => [
+            function_type_over_callable_type => "{{literal_or_interface}} only has a call signature, you should use a function type instead.",
+            unexpected_this_on_function_only_interface => "`this` refers to the function type '{{interface_name}}', did you intend to use a generic `this` parameter like `<Self>(this: Self, ...) => Self` instead?",
+        ],
+        fixable => true,
+        methods => {
+            fn check_member(&self, member: Node<'a>, node: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+                let Some(return_type) = get_return_type(member) else {
+                    return;
+                };
+                let is_interface = node.kind() == InterfaceDeclaration;
+
+                if is_interface {
+                    // point directly at the (first) `this` rather than at the
+                    // whole member, since the message can be confusing otherwise
+                    if let Some(this_type) = find_this_type(member) {
+                        context.report(violation! {
+                            node => this_type,
+                            message_id => "unexpected_this_on_function_only_interface",
+                            data => {
+                                interface_name => node.field("name").text(context),
+                            },
+                        });
+                        return;
+                    }
+                }
+
+                let parent = node.parent().unwrap();
+                let fixable = !is_export_default(parent);
+                let body = if is_interface { node.field("body") } else { node };
+
+                context.report(violation! {
+                    node => member,
+                    message_id => "function_type_over_callable_type",
+                    data => {
+                        literal_or_interface => if is_interface { "Interface" } else { "Type literal" },
+                    },
+                    fix => |fixer| {
+                        if !fixable {
+                            return;
+                        }
+
+                        let text = member.text(context);
+                        let colon_pos = return_type.start_byte() - member.start_byte();
+                        let mut suggestion = format!("{} =>{}", &text[..colon_pos], &text[colon_pos + 1..]);
+                        let last_char = context
+                            .maybe_get_token_after(member, Option::<fn(Node) -> bool>::None)
+                            .filter(|token| token.kind() == ";")
+                            .map_or("", |_| ";");
+
+                        if should_wrap_suggestion(parent) {
+                            suggestion = format!("({suggestion})");
+                        }
+
+                        if is_interface {
+                            let name = format!(
+                                "{}{}",
+                                node.field("name").text(context),
+                                node.child_by_field_name("type_parameters")
+                                    .map(|type_parameters| type_parameters.text(context))
+                                    .unwrap_or_default(),
+                            );
+                            suggestion = format!("type {name} = {suggestion}{last_char}");
+                        }
+
+                        let comments = body
+                            .children(&mut body.walk())
+                            .filter(|child| child.kind() == Comment)
+                            .collect::<Vec<_>>();
+                        if is_interface && parent.kind() == ExportStatement {
+                            // comments should move before the `export`, not
+                            // between it and the declaration
+                            let mut comments_text = IndentedText::new(parent, context);
+                            for comment in &comments {
+                                comments_text.push(&comment.text(context)).newline(0);
+                            }
+                            fixer.insert_text_before(parent, comments_text.build());
+                        } else {
+                            let mut comments_text = IndentedText::new(node, context);
+                            for comment in &comments {
+                                let is_line_comment = comment.text(context).starts_with("//");
+                                let is_on_same_line = comment.start_position().row
+                                    == member.start_position().row;
+                                comments_text.push(&comment.text(context));
+                                if is_on_same_line && !is_line_comment {
+                                    comments_text.push(" ");
+                                } else {
+                                    comments_text.newline(0);
+                                }
+                            }
+                            suggestion = format!("{}{suggestion}", comments_text.build());
+                        }
+
+                        fixer.replace_text(node, suggestion);
+                    }
+                });
+            }
+        },
+        listeners => [
+            r#"
+              (interface_declaration) @c
+            "# => |node, context| {
+                let Some(member) = get_single_member(node.field("body")) else {
+                    return;
+                };
+                if !has_one_supertype(node, context) {
+                    return;
+                }
+
+                self.check_member(member, node, context);
+            },
+            r#"
+              (object_type) @c
+            "# => |node, context| {
+                if !is_type_literal(node) {
+                    return;
+                }
+                let Some(member) = get_single_member(node) else {
+                    return;
+                };
+
+                self.check_member(member, node, context);
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn prefer_function_type_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "
+interface Foo {
+  (): void;
+  bar: number;
+}
+                    ",
+                "
+type Foo = {
+  (): void;
+  bar: number;
+};
+                    ",
+                "
+function foo(bar: { (): string; baz: number }): string {
+  return bar();
+}
+                    ",
+                "
+interface Foo {
+  bar: string;
+}
+interface Bar extends Foo {
+  (): void;
+}
+                    ",
+                "
+interface Foo {
+  bar: string;
+}
+interface Bar extends Function, Foo {
+  (): void;
+}
+                    ",
+                "
+interface Foo {
+  bar(): void;
+}
+                    ",
+            ],
+            invalid => [
+                {
+                  code => "
+interface Foo {
+  (): string;
+}
+                      ",
+                  output => "
+type Foo = () => string;
+                      ",
+                  errors => [
+                    {
+                      message_id => "function_type_over_callable_type",
+                      data => { literal_or_interface => "Interface" },
+                      line => 3,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => "
+// isn't actually valid ts but want to not give message saying it refers to Foo.
+interface Foo {
+  (): {
+    a: {
+      nested: this;
+    };
+    between: this;
+    b: {
+      nested: string;
+    };
+  };
+}
+                      ",
+                  output => "
+// isn't actually valid ts but want to not give message saying it refers to Foo.
+type Foo = () => {
+    a: {
+      nested: this;
+    };
+    between: this;
+    b: {
+      nested: string;
+    };
+  };
+                      ",
+                  errors => [
+                    { message_id => "function_type_over_callable_type" },
+                  ],
+                },
+                {
+                  code => "
+type Foo = {
+  (): string;
+};
+                      ",
+                  output => "
+type Foo = () => string;
+                      ",
+                  errors => [
+                    {
+                      message_id => "function_type_over_callable_type",
+                      data => { literal_or_interface => "Type literal" },
+                    },
+                  ],
+                },
+                {
+                  code => "
+function foo(bar: { (s: string): number }): number {
+  return bar('hello');
+}
+                      ",
+                  output => "
+function foo(bar: (s: string) => number): number {
+  return bar('hello');
+}
+                      ",
+                  errors => [{ message_id => "function_type_over_callable_type" }],
+                },
+                {
+                  code => "
+function foo(bar: { (s: string): number } | undefined): number {
+  return bar('hello');
+}
+                      ",
+                  output => "
+function foo(bar: ((s: string) => number) | undefined): number {
+  return bar('hello');
+}
+                      ",
+                  errors => [{ message_id => "function_type_over_callable_type" }],
+                },
+                {
+                  code => "
+interface Foo extends Function {
+  (): void;
+}
+                      ",
+                  output => "
+type Foo = () => void;
+                      ",
+                  errors => [{ message_id => "function_type_over_callable_type" }],
+                },
+                {
+                  code => "
+interface Foo<T> {
+  (bar: T): string;
+}
+                      ",
+                  output => "
+type Foo<T> = (bar: T) => string;
+                      ",
+                  errors => [{ message_id => "function_type_over_callable_type" }],
+                },
+                {
+                  code => "
+interface Foo<T> {
+  (this: T): void;
+}
+                      ",
+                  output => "
+type Foo<T> = (this: T) => void;
+                      ",
+                  errors => [{ message_id => "function_type_over_callable_type" }],
+                },
+                {
+                  code => "
+type Foo<T> = { (this: string): T };
+                      ",
+                  output => "
+type Foo<T> = (this: string) => T;
+                      ",
+                  errors => [{ message_id => "function_type_over_callable_type" }],
+                },
+                {
+                  code => "
+interface Foo {
+  (arg: this): void;
+}
+                      ",
+                  errors => [
+                    {
+                      message_id => "unexpected_this_on_function_only_interface",
+                      data => { interface_name => "Foo" },
+                      line => 3,
+                      column => 9,
+                    },
+                  ],
+                },
+                {
+                  code => "
+interface Foo {
+  (arg: number): this | undefined;
+}
+                      ",
+                  errors => [
+                    {
+                      message_id => "unexpected_this_on_function_only_interface",
+                      data => { interface_name => "Foo" },
+                      line => 3,
+                      column => 18,
+                    },
+                  ],
+                },
+                {
+                  code => "
+interface Foo {
+  // isn't actually valid ts but want to not give message saying it refers to Foo.
+  (): {
+    a: {
+      nested: this;
+    };
+  };
+}
+                      ",
+                  output => "
+// isn't actually valid ts but want to not give message saying it refers to Foo.
+type Foo = () => {
+    a: {
+      nested: this;
+    };
+  };
+                      ",
+                  errors => [{ message_id => "function_type_over_callable_type" }],
+                },
+                {
+                  code => "
+export interface Foo {
+  /** comment */
+  (): void;
+}
+                      ",
+                  output => "
+/** comment */
+export type Foo = () => void;
+                      ",
+                  errors => [{ message_id => "function_type_over_callable_type" }],
+                },
+                {
+                  code => "
+export interface Foo {
+  // comment 1
+  // comment 2
+  (): void;
+}
+                      ",
+                  output => "
+// comment 1
+// comment 2
+export type Foo = () => void;
+                      ",
+                  errors => [{ message_id => "function_type_over_callable_type" }],
+                },
+                {
+                  code => "
+/** leading */
+interface Foo {
+  /* inline */ (): void; // trailing
+}
+                      ",
+                  output => "
+/** leading */
+/* inline */ // trailing
+type Foo = () => void;
+                      ",
+                  errors => [{ message_id => "function_type_over_callable_type" }],
+                },
+                {
+                  code => "
+declare namespace foo {
+  export interface Foo {
+    // comment
+    (): void;
+  }
+}
+                      ",
+                  output => "
+declare namespace foo {
+  // comment
+  export type Foo = () => void;
+}
+                      ",
+                  errors => [{ message_id => "function_type_over_callable_type" }],
+                },
+                {
+                  code => "
+export default interface Foo {
+  (): void;
+}
+                      ",
+                  errors => [{ message_id => "function_type_over_callable_type" }],
+                },
+                {
+                  code => "
+interface Foo {
+  new (): Foo;
+}
+                      ",
+                  output => "
+type Foo = new () => Foo;
+                      ",
+                  errors => [{ message_id => "function_type_over_callable_type" }],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_prefer_function_type_rule() {
+        run_for_typescript_and_tsx(
+            prefer_function_type_rule(),
+            test_cases::prefer_function_type_rule_tests(),
+        );
+    }
+}
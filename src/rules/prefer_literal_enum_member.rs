@@ -0,0 +1,588 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use squalid::OptionExt;
+use tree_sitter_lint::{rule, tree_sitter::Node, violation, NodeExt, QueryMatchContext, Rule};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    assert_kind,
+    kind::{
+        is_literal_kind, BinaryExpression, Identifier, MemberExpression, PropertyIdentifier,
+        SubscriptExpression, UnaryExpression,
+    },
+    utils::ast_utils::get_static_string_value,
+};
+
+use crate::{
+    ast_helpers::find_enum_member,
+    kind::EnumDeclaration,
+    util::{is_signed_literal, is_static_literal, skip_parenthesized_expressions},
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    allow_bitwise_expressions: Option<bool>,
+}
+
+impl Options {
+    fn allow_bitwise_expressions(&self) -> bool {
+        self.allow_bitwise_expressions.unwrap_or_default()
+    }
+}
+
+fn has_enum_member<'a>(
+    declaration: Node<'a>,
+    name: &str,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    find_enum_member(declaration, name, context).is_some()
+}
+
+fn is_self_enum_member<'a>(
+    declaration: Node<'a>,
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    assert_kind!(declaration, EnumDeclaration);
+
+    let node = skip_parenthesized_expressions(node);
+    let is_enum_name = |object: Node<'a>| {
+        object.kind() == Identifier
+            && object.text(context) == declaration.field("name").text(context)
+    };
+    match node.kind() {
+        Identifier => has_enum_member(declaration, &node.text(context), context),
+        MemberExpression if is_enum_name(node.field("object")) => {
+            let property = node.field("property");
+            property.kind() == PropertyIdentifier
+                && has_enum_member(declaration, &property.text(context), context)
+        }
+        SubscriptExpression if is_enum_name(node.field("object")) => {
+            get_static_string_value(node.field("index"), context)
+                .matches(|property_name| has_enum_member(declaration, &property_name, context))
+        }
+        _ => false,
+    }
+}
+
+fn is_allowed_bitwise_operand<'a>(
+    declaration: Node<'a>,
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    is_literal_kind(skip_parenthesized_expressions(node).kind())
+        || is_self_enum_member(declaration, node, context)
+}
+
+pub fn prefer_literal_enum_member_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "prefer-literal-enum-member",
+        languages => [Typescript],
+        messages => [
+            define_initializer => "The value of a member initializer must be a literal.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            allow_bitwise_expressions: bool = options.allow_bitwise_expressions(),
+        },
+        listeners => [
+            r#"
+              (enum_assignment) @c
+            "# => |node, context| {
+                let initializer = skip_parenthesized_expressions(node.field("value"));
+
+                // any old literal or template literal without interpolation
+                if is_static_literal(initializer) {
+                    return;
+                }
+
+                // -1 and +1
+                if is_signed_literal(initializer) {
+                    return;
+                }
+
+                let declaration = node.parent().unwrap().parent().unwrap();
+
+                if self.allow_bitwise_expressions {
+                    match initializer.kind() {
+                        UnaryExpression => {
+                            if initializer.field("operator").kind() == "~" &&
+                                is_allowed_bitwise_operand(declaration, initializer.field("argument"), context) {
+                                return;
+                            }
+                        }
+                        BinaryExpression => {
+                            if matches!(
+                                initializer.field("operator").kind(),
+                                "|" | "&" | "^" | "<<" | ">>" | ">>>"
+                            ) && is_allowed_bitwise_operand(declaration, initializer.field("left"), context) &&
+                                is_allowed_bitwise_operand(declaration, initializer.field("right"), context) {
+                                return;
+                            }
+                        }
+                        _ => ()
+                    }
+                }
+
+                context.report(violation! {
+                    node => node.field("name"),
+                    message_id => "define_initializer",
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn prefer_literal_enum_member_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              r#"
+enum ValidRegex {
+  A = /test/,
+}
+                  "#,
+              r#"
+enum ValidString {
+  A = 'test',
+}
+                  "#,
+              r#"
+enum ValidLiteral {
+  A = `test`,
+}
+                  "#,
+              r#"
+enum ValidNumber {
+  A = 42,
+}
+                  "#,
+              r#"
+enum ValidNumber {
+  A = -42,
+}
+                  "#,
+              r#"
+enum ValidNumber {
+  A = +42,
+}
+                  "#,
+              r#"
+enum ValidNull {
+  A = null,
+}
+                  "#,
+              r#"
+enum ValidPlain {
+  A,
+}
+                  "#,
+              r#"
+enum ValidQuotedKey {
+  'a',
+}
+                  "#,
+              r#"
+enum ValidQuotedKeyWithAssignment {
+  'a' = 1,
+}
+                  "#,
+              r#"
+enum ValidKeyWithComputedSyntaxButNoBrackets {
+  ['a'] = 1,
+}
+                  "#,
+              r#"
+enum ValidParenthesized {
+  A = (1),
+  B = -(2),
+}
+                  "#,
+              {
+                code => r#"
+enum Foo {
+  A = 1 << 0,
+  B = 1 >> 0,
+  C = 1 >>> 0,
+  D = 1 | 0,
+  E = 1 & 0,
+  F = 1 ^ 0,
+  G = ~1,
+}
+                    "#,
+                options => { allow_bitwise_expressions => true },
+              },
+              {
+                code => r#"
+enum Foo {
+  A = 1 << 0,
+  B = A >> 0,
+  C = A >>> 0,
+  D = A | 0,
+  E = A & 0,
+  F = A ^ 0,
+  G = ~A,
+}
+                    "#,
+                options => { allow_bitwise_expressions => true },
+              },
+              {
+                code => r#"
+enum Foo {
+  A = 1 << 0,
+  B = 1 << 1,
+  C = A | B,
+}
+                    "#,
+                options => { allow_bitwise_expressions => true },
+              },
+              {
+                code => r#"
+enum Foo {
+  A = 1 << 0,
+  B = 1 << 1,
+  C = Foo.A | Foo.B,
+  D = Foo['A'] | Foo['B'],
+}
+                    "#,
+                options => { allow_bitwise_expressions => true },
+              },
+              {
+                code => r#"
+enum Foo {
+  'A' = 1 << 0,
+  ['B'] = 1 << 1,
+  C = A | B,
+}
+                    "#,
+                options => { allow_bitwise_expressions => true },
+              },
+            ],
+            invalid => [
+              {
+                code => r#"
+enum InvalidObject {
+  A = {},
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "define_initializer",
+                    line => 3,
+                    column => 3,
+                    end_line => 3,
+                    end_column => 4,
+                  },
+                ],
+              },
+              {
+                code => r#"
+enum InvalidArray {
+  A = [],
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "define_initializer",
+                    line => 3,
+                    column => 3,
+                    end_line => 3,
+                    end_column => 4,
+                  },
+                ],
+              },
+              {
+                code => r#"
+enum InvalidTemplateLiteral {
+  A = `foo ${0}`,
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "define_initializer",
+                    line => 3,
+                    column => 3,
+                    end_line => 3,
+                    end_column => 4,
+                  },
+                ],
+              },
+              {
+                code => r#"
+enum InvalidConstructor {
+  A = new Set(),
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "define_initializer",
+                    line => 3,
+                    column => 3,
+                    end_line => 3,
+                    end_column => 4,
+                  },
+                ],
+              },
+              {
+                code => r#"
+enum InvalidExpression {
+  A = 2 + 2,
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "define_initializer",
+                    line => 3,
+                    column => 3,
+                    end_line => 3,
+                    end_column => 4,
+                  },
+                ],
+              },
+              {
+                code => r#"
+enum InvalidExpression {
+  A = delete 2,
+  B = -a,
+  C = void 2,
+  D = ~2,
+  E = !0,
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "define_initializer",
+                    line => 3,
+                    column => 3,
+                    end_line => 3,
+                    end_column => 4,
+                  },
+                  {
+                    message_id => "define_initializer",
+                    line => 4,
+                    column => 3,
+                    end_line => 4,
+                    end_column => 4,
+                  },
+                  {
+                    message_id => "define_initializer",
+                    line => 5,
+                    column => 3,
+                    end_line => 5,
+                    end_column => 4,
+                  },
+                  {
+                    message_id => "define_initializer",
+                    line => 6,
+                    column => 3,
+                    end_line => 6,
+                    end_column => 4,
+                  },
+                  {
+                    message_id => "define_initializer",
+                    line => 7,
+                    column => 3,
+                    end_line => 7,
+                    end_column => 4,
+                  },
+                ],
+              },
+              {
+                code => r#"
+const variable = 'Test';
+enum InvalidVariable {
+  A = 'TestStr',
+  B = 2,
+  C,
+  V = variable,
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "define_initializer",
+                    line => 7,
+                    column => 3,
+                    end_line => 7,
+                    end_column => 4,
+                  },
+                ],
+              },
+              {
+                code => r#"
+enum Foo {
+  A = 1 << 0,
+  B = 1 >> 0,
+  C = 1 >>> 0,
+  D = 1 | 0,
+  E = 1 & 0,
+  F = 1 ^ 0,
+  G = ~1,
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "define_initializer",
+                    line => 3,
+                    column => 3,
+                    end_line => 3,
+                    end_column => 4,
+                  },
+                  {
+                    message_id => "define_initializer",
+                    line => 4,
+                    column => 3,
+                    end_line => 4,
+                    end_column => 4,
+                  },
+                  {
+                    message_id => "define_initializer",
+                    line => 5,
+                    column => 3,
+                    end_line => 5,
+                    end_column => 4,
+                  },
+                  {
+                    message_id => "define_initializer",
+                    line => 6,
+                    column => 3,
+                    end_line => 6,
+                    end_column => 4,
+                  },
+                  {
+                    message_id => "define_initializer",
+                    line => 7,
+                    column => 3,
+                    end_line => 7,
+                    end_column => 4,
+                  },
+                  {
+                    message_id => "define_initializer",
+                    line => 8,
+                    column => 3,
+                    end_line => 8,
+                    end_column => 4,
+                  },
+                  {
+                    message_id => "define_initializer",
+                    line => 9,
+                    column => 3,
+                    end_line => 9,
+                    end_column => 4,
+                  },
+                ],
+              },
+              {
+                code => r#"
+const x = 1;
+enum Foo {
+  A = x << 0,
+  B = x >> 0,
+  C = x >>> 0,
+  D = x | 0,
+  E = x & 0,
+  F = x ^ 0,
+  G = ~x,
+}
+                    "#,
+                options => { allow_bitwise_expressions => true },
+                errors => [
+                  {
+                    message_id => "define_initializer",
+                    line => 4,
+                    column => 3,
+                    end_line => 4,
+                    end_column => 4,
+                  },
+                  {
+                    message_id => "define_initializer",
+                    line => 5,
+                    column => 3,
+                    end_line => 5,
+                    end_column => 4,
+                  },
+                  {
+                    message_id => "define_initializer",
+                    line => 6,
+                    column => 3,
+                    end_line => 6,
+                    end_column => 4,
+                  },
+                  {
+                    message_id => "define_initializer",
+                    line => 7,
+                    column => 3,
+                    end_line => 7,
+                    end_column => 4,
+                  },
+                  {
+                    message_id => "define_initializer",
+                    line => 8,
+                    column => 3,
+                    end_line => 8,
+                    end_column => 4,
+                  },
+                  {
+                    message_id => "define_initializer",
+                    line => 9,
+                    column => 3,
+                    end_line => 9,
+                    end_column => 4,
+                  },
+                  {
+                    message_id => "define_initializer",
+                    line => 10,
+                    column => 3,
+                    end_line => 10,
+                    end_column => 4,
+                  },
+                ],
+              },
+              {
+                code => r#"
+const x = 1;
+enum Foo {
+  A = Bar.A | 0,
+  B = Foo.C | 0,
+  C = 1 + 0,
+}
+                    "#,
+                options => { allow_bitwise_expressions => true },
+                errors => [
+                  {
+                    message_id => "define_initializer",
+                    line => 4,
+                    column => 3,
+                    end_line => 4,
+                    end_column => 4,
+                  },
+                  {
+                    message_id => "define_initializer",
+                    line => 6,
+                    column => 3,
+                    end_line => 6,
+                    end_column => 4,
+                  },
+                ],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_prefer_literal_enum_member_rule() {
+        run_for_typescript_and_tsx(
+            prefer_literal_enum_member_rule(),
+            test_cases::prefer_literal_enum_member_rule_tests(),
+        );
+    }
+}
@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use tree_sitter_lint::{rule, violation, Rule};
+
+use crate::{
+    ast_helpers::{get_module_declaration_keyword, is_external_module_declaration},
+    kind::AmbientDeclaration,
+};
+
+pub fn prefer_namespace_keyword_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "prefer-namespace-keyword",
+        languages => [Typescript],
+        messages => [
+            use_namespace => "Use 'namespace' instead of 'module' to declare custom TypeScript modules.",
+        ],
+        fixable => true,
+        listeners => [
+            // `namespace foo {}` is an `internal_module` and `declare global
+            // {}` isn't a module declaration at all, so only `module` needs
+            // looking at
+            r#"
+              (module) @c
+            "# => |node, context| {
+                if is_external_module_declaration(node) {
+                    return;
+                }
+
+                let keyword = get_module_declaration_keyword(node);
+                context.report(violation! {
+                    node => node.parent().filter(|parent| parent.kind() == AmbientDeclaration).unwrap_or(node),
+                    message_id => "use_namespace",
+                    data => {
+                        banned_name => "module",
+                        replacement => "namespace",
+                    },
+                    fix => |fixer| {
+                        // (leaving any `declare` alone)
+                        fixer.replace_text(keyword, "namespace");
+                    },
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn prefer_namespace_keyword_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "declare module 'foo';",
+              "declare module 'foo' {}",
+              "namespace foo {}",
+              "declare namespace foo {}",
+              "declare global {}",
+              "declare global { interface Foo {} }",
+            ],
+            invalid => [
+              {
+                code => "module foo {}",
+                output => "namespace foo {}",
+                errors => [
+                  {
+                    message_id => "use_namespace",
+                    data => {
+                      banned_name => "module",
+                      replacement => "namespace",
+                    },
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "declare module foo {}",
+                output => "declare namespace foo {}",
+                errors => [
+                  {
+                    message_id => "use_namespace",
+                    line => 1,
+                    column => 1,
+                  },
+                ],
+              },
+              {
+                code => "export declare module foo {}",
+                output => "export declare namespace foo {}",
+                errors => [
+                  {
+                    message_id => "use_namespace",
+                    line => 1,
+                    column => 8,
+                  },
+                ],
+              },
+              {
+                code => r#"
+declare module foo {
+  declare module bar {}
+}
+                    "#,
+                output => r#"
+declare namespace foo {
+  declare namespace bar {}
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "use_namespace",
+                    line => 2,
+                    column => 1,
+                  },
+                  {
+                    message_id => "use_namespace",
+                    line => 3,
+                    column => 3,
+                  },
+                ],
+              },
+              {
+                code => "module foo.bar.baz {}",
+                output => "namespace foo.bar.baz {}",
+                errors => [{ message_id => "use_namespace" }],
+              },
+              {
+                code => r#"
+declare global {
+  module foo {}
+}
+                    "#,
+                output => r#"
+declare global {
+  namespace foo {}
+}
+                    "#,
+                errors => [
+                  {
+                    message_id => "use_namespace",
+                    line => 3,
+                    column => 3,
+                  },
+                ],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_prefer_namespace_keyword_rule() {
+        run_for_typescript_and_tsx(
+            prefer_namespace_keyword_rule(),
+            test_cases::prefer_namespace_keyword_rule_tests(),
+        );
+    }
+}
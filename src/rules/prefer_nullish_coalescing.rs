@@ -0,0 +1,782 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    AssignmentExpression, AugmentedAssignmentExpression, BinaryExpression, ConditionalExpression,
+    DoStatement, ExpressionStatement, False, ForStatement, Identifier, IfStatement, Null, Number,
+    ParenthesizedExpression, SequenceExpression, String as StringKind, True, UnaryExpression,
+    Undefined, WhileStatement, YieldExpression,
+};
+
+use crate::{
+    ast_helpers::is_same_expression,
+    kind::{
+        ConstructorType, FunctionType, LiteralType, ObjectType, PredefinedType,
+        TemplateLiteralType, TupleType,
+    },
+    type_utils::{
+        get_array_element_type, get_declared_type_members, is_literal_type, is_nullish_member,
+    },
+    util::skip_parenthesized_expressions,
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct IgnorePrimitives {
+    bigint: bool,
+    boolean: bool,
+    number: bool,
+    string: bool,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    ignore_conditional_tests: Option<bool>,
+    ignore_mixed_logical_expressions: Option<bool>,
+    ignore_primitives: Option<IgnorePrimitives>,
+    ignore_ternary_tests: Option<bool>,
+}
+
+impl Options {
+    fn ignore_conditional_tests(&self) -> bool {
+        self.ignore_conditional_tests.unwrap_or_default()
+    }
+
+    fn ignore_mixed_logical_expressions(&self) -> bool {
+        self.ignore_mixed_logical_expressions.unwrap_or_default()
+    }
+
+    fn ignored_primitives(&self) -> Vec<&'static str> {
+        let Some(ignore_primitives) = self.ignore_primitives.as_ref() else {
+            return Default::default();
+        };
+        [
+            (ignore_primitives.bigint, "bigint"),
+            (ignore_primitives.boolean, "boolean"),
+            (ignore_primitives.number, "number"),
+            (ignore_primitives.string, "string"),
+        ]
+        .into_iter()
+        .filter_map(|(is_ignored, primitive)| is_ignored.then_some(primitive))
+        .collect()
+    }
+
+    fn ignore_ternary_tests(&self) -> bool {
+        self.ignore_ternary_tests.unwrap_or_default()
+    }
+}
+
+/// `string`, `number`, `bigint` or `boolean`, if the type is (a literal of)
+/// one of those.
+fn get_primitive<'a>(type_: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Option<&'static str> {
+    match type_.kind() {
+        PredefinedType => match &*type_.text(context) {
+            "string" => Some("string"),
+            "number" => Some("number"),
+            "bigint" => Some("bigint"),
+            "boolean" => Some("boolean"),
+            _ => None,
+        },
+        TemplateLiteralType => Some("string"),
+        LiteralType => match type_
+            .first_non_comment_named_child(SupportedLanguage::Javascript)
+            .kind()
+        {
+            StringKind => Some("string"),
+            Number | UnaryExpression => Some(if type_.text(context).ends_with('n') {
+                "bigint"
+            } else {
+                "number"
+            }),
+            True | False => Some("boolean"),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether values of the type are always truthy, in which case `||` and `??`
+/// behave the same.
+fn is_always_truthy_type<'a>(type_: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    match type_.kind() {
+        ObjectType | TupleType | FunctionType | ConstructorType => true,
+        PredefinedType => matches!(&*type_.text(context), "object" | "symbol"),
+        _ => get_array_element_type(type_, context).is_some(),
+    }
+}
+
+/// If the left side of `a || b` or `a ||= b` is declared with a nullable
+/// type (that isn't an ignored primitive), whether `??` would behave the same.
+fn is_nullish_coalescing_equivalent<'a>(
+    left: Node<'a>,
+    ignored_primitives: &[&'static str],
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<bool> {
+    let members = get_declared_type_members(left, context)?;
+    if !members
+        .iter()
+        .any(|&member| is_nullish_member(member, context))
+    {
+        return None;
+    }
+    let non_nullish_members = members
+        .into_iter()
+        .flatten()
+        .filter(|&member| !is_nullish_member(Some(member), context))
+        .collect::<Vec<_>>();
+    if non_nullish_members.iter().any(|&member| {
+        get_primitive(member, context)
+            .is_some_and(|primitive| ignored_primitives.contains(&primitive))
+    }) {
+        return None;
+    }
+    Some(
+        non_nullish_members
+            .into_iter()
+            .all(|member| is_always_truthy_type(member, context)),
+    )
+}
+
+fn is_logical_operator(node: Node, operators: &[&str]) -> bool {
+    node.kind() == BinaryExpression && operators.contains(&node.field("operator").kind())
+}
+
+/// Whether the expression's value ends up deciding an `if`/loop condition or
+/// a ternary (looking through other logical operators).
+fn is_conditional_test(node: Node) -> bool {
+    let mut node = node;
+    while let Some(parent) = node.parent() {
+        match parent.kind() {
+            ParenthesizedExpression | SequenceExpression => (),
+            BinaryExpression if is_logical_operator(parent, &["&&", "||", "??"]) => (),
+            UnaryExpression if parent.field("operator").kind() == "!" => (),
+            ConditionalExpression => {
+                if parent.field("condition") == node {
+                    return true;
+                }
+            }
+            IfStatement | WhileStatement | DoStatement => {
+                return parent.field("condition") == node;
+            }
+            ForStatement => return parent.child_by_field_name("condition") == Some(node),
+            ExpressionStatement => {
+                return parent.parent().is_some_and(|grandparent| {
+                    grandparent.kind() == ForStatement
+                        && grandparent.child_by_field_name("condition") == Some(parent)
+                });
+            }
+            _ => return false,
+        }
+        node = parent;
+    }
+    false
+}
+
+/// Whether the `||` is connected (through other `||`s and `&&`s) to an `&&`.
+fn is_mixed_logical_expression(node: Node) -> bool {
+    let mut seen = vec![];
+    let mut queue = vec![node];
+    while let Some(current) = queue.pop() {
+        if seen.contains(&current) {
+            continue;
+        }
+        seen.push(current);
+        if is_logical_operator(current, &["&&"]) {
+            return true;
+        }
+        if !is_logical_operator(current, &["||", "&&"]) {
+            continue;
+        }
+        queue.extend(
+            current
+                .ancestors()
+                .find(|ancestor| ancestor.kind() != ParenthesizedExpression),
+        );
+        queue.push(skip_parenthesized_expressions(current.field("left")));
+        queue.push(skip_parenthesized_expressions(current.field("right")));
+    }
+    false
+}
+
+/// Whether `??` can't be used next to the node without parentheses (which
+/// includes mixing it with `||` or `&&`).
+fn needs_parentheses_next_to_nullish_coalescing(node: Node) -> bool {
+    match node.kind() {
+        BinaryExpression => is_logical_operator(node, &["||", "&&", "??"]),
+        ConditionalExpression
+        | SequenceExpression
+        | AssignmentExpression
+        | AugmentedAssignmentExpression
+        | YieldExpression => true,
+        _ => false,
+    }
+}
+
+fn is_null_or_undefined<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    let node = skip_parenthesized_expressions(node);
+    match node.kind() {
+        Null | Undefined => true,
+        Identifier => node.text(context) == "undefined",
+        _ => false,
+    }
+}
+
+/// A comparison like `x !== null` or `undefined == x`.
+struct NullishComparison<'a> {
+    operand: Node<'a>,
+    is_negated: bool,
+    is_strict: bool,
+    is_null: bool,
+}
+
+fn get_nullish_comparison<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<NullishComparison<'a>> {
+    let node = skip_parenthesized_expressions(node);
+    if node.kind() != BinaryExpression {
+        return None;
+    }
+    let operator = node.field("operator").kind();
+    if !matches!(operator, "==" | "!=" | "===" | "!==") {
+        return None;
+    }
+    let left = node.field("left");
+    let right = node.field("right");
+    let (operand, nullish) = if is_null_or_undefined(right, context) {
+        (left, right)
+    } else if is_null_or_undefined(left, context) {
+        (right, left)
+    } else {
+        return None;
+    };
+    Some(NullishComparison {
+        operand,
+        is_negated: operator.starts_with('!'),
+        is_strict: operator.len() == 3,
+        is_null: skip_parenthesized_expressions(nullish).kind() == Null,
+    })
+}
+
+/// The checked expression, if the ternary's condition checks it against both
+/// `null` and `undefined` (or against whichever of them its declared type
+/// includes) and the ternary picks it when it's not nullish.
+fn get_ternary_checked_expression<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<(Node<'a>, Node<'a>)> {
+    let condition = skip_parenthesized_expressions(node.field("condition"));
+    let (comparisons, is_negated) =
+        if let Some(comparison) = get_nullish_comparison(condition, context) {
+            let is_negated = comparison.is_negated;
+            (vec![comparison], is_negated)
+        } else {
+            let is_negated = if is_logical_operator(condition, &["&&"]) {
+                true
+            } else if is_logical_operator(condition, &["||"]) {
+                false
+            } else {
+                return None;
+            };
+            let comparisons = [condition.field("left"), condition.field("right")]
+                .into_iter()
+                .map(|operand| get_nullish_comparison(operand, context))
+                .collect::<Option<Vec<_>>>()?;
+            if comparisons
+                .iter()
+                .any(|comparison| comparison.is_negated != is_negated)
+            {
+                return None;
+            }
+            (comparisons, is_negated)
+        };
+
+    let operand = comparisons[0].operand;
+    if comparisons[1..]
+        .iter()
+        .any(|comparison| !is_same_expression(comparison.operand, operand, context))
+    {
+        return None;
+    }
+    let (checked, other) = if is_negated {
+        (node.field("consequence"), node.field("alternative"))
+    } else {
+        (node.field("alternative"), node.field("consequence"))
+    };
+    if !is_same_expression(
+        skip_parenthesized_expressions(checked),
+        skip_parenthesized_expressions(operand),
+        context,
+    ) {
+        return None;
+    }
+
+    let checks_null = comparisons
+        .iter()
+        .any(|comparison| !comparison.is_strict || comparison.is_null);
+    let checks_undefined = comparisons
+        .iter()
+        .any(|comparison| !comparison.is_strict || !comparison.is_null);
+    if !(checks_null && checks_undefined) {
+        // eg `x !== undefined ? x : y` is only equivalent if `x` can't be
+        // `null`
+        let members = get_declared_type_members(operand, context)?;
+        let can_be_null = members
+            .iter()
+            .any(|&member| member.is_some_and(|member| is_literal_type(member, "null", context)));
+        let can_be_undefined = members.iter().any(|&member| {
+            member.map_or(true, |member| is_literal_type(member, "undefined", context))
+        });
+        if (!checks_null && can_be_null) || (!checks_undefined && can_be_undefined) {
+            return None;
+        }
+    }
+
+    Some((checked, other))
+}
+
+fn parenthesize_if_needed<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> String {
+    if needs_parentheses_next_to_nullish_coalescing(node) {
+        format!("({})", node.text(context))
+    } else {
+        node.text(context).into_owned()
+    }
+}
+
+pub fn prefer_nullish_coalescing_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "prefer-nullish-coalescing",
+        languages => [Typescript],
+        messages => [
+            prefer_nullish_over_or => "Prefer using nullish coalescing operator (`??{{equals}}`) instead of a logical {{description}} (`||{{equals}}`), as it is a safer operator.",
+            prefer_nullish_over_ternary => "Prefer using nullish coalescing operator (`??`) instead of a ternary expression, as it is simpler to read.",
+            suggest_nullish => "Fix to nullish coalescing operator (`??{{equals}}`).",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            ignore_conditional_tests: bool = options.ignore_conditional_tests(),
+            ignore_mixed_logical_expressions: bool = options.ignore_mixed_logical_expressions(),
+            ignored_primitives: Vec<&'static str> = options.ignored_primitives(),
+            ignore_ternary_tests: bool = options.ignore_ternary_tests(),
+        },
+        listeners => [
+            r#"
+              (binary_expression
+                operator: "||"
+              ) @c
+              (augmented_assignment_expression
+                operator: "||="
+              ) @c
+            "# => |node, context| {
+                let is_assignment = node.kind() == AugmentedAssignmentExpression;
+                let left = node.field("left");
+                let right = node.field("right");
+                let Some(is_equivalent) =
+                    is_nullish_coalescing_equivalent(left, &self.ignored_primitives, context)
+                else {
+                    return;
+                };
+                if self.ignore_conditional_tests && is_conditional_test(node) {
+                    return;
+                }
+                if !is_assignment
+                    && self.ignore_mixed_logical_expressions
+                    && is_mixed_logical_expression(node)
+                {
+                    return;
+                }
+
+                let operator = node.field("operator");
+                // `a || b || c` -> `a ?? b || c` would be a syntax error
+                let is_fixable = is_equivalent
+                    && (is_assignment
+                        || ![node.parent(), Some(left), Some(right)]
+                            .into_iter()
+                            .flatten()
+                            .any(|adjacent| is_logical_operator(adjacent, &["||", "&&", "??"])));
+                context.report(violation! {
+                    node => operator,
+                    message_id => "prefer_nullish_over_or",
+                    data => {
+                        equals => if is_assignment { "=" } else { "" },
+                        description => if is_assignment { "assignment" } else { "or" },
+                    },
+                    // TODO: suggestions? (for when the left side could also be
+                    // falsy without being nullish, where `??` would behave
+                    // differently)
+                    fix => |fixer| {
+                        if !is_fixable {
+                            return;
+                        }
+                        fixer.replace_text(operator, if is_assignment { "??=" } else { "??" });
+                    },
+                });
+            },
+            r#"
+              (ternary_expression) @c
+            "# => |node, context| {
+                if self.ignore_ternary_tests {
+                    return;
+                }
+                let Some((checked, other)) = get_ternary_checked_expression(node, context) else {
+                    return;
+                };
+
+                context.report(violation! {
+                    node => node,
+                    message_id => "prefer_nullish_over_ternary",
+                    fix => |fixer| {
+                        fixer.replace_text(
+                            node,
+                            format!(
+                                "{} ?? {}",
+                                parenthesize_if_needed(checked, context),
+                                parenthesize_if_needed(other, context),
+                            ),
+                        );
+                    },
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn prefer_nullish_coalescing_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                "
+declare const x: string;
+x || 'foo';
+                    ",
+                "
+declare const x: number;
+x || 1;
+                    ",
+                "
+declare const x: any;
+x || 'foo';
+                    ",
+                "
+declare const x: string | null;
+x ?? 'foo';
+                    ",
+                "
+let x: string = 'a';
+x ||= 'foo';
+                    ",
+                // unknown
+                "x || 'foo';",
+                {
+                  code => "
+declare const x: string | null;
+if (x || 'foo') {}
+                      ",
+                  options => { ignore_conditional_tests => true },
+                },
+                {
+                  code => "
+declare const x: string | null;
+while ((x || 'foo') && y) {}
+                      ",
+                  options => { ignore_conditional_tests => true },
+                },
+                {
+                  code => "
+declare const x: string | null;
+const y = x || 'foo' ? 1 : 2;
+                      ",
+                  options => { ignore_conditional_tests => true },
+                },
+                {
+                  code => "
+declare let x: string | null;
+for (; (x ||= 'foo'); ) {}
+                      ",
+                  options => { ignore_conditional_tests => true },
+                },
+                {
+                  code => "
+declare const a: string | null;
+declare const b: string | null;
+declare const c: string | null;
+a || (b && c);
+                      ",
+                  options => { ignore_mixed_logical_expressions => true },
+                },
+                {
+                  code => "
+declare const a: string | null;
+declare const b: string | null;
+declare const c: string | null;
+(a && b) || c || a;
+                      ",
+                  options => { ignore_mixed_logical_expressions => true },
+                },
+                {
+                  code => "
+declare const x: string | undefined;
+x || 'foo';
+                      ",
+                  options => { ignore_primitives => { string => true } },
+                },
+                {
+                  code => "
+declare let x: 1 | 2 | null;
+x ||= 3;
+                      ",
+                  options => { ignore_primitives => { number => true } },
+                },
+                {
+                  code => "
+function foo(x?: boolean) {
+  return x || true;
+}
+                      ",
+                  options => { ignore_primitives => { boolean => true } },
+                },
+                // ternaries
+                "
+declare const x: string | null;
+x !== undefined ? x : y;
+                    ",
+                "
+declare const x: string | undefined;
+x === null ? y : x;
+                    ",
+                "x !== undefined ? x : y;",
+                "x != null ? y : x;",
+                "x != null ? x.a : y;",
+                "x !== null || x !== undefined ? x : y;",
+                "x != null && z != undefined ? x : y;",
+                "x ? x : y;",
+                {
+                  code => "x != null ? x : y;",
+                  options => { ignore_ternary_tests => true },
+                },
+            ],
+            invalid => [
+                {
+                  code => "
+declare const x: string | null;
+x || 'foo';
+                      ",
+                  errors => [
+                    {
+                      message_id => "prefer_nullish_over_or",
+                      data => { equals => "", description => "or" },
+                      line => 3,
+                      column => 3,
+                      end_column => 5,
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare const x: { a: string } | undefined;
+x || { a: 'foo' };
+                      ",
+                  output => "
+declare const x: { a: string } | undefined;
+x ?? { a: 'foo' };
+                      ",
+                  errors => [
+                    {
+                      message_id => "prefer_nullish_over_or",
+                      data => { equals => "", description => "or" },
+                      line => 3,
+                      column => 3,
+                    },
+                  ],
+                },
+                {
+                  code => "
+function foo(x?: string[]) {
+  return x || [];
+}
+                      ",
+                  output => "
+function foo(x?: string[]) {
+  return x ?? [];
+}
+                      ",
+                  errors => [{ message_id => "prefer_nullish_over_or", line => 3, column => 12 }],
+                },
+                {
+                  code => "
+declare let x: string | undefined;
+x ||= 'foo';
+                      ",
+                  errors => [
+                    {
+                      message_id => "prefer_nullish_over_or",
+                      data => { equals => "=", description => "assignment" },
+                      line => 3,
+                      column => 3,
+                      end_column => 6,
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare let x: (() => void) | null;
+x ||= () => {};
+                      ",
+                  output => "
+declare let x: (() => void) | null;
+x ??= () => {};
+                      ",
+                  errors => [
+                    {
+                      message_id => "prefer_nullish_over_or",
+                      data => { equals => "=", description => "assignment" },
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare let x: object | null;
+if ((x ||= {})) {}
+                      ",
+                  output => "
+declare let x: object | null;
+if ((x ??= {})) {}
+                      ",
+                  errors => [{ message_id => "prefer_nullish_over_or" }],
+                },
+                {
+                  code => "
+declare let x: string | null;
+if ((x ||= 'foo')) {}
+                      ",
+                  options => { ignore_mixed_logical_expressions => true },
+                  errors => [{ message_id => "prefer_nullish_over_or" }],
+                },
+                {
+                  code => "
+declare let x: number | undefined;
+x ||= 1;
+                      ",
+                  options => { ignore_primitives => { string => true } },
+                  errors => [{ message_id => "prefer_nullish_over_or" }],
+                },
+                {
+                  code => "
+declare const x: object | null;
+declare const y: string;
+x || y || 'foo';
+                      ",
+                  errors => [{ message_id => "prefer_nullish_over_or", line => 4, column => 3 }],
+                },
+                {
+                  code => "
+declare const a: string | null;
+declare const b: string | null;
+declare const c: string | null;
+a || (b && c);
+                      ",
+                  errors => [{ message_id => "prefer_nullish_over_or", line => 5 }],
+                },
+                {
+                  code => "
+declare const x: string | null;
+if (x || 'foo') {}
+                      ",
+                  errors => [{ message_id => "prefer_nullish_over_or" }],
+                },
+                // ternaries
+                {
+                  code => "x !== undefined && x !== null ? x : y;",
+                  output => "x ?? y;",
+                  errors => [
+                    {
+                      message_id => "prefer_nullish_over_ternary",
+                      line => 1,
+                      column => 1,
+                      end_column => 38,
+                    },
+                  ],
+                },
+                {
+                  code => "x === null || undefined === x ? y : x;",
+                  output => "x ?? y;",
+                  errors => [{ message_id => "prefer_nullish_over_ternary" }],
+                },
+                {
+                  code => "x != null ? x : y;",
+                  output => "x ?? y;",
+                  errors => [{ message_id => "prefer_nullish_over_ternary" }],
+                },
+                {
+                  code => "undefined == x.a ? y : x.a;",
+                  output => "x.a ?? y;",
+                  errors => [{ message_id => "prefer_nullish_over_ternary" }],
+                },
+                {
+                  code => "
+declare const x: string | undefined;
+x !== undefined ? x : y;
+                      ",
+                  output => "
+declare const x: string | undefined;
+x ?? y;
+                      ",
+                  errors => [{ message_id => "prefer_nullish_over_ternary", line => 3 }],
+                },
+                {
+                  code => "
+function foo(x?: string) {
+  return x === undefined ? 'foo' : x;
+}
+                      ",
+                  output => "
+function foo(x?: string) {
+  return x ?? 'foo';
+}
+                      ",
+                  errors => [{ message_id => "prefer_nullish_over_ternary", line => 3 }],
+                },
+                {
+                  code => "
+declare const x: string | null;
+x !== null ? x : y;
+                      ",
+                  output => "
+declare const x: string | null;
+x ?? y;
+                      ",
+                  errors => [{ message_id => "prefer_nullish_over_ternary", line => 3 }],
+                },
+                {
+                  code => "x != null ? x : a || b;",
+                  output => "x ?? (a || b);",
+                  errors => [{ message_id => "prefer_nullish_over_ternary" }],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_prefer_nullish_coalescing_rule() {
+        run_for_typescript_and_tsx(
+            prefer_nullish_coalescing_rule(),
+            test_cases::prefer_nullish_coalescing_rule_tests(),
+        );
+    }
+}
@@ -0,0 +1,522 @@
+use std::{borrow::Cow, sync::Arc};
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    kind::{
+        Arguments, BinaryExpression, CallExpression, Identifier, MemberExpression, Null,
+        ParenthesizedExpression, PropertyIdentifier, Regex, String as StringKind,
+        SubscriptExpression, TemplateString,
+    },
+    utils::ast_utils::get_static_string_value,
+};
+
+use crate::util::{
+    evaluate_constant_expression, skip_parenthesized_expressions, to_string_literal,
+    AnchoredLiteral, ConstantValue, RegexLiteral,
+};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum AllowSingleElementEquality {
+    Always,
+    #[default]
+    Never,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    allow_single_element_equality: Option<AllowSingleElementEquality>,
+}
+
+impl Options {
+    fn allow_single_element_equality(&self) -> AllowSingleElementEquality {
+        self.allow_single_element_equality.unwrap_or_default()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Method {
+    StartsWith,
+    EndsWith,
+}
+
+impl Method {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::StartsWith => "startsWith",
+            Self::EndsWith => "endsWith",
+        }
+    }
+
+    fn message_id(&self) -> &'static str {
+        match self {
+            Self::StartsWith => "prefer_starts_with",
+            Self::EndsWith => "prefer_ends_with",
+        }
+    }
+}
+
+fn get_method_and_value(anchored_literal: AnchoredLiteral) -> (Method, String) {
+    match anchored_literal {
+        AnchoredLiteral::StartsWith(value) => (Method::StartsWith, value),
+        AnchoredLiteral::EndsWith(value) => (Method::EndsWith, value),
+    }
+}
+
+/// `foo.bar(...)` -> (`foo`, `bar`, arguments)
+fn get_method_call<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<(Node<'a>, Cow<'a, str>, Vec<Node<'a>>)> {
+    if node.kind() != CallExpression {
+        return None;
+    }
+    let callee = node.field("function");
+    let arguments = node.field("arguments");
+    if callee.kind() != MemberExpression || arguments.kind() != Arguments {
+        return None;
+    }
+    let property = callee.field("property");
+    if property.kind() != PropertyIdentifier {
+        return None;
+    }
+    Some((
+        callee.field("object"),
+        property.text(context),
+        arguments
+            .non_comment_named_children(SupportedLanguage::Javascript)
+            .collect(),
+    ))
+}
+
+fn is_number(node: Node, value: f64, context: &QueryMatchContext) -> bool {
+    evaluate_constant_expression(node, context) == Some(ConstantValue::Number(value))
+}
+
+/// The string value of a string literal, which is also what tells us that
+/// whatever it's being compared against is a string.
+fn get_string_literal_value<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Cow<'a, str>> {
+    let node = skip_parenthesized_expressions(node);
+    if !matches!(node.kind(), StringKind | TemplateString) {
+        return None;
+    }
+    get_static_string_value(node, context)
+}
+
+fn get_js_length(value: &str) -> usize {
+    value.encode_utf16().count()
+}
+
+/// `object.length - N`
+fn get_length_offset(node: Node, object: Node, context: &QueryMatchContext) -> Option<f64> {
+    let node = skip_parenthesized_expressions(node);
+    if node.kind() != BinaryExpression || node.field("operator").kind() != "-" {
+        return None;
+    }
+    let left = skip_parenthesized_expressions(node.field("left"));
+    if left.kind() != MemberExpression
+        || left.field("property").text(context) != "length"
+        || left.field("object").text(context) != object.text(context)
+    {
+        return None;
+    }
+    match evaluate_constant_expression(node.field("right"), context)? {
+        ConstantValue::Number(value) => Some(value),
+        _ => None,
+    }
+}
+
+fn needs_parens_as_receiver(node: Node) -> bool {
+    !matches!(
+        node.kind(),
+        Identifier
+            | MemberExpression
+            | SubscriptExpression
+            | CallExpression
+            | ParenthesizedExpression
+            | StringKind
+            | TemplateString
+    )
+}
+
+struct Match<'a> {
+    method: Method,
+    object: Node<'a>,
+    /// The text of the argument to pass to `startsWith()`/`endsWith()`, if
+    /// the replacement is known to behave the same.
+    argument_text: Option<Cow<'a, str>>,
+}
+
+pub fn prefer_string_starts_ends_with_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "prefer-string-starts-ends-with",
+        languages => [Typescript],
+        messages => [
+            prefer_starts_with => "Use 'String#startsWith' method instead.",
+            prefer_ends_with => "Use the 'String#endsWith' method instead.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            allow_single_element_equality: AllowSingleElementEquality = options.allow_single_element_equality(),
+        },
+        methods => {
+            // `foo[0] === 'a'`, `foo.charAt(foo.length - 1) === 'a'`
+            fn get_character_match(&self, left: Node<'a>, right: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Option<Match<'a>> {
+                let (object, index) = match left.kind() {
+                    SubscriptExpression => {
+                        if self.allow_single_element_equality == AllowSingleElementEquality::Always {
+                            return None;
+                        }
+                        (left.field("object"), left.field("index"))
+                    }
+                    _ => match get_method_call(left, context)? {
+                        (object, method, arguments) if method == "charAt" && arguments.len() == 1 => (object, arguments[0]),
+                        _ => return None,
+                    },
+                };
+                let right_value = get_string_literal_value(right, context)?;
+                let method = if is_number(index, 0., context) {
+                    Method::StartsWith
+                } else if get_length_offset(index, object, context) == Some(1.) {
+                    Method::EndsWith
+                } else {
+                    return None;
+                };
+                Some(Match {
+                    method,
+                    object,
+                    argument_text: (get_js_length(&right_value) == 1).then(|| right.text(context)),
+                })
+            }
+
+            // `foo.indexOf('bar') === 0`, `foo.lastIndexOf('bar') === foo.length - 3`
+            fn get_index_of_match(&self, left: Node<'a>, right: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Option<Match<'a>> {
+                let (object, method, arguments) = get_method_call(left, context)?;
+                if arguments.len() != 1 {
+                    return None;
+                }
+                let needle = get_string_literal_value(arguments[0], context)?;
+                let method = match &*method {
+                    "indexOf" if is_number(right, 0., context) => Method::StartsWith,
+                    "lastIndexOf"
+                        if get_length_offset(right, object, context)
+                            == Some(get_js_length(&needle) as f64) =>
+                    {
+                        Method::EndsWith
+                    }
+                    _ => return None,
+                };
+                Some(Match {
+                    method,
+                    object,
+                    argument_text: Some(arguments[0].text(context)),
+                })
+            }
+
+            // `foo.slice(0, 3) === 'bar'`, `foo.substring(0, 3) === 'bar'`,
+            // `foo.slice(-3) === 'bar'`
+            fn get_slice_match(&self, left: Node<'a>, right: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Option<Match<'a>> {
+                let (object, method, arguments) = get_method_call(left, context)?;
+                if !matches!(&*method, "slice" | "substring") {
+                    return None;
+                }
+                let length = get_js_length(&get_string_literal_value(right, context)?) as f64;
+                let method = match &*arguments {
+                    [start, end] if is_number(*start, 0., context) && is_number(*end, length, context) => {
+                        Method::StartsWith
+                    }
+                    [start] if method == "slice" && is_number(*start, -length, context) => {
+                        Method::EndsWith
+                    }
+                    _ => return None,
+                };
+                Some(Match {
+                    method,
+                    object,
+                    argument_text: Some(right.text(context)),
+                })
+            }
+
+            // `foo.match(/^bar/) !== null`
+            fn get_regex_match_match(&self, left: Node<'a>, right: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Option<Match<'a>> {
+                let (object, method, arguments) = get_method_call(left, context)?;
+                if method != "match" || arguments.len() != 1 || skip_parenthesized_expressions(right).kind() != Null {
+                    return None;
+                }
+                let regex = skip_parenthesized_expressions(arguments[0]);
+                if regex.kind() != Regex {
+                    return None;
+                }
+                let (method, value) = get_method_and_value(
+                    RegexLiteral::from_node(regex, context).get_anchored_literal()?,
+                );
+                Some(Match {
+                    method,
+                    object,
+                    argument_text: Some(to_string_literal(&value).into()),
+                })
+            }
+
+            fn report(&self, node: Node<'a>, match_: Match<'a>, is_negative: bool, context: &QueryMatchContext<'a, '_>) {
+                let Match { method, object, argument_text } = match_;
+                context.report(violation! {
+                    node => node,
+                    message_id => method.message_id(),
+                    fix => |fixer| {
+                        let Some(argument_text) = argument_text.as_ref() else {
+                            return;
+                        };
+                        fixer.replace_text(
+                            node,
+                            format!(
+                                "{}{}.{}({})",
+                                if is_negative { "!" } else { "" },
+                                object.text(context),
+                                method.name(),
+                                argument_text,
+                            ),
+                        );
+                    },
+                });
+            }
+        },
+        listeners => [
+            r#"
+              (binary_expression
+                operator: [
+                  "=="
+                  "==="
+                  "!="
+                  "!=="
+                ]
+              ) @c
+            "# => |node, context| {
+                let left = skip_parenthesized_expressions(node.field("left"));
+                let right = node.field("right");
+                let operator = node.field("operator").kind();
+                let mut is_negative = matches!(operator, "!=" | "!==");
+
+                let match_ = match self.get_character_match(left, right, context)
+                    .or_else(|| self.get_index_of_match(left, right, context))
+                    .or_else(|| self.get_slice_match(left, right, context)) {
+                    Some(match_) => match_,
+                    None => {
+                        let Some(match_) = self.get_regex_match_match(left, right, context) else {
+                            return;
+                        };
+                        // `!== null` is the positive check here
+                        is_negative = !is_negative;
+                        match_
+                    }
+                };
+                self.report(node, match_, is_negative, context);
+            },
+            r#"
+              (call_expression
+                function: (member_expression
+                  object: (_) @regex
+                  property: (property_identifier) @method (#match? @method "^test$")
+                )
+                arguments: (arguments
+                  .
+                  (_) @argument
+                  .
+                )
+              ) @call
+            "# => |captures, context| {
+                let regex = skip_parenthesized_expressions(captures["regex"]);
+                if regex.kind() != Regex {
+                    return;
+                }
+                let Some(anchored_literal) = RegexLiteral::from_node(regex, context).get_anchored_literal() else {
+                    return;
+                };
+                let (method, value) = get_method_and_value(anchored_literal);
+                let argument = captures["argument"];
+                let object_text = if needs_parens_as_receiver(argument) {
+                    format!("({})", argument.text(context)).into()
+                } else {
+                    argument.text(context)
+                };
+                let call = captures["call"];
+                context.report(violation! {
+                    node => call,
+                    message_id => method.message_id(),
+                    fix => |fixer| {
+                        fixer.replace_text(
+                            call,
+                            format!(
+                                "{}.{}({})",
+                                object_text,
+                                method.name(),
+                                to_string_literal(&value),
+                            ),
+                        );
+                    },
+                });
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn prefer_string_starts_ends_with_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "x[0] === y;",
+              "x.charAt(1) === 'a';",
+              "x[x.length - 2] === 'a';",
+              "x.indexOf('bar') === 1;",
+              "x.indexOf(y) === 0;",
+              "x.lastIndexOf('bar') === x.length - 2;",
+              "x.slice(0, 2) === 'bar';",
+              "x.slice(-2) === 'bar';",
+              "x.substring(-3) === 'bar';",
+              "x.match(/^bar$/) !== null;",
+              "x.match(/^bar/i) !== null;",
+              "x.match(/^b.r/) !== null;",
+              "/bar\\$/.test(x);",
+              "/^bar/.exec(x);",
+              "pattern.test(x);",
+              {
+                code => "x[0] === 'a';",
+                options => { allow_single_element_equality => "always" },
+              },
+              {
+                code => "x[x.length - 1] === 'a';",
+                options => { allow_single_element_equality => "always" },
+              },
+            ],
+            invalid => [
+              {
+                code => "x[0] === 'a';",
+                output => "x.startsWith('a');",
+                errors => [
+                  {
+                    message_id => "prefer_starts_with",
+                    line => 1,
+                    column => 1,
+                    end_line => 1,
+                    end_column => 13,
+                  },
+                ],
+              },
+              {
+                code => "x[0] !== 'a';",
+                output => "!x.startsWith('a');",
+                errors => [{ message_id => "prefer_starts_with" }],
+              },
+              {
+                code => "x[0] === 'ab';",
+                errors => [{ message_id => "prefer_starts_with" }],
+              },
+              {
+                code => "x[x.length - 1] === 'a';",
+                output => "x.endsWith('a');",
+                errors => [{ message_id => "prefer_ends_with" }],
+              },
+              {
+                code => "x.charAt(0) === 'a';",
+                output => "x.startsWith('a');",
+                options => { allow_single_element_equality => "always" },
+                errors => [{ message_id => "prefer_starts_with" }],
+              },
+              {
+                code => "x.charAt(x.length - 1) == 'a';",
+                output => "x.endsWith('a');",
+                errors => [{ message_id => "prefer_ends_with" }],
+              },
+              {
+                code => "x.indexOf('bar') === 0;",
+                output => "x.startsWith('bar');",
+                errors => [{ message_id => "prefer_starts_with" }],
+              },
+              {
+                code => "x.indexOf('bar') !== 0;",
+                output => "!x.startsWith('bar');",
+                errors => [{ message_id => "prefer_starts_with" }],
+              },
+              {
+                code => "x.lastIndexOf('bar') === x.length - 3;",
+                output => "x.endsWith('bar');",
+                errors => [{ message_id => "prefer_ends_with" }],
+              },
+              {
+                code => "x.slice(0, 3) === 'bar';",
+                output => "x.startsWith('bar');",
+                errors => [{ message_id => "prefer_starts_with" }],
+              },
+              {
+                code => "x.substring(0, 3) !== 'bar';",
+                output => "!x.startsWith('bar');",
+                errors => [{ message_id => "prefer_starts_with" }],
+              },
+              {
+                code => "x.slice(-3) === 'bar';",
+                output => "x.endsWith('bar');",
+                errors => [{ message_id => "prefer_ends_with" }],
+              },
+              {
+                code => "x.match(/^bar/) !== null;",
+                output => "x.startsWith(\"bar\");",
+                errors => [{ message_id => "prefer_starts_with" }],
+              },
+              {
+                code => "x.match(/bar\\.baz$/) === null;",
+                output => "!x.endsWith(\"bar.baz\");",
+                errors => [{ message_id => "prefer_ends_with" }],
+              },
+              {
+                code => "/^bar/.test(x);",
+                output => "x.startsWith(\"bar\");",
+                errors => [
+                  {
+                    message_id => "prefer_starts_with",
+                    line => 1,
+                    column => 1,
+                    end_line => 1,
+                    end_column => 15,
+                  },
+                ],
+              },
+              {
+                code => "/bar$/.test(a + b);",
+                output => "(a + b).endsWith(\"bar\");",
+                errors => [{ message_id => "prefer_ends_with" }],
+              },
+              {
+                code => "(/^\"\\//).test(x.y);",
+                output => "x.y.startsWith(\"\\\"/\");",
+                errors => [{ message_id => "prefer_starts_with" }],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_prefer_string_starts_ends_with_rule() {
+        run_for_typescript_and_tsx(
+            prefer_string_starts_ends_with_rule(),
+            test_cases::prefer_string_starts_ends_with_rule_tests(),
+        );
+    }
+}
@@ -0,0 +1,528 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    Array, ArrowFunction, BinaryExpression, CallExpression, Class, Function, Identifier,
+    NewExpression, Object, Regex, String as StringKind, TemplateString, UnaryExpression,
+};
+
+use crate::{
+    kind::{AsExpression, LiteralType, PredefinedType, SatisfiesExpression, TypeIdentifier},
+    util::{evaluate_constant_expression, skip_parenthesized_expressions, ConstantValue},
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    allow_any: Option<bool>,
+    allow_boolean: Option<bool>,
+    allow_nullish: Option<bool>,
+    allow_number_and_string: Option<bool>,
+    allow_reg_exp: Option<bool>,
+    skip_compound_assignments: Option<bool>,
+}
+
+impl Options {
+    fn allow_any(&self) -> bool {
+        self.allow_any.unwrap_or(true)
+    }
+
+    fn allow_boolean(&self) -> bool {
+        self.allow_boolean.unwrap_or(true)
+    }
+
+    fn allow_nullish(&self) -> bool {
+        self.allow_nullish.unwrap_or(true)
+    }
+
+    fn allow_number_and_string(&self) -> bool {
+        self.allow_number_and_string.unwrap_or(true)
+    }
+
+    fn allow_reg_exp(&self) -> bool {
+        self.allow_reg_exp.unwrap_or(true)
+    }
+
+    fn skip_compound_assignments(&self) -> bool {
+        self.skip_compound_assignments.unwrap_or_default()
+    }
+
+    fn string_like(&self) -> String {
+        let string_likes = [
+            (self.allow_any(), "`any`"),
+            (self.allow_boolean(), "`boolean`"),
+            (self.allow_nullish(), "`null`"),
+            (self.allow_reg_exp(), "`RegExp`"),
+            (self.allow_nullish(), "`undefined`"),
+        ]
+        .into_iter()
+        .filter_map(|(is_allowed, type_)| is_allowed.then_some(type_))
+        .collect::<Vec<_>>();
+        match string_likes.len() {
+            0 => "string".to_owned(),
+            1 => format!("string, allowing a string + {}", string_likes[0]),
+            _ => format!(
+                "string, allowing a string + any of: {}",
+                string_likes.join(", ")
+            ),
+        }
+    }
+}
+
+/// What can be told about an operand's type without a type checker.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum OperandType {
+    Any,
+    Unknown,
+    Never,
+    Number,
+    BigInt,
+    String,
+    Boolean,
+    Null,
+    Undefined,
+    RegExp,
+    Symbol,
+    Object,
+}
+
+impl OperandType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Any => "any",
+            Self::Unknown => "unknown",
+            Self::Never => "never",
+            Self::Number => "number",
+            Self::BigInt => "bigint",
+            Self::String => "string",
+            Self::Boolean => "boolean",
+            Self::Null => "null",
+            Self::Undefined => "undefined",
+            Self::RegExp => "RegExp",
+            Self::Symbol => "symbol",
+            Self::Object => "object",
+        }
+    }
+
+    fn is_numeric(&self) -> bool {
+        matches!(self, Self::Number | Self::BigInt)
+    }
+}
+
+impl From<ConstantValue> for OperandType {
+    fn from(value: ConstantValue) -> Self {
+        match value {
+            ConstantValue::Number(_) => Self::Number,
+            ConstantValue::BigInt(_) => Self::BigInt,
+            ConstantValue::String(_) => Self::String,
+            ConstantValue::Boolean(_) => Self::Boolean,
+            ConstantValue::Null => Self::Null,
+            ConstantValue::Undefined => Self::Undefined,
+        }
+    }
+}
+
+fn get_asserted_type(type_: Node, context: &QueryMatchContext) -> Option<OperandType> {
+    Some(match type_.kind() {
+        PredefinedType => match &*type_.text(context) {
+            "any" => OperandType::Any,
+            "unknown" => OperandType::Unknown,
+            "never" => OperandType::Never,
+            "number" => OperandType::Number,
+            "bigint" => OperandType::BigInt,
+            "string" => OperandType::String,
+            "boolean" => OperandType::Boolean,
+            "symbol" => OperandType::Symbol,
+            "object" => OperandType::Object,
+            _ => return None,
+        },
+        LiteralType => match &*type_.text(context) {
+            "null" => OperandType::Null,
+            "undefined" => OperandType::Undefined,
+            _ => return None,
+        },
+        TypeIdentifier if type_.text(context) == "RegExp" => OperandType::RegExp,
+        _ => return None,
+    })
+}
+
+fn get_binary_expression_type(node: Node, context: &QueryMatchContext) -> Option<OperandType> {
+    match node.field("operator").kind() {
+        "+" => {
+            let left = get_operand_type(node.field("left"), context)?;
+            let right = get_operand_type(node.field("right"), context)?;
+            if left == OperandType::String || right == OperandType::String {
+                return Some(OperandType::String);
+            }
+            (left == right && left.is_numeric()).then_some(left)
+        }
+        "-" | "*" | "/" | "%" | "**" | "&" | "|" | "^" | "<<" | ">>" => {
+            let left = get_operand_type(node.field("left"), context)?;
+            let right = get_operand_type(node.field("right"), context)?;
+            Some(
+                if left == OperandType::BigInt && right == OperandType::BigInt {
+                    OperandType::BigInt
+                } else {
+                    OperandType::Number
+                },
+            )
+        }
+        ">>>" => Some(OperandType::Number),
+        "==" | "!=" | "===" | "!==" | "<" | "<=" | ">" | ">=" | "in" | "instanceof" => {
+            Some(OperandType::Boolean)
+        }
+        _ => None,
+    }
+}
+
+/// Approximates the type of an operand from its syntax: literals (and
+/// `const` variables that fold to one), object/array/function/class
+/// expressions, `as` assertions to a primitive type and operators whose
+/// result type is fixed. `None` means unknown, in which case the operand is
+/// assumed to be fine.
+fn get_operand_type(node: Node, context: &QueryMatchContext) -> Option<OperandType> {
+    let node = skip_parenthesized_expressions(node);
+    if let Some(value) = evaluate_constant_expression(node, context) {
+        return Some(value.into());
+    }
+    match node.kind() {
+        StringKind | TemplateString => Some(OperandType::String),
+        Regex => Some(OperandType::RegExp),
+        Object | Array | ArrowFunction | Function | Class => Some(OperandType::Object),
+        NewExpression => {
+            let constructor = node.field("constructor");
+            (constructor.kind() == Identifier && constructor.text(context) == "RegExp")
+                .then_some(OperandType::RegExp)
+        }
+        CallExpression => {
+            let function = node.field("function");
+            (function.kind() == Identifier && function.text(context) == "Symbol")
+                .then_some(OperandType::Symbol)
+        }
+        AsExpression => get_asserted_type(
+            node.non_comment_named_children(SupportedLanguage::Javascript)
+                .last()
+                .unwrap(),
+            context,
+        ),
+        SatisfiesExpression => get_operand_type(
+            node.first_non_comment_named_child(SupportedLanguage::Javascript),
+            context,
+        ),
+        UnaryExpression => match node.field("operator").kind() {
+            "typeof" => Some(OperandType::String),
+            "void" => Some(OperandType::Undefined),
+            "!" | "delete" => Some(OperandType::Boolean),
+            "+" => Some(OperandType::Number),
+            _ => get_operand_type(node.field("argument"), context).map(|argument| match argument {
+                OperandType::BigInt => OperandType::BigInt,
+                _ => OperandType::Number,
+            }),
+        },
+        BinaryExpression => get_binary_expression_type(node, context),
+        _ => None,
+    }
+}
+
+pub fn restrict_plus_operands_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "restrict-plus-operands",
+        languages => [Typescript],
+        messages => [
+            bigint_and_number => "Numeric '+' operations must either be both bigints or both numbers. Got `{{left}}` + `{{right}}`.",
+            invalid => "Invalid operand for a '+' operation. Operands must each be a number or {{string_like}}. Got `{{type}}`.",
+            mismatched => "Operands of '+' operations must be a number or {{string_like}}. Got `{{left}}` + `{{right}}`.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            allow_any: bool = options.allow_any(),
+            allow_boolean: bool = options.allow_boolean(),
+            allow_nullish: bool = options.allow_nullish(),
+            allow_number_and_string: bool = options.allow_number_and_string(),
+            allow_reg_exp: bool = options.allow_reg_exp(),
+            skip_compound_assignments: bool = options.skip_compound_assignments(),
+            string_like: String = options.string_like(),
+        },
+        methods => {
+            fn is_invalid_operand(&self, type_: OperandType, other_type: Option<OperandType>) -> bool {
+                match type_ {
+                    OperandType::Symbol | OperandType::Never | OperandType::Unknown | OperandType::Object => true,
+                    OperandType::Any => !self.allow_any,
+                    OperandType::Boolean => !self.allow_boolean,
+                    OperandType::Null | OperandType::Undefined => !self.allow_nullish,
+                    OperandType::RegExp => !self.allow_reg_exp || other_type == Some(OperandType::Number),
+                    OperandType::Number | OperandType::BigInt | OperandType::String => false,
+                }
+            }
+
+            fn check_plus_operands(&self, node: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+                let left = node.field("left");
+                let right = node.field("right");
+                let left_type = get_operand_type(left, context);
+                let right_type = get_operand_type(right, context);
+                if left_type == right_type && left_type.is_some_and(|left_type| {
+                    left_type.is_numeric() || left_type == OperandType::String
+                }) {
+                    return;
+                }
+
+                let mut had_individual_complaint = false;
+                for (base_node, base_type, other_type) in [
+                    (left, left_type, right_type),
+                    (right, right_type, left_type),
+                ] {
+                    let Some(base_type) = base_type.filter(|&base_type| {
+                        self.is_invalid_operand(base_type, other_type)
+                    }) else {
+                        continue;
+                    };
+                    context.report(violation! {
+                        node => base_node,
+                        message_id => "invalid",
+                        data => {
+                            string_like => &self.string_like,
+                            type => base_type.as_str(),
+                        },
+                    });
+                    had_individual_complaint = true;
+                }
+                if had_individual_complaint {
+                    return;
+                }
+
+                let (Some(left_type), Some(right_type)) = (left_type, right_type) else {
+                    return;
+                };
+                for (base_type, other_type) in [(left_type, right_type), (right_type, left_type)] {
+                    if !self.allow_number_and_string
+                        && base_type == OperandType::String
+                        && other_type.is_numeric()
+                    {
+                        context.report(violation! {
+                            node => node,
+                            message_id => "mismatched",
+                            data => {
+                                string_like => &self.string_like,
+                                left => left_type.as_str(),
+                                right => right_type.as_str(),
+                            },
+                        });
+                        return;
+                    }
+                    if base_type == OperandType::Number && other_type == OperandType::BigInt {
+                        context.report(violation! {
+                            node => node,
+                            message_id => "bigint_and_number",
+                            data => {
+                                left => left_type.as_str(),
+                                right => right_type.as_str(),
+                            },
+                        });
+                        return;
+                    }
+                }
+            }
+        },
+        listeners => [
+            r#"
+              (binary_expression
+                operator: "+"
+              ) @c
+            "# => |node, context| {
+                self.check_plus_operands(node, context);
+            },
+            r#"
+              (augmented_assignment_expression
+                operator: "+="
+              ) @c
+            "# => |node, context| {
+                if self.skip_compound_assignments {
+                    return;
+                }
+
+                self.check_plus_operands(node, context);
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn restrict_plus_operands_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              "let x = 5;",
+              "let y = '10';",
+              "let z = 8.2;",
+              "let w = '6.5';",
+              "let foo = 5 + 10;",
+              "let foo = '5.5' + '10';",
+              "let foo = parseInt('5.5', 10) + 10;",
+              "let foo = 1n + 1n;",
+              "let foo = `${1}` + 'a';",
+              "let foo = (1 + 2) + 3;",
+              "let foo = (a as number) + b;",
+              "let foo = -a + 1n;",
+              "let foo = (a - b) + 1n;",
+              "foo += 'a';",
+              "foo += bar;",
+              "let foo = 'a' + 1;",
+              "let foo = 'a' + true;",
+              "let foo = 'a' + null;",
+              "let foo = 'a' + /a/;",
+              "let foo = 'a' + (x as any);",
+              {
+                code => "foo += {};",
+                options => { skip_compound_assignments => true },
+              },
+              {
+                code => r#"
+const a = 1;
+const b = 2;
+let foo = a + b;
+                    "#,
+                options => { allow_number_and_string => false },
+              },
+            ],
+            invalid => [
+              {
+                code => "let foo = '1' + 1;",
+                options => { allow_number_and_string => false },
+                errors => [
+                  {
+                    message_id => "mismatched",
+                    data => {
+                      string_like => "string, allowing a string + any of: `any`, `boolean`, `null`, `RegExp`, `undefined`",
+                      left => "string",
+                      right => "number",
+                    },
+                    line => 1,
+                    column => 11,
+                    end_line => 1,
+                    end_column => 18,
+                  },
+                ],
+              },
+              {
+                code => "let foo = 1n + 1;",
+                errors => [
+                  {
+                    message_id => "bigint_and_number",
+                    data => {
+                      left => "bigint",
+                      right => "number",
+                    },
+                    line => 1,
+                    column => 11,
+                  },
+                ],
+              },
+              {
+                code => "let foo = [] + {};",
+                errors => [
+                  {
+                    message_id => "invalid",
+                    data => {
+                      string_like => "string, allowing a string + any of: `any`, `boolean`, `null`, `RegExp`, `undefined`",
+                      type => "object",
+                    },
+                    line => 1,
+                    column => 11,
+                    end_line => 1,
+                    end_column => 13,
+                  },
+                  {
+                    message_id => "invalid",
+                    line => 1,
+                    column => 16,
+                  },
+                ],
+              },
+              {
+                code => "let foo = 1 + /a/;",
+                errors => [{ message_id => "invalid", line => 1, column => 15 }],
+              },
+              {
+                code => "let foo = 'a' + Symbol();",
+                errors => [{ message_id => "invalid", line => 1, column => 17 }],
+              },
+              {
+                code => "let foo = 'a' + (x as unknown);",
+                errors => [{ message_id => "invalid", line => 1, column => 17 }],
+              },
+              {
+                code => "let foo = 'a' + true;",
+                options => { allow_boolean => false },
+                errors => [
+                  {
+                    message_id => "invalid",
+                    data => {
+                      string_like => "string, allowing a string + any of: `any`, `null`, `RegExp`, `undefined`",
+                      type => "boolean",
+                    },
+                    line => 1,
+                    column => 17,
+                  },
+                ],
+              },
+              {
+                code => "let foo = 'a' + undefined;",
+                options => {
+                  allow_any => false,
+                  allow_boolean => false,
+                  allow_nullish => false,
+                  allow_reg_exp => false,
+                },
+                errors => [
+                  {
+                    message_id => "invalid",
+                    data => {
+                      string_like => "string",
+                      type => "undefined",
+                    },
+                    line => 1,
+                    column => 17,
+                  },
+                ],
+              },
+              {
+                code => "let foo = 'a' + (x as any);",
+                options => { allow_any => false },
+                errors => [{ message_id => "invalid", line => 1, column => 17 }],
+              },
+              {
+                code => "foo += {};",
+                errors => [{ message_id => "invalid", line => 1, column => 8 }],
+              },
+              {
+                code => r#"
+const a = 1n;
+foo += a + 1;
+                    "#,
+                errors => [{ message_id => "bigint_and_number", line => 3, column => 8 }],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_restrict_plus_operands_rule() {
+        run_for_typescript_and_tsx(
+            restrict_plus_operands_rule(),
+            test_cases::restrict_plus_operands_rule_tests(),
+        );
+    }
+}
@@ -0,0 +1,842 @@
+use std::{borrow::Cow, sync::Arc};
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    ast_helpers::is_tagged_template_expression,
+    kind::{
+        Array, ArrowFunction, BinaryExpression, CallExpression, Class, False, Function, Identifier,
+        NewExpression, Number, Object, Regex, String as StringKind, TemplateString,
+        TemplateSubstitution, True, UnaryExpression,
+    },
+};
+
+use crate::{
+    ast_helpers::NodeExtTypescript,
+    kind::{
+        ArrayType, AsExpression, ConstructorType, FunctionType, GenericType, LiteralType,
+        ObjectType, PredefinedType, ReadonlyType, SatisfiesExpression, TemplateLiteralType,
+        TupleType, TypeIdentifier, UnionType,
+    },
+    type_declarations::{TypeDeclarationKind, TypeDeclarations},
+    util::{
+        evaluate_constant_expression, get_declared_type, skip_parenthesized_expressions,
+        ConstantValue,
+    },
+};
+
+// (guards against eg `type A = B; type B = A;`)
+const MAX_ALIAS_EXPANSION_DEPTH: usize = 10;
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    allow_any: Option<bool>,
+    allow_array: Option<bool>,
+    allow_boolean: Option<bool>,
+    allow_never: Option<bool>,
+    allow_nullish: Option<bool>,
+    allow_number: Option<bool>,
+    allow_reg_exp: Option<bool>,
+}
+
+impl Options {
+    fn allow_any(&self) -> bool {
+        self.allow_any.unwrap_or(true)
+    }
+
+    fn allow_array(&self) -> bool {
+        self.allow_array.unwrap_or_default()
+    }
+
+    fn allow_boolean(&self) -> bool {
+        self.allow_boolean.unwrap_or(true)
+    }
+
+    fn allow_never(&self) -> bool {
+        self.allow_never.unwrap_or_default()
+    }
+
+    fn allow_nullish(&self) -> bool {
+        self.allow_nullish.unwrap_or(true)
+    }
+
+    fn allow_number(&self) -> bool {
+        self.allow_number.unwrap_or(true)
+    }
+
+    fn allow_reg_exp(&self) -> bool {
+        self.allow_reg_exp.unwrap_or(true)
+    }
+}
+
+/// What can be told about (a union member of) an interpolated expression's
+/// type without a type checker.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum InterpolationType {
+    Any,
+    Unknown,
+    Never,
+    Number,
+    BigInt,
+    String,
+    Boolean,
+    Null,
+    Undefined,
+    RegExp,
+    Symbol,
+    Array,
+    Object,
+}
+
+impl InterpolationType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Any => "any",
+            Self::Unknown => "unknown",
+            Self::Never => "never",
+            Self::Number => "number",
+            Self::BigInt => "bigint",
+            Self::String => "string",
+            Self::Boolean => "boolean",
+            Self::Null => "null",
+            Self::Undefined => "undefined",
+            Self::RegExp => "RegExp",
+            Self::Symbol => "symbol",
+            Self::Array => "array",
+            Self::Object => "object",
+        }
+    }
+}
+
+impl From<ConstantValue> for InterpolationType {
+    fn from(value: ConstantValue) -> Self {
+        match value {
+            ConstantValue::Number(_) => Self::Number,
+            ConstantValue::BigInt(_) => Self::BigInt,
+            ConstantValue::String(_) => Self::String,
+            ConstantValue::Boolean(_) => Self::Boolean,
+            ConstantValue::Null => Self::Null,
+            ConstantValue::Undefined => Self::Undefined,
+        }
+    }
+}
+
+/// The union members of a type annotation, looking through non-generic type
+/// aliases declared in the file. `None` if any of them can't be told from
+/// the syntax.
+fn get_annotated_types<'a>(
+    type_: Node<'a>,
+    type_declarations: &TypeDeclarations<'a>,
+    context: &QueryMatchContext<'a, '_>,
+    depth: usize,
+) -> Option<Vec<InterpolationType>> {
+    let type_ = type_.skip_parenthesized_types();
+    Some(vec![match type_.kind() {
+        PredefinedType => match &*type_.text(context) {
+            "any" => InterpolationType::Any,
+            "unknown" => InterpolationType::Unknown,
+            "never" => InterpolationType::Never,
+            "number" => InterpolationType::Number,
+            "bigint" => InterpolationType::BigInt,
+            "string" => InterpolationType::String,
+            "boolean" => InterpolationType::Boolean,
+            "symbol" => InterpolationType::Symbol,
+            "object" => InterpolationType::Object,
+            _ => return None,
+        },
+        LiteralType => match &*type_.text(context) {
+            "null" => InterpolationType::Null,
+            "undefined" => InterpolationType::Undefined,
+            _ => match type_
+                .first_non_comment_named_child(SupportedLanguage::Javascript)
+                .kind()
+            {
+                StringKind => InterpolationType::String,
+                Number | UnaryExpression => InterpolationType::Number,
+                True | False => InterpolationType::Boolean,
+                _ => return None,
+            },
+        },
+        TemplateLiteralType => InterpolationType::String,
+        ArrayType | TupleType => InterpolationType::Array,
+        ReadonlyType => {
+            return get_annotated_types(
+                type_.first_non_comment_named_child(SupportedLanguage::Javascript),
+                type_declarations,
+                context,
+                depth,
+            )
+        }
+        GenericType
+            if matches!(
+                &*type_.field("name").text(context),
+                "Array" | "ReadonlyArray"
+            ) =>
+        {
+            InterpolationType::Array
+        }
+        ObjectType | FunctionType | ConstructorType => InterpolationType::Object,
+        TypeIdentifier if type_.text(context) == "RegExp" => InterpolationType::RegExp,
+        TypeIdentifier => {
+            if depth >= MAX_ALIAS_EXPANSION_DEPTH {
+                return None;
+            }
+            let name = type_.text(context);
+            let mut aliases = type_declarations.get_of_kind(&name, TypeDeclarationKind::TypeAlias);
+            if let (Some(alias), None) = (aliases.next(), aliases.next()) {
+                if alias.child_by_field_name("type_parameters").is_some() {
+                    return None;
+                }
+                return get_annotated_types(
+                    alias.field("value"),
+                    type_declarations,
+                    context,
+                    depth + 1,
+                );
+            }
+            if type_declarations
+                .get_of_kind(&name, TypeDeclarationKind::Interface)
+                .next()
+                .is_none()
+            {
+                return None;
+            }
+            InterpolationType::Object
+        }
+        UnionType => {
+            let mut types = vec![];
+            for member in type_.non_comment_named_children(SupportedLanguage::Javascript) {
+                types.extend(get_annotated_types(
+                    member,
+                    type_declarations,
+                    context,
+                    depth,
+                )?);
+            }
+            return Some(types);
+        }
+        _ => return None,
+    }])
+}
+
+/// The (union members of the) type of an interpolated expression, along with
+/// how to describe it: literals (and `const` variables that fold to one),
+/// object/array/function/class expressions, variables with a type
+/// annotation, `as` assertions and operators whose result type is fixed.
+fn get_interpolation_types<'a>(
+    node: Node<'a>,
+    type_declarations: &TypeDeclarations<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<(Vec<InterpolationType>, Cow<'a, str>)> {
+    let node = skip_parenthesized_expressions(node);
+    let from_annotation = |type_: Node<'a>| {
+        get_annotated_types(type_, type_declarations, context, 0)
+            .map(|types| (types, type_.text(context)))
+    };
+    if let Some(value) = evaluate_constant_expression(node, context) {
+        let type_ = InterpolationType::from(value);
+        return Some((vec![type_], type_.as_str().into()));
+    }
+    let type_ = match node.kind() {
+        StringKind | TemplateString => InterpolationType::String,
+        Regex => InterpolationType::RegExp,
+        Array => InterpolationType::Array,
+        Object | ArrowFunction | Function | Class => InterpolationType::Object,
+        NewExpression => {
+            let constructor = node.field("constructor");
+            if constructor.kind() != Identifier || constructor.text(context) != "RegExp" {
+                return None;
+            }
+            InterpolationType::RegExp
+        }
+        CallExpression => {
+            let function = node.field("function");
+            if function.kind() != Identifier || function.text(context) != "Symbol" {
+                return None;
+            }
+            InterpolationType::Symbol
+        }
+        Identifier => return from_annotation(get_declared_type(node, context)?),
+        AsExpression => {
+            return from_annotation(
+                node.non_comment_named_children(SupportedLanguage::Javascript)
+                    .last()
+                    .unwrap(),
+            )
+        }
+        SatisfiesExpression => {
+            return get_interpolation_types(
+                node.first_non_comment_named_child(SupportedLanguage::Javascript),
+                type_declarations,
+                context,
+            )
+        }
+        UnaryExpression => match node.field("operator").kind() {
+            "typeof" => InterpolationType::String,
+            "void" => InterpolationType::Undefined,
+            "!" | "delete" => InterpolationType::Boolean,
+            _ => return None,
+        },
+        BinaryExpression => match node.field("operator").kind() {
+            "==" | "!=" | "===" | "!==" | "<" | "<=" | ">" | ">=" | "in" | "instanceof" => {
+                InterpolationType::Boolean
+            }
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some((vec![type_], type_.as_str().into()))
+}
+
+pub fn restrict_template_expressions_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "restrict-template-expressions",
+        languages => [Typescript],
+        messages => [
+            invalid_type => "Invalid type \"{{type}}\" of template literal expression.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            allow_any: bool = options.allow_any(),
+            allow_array: bool = options.allow_array(),
+            allow_boolean: bool = options.allow_boolean(),
+            allow_never: bool = options.allow_never(),
+            allow_nullish: bool = options.allow_nullish(),
+            allow_number: bool = options.allow_number(),
+            allow_reg_exp: bool = options.allow_reg_exp(),
+        },
+        methods => {
+            fn is_allowed(&self, type_: InterpolationType) -> bool {
+                match type_ {
+                    InterpolationType::String => true,
+                    InterpolationType::Any => self.allow_any,
+                    InterpolationType::Array => self.allow_array,
+                    InterpolationType::Boolean => self.allow_boolean,
+                    InterpolationType::Never => self.allow_never,
+                    InterpolationType::Null | InterpolationType::Undefined => self.allow_nullish,
+                    InterpolationType::Number | InterpolationType::BigInt => self.allow_number,
+                    InterpolationType::RegExp => self.allow_reg_exp,
+                    InterpolationType::Unknown | InterpolationType::Symbol | InterpolationType::Object => false,
+                }
+            }
+        },
+        listeners => [
+            r#"
+              (template_string) @c
+            "# => |node, context| {
+                // tagged templates can do whatever they want with their
+                // arguments (but a template nested inside one of their
+                // interpolations still gets its own visit)
+                if node.parent().is_some_and(|parent| {
+                    parent.kind() == CallExpression && is_tagged_template_expression(parent)
+                }) {
+                    return;
+                }
+
+                let type_declarations = context.retrieve::<TypeDeclarations<'a>>();
+                for substitution in node
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                    .filter(|child| child.kind() == TemplateSubstitution)
+                {
+                    let expression =
+                        substitution.first_non_comment_named_child(SupportedLanguage::Javascript);
+                    let Some((types, type_text)) =
+                        get_interpolation_types(expression, &type_declarations, context)
+                    else {
+                        continue;
+                    };
+                    if types.into_iter().all(|type_| self.is_allowed(type_)) {
+                        continue;
+                    }
+
+                    context.report(violation! {
+                        node => expression,
+                        message_id => "invalid_type",
+                        data => {
+                            type => type_text,
+                        },
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn restrict_template_expressions_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                // base case
+                "
+const msg = `arg = ${'foo'}`;
+                    ",
+                "
+const arg = 'foo';
+const msg = `arg = ${arg}`;
+                    ",
+                "
+const arg = 'foo';
+const msg = `arg = ${arg || 'default'}`;
+                    ",
+                "
+function test<T extends string>(arg: T) {
+  return `arg = ${arg}`;
+}
+                    ",
+                "
+function test(arg: `foo${string}`) {
+  return `arg = ${arg}`;
+}
+                    ",
+                "
+type Name = 'a' | 'b';
+function test(arg: Name) {
+  return `arg = ${arg}`;
+}
+                    ",
+                // tagged templates
+                "
+tag`${{}}`;
+                    ",
+                "
+function incrementTwice(arg: number[]) {
+  return tag`${arg}`;
+}
+                    ",
+                // allowNumber
+                {
+                  code => "
+const arg = 123;
+const msg = `arg = ${arg}`;
+                      ",
+                  options => { allow_number => true },
+                },
+                {
+                  code => "
+function test(arg: bigint | 1 | 2) {
+  return `arg = ${arg}`;
+}
+                      ",
+                  options => { allow_number => true },
+                },
+                // allowBoolean
+                {
+                  code => "
+const arg = true;
+const msg = `arg = ${arg}`;
+                      ",
+                  options => { allow_boolean => true },
+                },
+                {
+                  code => "
+function test(arg: boolean, other: string) {
+  return `${arg} ${other === 'x'}`;
+}
+                      ",
+                  options => { allow_boolean => true },
+                },
+                // allowAny
+                {
+                  code => "
+const arg: any = 123;
+const msg = `arg = ${arg}`;
+                      ",
+                  options => { allow_any => true },
+                },
+                {
+                  code => "
+function test(arg: any) {
+  return `arg = ${arg as any}`;
+}
+                      ",
+                  options => { allow_any => true },
+                },
+                // allowNullish
+                {
+                  code => "
+const arg = null;
+const msg = `arg = ${arg}`;
+                      ",
+                  options => { allow_nullish => true },
+                },
+                {
+                  code => "
+function test(arg: string | undefined) {
+  return `arg = ${arg}`;
+}
+                      ",
+                  options => { allow_nullish => true },
+                },
+                // allowRegExp
+                {
+                  code => "
+const msg = `arg = ${new RegExp('foo')}`;
+                      ",
+                  options => { allow_reg_exp => true },
+                },
+                {
+                  code => "
+const msg = `arg = ${/foo/}`;
+                      ",
+                  options => { allow_reg_exp => true },
+                },
+                // allowNever
+                {
+                  code => "
+declare const value: never;
+const stringy = `${value}`;
+                      ",
+                  options => { allow_never => true },
+                },
+                // allowArray
+                {
+                  code => "
+const msg = `arg = ${['foo', 'bar']}`;
+                      ",
+                  options => { allow_array => true },
+                },
+                {
+                  code => "
+function test(arg: readonly string[] | Array<number> | [string, number]) {
+  return `arg = ${arg}`;
+}
+                      ",
+                  options => { allow_array => true },
+                },
+                // nested templates
+                "
+const msg = `arg = ${`nested ${'foo'}`}`;
+                    ",
+                "
+function test(arg: string[]) {
+  return tag`${`nested ${arg.join(', ')}`}`;
+}
+                    ",
+                // unknowable without a type checker
+                "
+const msg = `arg = ${foo()}`;
+                    ",
+                "
+function test(arg: Foo) {
+  return `arg = ${arg}`;
+}
+                    ",
+            ],
+            invalid => [
+                {
+                  code => "
+const msg = `arg = ${123}`;
+                      ",
+                  options => { allow_number => false },
+                  errors => [
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "number" },
+                      line => 2,
+                      column => 22,
+                    },
+                  ],
+                },
+                {
+                  code => "
+const msg = `arg = ${false}`;
+                      ",
+                  options => { allow_boolean => false },
+                  errors => [
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "boolean" },
+                      line => 2,
+                      column => 22,
+                    },
+                  ],
+                },
+                {
+                  code => "
+const msg = `arg = ${null}`;
+                      ",
+                  options => { allow_nullish => false },
+                  errors => [
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "null" },
+                      line => 2,
+                      column => 22,
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare const arg: number | undefined;
+const msg = `arg = ${arg}`;
+                      ",
+                  options => { allow_nullish => false },
+                  errors => [
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "number | undefined" },
+                      line => 3,
+                      column => 22,
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare const arg: bigint;
+const msg = `arg = ${arg}`;
+                      ",
+                  options => { allow_number => false },
+                  errors => [
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "bigint" },
+                      line => 3,
+                    },
+                  ],
+                },
+                {
+                  code => "
+const msg = `arg = ${[]}`;
+                      ",
+                  errors => [
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "array" },
+                      line => 2,
+                      column => 22,
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare const arg: string[];
+const msg = `arg = ${arg}`;
+                      ",
+                  options => { allow_array => false },
+                  errors => [
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "string[]" },
+                      line => 3,
+                      column => 22,
+                    },
+                  ],
+                },
+                {
+                  code => "
+type Names = readonly string[];
+declare const names: Names;
+const msg = `names = ${names}`;
+                      ",
+                  errors => [
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "Names" },
+                      line => 4,
+                      column => 24,
+                    },
+                  ],
+                },
+                {
+                  code => "
+const msg = `arg = ${{}}`;
+                      ",
+                  errors => [
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "object" },
+                      line => 2,
+                      column => 22,
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare const arg: { a: string };
+const msg = `${arg}`;
+                      ",
+                  errors => [
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "{ a: string }" },
+                      line => 3,
+                      column => 16,
+                    },
+                  ],
+                },
+                {
+                  code => "
+interface Foo {
+  a: string;
+}
+function test(arg: Foo) {
+  return `arg = ${arg}`;
+}
+                      ",
+                  errors => [
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "Foo" },
+                      line => 6,
+                    },
+                  ],
+                },
+                {
+                  code => "
+const msg = `arg = ${Symbol('foo')}`;
+                      ",
+                  errors => [
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "symbol" },
+                    },
+                  ],
+                },
+                {
+                  code => "
+function test(arg: unknown) {
+  return `arg = ${arg}`;
+}
+                      ",
+                  errors => [
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "unknown" },
+                      line => 3,
+                      column => 19,
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare const arg: () => void;
+const msg = `arg = ${arg}`;
+                      ",
+                  errors => [
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "() => void" },
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare const arg: any;
+const msg = `arg = ${arg}`;
+                      ",
+                  options => { allow_any => false },
+                  errors => [
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "any" },
+                    },
+                  ],
+                },
+                {
+                  code => "
+const msg = `arg = ${/foo/}`;
+                      ",
+                  options => { allow_reg_exp => false },
+                  errors => [
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "RegExp" },
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare const value: never;
+const stringy = `${value}`;
+                      ",
+                  errors => [
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "never" },
+                      line => 3,
+                      column => 20,
+                    },
+                  ],
+                },
+                // nested templates
+                {
+                  code => "
+declare const arg: object;
+const msg = `outer ${`inner ${arg}`}`;
+                      ",
+                  errors => [
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "object" },
+                      line => 3,
+                      column => 31,
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare const arg: object;
+const msg = tag`outer ${`inner ${arg}`}`;
+                      ",
+                  errors => [
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "object" },
+                      line => 3,
+                      column => 34,
+                    },
+                  ],
+                },
+                {
+                  code => "
+declare const a: symbol;
+declare const b: string[];
+const msg = `${a} and ${b} and ${`${b}`}`;
+                      ",
+                  errors => [
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "symbol" },
+                      line => 4,
+                      column => 16,
+                    },
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "string[]" },
+                      line => 4,
+                      column => 25,
+                    },
+                    {
+                      message_id => "invalid_type",
+                      data => { type => "string[]" },
+                      line => 4,
+                      column => 37,
+                    },
+                  ],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx_with_provided_types;
+
+    #[test]
+    fn test_restrict_template_expressions_rule() {
+        run_for_typescript_and_tsx_with_provided_types(
+            restrict_template_expressions_rule(),
+            test_cases::restrict_template_expressions_rule_tests(),
+        );
+    }
+}
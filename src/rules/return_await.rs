@@ -0,0 +1,293 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    range_between_starts, rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation,
+    NodeExt, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    AwaitExpression, ConditionalExpression, StatementBlock,
+};
+
+use crate::{
+    try_contexts::{TryContext, TryContexts},
+    util::skip_parenthesized_expressions,
+};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Options {
+    #[default]
+    InTryCatch,
+    Always,
+    Never,
+}
+
+/// The nodes whose value `return node` might end up returning.
+fn get_possibly_returned_nodes(node: Node) -> Vec<Node> {
+    let node = skip_parenthesized_expressions(node);
+    match node.kind() {
+        ConditionalExpression => get_possibly_returned_nodes(node.field("consequence"))
+            .into_iter()
+            .chain(get_possibly_returned_nodes(node.field("alternative")))
+            .collect(),
+        _ => vec![node],
+    }
+}
+
+/// Removing the `await` changes behavior if a rejection would then no longer
+/// get caught, or the `finally` would run before the promise settles.
+fn is_await_removal_safe(node: Node, try_contexts: &TryContexts) -> bool {
+    !matches!(
+        try_contexts.get(node),
+        Some(TryContext::Try | TryContext::Catch { has_finally: true })
+    )
+}
+
+pub fn return_await_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "return-await",
+        languages => [Typescript],
+        messages => [
+            non_promise_await => "Returning an awaited value that is not a promise is not allowed.",
+            disallowed_promise_await => "Returning an awaited promise is not allowed in this context.",
+            required_promise_await => "Returning an awaited promise is required in this context.",
+        ],
+        fixable => true,
+        options_type => Options,
+        state => {
+            [per-config]
+            option: Options = options,
+        },
+        listeners => [
+            r#"
+              (return_statement
+                (_) @c
+              )
+              (arrow_function
+                body: (_) @c
+              )
+            "# => |node, context| {
+                // TODO: in-try-catch and always need to know whether the
+                // returned value is a promise
+                if self.option != Options::Never {
+                    return;
+                }
+                if node.kind() == StatementBlock {
+                    return;
+                }
+
+                let try_contexts = context.retrieve::<TryContexts>();
+                for returned in get_possibly_returned_nodes(node) {
+                    if returned.kind() != AwaitExpression {
+                        continue;
+                    }
+
+                    let argument = returned.first_non_comment_named_child(SupportedLanguage::Javascript);
+                    let is_fixable = is_await_removal_safe(returned, &try_contexts);
+                    context.report(violation! {
+                        node => returned,
+                        message_id => "disallowed_promise_await",
+                        fix => |fixer| {
+                            if !is_fixable {
+                                return;
+                            }
+                            fixer.remove_range(range_between_starts(returned.range(), argument.range()));
+                        },
+                    });
+                }
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn return_await_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+              {
+                code => r#"
+async function test() {
+  return Promise.resolve(1);
+}
+                    "#,
+                options => "never",
+              },
+              {
+                code => r#"
+async function test() {
+  const value = await Promise.resolve(1);
+  return value;
+}
+                    "#,
+                options => "never",
+              },
+              {
+                code => "const test = async () => Promise.resolve(1);",
+                options => "never",
+              },
+              {
+                code => r#"
+async function test() {
+  return cond ? Promise.resolve(1) : 2;
+}
+                    "#,
+                options => "never",
+              },
+              r#"
+async function test() {
+  return await Promise.resolve(1);
+}
+                  "#,
+            ],
+            invalid => [
+              {
+                code => r#"
+async function test() {
+  return await Promise.resolve(1);
+}
+                    "#,
+                output => r#"
+async function test() {
+  return Promise.resolve(1);
+}
+                    "#,
+                options => "never",
+                errors => [
+                  {
+                    message_id => "disallowed_promise_await",
+                    line => 3,
+                    column => 10,
+                    end_line => 3,
+                    end_column => 34,
+                  },
+                ],
+              },
+              {
+                code => "const test = async () => await Promise.resolve(1);",
+                output => "const test = async () => Promise.resolve(1);",
+                options => "never",
+                errors => [{ message_id => "disallowed_promise_await", line => 1, column => 26 }],
+              },
+              {
+                code => r#"
+async function test() {
+  return cond ? await a : (await b);
+}
+                    "#,
+                output => r#"
+async function test() {
+  return cond ? a : (b);
+}
+                    "#,
+                options => "never",
+                errors => [
+                  { message_id => "disallowed_promise_await", line => 3, column => 17 },
+                  { message_id => "disallowed_promise_await", line => 3, column => 28 },
+                ],
+              },
+              {
+                code => r#"
+async function test() {
+  try {
+    return await Promise.resolve(1);
+  } catch (e) {
+    return await Promise.resolve(2);
+  } finally {
+    console.log('done');
+  }
+}
+                    "#,
+                options => "never",
+                errors => [
+                  { message_id => "disallowed_promise_await", line => 4, column => 12 },
+                  { message_id => "disallowed_promise_await", line => 6, column => 12 },
+                ],
+              },
+              {
+                code => r#"
+async function test() {
+  try {
+    throw new Error();
+  } catch (e) {
+    return await Promise.resolve(2);
+  }
+}
+                    "#,
+                output => r#"
+async function test() {
+  try {
+    throw new Error();
+  } catch (e) {
+    return Promise.resolve(2);
+  }
+}
+                    "#,
+                options => "never",
+                errors => [{ message_id => "disallowed_promise_await", line => 6, column => 12 }],
+              },
+              {
+                code => r#"
+async function test() {
+  try {
+    doSomething();
+  } finally {
+    return await Promise.resolve(1);
+  }
+}
+                    "#,
+                output => r#"
+async function test() {
+  try {
+    doSomething();
+  } finally {
+    return Promise.resolve(1);
+  }
+}
+                    "#,
+                options => "never",
+                errors => [{ message_id => "disallowed_promise_await", line => 6, column => 12 }],
+              },
+              {
+                code => r#"
+async function test() {
+  try {
+    const callback = async () => {
+      return await Promise.resolve(1);
+    };
+  } catch {}
+}
+                    "#,
+                output => r#"
+async function test() {
+  try {
+    const callback = async () => {
+      return Promise.resolve(1);
+    };
+  } catch {}
+}
+                    "#,
+                options => "never",
+                errors => [{ message_id => "disallowed_promise_await", line => 5, column => 14 }],
+              },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx_with_provided_types;
+
+    #[test]
+    fn test_return_await_rule() {
+        run_for_typescript_and_tsx_with_provided_types(
+            return_await_rule(),
+            test_cases::return_await_rule_tests(),
+        );
+    }
+}
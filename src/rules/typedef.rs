@@ -0,0 +1,952 @@
+use std::{borrow::Cow, sync::Arc};
+
+use serde::Deserialize;
+use tree_sitter_lint::{
+    rule, tree_sitter::Node, tree_sitter_grep::SupportedLanguage, violation, NodeExt,
+    QueryMatchContext, Rule,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    ArrayPattern, ArrowFunction, AssignmentExpression, ForInStatement, Function,
+    FunctionDeclaration, GeneratorFunction, GeneratorFunctionDeclaration, Identifier,
+    LexicalDeclaration, ObjectPattern, PairPattern, PropertyIdentifier, RestPattern,
+    VariableDeclaration, VariableDeclarator,
+};
+
+use crate::{
+    ast_helpers::{is_contextually_typed_position, is_parameter_property},
+    kind::{MappedTypeClause, OptionalParameter, PropertySignature, RequiredParameter},
+};
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    array_destructuring: Option<bool>,
+    arrow_parameter: Option<bool>,
+    member_variable_declaration: Option<bool>,
+    object_destructuring: Option<bool>,
+    parameter: Option<bool>,
+    property_declaration: Option<bool>,
+    variable_declaration: Option<bool>,
+    variable_declaration_ignore_function: Option<bool>,
+}
+
+impl Options {
+    fn array_destructuring(&self) -> bool {
+        self.array_destructuring.unwrap_or_default()
+    }
+
+    fn arrow_parameter(&self) -> bool {
+        self.arrow_parameter.unwrap_or_default()
+    }
+
+    fn member_variable_declaration(&self) -> bool {
+        self.member_variable_declaration.unwrap_or_default()
+    }
+
+    fn object_destructuring(&self) -> bool {
+        self.object_destructuring.unwrap_or_default()
+    }
+
+    fn parameter(&self) -> bool {
+        self.parameter.unwrap_or_default()
+    }
+
+    fn property_declaration(&self) -> bool {
+        self.property_declaration.unwrap_or_default()
+    }
+
+    fn variable_declaration(&self) -> bool {
+        self.variable_declaration.unwrap_or_default()
+    }
+
+    fn variable_declaration_ignore_function(&self) -> bool {
+        self.variable_declaration_ignore_function
+            .unwrap_or_default()
+    }
+}
+
+fn report<'a>(node: Node<'a>, name: Option<Cow<'a, str>>, context: &QueryMatchContext<'a, '_>) {
+    match name {
+        Some(name) => context.report(violation! {
+            node => node,
+            message_id => "expected_typedef_named",
+            data => {
+                name => name,
+            },
+        }),
+        None => context.report(violation! {
+            node => node,
+            message_id => "expected_typedef",
+        }),
+    }
+}
+
+fn get_identifier_name<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Cow<'a, str>> {
+    matches!(node.kind(), Identifier | PropertyIdentifier).then(|| node.text(context))
+}
+
+// like upstream, only plain parameters (without a default value and that
+// aren't parameter properties) get named
+fn get_parameter_name<'a>(
+    parameter: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Cow<'a, str>> {
+    if parameter.kind() == Identifier {
+        return Some(parameter.text(context));
+    }
+    if parameter.child_by_field_name("value").is_some() || is_parameter_property(parameter) {
+        return None;
+    }
+    get_identifier_name(parameter.field("pattern"), context)
+}
+
+fn check_parameters<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) {
+    // a lone arrow function parameter without parentheses (which therefore
+    // can't have a type annotation)
+    if let Some(parameter) = node.child_by_field_name("parameter") {
+        report(parameter, get_parameter_name(parameter, context), context);
+        return;
+    }
+
+    for parameter in node
+        .field("parameters")
+        .non_comment_named_children(SupportedLanguage::Javascript)
+        .filter(|parameter| matches!(parameter.kind(), RequiredParameter | OptionalParameter))
+    {
+        if parameter.child_by_field_name("type").is_some() {
+            continue;
+        }
+        report(parameter, get_parameter_name(parameter, context), context);
+    }
+}
+
+fn is_function_expression(node: Node) -> bool {
+    matches!(node.kind(), ArrowFunction | Function | GeneratorFunction)
+}
+
+fn has_type_annotation(pattern: Node) -> bool {
+    let mut owner = pattern.parent().unwrap();
+    if owner.kind() == RestPattern {
+        owner = owner.parent().unwrap();
+    }
+    matches!(
+        owner.kind(),
+        VariableDeclarator | RequiredParameter | OptionalParameter
+    ) && owner.child_by_field_name("type").is_some()
+}
+
+fn is_for_of_statement_context(pattern: Node) -> bool {
+    for ancestor in pattern.ancestors() {
+        match ancestor.kind() {
+            VariableDeclarator | LexicalDeclaration | VariableDeclaration | ObjectPattern
+            | ArrayPattern | PairPattern => (),
+            ForInStatement => return ancestor.field("operator").kind() == "of",
+            _ => return false,
+        }
+    }
+    false
+}
+
+fn ancestor_has_type_annotation(pattern: Node) -> bool {
+    pattern.ancestors().any(|ancestor| {
+        matches!(ancestor.kind(), ObjectPattern | ArrayPattern) && has_type_annotation(ancestor)
+    })
+}
+
+fn should_report_destructuring(pattern: Node) -> bool {
+    !has_type_annotation(pattern)
+        && !is_for_of_statement_context(pattern)
+        && !ancestor_has_type_annotation(pattern)
+}
+
+pub fn typedef_rule() -> Arc<dyn Rule> {
+    rule! {
+        name => "typedef",
+        languages => [Typescript],
+        messages => [
+            expected_typedef => "Expected a type annotation.",
+            expected_typedef_named => "Expected {{name}} to have a type annotation.",
+        ],
+        options_type => Options,
+        state => {
+            [per-config]
+            array_destructuring: bool = options.array_destructuring(),
+            arrow_parameter: bool = options.arrow_parameter(),
+            member_variable_declaration: bool = options.member_variable_declaration(),
+            object_destructuring: bool = options.object_destructuring(),
+            parameter: bool = options.parameter(),
+            property_declaration: bool = options.property_declaration(),
+            variable_declaration: bool = options.variable_declaration(),
+            variable_declaration_ignore_function: bool = options.variable_declaration_ignore_function(),
+        },
+        methods => {
+            fn is_variable_declaration_ignore_function(&self, node: Node) -> bool {
+                self.variable_declaration_ignore_function && is_function_expression(node)
+            }
+        },
+        listeners => [
+            r#"
+              (array_pattern) @c
+            "# => |node, context| {
+                if !self.array_destructuring {
+                    return;
+                }
+
+                if should_report_destructuring(node)
+                    && node.parent().unwrap().kind() != AssignmentExpression
+                {
+                    report(node, None, context);
+                }
+            },
+            r#"
+              (object_pattern) @c
+            "# => |node, context| {
+                if !self.object_destructuring {
+                    return;
+                }
+
+                if should_report_destructuring(node) {
+                    report(node, None, context);
+                }
+            },
+            r#"
+              (arrow_function) @c
+            "# => |node, context| {
+                if !self.arrow_parameter || is_contextually_typed_position(node, context) {
+                    return;
+                }
+
+                check_parameters(node, context);
+            },
+            r#"
+              (function_declaration) @c
+              (function) @c
+              (generator_function_declaration) @c
+              (generator_function) @c
+              (method_definition) @c
+            "# => |node, context| {
+                if !self.parameter {
+                    return;
+                }
+                if !matches!(node.kind(), FunctionDeclaration | GeneratorFunctionDeclaration)
+                    && is_contextually_typed_position(node, context)
+                {
+                    return;
+                }
+
+                check_parameters(node, context);
+            },
+            r#"
+              (public_field_definition) @c
+            "# => |node, context| {
+                if !self.member_variable_declaration
+                    || node.child_by_field_name("type").is_some()
+                {
+                    return;
+                }
+                if node
+                    .child_by_field_name("value")
+                    .is_some_and(|value| self.is_variable_declaration_ignore_function(value))
+                {
+                    return;
+                }
+
+                report(node, get_identifier_name(node.field("name"), context), context);
+            },
+            r#"
+              (property_signature) @c
+              (index_signature) @c
+            "# => |node, context| {
+                if !self.property_declaration
+                    || node.child_by_field_name("type").is_some()
+                    // (a mapped type)
+                    || node.has_child_of_kind(MappedTypeClause)
+                {
+                    return;
+                }
+
+                report(
+                    node,
+                    if node.kind() == PropertySignature {
+                        get_identifier_name(node.field("name"), context)
+                    } else {
+                        None
+                    },
+                    context,
+                );
+            },
+            r#"
+              (variable_declarator) @c
+            "# => |node, context| {
+                if !self.variable_declaration || node.child_by_field_name("type").is_some() {
+                    return;
+                }
+                let name = node.field("name");
+                match name.kind() {
+                    ArrayPattern if !self.array_destructuring => return,
+                    ObjectPattern if !self.object_destructuring => return,
+                    _ => (),
+                }
+                if node
+                    .child_by_field_name("value")
+                    .is_some_and(|value| self.is_variable_declaration_ignore_function(value))
+                {
+                    return;
+                }
+
+                report(node, get_identifier_name(name, context), context);
+            },
+        ],
+    }
+}
+
+#[cfg(any(test, feature = "docs"))]
+pub mod test_cases {
+    use tree_sitter_lint::{rule_tests, RuleTests};
+
+    pub fn typedef_rule_tests() -> RuleTests {
+        rule_tests! {
+            valid => [
+                // Array destructuring
+                {
+                  code => "function foo(...args: number[]) {}",
+                  options => { array_destructuring => true },
+                },
+                {
+                  code => "function foo(...[a]: number[]) {}",
+                  options => { array_destructuring => true },
+                },
+                {
+                  code => "const [a]: [number] = [1];",
+                  options => { array_destructuring => true },
+                },
+                {
+                  code => "const [a, b]: [number, number] = [1, 2];",
+                  options => { array_destructuring => true },
+                },
+                {
+                  code => "const [[a]]: number[][] = [[1]];",
+                  options => { array_destructuring => true },
+                },
+                {
+                  code => "
+                        let a: number;
+                        [a] = [1];
+                      ",
+                  options => { array_destructuring => true },
+                },
+                {
+                  code => "
+                        for (const [key, val] of new Map([['key', 1]])) {
+                        }
+                      ",
+                  options => { array_destructuring => true },
+                },
+                {
+                  code => "
+                        for (const [[key]] of [[['key']]]) {
+                        }
+                      ",
+                  options => { array_destructuring => true },
+                },
+                "let a: number = 1;",
+                "const [a] = [1];",
+                // Arrow parameters
+                {
+                  code => "((a: number): void => {})();",
+                  options => { arrow_parameter => true },
+                },
+                {
+                  code => "((a: string, b: string): void => {})();",
+                  options => { arrow_parameter => true },
+                },
+                {
+                  code => "((a: number) => {})();",
+                  options => { arrow_parameter => true },
+                },
+                {
+                  code => "const receivesNumbers = (): void => {};",
+                  options => { arrow_parameter => true },
+                },
+                "const receivesNumber = (a): void => {};",
+                // callbacks get their parameters' types from where
+                // they're passed
+                {
+                  code => "['hello', 'world'].map(text => text.length);",
+                  options => { arrow_parameter => true },
+                },
+                {
+                  code => "['hello', 'world'].map((text, index) => text.length + index);",
+                  options => { arrow_parameter => true },
+                },
+                {
+                  code => "const mapper: (text: string) => number = text => text.length;",
+                  options => { arrow_parameter => true },
+                },
+                {
+                  code => "const handlers: Handlers = { onClick: event => {} };",
+                  options => { arrow_parameter => true },
+                },
+                {
+                  code => "
+                        function makeMapper(): (text: string) => number {
+                          return text => text.length;
+                        }
+                      ",
+                  options => { arrow_parameter => true },
+                },
+                {
+                  code => "
+                        function apply(callback: (value: number) => void) {}
+                        apply(value => {});
+                      ",
+                  options => { arrow_parameter => true },
+                },
+                {
+                  code => "
+                        function applyAll(first: string, ...callbacks: Array<(value: number) => void>) {}
+                        applyAll('', a => {}, b => {});
+                      ",
+                  options => { arrow_parameter => true },
+                },
+                {
+                  code => "
+                        const handlers: Handlers = {
+                          onClick(event) {},
+                        };
+                      ",
+                  options => { parameter => true },
+                },
+                {
+                  code => "['hello', 'world'].map(function (text) { return text.length; });",
+                  options => { parameter => true },
+                },
+                // Member variable declarations
+                {
+                  code => "
+                        class Test {
+                          state: number;
+                        }
+                      ",
+                  options => { member_variable_declaration => true },
+                },
+                {
+                  code => "
+                        class Test {
+                          state: number = 1;
+                        }
+                      ",
+                  options => { member_variable_declaration => true },
+                },
+                {
+                  code => "
+                        class Test {
+                          state = 1;
+                        }
+                      ",
+                  options => { member_variable_declaration => false },
+                },
+                {
+                  code => "
+                        class Test {
+                          handler = (): void => {};
+                        }
+                      ",
+                  options => {
+                    member_variable_declaration => true,
+                    variable_declaration_ignore_function => true,
+                  },
+                },
+                // Object destructuring
+                {
+                  code => "const { a }: { a: number } = { a: 1 };",
+                  options => { object_destructuring => true },
+                },
+                {
+                  code => "const { a: { b } }: { a: { b: number } } = { a: { b: 1 } };",
+                  options => { object_destructuring => true },
+                },
+                {
+                  code => "
+                        for (const { key, val } of [{ key: 'key', val: 1 }]) {
+                        }
+                      ",
+                  options => { object_destructuring => true },
+                },
+                "const { a } = { a: 1 };",
+                // Function parameters
+                {
+                  code => "function receivesNumber(a: number): void {}",
+                  options => { parameter => true },
+                },
+                {
+                  code => "function receivesStrings(a: string, b: string): void {}",
+                  options => { parameter => true },
+                },
+                {
+                  code => "function receivesNumber([a]: [number]): void {}",
+                  options => { parameter => true },
+                },
+                {
+                  code => "function receivesNumbers({ a }: { a: number }): void {}",
+                  options => { parameter => true },
+                },
+                {
+                  code => "function receivesOptional(a?: number): void {}",
+                  options => { parameter => true },
+                },
+                {
+                  code => "
+                        class Test {
+                          constructor(private param: string = 'something') {}
+                        }
+                      ",
+                  options => { parameter => true },
+                },
+                {
+                  code => "
+                        class Test {
+                          method(x: number): number {
+                            return x;
+                          }
+                        }
+                      ",
+                  options => { parameter => true },
+                },
+                "function receivesNumber(a): void {}",
+                // Property declarations
+                {
+                  code => "
+                        type Test = {
+                          member: number;
+                        };
+                      ",
+                  options => { property_declaration => true },
+                },
+                {
+                  code => "
+                        interface Test {
+                          member: number;
+                        }
+                      ",
+                  options => { property_declaration => true },
+                },
+                {
+                  code => "
+                        type Test = {
+                          [i: string]: number;
+                        };
+                      ",
+                  options => { property_declaration => true },
+                },
+                {
+                  code => "type Test = { [K in Keys]: number };",
+                  options => { property_declaration => true },
+                },
+                {
+                  code => "
+                        interface Test {
+                          member;
+                        }
+                      ",
+                  options => { property_declaration => false },
+                },
+                // Variable declarations
+                {
+                  code => "const x: string = '';",
+                  options => { variable_declaration => true },
+                },
+                {
+                  code => "let x: string = '';",
+                  options => { variable_declaration => true },
+                },
+                {
+                  code => "let x: string;",
+                  options => { variable_declaration => true },
+                },
+                {
+                  code => "const [a, b] = [1, 2];",
+                  options => { object_destructuring => false, variable_declaration => true },
+                },
+                {
+                  code => "const { a } = { a: 1 };",
+                  options => { object_destructuring => false, variable_declaration => true },
+                },
+                {
+                  code => "
+                        for (const i of [0, 1, 2]) {
+                        }
+                      ",
+                  options => { variable_declaration => true },
+                },
+                {
+                  code => "
+                        for (const i in [0, 1, 2]) {
+                        }
+                      ",
+                  options => { variable_declaration => true },
+                },
+                {
+                  code => "const foo = function (): void {};",
+                  options => {
+                    variable_declaration => true,
+                    variable_declaration_ignore_function => true,
+                  },
+                },
+                {
+                  code => "const foo = (): void => {};",
+                  options => {
+                    variable_declaration => true,
+                    variable_declaration_ignore_function => true,
+                  },
+                },
+            ],
+            invalid => [
+                // Array destructuring
+                {
+                  code => "const [a] = [1];",
+                  options => { array_destructuring => true },
+                  errors => [{ message_id => "expected_typedef", line => 1, column => 7 }],
+                },
+                {
+                  code => "const [a, b] = [1, 2];",
+                  options => { array_destructuring => true },
+                  errors => [{ message_id => "expected_typedef" }],
+                },
+                {
+                  code => "function foo([a]) {}",
+                  options => { array_destructuring => true },
+                  errors => [{ message_id => "expected_typedef", column => 14 }],
+                },
+                // Arrow parameters
+                {
+                  code => "const receivesNumber = (a): void => {};",
+                  options => { arrow_parameter => true },
+                  errors => [
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "a" },
+                      column => 25,
+                    },
+                  ],
+                },
+                {
+                  code => "const receivesStrings = (a, b): void => {};",
+                  options => { arrow_parameter => true },
+                  errors => [
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "a" },
+                    },
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "b" },
+                    },
+                  ],
+                },
+                {
+                  code => "const receivesNumber = a => {};",
+                  options => { arrow_parameter => true },
+                  errors => [
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "a" },
+                      column => 24,
+                    },
+                  ],
+                },
+                {
+                  code => "const withDefault = (a = 1) => a;",
+                  options => { arrow_parameter => true },
+                  errors => [{ message_id => "expected_typedef" }],
+                },
+                {
+                  code => "
+                        function makeMapper() {
+                          return text => text.length;
+                        }
+                      ",
+                  options => { arrow_parameter => true },
+                  errors => [
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "text" },
+                      line => 3,
+                    },
+                  ],
+                },
+                {
+                  code => "
+                        function apply(callback) {}
+                        apply(value => {});
+                      ",
+                  options => { arrow_parameter => true },
+                  errors => [
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "value" },
+                      line => 3,
+                    },
+                  ],
+                },
+                {
+                  code => "
+                        const apply = (count: number, callback) => {};
+                        apply(1, value => {});
+                      ",
+                  options => { arrow_parameter => true },
+                  errors => [
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "callback" },
+                      line => 2,
+                    },
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "value" },
+                      line => 3,
+                    },
+                  ],
+                },
+                // Member variable declarations
+                {
+                  code => "
+                        class Test {
+                          state = 1;
+                        }
+                      ",
+                  options => { member_variable_declaration => true },
+                  errors => [
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "state" },
+                      line => 3,
+                    },
+                  ],
+                },
+                {
+                  code => "
+                        class Test {
+                          #state = 1;
+                        }
+                      ",
+                  options => { member_variable_declaration => true },
+                  errors => [{ message_id => "expected_typedef", line => 3 }],
+                },
+                {
+                  code => "
+                        class Test {
+                          handler = (): void => {};
+                        }
+                      ",
+                  options => { member_variable_declaration => true },
+                  errors => [
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "handler" },
+                    },
+                  ],
+                },
+                // Object destructuring
+                {
+                  code => "const { a } = { a: 1 };",
+                  options => { object_destructuring => true },
+                  errors => [{ message_id => "expected_typedef", column => 7 }],
+                },
+                {
+                  code => "function foo({ a }) {}",
+                  options => { object_destructuring => true },
+                  errors => [{ message_id => "expected_typedef", column => 14 }],
+                },
+                // Function parameters
+                {
+                  code => "function receivesNumber(a): void {}",
+                  options => { parameter => true },
+                  errors => [
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "a" },
+                      column => 25,
+                    },
+                  ],
+                },
+                {
+                  code => "function receivesStrings(a, b): void {}",
+                  options => { parameter => true },
+                  errors => [
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "a" },
+                    },
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "b" },
+                    },
+                  ],
+                },
+                {
+                  code => "function receivesNumber([a]): void {}",
+                  options => { parameter => true },
+                  errors => [{ message_id => "expected_typedef", column => 25 }],
+                },
+                {
+                  code => "function receivesNumbers({ a }): void {}",
+                  options => { parameter => true },
+                  errors => [{ message_id => "expected_typedef", column => 26 }],
+                },
+                {
+                  code => "function receivesDefault(a = 1): void {}",
+                  options => { parameter => true },
+                  errors => [{ message_id => "expected_typedef", column => 26 }],
+                },
+                {
+                  code => "const receivesNumber = function (a): void {};",
+                  options => { parameter => true },
+                  errors => [
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "a" },
+                    },
+                  ],
+                },
+                {
+                  code => "
+                        class Test {
+                          constructor(private param = 'something') {}
+                        }
+                      ",
+                  options => { parameter => true },
+                  errors => [{ message_id => "expected_typedef", line => 3 }],
+                },
+                {
+                  code => "
+                        class Test {
+                          method(x) {
+                            return x;
+                          }
+                        }
+                      ",
+                  options => { parameter => true },
+                  errors => [
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "x" },
+                      line => 3,
+                    },
+                  ],
+                },
+                // Property declarations
+                {
+                  code => "
+                        type Test = {
+                          member;
+                        };
+                      ",
+                  options => { property_declaration => true },
+                  errors => [
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "member" },
+                      line => 3,
+                    },
+                  ],
+                },
+                {
+                  code => "
+                        interface Test {
+                          member;
+                        }
+                      ",
+                  options => { property_declaration => true },
+                  errors => [
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "member" },
+                      line => 3,
+                    },
+                  ],
+                },
+                // Variable declarations
+                {
+                  code => "const a = 1;",
+                  options => { variable_declaration => true },
+                  errors => [
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "a" },
+                      column => 7,
+                    },
+                  ],
+                },
+                {
+                  code => "
+                        const a = 1,
+                          b: number = 2,
+                          c = 3;
+                      ",
+                  options => { variable_declaration => true },
+                  errors => [
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "a" },
+                      line => 2,
+                    },
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "c" },
+                      line => 4,
+                    },
+                  ],
+                },
+                {
+                  code => "
+                        for (let i = 0; i < 3; i++) {
+                        }
+                      ",
+                  options => { variable_declaration => true },
+                  errors => [
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "i" },
+                    },
+                  ],
+                },
+                {
+                  code => "const [a, b] = [1, 2];",
+                  options => { array_destructuring => true, variable_declaration => true },
+                  errors => [
+                    { message_id => "expected_typedef", column => 7 },
+                    { message_id => "expected_typedef", column => 7 },
+                  ],
+                },
+                {
+                  code => "const foo = (): void => {};",
+                  options => {
+                    variable_declaration => true,
+                    variable_declaration_ignore_function => false,
+                  },
+                  errors => [
+                    {
+                      message_id => "expected_typedef_named",
+                      data => { name => "foo" },
+                    },
+                  ],
+                },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_grammar::run_for_typescript_and_tsx;
+
+    #[test]
+    fn test_typedef_rule() {
+        run_for_typescript_and_tsx(typedef_rule(), test_cases::typedef_rule_tests());
+    }
+}
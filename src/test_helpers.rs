@@ -0,0 +1,199 @@
+//! Builders for generating rule test cases programmatically, for rules whose
+//! upstream tests are grids of code snippets and options rather than
+//! individually written cases. Generated cases get spliced into
+//! `rule_tests!` with `...`.
+
+use tree_sitter_lint::{
+    serde_json::{Map, Value},
+    RuleTestExpectedError, RuleTestExpectedErrorBuilder, RuleTestInvalid, RuleTestInvalidBuilder,
+    RuleTestValid, RuleTestValidBuilder,
+};
+
+pub fn valid_case(code: impl Into<String>, options: Value) -> RuleTestValid {
+    RuleTestValidBuilder::default()
+        .code(code.into())
+        .options(options)
+        .build()
+        .unwrap()
+}
+
+/// An expected error with the given message id and (unless it's empty)
+/// data, to which a position can be added before building it.
+pub fn expected_error<const N: usize>(
+    message_id: &'static str,
+    data: [(&str, &str); N],
+) -> RuleTestExpectedErrorBuilder {
+    let mut builder = RuleTestExpectedErrorBuilder::default();
+    builder.message_id(message_id);
+    if N > 0 {
+        builder.data(data.map(|(key, value)| (key.to_owned(), value.to_owned())));
+    }
+    builder
+}
+
+fn invalid_case_builder(
+    code: impl Into<String>,
+    options: Value,
+    errors: Vec<RuleTestExpectedError>,
+) -> RuleTestInvalidBuilder {
+    let mut builder = RuleTestInvalidBuilder::default();
+    builder.code(code.into()).options(options).errors(errors);
+    builder
+}
+
+/// An invalid case whose errors aren't fixable.
+pub fn invalid_case(
+    code: impl Into<String>,
+    options: Value,
+    errors: Vec<RuleTestExpectedError>,
+) -> RuleTestInvalid {
+    invalid_case_builder(code, options, errors).build().unwrap()
+}
+
+pub fn invalid_case_with_output(
+    code: impl Into<String>,
+    output: impl Into<String>,
+    options: Value,
+    errors: Vec<RuleTestExpectedError>,
+) -> RuleTestInvalid {
+    invalid_case_builder(code, options, errors)
+        .output(output.into())
+        .build()
+        .unwrap()
+}
+
+/// The combinations of option values to generate test cases for, eg every
+/// `default` with every `readonly` (including not giving one).
+#[derive(Default)]
+pub struct OptionMatrix {
+    axes: Vec<(&'static str, Vec<Option<Value>>)>,
+    wrap: Option<fn(Value) -> Value>,
+}
+
+impl OptionMatrix {
+    pub fn axis<TValue: Into<Value>>(
+        mut self,
+        name: &'static str,
+        values: impl IntoIterator<Item = TValue>,
+    ) -> Self {
+        self.axes.push((
+            name,
+            values.into_iter().map(|value| Some(value.into())).collect(),
+        ));
+        self
+    }
+
+    /// Like `axis()` but also includes the combinations where the option
+    /// isn't given at all.
+    pub fn optional_axis<TValue: Into<Value>>(
+        mut self,
+        name: &'static str,
+        values: impl IntoIterator<Item = TValue>,
+    ) -> Self {
+        self.axes.push((
+            name,
+            [None]
+                .into_iter()
+                .chain(values.into_iter().map(|value| Some(value.into())))
+                .collect(),
+        ));
+        self
+    }
+
+    /// Makes each combination into the rule's options with `wrap`, for rules
+    /// whose varying options aren't at the top level (eg naming-convention's
+    /// list of selectors).
+    pub fn wrap(mut self, wrap: fn(Value) -> Value) -> Self {
+        self.wrap = Some(wrap);
+        self
+    }
+
+    pub fn combinations(&self) -> Vec<Value> {
+        self.axes
+            .iter()
+            .fold(vec![Map::new()], |combinations, (name, values)| {
+                combinations
+                    .iter()
+                    .flat_map(|combination| {
+                        values.iter().map(move |value| {
+                            let mut combination = combination.clone();
+                            if let Some(value) = value {
+                                combination.insert((*name).to_owned(), value.clone());
+                            }
+                            combination
+                        })
+                    })
+                    .collect()
+            })
+            .into_iter()
+            .map(Value::Object)
+            .collect()
+    }
+
+    /// Runs `template` once per combination, giving it a `CaseGenerator`
+    /// whose cases get that combination as their options.
+    pub fn generate(&self, mut template: impl FnMut(&mut CaseGenerator)) -> GeneratedCases {
+        let mut cases = GeneratedCases::default();
+        for combination in self.combinations() {
+            let options = match self.wrap {
+                Some(wrap) => wrap(combination.clone()),
+                None => combination.clone(),
+            };
+            template(&mut CaseGenerator {
+                combination: &combination,
+                options: &options,
+                cases: &mut cases,
+            });
+        }
+        cases
+    }
+}
+
+#[derive(Default)]
+pub struct GeneratedCases {
+    pub valid: Vec<RuleTestValid>,
+    pub invalid: Vec<RuleTestInvalid>,
+}
+
+pub struct CaseGenerator<'a> {
+    combination: &'a Value,
+    options: &'a Value,
+    cases: &'a mut GeneratedCases,
+}
+
+impl<'a> CaseGenerator<'a> {
+    /// The value of an option, if it's given in this combination.
+    pub fn option(&self, name: &str) -> Option<&'a Value> {
+        self.combination.get(name)
+    }
+
+    pub fn str_option(&self, name: &str) -> Option<&'a str> {
+        self.option(name).and_then(Value::as_str)
+    }
+
+    pub fn valid(&mut self, code: impl Into<String>) {
+        self.cases
+            .valid
+            .push(valid_case(code, self.options.clone()));
+    }
+
+    pub fn invalid(&mut self, code: impl Into<String>, errors: Vec<RuleTestExpectedError>) {
+        self.cases
+            .invalid
+            .push(invalid_case(code, self.options.clone(), errors));
+    }
+
+    pub fn invalid_with_output(
+        &mut self,
+        code: impl Into<String>,
+        output: impl Into<String>,
+        errors: Vec<RuleTestExpectedError>,
+    ) {
+        self.cases.invalid.push(invalid_case_with_output(
+            code,
+            output,
+            self.options.clone(),
+            errors,
+        ));
+    }
+}
@@ -0,0 +1,85 @@
+use tree_sitter_lint::{
+    tree_sitter::{Node, Range},
+    FileRunContext, FromFileRunContext,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{CatchClause, FinallyClause, TryStatement};
+
+use crate::util::is_function;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TryContext {
+    Try,
+    Catch { has_finally: bool },
+    Finally,
+}
+
+/// The ranges of every `try` block, `catch` clause and `finally` clause in a
+/// file, so that rules can ask which one (if any) a node is in without
+/// re-walking its ancestors.
+///
+/// Function ranges are recorded too (with no context), since eg a `return`
+/// in a callback defined inside a `try` block isn't affected by the `try`.
+#[derive(Debug, Default)]
+pub struct TryContexts {
+    /// Sorted by start. Since the ranges come from the tree they're always
+    /// either nested or disjoint.
+    ranges: Vec<(Range, Option<TryContext>)>,
+}
+
+impl TryContexts {
+    /// Which part of the nearest enclosing `try` statement (within the same
+    /// function) the node is in.
+    pub fn get(&self, node: Node) -> Option<TryContext> {
+        let end = self
+            .ranges
+            .partition_point(|(range, _)| range.start_byte <= node.start_byte());
+        self.ranges[..end]
+            .iter()
+            .rev()
+            .find(|(range, _)| range.end_byte >= node.end_byte())
+            .and_then(|&(_, try_context)| try_context)
+    }
+}
+
+impl<'a> FromFileRunContext<'a> for TryContexts {
+    fn from_file_run_context(file_run_context: FileRunContext<'a, '_>) -> Self {
+        let mut ranges: Vec<(Range, Option<TryContext>)> = Default::default();
+
+        let mut cursor = file_run_context.tree.root_node().walk();
+        'outer: loop {
+            let node = cursor.node();
+            if is_function(node) {
+                ranges.push((node.range(), None));
+            } else if node.kind() == TryStatement {
+                if let Some(body) = node.child_by_field_name("body") {
+                    ranges.push((body.range(), Some(TryContext::Try)));
+                }
+                let has_finally = node.child_by_field_name("finalizer").is_some();
+                let mut child_cursor = node.walk();
+                for child in node.named_children(&mut child_cursor) {
+                    match child.kind() {
+                        CatchClause => {
+                            ranges.push((child.range(), Some(TryContext::Catch { has_finally })))
+                        }
+                        FinallyClause => ranges.push((child.range(), Some(TryContext::Finally))),
+                        _ => (),
+                    }
+                }
+            }
+            if cursor.goto_first_child() {
+                continue;
+            }
+            loop {
+                if cursor.goto_next_sibling() {
+                    continue 'outer;
+                }
+                if !cursor.goto_parent() {
+                    break 'outer;
+                }
+            }
+        }
+        ranges.sort_by_key(|(range, _)| range.start_byte);
+
+        Self { ranges }
+    }
+}
@@ -0,0 +1,249 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use tree_sitter_lint::{
+    tree_sitter::Node, tree_sitter_grep::SupportedLanguage, FileRunContext, FromFileRunContext,
+    NodeExt,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    ClassDeclaration, ExportStatement, ExpressionStatement, NestedIdentifier,
+};
+
+use crate::kind::{
+    AbstractClassDeclaration, AmbientDeclaration, EnumDeclaration, InterfaceDeclaration,
+    InternalModule, Module, TypeAliasDeclaration,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TypeDeclarationKind {
+    Class,
+    Interface,
+    Enum,
+    TypeAlias,
+    Namespace,
+}
+
+impl TypeDeclarationKind {
+    pub fn from_node(node: Node) -> Option<Self> {
+        match node.kind() {
+            ClassDeclaration | AbstractClassDeclaration => Some(Self::Class),
+            InterfaceDeclaration => Some(Self::Interface),
+            EnumDeclaration => Some(Self::Enum),
+            TypeAliasDeclaration => Some(Self::TypeAlias),
+            InternalModule | Module => Some(Self::Namespace),
+            _ => None,
+        }
+    }
+}
+
+/// The node whose children the declaration is a member of, looking through
+/// `export` and `declare` wrappers (and the `expression_statement` that
+/// tree-sitter-typescript wraps a bare `namespace` in).
+fn get_declaration_scope(node: Node) -> Option<Node> {
+    node.ancestors().find(|ancestor| {
+        !matches!(
+            ancestor.kind(),
+            ExportStatement | AmbientDeclaration | ExpressionStatement
+        )
+    })
+}
+
+/// The identifier naming the declaration, or for a namespace like `A.B` the
+/// outermost one (`A`).
+fn get_declared_name(node: Node) -> Node {
+    let mut name = node.field("name");
+    while name.kind() == NestedIdentifier {
+        name = name.first_non_comment_named_child(SupportedLanguage::Javascript);
+    }
+    name
+}
+
+/// Index of the class, interface, enum, type alias and namespace declarations
+/// in a file, keyed by declared name.
+#[derive(Debug, Default)]
+pub struct TypeDeclarations<'a> {
+    by_name: HashMap<Cow<'a, str>, Vec<Node<'a>>>,
+}
+
+impl<'a> TypeDeclarations<'a> {
+    fn new(root: Node<'a>, get_text: impl Fn(Node<'a>) -> Cow<'a, str>) -> Self {
+        let mut by_name: HashMap<Cow<'a, str>, Vec<Node<'a>>> = Default::default();
+
+        let mut cursor = root.walk();
+        'outer: loop {
+            let node = cursor.node();
+            if TypeDeclarationKind::from_node(node).is_some() {
+                by_name
+                    .entry(get_text(get_declared_name(node)))
+                    .or_default()
+                    .push(node);
+            }
+            if cursor.goto_first_child() {
+                continue;
+            }
+            loop {
+                if cursor.goto_next_sibling() {
+                    continue 'outer;
+                }
+                if !cursor.goto_parent() {
+                    break 'outer;
+                }
+            }
+        }
+
+        Self { by_name }
+    }
+
+    pub fn get(&self, name: &str) -> &[Node<'a>] {
+        self.by_name
+            .get(name)
+            .map(|declarations| &**declarations)
+            .unwrap_or_default()
+    }
+
+    pub fn get_of_kind(
+        &self,
+        name: &str,
+        kind: TypeDeclarationKind,
+    ) -> impl Iterator<Item = Node<'a>> + '_ {
+        self.get(name)
+            .iter()
+            .copied()
+            .filter(move |&declaration| TypeDeclarationKind::from_node(declaration) == Some(kind))
+    }
+
+    /// The other declarations with the same name in the same scope, ie the
+    /// ones that `declaration` would merge with.
+    pub fn get_merged_declarations(
+        &self,
+        declaration: Node<'a>,
+        name: &str,
+    ) -> impl Iterator<Item = Node<'a>> + '_ {
+        let scope = get_declaration_scope(declaration);
+        self.get(name)
+            .iter()
+            .copied()
+            .filter(move |&other| other != declaration && get_declaration_scope(other) == scope)
+    }
+}
+
+impl<'a> FromFileRunContext<'a> for TypeDeclarations<'a> {
+    fn from_file_run_context(file_run_context: FileRunContext<'a, '_>) -> Self {
+        Self::new(file_run_context.tree.root_node(), |node| {
+            node.text(&file_run_context)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tree_sitter_lint::tree_sitter::Parser;
+
+    use super::*;
+
+    fn test_type_declarations(source: &str, test: impl FnOnce(&TypeDeclarations)) {
+        let mut parser = Parser::new();
+        parser
+            .set_language(SupportedLanguage::Typescript.language(None))
+            .unwrap();
+        let tree = parser.parse(source, None).unwrap();
+        test(&TypeDeclarations::new(tree.root_node(), |node| {
+            node.utf8_text(source.as_bytes()).unwrap().into()
+        }));
+    }
+
+    #[test]
+    fn test_declaration_merging() {
+        test_type_declarations(
+            r#"
+interface Foo { a: string }
+export interface Foo { b: string }
+declare namespace Foo {}
+class Bar {}
+type Baz = Bar;
+enum Qux { A }
+declare namespace A.B.C {}
+            "#,
+            |type_declarations| {
+                assert_eq!(type_declarations.get("Foo").len(), 3);
+                assert_eq!(
+                    type_declarations
+                        .get_of_kind("Foo", TypeDeclarationKind::Interface)
+                        .count(),
+                    2
+                );
+                assert_eq!(
+                    type_declarations
+                        .get_of_kind("Foo", TypeDeclarationKind::Namespace)
+                        .count(),
+                    1
+                );
+                let first_foo = type_declarations.get("Foo")[0];
+                assert_eq!(
+                    type_declarations
+                        .get_merged_declarations(first_foo, "Foo")
+                        .count(),
+                    2
+                );
+                assert_eq!(
+                    type_declarations
+                        .get_of_kind("Bar", TypeDeclarationKind::Class)
+                        .count(),
+                    1
+                );
+                assert_eq!(
+                    type_declarations
+                        .get_of_kind("Baz", TypeDeclarationKind::TypeAlias)
+                        .count(),
+                    1
+                );
+                assert_eq!(
+                    type_declarations
+                        .get_of_kind("Qux", TypeDeclarationKind::Enum)
+                        .count(),
+                    1
+                );
+                assert_eq!(type_declarations.get("A").len(), 1);
+                assert!(type_declarations.get("B").is_empty());
+                assert!(type_declarations.get("Missing").is_empty());
+            },
+        );
+    }
+
+    #[test]
+    fn test_declaration_scoping() {
+        test_type_declarations(
+            r#"
+interface Foo {}
+namespace NS {
+  interface Foo {}
+  export interface Foo {}
+}
+function f() {
+  class Foo {}
+}
+            "#,
+            |type_declarations| {
+                let declarations = type_declarations.get("Foo");
+                assert_eq!(declarations.len(), 4);
+                assert_eq!(
+                    type_declarations
+                        .get_merged_declarations(declarations[0], "Foo")
+                        .count(),
+                    0
+                );
+                assert_eq!(
+                    type_declarations
+                        .get_merged_declarations(declarations[1], "Foo")
+                        .collect::<Vec<_>>(),
+                    [declarations[2]]
+                );
+                assert_eq!(
+                    type_declarations
+                        .get_merged_declarations(declarations[3], "Foo")
+                        .count(),
+                    0
+                );
+            },
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use tree_sitter_lint::{
+    tree_sitter::Node, tree_sitter_grep::SupportedLanguage, NodeExt, QueryMatchContext,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    ArrayPattern, AssignmentExpression, AugmentedAssignmentExpression, ForInStatement, Identifier,
+    ObjectPattern, Program, ShorthandPropertyIdentifierPattern, StatementBlock,
+    SubscriptExpression, UpdateExpression, VariableDeclarator,
+};
+
+use crate::{
+    ast_helpers::NodeExtTypescript,
+    kind::{
+        ArrayType, AsExpression, GenericType, PredefinedType, ReadonlyType, SatisfiesExpression,
+        TypeAssertion,
+    },
+    util::{find_declaration, get_declared_type, skip_parenthesized_expressions},
+};
+
+// (guards against eg `let a = b, b = a;`)
+const MAX_PROPAGATION_DEPTH: usize = 8;
+
+/// What can be told in-file about an expression being `any`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Anyness {
+    Any,
+    AnyArray,
+}
+
+impl Anyness {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Any => "any",
+            Self::AnyArray => "any[]",
+        }
+    }
+}
+
+pub fn is_predefined_type(type_: Node, name: &str, context: &QueryMatchContext) -> bool {
+    type_.kind() == PredefinedType && type_.text(context) == name
+}
+
+/// The element type of `T[]`, `readonly T[]`, `Array<T>` or
+/// `ReadonlyArray<T>`.
+pub fn get_array_element_type<'a>(
+    type_: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    let type_ = type_.skip_parenthesized_types();
+    match type_.kind() {
+        ReadonlyType => get_array_element_type(
+            type_.first_non_comment_named_child(SupportedLanguage::Javascript),
+            context,
+        ),
+        ArrayType => Some(
+            type_
+                .first_non_comment_named_child(SupportedLanguage::Javascript)
+                .skip_parenthesized_types(),
+        ),
+        GenericType
+            if matches!(
+                &*type_.field("name").text(context),
+                "Array" | "ReadonlyArray"
+            ) =>
+        {
+            let mut type_arguments = type_
+                .field("type_arguments")
+                .non_comment_named_children(SupportedLanguage::Javascript);
+            let element_type = type_arguments.next()?;
+            type_arguments
+                .next()
+                .is_none()
+                .then(|| element_type.skip_parenthesized_types())
+        }
+        _ => None,
+    }
+}
+
+pub fn get_type_anyness(type_: Node, context: &QueryMatchContext) -> Option<Anyness> {
+    let type_ = type_.skip_parenthesized_types();
+    if is_predefined_type(type_, "any", context) {
+        return Some(Anyness::Any);
+    }
+    get_array_element_type(type_, context)
+        .filter(|&element_type| is_predefined_type(element_type, "any", context))
+        .map(|_| Anyness::AnyArray)
+}
+
+/// The `for (const x of ...)` loop that declares the variable `identifier`
+/// refers to, if any (and unless it's shadowed by a declaration inside the
+/// loop).
+fn find_for_of_loop<'a>(
+    identifier: Node<'a>,
+    declaration: Option<Node<'a>>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    let name = identifier.text(context);
+    let mut child = identifier;
+    for ancestor in identifier.ancestors() {
+        if ancestor.kind() == ForInStatement
+            && ancestor.field("operator").kind() == "of"
+            && ancestor.child_by_field_name("kind").is_some()
+            && ancestor.field("body") == child
+        {
+            let left = ancestor.field("left");
+            if left.kind() == Identifier && left.text(context) == name {
+                let is_shadowed = declaration.is_some_and(|declaration| {
+                    ancestor.start_byte() <= declaration.start_byte()
+                        && declaration.end_byte() <= ancestor.end_byte()
+                });
+                return (!is_shadowed).then_some(ancestor);
+            }
+        }
+        child = ancestor;
+    }
+    None
+}
+
+/// Whether `node` (an identifier or shorthand pattern) gets assigned to by
+/// something other than a plain `=` assignment, eg destructuring or `+=`.
+fn is_other_write(node: Node) -> bool {
+    let parent = node.parent().unwrap();
+    match parent.kind() {
+        AssignmentExpression => false,
+        AugmentedAssignmentExpression | ForInStatement => parent.field("left") == node,
+        UpdateExpression => true,
+        _ => node.ancestors().any(|ancestor| {
+            matches!(ancestor.kind(), ArrayPattern | ObjectPattern)
+                && ancestor.parent().is_some_and(|parent| {
+                    matches!(parent.kind(), AssignmentExpression | ForInStatement)
+                        && parent.field("left") == ancestor
+                })
+        }),
+    }
+}
+
+fn collect_assigned_values<'a>(
+    node: Node<'a>,
+    declarator: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+    values: &mut Vec<Node<'a>>,
+) -> Option<()> {
+    if matches!(node.kind(), Identifier | ShorthandPropertyIdentifierPattern)
+        && node.text(context) == declarator.field("name").text(context)
+        && find_declaration(node, context) == Some(declarator)
+    {
+        let parent = node.parent().unwrap();
+        if parent.kind() == AssignmentExpression && parent.field("left") == node {
+            values.push(parent.field("right"));
+        } else if is_other_write(node) {
+            return None;
+        }
+    }
+    for child in node.non_comment_named_children(SupportedLanguage::Javascript) {
+        collect_assigned_values(child, declarator, context, values)?;
+    }
+    Some(())
+}
+
+/// The initializer of the (unannotated) variable along with everything that
+/// gets assigned to it with plain `=` assignments, or `None` if it also gets
+/// written some other way.
+fn get_assigned_values<'a>(
+    declarator: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Vec<Node<'a>>> {
+    let scope = declarator
+        .ancestors()
+        .find(|ancestor| matches!(ancestor.kind(), StatementBlock | Program))?;
+    let mut values: Vec<Node<'a>> = declarator
+        .child_by_field_name("value")
+        .into_iter()
+        .collect();
+    collect_assigned_values(scope, declarator, context, &mut values)?;
+    Some(values)
+}
+
+fn get_identifier_anyness<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+    depth: usize,
+) -> Option<Anyness> {
+    let declaration = find_declaration(node, context);
+    if let Some(for_of_loop) = find_for_of_loop(node, declaration, context) {
+        return match get_anyness(for_of_loop.field("right"), context, depth + 1)? {
+            Anyness::Any | Anyness::AnyArray => Some(Anyness::Any),
+        };
+    }
+    if let Some(type_) = get_declared_type(node, context) {
+        return get_type_anyness(type_, context);
+    }
+    // an unannotated variable is whatever gets put in it, as long as that's
+    // consistently the same kind of `any`
+    let declaration = declaration.filter(|declaration| declaration.kind() == VariableDeclarator)?;
+    let mut anyness = None;
+    for value in get_assigned_values(declaration, context)? {
+        let value_anyness = get_anyness(value, context, depth + 1)?;
+        if anyness.is_some_and(|anyness| anyness != value_anyness) {
+            return None;
+        }
+        anyness = Some(value_anyness);
+    }
+    anyness
+}
+
+fn get_anyness<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+    depth: usize,
+) -> Option<Anyness> {
+    if depth > MAX_PROPAGATION_DEPTH {
+        return None;
+    }
+    let node = skip_parenthesized_expressions(node);
+    match node.kind() {
+        Identifier => get_identifier_anyness(node, context, depth),
+        // (indexing into something `any` gives `any` too, but it's the
+        // original `any` that rules report)
+        SubscriptExpression => match get_anyness(node.field("object"), context, depth + 1)? {
+            Anyness::AnyArray => Some(Anyness::Any),
+            Anyness::Any => None,
+        },
+        AsExpression => get_type_anyness(
+            node.non_comment_named_children(SupportedLanguage::Javascript)
+                .last()
+                .unwrap(),
+            context,
+        ),
+        TypeAssertion => get_type_anyness(
+            node.first_non_comment_named_child(SupportedLanguage::Javascript)
+                .first_non_comment_named_child(SupportedLanguage::Javascript),
+            context,
+        ),
+        SatisfiesExpression => get_anyness(
+            node.first_non_comment_named_child(SupportedLanguage::Javascript),
+            context,
+            depth,
+        ),
+        _ => None,
+    }
+}
+
+/// Approximates whether an expression is `any` (or `any[]`) from in-file
+/// declarations: variables and parameters annotated as such, assertions to
+/// such types, and what those flow into via unannotated variables (their
+/// initializers and any later assignments) and `for...of` loops. `None`
+/// means not known to be.
+pub fn get_expression_anyness<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Anyness> {
+    get_anyness(node, context, 0)
+}
+
+/// Whether the expression is known to be `any` (see
+/// [`get_expression_anyness`]).
+pub fn is_definitely_any<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    get_expression_anyness(node, context) == Some(Anyness::Any)
+}
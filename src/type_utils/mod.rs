@@ -1,3 +1,17 @@
+#[cfg(feature = "type-aware")]
+mod anyness;
 mod requires_quoting;
+#[cfg(feature = "type-aware")]
+mod return_type_kind;
+mod type_text;
+#[cfg(feature = "type-aware")]
+mod union_members;
 
+#[cfg(feature = "type-aware")]
+pub use anyness::*;
 pub use requires_quoting::*;
+#[cfg(feature = "type-aware")]
+pub use return_type_kind::*;
+pub use type_text::*;
+#[cfg(feature = "type-aware")]
+pub use union_members::*;
@@ -1,7 +1,8 @@
+use crate::util::is_valid_identifier;
+
 pub fn requires_quoting(
     name: &str,
     // target: ts.ScriptTarget = ts.ScriptTarget.ESNext,
 ) -> bool {
-    // TODO: actual logic
-    name.starts_with('#')
+    !is_valid_identifier(name)
 }
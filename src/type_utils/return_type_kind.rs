@@ -0,0 +1,181 @@
+use tree_sitter_lint::{
+    tree_sitter::Node, tree_sitter_grep::SupportedLanguage, NodeExt, QueryMatchContext,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    ArrowFunction, AwaitExpression, CallExpression, Function, GeneratorFunction,
+    GeneratorFunctionDeclaration, Identifier, MemberExpression, ReturnStatement, StatementBlock,
+};
+
+use super::is_predefined_type;
+use crate::{
+    ast_helpers::{is_async_function, NodeExtTypescript},
+    kind::{FunctionSignature, FunctionType, GenericType, TypeIdentifier},
+    util::{get_declared_type, is_function, resolve_function, skip_parenthesized_expressions},
+};
+
+// (how far to follow eg `const f = () => g()` when looking for a `void`)
+const MAX_RESOLVE_DEPTH: usize = 5;
+
+const VOID_RETURNING_CONSOLE_METHODS: [&str; 15] = [
+    "assert", "clear", "count", "debug", "dir", "error", "group", "groupEnd", "info", "log",
+    "table", "time", "timeEnd", "trace", "warn",
+];
+
+/// What calling a function gives, as far as can be told in-file.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReturnTypeKind {
+    Void,
+    VoidPromise,
+    /// It never returns (ie always throws).
+    Never,
+    Other,
+}
+
+fn is_void_type<'a>(type_: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    is_predefined_type(type_.skip_parenthesized_types(), "void", context)
+}
+
+// `Promise<void>`
+fn is_void_promise_type<'a>(type_: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    let type_ = type_.skip_parenthesized_types();
+    if type_.kind() != GenericType {
+        return false;
+    }
+    let name = type_.field("name");
+    let mut type_arguments = type_
+        .field("type_arguments")
+        .non_comment_named_children(SupportedLanguage::Javascript);
+    name.kind() == TypeIdentifier
+        && name.text(context) == "Promise"
+        && matches!(
+            (type_arguments.next(), type_arguments.next()),
+            (Some(type_argument), None) if is_void_type(type_argument, context)
+        )
+}
+
+fn get_annotated_return_type_kind<'a>(
+    return_type: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> ReturnTypeKind {
+    if is_void_type(return_type, context) {
+        ReturnTypeKind::Void
+    } else if is_void_promise_type(return_type, context) {
+        ReturnTypeKind::VoidPromise
+    } else if is_predefined_type(return_type.skip_parenthesized_types(), "never", context) {
+        ReturnTypeKind::Never
+    } else {
+        ReturnTypeKind::Other
+    }
+}
+
+fn has_return_value(node: Node) -> bool {
+    node.non_comment_named_children(SupportedLanguage::Javascript)
+        .any(|child| match child.kind() {
+            ReturnStatement => child
+                .non_comment_named_children(SupportedLanguage::Javascript)
+                .next()
+                .is_some(),
+            _ if is_function(child) => false,
+            _ => has_return_value(child),
+        })
+}
+
+/// What calling the (in-file) function gives, from its return type
+/// annotation or else from its body.
+fn get_return_type_kind<'a>(
+    function: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+    depth: usize,
+) -> Option<ReturnTypeKind> {
+    if matches!(
+        function.kind(),
+        GeneratorFunction | GeneratorFunctionDeclaration
+    ) {
+        return Some(ReturnTypeKind::Other);
+    }
+    let is_async = function.kind() != FunctionSignature && is_async_function(function);
+    if let Some(return_type) = function.child_by_field_name("return_type") {
+        let return_type = return_type.first_non_comment_named_child(SupportedLanguage::Javascript);
+        return Some(get_annotated_return_type_kind(return_type, context));
+    }
+    let body = function.child_by_field_name("body")?;
+    let is_void = if body.kind() == StatementBlock {
+        !has_return_value(body)
+    } else {
+        is_void_expression_with_depth(body, context, depth + 1)
+    };
+    Some(match (is_void, is_async) {
+        (true, false) => ReturnTypeKind::Void,
+        (true, true) => ReturnTypeKind::VoidPromise,
+        (false, _) => ReturnTypeKind::Other,
+    })
+}
+
+fn get_call_return_type_kind_with_depth<'a>(
+    call: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+    depth: usize,
+) -> Option<ReturnTypeKind> {
+    if depth > MAX_RESOLVE_DEPTH {
+        return None;
+    }
+    let callee = skip_parenthesized_expressions(call.field("function"));
+    match callee.kind() {
+        Identifier => {
+            if let Some(function) = resolve_function(callee, context) {
+                return get_return_type_kind(function, context, depth);
+            }
+            let declared_type = get_declared_type(callee, context)?;
+            (declared_type.kind() == FunctionType).then(|| {
+                get_annotated_return_type_kind(declared_type.field("return_type"), context)
+            })
+        }
+        // eg `(() => {})()`
+        ArrowFunction | Function => get_return_type_kind(callee, context, depth),
+        MemberExpression => {
+            let object = callee.field("object");
+            (object.kind() == Identifier
+                && object.text(context) == "console"
+                && VOID_RETURNING_CONSOLE_METHODS
+                    .contains(&&*callee.field("property").text(context)))
+            .then_some(ReturnTypeKind::Void)
+        }
+        _ => None,
+    }
+}
+
+fn is_void_expression_with_depth<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+    depth: usize,
+) -> bool {
+    let node = skip_parenthesized_expressions(node);
+    match node.kind() {
+        CallExpression => {
+            get_call_return_type_kind_with_depth(node, context, depth) == Some(ReturnTypeKind::Void)
+        }
+        AwaitExpression => {
+            let argument = skip_parenthesized_expressions(
+                node.first_non_comment_named_child(SupportedLanguage::Javascript),
+            );
+            argument.kind() == CallExpression
+                && get_call_return_type_kind_with_depth(argument, context, depth)
+                    == Some(ReturnTypeKind::VoidPromise)
+        }
+        _ => false,
+    }
+}
+
+/// What calling `call` gives, if its callee can be resolved in-file (or is a
+/// known `console` method).
+pub fn get_call_return_type_kind<'a>(
+    call: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<ReturnTypeKind> {
+    get_call_return_type_kind_with_depth(call, context, 0)
+}
+
+/// Whether the call (or `await` of a call) is known to give `void`.
+pub fn is_void_expression<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    is_void_expression_with_depth(node, context, 0)
+}
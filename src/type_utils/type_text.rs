@@ -0,0 +1,96 @@
+use tree_sitter_lint::{
+    tree_sitter::Node, tree_sitter_grep::SupportedLanguage, NodeExt, QueryMatchContext,
+};
+
+use crate::{
+    ast_helpers::NodeExtTypescript,
+    kind::TypeIdentifier,
+    type_declarations::{TypeDeclarationKind, TypeDeclarations},
+};
+
+// (guards against eg `type A = B; type B = A;`)
+const MAX_ALIAS_EXPANSION_DEPTH: usize = 10;
+
+fn get_comparable_children(node: Node) -> Vec<Node> {
+    node.non_comment_children(SupportedLanguage::Javascript)
+        // `{ a: string, b: number }` is the same as `{ a: string; b: number }`
+        .filter(|child| !matches!(child.kind(), "," | ";"))
+        .map(|child| child.skip_parenthesized_types())
+        .collect()
+}
+
+/// The aliased type, if `node` refers to the only (non-generic) type alias
+/// of that name declared in the file.
+fn expand_type_alias<'a>(
+    node: Node<'a>,
+    type_declarations: &TypeDeclarations<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    if node.kind() != TypeIdentifier {
+        return None;
+    }
+    let mut aliases =
+        type_declarations.get_of_kind(&node.text(context), TypeDeclarationKind::TypeAlias);
+    let (Some(alias), None) = (aliases.next(), aliases.next()) else {
+        return None;
+    };
+    if alias.child_by_field_name("type_parameters").is_some() {
+        return None;
+    }
+    Some(alias.field("value").skip_parenthesized_types())
+}
+
+fn are_equivalent<'a>(
+    a: Node<'a>,
+    b: Node<'a>,
+    type_declarations: Option<&TypeDeclarations<'a>>,
+    context: &QueryMatchContext<'a, '_>,
+    depth: usize,
+) -> bool {
+    let a = a.skip_parenthesized_types();
+    let b = b.skip_parenthesized_types();
+
+    if let Some(type_declarations) = type_declarations.filter(|_| depth < MAX_ALIAS_EXPANSION_DEPTH)
+    {
+        let is_same_reference = a.kind() == TypeIdentifier
+            && b.kind() == TypeIdentifier
+            && a.text(context) == b.text(context);
+        if !is_same_reference {
+            if let Some(expanded) = expand_type_alias(a, type_declarations, context) {
+                return are_equivalent(expanded, b, Some(type_declarations), context, depth + 1);
+            }
+            if let Some(expanded) = expand_type_alias(b, type_declarations, context) {
+                return are_equivalent(a, expanded, Some(type_declarations), context, depth + 1);
+            }
+        }
+    }
+
+    if a.kind() != b.kind() {
+        return false;
+    }
+    let a_children = get_comparable_children(a);
+    let b_children = get_comparable_children(b);
+    if a_children.is_empty() && b_children.is_empty() {
+        return a.text(context) == b.text(context);
+    }
+    a_children.len() == b_children.len()
+        && a_children
+            .into_iter()
+            .zip(b_children)
+            .all(|(a, b)| are_equivalent(a, b, type_declarations, context, depth))
+}
+
+/// Whether the two types (or type annotations) are written the same way,
+/// ignoring whitespace, comments, redundant parentheses and which member
+/// separators are used.
+///
+/// If `type_declarations` is given, references to (non-generic) type aliases
+/// declared in the file also count as the same as the aliased type.
+pub fn are_types_equivalent<'a>(
+    a: Node<'a>,
+    b: Node<'a>,
+    type_declarations: Option<&TypeDeclarations<'a>>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    are_equivalent(a, b, type_declarations, context, 0)
+}
@@ -0,0 +1,59 @@
+use tree_sitter_lint::{
+    tree_sitter::Node, tree_sitter_grep::SupportedLanguage, NodeExt, QueryMatchContext,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::Identifier;
+
+use crate::{
+    ast_helpers::NodeExtTypescript,
+    kind::{LiteralType, OptionalParameter, UnionType},
+    util::{find_declaration, get_declared_type, skip_parenthesized_expressions},
+};
+
+/// The members of the (possibly union) type that the variable or parameter
+/// `identifier` is declared with, including an implicit `undefined` member
+/// for optional parameters (represented by `None`).
+pub fn get_declared_type_members<'a>(
+    identifier: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Vec<Option<Node<'a>>>> {
+    let identifier = skip_parenthesized_expressions(identifier);
+    if identifier.kind() != Identifier {
+        return None;
+    }
+    let type_ = get_declared_type(identifier, context)?;
+    let mut members = vec![];
+    collect_union_members(type_, &mut members);
+    if find_declaration(identifier, context)?.kind() == OptionalParameter {
+        members.push(None);
+    }
+    Some(members)
+}
+
+pub fn collect_union_members<'a>(type_: Node<'a>, members: &mut Vec<Option<Node<'a>>>) {
+    let type_ = type_.skip_parenthesized_types();
+    match type_.kind() {
+        UnionType => {
+            for member in type_.non_comment_named_children(SupportedLanguage::Javascript) {
+                collect_union_members(member, members);
+            }
+        }
+        _ => members.push(Some(type_)),
+    }
+}
+
+pub fn is_literal_type<'a>(
+    type_: Node<'a>,
+    text: &str,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    type_.kind() == LiteralType && type_.text(context) == text
+}
+
+pub fn is_nullish_member<'a>(
+    member: Option<Node<'a>>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    member.map_or(true, |member| {
+        is_literal_type(member, "null", context) || is_literal_type(member, "undefined", context)
+    })
+}
@@ -0,0 +1,115 @@
+use tree_sitter_lint::{tree_sitter::Node, tree_sitter_grep::SupportedLanguage, NodeExt};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    Class, ClassDeclaration, ImportSpecifier, ImportStatement,
+};
+
+use super::is_function;
+use crate::{
+    ast_helpers::is_class,
+    kind::{
+        AbstractClassDeclaration, AbstractMethodSignature, AsExpression, AssertsAnnotation,
+        ExtendsClause, FunctionSignature, ImplementsClause, InterfaceDeclaration, MethodSignature,
+        SatisfiesExpression, TypeAliasDeclaration, TypeAnnotation, TypeArguments, TypeParameters,
+        TypePredicate, TypePredicateAnnotation, TypeQuery,
+    },
+};
+
+/// [`is_function`], plus the body-less signatures that only exist in TS
+/// (`declare function`, overloads, abstract methods and interface methods).
+pub fn is_function_like(node: Node) -> bool {
+    is_function(node)
+        || matches!(
+            node.kind(),
+            FunctionSignature | MethodSignature | AbstractMethodSignature
+        )
+}
+
+/// The innermost function-like node (see [`is_function_like`]) containing
+/// `node`, not counting `node` itself.
+pub fn nearest_enclosing_function(node: Node) -> Option<Node> {
+    node.ancestors()
+        .find(|&ancestor| is_function_like(ancestor))
+}
+
+/// The innermost class containing `node` (not counting `node` itself),
+/// including abstract classes.
+pub fn nearest_class(node: Node) -> Option<Node> {
+    node.ancestors().find(|&ancestor| is_class(ancestor))
+}
+
+/// Whether `child` is the root of a type, either because of its kind (eg a
+/// `type_annotation`) or because it's the type side of an `as`/`satisfies`.
+fn is_type_root(parent: Node, child: Node) -> bool {
+    match child.kind() {
+        TypeAnnotation
+        | TypeArguments
+        | TypeParameters
+        | TypePredicateAnnotation
+        | AssertsAnnotation
+        | ImplementsClause
+        | TypeAliasDeclaration
+        | InterfaceDeclaration => true,
+        _ => {
+            matches!(parent.kind(), AsExpression | SatisfiesExpression)
+                && parent.first_non_comment_named_child(SupportedLanguage::Javascript) != child
+        }
+    }
+}
+
+/// Whether `node` is part of a type (annotation, type arguments, type alias,
+/// interface, `implements` clause, type predicate, the type side of an `as`
+/// etc), as opposed to an expression.
+pub fn is_inside_type_context(node: Node) -> bool {
+    let mut child = node;
+    while let Some(parent) = child.parent() {
+        if is_type_root(parent, child) {
+            return true;
+        }
+        child = parent;
+    }
+    false
+}
+
+/// Whether the reference `node` (eg an `identifier` or `type_identifier`)
+/// refers to something in the type namespace rather than the value
+/// namespace.
+///
+/// Unlike [`is_inside_type_context`] this treats `typeof foo` (in a type),
+/// the parameter name in `x is Foo` and the superclass in a class's
+/// `extends` clause as value positions, and specifiers of `import
+/// type`/`import { type }` as type positions. Declared names (eg of an
+/// interface) aren't references so aren't in either.
+pub fn is_in_type_position(node: Node) -> bool {
+    let mut child = node;
+    while let Some(parent) = child.parent() {
+        match parent.kind() {
+            TypeQuery => return false,
+            // the superclass expression, but not its type arguments
+            ExtendsClause
+                if parent
+                    .children_by_field_name("value", &mut parent.walk())
+                    .any(|value| value == child) =>
+            {
+                return false
+            }
+            TypePredicate if parent.child_by_field_name("name") == Some(child) => return false,
+            InterfaceDeclaration
+            | TypeAliasDeclaration
+            | ClassDeclaration
+            | AbstractClassDeclaration
+            | Class
+                if parent.child_by_field_name("name") == Some(child) =>
+            {
+                return false
+            }
+            ImportSpecifier if parent.has_child_of_kind("type") => return true,
+            ImportStatement => return parent.has_child_of_kind("type"),
+            _ => (),
+        }
+        if is_type_root(parent, child) {
+            return true;
+        }
+        child = parent;
+    }
+    false
+}
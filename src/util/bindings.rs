@@ -0,0 +1,450 @@
+use tree_sitter_lint::{
+    tree_sitter::Node, tree_sitter_grep::SupportedLanguage, NodeExt, QueryMatchContext,
+};
+use tree_sitter_lint_plugin_eslint_builtin::kind::{
+    ArrowFunction, AssignmentExpression, CatchClause, Class, ClassDeclaration, ExportClause,
+    ExportStatement, ForInStatement, ForStatement, FormalParameters, Function, FunctionDeclaration,
+    GeneratorFunction, GeneratorFunctionDeclaration, Identifier, ImportClause, ImportStatement,
+    LexicalDeclaration, Program, RestPattern, ShorthandPropertyIdentifier, StatementBlock,
+    SwitchBody, VariableDeclaration, VariableDeclarator,
+};
+
+use super::{is_function, skip_parenthesized_expressions};
+use crate::{
+    ast_helpers::{get_import_bindings, get_pattern_identifiers, is_class, NodeExtTypescript},
+    kind::{
+        AbstractClassDeclaration, AmbientDeclaration, EnumDeclaration, FunctionSignature,
+        InterfaceDeclaration, OptionalParameter, RequiredParameter, TypeAliasDeclaration,
+        TypeIdentifier, TypeParameters,
+    },
+};
+
+fn get_parameter_name(parameter: Node) -> Option<Node> {
+    let pattern = match parameter.kind() {
+        RequiredParameter | OptionalParameter => parameter.field("pattern"),
+        Identifier => parameter,
+        _ => return None,
+    };
+    match pattern.kind() {
+        Identifier => Some(pattern),
+        RestPattern => Some(pattern.first_non_comment_named_child(SupportedLanguage::Javascript))
+            .filter(|name| name.kind() == Identifier),
+        _ => None,
+    }
+}
+
+fn find_parameter<'a>(
+    function: Node<'a>,
+    name: &str,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    if let Some(parameter) = function.child_by_field_name("parameter") {
+        return (parameter.text(context) == name).then_some(parameter);
+    }
+    function
+        .child_by_field_name("parameters")?
+        .non_comment_named_children(SupportedLanguage::Javascript)
+        .find(|&parameter| {
+            get_parameter_name(parameter)
+                .is_some_and(|parameter_name| parameter_name.text(context) == name)
+        })
+}
+
+fn find_declaration_in_block<'a>(
+    block: Node<'a>,
+    name: &str,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    block
+        .non_comment_named_children(SupportedLanguage::Javascript)
+        .find_map(|statement| {
+            let statement = match statement.kind() {
+                ExportStatement => statement.child_by_field_name("declaration")?,
+                AmbientDeclaration => {
+                    statement.first_non_comment_named_child(SupportedLanguage::Javascript)
+                }
+                _ => statement,
+            };
+            match statement.kind() {
+                FunctionDeclaration | FunctionSignature
+                    if statement.field("name").text(context) == name =>
+                {
+                    Some(statement)
+                }
+                LexicalDeclaration | VariableDeclaration => statement
+                    .non_comment_named_children(SupportedLanguage::Javascript)
+                    .find(|declarator| {
+                        declarator.kind() == VariableDeclarator
+                            && declarator.field("name").kind() == Identifier
+                            && declarator.field("name").text(context) == name
+                    }),
+                _ => None,
+            }
+        })
+}
+
+/// The innermost declaration of the name `identifier` refers to, if it's
+/// a parameter of an enclosing function or is declared directly in an
+/// enclosing block: a `variable_declarator`, a parameter (or the lone
+/// identifier parameter of an arrow function) or a function declaration (the
+/// first signature, for overloaded functions).
+pub fn find_declaration<'a>(
+    identifier: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    let name = identifier.text(context);
+    identifier.ancestors().find_map(|ancestor| {
+        if is_function(ancestor) {
+            return find_parameter(ancestor, &name, context);
+        }
+        if !matches!(ancestor.kind(), Program | StatementBlock) {
+            return None;
+        }
+        find_declaration_in_block(ancestor, &name, context)
+    })
+}
+
+/// The names bound by a statement directly in a block (looking through
+/// `export` and `declare`).
+fn get_statement_bindings<'a>(
+    statement: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Vec<Node<'a>> {
+    let statement = match statement.kind() {
+        ExportStatement => match statement.child_by_field_name("declaration") {
+            Some(declaration) => declaration,
+            None => return vec![],
+        },
+        AmbientDeclaration => {
+            statement.first_non_comment_named_child(SupportedLanguage::Javascript)
+        }
+        _ => statement,
+    };
+    match statement.kind() {
+        LexicalDeclaration | VariableDeclaration => statement
+            .non_comment_named_children(SupportedLanguage::Javascript)
+            .filter(|declarator| declarator.kind() == VariableDeclarator)
+            .flat_map(|declarator| get_pattern_identifiers(declarator.field("name")))
+            .collect(),
+        FunctionDeclaration
+        | GeneratorFunctionDeclaration
+        | FunctionSignature
+        | ClassDeclaration
+        | AbstractClassDeclaration
+        | EnumDeclaration => vec![statement.field("name")],
+        ImportStatement => statement
+            .maybe_first_child_of_kind(ImportClause)
+            .map(|import_clause| {
+                get_import_bindings(import_clause, context)
+                    .into_iter()
+                    .map(|(local, _)| local)
+                    .collect()
+            })
+            .unwrap_or_default(),
+        _ => vec![],
+    }
+}
+
+fn find_named<'a>(
+    identifiers: impl IntoIterator<Item = Node<'a>>,
+    name: &str,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    identifiers
+        .into_iter()
+        .find(|identifier| identifier.text(context) == name)
+}
+
+fn find_in_statements<'a>(
+    statements: impl Iterator<Item = Node<'a>>,
+    name: &str,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    statements
+        .flat_map(|statement| get_statement_bindings(statement, context))
+        .find(|identifier| identifier.text(context) == name)
+}
+
+/// A `var` declared anywhere in `node` other than inside a nested function
+/// (or class).
+fn find_hoisted_var<'a>(
+    node: Node<'a>,
+    name: &str,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    node.non_comment_named_children(SupportedLanguage::Javascript)
+        .find_map(|child| match child.kind() {
+            VariableDeclaration => {
+                find_named(get_statement_bindings(child, context), name, context)
+            }
+            ForInStatement
+                if child
+                    .child_by_field_name("kind")
+                    .is_some_and(|kind| kind.kind() == "var") =>
+            {
+                find_named(get_pattern_identifiers(child.field("left")), name, context)
+                    .or_else(|| find_hoisted_var(child.field("body"), name, context))
+            }
+            _ if is_function(child) || is_class(child) => None,
+            _ => find_hoisted_var(child, name, context),
+        })
+}
+
+fn find_binding_in_function<'a>(
+    function: Node<'a>,
+    name: &str,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    // the name of a named function expression
+    if matches!(function.kind(), Function | GeneratorFunction) {
+        if let Some(function_name) = function
+            .child_by_field_name("name")
+            .filter(|function_name| function_name.text(context) == name)
+        {
+            return Some(function_name);
+        }
+    }
+    let parameters = match function.child_by_field_name("parameter") {
+        Some(parameter) => vec![parameter],
+        None => function
+            .child_by_field_name("parameters")
+            .into_iter()
+            .flat_map(|parameters| {
+                parameters.non_comment_named_children(SupportedLanguage::Javascript)
+            })
+            .filter(|parameter| matches!(parameter.kind(), RequiredParameter | OptionalParameter))
+            .flat_map(|parameter| get_pattern_identifiers(parameter.field("pattern")))
+            .collect(),
+    };
+    find_named(parameters, name, context).or_else(|| {
+        function
+            .child_by_field_name("body")
+            .filter(|body| body.kind() == StatementBlock)
+            .and_then(|body| find_hoisted_var(body, name, context))
+    })
+}
+
+fn find_binding_in_scope<'a>(
+    scope: Node<'a>,
+    name: &str,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    match scope.kind() {
+        Program => find_in_statements(
+            scope.non_comment_named_children(SupportedLanguage::Javascript),
+            name,
+            context,
+        )
+        .or_else(|| find_hoisted_var(scope, name, context)),
+        StatementBlock => find_in_statements(
+            scope.non_comment_named_children(SupportedLanguage::Javascript),
+            name,
+            context,
+        ),
+        SwitchBody => find_in_statements(
+            scope
+                .non_comment_named_children(SupportedLanguage::Javascript)
+                .flat_map(|case| case.non_comment_named_children(SupportedLanguage::Javascript)),
+            name,
+            context,
+        ),
+        ForStatement => find_in_statements(
+            scope.child_by_field_name("initializer").into_iter(),
+            name,
+            context,
+        ),
+        ForInStatement if scope.child_by_field_name("kind").is_some() => {
+            find_named(get_pattern_identifiers(scope.field("left")), name, context)
+        }
+        CatchClause => find_named(
+            scope
+                .child_by_field_name("parameter")
+                .map(get_pattern_identifiers)
+                .unwrap_or_default(),
+            name,
+            context,
+        ),
+        // the name of a named class expression
+        Class => find_named(scope.child_by_field_name("name"), name, context),
+        _ if is_function(scope) => find_binding_in_function(scope, name, context),
+        _ => None,
+    }
+}
+
+/// The declared name of the innermost binding that the reference
+/// `identifier` resolves to: a variable (including `var`s hoisted out of
+/// nested blocks), parameter, loop or `catch` variable, function, class, enum
+/// or import. Unlike [`find_declaration`] every kind of binding counts, so
+/// eg a parameter hides an outer `const` of the same name. Type-only
+/// declarations and `declare global` aren't taken into account.
+pub fn find_binding<'a>(
+    identifier: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    let name = identifier.text(context);
+    identifier
+        .ancestors()
+        .find_map(|scope| find_binding_in_scope(scope, &name, context))
+}
+
+/// The function (or the initializer of the variable) that `identifier` refers
+/// to, if it's declared in an enclosing block.
+pub fn resolve_function<'a>(
+    identifier: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    let declaration = find_declaration(identifier, context)?;
+    match declaration.kind() {
+        FunctionDeclaration | FunctionSignature => Some(declaration),
+        VariableDeclarator => declaration
+            .child_by_field_name("value")
+            .map(skip_parenthesized_expressions)
+            .filter(|value| matches!(value.kind(), ArrowFunction | Function)),
+        _ => None,
+    }
+}
+
+/// The type that the variable or parameter `identifier` refers to is
+/// annotated with (see [`find_declaration`]).
+pub fn get_declared_type<'a>(
+    identifier: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    let declaration = find_declaration(identifier, context)?;
+    if !matches!(
+        declaration.kind(),
+        VariableDeclarator | RequiredParameter | OptionalParameter
+    ) {
+        return None;
+    }
+    Some(
+        declaration
+            .child_by_field_name("type")?
+            .first_non_comment_named_child(SupportedLanguage::Javascript)
+            .skip_parenthesized_types(),
+    )
+}
+
+/// The statement list a declaration statement belongs to, looking through
+/// `export` and `declare`.
+fn get_enclosing_statement_list(declaration: Node) -> Node {
+    declaration
+        .ancestors()
+        .find(|ancestor| !matches!(ancestor.kind(), ExportStatement | AmbientDeclaration))
+        .unwrap()
+}
+
+fn get_function_scope(node: Node) -> Node {
+    node.ancestors()
+        .find(|&ancestor| is_function(ancestor) || ancestor.kind() == Program)
+        .unwrap()
+}
+
+/// The node throughout which the binding introduced by the declared name
+/// `identifier` is visible, eg the enclosing function for a `var` or a
+/// parameter, the enclosing block for a `let` or a class, or the function
+/// expression itself for the name of a named function expression.
+/// Declaration merging and `declare global` aren't taken into account.
+pub fn get_binding_scope(identifier: Node) -> Option<Node> {
+    identifier.ancestors().find_map(|ancestor| {
+        Some(match ancestor.kind() {
+            FormalParameters | TypeParameters => ancestor.parent().unwrap(),
+            VariableDeclaration => get_function_scope(ancestor),
+            LexicalDeclaration => get_enclosing_statement_list(ancestor),
+            ForInStatement => match ancestor.child_by_field_name("kind")?.kind() {
+                "var" => get_function_scope(ancestor),
+                _ => ancestor,
+            },
+            CatchClause => ancestor,
+            // the lone parameter of eg `x => x`
+            ArrowFunction => ancestor,
+            Function | GeneratorFunction | Class => ancestor,
+            FunctionDeclaration
+            | GeneratorFunctionDeclaration
+            | FunctionSignature
+            | ClassDeclaration
+            | AbstractClassDeclaration
+            | InterfaceDeclaration
+            | TypeAliasDeclaration
+            | EnumDeclaration => get_enclosing_statement_list(ancestor),
+            _ if is_function(ancestor) => ancestor,
+            _ => return None,
+        })
+    })
+}
+
+fn is_assignment_target(identifier: Node) -> bool {
+    identifier.parent().is_some_and(|parent| {
+        parent.kind() == AssignmentExpression && parent.field("left") == identifier
+    })
+}
+
+fn has_reference<'a>(
+    node: Node<'a>,
+    declared: Node<'a>,
+    name: &str,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    if node != declared
+        && matches!(
+            node.kind(),
+            Identifier | ShorthandPropertyIdentifier | TypeIdentifier
+        )
+        && node.text(context) == name
+        && !is_assignment_target(node)
+    {
+        return true;
+    }
+    node.non_comment_named_children(SupportedLanguage::Javascript)
+        .any(|child| has_reference(child, declared, name, context))
+}
+
+/// Whether the binding introduced by the declared name `identifier` is read
+/// (or mentioned as a type) anywhere it's visible (see
+/// [`get_binding_scope`]). Being assigned to doesn't count. Shadowing
+/// declarations aren't told apart from references, which errs on the side of
+/// "used".
+pub fn is_binding_referenced<'a>(
+    identifier: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    let Some(scope) = get_binding_scope(identifier) else {
+        return true;
+    };
+    has_reference(scope, identifier, &identifier.text(context), context)
+}
+
+/// Whether the binding introduced by the declared name `identifier` lives in
+/// the top-level scope of the file.
+pub fn is_global_binding(identifier: Node) -> bool {
+    get_binding_scope(identifier).is_some_and(|scope| scope.kind() == Program)
+}
+
+/// Whether the binding called `name` introduced by the statement
+/// `declaration` is exported, either by the declaration itself or by an
+/// `export { name }`/`export default name` alongside it.
+pub fn is_exported<'a>(
+    declaration: Node<'a>,
+    name: &str,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    let parent = declaration.parent().unwrap();
+    if parent.kind() == ExportStatement {
+        return true;
+    }
+    parent
+        .non_comment_named_children(SupportedLanguage::Javascript)
+        .filter(|statement| {
+            statement.kind() == ExportStatement && statement.child_by_field_name("source").is_none()
+        })
+        .any(|statement| {
+            if let Some(value) = statement.child_by_field_name("value") {
+                return value.kind() == Identifier && value.text(context) == name;
+            }
+            statement
+                .non_comment_named_children(SupportedLanguage::Javascript)
+                .filter(|child| child.kind() == ExportClause)
+                .flat_map(|clause| clause.non_comment_named_children(SupportedLanguage::Javascript))
+                .any(|specifier| specifier.field("name").text(context) == name)
+        })
+}
@@ -0,0 +1,458 @@
+use std::borrow::Cow;
+
+use tree_sitter_lint::{
+    tree_sitter::Node, tree_sitter_grep::SupportedLanguage, NodeExt, QueryMatchContext,
+};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    ast_helpers::{get_method_definition_kind, MethodDefinitionKind},
+    kind::{
+        AssignmentExpression, AugmentedAssignmentExpression, BinaryExpression, Class,
+        ClassDeclaration, ClassStaticBlock, Decorator, ExpressionStatement, Function,
+        FunctionDeclaration, GeneratorFunction, GeneratorFunctionDeclaration, Identifier,
+        MemberExpression, MethodDefinition, Number, ObjectPattern, PairPattern,
+        ParenthesizedExpression, PrivatePropertyIdentifier, PropertyIdentifier,
+        ShorthandPropertyIdentifierPattern, SubscriptExpression, Super, This, UpdateExpression,
+        VariableDeclarator,
+    },
+    utils::ast_utils::get_static_string_value,
+};
+
+use crate::{
+    ast_helpers::{get_accessibility_modifier, is_class, is_member_static, is_parameter_property},
+    kind::{
+        AbstractClassDeclaration, AbstractMethodSignature, IndexSignature, MethodSignature,
+        OptionalParameter, PublicFieldDefinition, RequiredParameter,
+    },
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ClassMemberKind {
+    Field,
+    Method,
+    Getter,
+    Setter,
+    Constructor,
+    ParameterProperty,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+pub struct ClassMember<'a> {
+    // the member itself (or, for parameter properties, the parameter)
+    pub node: Node<'a>,
+    pub name_node: Node<'a>,
+    pub name: Cow<'a, str>,
+    pub kind: ClassMemberKind,
+    pub is_static: bool,
+    pub accessibility: Option<Cow<'a, str>>,
+}
+
+impl<'a> ClassMember<'a> {
+    pub fn is_private_name(&self) -> bool {
+        self.name_node.kind() == PrivatePropertyIdentifier
+    }
+
+    /// Either `#foo` or declared `private`.
+    pub fn is_private(&self) -> bool {
+        self.is_private_name() || self.accessibility.as_deref() == Some("private")
+    }
+
+    pub fn is_accessor(&self) -> bool {
+        matches!(self.kind, ClassMemberKind::Getter | ClassMemberKind::Setter)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ClassMemberReceiver {
+    This,
+    Super,
+    // eg `Foo.bar` inside `class Foo`
+    ClassName,
+    // eg `other.#foo`, or `this` that isn't bound to this class
+    Other,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+pub struct ClassMemberUsage<'a> {
+    pub node: Node<'a>,
+    pub name: Cow<'a, str>,
+    pub receiver: ClassMemberReceiver,
+    pub is_static: bool,
+    // whether the accessed value is observed (so eg `this.foo += 1;` as a
+    // statement is a write but not a read)
+    pub is_read: bool,
+    pub is_written: bool,
+}
+
+/// The members declared directly in a class body, along with the member
+/// accesses found anywhere inside it (including in type annotations, eg
+/// `typeof this.foo`).
+pub struct ClassMemberIndex<'a> {
+    pub members: Vec<ClassMember<'a>>,
+    pub usages: Vec<ClassMemberUsage<'a>>,
+}
+
+impl<'a> ClassMemberIndex<'a> {
+    pub fn new(class_body: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Self {
+        let class_name = class_body
+            .parent()
+            .filter(|&class| is_class(class))
+            .and_then(|class| class.child_by_field_name("name"))
+            .map(|name| name.text(context));
+        let mut collector = UsageCollector {
+            context,
+            class_name,
+            usages: Default::default(),
+        };
+        for member in class_body.non_comment_named_children(SupportedLanguage::Javascript) {
+            let is_static = match member.kind() {
+                ClassStaticBlock => true,
+                MethodDefinition
+                | MethodSignature
+                | AbstractMethodSignature
+                | PublicFieldDefinition => is_member_static(member),
+                _ => false,
+            };
+            for child in member.non_comment_named_children(SupportedLanguage::Javascript) {
+                collector.collect(child, ThisBinding::Class { is_static });
+            }
+        }
+
+        Self {
+            members: get_class_members(class_body, context),
+            usages: collector.usages,
+        }
+    }
+
+    pub fn usages_of<'b>(
+        &'b self,
+        name: &'b str,
+    ) -> impl Iterator<Item = &'b ClassMemberUsage<'a>> {
+        self.usages.iter().filter(move |usage| usage.name == name)
+    }
+
+    pub fn is_read(&self, name: &str) -> bool {
+        self.usages_of(name).any(|usage| usage.is_read)
+    }
+
+    pub fn is_written(&self, name: &str) -> bool {
+        self.usages_of(name).any(|usage| usage.is_written)
+    }
+}
+
+/// What a class body is made up of, as far as whether instances of the class
+/// carry any state or behavior of their own.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ClassBodyKind {
+    Empty,
+    /// Just a constructor, which doesn't declare any parameter properties.
+    OnlyConstructor,
+    /// Just static members and/or static blocks.
+    OnlyStatic,
+    Other,
+}
+
+/// Whether the class body member declares something that instances of the
+/// class have (so eg parameter properties count but a constructor without
+/// any doesn't).
+pub fn is_instance_member<'a>(member: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    match member.kind() {
+        ClassStaticBlock => false,
+        MethodDefinition
+            if get_method_definition_kind(member, context) == MethodDefinitionKind::Constructor =>
+        {
+            constructor_has_parameter_properties(member)
+        }
+        MethodDefinition | MethodSignature | PublicFieldDefinition => !is_member_static(member),
+        IndexSignature => !member.has_child_of_kind("static"),
+        // `static abstract` isn't supported
+        _ => true,
+    }
+}
+
+pub fn constructor_has_parameter_properties(node: Node) -> bool {
+    node.field("parameters")
+        .non_comment_named_children(SupportedLanguage::Javascript)
+        .any(is_parameter_property)
+}
+
+pub fn classify_class_body<'a>(
+    class_body: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> ClassBodyKind {
+    let mut members = class_body
+        .non_comment_named_children(SupportedLanguage::Javascript)
+        .filter(|member| member.kind() != Decorator)
+        .peekable();
+    if members.peek().is_none() {
+        return ClassBodyKind::Empty;
+    }
+
+    let mut only_constructor = true;
+    for member in members {
+        if is_instance_member(member, context) {
+            return ClassBodyKind::Other;
+        }
+        // (a constructor that doesn't declare anything doesn't stop the class
+        // from being "only static")
+        let is_constructor = member.kind() == MethodDefinition
+            && get_method_definition_kind(member, context) == MethodDefinitionKind::Constructor;
+        if !is_constructor {
+            only_constructor = false;
+        }
+    }
+    if only_constructor {
+        ClassBodyKind::OnlyConstructor
+    } else {
+        ClassBodyKind::OnlyStatic
+    }
+}
+
+fn get_member_name<'a>(
+    name_node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Cow<'a, str>> {
+    match name_node.kind() {
+        PropertyIdentifier | PrivatePropertyIdentifier | Identifier | Number => {
+            Some(name_node.text(context))
+        }
+        _ => get_static_string_value(name_node, context),
+    }
+}
+
+fn get_accessibility<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Cow<'a, str>> {
+    get_accessibility_modifier(node)
+        .map(|accessibility_modifier| accessibility_modifier.text(context))
+}
+
+fn get_class_members<'a>(
+    class_body: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Vec<ClassMember<'a>> {
+    let mut members: Vec<ClassMember<'a>> = Default::default();
+    for member in class_body.non_comment_named_children(SupportedLanguage::Javascript) {
+        let kind = match member.kind() {
+            PublicFieldDefinition => ClassMemberKind::Field,
+            MethodSignature | AbstractMethodSignature => ClassMemberKind::Method,
+            MethodDefinition => match get_method_definition_kind(member, context) {
+                MethodDefinitionKind::Get => ClassMemberKind::Getter,
+                MethodDefinitionKind::Set => ClassMemberKind::Setter,
+                MethodDefinitionKind::Constructor => ClassMemberKind::Constructor,
+                _ => ClassMemberKind::Method,
+            },
+            _ => continue,
+        };
+        let name_node = member.field("name");
+        if let Some(name) = get_member_name(name_node, context) {
+            members.push(ClassMember {
+                node: member,
+                name_node,
+                name,
+                kind,
+                is_static: is_member_static(member),
+                accessibility: get_accessibility(member, context),
+            });
+        }
+
+        if kind != ClassMemberKind::Constructor {
+            continue;
+        }
+        for parameter in member
+            .field("parameters")
+            .non_comment_named_children(SupportedLanguage::Javascript)
+        {
+            if !matches!(parameter.kind(), RequiredParameter | OptionalParameter) {
+                continue;
+            }
+            // parameter properties are the parameters with an accessibility
+            // and/or `readonly` modifier
+            let accessibility = get_accessibility(parameter, context);
+            if accessibility.is_none() && !parameter.has_child_of_kind("readonly") {
+                continue;
+            }
+            let pattern = parameter.field("pattern");
+            if pattern.kind() != Identifier {
+                continue;
+            }
+            members.push(ClassMember {
+                node: parameter,
+                name_node: pattern,
+                name: pattern.text(context),
+                kind: ClassMemberKind::ParameterProperty,
+                is_static: false,
+                accessibility,
+            });
+        }
+    }
+    members
+}
+
+#[derive(Copy, Clone)]
+enum ThisBinding {
+    Class { is_static: bool },
+    Other,
+}
+
+struct UsageCollector<'a, 'b, 'c> {
+    context: &'b QueryMatchContext<'a, 'c>,
+    class_name: Option<Cow<'a, str>>,
+    usages: Vec<ClassMemberUsage<'a>>,
+}
+
+impl<'a> UsageCollector<'a, '_, '_> {
+    fn get_receiver(
+        &self,
+        object: Node<'a>,
+        this_binding: ThisBinding,
+    ) -> (ClassMemberReceiver, bool) {
+        match (object.kind(), this_binding) {
+            (This, ThisBinding::Class { is_static }) => (ClassMemberReceiver::This, is_static),
+            (Super, ThisBinding::Class { is_static }) => (ClassMemberReceiver::Super, is_static),
+            (Identifier, _)
+                if self
+                    .class_name
+                    .as_deref()
+                    .is_some_and(|class_name| object.text(self.context) == class_name) =>
+            {
+                (ClassMemberReceiver::ClassName, true)
+            }
+            _ => (ClassMemberReceiver::Other, false),
+        }
+    }
+
+    fn add(
+        &mut self,
+        access: Node<'a>,
+        name: Cow<'a, str>,
+        object: Node<'a>,
+        this_binding: ThisBinding,
+    ) {
+        let (receiver, is_static) = self.get_receiver(object, this_binding);
+        let (is_read, is_written) = get_read_write(access);
+        self.usages.push(ClassMemberUsage {
+            node: access,
+            name,
+            receiver,
+            is_static,
+            is_read,
+            is_written,
+        });
+    }
+
+    fn collect(&mut self, node: Node<'a>, this_binding: ThisBinding) {
+        match node.kind() {
+            MemberExpression => {
+                let property = node.field("property");
+                if matches!(
+                    property.kind(),
+                    PropertyIdentifier | PrivatePropertyIdentifier
+                ) {
+                    self.add(
+                        node,
+                        property.text(self.context),
+                        node.field("object"),
+                        this_binding,
+                    );
+                }
+            }
+            SubscriptExpression => {
+                if let Some(name) = get_static_string_value(node.field("index"), self.context) {
+                    self.add(node, name, node.field("object"), this_binding);
+                }
+            }
+            BinaryExpression => {
+                // `#foo in obj`
+                let left = node.field("left");
+                if left.kind() == PrivatePropertyIdentifier {
+                    self.usages.push(ClassMemberUsage {
+                        node: left,
+                        name: left.text(self.context),
+                        receiver: ClassMemberReceiver::Other,
+                        is_static: false,
+                        is_read: true,
+                        is_written: false,
+                    });
+                }
+            }
+            VariableDeclarator | AssignmentExpression => {
+                let (pattern, value) = match node.kind() {
+                    VariableDeclarator => (node.field("name"), node.child_by_field_name("value")),
+                    _ => (node.field("left"), Some(node.field("right"))),
+                };
+                if pattern.kind() == ObjectPattern {
+                    if let Some(value) = value {
+                        self.collect_destructuring(pattern, value, this_binding);
+                    }
+                }
+            }
+            _ => (),
+        }
+
+        let this_binding = match node.kind() {
+            Function
+            | FunctionDeclaration
+            | GeneratorFunction
+            | GeneratorFunctionDeclaration
+            | MethodDefinition
+            | Class
+            | ClassDeclaration
+            | AbstractClassDeclaration => ThisBinding::Other,
+            _ => this_binding,
+        };
+        for child in node.non_comment_named_children(SupportedLanguage::Javascript) {
+            self.collect(child, this_binding);
+        }
+    }
+
+    // eg `const { foo, bar: baz } = this;`
+    fn collect_destructuring(
+        &mut self,
+        pattern: Node<'a>,
+        value: Node<'a>,
+        this_binding: ThisBinding,
+    ) {
+        let (receiver, is_static) = self.get_receiver(value, this_binding);
+        if receiver == ClassMemberReceiver::Other {
+            return;
+        }
+        for property in pattern.non_comment_named_children(SupportedLanguage::Javascript) {
+            let name = match property.kind() {
+                ShorthandPropertyIdentifierPattern => property.text(self.context),
+                PairPattern => match get_member_name(property.field("key"), self.context) {
+                    Some(name) => name,
+                    None => continue,
+                },
+                _ => continue,
+            };
+            self.usages.push(ClassMemberUsage {
+                node: property,
+                name,
+                receiver,
+                is_static,
+                is_read: true,
+                is_written: false,
+            });
+        }
+    }
+}
+
+fn get_read_write(node: Node) -> (bool, bool) {
+    let mut node = node;
+    let mut parent = node.parent().unwrap();
+    while parent.kind() == ParenthesizedExpression {
+        node = parent;
+        parent = node.parent().unwrap();
+    }
+    match parent.kind() {
+        AssignmentExpression if parent.field("left") == node => (false, true),
+        AugmentedAssignmentExpression if parent.field("left") == node => {
+            (parent.parent().unwrap().kind() != ExpressionStatement, true)
+        }
+        UpdateExpression => (parent.parent().unwrap().kind() != ExpressionStatement, true),
+        _ => (true, false),
+    }
+}
@@ -0,0 +1,32 @@
+use std::borrow::Cow;
+
+use regex::Regex;
+use tree_sitter_lint::{tree_sitter::Node, QueryMatchContext};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    kind::{String as StringKind, TemplateString},
+    utils::ast_utils::get_static_string_value,
+};
+
+use super::find_declaration;
+
+/// The module path passed to `require()` (or `import foo = require()`), if
+/// it's a string literal or a template literal without substitutions.
+pub fn get_require_path<'a>(
+    argument: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Cow<'a, str>> {
+    if !matches!(argument.kind(), StringKind | TemplateString) {
+        return None;
+    }
+    get_static_string_value(argument, context)
+}
+
+pub fn is_require_path_allowed(path: &str, allow_patterns: &[Regex]) -> bool {
+    allow_patterns.iter().any(|pattern| pattern.is_match(path))
+}
+
+/// Whether the `require` being called is the CommonJS one, rather than
+/// something declared locally under that name.
+pub fn is_global_require<'a>(callee: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    find_declaration(callee, context).is_none()
+}
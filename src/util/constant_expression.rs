@@ -0,0 +1,518 @@
+use std::cmp::Ordering;
+
+use tree_sitter_lint::{
+    tree_sitter::Node, tree_sitter_grep::SupportedLanguage, NodeExt, QueryMatchContext,
+};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    kind::{
+        BinaryExpression, False, Identifier, LexicalDeclaration, Null, Number,
+        String as StringKind, TemplateString, TemplateSubstitution, True, UnaryExpression,
+        Undefined, VariableDeclarator,
+    },
+    utils::ast_utils::get_static_string_value,
+};
+
+use super::{
+    find_binding, is_static_template_literal, parse_numeric_literal,
+    skip_parenthesized_expressions, NumericLiteralValue,
+};
+
+const MAX_REFERENCE_DEPTH: usize = 16;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConstantValue {
+    Number(f64),
+    BigInt(i128),
+    String(String),
+    Boolean(bool),
+    Null,
+    Undefined,
+}
+
+impl ConstantValue {
+    pub fn is_truthy(&self) -> bool {
+        match self {
+            Self::Number(value) => *value != 0. && !value.is_nan(),
+            Self::BigInt(value) => *value != 0,
+            Self::String(value) => !value.is_empty(),
+            Self::Boolean(value) => *value,
+            Self::Null | Self::Undefined => false,
+        }
+    }
+
+    pub fn is_nullish(&self) -> bool {
+        matches!(self, Self::Null | Self::Undefined)
+    }
+
+    pub fn type_of(&self) -> &'static str {
+        match self {
+            Self::Number(_) => "number",
+            Self::BigInt(_) => "bigint",
+            Self::String(_) => "string",
+            Self::Boolean(_) => "boolean",
+            Self::Null => "object",
+            Self::Undefined => "undefined",
+        }
+    }
+
+    pub fn to_number(&self) -> Option<f64> {
+        Some(match self {
+            Self::Number(value) => *value,
+            Self::BigInt(_) => return None,
+            Self::String(value) => string_to_number(value),
+            Self::Boolean(value) => {
+                if *value {
+                    1.
+                } else {
+                    0.
+                }
+            }
+            Self::Null => 0.,
+            Self::Undefined => f64::NAN,
+        })
+    }
+
+    pub fn to_js_string(&self) -> String {
+        match self {
+            Self::Number(value) => number_to_string(*value),
+            Self::BigInt(value) => value.to_string(),
+            Self::String(value) => value.clone(),
+            Self::Boolean(value) => value.to_string(),
+            Self::Null => "null".to_owned(),
+            Self::Undefined => "undefined".to_owned(),
+        }
+    }
+
+    pub fn strict_equals(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a == b,
+            _ => self == other,
+        }
+    }
+
+    pub fn loose_equals(&self, other: &Self) -> bool {
+        match (self, other) {
+            (a, b) if a.is_nullish() || b.is_nullish() => a.is_nullish() && b.is_nullish(),
+            (Self::String(_), Self::String(_)) | (Self::Boolean(_), Self::Boolean(_)) => {
+                self == other
+            }
+            (Self::BigInt(a), Self::BigInt(b)) => a == b,
+            (Self::BigInt(a), b) | (b, Self::BigInt(a)) => {
+                b.to_number().is_some_and(|b| b == *a as f64)
+            }
+            _ => self.to_number() == other.to_number() && self.to_number().is_some(),
+        }
+    }
+}
+
+fn string_to_number(value: &str) -> f64 {
+    let value = value.trim();
+    if value.is_empty() {
+        return 0.;
+    }
+    match value {
+        "Infinity" | "+Infinity" => return f64::INFINITY,
+        "-Infinity" => return f64::NEG_INFINITY,
+        _ => (),
+    }
+    if value.contains('_') || value.ends_with('n') {
+        return f64::NAN;
+    }
+    if value.starts_with('0') && value.chars().all(|c| c.is_ascii_digit()) {
+        // no legacy octal when converting strings
+        return value.parse().unwrap_or(f64::NAN);
+    }
+    parse_numeric_literal(value).map_or(f64::NAN, |literal| literal.value.to_number())
+}
+
+fn number_to_string(value: f64) -> String {
+    if value.is_nan() {
+        return "NaN".to_owned();
+    }
+    if value.is_infinite() {
+        return if value > 0. { "Infinity" } else { "-Infinity" }.to_owned();
+    }
+    if value == 0. {
+        return "0".to_owned();
+    }
+    let magnitude = value.abs();
+    if (1e-7..1e21).contains(&magnitude) {
+        return value.to_string();
+    }
+    let formatted = format!("{value:e}");
+    match formatted.split_once('e') {
+        Some((mantissa, exponent)) if !exponent.starts_with('-') => {
+            format!("{mantissa}e+{exponent}")
+        }
+        _ => formatted,
+    }
+}
+
+fn to_int32(value: f64) -> i32 {
+    if !value.is_finite() {
+        return 0;
+    }
+    (value.trunc().rem_euclid(4294967296.) as u32) as i32
+}
+
+fn to_uint32(value: f64) -> u32 {
+    to_int32(value) as u32
+}
+
+/// The initializer of the `const` that `identifier` refers to, if the
+/// innermost binding of its name (see [`find_binding`]) is such a `const`.
+fn find_const_initializer<'a>(
+    identifier: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<Node<'a>> {
+    let binding = find_binding(identifier, context)?;
+    let declarator = binding.parent()?;
+    if declarator.kind() != VariableDeclarator || declarator.field("name") != binding {
+        return None;
+    }
+    let declaration = declarator.parent()?;
+    if declaration.kind() != LexicalDeclaration || declaration.field("kind").kind() != "const" {
+        return None;
+    }
+    declarator.child_by_field_name("value")
+}
+
+fn evaluate_template_string<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+    depth: usize,
+) -> Option<ConstantValue> {
+    if is_static_template_literal(node) {
+        return get_static_string_value(node, context)
+            .map(|value| ConstantValue::String(value.into_owned()));
+    }
+
+    let text = node.text(context);
+    let start_byte = node.start_byte();
+    let mut value = String::new();
+    let mut chunk_start = 1;
+    for substitution in node
+        .non_comment_named_children(SupportedLanguage::Javascript)
+        .filter(|child| child.kind() == TemplateSubstitution)
+    {
+        let chunk = &text[chunk_start..substitution.start_byte() - start_byte];
+        if chunk.contains('\\') {
+            return None;
+        }
+        value.push_str(chunk);
+        value.push_str(
+            &evaluate(
+                substitution.first_non_comment_named_child(SupportedLanguage::Javascript),
+                context,
+                depth,
+            )?
+            .to_js_string(),
+        );
+        chunk_start = substitution.end_byte() - start_byte;
+    }
+    let chunk = &text[chunk_start..text.len() - 1];
+    if chunk.contains('\\') {
+        return None;
+    }
+    value.push_str(chunk);
+    Some(ConstantValue::String(value))
+}
+
+fn evaluate_unary<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+    depth: usize,
+) -> Option<ConstantValue> {
+    let operator = node.field("operator").kind();
+    if operator == "delete" {
+        return None;
+    }
+    let argument = evaluate(node.field("argument"), context, depth)?;
+    Some(match operator {
+        "!" => ConstantValue::Boolean(!argument.is_truthy()),
+        "-" => match argument {
+            ConstantValue::BigInt(value) => ConstantValue::BigInt(value.checked_neg()?),
+            argument => ConstantValue::Number(-argument.to_number()?),
+        },
+        "+" => ConstantValue::Number(argument.to_number()?),
+        "~" => match argument {
+            ConstantValue::BigInt(value) => ConstantValue::BigInt(!value),
+            argument => ConstantValue::Number(!to_int32(argument.to_number()?) as f64),
+        },
+        "typeof" => ConstantValue::String(argument.type_of().to_owned()),
+        "void" => ConstantValue::Undefined,
+        _ => return None,
+    })
+}
+
+fn evaluate_bigint_binary(operator: &str, left: i128, right: i128) -> Option<ConstantValue> {
+    Some(ConstantValue::BigInt(match operator {
+        "+" => left.checked_add(right)?,
+        "-" => left.checked_sub(right)?,
+        "*" => left.checked_mul(right)?,
+        "/" => left.checked_div(right)?,
+        "%" => left.checked_rem(right)?,
+        "**" => left.checked_pow(right.try_into().ok()?)?,
+        "|" => left | right,
+        "&" => left & right,
+        "^" => left ^ right,
+        "<<" => left.checked_shl(right.try_into().ok()?)?,
+        ">>" => left.checked_shr(right.try_into().ok()?)?,
+        _ => return None,
+    }))
+}
+
+fn compare(left: &ConstantValue, right: &ConstantValue) -> Option<Ordering> {
+    match (left, right) {
+        (ConstantValue::String(left), ConstantValue::String(right)) => {
+            Some(left.encode_utf16().cmp(right.encode_utf16()))
+        }
+        (ConstantValue::BigInt(left), ConstantValue::BigInt(right)) => Some(left.cmp(right)),
+        (ConstantValue::BigInt(left), right) => (*left as f64).partial_cmp(&right.to_number()?),
+        (left, ConstantValue::BigInt(right)) => left.to_number()?.partial_cmp(&(*right as f64)),
+        _ => left.to_number()?.partial_cmp(&right.to_number()?),
+    }
+}
+
+fn evaluate_binary<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+    depth: usize,
+) -> Option<ConstantValue> {
+    let operator = node.field("operator").kind();
+    let left = evaluate(node.field("left"), context, depth)?;
+
+    match operator {
+        "&&" if !left.is_truthy() => return Some(left),
+        "||" if left.is_truthy() => return Some(left),
+        "??" if !left.is_nullish() => return Some(left),
+        "&&" | "||" | "??" => return evaluate(node.field("right"), context, depth),
+        _ => (),
+    }
+
+    let right = evaluate(node.field("right"), context, depth)?;
+
+    match operator {
+        "===" => return Some(ConstantValue::Boolean(left.strict_equals(&right))),
+        "!==" => return Some(ConstantValue::Boolean(!left.strict_equals(&right))),
+        "==" => return Some(ConstantValue::Boolean(left.loose_equals(&right))),
+        "!=" => return Some(ConstantValue::Boolean(!left.loose_equals(&right))),
+        "<" => {
+            return Some(ConstantValue::Boolean(
+                compare(&left, &right) == Some(Ordering::Less),
+            ))
+        }
+        ">" => {
+            return Some(ConstantValue::Boolean(
+                compare(&left, &right) == Some(Ordering::Greater),
+            ))
+        }
+        "<=" => {
+            return Some(ConstantValue::Boolean(matches!(
+                compare(&left, &right),
+                Some(Ordering::Less | Ordering::Equal)
+            )))
+        }
+        ">=" => {
+            return Some(ConstantValue::Boolean(matches!(
+                compare(&left, &right),
+                Some(Ordering::Greater | Ordering::Equal)
+            )))
+        }
+        "+" if matches!(left, ConstantValue::String(_))
+            || matches!(right, ConstantValue::String(_)) =>
+        {
+            return Some(ConstantValue::String(
+                left.to_js_string() + &right.to_js_string(),
+            ))
+        }
+        _ => (),
+    }
+
+    match (&left, &right) {
+        (ConstantValue::BigInt(left), ConstantValue::BigInt(right)) => {
+            return evaluate_bigint_binary(operator, *left, *right);
+        }
+        (ConstantValue::BigInt(_), _) | (_, ConstantValue::BigInt(_)) => return None,
+        _ => (),
+    }
+
+    let left = left.to_number()?;
+    let right = right.to_number()?;
+    Some(ConstantValue::Number(match operator {
+        "+" => left + right,
+        "-" => left - right,
+        "*" => left * right,
+        "/" => left / right,
+        "%" => left % right,
+        "**" => left.powf(right),
+        "|" => (to_int32(left) | to_int32(right)) as f64,
+        "&" => (to_int32(left) & to_int32(right)) as f64,
+        "^" => (to_int32(left) ^ to_int32(right)) as f64,
+        "<<" => to_int32(left).wrapping_shl(to_uint32(right) & 0x1f) as f64,
+        ">>" => to_int32(left).wrapping_shr(to_uint32(right) & 0x1f) as f64,
+        ">>>" => to_uint32(left).wrapping_shr(to_uint32(right) & 0x1f) as f64,
+        _ => return None,
+    }))
+}
+
+fn evaluate<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+    depth: usize,
+) -> Option<ConstantValue> {
+    let node = skip_parenthesized_expressions(node);
+    match node.kind() {
+        Number => parse_numeric_literal(&node.text(context)).map(|literal| match literal.value {
+            NumericLiteralValue::Number(value) => ConstantValue::Number(value),
+            NumericLiteralValue::BigInt(value) => ConstantValue::BigInt(value),
+        }),
+        StringKind => get_static_string_value(node, context)
+            .map(|value| ConstantValue::String(value.into_owned())),
+        TemplateString => evaluate_template_string(node, context, depth),
+        True => Some(ConstantValue::Boolean(true)),
+        False => Some(ConstantValue::Boolean(false)),
+        Null => Some(ConstantValue::Null),
+        Undefined => Some(ConstantValue::Undefined),
+        UnaryExpression => evaluate_unary(node, context, depth),
+        BinaryExpression => evaluate_binary(node, context, depth),
+        Identifier => match &*node.text(context) {
+            "NaN" => Some(ConstantValue::Number(f64::NAN)),
+            "Infinity" => Some(ConstantValue::Number(f64::INFINITY)),
+            _ if depth < MAX_REFERENCE_DEPTH => {
+                evaluate(find_const_initializer(node, context)?, context, depth + 1)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Folds `node` to a value if it is built only out of literals, unary/binary
+/// operators, template literals and references to `const` variables that are
+/// themselves initialized with such an expression. A reference only counts if
+/// no closer binding of the same name (eg a parameter or a `let` in an inner
+/// block) shadows the `const`.
+pub fn evaluate_constant_expression<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> Option<ConstantValue> {
+    evaluate(node, context, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use tree_sitter_lint::{rule, rule_tests, violation, Rule, RuleTester};
+
+    use super::*;
+
+    // reports the folded value of each expression statement
+    fn evaluate_constant_expression_rule() -> Arc<dyn Rule> {
+        rule! {
+            name => "evaluate-constant-expression",
+            languages => [Typescript],
+            messages => [
+                constant => "{{value}}",
+            ],
+            listeners => [
+                r#"
+                  (expression_statement) @c
+                "# => |node, context| {
+                    let Some(value) = evaluate_constant_expression(
+                        node.first_non_comment_named_child(SupportedLanguage::Javascript),
+                        context,
+                    ) else {
+                        return;
+                    };
+                    context.report(violation! {
+                        node => node,
+                        message_id => "constant",
+                        data => {
+                            value => format!("{value:?}"),
+                        },
+                    });
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_evaluate_constant_expression() {
+        RuleTester::run(
+            evaluate_constant_expression_rule(),
+            rule_tests! {
+                valid => [
+                    "foo;",
+                    "foo + 1;",
+                    "let x = 1; x;",
+                    "const x = foo(); x;",
+                    "const x = 1; function f(x) { x; }",
+                    "const x = 1; const f = (x) => { x; };",
+                    "const x = 1; function f({ x }) { x; }",
+                    "const x = 1; function f([, x = 2]) { x; }",
+                    "const x = 1; { let x = g(); x; }",
+                    "const x = 1; function f() { if (y) { x; } var x = g(); }",
+                    "const x = 1; for (const x of xs) { x; }",
+                    "const x = 1; for (let x = 0; x < 3; x++) { x; }",
+                    "const x = 1; try {} catch (x) { x; }",
+                    "const x = 1; function f() { x; function x() {} }",
+                    "const x = 1; { class x {} x; }",
+                    "const x = 1; switch (y) { case 1: let x = g(); x; }",
+                    "const x = 1; (function x() { x; });",
+                ],
+                invalid => [
+                    {
+                      code => "1 + 2;",
+                      errors => [{ message_id => "constant", data => { value => "Number(3.0)" } }],
+                    },
+                    {
+                      code => "const x = 1; x + 1;",
+                      errors => [{ message_id => "constant", data => { value => "Number(2.0)" } }],
+                    },
+                    {
+                      code => "const x = 1; function f(y) { x * 3; }",
+                      errors => [{ message_id => "constant", data => { value => "Number(3.0)" } }],
+                    },
+                    {
+                      code => "const x = 1; function f() { const x = 2; x; }",
+                      errors => [{ message_id => "constant", data => { value => "Number(2.0)" } }],
+                    },
+                    {
+                      code => "const x = 1; { let y = g(); x; }",
+                      errors => [{ message_id => "constant", data => { value => "Number(1.0)" } }],
+                    },
+                    {
+                      code => "export const a = 'foo'; const b = `${a}bar`; b;",
+                      errors => [{ message_id => "constant", data => { value => "String(\"foobar\")" } }],
+                    },
+                    {
+                      code => "1n << 2n;",
+                      errors => [{ message_id => "constant", data => { value => "BigInt(4)" } }],
+                    },
+                    {
+                      code => "!!'';",
+                      errors => [{ message_id => "constant", data => { value => "Boolean(false)" } }],
+                    },
+                    {
+                      code => "typeof null;",
+                      errors => [{ message_id => "constant", data => { value => "String(\"object\")" } }],
+                    },
+                    {
+                      code => "'1' == 1;",
+                      errors => [{ message_id => "constant", data => { value => "Boolean(true)" } }],
+                    },
+                    {
+                      code => "null ?? 'a' + 1;",
+                      errors => [{ message_id => "constant", data => { value => "String(\"a1\")" } }],
+                    },
+                    {
+                      code => "void 0;",
+                      errors => [{ message_id => "constant", data => { value => "Undefined" } }],
+                    },
+                ],
+            },
+        )
+    }
+}
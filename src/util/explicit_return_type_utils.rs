@@ -0,0 +1,262 @@
+use std::iter;
+
+use squalid::OptionExt;
+use tree_sitter_lint::{
+    tree_sitter::{Node, Range},
+    tree_sitter_grep::SupportedLanguage,
+    NodeExt, QueryMatchContext,
+};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    ast_helpers::{get_method_definition_kind, MethodDefinitionKind},
+    kind::{
+        Arguments, ArrowFunction, CallExpression, ExportStatement, ExpressionStatement, Function,
+        FunctionDeclaration, GeneratorFunction, GeneratorFunctionDeclaration, Identifier,
+        JsxExpression, MethodDefinition, Object, Pair, PropertyIdentifier, ReturnStatement,
+        StatementBlock, VariableDeclarator,
+    },
+    utils::ast_utils::get_function_head_range,
+};
+
+use super::skip_parenthesized_expressions;
+use crate::ast_helpers::unwrap_type_assertion;
+use crate::kind::{
+    AsExpression, OptionalParameter, PublicFieldDefinition, RequiredParameter, TypeAssertion,
+};
+
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ReturnTypeOptions {
+    pub allow_expressions: bool,
+    pub allow_typed_function_expressions: bool,
+    pub allow_higher_order_functions: bool,
+    pub allow_direct_const_assertion_in_arrow_functions: bool,
+}
+
+pub fn is_function(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        ArrowFunction
+            | Function
+            | FunctionDeclaration
+            | GeneratorFunction
+            | GeneratorFunctionDeclaration
+            | MethodDefinition
+    )
+}
+
+fn is_type_assertion(node: Node) -> bool {
+    matches!(node.kind(), AsExpression | TypeAssertion)
+}
+
+fn is_variable_declarator_with_type_annotation(node: Node) -> bool {
+    node.kind() == VariableDeclarator && node.child_by_field_name("type").is_some()
+}
+
+fn is_default_function_parameter_with_type_annotation(node: Node) -> bool {
+    matches!(node.kind(), RequiredParameter | OptionalParameter)
+        && node.child_by_field_name("type").is_some()
+}
+
+fn is_property_definition_with_type_annotation(node: Node) -> bool {
+    node.kind() == PublicFieldDefinition && node.child_by_field_name("type").is_some()
+}
+
+fn is_function_argument(node: Node) -> bool {
+    node.kind() == Arguments && node.parent().unwrap().kind() == CallExpression
+}
+
+fn is_typed_jsx(node: Node) -> bool {
+    node.kind() == JsxExpression
+}
+
+fn is_property_of_object_with_type(property: Node) -> bool {
+    if !matches!(property.kind(), Pair | MethodDefinition) {
+        return false;
+    }
+    let object = property.parent().unwrap();
+    if object.kind() != Object {
+        return false;
+    }
+    let parent = object.parent().unwrap();
+
+    is_type_assertion(parent)
+        || is_property_definition_with_type_annotation(parent)
+        || is_variable_declarator_with_type_annotation(parent)
+        || is_function_argument(parent)
+        || is_property_of_object_with_type(parent)
+        || is_typed_jsx(parent)
+}
+
+/// Whether the function expression gets its type from where it appears, eg
+/// from the annotation of the variable it's assigned to.
+pub fn is_in_typed_position(node: Node) -> bool {
+    let parent = node.parent().unwrap();
+    is_type_assertion(parent)
+        || is_variable_declarator_with_type_annotation(parent)
+        || is_default_function_parameter_with_type_annotation(parent)
+        || is_property_definition_with_type_annotation(parent)
+        || is_function_argument(parent)
+        || is_property_of_object_with_type(if node.kind() == MethodDefinition {
+            node
+        } else {
+            parent
+        })
+        || is_typed_jsx(parent)
+}
+
+pub fn is_typed_function_expression(node: Node, options: &ReturnTypeOptions) -> bool {
+    options.allow_typed_function_expressions && is_in_typed_position(node)
+}
+
+fn get_return_argument(node: Node) -> Option<Node> {
+    node.non_comment_named_children(SupportedLanguage::Javascript)
+        .next()
+        .map(skip_parenthesized_expressions)
+}
+
+fn is_const_assertion<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    unwrap_type_assertion(node, context).is_some_and(|assertion| assertion.type_.is_none())
+}
+
+fn returns_const_assertion_directly<'a>(
+    node: Node<'a>,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    let body = node.field("body");
+    if is_const_assertion(skip_parenthesized_expressions(body), context) {
+        return true;
+    }
+
+    body.kind() == StatementBlock
+        && body
+            .non_comment_named_children(SupportedLanguage::Javascript)
+            .last()
+            .filter(|last_statement| last_statement.kind() == ReturnStatement)
+            .and_then(get_return_argument)
+            .matches(|argument| is_const_assertion(argument, context))
+}
+
+pub fn is_valid_function_expression_return_type<'a>(
+    node: Node<'a>,
+    options: &ReturnTypeOptions,
+    context: &QueryMatchContext<'a, '_>,
+) -> bool {
+    if is_typed_function_expression(node, options) {
+        return true;
+    }
+
+    let parent = node.parent().unwrap();
+    if options.allow_expressions
+        && node.kind() != MethodDefinition
+        && !matches!(
+            parent.kind(),
+            VariableDeclarator | PublicFieldDefinition | ExportStatement
+        )
+    {
+        return true;
+    }
+
+    options.allow_direct_const_assertion_in_arrow_functions
+        && node.kind() == ArrowFunction
+        && returns_const_assertion_directly(node, context)
+}
+
+/// Whether the function's body is (or consists of just a `return` of) another
+/// function.
+pub fn does_immediately_return_function_expression(node: Node) -> bool {
+    let body = node.field("body");
+    if body.kind() == StatementBlock {
+        let mut statements = body.non_comment_named_children(SupportedLanguage::Javascript);
+        if let (Some(statement), None) = (statements.next(), statements.next()) {
+            if statement.kind() == ReturnStatement {
+                if let Some(argument) = get_return_argument(statement) {
+                    return is_function(argument);
+                }
+            }
+        }
+    }
+
+    is_function(skip_parenthesized_expressions(body))
+}
+
+fn is_constructor_or_setter<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> bool {
+    node.kind() == MethodDefinition
+        && matches!(
+            get_method_definition_kind(node, context),
+            MethodDefinitionKind::Constructor | MethodDefinitionKind::Set
+        )
+}
+
+pub fn check_function_return_type<'a>(
+    node: Node<'a>,
+    options: &ReturnTypeOptions,
+    context: &QueryMatchContext<'a, '_>,
+    report: impl FnOnce(Range),
+) {
+    if options.allow_higher_order_functions && does_immediately_return_function_expression(node) {
+        return;
+    }
+
+    if node.child_by_field_name("return_type").is_some() || is_constructor_or_setter(node, context)
+    {
+        return;
+    }
+
+    report(get_function_head_range(node));
+}
+
+pub fn check_function_expression_return_type<'a>(
+    node: Node<'a>,
+    options: &ReturnTypeOptions,
+    context: &QueryMatchContext<'a, '_>,
+    report: impl FnOnce(Range),
+) {
+    if is_valid_function_expression_return_type(node, options, context) {
+        return;
+    }
+
+    check_function_return_type(node, options, context, report);
+}
+
+/// Whether the function is returned from a function with an explicit return
+/// type (or from one assigned to an annotated variable or class field).
+pub fn ancestor_has_return_type(node: Node) -> bool {
+    let parent = node.parent().unwrap();
+    let ancestor = match parent.kind() {
+        Pair => parent.field("value"),
+        // object literal methods
+        Object => node,
+        _ => parent,
+    };
+
+    // if the ancestor is not a return, then this function was not returned at
+    // all
+    let is_return_statement = ancestor.kind() == ReturnStatement;
+    let is_bodyless_arrow =
+        ancestor.kind() == ArrowFunction && ancestor.field("body").kind() != StatementBlock;
+    if !is_return_statement && !is_bodyless_arrow {
+        return false;
+    }
+
+    for ancestor in iter::successors(Some(ancestor), |ancestor| ancestor.parent()) {
+        match ancestor.kind() {
+            _ if is_function(ancestor) => {
+                if ancestor.child_by_field_name("return_type").is_some() {
+                    return true;
+                }
+            }
+            // const x: Foo = () => {};
+            // Assume that a typed variable types the function expression
+            VariableDeclarator => {
+                return ancestor.field("name").kind() == Identifier
+                    && ancestor.child_by_field_name("type").is_some();
+            }
+            PublicFieldDefinition => {
+                return ancestor.field("name").kind() == PropertyIdentifier
+                    && ancestor.child_by_field_name("type").is_some();
+            }
+            ExpressionStatement => return false,
+            _ => (),
+        }
+    }
+    false
+}
@@ -0,0 +1,33 @@
+use std::borrow::Cow;
+
+use tree_sitter_lint::serde_json;
+
+// (`char::is_alphabetic()`/`is_alphanumeric()` are close enough
+// approximations of the Unicode `ID_Start`/`ID_Continue` properties)
+fn is_identifier_start(c: char) -> bool {
+    c == '$' || c == '_' || c.is_alphabetic()
+}
+
+fn is_identifier_part(c: char) -> bool {
+    is_identifier_start(c) || c.is_alphanumeric() || c == '\u{200c}' || c == '\u{200d}'
+}
+
+/// Whether `name` could be written as an identifier, eg after a `.` in a
+/// member access or as an unquoted object literal/type member key.
+///
+/// Reserved words count as valid here, since they're fine in those
+/// positions (eg `foo.class`, `{ delete: true }`).
+pub fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(is_identifier_start) && chars.all(is_identifier_part)
+}
+
+/// The property name as it'd be written as a key: as is if it's a valid
+/// identifier, otherwise as a (double-quoted, escaped) string literal.
+pub fn quote_property_name(name: &str) -> Cow<'_, str> {
+    if is_valid_identifier(name) {
+        return name.into();
+    }
+    // JSON string escaping is also valid JS string escaping
+    serde_json::to_string(name).unwrap().into()
+}
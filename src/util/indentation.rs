@@ -0,0 +1,91 @@
+use std::iter;
+
+use tree_sitter_lint::{tree_sitter::Node, NodeExt, QueryMatchContext};
+
+/// The whitespace at the start of the line that `node` starts on.
+pub fn get_line_indentation<'a>(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> String {
+    let line_start_byte = node.start_byte() - node.start_position().column;
+    match iter::successors(Some(node), |node| node.parent())
+        .find(|ancestor| ancestor.start_byte() <= line_start_byte)
+    {
+        Some(enclosing) => enclosing.text(context)[line_start_byte - enclosing.start_byte()..]
+            .chars()
+            .take_while(|&ch| ch == ' ' || ch == '\t')
+            .collect(),
+        // leading whitespace at the start of the file isn't part of any node
+        None => " ".repeat(
+            context
+                .file_run_context
+                .tree
+                .root_node()
+                .start_position()
+                .column,
+        ),
+    }
+}
+
+/// Builds the text for a fix that spans multiple lines, indenting each new
+/// line relative to the line that the anchor node starts on. (The first line
+/// is assumed to continue wherever the fix puts it.)
+///
+/// Nested lines are indented with a tab if the anchor's line is, otherwise
+/// with two spaces.
+#[derive(Clone, Debug)]
+pub struct IndentedText {
+    indentation: String,
+    indent_unit: &'static str,
+    text: String,
+}
+
+impl IndentedText {
+    pub fn new<'a>(anchor: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Self {
+        let indentation = get_line_indentation(anchor, context);
+        Self {
+            indent_unit: if indentation.contains('\t') {
+                "\t"
+            } else {
+                "  "
+            },
+            indentation,
+            text: Default::default(),
+        }
+    }
+
+    /// The indentation of the anchor's line.
+    pub fn indentation(&self) -> &str {
+        &self.indentation
+    }
+
+    /// Appends to the current line.
+    pub fn push(&mut self, text: &str) -> &mut Self {
+        self.text.push_str(text);
+        self
+    }
+
+    /// Starts a new line, indented `depth` levels deeper than the anchor's
+    /// line.
+    pub fn newline(&mut self, depth: usize) -> &mut Self {
+        self.text.push('\n');
+        self.text.push_str(&self.indentation);
+        for _ in 0..depth {
+            self.text.push_str(self.indent_unit);
+        }
+        self
+    }
+
+    /// Appends `text` line by line, starting each of its lines after the
+    /// first on a new line at `depth`.
+    pub fn push_lines(&mut self, text: &str, depth: usize) -> &mut Self {
+        for (index, line) in text.lines().enumerate() {
+            if index > 0 {
+                self.newline(depth);
+            }
+            self.push(line);
+        }
+        self
+    }
+
+    pub fn build(&self) -> String {
+        self.text.clone()
+    }
+}
@@ -0,0 +1,16 @@
+use std::path::Path;
+
+use tree_sitter_lint::{tree_sitter::Language, tree_sitter_grep::SupportedLanguage};
+
+use crate::kind::TypeAssertion;
+
+/// The grammar `.tsx` files get parsed with.
+pub fn get_tsx_language() -> Language {
+    SupportedLanguage::Typescript.language(Some(Path::new("file.tsx")))
+}
+
+/// (The TSX grammar is the one without angle-bracket type assertions, since
+/// those would be ambiguous with JSX.)
+pub fn is_tsx_language(language: Language) -> bool {
+    language.id_for_node_kind(TypeAssertion, true) == 0
+}
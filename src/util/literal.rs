@@ -0,0 +1,25 @@
+use tree_sitter_lint::{tree_sitter::Node, NodeExt};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    ast_helpers::{is_simple_template_literal, skip_nodes_of_type},
+    kind::{is_literal_kind, ParenthesizedExpression, TemplateString, UnaryExpression},
+};
+
+pub fn skip_parenthesized_expressions(node: Node) -> Node {
+    skip_nodes_of_type(node, ParenthesizedExpression)
+}
+
+pub fn is_static_template_literal(node: Node) -> bool {
+    node.kind() == TemplateString && is_simple_template_literal(node)
+}
+
+pub fn is_static_literal(node: Node) -> bool {
+    let node = skip_parenthesized_expressions(node);
+    is_literal_kind(node.kind()) || is_static_template_literal(node)
+}
+
+pub fn is_signed_literal(node: Node) -> bool {
+    let node = skip_parenthesized_expressions(node);
+    node.kind() == UnaryExpression
+        && matches!(node.field("operator").kind(), "+" | "-")
+        && is_literal_kind(skip_parenthesized_expressions(node.field("argument")).kind())
+}
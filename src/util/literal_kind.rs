@@ -0,0 +1,32 @@
+use tree_sitter_lint::{tree_sitter::Node, NodeExt, QueryMatchContext};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    ast_helpers::is_simple_template_literal,
+    kind::{False, Null, Number, Regex, String as StringKind, TemplateString, True},
+};
+
+/// What type of value a literal expression is, ie what type it would be
+/// inferred as (once widened).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LiteralKind {
+    BigInt,
+    Boolean,
+    Null,
+    Number,
+    RegExp,
+    String,
+}
+
+/// `None` if `node` isn't a literal. Template literals without any
+/// substitutions count as string literals.
+pub fn get_literal_kind(node: Node, context: &QueryMatchContext) -> Option<LiteralKind> {
+    Some(match node.kind() {
+        Number if node.text(context).ends_with('n') => LiteralKind::BigInt,
+        Number => LiteralKind::Number,
+        True | False => LiteralKind::Boolean,
+        Null => LiteralKind::Null,
+        Regex => LiteralKind::RegExp,
+        StringKind => LiteralKind::String,
+        TemplateString if is_simple_template_literal(node) => LiteralKind::String,
+        _ => return None,
+    })
+}
@@ -0,0 +1,117 @@
+use std::borrow::Cow;
+
+use tree_sitter_lint::{tree_sitter::Node, NodeExt, QueryMatchContext};
+use tree_sitter_lint_plugin_eslint_builtin::{
+    kind::{CallExpression, MemberExpression, PropertyIdentifier, SubscriptExpression},
+    utils::ast_utils::get_static_string_value,
+};
+
+use super::skip_parenthesized_expressions;
+use crate::ast_helpers::{get_call_arguments, CallArgument};
+
+/// A call of the form `receiver.method(...arguments)` (or
+/// `receiver['method'](...)`).
+#[derive(Clone, Debug)]
+pub struct MethodCall<'a> {
+    pub node: Node<'a>,
+    pub receiver: Node<'a>,
+    pub method: Cow<'a, str>,
+    pub arguments: Vec<Node<'a>>,
+}
+
+impl<'a> MethodCall<'a> {
+    pub fn from_node(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Option<Self> {
+        if node.kind() != CallExpression {
+            return None;
+        }
+        let callee = skip_parenthesized_expressions(node.field("function"));
+        let method = match callee.kind() {
+            MemberExpression => {
+                let property = callee.field("property");
+                (property.kind() == PropertyIdentifier).then(|| property.text(context))?
+            }
+            SubscriptExpression => get_static_string_value(callee.field("index"), context)?,
+            _ => return None,
+        };
+        let arguments = get_call_arguments(node)
+            .map(|argument| match argument {
+                CallArgument::Expression(argument) => Some(argument),
+                CallArgument::Spread { .. } => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self {
+            node,
+            receiver: callee.field("object"),
+            method,
+            arguments,
+        })
+    }
+
+    pub fn is_optional(&self) -> bool {
+        skip_parenthesized_expressions(self.node.field("function"))
+            .child_by_field_name("optional_chain")
+            .is_some()
+    }
+}
+
+/// A declarative description of the method calls a rule is interested in,
+/// eg "`.indexOf()` with one argument that's a string literal":
+///
+/// ```ignore
+/// MethodCallMatcher {
+///     methods: &["indexOf"],
+///     argument_kinds: &[&[StringKind]],
+///     ..Default::default()
+/// }
+/// ```
+///
+/// Calls with spread arguments never match, since which argument is which
+/// isn't known.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct MethodCallMatcher<'m> {
+    /// (any method if empty)
+    pub methods: &'m [&'m str],
+    pub receiver: Option<fn(Node) -> bool>,
+    /// The allowed numbers of arguments (any number if empty).
+    pub argument_counts: &'m [usize],
+    /// The allowed kinds of the argument in each position (looking through
+    /// parentheses). An empty list allows any kind, as do positions past the
+    /// end.
+    pub argument_kinds: &'m [&'m [&'m str]],
+    pub allow_optional_chain: bool,
+}
+
+impl MethodCallMatcher<'_> {
+    pub fn matches<'a>(
+        &self,
+        node: Node<'a>,
+        context: &QueryMatchContext<'a, '_>,
+    ) -> Option<MethodCall<'a>> {
+        let call = MethodCall::from_node(node, context)?;
+        if !self.methods.is_empty() && !self.methods.contains(&&*call.method) {
+            return None;
+        }
+        if !self.allow_optional_chain && call.is_optional() {
+            return None;
+        }
+        if self
+            .receiver
+            .is_some_and(|receiver| !receiver(skip_parenthesized_expressions(call.receiver)))
+        {
+            return None;
+        }
+        if !self.argument_counts.is_empty() && !self.argument_counts.contains(&call.arguments.len())
+        {
+            return None;
+        }
+        let are_argument_kinds_allowed =
+            call.arguments
+                .iter()
+                .zip(self.argument_kinds)
+                .all(|(&argument, &kinds)| {
+                    kinds.is_empty()
+                        || kinds.contains(&skip_parenthesized_expressions(argument).kind())
+                });
+        are_argument_kinds_allowed.then_some(call)
+    }
+}
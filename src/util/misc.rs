@@ -6,13 +6,16 @@ use tree_sitter_lint::{
 use tree_sitter_lint_plugin_eslint_builtin::{
     assert_kind,
     kind::{
-        ComputedPropertyName, Identifier, MethodDefinition, PrivatePropertyIdentifier,
+        ComputedPropertyName, Identifier, MethodDefinition, Number, PrivatePropertyIdentifier,
         PropertyIdentifier,
     },
     utils::ast_utils::get_static_string_value,
 };
 
-use crate::{kind::MethodSignature, type_utils::requires_quoting};
+use super::{is_valid_identifier, parse_numeric_literal, quote_property_name};
+use crate::kind::{
+    AbstractMethodSignature, MethodSignature, PropertySignature, PublicFieldDefinition,
+};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum MemberNameType {
@@ -22,6 +25,7 @@ pub enum MemberNameType {
     Expression,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MemberName<'a> {
     pub type_: MemberNameType,
     pub name: Cow<'a, str>,
@@ -33,7 +37,11 @@ pub fn get_name_from_member<'a>(
 ) -> MemberName<'a> {
     assert_kind!(
         member,
-        MethodDefinition | MethodSignature /*TODO: others*/
+        MethodDefinition
+            | MethodSignature
+            | AbstractMethodSignature
+            | PropertySignature
+            | PublicFieldDefinition
     );
     let key = member.field("name");
     get_name_from_member_key(key, context)
@@ -57,21 +65,39 @@ fn get_name_from_member_key<'a>(
             context,
         ),
         tree_sitter_lint_plugin_eslint_builtin::kind::String => {
-            let name = get_static_string_value(key, context).unwrap();
-            if requires_quoting(&name) {
-                return MemberName {
-                    type_: MemberNameType::Quoted,
-                    name: format!("\"{name}\"").into(),
-                };
-            }
-            MemberName {
-                type_: MemberNameType::Normal,
-                name,
-            }
+            get_name_from_literal_key(get_static_string_value(key, context).unwrap())
         }
+        // `1`, `1.0` and `0x1` all name the property `"1"`
+        Number => get_name_from_literal_key(
+            get_number_key_value(&key.text(context)).map_or_else(|| key.text(context), Cow::Owned),
+        ),
         _ => MemberName {
             type_: MemberNameType::Expression,
             name: key.text(context),
         },
     }
 }
+
+fn get_name_from_literal_key(name: Cow<str>) -> MemberName {
+    if !is_valid_identifier(&name) {
+        return MemberName {
+            type_: MemberNameType::Quoted,
+            name: quote_property_name(&name).into_owned().into(),
+        };
+    }
+    MemberName {
+        type_: MemberNameType::Normal,
+        name,
+    }
+}
+
+fn get_number_key_value(text: &str) -> Option<String> {
+    let value = parse_numeric_literal(text)?.value.to_number();
+    // (the property name is what `String(value)` gives, which for the
+    // "everyday" integers doesn't have a fractional part)
+    Some(if value.fract() == 0.0 && value.abs() < 1e21 {
+        format!("{value:.0}")
+    } else {
+        value.to_string()
+    })
+}
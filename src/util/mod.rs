@@ -1,5 +1,41 @@
+mod ancestors;
+mod bindings;
+mod class_members;
+mod commonjs;
+mod constant_expression;
+mod explicit_return_type_utils;
 mod get_string_length;
+mod identifier;
+#[cfg(any(feature = "stylistic", feature = "type-aware"))]
+mod indentation;
+mod language;
+mod literal;
+#[cfg(feature = "stylistic")]
+mod literal_kind;
+mod method_call;
 mod misc;
+mod name_pattern;
+mod numeric_literal;
+#[cfg(feature = "type-aware")]
+mod regex_literal;
 
+pub use ancestors::*;
+pub use bindings::*;
+pub use class_members::*;
+pub use commonjs::*;
+pub use constant_expression::*;
+pub use explicit_return_type_utils::*;
 pub use get_string_length::*;
+pub use identifier::*;
+#[cfg(any(feature = "stylistic", feature = "type-aware"))]
+pub use indentation::*;
+pub use language::*;
+pub use literal::*;
+#[cfg(feature = "stylistic")]
+pub use literal_kind::*;
+pub use method_call::*;
 pub use misc::*;
+pub use name_pattern::*;
+pub use numeric_literal::*;
+#[cfg(feature = "type-aware")]
+pub use regex_literal::*;
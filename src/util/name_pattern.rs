@@ -0,0 +1,96 @@
+use regex::{Regex, RegexBuilder};
+use serde::Deserialize;
+
+/// A name (eg of a method) to match against, as given in a rule's options:
+/// `/^on[A-Z]/` (optionally with `i`, `m`, `s` or `u` flags) is a regex,
+/// anything containing `*` or `?` is a glob (`render*`) and anything else has
+/// to match exactly.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(try_from = "String")]
+pub enum NamePattern {
+    Exact(String),
+    Glob(Regex),
+    Regex(Regex),
+}
+
+impl NamePattern {
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        if let Some((regex, flags)) = parse_regex_pattern(pattern) {
+            let mut builder = RegexBuilder::new(regex);
+            for flag in flags.chars() {
+                match flag {
+                    'i' => builder.case_insensitive(true),
+                    'm' => builder.multi_line(true),
+                    's' => builder.dot_matches_new_line(true),
+                    'u' => builder.unicode(true),
+                    _ => unreachable!(),
+                };
+            }
+            return builder.build().map(Self::Regex);
+        }
+        if pattern.contains(['*', '?']) {
+            return Regex::new(&glob_to_regex(pattern)).map(Self::Glob);
+        }
+        Ok(Self::Exact(pattern.to_owned()))
+    }
+
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            Self::Exact(exact) => exact == name,
+            Self::Glob(regex) | Self::Regex(regex) => regex.is_match(name),
+        }
+    }
+}
+
+impl TryFrom<String> for NamePattern {
+    type Error = regex::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(&value)
+    }
+}
+
+/// A regex given in a rule's options as just its source (eg `^I[A-Z]`), so
+/// that one that doesn't compile is an error in the options.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(try_from = "String")]
+pub struct RegexOption(pub Regex);
+
+impl TryFrom<String> for RegexOption {
+    type Error = regex::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Regex::new(&value).map(Self)
+    }
+}
+
+/// The pattern and flags of `/pattern/flags`.
+fn parse_regex_pattern(pattern: &str) -> Option<(&str, &str)> {
+    let rest = pattern.strip_prefix('/')?;
+    let (regex, flags) = rest.rsplit_once('/')?;
+    (!regex.is_empty()
+        && flags
+            .chars()
+            .all(|flag| matches!(flag, 'i' | 'm' | 's' | 'u')))
+    .then_some((regex, flags))
+}
+
+fn glob_to_regex(glob: &str) -> String {
+    let mut regex = String::with_capacity(glob.len() + 2);
+    regex.push('^');
+    let mut literal = [0; 4];
+    for c in glob.chars() {
+        match c {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            c => regex.push_str(&regex::escape(c.encode_utf8(&mut literal))),
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+/// Whether any of `patterns` matches `name`.
+pub fn matches_any_name_pattern(patterns: &[NamePattern], name: &str) -> bool {
+    patterns.iter().any(|pattern| pattern.matches(name))
+}
@@ -0,0 +1,79 @@
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum NumericLiteralValue {
+    Number(f64),
+    BigInt(i128),
+}
+
+impl NumericLiteralValue {
+    pub fn to_number(self) -> f64 {
+        match self {
+            Self::Number(value) => value,
+            Self::BigInt(value) => value as f64,
+        }
+    }
+}
+
+/// A numeric literal (eg `0x1_000n`) broken down into the parts that rules
+/// care about.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NumericLiteral {
+    pub value: NumericLiteralValue,
+    pub radix: u32,
+    /// The literal as written minus any separators, radix prefix and bigint
+    /// suffix (so for decimal literals, including any fraction/exponent).
+    pub digits: String,
+}
+
+impl NumericLiteral {
+    pub fn is_bigint(&self) -> bool {
+        matches!(self.value, NumericLiteralValue::BigInt(_))
+    }
+}
+
+/// Parses the text of a numeric literal (or a numeric-looking string), eg
+/// `1_000`, `0xff`, `017`, `1.5e3` or `123n`. Legacy octal literals count as
+/// octal, but eg `019` is decimal. Bigints that don't fit in an `i128` aren't
+/// supported.
+pub fn parse_numeric_literal(text: &str) -> Option<NumericLiteral> {
+    let (text, is_bigint) = match text.strip_suffix('n') {
+        Some(text) => (text, true),
+        None => (text, false),
+    };
+    let text = text.replace('_', "");
+    let (radix, digits) = split_radix_prefix(&text);
+    let value = if is_bigint {
+        NumericLiteralValue::BigInt(i128::from_str_radix(digits, radix).ok()?)
+    } else if radix == 10 {
+        if !digits
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-'))
+        {
+            return None;
+        }
+        NumericLiteralValue::Number(digits.parse().ok()?)
+    } else {
+        NumericLiteralValue::Number(digits.chars().try_fold(0f64, |value, digit| {
+            Some(value * radix as f64 + digit.to_digit(radix)? as f64)
+        })?)
+    };
+    Some(NumericLiteral {
+        value,
+        radix,
+        digits: digits.to_owned(),
+    })
+}
+
+fn split_radix_prefix(text: &str) -> (u32, &str) {
+    match text.get(..2) {
+        Some("0x" | "0X") => (16, &text[2..]),
+        Some("0o" | "0O") => (8, &text[2..]),
+        Some("0b" | "0B") => (2, &text[2..]),
+        _ if text.len() > 1
+            && text.starts_with('0')
+            && text[1..].chars().all(|c| ('0'..='7').contains(&c)) =>
+        {
+            (8, &text[1..])
+        }
+        _ => (10, text),
+    }
+}
@@ -0,0 +1,105 @@
+use std::borrow::Cow;
+
+use tree_sitter_lint::{tree_sitter::Node, NodeExt, QueryMatchContext};
+use tree_sitter_lint_plugin_eslint_builtin::{assert_kind, kind::Regex};
+
+#[derive(Clone, Debug)]
+pub struct RegexLiteral<'a> {
+    pub pattern: Cow<'a, str>,
+    pub flags: Cow<'a, str>,
+}
+
+impl<'a> RegexLiteral<'a> {
+    pub fn from_node(node: Node<'a>, context: &QueryMatchContext<'a, '_>) -> Self {
+        assert_kind!(node, Regex);
+
+        Self {
+            pattern: node.field("pattern").text(context),
+            flags: node
+                .child_by_field_name("flags")
+                .map_or_else(|| "".into(), |flags| flags.text(context)),
+        }
+    }
+
+    pub fn has_flag(&self, flag: char) -> bool {
+        self.flags.contains(flag)
+    }
+
+    /// `^foo` or `foo$`, if the regex is that and nothing else (and matching
+    /// isn't affected by its flags).
+    pub fn get_anchored_literal(&self) -> Option<AnchoredLiteral> {
+        if self.has_flag('i') || self.has_flag('m') {
+            return None;
+        }
+        get_anchored_literal(&self.pattern)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnchoredLiteral {
+    StartsWith(String),
+    EndsWith(String),
+}
+
+/// The string a regex pattern matches if it contains no regex syntax other
+/// than escaped characters, eg `foo\.bar` matches (only) `foo.bar`.
+pub fn parse_literal_regex_pattern(pattern: &str) -> Option<String> {
+    let mut value = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => value.push(match chars.next()? {
+                c @ ('^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{'
+                | '}' | '|' | '/' | '-') => c,
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                'f' => '\x0c',
+                'v' => '\x0b',
+                '0' if !chars.peek().is_some_and(char::is_ascii_digit) => '\0',
+                _ => return None,
+            }),
+            '^' | '$' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|' => {
+                return None
+            }
+            c => value.push(c),
+        }
+    }
+    Some(value)
+}
+
+/// See [`RegexLiteral::get_anchored_literal`].
+pub fn get_anchored_literal(pattern: &str) -> Option<AnchoredLiteral> {
+    if let Some(rest) = pattern.strip_prefix('^') {
+        return parse_literal_regex_pattern(rest)
+            .filter(|value| !value.is_empty())
+            .map(AnchoredLiteral::StartsWith);
+    }
+    // a trailing `\$` is an escaped dollar sign, not an anchor
+    let rest = pattern.strip_suffix('$')?;
+    if rest.chars().rev().take_while(|&c| c == '\\').count() % 2 == 1 {
+        return None;
+    }
+    parse_literal_regex_pattern(rest)
+        .filter(|value| !value.is_empty())
+        .map(AnchoredLiteral::EndsWith)
+}
+
+/// Renders `value` as a (double-quoted) JS string literal.
+pub fn to_string_literal(value: &str) -> String {
+    let mut literal = String::with_capacity(value.len() + 2);
+    literal.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c if c.is_control() => literal.push_str(&format!("\\u{:04x}", c as u32)),
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
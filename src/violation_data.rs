@@ -0,0 +1,259 @@
+//! The data keys under which rules that ban something (or that pick between
+//! interchangeable forms of something according to an option) say what they
+//! flagged, alongside whatever their own messages interpolate, so that
+//! consumers (eg producing SARIF) can pick that out the same way for every
+//! rule.
+
+/// What's banned, eg `@ts-ignore`, or for a rule picking between forms, the
+/// form that wasn't picked (eg `interface`).
+pub const BANNED_NAME: &str = "banned_name";
+
+/// What to use instead, where there's a single replacement.
+pub const REPLACEMENT: &str = "replacement";
+
+/// The option responsible: its name (eg array-type's `readonly`) or, for a
+/// rule whose options are a single string, its value (eg
+/// consistent-type-definitions' `type`).
+pub const OPTION: &str = "option";
+
+/// For each rule and message id, which of the keys above its violations
+/// always have.
+pub static VIOLATION_DATA_KEYS: &[(&str, &str, &[&str])] = &[
+    (
+        "array-type",
+        "error_string_array",
+        &[BANNED_NAME, REPLACEMENT, OPTION],
+    ),
+    (
+        "array-type",
+        "error_string_array_simple",
+        &[BANNED_NAME, REPLACEMENT, OPTION],
+    ),
+    (
+        "array-type",
+        "error_string_generic",
+        &[BANNED_NAME, REPLACEMENT, OPTION],
+    ),
+    (
+        "array-type",
+        "error_string_generic_simple",
+        &[BANNED_NAME, REPLACEMENT, OPTION],
+    ),
+    (
+        "ban-ts-comment",
+        "ts_directive_comment",
+        &[BANNED_NAME, OPTION],
+    ),
+    (
+        "ban-ts-comment",
+        "ts_directive_comment_description_not_match_pattern",
+        &[BANNED_NAME, OPTION],
+    ),
+    (
+        "ban-ts-comment",
+        "ts_directive_comment_requires_description",
+        &[BANNED_NAME, OPTION],
+    ),
+    (
+        "ban-ts-comment",
+        "ts_ignore_instead_of_expect_error",
+        &[BANNED_NAME, REPLACEMENT, OPTION],
+    ),
+    ("ban-tslint-comment", "comment_detected", &[BANNED_NAME]),
+    (
+        "ban-types",
+        "banned_type_message",
+        &[BANNED_NAME, REPLACEMENT],
+    ),
+    (
+        "class-literal-property-style",
+        "prefer_field_style",
+        &[BANNED_NAME, REPLACEMENT, OPTION],
+    ),
+    (
+        "class-literal-property-style",
+        "prefer_getter_style",
+        &[BANNED_NAME, REPLACEMENT, OPTION],
+    ),
+    (
+        "consistent-generic-constructors",
+        "prefer_constructor",
+        &[BANNED_NAME, REPLACEMENT, OPTION],
+    ),
+    (
+        "consistent-generic-constructors",
+        "prefer_type_annotation",
+        &[BANNED_NAME, REPLACEMENT, OPTION],
+    ),
+    (
+        "consistent-indexed-object-style",
+        "prefer_index_signature",
+        &[BANNED_NAME, REPLACEMENT, OPTION],
+    ),
+    (
+        "consistent-indexed-object-style",
+        "prefer_record",
+        &[BANNED_NAME, REPLACEMENT, OPTION],
+    ),
+    (
+        "consistent-type-definitions",
+        "interface_over_type",
+        &[BANNED_NAME, REPLACEMENT, OPTION],
+    ),
+    (
+        "consistent-type-definitions",
+        "type_over_interface",
+        &[BANNED_NAME, REPLACEMENT, OPTION],
+    ),
+    (
+        "method-signature-style",
+        "error_method",
+        &[BANNED_NAME, REPLACEMENT, OPTION],
+    ),
+    (
+        "method-signature-style",
+        "error_property",
+        &[BANNED_NAME, REPLACEMENT, OPTION],
+    ),
+    (
+        "no-array-method-misuse",
+        "mutated_slice_copy",
+        &[BANNED_NAME, REPLACEMENT],
+    ),
+    ("no-require-imports", "no_require_imports", &[BANNED_NAME]),
+    (
+        "no-restricted-ts-syntax",
+        "restricted_syntax",
+        &[BANNED_NAME],
+    ),
+    (
+        "no-restricted-ts-syntax",
+        "restricted_syntax_custom",
+        &[BANNED_NAME],
+    ),
+    ("no-var-requires", "no_var_reqs", &[BANNED_NAME]),
+    (
+        "parameter-properties",
+        "prefer_class_property",
+        &[BANNED_NAME, REPLACEMENT, OPTION],
+    ),
+    (
+        "parameter-properties",
+        "prefer_parameter_property",
+        &[BANNED_NAME, REPLACEMENT, OPTION],
+    ),
+    (
+        "prefer-namespace-keyword",
+        "use_namespace",
+        &[BANNED_NAME, REPLACEMENT],
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use squalid::regex;
+
+    use super::*;
+    use crate::{instantiate, is_rule_available, rules::get_rule_tests};
+
+    fn get_placeholders(message: &str) -> HashSet<&str> {
+        regex!(r#"\{\{\s*(\w+)\s*\}\}"#)
+            .captures_iter(message)
+            .map(|captures| captures.get(1).unwrap().as_str())
+            .collect()
+    }
+
+    fn get_violation_data_keys(
+        rule_name: &str,
+        message_id: &str,
+    ) -> Option<&'static [&'static str]> {
+        VIOLATION_DATA_KEYS
+            .iter()
+            .find(|&&(entry_rule_name, entry_message_id, _)| {
+                entry_rule_name == rule_name && entry_message_id == message_id
+            })
+            .map(|&(_, _, keys)| keys)
+    }
+
+    /// Checks the data that the rule tests expect each error to have against
+    /// the template of that error's own message (and against
+    /// `VIOLATION_DATA_KEYS`), and that for every message id the tests
+    /// expect that has anything to populate, at least one of them checks the
+    /// data.
+    #[test]
+    fn test_violation_data() {
+        let mut failures: Vec<String> = Default::default();
+        let mut unused_keys_entries = VIOLATION_DATA_KEYS.iter().collect::<HashSet<_>>();
+        for rule in instantiate().rules {
+            let meta = rule.meta();
+            let messages = meta
+                .messages
+                .iter()
+                .flatten()
+                .map(|(message_id, message)| (message_id.to_string(), message.to_string()))
+                .collect::<HashMap<_, _>>();
+            let Some(rule_tests) = get_rule_tests(&meta.name) else {
+                failures.push(format!("{}: no test cases", meta.name));
+                continue;
+            };
+
+            let mut is_data_checked: HashMap<&str, bool> = Default::default();
+            for error in rule_tests.invalid.iter().flat_map(|case| &case.errors) {
+                let Some(message_id) = error.message_id.as_deref() else {
+                    continue;
+                };
+                let Some(message) = messages.get(message_id) else {
+                    failures.push(format!("{}: unknown message id {message_id}", meta.name));
+                    continue;
+                };
+                let is_checked = is_data_checked.entry(message_id).or_default();
+                let Some(data) = error.data.as_ref() else {
+                    continue;
+                };
+                *is_checked = true;
+                for key in get_placeholders(message).into_iter().chain(
+                    get_violation_data_keys(&meta.name, message_id)
+                        .into_iter()
+                        .flatten()
+                        .copied(),
+                ) {
+                    if !data.contains_key(key) {
+                        failures.push(format!(
+                            "{}: no {key} in the data expected for {message_id}: {data:?}",
+                            meta.name,
+                        ));
+                    }
+                }
+            }
+
+            for (message_id, is_checked) in is_data_checked {
+                unused_keys_entries.retain(|&&(rule_name, entry_message_id, _)| {
+                    !(rule_name == meta.name && entry_message_id == message_id)
+                });
+                let has_data = !get_placeholders(&messages[message_id]).is_empty()
+                    || get_violation_data_keys(&meta.name, message_id).is_some();
+                if has_data && !is_checked {
+                    failures.push(format!(
+                        "{}: no test case checks the data of {message_id}",
+                        meta.name
+                    ));
+                }
+            }
+        }
+        failures.extend(
+            unused_keys_entries
+                .into_iter()
+                .filter(|(rule_name, _, _)| is_rule_available(rule_name))
+                .map(|(rule_name, message_id, _)| {
+                    format!("{rule_name}: no test case expects message id {message_id}")
+                }),
+        );
+        assert!(
+            failures.is_empty(),
+            "violation data problems:\n{}",
+            failures.join("\n")
+        );
+    }
+}
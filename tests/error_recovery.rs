@@ -0,0 +1,287 @@
+//! Smoke tests that no rule panics on source that doesn't parse cleanly (eg
+//! truncated while being typed), whose trees have `ERROR`/`MISSING` nodes
+//! where the rules' helpers would otherwise find the fields they `unwrap()`.
+//!
+//! Each rule is run over each broken input as a valid case, since whether it
+//! reports anything there isn't the point: a failed expectation (which panics
+//! from within `RuleTester`) is tolerated, a panic from this crate's own
+//! source isn't. The fixes of whatever does get reported are then applied to
+//! the input, and have to stay within it and not overlap each other.
+
+use std::{
+    ops::Range,
+    panic::{self, AssertUnwindSafe},
+    sync::{Arc, Mutex},
+};
+
+use tree_sitter_lint::{
+    rule_tests, run_for_slice, tree_sitter::Parser, tree_sitter_grep::SupportedLanguage,
+    ConfigBuilder, Rule, RuleTestValidBuilder, RuleTester,
+};
+use tree_sitter_lint_plugin_typescript_eslint::{get_instance_provider_factory, instantiate};
+
+/// Covers a bit of everything, so that truncating it after each line breaks
+/// off most kinds of node partway through.
+const FIXTURE: &str = r#"import Foo, { type Bar, baz as qux } from "./foo";
+import * as ns from "ns";
+export = ns;
+declare module "m" {
+  export function f(a?: number): void;
+}
+namespace A.B {
+  export const x = 1;
+}
+enum E {
+  A = 1,
+  B = A << 1,
+}
+type T<U extends object = {}> = U extends Array<infer V> ? V[] : readonly U[];
+interface I<T> extends Foo, Bar {
+  [key: string]: any;
+  method?<U>(this: I<T>, ...args: U[]): T | null;
+  new (x: number): I<T>;
+}
+abstract class C<T> extends Base<T> implements I<T> {
+  private readonly x: number = 1;
+  static #y?: string;
+  declare z: T;
+  constructor(public a: string, protected b = 2) {
+    super();
+  }
+  @decorator()
+  get value(): T {
+    return this.z!;
+  }
+  abstract m(): void;
+  async *gen(): AsyncGenerator<number> {
+    yield* [1, 2];
+  }
+}
+function overloaded(x: string): string;
+function overloaded(x: any) {
+  if (typeof x === "string" && x !== null) {
+    return <string>x;
+  } else if (x?.y ?? false) {
+    for (const [k, v] of Object.entries(x)) {
+      delete x[k];
+    }
+  }
+  try {
+    return (x as unknown as T).foo?.[0]!;
+  } catch (e: unknown) {
+    throw new Error(`bad ${e}`);
+  } finally {
+    label: while (true) break label;
+  }
+}
+const arrow = async <T,>({ a, b: [c] = [] }: { a: T; b?: T[] }): Promise<void> => {
+  await Promise.all([a, c].map((item) => item satisfies T));
+};
+let { length } = "abc", re = /a+b/gu, n = 1_000n, obj = { [Symbol.iterator]: 1, ...{} };
+switch (n) {
+  case 1n:
+    var hoisted = require("x");
+    break;
+  default:
+}
+// eslint-disable-next-line
+export default class extends C<number> {}
+"#;
+
+/// Broken in ways truncation alone doesn't produce.
+const BROKEN_SNIPPETS: &[&str] = &[
+    "let x: = 1;",
+    "const = 1;",
+    "function (a, b {}",
+    "class { foo(): }",
+    "class A extends {}",
+    "interface { a: }",
+    "enum { A = }",
+    "type T = A extends ? B : C;",
+    "x as;",
+    "<>x;",
+    "x!.;",
+    "a?.[;",
+    "a ?? ;",
+    "a === ;",
+    "delete ;",
+    "import { from 'x';",
+    "export { a as };",
+    "for (const of xs) {}",
+    "for (;;",
+    "if () {} else",
+    "switch (x) { case: }",
+    "try {} catch () {}",
+    "`${`",
+    "@ class A {}",
+    "namespace . {}",
+    "declare module {}",
+    "abstract class A { abstract m() {} }",
+    "const f = (a,) => ;",
+    "x = y ||",
+    "}{",
+];
+
+/// Where the most recent panic came from.
+static PANIC_LOCATION: Mutex<Option<String>> = Mutex::new(None);
+
+/// Whether a panic at `location` (as recorded in `PANIC_LOCATION`, with
+/// Windows' backslashes already turned into slashes) came from this crate's
+/// own source.
+fn is_in_crate_source(location: &str) -> bool {
+    location.starts_with("src/")
+}
+
+/// The replacements that the fixes of each of the rule's violations on
+/// `input` make, one `Vec` per violation.
+fn get_fixes(rule: &Arc<dyn Rule>, input: &str) -> Vec<Vec<(Range<usize>, String)>> {
+    run_for_slice(
+        input.as_bytes(),
+        None,
+        "broken.ts",
+        ConfigBuilder::default()
+            .rule(rule.meta().name.clone())
+            .all_standalone_rules([rule.clone()])
+            .from_file_run_context_instance_provider_factory(get_instance_provider_factory())
+            .build()
+            .unwrap(),
+        SupportedLanguage::Typescript,
+    )
+    .into_iter()
+    .map(|violation| {
+        violation
+            .fixes
+            .into_iter()
+            .flatten()
+            .map(|fix| (fix.range.start_byte..fix.range.end_byte, fix.replacement))
+            .collect()
+    })
+    .collect()
+}
+
+/// Applies one violation's fixes to `input`, or says why they can't be.
+fn apply_fixes(input: &str, fixes: &[(Range<usize>, String)]) -> Result<String, String> {
+    let mut fixes = fixes.iter().collect::<Vec<_>>();
+    fixes.sort_by_key(|(range, _)| (range.start, range.end));
+    let mut previous_end = 0;
+    for (range, _) in &fixes {
+        if range.start > range.end || range.end > input.len() {
+            return Err(format!("fix range {range:?} is out of bounds"));
+        }
+        if !input.is_char_boundary(range.start) || !input.is_char_boundary(range.end) {
+            return Err(format!("fix range {range:?} splits a character"));
+        }
+        if range.start < previous_end {
+            return Err(format!("fix range {range:?} overlaps the previous one"));
+        }
+        previous_end = range.end;
+    }
+    let mut fixed = input.to_owned();
+    for (range, replacement) in fixes.into_iter().rev() {
+        fixed.replace_range(range.clone(), replacement);
+    }
+    Ok(fixed)
+}
+
+/// The fixture truncated after each line (where that leaves it broken), and
+/// the snippets.
+fn get_broken_inputs() -> Vec<String> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(SupportedLanguage::Typescript.language(None))
+        .unwrap();
+    let mut has_error = |input: &str| parser.parse(input, None).unwrap().root_node().has_error();
+    assert!(!has_error(FIXTURE));
+    for snippet in BROKEN_SNIPPETS {
+        assert!(has_error(snippet), "not broken: {snippet}");
+    }
+    FIXTURE
+        .match_indices('\n')
+        .map(|(index, _)| &FIXTURE[..index])
+        .filter(|truncated| has_error(truncated))
+        .chain(BROKEN_SNIPPETS.iter().copied())
+        .map(ToOwned::to_owned)
+        .collect()
+}
+
+#[test]
+fn test_apply_fixes() {
+    let fix = |range: Range<usize>, replacement: &str| (range, replacement.to_owned());
+    assert_eq!(
+        apply_fixes("let x: = 1;", &[fix(7..7, "any"), fix(0..3, "const")]),
+        Ok("const x: any= 1;".to_owned())
+    );
+    assert_eq!(
+        apply_fixes("a", &[fix(1..1, ";"), fix(1..1, "")]),
+        Ok("a;".to_owned())
+    );
+    assert!(apply_fixes("a", &[fix(0..2, "")]).is_err());
+    assert!(apply_fixes("é", &[fix(1..2, "")]).is_err());
+    assert!(apply_fixes("abc", &[fix(0..2, ""), fix(1..3, "")]).is_err());
+}
+
+#[test]
+fn test_rules_dont_panic_on_broken_input() {
+    let inputs = get_broken_inputs();
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        *PANIC_LOCATION.lock().unwrap() = info.location().map(|location| {
+            format!(
+                "{}:{}:{}",
+                location.file().replace('\\', "/"),
+                location.line(),
+                location.column()
+            )
+        });
+    }));
+    let mut failures: Vec<String> = Default::default();
+    for rule in instantiate().rules {
+        let rule_name = &rule.meta().name;
+        for input in &inputs {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                RuleTester::run_with_from_file_run_context_instance_provider(
+                    rule.clone(),
+                    rule_tests! {
+                        valid => [
+                            ...[RuleTestValidBuilder::default()
+                                .code(input.clone())
+                                .build()
+                                .unwrap()],
+                        ],
+                        invalid => [],
+                    },
+                    get_instance_provider_factory(),
+                );
+            }));
+            let location = PANIC_LOCATION.lock().unwrap().take();
+            if result.is_err() {
+                if let Some(location) = location.filter(|location| is_in_crate_source(location)) {
+                    failures.push(format!("{rule_name} panicked at {location} on:\n{input}"));
+                    continue;
+                }
+            }
+
+            // the rule itself got through the input above, so a panic here
+            // is from computing its fixes
+            let Ok(fixes) = panic::catch_unwind(AssertUnwindSafe(|| get_fixes(&rule, input)))
+            else {
+                let location = PANIC_LOCATION.lock().unwrap().take().unwrap_or_default();
+                failures.push(format!(
+                    "{rule_name} panicked at {location} while fixing:\n{input}"
+                ));
+                continue;
+            };
+            for violation_fixes in fixes {
+                if let Err(error) = apply_fixes(input, &violation_fixes) {
+                    failures.push(format!("{rule_name}: {error} on:\n{input}"));
+                }
+            }
+        }
+    }
+    panic::set_hook(previous_hook);
+    assert!(
+        failures.is_empty(),
+        "rules that panicked or produced bad fixes on broken input:\n{}",
+        failures.join("\n\n")
+    );
+}